@@ -35,7 +35,7 @@ impl PathExtractionError {
 
 /// Extract the filesystem path from a SearchResult for reveal/copy operations.
 ///
-/// Supports: Script, App, Agent
+/// Supports: Script, App, Agent, RecentFile
 /// Not supported: Scriptlet, BuiltIn, Window, Fallback
 pub fn extract_path_for_reveal(
     result: Option<&SearchResult>,
@@ -45,6 +45,7 @@ pub fn extract_path_for_reveal(
         Some(SearchResult::Script(m)) => Ok(m.script.path.clone()),
         Some(SearchResult::App(m)) => Ok(m.app.path.clone()),
         Some(SearchResult::Agent(m)) => Ok(m.agent.path.clone()),
+        Some(SearchResult::RecentFile(m)) => Ok(m.file.path.clone()),
         Some(SearchResult::Scriptlet(_)) => Err(PathExtractionError::UnsupportedType(
             SharedString::from("Cannot reveal scriptlets in Finder"),
         )),
@@ -69,6 +70,7 @@ pub fn extract_path_for_copy(
         Some(SearchResult::Script(m)) => Ok(m.script.path.clone()),
         Some(SearchResult::App(m)) => Ok(m.app.path.clone()),
         Some(SearchResult::Agent(m)) => Ok(m.agent.path.clone()),
+        Some(SearchResult::RecentFile(m)) => Ok(m.file.path.clone()),
         Some(SearchResult::Scriptlet(_)) => Err(PathExtractionError::UnsupportedType(
             SharedString::from("Cannot copy scriptlet path"),
         )),
@@ -107,6 +109,9 @@ pub fn extract_path_for_edit(
         Some(SearchResult::Window(_)) => Err(PathExtractionError::UnsupportedType(
             SharedString::from("Cannot edit windows"),
         )),
+        Some(SearchResult::RecentFile(_)) => Err(PathExtractionError::UnsupportedType(
+            SharedString::from("Cannot edit recent files"),
+        )),
         Some(SearchResult::Fallback(_)) => Err(PathExtractionError::UnsupportedType(
             SharedString::from("Cannot edit fallback commands"),
         )),
@@ -224,10 +229,13 @@ pub fn trigger_sdk_action(
 pub const RESERVED_ACTION_IDS: &[&str] = &[
     "run_script",
     "view_logs",
+    "view_last_run_log",
     "reveal_in_finder",
     "copy_path",
     "edit_script",
     "copy_deeplink",
+    "toggle_source_preview",
+    "cycle_sort_mode",
     // Dynamic shortcut actions (context-dependent)
     "add_shortcut",
     "update_shortcut",
@@ -21,6 +21,12 @@ fn make_script(name: &str, path: &str) -> Arc<Script> {
         shortcut: None,
         typed_metadata: None,
         schema: None,
+        concurrency: Default::default(),
+        tray: false,
+        background: false,
+        keep_open: false,
+        kenv: None,
+        app_filter: None,
     })
 }
 
@@ -46,6 +52,10 @@ fn make_scriptlet_match() -> ScriptletMatch {
             file_path: None,
             command: None,
             alias: None,
+            inputs: Vec::new(),
+            schema: None,
+            extra_blocks: Vec::new(),
+            sequence: false,
         }),
         score: 100,
         display_file_path: None,
@@ -170,6 +180,7 @@ fn test_extract_path_for_edit_scriptlet() {
 fn test_is_reserved_action_id() {
     assert!(is_reserved_action_id("copy_path"));
     assert!(is_reserved_action_id("edit_script"));
+    assert!(is_reserved_action_id("view_last_run_log"));
     assert!(is_reserved_action_id("copy_deeplink"));
     assert!(is_reserved_action_id("__cancel__"));
 
@@ -169,6 +169,13 @@ pub fn get_script_context_actions(script: &ScriptInfo) -> Vec<Action> {
             .with_shortcut("⌘L"),
         );
 
+        actions.push(Action::new(
+            "view_last_run_log",
+            "View Last Run Log",
+            Some("Open this script's most recent per-run log file".to_string()),
+            ActionCategory::ScriptContext,
+        ));
+
         actions.push(
             Action::new(
                 "reveal_in_finder",
@@ -188,6 +195,13 @@ pub fn get_script_context_actions(script: &ScriptInfo) -> Vec<Action> {
             )
             .with_shortcut("⌘⇧C"),
         );
+
+        actions.push(Action::new(
+            "toggle_source_preview",
+            "Toggle Source Preview",
+            Some("Switch between the doc and source code preview".to_string()),
+            ActionCategory::ScriptContext,
+        ));
     }
 
     // Copy deeplink - available for both scripts and built-ins
@@ -211,7 +225,15 @@ pub fn get_script_context_actions(script: &ScriptInfo) -> Vec<Action> {
 /// Predefined global actions
 /// Note: Settings and Quit are available from the main menu, not shown in actions dialog
 pub fn get_global_actions() -> Vec<Action> {
-    vec![]
+    vec![Action::new(
+        "cycle_sort_mode",
+        "Sort by Name / Last Used / Frecency",
+        Some(
+            "Cycle how the SCRIPTS/SCRIPTLETS/COMMANDS/APPS/AGENTS sections are ordered"
+                .to_string(),
+        ),
+        ActionCategory::GlobalOps,
+    )]
 }
 
 #[cfg(test)]
@@ -228,6 +250,7 @@ mod tests {
         // Script-specific actions should be present
         assert!(actions.iter().any(|a| a.id == "edit_script"));
         assert!(actions.iter().any(|a| a.id == "view_logs"));
+        assert!(actions.iter().any(|a| a.id == "view_last_run_log"));
         assert!(actions.iter().any(|a| a.id == "reveal_in_finder"));
         assert!(actions.iter().any(|a| a.id == "copy_path"));
         assert!(actions.iter().any(|a| a.id == "run_script"));
@@ -268,6 +291,7 @@ mod tests {
         // Should NOT have script-only actions
         assert!(!actions.iter().any(|a| a.id == "edit_script"));
         assert!(!actions.iter().any(|a| a.id == "view_logs"));
+        assert!(!actions.iter().any(|a| a.id == "view_last_run_log"));
         assert!(!actions.iter().any(|a| a.id == "reveal_in_finder"));
         assert!(!actions.iter().any(|a| a.id == "copy_path"));
     }
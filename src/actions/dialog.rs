@@ -104,6 +104,7 @@ impl ActionsDialog {
     ) -> Self {
         let actions = get_path_context_actions(path_info);
         let filtered_actions: Vec<usize> = (0..actions.len()).collect();
+        Self::check_shortcut_conflicts(&actions);
 
         logging::log(
             "ACTIONS",
@@ -254,6 +255,7 @@ impl ActionsDialog {
             ),
         );
 
+        Self::check_shortcut_conflicts(&converted);
         self.actions = converted;
         self.filtered_actions = (0..self.actions.len()).collect();
         self.selected_index = 0;
@@ -340,6 +342,7 @@ impl ActionsDialog {
         // Add global actions
         actions.extend(get_global_actions());
 
+        Self::check_shortcut_conflicts(&actions);
         actions
     }
 
@@ -551,11 +554,52 @@ impl ActionsDialog {
     /// Returns true if the action has close: true (or no close field, which defaults to true)
     /// Returns true for built-in actions (they always close)
     pub fn selected_action_should_close(&self) -> bool {
-        if let Some(protocol_action) = self.get_selected_protocol_action() {
-            protocol_action.should_close()
-        } else {
-            // Built-in actions always close
-            true
+        self.action_should_close(self.get_selected_action_id().as_deref())
+    }
+
+    /// Check if a given action id should close the dialog when executed.
+    /// Same close semantics as `selected_action_should_close`, but for an
+    /// action resolved by id (e.g. via a direct shortcut) rather than the
+    /// current selection.
+    fn action_should_close(&self, action_id: Option<&str>) -> bool {
+        let protocol_action = action_id.and_then(|id| {
+            self.sdk_actions
+                .as_ref()?
+                .iter()
+                .find(|a| a.name == id)
+        });
+        match protocol_action {
+            Some(protocol_action) => protocol_action.should_close(),
+            // Built-in actions (or no match) always close.
+            None => true,
+        }
+    }
+
+    /// Resolve a normalized shortcut string (e.g. "cmd+shift+c") to the id
+    /// of the first action that declares it, in display order - "first wins"
+    /// for conflicting shortcuts (see `check_shortcut_conflicts`).
+    pub fn resolve_shortcut(&self, normalized: &str) -> Option<String> {
+        resolve_shortcut_in(&self.actions, normalized)
+    }
+
+    /// Check if executing `action_id` directly via its shortcut should close
+    /// the dialog, mirroring `selected_action_should_close`'s semantics.
+    pub fn shortcut_action_should_close(&self, action_id: &str) -> bool {
+        self.action_should_close(Some(action_id))
+    }
+
+    /// Log a warning for any shortcuts shared by more than one action in
+    /// `actions`. The first action to declare a given shortcut wins;
+    /// resolution order in `resolve_shortcut` always favors it.
+    fn check_shortcut_conflicts(actions: &[Action]) {
+        for (shortcut, first_id, conflicting_id) in find_shortcut_conflicts(actions) {
+            logging::log(
+                "ACTIONS",
+                &format!(
+                    "Shortcut conflict: '{}' is used by both '{}' and '{}' - '{}' wins",
+                    shortcut, first_id, conflicting_id, first_id
+                ),
+            );
         }
     }
 
@@ -1195,3 +1239,82 @@ impl Render for ActionsDialog {
             .when(!self.hide_search, |d| d.child(input_container))
     }
 }
+
+/// Resolve a normalized shortcut string to the id of the first action in
+/// `actions` that declares it. Extracted as a free function (rather than
+/// living only on `ActionsDialog`) so it's testable without a gpui context.
+fn resolve_shortcut_in(actions: &[Action], normalized: &str) -> Option<String> {
+    actions
+        .iter()
+        .find(|action| action.shortcut_normalized().as_deref() == Some(normalized))
+        .map(|action| action.id.clone())
+}
+
+/// Find shortcuts shared by more than one action in `actions`, in display
+/// order. Returns `(shortcut, first_action_id, conflicting_action_id)` for
+/// each conflict - the first action to declare a shortcut always wins.
+fn find_shortcut_conflicts(actions: &[Action]) -> Vec<(String, String, String)> {
+    let mut seen: Vec<(String, &str)> = Vec::new();
+    let mut conflicts = Vec::new();
+
+    for action in actions {
+        let Some(normalized) = action.shortcut_normalized() else {
+            continue;
+        };
+        match seen.iter().find(|(s, _)| *s == normalized) {
+            Some((_, first_id)) => {
+                conflicts.push((normalized, first_id.to_string(), action.id.clone()));
+            }
+            None => seen.push((normalized, &action.id)),
+        }
+    }
+
+    conflicts
+}
+
+#[cfg(test)]
+mod shortcut_tests {
+    use super::*;
+
+    fn action(id: &str, shortcut: &str) -> Action {
+        Action::new(id, id, None, ActionCategory::GlobalOps).with_shortcut(shortcut)
+    }
+
+    #[test]
+    fn resolve_shortcut_in_finds_matching_action() {
+        let actions = vec![action("edit", "⌘E"), action("copy_path", "⌘⇧C")];
+        assert_eq!(
+            resolve_shortcut_in(&actions, "cmd+shift+c"),
+            Some("copy_path".to_string())
+        );
+        assert_eq!(resolve_shortcut_in(&actions, "cmd+z"), None);
+    }
+
+    #[test]
+    fn resolve_shortcut_in_ignores_actions_without_a_modifier() {
+        let actions = vec![action("run", "↵")];
+        // "run"'s shortcut has no modifier, so it never registers as a
+        // normalized shortcut and can't be matched here.
+        assert_eq!(resolve_shortcut_in(&actions, "enter"), None);
+    }
+
+    #[test]
+    fn find_shortcut_conflicts_reports_first_wins() {
+        let actions = vec![
+            action("edit", "⌘E"),
+            action("export", "⌘E"),
+            action("copy_path", "⌘⇧C"),
+        ];
+        let conflicts = find_shortcut_conflicts(&actions);
+        assert_eq!(
+            conflicts,
+            vec![("cmd+e".to_string(), "edit".to_string(), "export".to_string())]
+        );
+    }
+
+    #[test]
+    fn find_shortcut_conflicts_empty_when_all_unique() {
+        let actions = vec![action("edit", "⌘E"), action("copy_path", "⌘⇧C")];
+        assert!(find_shortcut_conflicts(&actions).is_empty());
+    }
+}
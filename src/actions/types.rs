@@ -175,6 +175,64 @@ impl Action {
         self.shortcut = Some(shortcut.into());
         self
     }
+
+    /// Convert the display shortcut (e.g. "⌘⇧C") into the normalized
+    /// "cmd+shift+c" form used by `shortcuts::hotkey_compat::keystroke_to_shortcut`,
+    /// so a live keystroke can be compared against it directly.
+    ///
+    /// Returns `None` when the shortcut has no modifier key at all (bare
+    /// "↵", arrows, etc.) - those are already owned by the actions dialog's
+    /// dedicated navigation handling and must never be captured here.
+    pub fn shortcut_normalized(&self) -> Option<String> {
+        let raw = self.shortcut.as_ref()?;
+
+        let mut alt = false;
+        let mut cmd = false;
+        let mut ctrl = false;
+        let mut shift = false;
+        let mut key = String::new();
+
+        for ch in raw.chars() {
+            match ch {
+                '⌘' => cmd = true,
+                '⌃' => ctrl = true,
+                '⌥' => alt = true,
+                '⇧' => shift = true,
+                '↵' => key.push_str("enter"),
+                '⎋' => key.push_str("escape"),
+                '⇥' => key.push_str("tab"),
+                '⌫' => key.push_str("backspace"),
+                '␣' => key.push_str("space"),
+                '↑' => key.push_str("up"),
+                '↓' => key.push_str("down"),
+                '←' => key.push_str("left"),
+                '→' => key.push_str("right"),
+                other => key.push(other.to_ascii_lowercase()),
+            }
+        }
+
+        if key.is_empty() || !(alt || cmd || ctrl || shift) {
+            return None;
+        }
+
+        // Same modifier order as `hotkey_compat::keystroke_to_shortcut`.
+        let mut parts: Vec<&str> = Vec::new();
+        if alt {
+            parts.push("alt");
+        }
+        if cmd {
+            parts.push("cmd");
+        }
+        if ctrl {
+            parts.push("ctrl");
+        }
+        if shift {
+            parts.push("shift");
+        }
+        parts.push(&key);
+
+        Some(parts.join("+"))
+    }
 }
 
 #[cfg(test)]
@@ -240,6 +298,43 @@ mod tests {
         assert_eq!(action.shortcut, Some("⌘T".to_string()));
     }
 
+    #[test]
+    fn test_shortcut_normalized_single_modifier() {
+        let action = Action::new("edit", "Edit", None, ActionCategory::ScriptContext)
+            .with_shortcut("⌘E");
+        assert_eq!(action.shortcut_normalized(), Some("cmd+e".to_string()));
+    }
+
+    #[test]
+    fn test_shortcut_normalized_multiple_modifiers_match_keystroke_order() {
+        let action = Action::new("copy_path", "Copy Path", None, ActionCategory::ScriptContext)
+            .with_shortcut("⌘⇧C");
+        assert_eq!(action.shortcut_normalized(), Some("cmd+shift+c".to_string()));
+    }
+
+    #[test]
+    fn test_shortcut_normalized_special_glyph() {
+        let action = Action::new("trash", "Move to Trash", None, ActionCategory::ScriptContext)
+            .with_shortcut("⌘⌫");
+        assert_eq!(
+            action.shortcut_normalized(),
+            Some("cmd+backspace".to_string())
+        );
+    }
+
+    #[test]
+    fn test_shortcut_normalized_none_without_modifier() {
+        let action = Action::new("run", "Run", None, ActionCategory::ScriptContext)
+            .with_shortcut("↵");
+        assert_eq!(action.shortcut_normalized(), None);
+    }
+
+    #[test]
+    fn test_shortcut_normalized_none_without_shortcut() {
+        let action = Action::new("run", "Run", None, ActionCategory::ScriptContext);
+        assert_eq!(action.shortcut_normalized(), None);
+    }
+
     #[test]
     fn test_action_new_defaults() {
         let action = Action::new(
@@ -3,6 +3,102 @@
 // Contains: handle_action, trigger_action_by_name
 
 impl ScriptListApp {
+    /// Begin graceful shutdown: ask any running scripts to exit on their own,
+    /// then escalate to SIGTERM and finally SIGKILL if they don't.
+    ///
+    /// Used by the quit action, the tray "Quit" menu item, and the
+    /// SIGINT/SIGTERM/SIGHUP handler (via the shutdown monitor task) so all
+    /// three paths share the same sequence instead of killing scripts
+    /// immediately. Setting `SHUTDOWN_REQUESTED` here also stops new scripts
+    /// from spawning while the sequence runs (see `is_shutting_down`).
+    fn begin_graceful_shutdown(&mut self, cx: &mut Context<Self>) {
+        // Dev session snapshot: save immediately (no point debouncing when
+        // the process is about to exit) - no-op unless `restore_session` is on
+        self.save_session_snapshot_now();
+
+        SHUTDOWN_REQUESTED.store(true, Ordering::SeqCst);
+
+        // The shutdown monitor task, the tray "Quit" item, and the in-app
+        // quit action can all race to call this - only run the sequence once.
+        if SHUTDOWN_IN_PROGRESS.swap(true, Ordering::SeqCst) {
+            logging::log(
+                "SHUTDOWN",
+                "Graceful shutdown already in progress, ignoring",
+            );
+            return;
+        }
+
+        let processes = PROCESS_MANAGER.get_active_processes();
+        let shutdown_config = self.config.get_shutdown();
+
+        if processes.is_empty() || shutdown_config.skip_wait {
+            logging::log(
+                "SHUTDOWN",
+                if processes.is_empty() {
+                    "No active processes, shutting down immediately"
+                } else {
+                    "Skip-wait configured, killing active processes immediately"
+                },
+            );
+            PROCESS_MANAGER.kill_all_processes();
+            PROCESS_MANAGER.remove_main_pid();
+            cx.quit();
+            return;
+        }
+
+        logging::log(
+            "SHUTDOWN",
+            &format!(
+                "Waiting up to {}ms for {} active process(es) to exit",
+                shutdown_config.grace_period_ms,
+                processes.len()
+            ),
+        );
+        self.show_hud(
+            "Waiting for scripts to finish…".to_string(),
+            Some(shutdown_config.grace_period_ms),
+            cx,
+        );
+
+        // Only the foreground interactive script has a live stdin sender -
+        // background/scheduled processes go straight to SIGTERM/SIGKILL.
+        let current_pid = self.current_script_pid;
+        let sender = self.response_sender.clone();
+        let targets: Vec<process_manager::RunningScriptTarget> = processes
+            .into_iter()
+            .map(|info| process_manager::RunningScriptTarget {
+                pid: info.pid,
+                sender: if Some(info.pid) == current_pid {
+                    sender.clone()
+                } else {
+                    None
+                },
+            })
+            .collect();
+
+        let grace_period = std::time::Duration::from_millis(shutdown_config.grace_period_ms);
+        let (done_tx, done_rx) = async_channel::bounded(1);
+
+        std::thread::spawn(move || {
+            escalate_shutdown(
+                &targets,
+                grace_period,
+                std::time::Duration::from_millis(100),
+            );
+            let _ = done_tx.try_send(());
+        });
+
+        cx.spawn(async move |_this, cx| {
+            let _ = done_rx.recv().await;
+            logging::log("SHUTDOWN", "All processes stopped, quitting application");
+            let _ = cx.update(|cx| {
+                PROCESS_MANAGER.remove_main_pid();
+                cx.quit();
+            });
+        })
+        .detach();
+    }
+
     /// Helper to hide main window and set reset flag
     fn hide_main_and_reset(&self, cx: &mut Context<Self>) {
         set_main_window_visible(false);
@@ -76,6 +172,19 @@ impl ScriptListApp {
                 logging::log("UI", "View logs action");
                 self.toggle_logs(cx);
             }
+            "toggle_source_preview" => {
+                logging::log("UI", "Toggle source preview action");
+                self.toggle_source_preview(cx);
+            }
+            "cycle_sort_mode" => {
+                let new_mode = self.list_sort.cycle();
+                self.list_sort.save().ok(); // Best-effort save
+                self.invalidate_grouped_cache();
+                logging::log("UI", &format!("Cycled sort mode to {}", new_mode.label()));
+                self.last_output =
+                    Some(SharedString::from(format!("Sort by: {}", new_mode.label())));
+                cx.notify();
+            }
             "reveal_in_finder" => {
                 logging::log("UI", "Reveal in Finder action");
                 if let Some(result) = self.get_selected_result() {
@@ -83,6 +192,7 @@ impl ScriptListApp {
                         scripts::SearchResult::Script(m) => Some(m.script.path.clone()),
                         scripts::SearchResult::App(m) => Some(m.app.path.clone()),
                         scripts::SearchResult::Agent(m) => Some(m.agent.path.clone()),
+                        scripts::SearchResult::RecentFile(m) => Some(m.file.path.clone()),
                         scripts::SearchResult::Scriptlet(_) => {
                             self.last_output =
                                 Some(SharedString::from("Cannot reveal scriptlets in Finder"));
@@ -122,6 +232,7 @@ impl ScriptListApp {
                         scripts::SearchResult::Script(m) => Some(m.script.path.clone()),
                         scripts::SearchResult::App(m) => Some(m.app.path.clone()),
                         scripts::SearchResult::Agent(m) => Some(m.agent.path.clone()),
+                        scripts::SearchResult::RecentFile(m) => Some(m.file.path.clone()),
                         scripts::SearchResult::Scriptlet(_) => {
                             self.last_output =
                                 Some(SharedString::from("Cannot copy scriptlet path"));
@@ -230,6 +341,11 @@ impl ScriptListApp {
                                 "Window shortcuts not supported - windows are transient",
                             ));
                         }
+                        scripts::SearchResult::RecentFile(_) => {
+                            self.last_output = Some(SharedString::from(
+                                "Recent file shortcuts not supported",
+                            ));
+                        }
                         scripts::SearchResult::Fallback(m) => {
                             match &m.fallback {
                                 crate::fallbacks::collector::FallbackItem::Builtin(b) => {
@@ -237,6 +353,11 @@ impl ScriptListApp {
                                     let command_name = b.name.to_string();
                                     self.show_shortcut_recorder(command_id, command_name, cx);
                                 }
+                                crate::fallbacks::collector::FallbackItem::Template(t) => {
+                                    let command_id = format!("fallback/{}", m.fallback.name());
+                                    let command_name = t.name.clone();
+                                    self.show_shortcut_recorder(command_id, command_name, cx);
+                                }
                                 crate::fallbacks::collector::FallbackItem::Script(s) => {
                                     // Script-based fallback - open the script
                                     self.edit_script(&s.script.path);
@@ -279,6 +400,11 @@ impl ScriptListApp {
                                 Some(SharedString::from("Window shortcuts not supported"));
                             None
                         }
+                        scripts::SearchResult::RecentFile(_) => {
+                            self.last_output =
+                                Some(SharedString::from("Recent file shortcuts not supported"));
+                            None
+                        }
                         scripts::SearchResult::Fallback(m) => {
                             Some(format!("fallback/{}", m.fallback.name()))
                         }
@@ -333,6 +459,10 @@ impl ScriptListApp {
                             self.last_output = Some(SharedString::from("Cannot edit windows"));
                             None
                         }
+                        scripts::SearchResult::RecentFile(_) => {
+                            self.last_output = Some(SharedString::from("Cannot edit recent files"));
+                            None
+                        }
                         scripts::SearchResult::Fallback(_) => {
                             self.last_output =
                                 Some(SharedString::from("Cannot edit fallback commands"));
@@ -348,6 +478,34 @@ impl ScriptListApp {
                     self.last_output = Some(SharedString::from("No script selected"));
                 }
             }
+            "view_last_run_log" => {
+                logging::log("UI", "View last run log action");
+                if let Some(result) = self.get_selected_result() {
+                    let name_opt = match result {
+                        scripts::SearchResult::Script(m) => Some(m.script.name.clone()),
+                        scripts::SearchResult::Agent(m) => Some(m.agent.name.clone()),
+                        _ => {
+                            self.last_output =
+                                Some(SharedString::from("No run logs for this item"));
+                            None
+                        }
+                    };
+
+                    if let Some(name) = name_opt {
+                        match executor::find_latest_run_log(&name) {
+                            Some(log_path) => {
+                                self.open_run_log(&log_path, cx);
+                            }
+                            None => {
+                                self.last_output =
+                                    Some(SharedString::from("No run logs found for this script"));
+                            }
+                        }
+                    }
+                } else {
+                    self.last_output = Some(SharedString::from("No script selected"));
+                }
+            }
             "reload_scripts" => {
                 logging::log("UI", "Reload scripts action");
                 self.refresh_scripts(cx);
@@ -359,9 +517,7 @@ impl ScriptListApp {
             }
             "quit" => {
                 logging::log("UI", "Quit action");
-                PROCESS_MANAGER.kill_all_processes();
-                PROCESS_MANAGER.remove_main_pid();
-                cx.quit();
+                self.begin_graceful_shutdown(cx);
                 return; // Early return after quit - no notify needed
             }
             "__cancel__" => {
@@ -369,7 +525,7 @@ impl ScriptListApp {
             }
             _ => {
                 // Handle SDK actions using shared helper
-                self.trigger_sdk_action_internal(&action_id);
+                self.trigger_sdk_action_internal(&action_id, cx);
             }
         }
 
@@ -377,7 +533,7 @@ impl ScriptListApp {
     }
 
     /// Internal helper for triggering SDK actions - used by both handle_action and trigger_action_by_name
-    fn trigger_sdk_action_internal(&mut self, action_name: &str) {
+    fn trigger_sdk_action_internal(&mut self, action_name: &str, cx: &mut Context<Self>) {
         if let Some(ref actions) = self.sdk_actions {
             if let Some(action) = actions.iter().find(|a| a.name == action_name) {
                 let send_result = if action.has_action {
@@ -441,6 +597,12 @@ impl ScriptListApp {
                         }
                         Err(std::sync::mpsc::TrySendError::Disconnected(_)) => {
                             logging::log("UI", "Response channel disconnected - script exited");
+                            self.show_hud(
+                                "Script is no longer running".to_string(),
+                                Some(3000),
+                                cx,
+                            );
+                            self.reset_to_script_list(cx);
                         }
                     }
                 }
@@ -461,11 +623,82 @@ impl ScriptListApp {
                     "ACTIONS",
                     &format!("Triggering SDK action '{}' via shortcut", action_name),
                 );
-                self.trigger_sdk_action_internal(action_name);
+                self.trigger_sdk_action_internal(action_name, cx);
                 cx.notify();
                 return true;
             }
         }
         false
     }
+
+    /// Send a `Message::ChoiceAction` for an action triggered from a
+    /// `Choice`'s own `actions` list, rather than the arg prompt's
+    /// script-level `sdk_actions`. Mirrors the send/error handling in
+    /// [`Self::trigger_sdk_action_internal`].
+    fn send_choice_action(
+        &mut self,
+        id: String,
+        choice_value: String,
+        action_id: String,
+        cx: &mut Context<Self>,
+    ) {
+        logging::log(
+            "ACTIONS",
+            &format!(
+                "Choice action triggered: '{}' on choice '{}'",
+                action_id, choice_value
+            ),
+        );
+        if let Some(ref sender) = self.response_sender {
+            let msg = protocol::Message::choice_action(id, choice_value, action_id.clone());
+            match sender.try_send(msg) {
+                Ok(()) => {}
+                Err(std::sync::mpsc::TrySendError::Full(_)) => {
+                    logging::log(
+                        "WARN",
+                        &format!("Response channel full - choice action '{}' dropped", action_id),
+                    );
+                }
+                Err(std::sync::mpsc::TrySendError::Disconnected(_)) => {
+                    logging::log("UI", "Response channel disconnected - script exited");
+                    self.show_hud("Script is no longer running".to_string(), Some(3000), cx);
+                    self.reset_to_script_list(cx);
+                }
+            }
+        }
+        cx.notify();
+    }
+
+    /// Notify the script that the highlighted choice changed in an
+    /// arg-family prompt (plain `Message::Arg` or `Message::Split`), so it
+    /// can push fresh preview content via `Message::SetPreview` or a
+    /// per-choice `Message::Preview`. A no-op outside of an arg-family
+    /// prompt - see `split_prompt_id`.
+    pub(crate) fn notify_selection_change(
+        &mut self,
+        choice_value: String,
+        index: usize,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(id) = self.split_prompt_id.clone() else {
+            return;
+        };
+        if let Some(ref sender) = self.response_sender {
+            let msg = protocol::Message::selection_change(id, choice_value, index);
+            match sender.try_send(msg) {
+                Ok(()) => {}
+                Err(std::sync::mpsc::TrySendError::Full(_)) => {
+                    logging::log(
+                        "WARN",
+                        "Response channel full - selection change notification dropped",
+                    );
+                }
+                Err(std::sync::mpsc::TrySendError::Disconnected(_)) => {
+                    logging::log("UI", "Response channel disconnected - script exited");
+                    self.show_hud("Script is no longer running".to_string(), Some(3000), cx);
+                    self.reset_to_script_list(cx);
+                }
+            }
+        }
+    }
 }
@@ -106,6 +106,10 @@ impl ScriptListApp {
                     Ok(windows) => {
                         logging::log("EXEC", &format!("Loaded {} windows", windows.len()));
                         self.cached_windows = windows;
+                        self.cached_frontmost_app = window_control::get_frontmost_window_of_previous_app()
+                            .ok()
+                            .flatten()
+                            .map(|w| w.app);
                         // Clear the shared input for fresh search (sync on next render)
                         self.filter_text = String::new();
                         self.pending_filter_sync = true;
@@ -133,6 +137,14 @@ impl ScriptListApp {
                 }
                 cx.notify();
             }
+            builtins::BuiltInFeature::RunningScripts => {
+                logging::log("EXEC", "Opening Running Scripts");
+                self.current_view = AppView::RunningScriptsView { selected_index: 0 };
+                resize_to_view_sync(ViewType::ScriptList, 0);
+                self.pending_focus = Some(FocusTarget::AppRoot);
+                self.focused_input = FocusedInput::None;
+                cx.notify();
+            }
             builtins::BuiltInFeature::DesignGallery => {
                 logging::log("EXEC", "Opening Design Gallery");
                 self.current_view = AppView::DesignGalleryView {
@@ -143,6 +155,16 @@ impl ScriptListApp {
                 resize_to_view_sync(ViewType::ScriptList, 0);
                 cx.notify();
             }
+            builtins::BuiltInFeature::Diagnostics => {
+                logging::log("EXEC", "Opening diagnostics report view");
+                let cache_stats = perf::CacheStats {
+                    filter_cache_entries: self.cached_filtered_results.len(),
+                    preview_cache_entries: self.preview_cache_lines.len(),
+                    clipboard_image_cache_entries: clipboard_history::image_cache_len(),
+                };
+                let report = perf::generate_diagnostics_report(&cache_stats);
+                self.open_diagnostics_view(report, cx);
+            }
             builtins::BuiltInFeature::AiChat => {
                 logging::log("EXEC", "Opening AI Chat window");
                 // Reset state, hide main window, and open AI window
@@ -755,6 +777,23 @@ impl ScriptListApp {
         }
     }
 
+    /// Open a recent file from the main search results with the system's
+    /// default application for its type, mirroring `execute_app`'s
+    /// launch-then-close-window shape.
+    fn execute_recent_file(&mut self, file: &recent_files::RecentFileInfo, cx: &mut Context<Self>) {
+        let path_str = file.path.to_string_lossy().to_string();
+        logging::log("EXEC", &format!("Opening recent file: {}", path_str));
+
+        if let Err(e) = file_search::open_file(&path_str) {
+            logging::log("ERROR", &format!("Failed to open {}: {}", path_str, e));
+            self.last_output = Some(SharedString::from(format!("Failed to open: {}", file.name)));
+            cx.notify();
+        } else {
+            logging::log("EXEC", &format!("Opened recent file: {}", path_str));
+            self.close_and_reset_window(cx);
+        }
+    }
+
     /// Focus a window from the main search results
     fn execute_window_focus(
         &mut self,
@@ -927,6 +966,111 @@ impl ScriptListApp {
         cx.notify();
     }
 
+    /// Open a script/agent's last run log in a read-only in-app editor view.
+    ///
+    /// Unlike `open_scratch_pad`, the content is not editable and nothing is
+    /// ever written back to disk - this is a viewer, not an editor.
+    fn open_run_log(&mut self, log_path: &std::path::Path, cx: &mut Context<Self>) {
+        logging::log(
+            "EXEC",
+            &format!("Opening run log view: {}", log_path.display()),
+        );
+
+        let content = match std::fs::read_to_string(log_path) {
+            Ok(content) => content,
+            Err(e) => {
+                logging::log(
+                    "ERROR",
+                    &format!("Failed to read run log {}: {}", log_path.display(), e),
+                );
+                self.toast_manager.push(
+                    components::toast::Toast::error(
+                        format!("Failed to read run log: {}", e),
+                        &self.theme,
+                    )
+                    .duration_ms(Some(5000)),
+                );
+                cx.notify();
+                return;
+            }
+        };
+
+        logging::log(
+            "EXEC",
+            &format!("Loaded run log with {} bytes", content.len()),
+        );
+
+        let editor_focus_handle = cx.focus_handle();
+        let editor_height = px(700.0 - window_resize::layout::FOOTER_HEIGHT);
+
+        let editor_prompt = EditorPrompt::read_only_view(
+            "run-log".to_string(),
+            content,
+            "log".to_string(),
+            editor_focus_handle.clone(),
+            std::sync::Arc::new(self.theme.clone()),
+            std::sync::Arc::new(self.config.clone()),
+            Some(editor_height),
+        );
+
+        let entity = cx.new(|_| editor_prompt);
+
+        self.current_view = AppView::RunLogView {
+            entity,
+            focus_handle: editor_focus_handle,
+        };
+        self.focused_input = FocusedInput::None;
+        self.pending_focus = Some(FocusTarget::EditorPrompt);
+
+        // DEFERRED RESIZE: Avoid RefCell borrow error by deferring window resize
+        // to after the current GPUI update cycle completes.
+        cx.spawn(async move |_this, _cx| {
+            resize_to_view_sync(ViewType::EditorPrompt, 0);
+        })
+        .detach();
+        cx.notify();
+    }
+
+    /// Open the perf/cache diagnostics report in a read-only in-app editor
+    /// view (see `AppView::DiagnosticsView`), rather than only copying it to
+    /// the clipboard.
+    fn open_diagnostics_view(&mut self, report: String, cx: &mut Context<Self>) {
+        logging::log(
+            "EXEC",
+            &format!("Loaded diagnostics report with {} bytes", report.len()),
+        );
+
+        let editor_focus_handle = cx.focus_handle();
+        let editor_height = px(700.0 - window_resize::layout::FOOTER_HEIGHT);
+
+        let editor_prompt = EditorPrompt::read_only_view(
+            "diagnostics".to_string(),
+            report,
+            "log".to_string(),
+            editor_focus_handle.clone(),
+            std::sync::Arc::new(self.theme.clone()),
+            std::sync::Arc::new(self.config.clone()),
+            Some(editor_height),
+        );
+
+        let entity = cx.new(|_| editor_prompt);
+
+        self.current_view = AppView::DiagnosticsView {
+            entity,
+            focus_handle: editor_focus_handle,
+        };
+        self.focused_input = FocusedInput::None;
+        self.pending_focus = Some(FocusTarget::EditorPrompt);
+
+        // DEFERRED RESIZE: Avoid RefCell borrow error by deferring window resize
+        // to after the current GPUI update cycle completes.
+        cx.spawn(async move |_this, _cx| {
+            resize_to_view_sync(ViewType::EditorPrompt, 0);
+        })
+        .detach();
+        cx.notify();
+    }
+
     /// Open a terminal with a specific command (for fallback "Run in Terminal")
     pub fn open_terminal_with_command(&mut self, command: String, cx: &mut Context<Self>) {
         logging::log(
@@ -947,6 +1091,9 @@ impl ScriptListApp {
         match term_prompt::TermPrompt::with_height(
             "fallback-terminal".to_string(),
             Some(command), // Run the specified command
+            None,
+            None,
+            None,
             self.focus_handle.clone(),
             submit_callback,
             std::sync::Arc::new(self.theme.clone()),
@@ -1096,6 +1243,9 @@ impl ScriptListApp {
         match term_prompt::TermPrompt::with_height(
             "quick-terminal".to_string(),
             None, // No command - opens default shell
+            None,
+            None,
+            None,
             self.focus_handle.clone(),
             submit_callback,
             std::sync::Arc::new(self.theme.clone()),
@@ -48,13 +48,17 @@ impl ScriptListApp {
             AppView::EnvPrompt { .. } => "EnvPrompt",
             AppView::DropPrompt { .. } => "DropPrompt",
             AppView::TemplatePrompt { .. } => "TemplatePrompt",
+            AppView::ConfirmPrompt { .. } => "ConfirmPrompt",
             AppView::ClipboardHistoryView { .. } => "ClipboardHistory",
             AppView::AppLauncherView { .. } => "AppLauncher",
             AppView::WindowSwitcherView { .. } => "WindowSwitcher",
             AppView::DesignGalleryView { .. } => "DesignGallery",
             AppView::ScratchPadView { .. } => "ScratchPad",
+            AppView::RunLogView { .. } => "RunLog",
+            AppView::DiagnosticsView { .. } => "Diagnostics",
             AppView::QuickTerminalView { .. } => "QuickTerminal",
             AppView::FileSearchView { .. } => "FileSearch",
+            AppView::RunningScriptsView { .. } => "RunningScripts",
             AppView::ActionsDialog => "ActionsDialog",
         };
 
@@ -512,13 +516,17 @@ impl ScriptListApp {
             AppView::EnvPrompt { .. } => "env",
             AppView::DropPrompt { .. } => "drop",
             AppView::TemplatePrompt { .. } => "template",
+            AppView::ConfirmPrompt { .. } => "confirm",
             AppView::ClipboardHistoryView { .. } => "clipboardHistory",
             AppView::AppLauncherView { .. } => "appLauncher",
             AppView::WindowSwitcherView { .. } => "windowSwitcher",
             AppView::DesignGalleryView { .. } => "designGallery",
             AppView::ScratchPadView { .. } => "scratchPad",
+            AppView::RunLogView { .. } => "runLog",
+            AppView::DiagnosticsView { .. } => "diagnostics",
             AppView::QuickTerminalView { .. } => "quickTerminal",
             AppView::FileSearchView { .. } => "fileSearch",
+            AppView::RunningScriptsView { .. } => "runningScripts",
             AppView::ActionsDialog => "actionsDialog",
         };
 
@@ -130,7 +130,7 @@ impl ScriptListApp {
     /// This should be called whenever scroll-related activity occurs:
     /// - Keyboard up/down navigation
     /// - scroll_to_item calls
-    /// - Mouse wheel scrolling (if tracked)
+    /// - Mouse wheel scrolling
     fn trigger_scroll_activity(&mut self, cx: &mut Context<Self>) {
         self.is_scrolling = true;
         self.last_scroll_time = Some(std::time::Instant::now());
@@ -140,9 +140,13 @@ impl ScriptListApp {
             Timer::after(std::time::Duration::from_millis(1000)).await;
             let _ = cx.update(|cx| {
                 this.update(cx, |app, cx| {
-                    // Only hide if no new scroll activity occurred
+                    // Only hide if no new scroll activity occurred, and the
+                    // user isn't hovering/dragging the scrollbar itself.
                     if let Some(last_time) = app.last_scroll_time {
-                        if last_time.elapsed() >= std::time::Duration::from_millis(1000) {
+                        if last_time.elapsed() >= std::time::Duration::from_millis(1000)
+                            && !app.scrollbar_hovered
+                            && !app.scrollbar_dragging
+                        {
                             app.is_scrolling = false;
                             cx.notify();
                         }
@@ -235,7 +239,10 @@ impl ScriptListApp {
         };
 
         // Final validation: ensure we're not on a header
-        if matches!(grouped_items.get(new_index), Some(GroupedListItem::SectionHeader(_))) {
+        if matches!(
+            grouped_items.get(new_index),
+            Some(GroupedListItem::SectionHeader(_))
+        ) {
             // Can't find a valid position, stay put
             return;
         }
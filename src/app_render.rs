@@ -253,57 +253,156 @@ impl ScriptListApp {
                                 .my(px(spacing.padding_sm)),
                         );
 
-                        // Code preview header
-                        panel = panel.child(
-                            div()
-                                .text_xs()
-                                .text_color(rgb(text_muted))
-                                .pb(px(spacing.padding_sm))
-                                .child("Code Preview"),
-                        );
-
-                        // Use cached syntax-highlighted lines (avoids file I/O and highlighting on every render)
                         let script_path = script.path.to_string_lossy().to_string();
-                        let lang = script.extension.clone();
-                        let lines = self
-                            .get_or_update_preview_cache(&script_path, &lang)
-                            .to_vec();
+                        let doc_blocks = if self.force_source_preview {
+                            None
+                        } else {
+                            self.get_or_update_doc_preview_cache(&script_path, &script.extension)
+                                .map(|blocks| blocks.to_vec())
+                        };
 
-                        // Build code container - render line by line with monospace font
-                        let mut code_container = div()
-                            .w_full()
-                            .min_w(px(280.))
-                            .p(px(spacing.padding_md))
-                            .rounded(px(border_radius))
-                            .bg(rgba((bg_search_box << 8) | 0x80))
-                            .overflow_hidden()
-                            .flex()
-                            .flex_col();
+                        if let Some(blocks) = doc_blocks {
+                            // Doc preview: labeled alias/schedule fields (name, description
+                            // and shortcut are already shown above) followed by the parsed
+                            // comment header / adjacent markdown file.
+                            if let Some(alias) = &script.alias {
+                                panel = panel.child(
+                                    div()
+                                        .flex()
+                                        .flex_col()
+                                        .pb(px(spacing.padding_md))
+                                        .child(
+                                            div()
+                                                .text_xs()
+                                                .text_color(rgb(text_muted))
+                                                .pb(px(spacing.padding_xs / 2.0))
+                                                .child("Alias"),
+                                        )
+                                        .child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(rgb(text_secondary))
+                                                .child(alias.clone()),
+                                        ),
+                                );
+                            }
 
-                        // Render each line as a row of spans with monospace font
-                        for line in lines {
-                            let mut line_div = div()
+                            let schedule = script
+                                .typed_metadata
+                                .as_ref()
+                                .and_then(|m| m.schedule.clone().or_else(|| m.cron.clone()));
+                            if let Some(schedule) = schedule {
+                                panel = panel.child(
+                                    div()
+                                        .flex()
+                                        .flex_col()
+                                        .pb(px(spacing.padding_md))
+                                        .child(
+                                            div()
+                                                .text_xs()
+                                                .text_color(rgb(text_muted))
+                                                .pb(px(spacing.padding_xs / 2.0))
+                                                .child("Schedule"),
+                                        )
+                                        .child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(rgb(text_secondary))
+                                                .child(schedule),
+                                        ),
+                                );
+                            }
+
+                            panel = panel.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(rgb(text_muted))
+                                    .pb(px(spacing.padding_sm))
+                                    .child("Documentation"),
+                            );
+
+                            let mut doc_container = div()
+                                .w_full()
+                                .min_w(px(280.))
+                                .p(px(spacing.padding_md))
+                                .rounded(px(border_radius))
+                                .bg(rgba((bg_search_box << 8) | 0x80))
+                                .overflow_hidden()
                                 .flex()
-                                .flex_row()
+                                .flex_col()
+                                .gap(px(spacing.gap_sm));
+
+                            for block in blocks {
+                                doc_container = doc_container.child(match block {
+                                    preview_doc::MarkdownBlock::Heading(level, text) => div()
+                                        .text_sm()
+                                        .font_weight(if level <= 2 {
+                                            gpui::FontWeight::SEMIBOLD
+                                        } else {
+                                            gpui::FontWeight::MEDIUM
+                                        })
+                                        .text_color(rgb(text_primary))
+                                        .child(text),
+                                    preview_doc::MarkdownBlock::Paragraph(text) => {
+                                        div().text_sm().text_color(rgb(text_secondary)).child(text)
+                                    }
+                                });
+                            }
+
+                            panel = panel.child(doc_container);
+                        } else {
+                            // Code preview header
+                            panel = panel.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(rgb(text_muted))
+                                    .pb(px(spacing.padding_sm))
+                                    .child("Code Preview"),
+                            );
+
+                            // Use cached syntax-highlighted lines (avoids file I/O and highlighting on every render)
+                            let lang = script.extension.clone();
+                            let lines = self
+                                .get_or_update_preview_cache(&script_path, &lang)
+                                .to_vec();
+
+                            // Build code container - render line by line with monospace font
+                            let mut code_container = div()
                                 .w_full()
-                                .font_family(typography.font_family_mono)
-                                .text_xs()
-                                .min_h(px(spacing.padding_lg)); // Line height
+                                .min_w(px(280.))
+                                .p(px(spacing.padding_md))
+                                .rounded(px(border_radius))
+                                .bg(rgba((bg_search_box << 8) | 0x80))
+                                .overflow_hidden()
+                                .flex()
+                                .flex_col();
 
-                            if line.spans.is_empty() {
-                                // Empty line - add a space to preserve height
-                                line_div = line_div.child(" ");
-                            } else {
-                                for span in line.spans {
-                                    line_div = line_div
-                                        .child(div().text_color(rgb(span.color)).child(span.text));
+                            // Render each line as a row of spans with monospace font
+                            for line in lines {
+                                let mut line_div = div()
+                                    .flex()
+                                    .flex_row()
+                                    .w_full()
+                                    .font_family(typography.font_family_mono)
+                                    .text_xs()
+                                    .min_h(px(spacing.padding_lg)); // Line height
+
+                                if line.spans.is_empty() {
+                                    // Empty line - add a space to preserve height
+                                    line_div = line_div.child(" ");
+                                } else {
+                                    for span in line.spans {
+                                        line_div = line_div.child(
+                                            div().text_color(rgb(span.color)).child(span.text),
+                                        );
+                                    }
                                 }
+
+                                code_container = code_container.child(line_div);
                             }
 
-                            code_container = code_container.child(line_div);
+                            panel = panel.child(code_container);
                         }
-
-                        panel = panel.child(code_container);
                     }
                     scripts::SearchResult::Scriptlet(scriptlet_match) => {
                         let scriptlet = &scriptlet_match.scriptlet;
@@ -594,7 +693,13 @@ impl ScriptListApp {
                             builtins::BuiltInFeature::WindowSwitcher => {
                                 "Window Manager".to_string()
                             }
+                            builtins::BuiltInFeature::RunningScripts => {
+                                "Running Scripts Manager".to_string()
+                            }
                             builtins::BuiltInFeature::DesignGallery => "Design Gallery".to_string(),
+                            builtins::BuiltInFeature::Diagnostics => {
+                                "Diagnostics Report".to_string()
+                            }
                             builtins::BuiltInFeature::AiChat => "AI Assistant".to_string(),
                             builtins::BuiltInFeature::Notes => "Notes & Scratchpad".to_string(),
                             builtins::BuiltInFeature::MenuBarAction(_) => {
@@ -973,6 +1078,70 @@ impl ScriptListApp {
                         );
                     }
 
+                    scripts::SearchResult::RecentFile(recent_file_match) => {
+                        let file = &recent_file_match.file;
+
+                        // File name header
+                        panel = panel.child(
+                            div()
+                                .text_lg()
+                                .font_weight(gpui::FontWeight::SEMIBOLD)
+                                .text_color(rgb(text_primary))
+                                .pb(px(spacing.padding_sm))
+                                .child(file.name.clone()),
+                        );
+
+                        // Path
+                        panel = panel.child(
+                            div()
+                                .flex()
+                                .flex_col()
+                                .pb(px(spacing.padding_md))
+                                .child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(rgb(text_muted))
+                                        .pb(px(spacing.padding_xs / 2.0))
+                                        .child("Path"),
+                                )
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(rgb(text_secondary))
+                                        .child(file.path.to_string_lossy().to_string()),
+                                ),
+                        );
+
+                        // Divider
+                        panel = panel.child(
+                            div()
+                                .w_full()
+                                .h(px(visual.border_thin))
+                                .bg(rgba((ui_border << 8) | 0x60))
+                                .my(px(spacing.padding_sm)),
+                        );
+
+                        // Type indicator
+                        panel = panel.child(
+                            div()
+                                .flex()
+                                .flex_col()
+                                .child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(rgb(text_muted))
+                                        .pb(px(spacing.padding_xs / 2.0))
+                                        .child("Type"),
+                                )
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(rgb(text_secondary))
+                                        .child("Recent File"),
+                                ),
+                        );
+                    }
+
                     scripts::SearchResult::Fallback(fallback_match) => {
                         // Fallback command preview
                         let fallback = &fallback_match.fallback;
@@ -1163,6 +1332,16 @@ impl ScriptListApp {
                             format!("agent:{}", m.agent.path.to_string_lossy()),
                         ))
                     }
+                    scripts::SearchResult::RecentFile(m) => {
+                        // Recent files use their path as identifier
+                        // is_script=false: opened files aren't editable scripts
+                        Some(ScriptInfo::with_action_verb(
+                            &m.file.name,
+                            m.file.path.to_string_lossy().to_string(),
+                            false,
+                            "Open",
+                        ))
+                    }
                     scripts::SearchResult::Fallback(m) => {
                         // Fallbacks use their name as identifier
                         // is_script depends on whether it's a built-in fallback or script-based
@@ -174,8 +174,13 @@ pub enum BuiltInFeature {
     App(String),
     /// Window switcher for managing and tiling windows
     WindowSwitcher,
+    /// Running scripts manager - lists and kills active background processes
+    RunningScripts,
     /// Design gallery for viewing separator and icon variations
     DesignGallery,
+    /// Diagnostics report (startup/search/window timing + cache sizes),
+    /// copied to the clipboard as markdown for bug reports
+    Diagnostics,
     /// AI Chat window for conversing with AI assistants
     AiChat,
     /// Notes window for quick notes and scratchpad
@@ -352,6 +357,24 @@ pub fn get_builtin_entries(config: &BuiltInConfig) -> Vec<BuiltInEntry> {
         debug!("Added Window Switcher built-in entry");
     }
 
+    // Running Scripts is always available
+    entries.push(BuiltInEntry::new_with_icon(
+        "builtin-running-scripts",
+        "Running Scripts",
+        "View and stop scripts running in the background",
+        vec![
+            "running",
+            "scripts",
+            "processes",
+            "background",
+            "kill",
+            "stop",
+        ],
+        BuiltInFeature::RunningScripts,
+        "⚙️",
+    ));
+    debug!("Added Running Scripts built-in entry");
+
     // AI Chat is always available
     entries.push(BuiltInEntry::new_with_icon(
         "builtin-ai-chat",
@@ -425,6 +448,29 @@ pub fn get_builtin_entries(config: &BuiltInConfig) -> Vec<BuiltInEntry> {
         debug!("Added Test Confirmation built-in entry");
     }
 
+    // Diagnostics report: startup/search/show/hide timing + cache sizes.
+    // Developer tool, so it's debug-only like Design Gallery above.
+    #[cfg(debug_assertions)]
+    {
+        entries.push(BuiltInEntry::new_with_icon(
+            "builtin-diagnostics",
+            "Copy Diagnostics Report",
+            "Copy a markdown report of startup/search/window timing and cache sizes",
+            vec![
+                "diagnostics",
+                "perf",
+                "performance",
+                "benchmark",
+                "report",
+                "metrics",
+                "debug",
+            ],
+            BuiltInFeature::Diagnostics,
+            "📊",
+        ));
+        debug!("Added Diagnostics built-in entry");
+    }
+
     // =========================================================================
     // System Actions
     // =========================================================================
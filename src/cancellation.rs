@@ -0,0 +1,93 @@
+//! Session-scoped cancellation tokens
+//!
+//! Each running script session hands out clones of one [`CancellationToken`]
+//! to its reader thread, its writer thread, and any UI-thread work dispatched
+//! on its behalf (e.g. answering a `GetState` request). When the session is
+//! torn down - the user cancels it, the app is shutting down, or the script
+//! exits - the token is cancelled so any of those clones can notice and drop
+//! work tied to the dead session instead of acting on it (writing a response
+//! to a closed pipe, applying a reply to UI state that's already moved on).
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A cheaply-clonable flag shared across a script session's threads.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a fresh, not-yet-cancelled token for a new session.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks the session as torn down. Cancelling an already-cancelled token
+    /// is a no-op.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether the session has been torn down.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fresh_token_is_not_cancelled() {
+        let token = CancellationToken::new();
+        assert!(!token.is_cancelled());
+    }
+
+    #[test]
+    fn test_cancel_marks_token_cancelled() {
+        let token = CancellationToken::new();
+        token.cancel();
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn test_cancel_is_idempotent() {
+        let token = CancellationToken::new();
+        token.cancel();
+        token.cancel();
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn test_clones_observe_cancellation_from_any_holder() {
+        // Mirrors how the reader/writer threads and the UI each hold their
+        // own clone of the same session's token.
+        let token = CancellationToken::new();
+        let writer_thread_token = token.clone();
+        let ui_token = token.clone();
+
+        assert!(!writer_thread_token.is_cancelled());
+        ui_token.cancel();
+        assert!(writer_thread_token.is_cancelled());
+    }
+
+    #[test]
+    fn test_kill_between_dispatch_and_response_drops_response() {
+        // A request (e.g. GetState) is dispatched to a handler, the script is
+        // then killed before the handler's response reaches the writer
+        // thread - the response must be dropped, not written to the now-dead
+        // pipe.
+        let token = CancellationToken::new();
+
+        let response: Option<&str> = Some("stale response for req-1");
+        token.cancel();
+
+        let written = response.filter(|_| !token.is_cancelled());
+        assert!(
+            written.is_none(),
+            "response for a cancelled session must be dropped, not sent"
+        );
+    }
+}
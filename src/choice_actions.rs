@@ -0,0 +1,85 @@
+//! Per-choice action shortcuts.
+//!
+//! A `Choice`'s own `actions` (see `protocol::types::Choice::actions`) can
+//! declare a `shortcut` so it fires directly while that choice is selected,
+//! without opening the ActionsDialog via Cmd+K first - mirroring how the arg
+//! prompt's own `action_shortcuts` map works for script-level actions.
+
+use crate::protocol::ProtocolAction;
+use crate::shortcuts;
+use std::collections::HashMap;
+
+/// Shortcuts already bound to something else while an arg prompt is
+/// focused, so a per-choice action can't claim them.
+const RESERVED_SHORTCUTS: &[&str] = &[
+    "up", "down", "left", "right", "enter", "escape", "backspace", "cmd+k", "cmd+w",
+];
+
+/// Build a `normalized shortcut -> action name` map from a choice's
+/// `actions`, for direct-fire dispatch that skips the ActionsDialog.
+///
+/// Actions without a `shortcut` are skipped. A shortcut that collides with
+/// [`RESERVED_SHORTCUTS`] is skipped entirely, and a shortcut declared by
+/// more than one action keeps only the first (matching the "first wins"
+/// convention used elsewhere for shortcut registries).
+pub fn build_choice_shortcut_map(actions: &[ProtocolAction]) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    for action in actions {
+        let Some(shortcut) = &action.shortcut else {
+            continue;
+        };
+        let normalized = shortcuts::normalize_shortcut(shortcut);
+        if RESERVED_SHORTCUTS.contains(&normalized.as_str()) {
+            continue;
+        }
+        map.entry(normalized).or_insert_with(|| action.name.clone());
+    }
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action_with_shortcut(name: &str, shortcut: &str) -> ProtocolAction {
+        let mut action = ProtocolAction::new(name.to_string());
+        action.shortcut = Some(shortcut.to_string());
+        action
+    }
+
+    #[test]
+    fn parses_and_normalizes_shortcuts() {
+        let actions = vec![action_with_shortcut("Archive", "Cmd+Shift+A")];
+        let map = build_choice_shortcut_map(&actions);
+        assert_eq!(map.get("cmd+shift+a"), Some(&"Archive".to_string()));
+    }
+
+    #[test]
+    fn skips_actions_without_a_shortcut() {
+        let actions = vec![ProtocolAction::new("No Shortcut".to_string())];
+        let map = build_choice_shortcut_map(&actions);
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn first_declared_shortcut_wins_on_collision() {
+        let actions = vec![
+            action_with_shortcut("First", "cmd+shift+a"),
+            action_with_shortcut("Second", "Cmd+Shift+A"),
+        ];
+        let map = build_choice_shortcut_map(&actions);
+        assert_eq!(map.get("cmd+shift+a"), Some(&"First".to_string()));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn excludes_reserved_shortcuts() {
+        let actions = vec![
+            action_with_shortcut("Open Dialog", "cmd+k"),
+            action_with_shortcut("Close", "cmd+w"),
+            action_with_shortcut("Move", "up"),
+        ];
+        let map = build_choice_shortcut_map(&actions);
+        assert!(map.is_empty());
+    }
+}
@@ -0,0 +1,244 @@
+//! Thumbnail loading for `Choice`/`ListItem` `img` fields
+//!
+//! A choice's `img` is either a file path or a `data:image/...` URI.
+//! Decoding happens off the render thread and results are cached in the
+//! same shared LRU the clipboard history module uses for decoded
+//! `RenderImage`s, so thumbnails don't grow memory usage independently of
+//! that existing budget.
+
+use std::fs;
+use std::sync::Arc;
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use gpui::RenderImage;
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+use crate::clipboard_history::{cache_image, get_cached_image};
+
+/// Thumbnails are downscaled to fit the 52px list row height.
+pub const THUMBNAIL_SIZE: u32 = 52;
+
+/// Images larger than this (after any base64 decoding) are rejected rather
+/// than decoded, so a malformed/huge source can't stall the loader thread.
+const MAX_IMAGE_BYTES: usize = 10 * 1024 * 1024;
+
+/// Build the shared-cache key for a choice image source.
+///
+/// File paths are used directly - they're already a stable, unique
+/// identifier. Data URIs are hashed since using the full payload as a cache
+/// key would be wasteful.
+fn cache_key(img: &str) -> String {
+    if let Some(data_uri) = img.strip_prefix("data:") {
+        let mut hasher = Sha256::new();
+        hasher.update(data_uri.as_bytes());
+        format!("choice-img:{}", hex::encode(hasher.finalize()))
+    } else {
+        format!("choice-img:{}", img)
+    }
+}
+
+/// Look up an already-decoded thumbnail without touching disk or decoding.
+pub fn get_cached_choice_image(img: &str) -> Option<Arc<RenderImage>> {
+    get_cached_image(&cache_key(img))
+}
+
+/// Decode and downscale a choice image, caching the result.
+///
+/// Does file IO and image decoding - call this from a background thread,
+/// not during render. Returns `None` (after logging why) for an unreadable
+/// path, oversized source, or corrupt image data.
+pub fn decode_choice_image(img: &str) -> Option<Arc<RenderImage>> {
+    let key = cache_key(img);
+    if let Some(cached) = get_cached_image(&key) {
+        return Some(cached);
+    }
+
+    let bytes = read_image_bytes(img)?;
+    if bytes.len() > MAX_IMAGE_BYTES {
+        warn!(
+            img = %img,
+            bytes = bytes.len(),
+            max_bytes = MAX_IMAGE_BYTES,
+            "Skipping oversized choice image"
+        );
+        return None;
+    }
+
+    let render_image = match decode_and_downscale(&bytes) {
+        Ok(image) => image,
+        Err(e) => {
+            warn!(img = %img, error = %e, "Failed to decode choice image");
+            return None;
+        }
+    };
+
+    cache_image(&key, render_image.clone());
+    Some(render_image)
+}
+
+/// Read the raw image bytes from a choice's `img` field, decoding base64 for
+/// a `data:` URI or reading the file for a plain path.
+fn read_image_bytes(img: &str) -> Option<Vec<u8>> {
+    if let Some(data_uri) = img.strip_prefix("data:") {
+        let (mime_and_encoding, payload) = data_uri.split_once(',')?;
+        // `;base64` is the only encoding we support; anything else (e.g.
+        // a URL-encoded text payload) isn't a binary image we can decode.
+        if !mime_and_encoding.contains("base64") {
+            warn!(img = %img, "Unsupported data URI encoding (expected base64)");
+            return None;
+        }
+        match BASE64.decode(payload) {
+            Ok(bytes) => Some(bytes),
+            Err(e) => {
+                warn!(img = %img, error = %e, "Failed to base64-decode data URI");
+                None
+            }
+        }
+    } else {
+        let expanded = shellexpand::tilde(img).to_string();
+        match fs::read(&expanded) {
+            Ok(bytes) => Some(bytes),
+            Err(e) => {
+                warn!(img = %img, error = %e, "Failed to read choice image file");
+                None
+            }
+        }
+    }
+}
+
+/// Decode arbitrary image bytes, downscale to `THUMBNAIL_SIZE`, and convert
+/// to a GPUI `RenderImage` (RGBA -> BGRA, matching what GPUI expects for
+/// Metal rendering - see `list_item::decode_png_to_render_image_internal`).
+fn decode_and_downscale(bytes: &[u8]) -> Result<Arc<RenderImage>, image::ImageError> {
+    use image::GenericImageView;
+    use smallvec::SmallVec;
+
+    let decoded = image::load_from_memory(bytes)?;
+    let thumbnail = decoded.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE);
+    let mut rgba = thumbnail.to_rgba8();
+    let (width, height) = thumbnail.dimensions();
+
+    for pixel in rgba.chunks_exact_mut(4) {
+        pixel.swap(0, 2); // RGBA -> BGRA
+    }
+
+    let buffer = image::RgbaImage::from_raw(width, height, rgba.into_raw())
+        .expect("thumbnail buffer length must match its own dimensions");
+    let frame = image::Frame::new(buffer);
+    Ok(Arc::new(RenderImage::new(SmallVec::from_elem(frame, 1))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// Encode a tiny solid-color PNG, for use as test fixture data.
+    fn make_test_png(width: u32, height: u32) -> Vec<u8> {
+        let img = image::RgbaImage::from_pixel(width, height, image::Rgba([255, 0, 0, 255]));
+        let mut bytes = Vec::new();
+        img.write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .expect("failed to encode test PNG");
+        bytes
+    }
+
+    fn make_test_data_uri(width: u32, height: u32) -> String {
+        let png = make_test_png(width, height);
+        format!("data:image/png;base64,{}", BASE64.encode(png))
+    }
+
+    #[test]
+    fn test_decode_data_uri_png() {
+        let uri = make_test_data_uri(8, 8);
+        // We can't easily inspect RenderImage pixels/dimensions (see the
+        // equivalent caveat in app_launcher's icon-decode test), but we can
+        // verify decoding a valid data URI succeeds and produces a usable
+        // Arc<RenderImage>.
+        let image = decode_choice_image(&uri).expect("valid PNG data URI should decode");
+        assert!(Arc::strong_count(&image) >= 1);
+    }
+
+    #[test]
+    fn test_decode_downscales_large_image_without_error() {
+        let uri = make_test_data_uri(400, 400);
+        assert!(decode_choice_image(&uri).is_some());
+    }
+
+    #[test]
+    fn test_decode_rejects_corrupt_data() {
+        let uri = "data:image/png;base64,dGhpcyBpcyBub3QgYSBwbmc=";
+        assert!(decode_choice_image(uri).is_none());
+    }
+
+    #[test]
+    fn test_decode_rejects_unknown_path() {
+        assert!(decode_choice_image("/nonexistent/path/to/image.png").is_none());
+    }
+
+    #[test]
+    fn test_decode_rejects_oversized_data_uri() {
+        // Larger than MAX_IMAGE_BYTES once base64-decoded, but not actually
+        // a valid image - exercises the size check before decode is attempted.
+        let huge = vec![0u8; MAX_IMAGE_BYTES + 1];
+        let uri = format!(
+            "data:application/octet-stream;base64,{}",
+            BASE64.encode(huge)
+        );
+        assert!(decode_choice_image(&uri).is_none());
+    }
+
+    #[test]
+    fn test_cache_key_reuses_path_directly() {
+        assert_eq!(cache_key("/a/b/c.png"), "choice-img:/a/b/c.png");
+    }
+
+    #[test]
+    fn test_cache_key_hashes_data_uri() {
+        let key = cache_key("data:image/png;base64,AAAA");
+        assert!(key.starts_with("choice-img:"));
+        assert_ne!(key, "choice-img:data:image/png;base64,AAAA");
+    }
+
+    #[test]
+    fn test_decoded_image_is_served_from_cache_on_second_call() {
+        let uri = make_test_data_uri(4, 4);
+        let first = decode_choice_image(&uri).expect("first decode should succeed");
+        let second =
+            get_cached_choice_image(&uri).expect("second lookup should hit the shared cache");
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    /// The shared clipboard-history image cache evicts its oldest entry once
+    /// full, so a flood of distinct choice thumbnails can't grow memory
+    /// without bound. Each call below decodes a distinctly-colored pixel so
+    /// every cache key is unique.
+    #[test]
+    fn test_cache_evicts_oldest_entry_past_capacity() {
+        use crate::clipboard_history::MAX_IMAGE_CACHE_ENTRIES;
+
+        let make_uri = |shade: u8| -> String {
+            let img = image::RgbaImage::from_pixel(1, 1, image::Rgba([shade, shade, shade, 255]));
+            let mut bytes = Vec::new();
+            img.write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+                .unwrap();
+            format!("data:image/png;base64,{}", BASE64.encode(bytes))
+        };
+
+        let first_uri = make_uri(1);
+        decode_choice_image(&first_uri).expect("first image should decode");
+        assert!(get_cached_choice_image(&first_uri).is_some());
+
+        // Fill past capacity with distinct images so the first one is evicted.
+        let fill_count = MAX_IMAGE_CACHE_ENTRIES as u8 + 1;
+        for shade in 2..=fill_count {
+            let uri = make_uri(shade);
+            decode_choice_image(&uri).expect("image should decode");
+        }
+
+        assert!(
+            get_cached_choice_image(&first_uri).is_none(),
+            "oldest entry should have been evicted once the shared cache filled up"
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! Populate an arg prompt from a shell command's stdout (`choices_cmd`)
+//!
+//! A very common pattern is piping the output of a command into a picker
+//! (git branches, kubectx, tmux sessions). This module runs that command
+//! through the user's shell and turns its stdout into one `Choice` per line,
+//! so the caller just needs to hand the result to `set_arg_choices`.
+
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::protocol::types::Choice;
+use crate::terminal::pty::resolve_shell;
+
+/// Lines beyond this cap are dropped and summarized in a trailing
+/// informational choice, so a runaway command can't flood the list.
+pub const MAX_CHOICES_CMD_LINES: usize = 500;
+
+/// How long a `choices_cmd` is allowed to run before it's killed.
+pub const DEFAULT_CHOICES_CMD_TIMEOUT_MS: u64 = 10_000;
+
+/// Result of running a `choices_cmd`: parsed choices, or an error message
+/// (including stderr when available) suitable for an error toast.
+pub type ChoicesCmdResult = Result<Vec<Choice>, String>;
+
+/// Split a command's stdout into choice lines.
+///
+/// `\r\n` line endings are normalized to `\n` first so CRLF output doesn't
+/// leave a stray `\r` in each name. A trailing newline produces no empty
+/// final entry, and blank lines are skipped entirely rather than becoming
+/// empty choices.
+pub fn split_choices_output(stdout: &str) -> Vec<String> {
+    stdout
+        .replace("\r\n", "\n")
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Build the `Choice` list for a `choices_cmd` run, capping the count and
+/// appending a "... N more" informational row when output was truncated.
+pub fn choices_from_output(stdout: &str) -> Vec<Choice> {
+    let lines = split_choices_output(stdout);
+    let total = lines.len();
+    let mut choices: Vec<Choice> = lines
+        .into_iter()
+        .take(MAX_CHOICES_CMD_LINES)
+        .map(|line| Choice::new(line.clone(), line))
+        .collect();
+
+    if total > MAX_CHOICES_CMD_LINES {
+        let more = total - MAX_CHOICES_CMD_LINES;
+        choices.push(Choice::with_description(
+            format!("... {} more", more),
+            String::new(),
+            "Output truncated - narrow your command to see the rest".to_string(),
+        ));
+    }
+
+    choices
+}
+
+#[cfg(unix)]
+fn kill_pid(pid: u32) {
+    // Safety: kill() is a simple syscall with no memory safety concerns.
+    unsafe {
+        libc::kill(pid as libc::pid_t, libc::SIGKILL);
+    }
+}
+
+#[cfg(not(unix))]
+fn kill_pid(pid: u32) {
+    let _ = Command::new("taskkill")
+        .args(["/F", "/PID", &pid.to_string()])
+        .status();
+}
+
+#[cfg(unix)]
+fn was_killed(status: &std::process::ExitStatus) -> bool {
+    use std::os::unix::process::ExitStatusExt;
+    status.signal().is_some()
+}
+
+#[cfg(not(unix))]
+fn was_killed(_status: &std::process::ExitStatus) -> bool {
+    false
+}
+
+/// Run `command` through the user's shell, capturing stdout into `Choice`s.
+///
+/// Blocking - call from a background thread, not the render/main thread. A
+/// watcher thread kills the child once `timeout` elapses so a runaway
+/// command can't hang the caller forever; the main thread still does the
+/// (deadlock-safe) `wait_with_output` read of both pipes.
+pub fn run_choices_cmd(command: &str, timeout: Duration) -> ChoicesCmdResult {
+    let shell = resolve_shell(None);
+    let child = Command::new(&shell)
+        .arg("-c")
+        .arg(command)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to start choices command: {}", e))?;
+
+    let pid = child.id();
+    let done = Arc::new(AtomicBool::new(false));
+    let done_for_watcher = done.clone();
+    let start = Instant::now();
+    let watcher = std::thread::spawn(move || {
+        while start.elapsed() < timeout {
+            if done_for_watcher.load(Ordering::Relaxed) {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(25));
+        }
+        if !done_for_watcher.load(Ordering::Relaxed) {
+            kill_pid(pid);
+        }
+    });
+
+    let output = child.wait_with_output();
+    done.store(true, Ordering::Relaxed);
+    let _ = watcher.join();
+
+    let output = output.map_err(|e| format!("Failed to wait on choices command: {}", e))?;
+
+    if !output.status.success() {
+        if was_killed(&output.status) {
+            return Err(format!(
+                "Choices command timed out after {}ms: {}",
+                timeout.as_millis(),
+                command
+            ));
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let detail = if stderr.trim().is_empty() {
+            format!("exit code {:?}", output.status.code())
+        } else {
+            stderr.trim().to_string()
+        };
+        return Err(format!("Choices command failed: {}", detail));
+    }
+
+    Ok(choices_from_output(&String::from_utf8_lossy(&output.stdout)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_trailing_newline_without_empty_entry() {
+        assert_eq!(split_choices_output("a\nb\nc\n"), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn normalizes_crlf_line_endings() {
+        assert_eq!(split_choices_output("a\r\nb\r\n"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn skips_empty_lines() {
+        assert_eq!(split_choices_output("a\n\nb\n\n\nc"), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn empty_output_yields_no_choices() {
+        assert!(split_choices_output("").is_empty());
+    }
+
+    #[test]
+    fn truncates_and_appends_more_row() {
+        let lines: Vec<String> = (0..MAX_CHOICES_CMD_LINES + 5)
+            .map(|i| i.to_string())
+            .collect();
+        let stdout = lines.join("\n");
+        let choices = choices_from_output(&stdout);
+        assert_eq!(choices.len(), MAX_CHOICES_CMD_LINES + 1);
+        assert!(choices.last().unwrap().name.contains("5 more"));
+    }
+
+    #[test]
+    fn times_out_a_runaway_command() {
+        let result = run_choices_cmd("sleep 5", Duration::from_millis(100));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("timed out"));
+    }
+
+    #[test]
+    fn surfaces_stderr_on_failure() {
+        let result = run_choices_cmd("echo boom >&2; exit 1", Duration::from_secs(5));
+        let err = result.unwrap_err();
+        assert!(err.contains("boom"));
+    }
+}
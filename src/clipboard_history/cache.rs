@@ -54,6 +54,14 @@ pub fn get_cached_image(id: &str) -> Option<Arc<RenderImage>> {
     get_image_cache().lock().ok()?.get(id).cloned()
 }
 
+/// Current number of entries in the decoded-image cache.
+pub fn image_cache_len() -> usize {
+    get_image_cache()
+        .lock()
+        .map(|cache| cache.len())
+        .unwrap_or(0)
+}
+
 /// Cache a decoded image (with LRU eviction at MAX_IMAGE_CACHE_ENTRIES limit)
 pub fn cache_image(id: &str, image: Arc<RenderImage>) {
     if let Ok(mut cache) = get_image_cache().lock() {
@@ -228,6 +236,7 @@ mod tests {
             image_height: None,
             byte_size: 10,
             ocr_text: None,
+            copy_count: 1,
         }
     }
 
@@ -5,11 +5,12 @@
 use anyhow::{Context, Result};
 use arboard::Clipboard;
 use rusqlite::params;
+use std::path::{Path, PathBuf};
 use tracing::info;
 
 use super::cache::refresh_entry_cache;
 use super::database::get_connection;
-use super::image::decode_base64_image;
+use super::image::{decode_base64_image, encode_image_to_png_bytes};
 use super::types::ContentType;
 
 /// Copy an entry back to the clipboard
@@ -73,3 +74,99 @@ pub fn copy_entry_to_clipboard(id: &str) -> Result<()> {
 
     Ok(())
 }
+
+/// Save a clipboard image entry to `~/Downloads` as a PNG file.
+///
+/// The filename is derived from the entry's timestamp
+/// (`clipboard-<unix_ms>.png`); if a file with that name already exists (two
+/// saves in quick succession, or saving the same entry twice), a numeric
+/// suffix is appended until a free name is found rather than clobbering it.
+///
+/// # Errors
+/// Returns an error if the entry doesn't exist, isn't an image, the stored
+/// image data is corrupt, or the Downloads directory can't be written to.
+pub fn save_entry_image_to_downloads(id: &str) -> Result<PathBuf> {
+    let conn = get_connection()?;
+    let conn = conn
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Lock error: {}", e))?;
+
+    let (content, content_type, timestamp): (String, String, i64) = conn
+        .query_row(
+            "SELECT content, content_type, timestamp FROM history WHERE id = ?",
+            params![id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .context("Entry not found")?;
+
+    drop(conn); // Release lock before image decode/file IO
+
+    if ContentType::from_str(&content_type) != ContentType::Image {
+        anyhow::bail!("Entry is not an image");
+    }
+
+    let image_data = decode_base64_image(&content).context("Failed to decode image data")?;
+    let png_bytes = encode_image_to_png_bytes(&image_data)?;
+
+    let downloads_dir = dirs::download_dir().context("Could not determine Downloads directory")?;
+    std::fs::create_dir_all(&downloads_dir)
+        .with_context(|| format!("Failed to create {}", downloads_dir.display()))?;
+    let path = unique_download_path(&downloads_dir, &format!("clipboard-{}", timestamp), "png");
+    std::fs::write(&path, &png_bytes)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    info!(id = %id, path = %path.display(), "Saved clipboard image to Downloads");
+
+    Ok(path)
+}
+
+/// Find a path in `dir` named `<stem>.<ext>` that doesn't already exist,
+/// appending ` (2)`, ` (3)`, ... to the stem until a free name is found.
+fn unique_download_path(dir: &Path, stem: &str, ext: &str) -> PathBuf {
+    let candidate = dir.join(format!("{stem}.{ext}"));
+    if !candidate.exists() {
+        return candidate;
+    }
+    let mut n = 2;
+    loop {
+        let candidate = dir.join(format!("{stem} ({n}).{ext}"));
+        if !candidate.exists() {
+            return candidate;
+        }
+        n += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unique_download_path_uses_plain_name_when_free() {
+        let dir = std::env::temp_dir().join(format!("skit-clipboard-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = unique_download_path(&dir, "clipboard-123", "png");
+        assert_eq!(path, dir.join("clipboard-123.png"));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_unique_download_path_appends_suffix_on_collision() {
+        let dir = std::env::temp_dir().join(format!(
+            "skit-clipboard-test-collision-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("clipboard-123.png"), b"existing").unwrap();
+
+        let path = unique_download_path(&dir, "clipboard-123", "png");
+        assert_eq!(path, dir.join("clipboard-123 (2).png"));
+
+        // Fill that one too - the next call should skip past it as well.
+        std::fs::write(&path, b"existing").unwrap();
+        let path2 = unique_download_path(&dir, "clipboard-123", "png");
+        assert_eq!(path2, dir.join("clipboard-123 (3).png"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,6 +1,6 @@
 //! Clipboard history configuration
 //!
-//! Retention settings and text length limits.
+//! Retention settings, text length limits, and dedupe behavior.
 
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::OnceLock;
@@ -11,12 +11,40 @@ pub const DEFAULT_RETENTION_DAYS: u32 = 30;
 /// Default maximum number of bytes allowed for text clipboard entries.
 pub const DEFAULT_MAX_TEXT_CONTENT_LEN: usize = 100_000;
 
+/// How aggressively identical clipboard content is merged instead of duplicated.
+///
+/// Corresponds to the `clipboard_history.dedupe` config setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClipboardDedupeMode {
+    /// Only merge with the single most-recently-added entry.
+    #[default]
+    Adjacent,
+    /// Merge with any existing entry that has the same content, regardless of recency
+    /// (promotes the older entry to the top instead of inserting a duplicate).
+    Global,
+    /// Never merge; every copy inserts a new entry.
+    Off,
+}
+
+impl ClipboardDedupeMode {
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "global" => ClipboardDedupeMode::Global,
+            "off" => ClipboardDedupeMode::Off,
+            _ => ClipboardDedupeMode::Adjacent,
+        }
+    }
+}
+
 /// Configured retention days (loaded from config, defaults to DEFAULT_RETENTION_DAYS)
 static RETENTION_DAYS: OnceLock<u32> = OnceLock::new();
 
 /// Configured maximum text entry length (bytes). usize::MAX means no limit.
 static MAX_TEXT_CONTENT_LEN: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_TEXT_CONTENT_LEN);
 
+/// Configured dedupe mode (loaded from config, defaults to ClipboardDedupeMode::Adjacent)
+static DEDUPE_MODE: OnceLock<ClipboardDedupeMode> = OnceLock::new();
+
 /// Get the configured retention period in days
 pub fn get_retention_days() -> u32 {
     *RETENTION_DAYS.get().unwrap_or(&DEFAULT_RETENTION_DAYS)
@@ -44,6 +72,19 @@ pub fn is_text_over_limit(text: &str) -> bool {
     text.len() > get_max_text_content_len()
 }
 
+/// Get the configured dedupe mode
+pub fn get_dedupe_mode() -> ClipboardDedupeMode {
+    *DEDUPE_MODE.get().unwrap_or(&ClipboardDedupeMode::Adjacent)
+}
+
+/// Set the dedupe mode. Call during config load, before
+/// `init_clipboard_history` - like `RETENTION_DAYS`, this is a `OnceLock` so
+/// only the first call (startup) takes effect; a later config hot-reload
+/// call is a no-op rather than a panic.
+pub fn set_dedupe_mode(mode: ClipboardDedupeMode) {
+    let _ = DEDUPE_MODE.set(mode);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,4 +102,32 @@ mod tests {
         let long_text = "a".repeat(DEFAULT_MAX_TEXT_CONTENT_LEN + 1);
         assert!(is_text_over_limit(&long_text));
     }
+
+    #[test]
+    fn test_dedupe_mode_default() {
+        assert_eq!(
+            ClipboardDedupeMode::default(),
+            ClipboardDedupeMode::Adjacent
+        );
+    }
+
+    #[test]
+    fn test_dedupe_mode_from_str() {
+        assert_eq!(
+            ClipboardDedupeMode::from_str("adjacent"),
+            ClipboardDedupeMode::Adjacent
+        );
+        assert_eq!(
+            ClipboardDedupeMode::from_str("global"),
+            ClipboardDedupeMode::Global
+        );
+        assert_eq!(
+            ClipboardDedupeMode::from_str("off"),
+            ClipboardDedupeMode::Off
+        );
+        assert_eq!(
+            ClipboardDedupeMode::from_str("nonsense"),
+            ClipboardDedupeMode::Adjacent
+        );
+    }
 }
@@ -15,7 +15,10 @@ use super::cache::{
     clear_all_caches, evict_image_cache, refresh_entry_cache, remove_entry_from_cache,
     update_pin_status_in_cache, upsert_entry_in_cache,
 };
-use super::config::{get_max_text_content_len, get_retention_days, is_text_over_limit};
+use super::config::{
+    get_dedupe_mode, get_max_text_content_len, get_retention_days, is_text_over_limit,
+    ClipboardDedupeMode,
+};
 use super::image::get_image_dimensions;
 use super::types::{ClipboardEntry, ClipboardEntryMeta, ContentType};
 
@@ -76,7 +79,8 @@ pub fn get_connection() -> Result<Arc<Mutex<Connection>>> {
             content_type TEXT NOT NULL DEFAULT 'text',
             timestamp INTEGER NOT NULL,
             pinned INTEGER DEFAULT 0,
-            ocr_text TEXT
+            ocr_text TEXT,
+            copy_count INTEGER NOT NULL DEFAULT 1
         )",
         [],
     )
@@ -114,6 +118,25 @@ pub fn get_connection() -> Result<Arc<Mutex<Connection>>> {
         info!("Migrated clipboard history: added content_hash column");
     }
 
+    // Migration: Add copy_count column if it doesn't exist
+    let has_copy_count_column: bool = conn
+        .query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('history') WHERE name='copy_count'",
+            [],
+            |row| row.get::<_, i32>(0),
+        )
+        .map(|count| count > 0)
+        .unwrap_or(false);
+
+    if !has_copy_count_column {
+        conn.execute(
+            "ALTER TABLE history ADD COLUMN copy_count INTEGER NOT NULL DEFAULT 1",
+            [],
+        )
+        .context("Failed to add copy_count column")?;
+        info!("Migrated clipboard history: added copy_count column");
+    }
+
     // Migration: Convert seconds timestamps to milliseconds
     // Timestamps < 100_000_000_000 (year ~5138 in seconds, year ~1973 in ms) are seconds
     // We multiply by 1000 to convert to milliseconds
@@ -278,27 +301,57 @@ pub fn add_entry(content: &str, content_type: ContentType) -> Result<String> {
     let timestamp = chrono::Utc::now().timestamp_millis();
     let content_hash = compute_content_hash(content);
 
-    // Check if entry with same hash exists (O(1) dedup via index)
-    // Also fetch pinned status to preserve it in cache update
-    let existing: Option<(String, bool)> = conn
-        .query_row(
-            "SELECT id, pinned FROM history WHERE content_type = ? AND content_hash = ?",
-            params![content_type.as_str(), &content_hash],
-            |row| Ok((row.get(0)?, row.get::<_, i64>(1)? != 0)),
-        )
-        .ok();
+    // Look up an existing entry to merge into, per the configured dedupe mode.
+    // Also fetch pinned status and copy_count to preserve/bump them in the cache update.
+    let existing: Option<(String, bool, u32)> = match dedupe_lookup_for_mode(get_dedupe_mode()) {
+        DedupeLookup::None => None,
+        DedupeLookup::AnyMatch => conn
+            .query_row(
+                "SELECT id, pinned, copy_count FROM history WHERE content_type = ? AND content_hash = ?",
+                params![content_type.as_str(), &content_hash],
+                |row| {
+                    Ok((
+                        row.get(0)?,
+                        row.get::<_, i64>(1)? != 0,
+                        row.get::<_, i64>(2)? as u32,
+                    ))
+                },
+            )
+            .ok(),
+        DedupeLookup::AdjacentOnly => conn
+            .query_row(
+                "SELECT id, pinned, copy_count, content_type, content_hash FROM history
+                 ORDER BY timestamp DESC LIMIT 1",
+                [],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, i64>(1)? != 0,
+                        row.get::<_, i64>(2)? as u32,
+                        row.get::<_, String>(3)?,
+                        row.get::<_, Option<String>>(4)?,
+                    ))
+                },
+            )
+            .ok()
+            .filter(|(_, _, _, newest_type, newest_hash)| {
+                newest_type == content_type.as_str() && newest_hash.as_deref() == Some(content_hash.as_str())
+            })
+            .map(|(id, pinned, copy_count, _, _)| (id, pinned, copy_count)),
+    };
 
     // Extract metadata for efficient list queries (done before lock for update case)
     let (text_preview, image_width, image_height, byte_size) =
         extract_metadata(content, content_type.clone());
 
-    if let Some((existing_id, existing_pinned)) = existing {
+    if let Some((existing_id, existing_pinned, existing_copy_count)) = existing {
+        let copy_count = existing_copy_count.saturating_add(1);
         conn.execute(
-            "UPDATE history SET timestamp = ? WHERE id = ?",
-            params![timestamp, &existing_id],
+            "UPDATE history SET timestamp = ?, copy_count = ? WHERE id = ?",
+            params![timestamp, copy_count, &existing_id],
         )
         .context("Failed to update existing entry timestamp")?;
-        debug!(id = %existing_id, "Updated existing clipboard entry timestamp");
+        debug!(id = %existing_id, copy_count, "Updated existing clipboard entry timestamp");
         drop(conn);
 
         // Incremental cache update instead of full refresh
@@ -313,6 +366,7 @@ pub fn add_entry(content: &str, content_type: ContentType) -> Result<String> {
             image_height,
             byte_size,
             ocr_text: None,
+            copy_count,
         });
 
         return Ok(existing_id);
@@ -320,8 +374,8 @@ pub fn add_entry(content: &str, content_type: ContentType) -> Result<String> {
 
     let id = Uuid::new_v4().to_string();
     conn.execute(
-        "INSERT INTO history (id, content, content_hash, content_type, timestamp, pinned, ocr_text, text_preview, image_width, image_height, byte_size)
-         VALUES (?1, ?2, ?3, ?4, ?5, 0, NULL, ?6, ?7, ?8, ?9)",
+        "INSERT INTO history (id, content, content_hash, content_type, timestamp, pinned, ocr_text, text_preview, image_width, image_height, byte_size, copy_count)
+         VALUES (?1, ?2, ?3, ?4, ?5, 0, NULL, ?6, ?7, ?8, ?9, 1)",
         params![&id, content, &content_hash, content_type.as_str(), timestamp, text_preview, image_width, image_height, byte_size as i64],
     )
     .context("Failed to insert clipboard entry")?;
@@ -341,11 +395,31 @@ pub fn add_entry(content: &str, content_type: ContentType) -> Result<String> {
         image_height,
         byte_size,
         ocr_text: None,
+        copy_count: 1,
     });
 
     Ok(id)
 }
 
+/// Which existing-entry lookup (if any) `add_entry` should perform for a given dedupe mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DedupeLookup {
+    /// Only merge with the single most-recently-added entry.
+    AdjacentOnly,
+    /// Merge with any entry that has a matching hash, regardless of recency.
+    AnyMatch,
+    /// Never merge; always insert a new entry.
+    None,
+}
+
+fn dedupe_lookup_for_mode(mode: ClipboardDedupeMode) -> DedupeLookup {
+    match mode {
+        ClipboardDedupeMode::Adjacent => DedupeLookup::AdjacentOnly,
+        ClipboardDedupeMode::Global => DedupeLookup::AnyMatch,
+        ClipboardDedupeMode::Off => DedupeLookup::None,
+    }
+}
+
 /// Prune entries older than retention period (except pinned entries)
 ///
 /// Returns the number of entries deleted.
@@ -436,9 +510,9 @@ pub fn get_clipboard_history_page(limit: usize, offset: usize) -> Vec<ClipboardE
     };
 
     let mut stmt = match conn.prepare(
-        "SELECT id, content, content_type, timestamp, pinned, ocr_text 
-         FROM history 
-         ORDER BY pinned DESC, timestamp DESC 
+        "SELECT id, content, content_type, timestamp, pinned, ocr_text, copy_count
+         FROM history
+         ORDER BY pinned DESC, timestamp DESC
          LIMIT ? OFFSET ?",
     ) {
         Ok(s) => s,
@@ -457,6 +531,7 @@ pub fn get_clipboard_history_page(limit: usize, offset: usize) -> Vec<ClipboardE
                 timestamp: row.get(3)?,
                 pinned: row.get::<_, i64>(4)? != 0,
                 ocr_text: row.get(5)?,
+                copy_count: row.get::<_, i64>(6)? as u32,
             })
         })
         .map(|rows| rows.filter_map(|r| r.ok()).collect())
@@ -529,7 +604,7 @@ pub fn get_clipboard_history_meta(limit: usize, offset: usize) -> Vec<ClipboardE
 
     // Query only metadata columns - NO content column
     let mut stmt = match conn.prepare(
-        "SELECT id, content_type, timestamp, pinned, text_preview, image_width, image_height, byte_size, ocr_text
+        "SELECT id, content_type, timestamp, pinned, text_preview, image_width, image_height, byte_size, ocr_text, copy_count
          FROM history
          ORDER BY pinned DESC, timestamp DESC
          LIMIT ? OFFSET ?",
@@ -553,6 +628,7 @@ pub fn get_clipboard_history_meta(limit: usize, offset: usize) -> Vec<ClipboardE
                 image_height: row.get::<_, Option<i64>>(6)?.map(|v| v as u32),
                 byte_size: row.get::<_, Option<i64>>(7)?.unwrap_or(0) as usize,
                 ocr_text: row.get(8)?,
+                copy_count: row.get::<_, i64>(9)? as u32,
             })
         })
         .map(|rows| rows.filter_map(|r| r.ok()).collect())
@@ -754,7 +830,7 @@ pub fn get_entry_by_id(id: &str) -> Option<ClipboardEntry> {
     let conn = conn.lock().ok()?;
 
     conn.query_row(
-        "SELECT id, content, content_type, timestamp, pinned, ocr_text FROM history WHERE id = ?",
+        "SELECT id, content, content_type, timestamp, pinned, ocr_text, copy_count FROM history WHERE id = ?",
         params![id],
         |row| {
             Ok(ClipboardEntry {
@@ -764,6 +840,7 @@ pub fn get_entry_by_id(id: &str) -> Option<ClipboardEntry> {
                 timestamp: row.get(3)?,
                 pinned: row.get::<_, i64>(4)? != 0,
                 ocr_text: row.get(5)?,
+                copy_count: row.get::<_, i64>(6)? as u32,
             })
         },
     )
@@ -901,6 +978,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dedupe_lookup_for_mode() {
+        assert_eq!(
+            dedupe_lookup_for_mode(ClipboardDedupeMode::Adjacent),
+            DedupeLookup::AdjacentOnly
+        );
+        assert_eq!(
+            dedupe_lookup_for_mode(ClipboardDedupeMode::Global),
+            DedupeLookup::AnyMatch
+        );
+        assert_eq!(
+            dedupe_lookup_for_mode(ClipboardDedupeMode::Off),
+            DedupeLookup::None
+        );
+    }
+
     #[test]
     fn test_busy_timeout_is_set() {
         // Verify that our connection setup includes busy_timeout
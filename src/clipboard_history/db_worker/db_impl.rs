@@ -20,28 +20,29 @@ pub fn add_or_touch_impl(
     let timestamp = chrono::Utc::now().timestamp_millis();
 
     // Check if entry with same hash exists (O(1) dedup via index)
-    let existing: Option<String> = conn
+    let existing: Option<(String, u32)> = conn
         .query_row(
-            "SELECT id FROM history WHERE content_type = ? AND content_hash = ?",
+            "SELECT id, copy_count FROM history WHERE content_type = ? AND content_hash = ?",
             params![content_type.as_str(), content_hash],
-            |row| row.get(0),
+            |row| Ok((row.get(0)?, row.get::<_, i64>(1)? as u32)),
         )
         .ok();
 
-    if let Some(existing_id) = existing {
+    if let Some((existing_id, existing_copy_count)) = existing {
+        let copy_count = existing_copy_count.saturating_add(1);
         conn.execute(
-            "UPDATE history SET timestamp = ? WHERE id = ?",
-            params![timestamp, &existing_id],
+            "UPDATE history SET timestamp = ?, copy_count = ? WHERE id = ?",
+            params![timestamp, copy_count, &existing_id],
         )
         .context("Failed to update existing entry timestamp")?;
-        debug!(id = %existing_id, "Updated existing clipboard entry timestamp");
+        debug!(id = %existing_id, copy_count, "Updated existing clipboard entry timestamp");
         return Ok(existing_id);
     }
 
     let id = uuid::Uuid::new_v4().to_string();
     conn.execute(
-        "INSERT INTO history (id, content, content_hash, content_type, timestamp, pinned, ocr_text, text_preview, image_width, image_height, byte_size)
-         VALUES (?1, ?2, ?3, ?4, ?5, 0, NULL, ?6, ?7, ?8, ?9)",
+        "INSERT INTO history (id, content, content_hash, content_type, timestamp, pinned, ocr_text, text_preview, image_width, image_height, byte_size, copy_count)
+         VALUES (?1, ?2, ?3, ?4, ?5, 0, NULL, ?6, ?7, ?8, ?9, 1)",
         params![&id, content, content_hash, content_type.as_str(), timestamp, text_preview, image_width, image_height, byte_size as i64],
     )
     .context("Failed to insert clipboard entry")?;
@@ -61,7 +62,7 @@ pub fn get_content_impl(conn: &Connection, id: &str) -> Option<String> {
 
 pub fn get_entry_impl(conn: &Connection, id: &str) -> Option<ClipboardEntry> {
     conn.query_row(
-        "SELECT id, content, content_type, timestamp, pinned, ocr_text FROM history WHERE id = ?",
+        "SELECT id, content, content_type, timestamp, pinned, ocr_text, copy_count FROM history WHERE id = ?",
         params![id],
         |row| {
             Ok(ClipboardEntry {
@@ -71,6 +72,7 @@ pub fn get_entry_impl(conn: &Connection, id: &str) -> Option<ClipboardEntry> {
                 timestamp: row.get(3)?,
                 pinned: row.get::<_, i64>(4)? != 0,
                 ocr_text: row.get(5)?,
+                copy_count: row.get::<_, i64>(6)? as u32,
             })
         },
     )
@@ -79,7 +81,7 @@ pub fn get_entry_impl(conn: &Connection, id: &str) -> Option<ClipboardEntry> {
 
 pub fn get_meta_impl(conn: &Connection, limit: usize, offset: usize) -> Vec<ClipboardEntryMeta> {
     let mut stmt = match conn.prepare(
-        "SELECT id, content_type, timestamp, pinned, text_preview, image_width, image_height, byte_size, ocr_text
+        "SELECT id, content_type, timestamp, pinned, text_preview, image_width, image_height, byte_size, ocr_text, copy_count
          FROM history ORDER BY pinned DESC, timestamp DESC LIMIT ? OFFSET ?",
     ) {
         Ok(s) => s,
@@ -100,6 +102,7 @@ pub fn get_meta_impl(conn: &Connection, limit: usize, offset: usize) -> Vec<Clip
             image_height: row.get::<_, Option<i64>>(6)?.map(|v| v as u32),
             byte_size: row.get::<_, Option<i64>>(7)?.unwrap_or(0) as usize,
             ocr_text: row.get(8)?,
+            copy_count: row.get::<_, i64>(9)? as u32,
         })
     })
     .map(|rows| rows.filter_map(|r| r.ok()).collect())
@@ -108,7 +111,7 @@ pub fn get_meta_impl(conn: &Connection, limit: usize, offset: usize) -> Vec<Clip
 
 pub fn get_page_impl(conn: &Connection, limit: usize, offset: usize) -> Vec<ClipboardEntry> {
     let mut stmt = match conn.prepare(
-        "SELECT id, content, content_type, timestamp, pinned, ocr_text
+        "SELECT id, content, content_type, timestamp, pinned, ocr_text, copy_count
          FROM history ORDER BY pinned DESC, timestamp DESC LIMIT ? OFFSET ?",
     ) {
         Ok(s) => s,
@@ -126,6 +129,7 @@ pub fn get_page_impl(conn: &Connection, limit: usize, offset: usize) -> Vec<Clip
             timestamp: row.get(3)?,
             pinned: row.get::<_, i64>(4)? != 0,
             ocr_text: row.get(5)?,
+            copy_count: row.get::<_, i64>(6)? as u32,
         })
     })
     .map(|rows| rows.filter_map(|r| r.ok()).collect())
@@ -41,7 +41,7 @@ pub fn encode_image_as_png(image: &arboard::ImageData) -> Result<String> {
 }
 
 /// Internal helper to encode image to PNG bytes
-fn encode_image_to_png_bytes(image: &arboard::ImageData) -> Result<Vec<u8>> {
+pub(super) fn encode_image_to_png_bytes(image: &arboard::ImageData) -> Result<Vec<u8>> {
     use std::io::Cursor;
 
     // Create an RgbaImage from the raw bytes
@@ -368,10 +368,50 @@ pub fn compute_image_hash(image: &arboard::ImageData) -> u64 {
     hasher.finish()
 }
 
+/// Compute the display size of an image scaled to fit within a bounding box
+/// while preserving aspect ratio, without ever upscaling past `(max_w, max_h)`.
+///
+/// Used by the clipboard history preview panel to size image thumbnails.
+pub fn fit_dimensions(content_w: f32, content_h: f32, max_w: f32, max_h: f32) -> (f32, f32) {
+    if content_w <= 0.0 || content_h <= 0.0 {
+        return (max_w, max_h);
+    }
+    let scale = (max_w / content_w).min(max_h / content_h).min(1.0);
+    (content_w * scale, content_h * scale)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fit_dimensions_downscales_to_fit_width() {
+        let (w, h) = fit_dimensions(1000.0, 500.0, 300.0, 300.0);
+        assert_eq!(w, 300.0);
+        assert_eq!(h, 150.0);
+    }
+
+    #[test]
+    fn test_fit_dimensions_downscales_to_fit_height() {
+        let (w, h) = fit_dimensions(500.0, 1000.0, 300.0, 300.0);
+        assert_eq!(w, 150.0);
+        assert_eq!(h, 300.0);
+    }
+
+    #[test]
+    fn test_fit_dimensions_never_upscales() {
+        let (w, h) = fit_dimensions(100.0, 50.0, 300.0, 300.0);
+        assert_eq!(w, 100.0);
+        assert_eq!(h, 50.0);
+    }
+
+    #[test]
+    fn test_fit_dimensions_falls_back_to_max_box_for_unknown_size() {
+        let (w, h) = fit_dimensions(0.0, 0.0, 300.0, 300.0);
+        assert_eq!(w, 300.0);
+        assert_eq!(h, 300.0);
+    }
+
     #[test]
     fn test_image_hash_deterministic() {
         let image = arboard::ImageData {
@@ -49,12 +49,15 @@ pub use db_worker::{get_db_sender, start_db_worker, DbRequest};
 // Config
 #[allow(unused_imports)]
 pub use config::{
-    get_max_text_content_len, get_retention_days, set_max_text_content_len, set_retention_days,
+    get_dedupe_mode, get_max_text_content_len, get_retention_days, set_dedupe_mode,
+    set_max_text_content_len, set_retention_days, ClipboardDedupeMode,
 };
 
 // Cache
 #[allow(unused_imports)]
-pub use cache::{cache_image, get_cached_entries, get_cached_image};
+pub use cache::{
+    cache_image, get_cached_entries, get_cached_image, image_cache_len, MAX_IMAGE_CACHE_ENTRIES,
+};
 
 // Database operations
 #[allow(unused_imports)]
@@ -66,14 +69,14 @@ pub use database::{
 
 // Image operations
 #[allow(unused_imports)]
-pub use image::decode_to_render_image;
+pub use image::{decode_to_render_image, fit_dimensions};
 
 // Monitor/Init
 #[allow(unused_imports)]
 pub use monitor::{init_clipboard_history, stop_clipboard_monitoring};
 
 // Clipboard operations
-pub use clipboard::copy_entry_to_clipboard;
+pub use clipboard::{copy_entry_to_clipboard, save_entry_image_to_downloads};
 
 // Test-only exports
 #[cfg(test)]
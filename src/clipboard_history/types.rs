@@ -79,6 +79,8 @@ pub struct ClipboardEntry {
     /// OCR text extracted from images (None for text entries or pending OCR)
     #[allow(dead_code)] // Used by downstream subtasks (OCR, UI)
     pub ocr_text: Option<String>,
+    /// Number of times this exact content has been copied in a row (merged via dedupe)
+    pub copy_count: u32,
 }
 
 /// Lightweight clipboard entry metadata for list views (no payload)
@@ -104,6 +106,8 @@ pub struct ClipboardEntryMeta {
     /// OCR text extracted from images (None for text entries or pending OCR)
     #[allow(dead_code)]
     pub ocr_text: Option<String>,
+    /// Number of times this exact content has been copied in a row (merged via dedupe)
+    pub copy_count: u32,
 }
 
 impl ClipboardEntryMeta {
@@ -352,6 +356,7 @@ mod tests {
                 timestamp: today_ts_ms,
                 pinned: false,
                 ocr_text: None,
+                copy_count: 1,
             },
             ClipboardEntry {
                 id: "2".to_string(),
@@ -360,6 +365,7 @@ mod tests {
                 timestamp: yesterday_ts_ms,
                 pinned: false,
                 ocr_text: None,
+                copy_count: 1,
             },
             ClipboardEntry {
                 id: "3".to_string(),
@@ -368,6 +374,7 @@ mod tests {
                 timestamp: old_ts_ms,
                 pinned: false,
                 ocr_text: None,
+                copy_count: 1,
             },
         ];
 
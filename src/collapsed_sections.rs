@@ -0,0 +1,214 @@
+//! Persisted collapsed/expanded state for main menu section headers
+//!
+//! Tracks which section headers (SUGGESTED, SCRIPTS, APPS, etc.) the user has
+//! collapsed, so the choice survives across launches. Collapsed sections are
+//! always ignored while a filter is active - search should always show
+//! everything regardless of what's collapsed in the grouped view.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use tracing::{debug, info, instrument};
+
+/// Raw data format for JSON serialization
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CollapsedSectionsData {
+    collapsed: HashSet<String>,
+}
+
+/// Store for which section headers are collapsed, keyed by section label
+/// (e.g. "SUGGESTED", "SCRIPTS").
+#[derive(Debug)]
+pub struct CollapsedSections {
+    collapsed: HashSet<String>,
+    file_path: PathBuf,
+    dirty: bool,
+}
+
+impl CollapsedSections {
+    /// Create a new store with the default path (~/.scriptkit/collapsed_sections.json)
+    pub fn new() -> Self {
+        Self {
+            collapsed: HashSet::new(),
+            file_path: Self::default_path(),
+            dirty: false,
+        }
+    }
+
+    /// Create a store with a custom path (for testing)
+    #[allow(dead_code)]
+    pub fn with_path(path: PathBuf) -> Self {
+        Self {
+            collapsed: HashSet::new(),
+            file_path: path,
+            dirty: false,
+        }
+    }
+
+    fn default_path() -> PathBuf {
+        PathBuf::from(shellexpand::tilde("~/.scriptkit/collapsed_sections.json").as_ref())
+    }
+
+    /// Load collapsed state from disk. Starts empty (all expanded) if the
+    /// file doesn't exist.
+    #[instrument(name = "collapsed_sections_load", skip(self))]
+    pub fn load(&mut self) -> Result<()> {
+        if !self.file_path.exists() {
+            info!(path = %self.file_path.display(), "Collapsed sections file not found, starting fresh");
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(&self.file_path).with_context(|| {
+            format!(
+                "Failed to read collapsed sections file: {}",
+                self.file_path.display()
+            )
+        })?;
+
+        let data: CollapsedSectionsData = serde_json::from_str(&content)
+            .with_context(|| "Failed to parse collapsed sections JSON")?;
+
+        self.collapsed = data.collapsed;
+        self.dirty = false;
+
+        info!(
+            path = %self.file_path.display(),
+            count = self.collapsed.len(),
+            "Loaded collapsed sections"
+        );
+        Ok(())
+    }
+
+    /// Save collapsed state to disk using atomic write (write temp + rename)
+    #[instrument(name = "collapsed_sections_save", skip(self))]
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            debug!("No changes to save");
+            return Ok(());
+        }
+
+        if let Some(parent) = self.file_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        let json = serde_json::to_string(&CollapsedSectionsData {
+            collapsed: self.collapsed.clone(),
+        })
+        .context("Failed to serialize collapsed sections")?;
+
+        let temp_path = self.file_path.with_extension("json.tmp");
+        std::fs::write(&temp_path, &json).with_context(|| {
+            format!(
+                "Failed to write temp collapsed sections file: {}",
+                temp_path.display()
+            )
+        })?;
+        std::fs::rename(&temp_path, &self.file_path).with_context(|| {
+            format!("Failed to rename temp file to {}", self.file_path.display())
+        })?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Whether the given section label is currently collapsed
+    pub fn is_collapsed(&self, label: &str) -> bool {
+        self.collapsed.contains(label)
+    }
+
+    /// Flip the collapsed state of a section, marking the store dirty
+    pub fn toggle(&mut self, label: &str) {
+        if !self.collapsed.remove(label) {
+            self.collapsed.insert(label.to_string());
+        }
+        self.dirty = true;
+    }
+
+    /// Explicitly set a section's collapsed state, marking the store dirty
+    /// only if the state actually changed.
+    #[allow(dead_code)]
+    pub fn set_collapsed(&mut self, label: &str, collapsed: bool) {
+        let changed = if collapsed {
+            self.collapsed.insert(label.to_string())
+        } else {
+            self.collapsed.remove(label)
+        };
+        if changed {
+            self.dirty = true;
+        }
+    }
+}
+
+impl Default for CollapsedSections {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("collapsed_sections_test_{}.json", name))
+    }
+
+    fn cleanup(path: &PathBuf) {
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_toggle_flips_collapsed_state() {
+        let mut store = CollapsedSections::with_path(temp_path("toggle"));
+        assert!(!store.is_collapsed("SCRIPTS"));
+        store.toggle("SCRIPTS");
+        assert!(store.is_collapsed("SCRIPTS"));
+        store.toggle("SCRIPTS");
+        assert!(!store.is_collapsed("SCRIPTS"));
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let path = temp_path("roundtrip");
+        cleanup(&path);
+
+        let mut store = CollapsedSections::with_path(path.clone());
+        store.toggle("SUGGESTED");
+        store.toggle("APPS");
+        store.save().unwrap();
+
+        let mut reloaded = CollapsedSections::with_path(path.clone());
+        reloaded.load().unwrap();
+        assert!(reloaded.is_collapsed("SUGGESTED"));
+        assert!(reloaded.is_collapsed("APPS"));
+        assert!(!reloaded.is_collapsed("SCRIPTS"));
+
+        cleanup(&path);
+    }
+
+    #[test]
+    fn test_load_missing_file_starts_empty() {
+        let mut store = CollapsedSections::with_path(temp_path("missing"));
+        store.load().unwrap();
+        assert!(!store.is_collapsed("SCRIPTS"));
+    }
+
+    #[test]
+    fn test_set_collapsed_only_marks_dirty_on_change() {
+        let path = temp_path("set_collapsed");
+        cleanup(&path);
+
+        let mut store = CollapsedSections::with_path(path.clone());
+        store.set_collapsed("SCRIPTS", false); // already expanded, no-op
+        store.save().unwrap();
+        assert!(!path.exists()); // nothing dirty, nothing written
+
+        store.set_collapsed("SCRIPTS", true);
+        store.save().unwrap();
+        assert!(path.exists());
+
+        cleanup(&path);
+    }
+}
@@ -18,6 +18,9 @@ pub enum ButtonVariant {
     Ghost,
     /// Icon button (compact, for icons)
     Icon,
+    /// Filled background using the error color, for destructive actions
+    /// (e.g. the confirm button in a "delete this?" dialog)
+    Destructive,
 }
 
 /// Pre-computed colors for Button rendering
@@ -39,6 +42,8 @@ pub struct ButtonColors {
     pub accent: u32,
     /// Border color
     pub border: u32,
+    /// Error/destructive color, used by `ButtonVariant::Destructive`
+    pub error: u32,
 }
 
 impl ButtonColors {
@@ -52,6 +57,7 @@ impl ButtonColors {
             background_hover: theme.colors.accent.selected_subtle,
             accent: theme.colors.accent.selected, // Yellow/gold - matches logo & highlights
             border: theme.colors.ui.border,
+            error: theme.colors.ui.error,
         }
     }
 
@@ -65,6 +71,7 @@ impl ButtonColors {
             background_hover: colors.background_hover,
             accent: colors.accent, // Primary accent (yellow/gold for default)
             border: colors.border,
+            error: colors.error,
         }
     }
 }
@@ -78,6 +85,7 @@ impl Default for ButtonColors {
             background_hover: 0x323232, // Slightly lighter
             accent: 0xfbbf24,           // Yellow/gold (Script Kit brand color)
             border: 0x464647,           // Border color
+            error: 0xef4444,            // Red-500 (Script Kit default error color)
         }
     }
 }
@@ -186,6 +194,11 @@ impl RenderOnce for Button {
                 let bg = rgba(0x00000000);
                 (rgb(colors.accent), bg, hover_overlay)
             }
+            ButtonVariant::Destructive => {
+                // Destructive: filled background with error color, white label
+                let bg = rgba((colors.error << 8) | 0xB0);
+                (rgb(0xffffff), bg, rgba((colors.error << 8) | 0xE0))
+            }
         };
 
         // Build shortcut element if present - smaller than label, same accent color
@@ -206,6 +219,7 @@ impl RenderOnce for Button {
             ButtonVariant::Primary => (rems(0.75), rems(0.375)), // 12px, 6px at 16px base
             ButtonVariant::Ghost => (rems(0.5), rems(0.25)),     // 8px, 4px at 16px base
             ButtonVariant::Icon => (rems(0.375), rems(0.375)),   // 6px, 6px at 16px base
+            ButtonVariant::Destructive => (rems(0.75), rems(0.375)), // Same as Primary
         };
 
         // Build the button element
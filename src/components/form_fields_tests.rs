@@ -135,6 +135,96 @@ fn test_drain_char_range_bullet() {
     assert_eq!(s, "••");
 }
 
+// --- CJK input tests ---
+//
+// CJK characters are 3 bytes each in UTF-8, so these exercise the same
+// byte/char mismatch that emoji do, just with a different encoding length.
+
+#[test]
+fn test_char_len_cjk_mixed() {
+    assert_eq!(char_len("日本語"), 3);
+    assert_eq!(char_len("こんにちは"), 5);
+    assert_eq!(char_len("hi日本"), 4);
+}
+
+#[test]
+fn test_byte_idx_from_char_idx_cjk() {
+    let s = "日本語"; // 3 chars, 3 bytes each = 9 bytes total
+    assert_eq!(byte_idx_from_char_idx(s, 0), 0);
+    assert_eq!(byte_idx_from_char_idx(s, 1), 3);
+    assert_eq!(byte_idx_from_char_idx(s, 2), 6);
+    assert_eq!(byte_idx_from_char_idx(s, 3), 9);
+}
+
+#[test]
+fn test_slice_by_char_range_cjk() {
+    let s = "日本語";
+    assert_eq!(slice_by_char_range(s, 0, 1), "日");
+    assert_eq!(slice_by_char_range(s, 1, 3), "本語");
+    assert_eq!(slice_by_char_range(s, 0, 3), "日本語");
+}
+
+#[test]
+fn test_drain_char_range_cjk() {
+    let mut s = "日本語".to_string();
+    drain_char_range(&mut s, 1, 2); // remove "本"
+    assert_eq!(s, "日語");
+}
+
+#[test]
+fn test_backspace_after_cjk_char_does_not_panic() {
+    // Regression case from the bug report: backspacing right after a
+    // multi-byte char must land on a char boundary, not a byte offset.
+    let mut s = "こんにちは".to_string();
+    let cursor = char_len(&s); // simulate cursor at end, after typing
+    drain_char_range(&mut s, cursor - 1, cursor);
+    assert_eq!(s, "こんにち");
+}
+
+// --- Combining character tests ---
+//
+// A combining accent (e.g. U+0301 COMBINING ACUTE ACCENT) is its own
+// Unicode scalar value, so "e" + U+0301 is 2 chars but renders as a single
+// glyph ("é"). Char-index slicing is still panic-safe here (it never lands
+// mid-codepoint), but note the known gap below: it can split the base
+// character from its combining mark, same as regular grapheme clusters.
+
+#[test]
+fn test_char_len_combining_accent() {
+    let e_acute = "e\u{0301}"; // "é" as base + combining mark
+    assert_eq!(char_len(e_acute), 2);
+    assert_eq!(char_len("cafe\u{0301}"), 5);
+}
+
+#[test]
+fn test_byte_idx_from_char_idx_combining_accent() {
+    let s = "e\u{0301}"; // 'e' = 1 byte, combining mark = 2 bytes
+    assert_eq!(byte_idx_from_char_idx(s, 0), 0);
+    assert_eq!(byte_idx_from_char_idx(s, 1), 1);
+    assert_eq!(byte_idx_from_char_idx(s, 2), 3);
+}
+
+#[test]
+fn test_slice_by_char_range_combining_accent() {
+    let s = "cafe\u{0301}"; // "café"
+    assert_eq!(slice_by_char_range(s, 0, 4), "cafe");
+    assert_eq!(slice_by_char_range(s, 4, 5), "\u{0301}");
+}
+
+#[test]
+fn test_drain_char_range_combining_accent_removes_mark_without_panicking() {
+    // Known gap: one backspace removes only the combining mark, leaving the
+    // bare base letter ("é" becomes "e") instead of deleting the whole
+    // glyph, since this indexes by Unicode scalar value, not grapheme
+    // cluster. It never
+    // panics, which is the bug this fixes - true grapheme-aware editing
+    // is a further improvement, not attempted here.
+    let mut s = "cafe\u{0301}".to_string(); // "café"
+    let last = char_len(&s);
+    drain_char_range(&mut s, last - 1, last); // backspace once
+    assert_eq!(s, "cafe");
+}
+
 // --- Password bullet rendering tests ---
 
 /// Test that password bullet string can be safely sliced by char index.
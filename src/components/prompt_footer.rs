@@ -29,6 +29,7 @@ use gpui::*;
 use std::rc::Rc;
 
 use crate::designs::DesignColors;
+use crate::protocol::FooterHint;
 use crate::theme::Theme;
 use crate::ui_foundation::{hstack, HexColorExt};
 use crate::utils;
@@ -101,6 +102,10 @@ pub struct PromptFooterConfig {
     pub helper_text: Option<String>,
     /// Optional info label shown before buttons (e.g., "typescript", "5 items")
     pub info_label: Option<String>,
+    /// Script-provided shortcut hints that replace the primary/secondary
+    /// buttons with a row of chips when present and non-empty (see
+    /// `protocol::resolve_footer_hints`).
+    pub custom_hints: Option<Vec<FooterHint>>,
 }
 
 impl Default for PromptFooterConfig {
@@ -114,6 +119,7 @@ impl Default for PromptFooterConfig {
             show_secondary: true,
             helper_text: None,
             info_label: None,
+            custom_hints: None,
         }
     }
 }
@@ -171,11 +177,21 @@ impl PromptFooterConfig {
         self.info_label = Some(label.into());
         self
     }
+
+    /// Replace the primary/secondary buttons with script-provided hint chips.
+    /// Ignored (falls back to primary/secondary) when `hints` is empty.
+    pub fn custom_hints(mut self, hints: Vec<FooterHint>) -> Self {
+        self.custom_hints = if hints.is_empty() { None } else { Some(hints) };
+        self
+    }
 }
 
 /// Callback type for button click events
 pub type FooterClickCallback = Box<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>;
 
+/// Callback type for hint chip clicks, indexed into `PromptFooterConfig::custom_hints`
+pub type FooterHintClickCallback = Box<dyn Fn(usize, &ClickEvent, &mut Window, &mut App) + 'static>;
+
 /// A reusable footer component for prompts
 ///
 /// Displays:
@@ -191,6 +207,7 @@ pub struct PromptFooter {
     colors: PromptFooterColors,
     on_primary_click: Option<Rc<FooterClickCallback>>,
     on_secondary_click: Option<Rc<FooterClickCallback>>,
+    on_hint_click: Option<Rc<FooterHintClickCallback>>,
 }
 
 impl PromptFooter {
@@ -201,6 +218,7 @@ impl PromptFooter {
             colors,
             on_primary_click: None,
             on_secondary_click: None,
+            on_hint_click: None,
         }
     }
 
@@ -216,6 +234,13 @@ impl PromptFooter {
         self
     }
 
+    /// Set the hint chip click callback, called with the clicked hint's index
+    /// into `PromptFooterConfig::custom_hints`
+    pub fn on_hint_click(mut self, callback: FooterHintClickCallback) -> Self {
+        self.on_hint_click = Some(Rc::new(callback));
+        self
+    }
+
     /// Render the Script Kit logo (accent-colored icon, no background)
     fn render_logo(&self) -> impl IntoElement {
         svg()
@@ -267,6 +292,42 @@ impl PromptFooter {
         )
     }
 
+    /// Render a single script-provided hint chip (label + shortcut), styled
+    /// like a footer button but keyed by index rather than a fixed id.
+    fn render_hint_chip(&self, index: usize, hint: &FooterHint) -> impl IntoElement {
+        let colors = self.colors;
+        let mut chip = div()
+            .id(("footer-hint", index))
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(px(6.))
+            .px(px(8.))
+            .py(px(4.))
+            .rounded(px(4.))
+            .cursor_pointer()
+            .hover(move |s| s.bg(rgba((colors.accent << 8) | 0x26)));
+
+        if let Some(callback) = self.on_hint_click.clone() {
+            chip = chip.on_click(move |event, window, cx| {
+                callback(index, event, window, cx);
+            });
+        }
+
+        chip.child(
+            div()
+                .text_sm()
+                .text_color(colors.accent.to_rgb())
+                .child(hint.label.clone()),
+        )
+        .child(
+            div()
+                .text_sm()
+                .text_color(colors.text_muted.to_rgb())
+                .child(hint.shortcut.clone()),
+        )
+    }
+
     /// Render the vertical divider between buttons
     fn render_divider(&self) -> impl IntoElement {
         div()
@@ -298,25 +359,35 @@ impl RenderOnce for PromptFooter {
         // Build the buttons container
         let mut buttons = hstack().gap(px(4.));
 
-        // Primary button
-        buttons = buttons.child(self.render_button(
-            "footer-primary-button",
-            self.config.primary_label.clone(),
-            self.config.primary_shortcut.clone(),
-            hover_bg,
-            self.on_primary_click.clone(),
-        ));
-
-        // Divider + Secondary button (if enabled)
-        if self.config.show_secondary {
-            buttons = buttons.child(self.render_divider());
+        if let Some(ref hints) = self.config.custom_hints {
+            // Script-provided hints replace the default primary/secondary buttons.
+            for (index, hint) in hints.iter().enumerate() {
+                if index > 0 {
+                    buttons = buttons.child(self.render_divider());
+                }
+                buttons = buttons.child(self.render_hint_chip(index, hint));
+            }
+        } else {
+            // Primary button
             buttons = buttons.child(self.render_button(
-                "footer-secondary-button",
-                self.config.secondary_label.clone(),
-                self.config.secondary_shortcut.clone(),
+                "footer-primary-button",
+                self.config.primary_label.clone(),
+                self.config.primary_shortcut.clone(),
                 hover_bg,
-                self.on_secondary_click.clone(),
+                self.on_primary_click.clone(),
             ));
+
+            // Divider + Secondary button (if enabled)
+            if self.config.show_secondary {
+                buttons = buttons.child(self.render_divider());
+                buttons = buttons.child(self.render_button(
+                    "footer-secondary-button",
+                    self.config.secondary_label.clone(),
+                    self.config.secondary_shortcut.clone(),
+                    hover_bg,
+                    self.on_secondary_click.clone(),
+                ));
+            }
         }
 
         right_side = right_side.child(buttons);
@@ -341,6 +341,7 @@ impl PromptHeader {
             background_hover: colors.background,
             accent: colors.accent,
             border: colors.border,
+            error: 0xef4444, // Unused here - these buttons never use ButtonVariant::Destructive
         };
 
         let on_primary = self.on_primary_click.clone();
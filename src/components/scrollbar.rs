@@ -10,12 +10,31 @@
 //! - Shows thumb position/size based on scroll state
 //! - Semi-transparent and only visible when content overflows
 //! - Theme-aware colors
+//! - Draggable thumb and clickable track (via `on_scroll`/`on_drag_changed`/`on_hover_changed`),
+//!   when the caller supplies `container_height` and `track_origin_y`
+//! - While dragging (`dragging(true)`), move/up handlers are registered on a
+//!   full-size overlay rather than the narrow track, so the drag survives the
+//!   pointer leaving the track horizontally
 //!
 
 #![allow(dead_code)]
 
+use std::rc::Rc;
+
 use gpui::{prelude::*, *};
 
+/// Callback invoked when the user interacts with the scrollbar.
+///
+/// `ratio` is the target scroll position in `0.0..=1.0` (top to bottom).
+/// `absolute` is true when the interaction should jump directly to `ratio`
+/// (dragging the thumb, or Option/Alt-clicking the track); when false
+/// (a plain track click) the caller should page toward `ratio` instead of
+/// snapping straight to it.
+pub type ScrollbarCallback = Rc<dyn Fn(f32, bool, &mut Window, &mut App) + 'static>;
+
+/// Callback invoked when the thumb drag starts/ends or the pointer enters/leaves the scrollbar.
+pub type ScrollbarHoverCallback = Rc<dyn Fn(bool, &mut Window, &mut App) + 'static>;
+
 /// Width of the scrollbar track in pixels
 pub const SCROLLBAR_WIDTH: f32 = 6.0;
 
@@ -106,9 +125,20 @@ pub struct Scrollbar {
     colors: ScrollbarColors,
     /// Container height in pixels (for calculating thumb position)
     container_height: Option<f32>,
+    /// Window-relative Y offset of the top of the scrollable container, used to translate
+    /// a click's window-relative position into a ratio within the track
+    track_origin_y: Option<f32>,
     /// Whether the scrollbar is visible (for scroll-activity-aware fade)
     /// When Some(true), shows at full opacity; Some(false), hidden; None, always visible
     is_visible: Option<bool>,
+    /// Invoked with the target ratio when the track is clicked or the thumb is dragged
+    on_scroll: Option<ScrollbarCallback>,
+    /// Invoked with `true`/`false` when the thumb drag starts/ends
+    on_drag_changed: Option<ScrollbarHoverCallback>,
+    /// Invoked with `true`/`false` when the pointer enters/leaves the scrollbar
+    on_hover_changed: Option<ScrollbarHoverCallback>,
+    /// Whether a thumb drag is currently in progress (see `dragging`)
+    dragging: bool,
 }
 
 impl Scrollbar {
@@ -131,10 +161,46 @@ impl Scrollbar {
             scroll_offset,
             colors,
             container_height: None,
+            track_origin_y: None,
             is_visible: None,
+            on_scroll: None,
+            on_drag_changed: None,
+            on_hover_changed: None,
+            dragging: false,
         }
     }
 
+    /// Register a callback for track clicks and thumb drags.
+    ///
+    /// Requires `container_height` to be set; without a known pixel height
+    /// the scrollbar falls back to percentage-based rendering and clicks
+    /// are ignored (there's no container geometry to map a click onto).
+    pub fn on_scroll(
+        mut self,
+        callback: impl Fn(f32, bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_scroll = Some(Rc::new(callback));
+        self
+    }
+
+    /// Register a callback fired with `true` when a thumb drag begins and `false` when it ends.
+    pub fn on_drag_changed(
+        mut self,
+        callback: impl Fn(bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_drag_changed = Some(Rc::new(callback));
+        self
+    }
+
+    /// Register a callback fired with `true`/`false` as the pointer enters/leaves the scrollbar.
+    pub fn on_hover_changed(
+        mut self,
+        callback: impl Fn(bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_hover_changed = Some(Rc::new(callback));
+        self
+    }
+
     /// Set the container height for precise thumb positioning
     ///
     /// If not set, the scrollbar will use percentage-based positioning
@@ -143,6 +209,29 @@ impl Scrollbar {
         self
     }
 
+    /// Set the window-relative Y offset of the container's top edge
+    ///
+    /// Needed to convert a raw mouse position (window-relative) into a ratio
+    /// within the track when handling clicks/drags. Without it, click-to-jump
+    /// and drag support are disabled.
+    pub fn track_origin_y(mut self, origin_y: f32) -> Self {
+        self.track_origin_y = Some(origin_y);
+        self
+    }
+
+    /// Tell the scrollbar whether a thumb drag is currently in progress.
+    ///
+    /// While `true`, drag-continuation (`on_mouse_move`) and drag-end
+    /// (`on_mouse_up`) handlers are registered on a full-size overlay that
+    /// covers the whole container, not just the narrow track, so the drag
+    /// keeps tracking the pointer even after it leaves the scrollbar
+    /// horizontally. The caller should pass its own "is a drag active"
+    /// state here (the same flag it flips in `on_drag_changed`).
+    pub fn dragging(mut self, dragging: bool) -> Self {
+        self.dragging = dragging;
+        self
+    }
+
     /// Set the scrollbar visibility for scroll-activity-aware fade
     ///
     /// - `true`: Show scrollbar at full opacity (during scroll activity)
@@ -215,6 +304,19 @@ impl RenderOnce for Scrollbar {
         let thumb_height_ratio = self.thumb_height_ratio();
         let thumb_position_ratio = self.thumb_position_ratio();
 
+        // Interaction is only available once we know the container's pixel
+        // geometry (height + window-relative top) -- without it we fall back
+        // to a display-only scrollbar like before.
+        let interaction = match (self.container_height, self.track_origin_y) {
+            (Some(height), Some(origin_y)) => Some((height, origin_y)),
+            _ => None,
+        };
+        let ratio_from_y = move |y: Pixels| -> f32 {
+            let (height, origin_y) = interaction.unwrap_or((1.0, 0.0));
+            let offset: f32 = y.into();
+            ((offset - origin_y) / height.max(1.0)).clamp(0.0, 1.0)
+        };
+
         // Calculate actual pixel values if container height is known
         let (thumb_height_px, thumb_top_px) = if let Some(container_h) = self.container_height {
             let available_height = container_h - (SCROLLBAR_PADDING * 2.0);
@@ -226,7 +328,8 @@ impl RenderOnce for Scrollbar {
             (None, None)
         };
 
-        // Build the scrollbar container (absolute positioned on right edge)
+        // Build the scrollbar container (absolute positioned on right edge), widened
+        // slightly on hover so the track is easier to grab without looking chunky at rest.
         let mut scrollbar = div()
             .absolute()
             .top_0()
@@ -236,10 +339,27 @@ impl RenderOnce for Scrollbar {
             .flex()
             .flex_col();
 
+        if let Some(on_hover_changed) = self.on_hover_changed.clone() {
+            scrollbar = scrollbar.on_hover(move |hovered, window, cx| {
+                on_hover_changed(*hovered, window, cx);
+            });
+        }
+
+        if interaction.is_some() {
+            if let Some(on_scroll) = self.on_scroll.clone() {
+                // Click anywhere on the track to page toward the click, or Option/Alt-click
+                // to jump straight to that absolute position.
+                scrollbar = scrollbar.on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                    let ratio = ratio_from_y(event.position.y);
+                    on_scroll(ratio, event.modifiers.alt, window, cx);
+                });
+            }
+        }
+
         // Build the thumb element
         let thumb = if let (Some(height), Some(top)) = (thumb_height_px, thumb_top_px) {
             // Precise pixel positioning
-            div()
+            let mut thumb = div()
                 .absolute()
                 .top(px(top))
                 .left_0()
@@ -251,7 +371,16 @@ impl RenderOnce for Scrollbar {
                     s.bg(rgba(
                         (colors.thumb_hover << 8) | ((thumb_hover_opacity * 255.0) as u32),
                     ))
-                })
+                });
+
+            if let Some(on_drag_changed) = self.on_drag_changed.clone() {
+                let drag_start = on_drag_changed.clone();
+                thumb = thumb.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                    drag_start(true, window, cx);
+                });
+            }
+
+            thumb
         } else {
             // Percentage-based positioning (fallback)
             // Use flex layout to position thumb
@@ -305,7 +434,34 @@ impl RenderOnce for Scrollbar {
             return scrollbar.into_any_element();
         };
 
-        scrollbar.child(thumb).into_any_element()
+        let track = scrollbar.child(thumb);
+
+        // While a thumb drag is in progress, wrap the visible track in a
+        // full-size overlay and register the move/up handlers there instead
+        // of on the 6px-wide track. Per-element `on_mouse_move`/`on_mouse_up`
+        // only fire while the pointer is still over that element, so without
+        // this the drag would silently freeze the moment the cursor left the
+        // track horizontally (this codebase has no `on_drag`/pointer-capture
+        // API to reach for instead, so a wide covering div is the mechanism).
+        if interaction.is_some() && self.dragging {
+            let mut capture = div().absolute().inset_0();
+
+            if let Some(on_scroll) = self.on_scroll {
+                capture = capture.on_mouse_move(move |event, window, cx| {
+                    let ratio = ratio_from_y(event.position.y);
+                    on_scroll(ratio, true, window, cx);
+                });
+            }
+            if let Some(on_drag_changed) = self.on_drag_changed {
+                capture = capture.on_mouse_up(MouseButton::Left, move |_event, window, cx| {
+                    on_drag_changed(false, window, cx);
+                });
+            }
+
+            capture.child(track).into_any_element()
+        } else {
+            track.into_any_element()
+        }
     }
 }
 
@@ -55,6 +55,8 @@ pub struct TextInputState {
     text: String,
     /// Selection state (anchor and cursor positions)
     selection: TextSelection,
+    /// Whether pasted text may contain newlines (textarea-style fields)
+    multiline: bool,
 }
 
 impl Default for TextInputState {
@@ -68,9 +70,16 @@ impl TextInputState {
         Self {
             text: String::new(),
             selection: TextSelection::caret(0),
+            multiline: false,
         }
     }
 
+    /// Allow pasted text to retain newlines instead of being flattened to a
+    /// single line. Used by textarea-style fields (e.g. multi-line env values).
+    pub fn set_multiline(&mut self, multiline: bool) {
+        self.multiline = multiline;
+    }
+
     #[allow(dead_code)]
     pub fn with_text(text: impl Into<String>) -> Self {
         let text = text.into();
@@ -78,6 +87,7 @@ impl TextInputState {
         Self {
             text,
             selection: TextSelection::caret(len), // Cursor at end
+            multiline: false,
         }
     }
 
@@ -300,14 +310,24 @@ impl TextInputState {
     pub fn paste<T: Render>(&mut self, cx: &mut Context<T>) {
         if let Some(item) = cx.read_from_clipboard() {
             if let Some(text) = item.text() {
-                // Filter to single line (no newlines)
-                let single_line: String =
-                    text.chars().filter(|c| *c != '\n' && *c != '\r').collect();
-                self.insert_str(&single_line);
+                let sanitized = self.sanitize_pasted_text(&text);
+                self.insert_str(&sanitized);
             }
         }
     }
 
+    /// Prepare clipboard text for insertion: single-line fields (the
+    /// default) strip `\n`/`\r` so pasting a multi-line snippet doesn't
+    /// silently split a filter or arg value across lines; multiline fields
+    /// (see `set_multiline`) pass the text through unchanged.
+    fn sanitize_pasted_text(&self, text: &str) -> String {
+        if self.multiline {
+            text.to_string()
+        } else {
+            text.chars().filter(|c| *c != '\n' && *c != '\r').collect()
+        }
+    }
+
     // === Key Handling ===
 
     /// Handle a key event. Returns true if the event was handled.
@@ -589,4 +609,47 @@ mod tests {
         input.move_right(false);
         assert_eq!(input.cursor(), 2); // After 'hé'
     }
+
+    #[test]
+    fn test_cursor_math_multibyte_emoji() {
+        // Each of these is a single char but multiple UTF-8 bytes (emoji is
+        // 4 bytes); cursor positions are char-based, so this must not panic
+        // or land mid-codepoint.
+        let mut input = TextInputState::with_text("a🎉b");
+        assert_eq!(input.text().chars().count(), 3);
+        input.move_to_start(false);
+        input.move_right(false);
+        assert_eq!(input.cursor(), 1); // After 'a', before the emoji
+        input.move_right(false);
+        assert_eq!(input.cursor(), 2); // After the emoji
+        input.backspace();
+        assert_eq!(input.text(), "ab");
+        assert_eq!(input.cursor(), 1);
+    }
+
+    #[test]
+    fn test_cursor_math_multibyte_word_boundary() {
+        let mut input = TextInputState::with_text("héllo wörld");
+        input.move_to_end(false);
+        input.move_word_left(false);
+        assert_eq!(input.cursor(), 6); // At 'w' of "wörld"
+        input.move_word_left(false);
+        assert_eq!(input.cursor(), 0);
+    }
+
+    #[test]
+    fn test_paste_strips_newlines_for_single_line_field() {
+        let mut input = TextInputState::with_text("ab");
+        let sanitized = input.sanitize_pasted_text("x\r\ny\nz");
+        assert_eq!(sanitized, "xyz");
+        input.insert_str(&sanitized);
+        assert_eq!(input.text(), "abxyz");
+    }
+
+    #[test]
+    fn test_paste_keeps_newlines_for_multiline_field() {
+        let mut input = TextInputState::new();
+        input.set_multiline(true);
+        assert_eq!(input.sanitize_pasted_text("x\r\ny"), "x\r\ny");
+    }
 }
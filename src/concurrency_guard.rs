@@ -0,0 +1,220 @@
+//! Per-script concurrency policy enforcement
+//!
+//! Tracks which script paths are currently running an interactive session
+//! and decides, at launch time, whether a new request for the same path
+//! should start, be refused, or wait in a queue - based on that script's
+//! `ScriptConcurrency` policy (see `scripts::types::ScriptConcurrency`).
+//!
+//! This only guards the single-session interactive UI path that funnels
+//! through `ScriptListApp::execute_interactive`. Scheduled runs (triggered
+//! by the cron/natural-language scheduler in `scheduler.rs`) execute headless
+//! via a separate raw-process path tracked by `process_manager::PROCESS_MANAGER`
+//! and are not covered by this gate.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
+
+use crate::logging;
+
+/// Maximum number of pending launch requests queued per script path.
+/// Extra requests beyond this are dropped (with a log entry) rather than
+/// growing the queue without bound.
+const MAX_QUEUED_PER_PATH: usize = 10;
+
+/// What the caller should do with a launch request, decided by `ConcurrencyGuard::gate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateDecision {
+    /// No conflicting run in progress - start it now.
+    Start,
+    /// A run of this path is already active and its policy is `Single` -
+    /// the new request is refused outright.
+    Blocked,
+    /// A run of this path is already active and its policy is `Queue` -
+    /// the new request was queued and will be dispatched from `finish()`,
+    /// or dropped if `MAX_QUEUED_PER_PATH` requests were already pending.
+    Queued,
+}
+
+/// Global singleton tracking in-flight interactive script runs by path.
+pub static CONCURRENCY_GUARD: LazyLock<ConcurrencyGuard> = LazyLock::new(ConcurrencyGuard::new);
+
+/// Thread-safe registry of currently-running script paths and their pending queues.
+#[derive(Debug, Default)]
+pub struct ConcurrencyGuard {
+    running: Mutex<HashSet<PathBuf>>,
+    pending: Mutex<HashMap<PathBuf, VecDeque<PathBuf>>>,
+}
+
+impl ConcurrencyGuard {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decide what to do with a launch request for `path` under `policy`.
+    ///
+    /// `Parallel` scripts are never tracked as "running" here, since their
+    /// whole point is to not gate future launches of the same path.
+    pub fn gate(&self, path: &PathBuf, policy: super::scripts::ScriptConcurrency) -> GateDecision {
+        use super::scripts::ScriptConcurrency;
+
+        if policy == ScriptConcurrency::Parallel {
+            return GateDecision::Start;
+        }
+
+        let mut running = self.running.lock().unwrap();
+        if running.insert(path.clone()) {
+            return GateDecision::Start;
+        }
+
+        match policy {
+            ScriptConcurrency::Single => GateDecision::Blocked,
+            ScriptConcurrency::Queue => {
+                let mut pending = self.pending.lock().unwrap();
+                let queue = pending.entry(path.clone()).or_default();
+                if queue.len() >= MAX_QUEUED_PER_PATH {
+                    logging::log(
+                        "EXEC",
+                        &format!(
+                            "Concurrency queue for {:?} is full ({} pending), dropping launch request",
+                            path, MAX_QUEUED_PER_PATH
+                        ),
+                    );
+                } else {
+                    queue.push_back(path.clone());
+                }
+                GateDecision::Queued
+            }
+            ScriptConcurrency::Parallel => unreachable!("handled above"),
+        }
+    }
+
+    /// Record that the run for `path` has exited, and return the next
+    /// queued path (if any) that should now be launched.
+    pub fn finish(&self, path: &PathBuf) -> Option<PathBuf> {
+        self.running.lock().unwrap().remove(path);
+
+        let mut pending = self.pending.lock().unwrap();
+        let Some(queue) = pending.get_mut(path) else {
+            return None;
+        };
+        let next = queue.pop_front();
+        if queue.is_empty() {
+            pending.remove(path);
+        }
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scripts::ScriptConcurrency;
+
+    #[test]
+    fn test_single_policy_blocks_second_launch() {
+        let guard = ConcurrencyGuard::new();
+        let path = PathBuf::from("/scripts/single.ts");
+
+        assert_eq!(
+            guard.gate(&path, ScriptConcurrency::Single),
+            GateDecision::Start
+        );
+        assert_eq!(
+            guard.gate(&path, ScriptConcurrency::Single),
+            GateDecision::Blocked
+        );
+
+        assert_eq!(guard.finish(&path), None);
+        assert_eq!(
+            guard.gate(&path, ScriptConcurrency::Single),
+            GateDecision::Start
+        );
+    }
+
+    #[test]
+    fn test_queue_policy_drains_in_order_on_finish() {
+        let guard = ConcurrencyGuard::new();
+        let path = PathBuf::from("/scripts/queued.ts");
+
+        assert_eq!(
+            guard.gate(&path, ScriptConcurrency::Queue),
+            GateDecision::Start
+        );
+        assert_eq!(
+            guard.gate(&path, ScriptConcurrency::Queue),
+            GateDecision::Queued
+        );
+        assert_eq!(
+            guard.gate(&path, ScriptConcurrency::Queue),
+            GateDecision::Queued
+        );
+
+        // First finish dispatches the first queued request.
+        assert_eq!(guard.finish(&path), Some(path.clone()));
+        // The path is marked running again implicitly by the caller re-gating it;
+        // until then, a second finish drains the remaining queued entry.
+        assert_eq!(guard.finish(&path), Some(path.clone()));
+        assert_eq!(guard.finish(&path), None);
+    }
+
+    #[test]
+    fn test_parallel_policy_never_blocks() {
+        let guard = ConcurrencyGuard::new();
+        let path = PathBuf::from("/scripts/parallel.ts");
+
+        assert_eq!(
+            guard.gate(&path, ScriptConcurrency::Parallel),
+            GateDecision::Start
+        );
+        assert_eq!(
+            guard.gate(&path, ScriptConcurrency::Parallel),
+            GateDecision::Start
+        );
+        // Parallel runs were never added to `running`, so finish() is a no-op.
+        assert_eq!(guard.finish(&path), None);
+    }
+
+    #[test]
+    fn test_queue_caps_pending_entries() {
+        let guard = ConcurrencyGuard::new();
+        let path = PathBuf::from("/scripts/busy.ts");
+
+        assert_eq!(
+            guard.gate(&path, ScriptConcurrency::Queue),
+            GateDecision::Start
+        );
+        for _ in 0..(MAX_QUEUED_PER_PATH + 5) {
+            assert_eq!(
+                guard.gate(&path, ScriptConcurrency::Queue),
+                GateDecision::Queued
+            );
+        }
+
+        let mut drained = 0;
+        while guard.finish(&path).is_some() {
+            drained += 1;
+        }
+        assert_eq!(drained, MAX_QUEUED_PER_PATH);
+    }
+
+    #[test]
+    fn test_different_paths_do_not_interfere() {
+        let guard = ConcurrencyGuard::new();
+        let a = PathBuf::from("/scripts/a.ts");
+        let b = PathBuf::from("/scripts/b.ts");
+
+        assert_eq!(
+            guard.gate(&a, ScriptConcurrency::Single),
+            GateDecision::Start
+        );
+        assert_eq!(
+            guard.gate(&b, ScriptConcurrency::Single),
+            GateDecision::Start
+        );
+        assert_eq!(
+            guard.gate(&a, ScriptConcurrency::Single),
+            GateDecision::Blocked
+        );
+    }
+}
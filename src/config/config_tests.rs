@@ -19,6 +19,36 @@ fn test_clipboard_history_max_text_length_default() {
     );
 }
 
+#[test]
+fn test_clipboard_auto_paste_default_is_off() {
+    let config = Config::default();
+    assert!(!config.get_clipboard_auto_paste());
+}
+
+#[test]
+fn test_hide_on_exit_default_is_on() {
+    let config = Config::default();
+    assert!(config.get_hide_on_exit());
+}
+
+#[test]
+fn test_hud_position_default_is_bottom_center() {
+    let config = Config::default();
+    assert_eq!(
+        config.get_hud_position(),
+        crate::protocol::HudPosition::BottomCenter
+    );
+}
+
+#[test]
+fn test_search_weights_default_is_unweighted() {
+    let config = Config::default();
+    let weights = config.get_search_weights();
+    assert_eq!(weights.name, 1.0);
+    assert_eq!(weights.description, 1.0);
+    assert_eq!(weights.filename, 1.0);
+}
+
 #[test]
 fn test_config_serialization() {
     let config = Config {
@@ -27,6 +57,8 @@ fn test_config_serialization() {
             key: "KeyA".to_string(),
         },
         bun_path: Some("/usr/local/bin/bun".to_string()),
+        tool_paths: None,
+        typed_errors: None,
         editor: Some("vim".to_string()),
         padding: None,
         editor_font_size: None,
@@ -35,10 +67,21 @@ fn test_config_serialization() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     let json = serde_json::to_string(&config).unwrap();
@@ -69,6 +112,8 @@ fn test_config_with_bun_path() {
             key: "Semicolon".to_string(),
         },
         bun_path: Some("/custom/path/bun".to_string()),
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -77,10 +122,21 @@ fn test_config_with_bun_path() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
     assert_eq!(config.bun_path, Some("/custom/path/bun".to_string()));
 }
@@ -93,6 +149,8 @@ fn test_config_without_bun_path() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -101,10 +159,21 @@ fn test_config_without_bun_path() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
     assert_eq!(config.bun_path, None);
 }
@@ -117,6 +186,8 @@ fn test_config_serialization_skip_none_bun_path() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -125,10 +196,21 @@ fn test_config_serialization_skip_none_bun_path() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     let json = serde_json::to_string(&config).unwrap();
@@ -146,6 +228,8 @@ fn test_config_serialization_preserves_multiple_modifiers() {
             key: "KeyP".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -154,10 +238,21 @@ fn test_config_serialization_preserves_multiple_modifiers() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     let json = serde_json::to_string(&config).unwrap();
@@ -242,6 +337,8 @@ fn test_config_with_empty_modifiers_list() {
             key: "KeyA".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -250,10 +347,21 @@ fn test_config_with_empty_modifiers_list() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     assert_eq!(config.hotkey.modifiers.len(), 0);
@@ -272,6 +380,8 @@ fn test_config_key_preservation() {
                 key: key.to_string(),
             },
             bun_path: None,
+            tool_paths: None,
+            typed_errors: None,
             editor: None,
             padding: None,
             editor_font_size: None,
@@ -280,10 +390,21 @@ fn test_config_key_preservation() {
             built_ins: None,
             process_limits: None,
             clipboard_history_max_text_length: None,
+            clipboard_auto_paste: None,
             suggested: None,
             notes_hotkey: None,
             ai_hotkey: None,
             commands: None,
+            theme: None,
+            shutdown: None,
+            hud_position: None,
+            search_weights: None,
+            terminal: None,
+            window: None,
+            fallbacks: None,
+            logging: None,
+            recent_files_folders: None,
+            script_dirs: None,
         };
 
         let json = serde_json::to_string(&config).unwrap();
@@ -301,6 +422,8 @@ fn test_config_with_editor() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: Some("vim".to_string()),
         padding: None,
         editor_font_size: None,
@@ -309,10 +432,21 @@ fn test_config_with_editor() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     let json = serde_json::to_string(&config).unwrap();
@@ -330,6 +464,8 @@ fn test_config_without_editor() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -338,10 +474,21 @@ fn test_config_without_editor() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     let json = serde_json::to_string(&config).unwrap();
@@ -360,6 +507,8 @@ fn test_get_editor_from_config() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: Some("nvim".to_string()),
         padding: None,
         editor_font_size: None,
@@ -368,10 +517,21 @@ fn test_get_editor_from_config() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     // Config editor takes precedence
@@ -392,6 +552,8 @@ fn test_get_editor_from_env() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -400,10 +562,21 @@ fn test_get_editor_from_env() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     // Should fall back to EDITOR env var
@@ -430,6 +603,8 @@ fn test_get_editor_default() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -438,10 +613,21 @@ fn test_get_editor_default() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     // Should fall back to "code" default
@@ -468,6 +654,8 @@ fn test_config_editor_priority() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: Some("vim".to_string()),
         padding: None,
         editor_font_size: None,
@@ -476,10 +664,21 @@ fn test_config_editor_priority() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     // Config editor should win
@@ -571,6 +770,8 @@ fn test_config_get_padding_custom() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: Some(ContentPadding {
             top: 10.0,
@@ -583,10 +784,21 @@ fn test_config_get_padding_custom() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     let padding = config.get_padding();
@@ -609,6 +821,8 @@ fn test_config_get_editor_font_size_custom() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: Some(16.0),
@@ -617,10 +831,21 @@ fn test_config_get_editor_font_size_custom() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     assert_eq!(config.get_editor_font_size(), 16.0);
@@ -640,6 +865,8 @@ fn test_config_get_terminal_font_size_custom() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -648,10 +875,21 @@ fn test_config_get_terminal_font_size_custom() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     assert_eq!(config.get_terminal_font_size(), 12.0);
@@ -671,6 +909,8 @@ fn test_config_get_ui_scale_custom() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -679,10 +919,21 @@ fn test_config_get_ui_scale_custom() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     assert_eq!(config.get_ui_scale(), 1.5);
@@ -763,6 +1014,8 @@ fn test_config_serialization_includes_set_ui_settings() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: Some(ContentPadding::default()),
         editor_font_size: Some(16.0),
@@ -771,10 +1024,21 @@ fn test_config_serialization_includes_set_ui_settings() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     let json = serde_json::to_string(&config).unwrap();
@@ -859,6 +1123,8 @@ fn test_config_with_builtins() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -871,10 +1137,21 @@ fn test_config_with_builtins() {
         }),
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     let builtins = config.get_builtins();
@@ -955,6 +1232,8 @@ fn test_config_serialization_includes_set_builtins() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -963,10 +1242,21 @@ fn test_config_serialization_includes_set_builtins() {
         built_ins: Some(BuiltInConfig::default()),
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     let json = serde_json::to_string(&config).unwrap();
@@ -1094,6 +1384,8 @@ fn test_config_with_process_limits() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -1106,10 +1398,21 @@ fn test_config_with_process_limits() {
             health_check_interval_ms: 3000,
         }),
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     let limits = config.get_process_limits();
@@ -1196,6 +1499,8 @@ fn test_config_serialization_includes_set_process_limits() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -1204,10 +1509,21 @@ fn test_config_serialization_includes_set_process_limits() {
         built_ins: None,
         process_limits: Some(ProcessLimits::default()),
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: None,
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     let json = serde_json::to_string(&config).unwrap();
@@ -1308,6 +1624,8 @@ fn test_requires_confirmation_user_override_disable() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -1316,10 +1634,21 @@ fn test_requires_confirmation_user_override_disable() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: Some(commands),
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     // Should NOT require confirmation because user disabled it
@@ -1347,6 +1676,8 @@ fn test_requires_confirmation_user_override_enable() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -1355,10 +1686,21 @@ fn test_requires_confirmation_user_override_enable() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: Some(commands),
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     // Should require confirmation because user enabled it
@@ -1499,6 +1841,8 @@ fn test_requires_confirmation_with_partial_command_config() {
             key: "Semicolon".to_string(),
         },
         bun_path: None,
+        tool_paths: None,
+        typed_errors: None,
         editor: None,
         padding: None,
         editor_font_size: None,
@@ -1507,12 +1851,266 @@ fn test_requires_confirmation_with_partial_command_config() {
         built_ins: None,
         process_limits: None,
         clipboard_history_max_text_length: None,
+        clipboard_auto_paste: None,
         suggested: None,
         notes_hotkey: None,
         ai_hotkey: None,
         commands: Some(commands),
+        theme: None,
+        shutdown: None,
+        hud_position: None,
+        search_weights: None,
+        terminal: None,
+        window: None,
+        fallbacks: None,
+        logging: None,
+        recent_files_folders: None,
+        script_dirs: None,
     };
 
     // Should still require confirmation (falls back to default)
     assert!(config.requires_confirmation("builtin-shut-down"));
 }
+
+#[test]
+fn test_get_theme_accent_defaults_to_none() {
+    let config = Config::default();
+    assert_eq!(config.get_theme_accent(), None);
+}
+
+#[test]
+fn test_get_theme_accent_system() {
+    let config = Config {
+        theme: Some(ThemeConfig {
+            accent: Some("system".to_string()),
+        }),
+        ..Config::default()
+    };
+    assert_eq!(config.get_theme_accent(), Some("system".to_string()));
+}
+
+#[test]
+fn test_get_theme_accent_custom_hex() {
+    let config = Config {
+        theme: Some(ThemeConfig {
+            accent: Some("#ff8800".to_string()),
+        }),
+        ..Config::default()
+    };
+    assert_eq!(config.get_theme_accent(), Some("#ff8800".to_string()));
+}
+
+#[test]
+fn test_get_terminal_shell_defaults_to_none() {
+    let config = Config::default();
+    assert_eq!(config.get_terminal_shell(), None);
+}
+
+#[test]
+fn test_get_terminal_shell_uses_configured_override() {
+    let config = Config {
+        terminal: Some(TerminalConfig {
+            shell: Some("/bin/bash".to_string()),
+            login: false,
+        }),
+        ..Config::default()
+    };
+    assert_eq!(config.get_terminal_shell(), Some("/bin/bash".to_string()));
+}
+
+#[test]
+fn test_get_terminal_login_defaults_to_false() {
+    let config = Config::default();
+    assert!(!config.get_terminal_login());
+}
+
+#[test]
+fn test_get_terminal_login_uses_configured_override() {
+    let config = Config {
+        terminal: Some(TerminalConfig {
+            shell: None,
+            login: true,
+        }),
+        ..Config::default()
+    };
+    assert!(config.get_terminal_login());
+}
+
+#[test]
+fn test_get_shutdown_defaults() {
+    let config = Config::default();
+    let shutdown = config.get_shutdown();
+    assert_eq!(shutdown.grace_period_ms, DEFAULT_SHUTDOWN_GRACE_PERIOD_MS);
+    assert!(!shutdown.skip_wait);
+    assert_eq!(
+        shutdown.cancel_grace_period_ms,
+        DEFAULT_CANCEL_GRACE_PERIOD_MS
+    );
+}
+
+#[test]
+fn test_get_shutdown_overrides() {
+    let config = Config {
+        shutdown: Some(ShutdownConfig {
+            grace_period_ms: 500,
+            skip_wait: true,
+            cancel_grace_period_ms: 250,
+        }),
+        ..Config::default()
+    };
+    let shutdown = config.get_shutdown();
+    assert_eq!(shutdown.grace_period_ms, 500);
+    assert!(shutdown.skip_wait);
+    assert_eq!(shutdown.cancel_grace_period_ms, 250);
+}
+
+#[test]
+fn test_get_window_vibrancy_default_is_none() {
+    let config = Config::default();
+    assert_eq!(config.get_window_vibrancy(), None);
+}
+
+#[test]
+fn test_get_window_vibrancy_uses_configured_override() {
+    let config = Config {
+        window: Some(WindowConfig {
+            vibrancy: Some(false),
+            ..WindowConfig::default()
+        }),
+        ..Config::default()
+    };
+    assert_eq!(config.get_window_vibrancy(), Some(false));
+}
+
+#[test]
+fn test_get_window_opacity_default_is_none() {
+    let config = Config::default();
+    assert_eq!(config.get_window_opacity(), None);
+}
+
+#[test]
+fn test_get_window_opacity_clamps_above_one() {
+    let config = Config {
+        window: Some(WindowConfig {
+            opacity: Some(1.5),
+            ..WindowConfig::default()
+        }),
+        ..Config::default()
+    };
+    assert_eq!(config.get_window_opacity(), Some(1.0));
+}
+
+#[test]
+fn test_get_window_opacity_clamps_below_zero() {
+    let config = Config {
+        window: Some(WindowConfig {
+            opacity: Some(-0.5),
+            ..WindowConfig::default()
+        }),
+        ..Config::default()
+    };
+    assert_eq!(config.get_window_opacity(), Some(0.0));
+}
+
+#[test]
+fn test_get_window_opacity_passes_through_valid_value() {
+    let config = Config {
+        window: Some(WindowConfig {
+            opacity: Some(0.6),
+            ..WindowConfig::default()
+        }),
+        ..Config::default()
+    };
+    assert_eq!(config.get_window_opacity(), Some(0.6));
+}
+
+#[test]
+fn test_window_config_parses_vibrancy_and_opacity_from_json() {
+    let json = r#"{
+        "hotkey": { "modifiers": ["meta"], "key": "Semicolon" },
+        "window": { "vibrancy": true, "opacity": 0.45 }
+    }"#;
+    let config: Config = serde_json::from_str(json).expect("valid config json");
+    assert_eq!(config.get_window_vibrancy(), Some(true));
+    assert_eq!(config.get_window_opacity(), Some(0.45));
+}
+
+#[test]
+fn test_window_config_parses_without_vibrancy_or_opacity() {
+    let json = r#"{
+        "hotkey": { "modifiers": ["meta"], "key": "Semicolon" },
+        "window": { "density": "compact" }
+    }"#;
+    let config: Config = serde_json::from_str(json).expect("valid config json");
+    assert_eq!(config.get_window_vibrancy(), None);
+    assert_eq!(config.get_window_opacity(), None);
+    assert_eq!(config.get_density(), Density::Compact);
+}
+
+#[test]
+fn test_get_hide_on_blur_default_is_true() {
+    let config = Config::default();
+    assert!(config.get_hide_on_blur());
+}
+
+#[test]
+fn test_get_hide_on_blur_uses_configured_override() {
+    let config = Config {
+        window: Some(WindowConfig {
+            hide_on_blur: Some(false),
+            ..WindowConfig::default()
+        }),
+        ..Config::default()
+    };
+    assert!(!config.get_hide_on_blur());
+}
+
+#[test]
+fn test_get_hide_on_blur_preserve_prompt_default_is_false() {
+    let config = Config::default();
+    assert!(!config.get_hide_on_blur_preserve_prompt());
+}
+
+#[test]
+fn test_window_config_parses_hide_on_blur_from_json() {
+    let json = r#"{
+        "hotkey": { "modifiers": ["meta"], "key": "Semicolon" },
+        "window": { "hideOnBlur": false, "hideOnBlurPreservePrompt": true }
+    }"#;
+    let config: Config = serde_json::from_str(json).expect("valid config json");
+    assert!(!config.get_hide_on_blur());
+    assert!(config.get_hide_on_blur_preserve_prompt());
+}
+
+#[test]
+fn test_get_script_dirs_default_is_empty() {
+    let config = Config::default();
+    assert!(config.get_script_dirs().is_empty());
+}
+
+#[test]
+fn test_get_script_dirs_defaults_kenv_to_directory_name() {
+    let config = Config {
+        script_dirs: Some(vec![ScriptDirConfig {
+            path: "/repos/work-scripts".to_string(),
+            kenv: None,
+        }]),
+        ..Config::default()
+    };
+    let dirs = config.get_script_dirs();
+    assert_eq!(dirs.len(), 1);
+    assert_eq!(dirs[0].0, "work-scripts");
+    assert_eq!(dirs[0].1, std::path::PathBuf::from("/repos/work-scripts"));
+}
+
+#[test]
+fn test_get_script_dirs_uses_explicit_kenv_override() {
+    let config = Config {
+        script_dirs: Some(vec![ScriptDirConfig {
+            path: "/repos/work-scripts".to_string(),
+            kenv: Some("work".to_string()),
+        }]),
+        ..Config::default()
+    };
+    assert_eq!(config.get_script_dirs()[0].0, "work");
+}
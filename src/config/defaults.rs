@@ -22,9 +22,40 @@ pub const DEFAULT_WINDOW_SWITCHER: bool = true;
 /// Default max text length for clipboard history entries (bytes)
 pub const DEFAULT_CLIPBOARD_HISTORY_MAX_TEXT_LENGTH: usize = 100_000;
 
+/// Default for auto-pasting a clipboard history selection into the
+/// frontmost app after hiding (off, since some users prefer manual paste)
+pub const DEFAULT_CLIPBOARD_AUTO_PASTE: bool = false;
+
+/// Default clipboard history dedupe mode string (matches
+/// `ClipboardDedupeMode::default()`, i.e. `Adjacent`)
+pub const DEFAULT_CLIPBOARD_HISTORY_DEDUPE: &str = "adjacent";
+
+/// Default for hiding the window when a script finishes running (on, matches
+/// the historical behavior of returning to the background after each run)
+pub const DEFAULT_HIDE_ON_EXIT: bool = true;
+
+/// Default for hiding the main window when it loses key focus to another
+/// app (on, matches Raycast/Alfred-style launcher behavior)
+pub const DEFAULT_HIDE_ON_BLUR: bool = true;
+
+/// Default for restoring the filter/selection/view session snapshot on
+/// startup (off - this is a dev convenience, not something most users want)
+pub const DEFAULT_RESTORE_SESSION: bool = false;
+
+/// Default for emitting `Message::Error` on request/response handler
+/// failures instead of each handler's legacy ad-hoc fallback (off, so
+/// SDKs written against the old response shapes aren't surprised by a
+/// message type they don't expect)
+pub const DEFAULT_TYPED_ERRORS: bool = false;
+
 /// Default process limits
 pub const DEFAULT_HEALTH_CHECK_INTERVAL_MS: u64 = 5000;
 
+/// Default script search field weights (multipliers, not raw scores)
+pub const DEFAULT_SEARCH_WEIGHT_NAME: f64 = 1.0;
+pub const DEFAULT_SEARCH_WEIGHT_DESCRIPTION: f64 = 1.0;
+pub const DEFAULT_SEARCH_WEIGHT_FILENAME: f64 = 1.0;
+
 /// Default suggested section settings
 pub const DEFAULT_SUGGESTED_ENABLED: bool = true;
 pub const DEFAULT_SUGGESTED_MAX_ITEMS: usize = 10;
@@ -47,3 +78,17 @@ pub const DEFAULT_CONFIRMATION_COMMANDS: &[&str] = &[
 /// Commands that should be excluded from frecency/suggested tracking.
 /// These are commands that don't make sense to suggest (e.g., quit).
 pub const DEFAULT_FRECENCY_EXCLUDED_COMMANDS: &[&str] = &["builtin-quit-script-kit"];
+
+/// Default grace period for graceful shutdown (waiting for a running script
+/// to exit on its own before escalating to SIGTERM/SIGKILL)
+pub const DEFAULT_SHUTDOWN_GRACE_PERIOD_MS: u64 = 2000;
+
+/// Default for skipping the graceful shutdown wait entirely (off, so quitting
+/// gives scripts a chance to clean up by default)
+pub const DEFAULT_SHUTDOWN_SKIP_WAIT: bool = false;
+
+/// Default grace period for cancelling a single running script (SIGTERM,
+/// then SIGKILL if it hasn't exited by the time this elapses). Shorter than
+/// [`DEFAULT_SHUTDOWN_GRACE_PERIOD_MS`] since a user cancelling a script
+/// expects the window to respond right away.
+pub const DEFAULT_CANCEL_GRACE_PERIOD_MS: u64 = 500;
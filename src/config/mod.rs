@@ -19,7 +19,11 @@ mod types;
 pub use defaults::DEFAULT_SUGGESTED_HALF_LIFE_DAYS;
 
 // Re-export types that are used externally
-pub use types::{BuiltInConfig, Config, HotkeyConfig, SuggestedConfig};
+pub use types::{
+    default_fallback_templates, BuiltInConfig, Config, Density, FallbackTemplateConfig,
+    HotkeyConfig, ScriptDirConfig, SearchWeights, ShutdownConfig, SuggestedConfig, ThemeConfig,
+    WindowConfig, WindowPositionMode,
+};
 
 // Re-export loader
 pub use loader::load_config;
@@ -29,7 +33,8 @@ pub use loader::load_config;
 pub use defaults::{
     DEFAULT_CLIPBOARD_HISTORY_MAX_TEXT_LENGTH, DEFAULT_CONFIRMATION_COMMANDS,
     DEFAULT_EDITOR_FONT_SIZE, DEFAULT_HEALTH_CHECK_INTERVAL_MS, DEFAULT_PADDING_LEFT,
-    DEFAULT_PADDING_RIGHT, DEFAULT_PADDING_TOP, DEFAULT_TERMINAL_FONT_SIZE, DEFAULT_UI_SCALE,
+    DEFAULT_PADDING_RIGHT, DEFAULT_PADDING_TOP, DEFAULT_SHUTDOWN_GRACE_PERIOD_MS,
+    DEFAULT_TERMINAL_FONT_SIZE, DEFAULT_UI_SCALE,
 };
 
 #[cfg(test)]
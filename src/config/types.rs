@@ -79,6 +79,103 @@ impl Default for ProcessLimits {
     }
 }
 
+// ============================================
+// SHUTDOWN CONFIG
+// ============================================
+
+/// Configuration for graceful shutdown of running scripts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShutdownConfig {
+    /// How long to wait for a running script to exit on its own after being
+    /// sent a shutdown message, before escalating to SIGTERM/SIGKILL
+    /// (default: 2000ms)
+    #[serde(default = "default_shutdown_grace_period_ms")]
+    pub grace_period_ms: u64,
+    /// Skip the graceful wait entirely and kill running scripts immediately
+    /// (default: false)
+    #[serde(default = "default_shutdown_skip_wait")]
+    pub skip_wait: bool,
+    /// How long to wait after SIGTERM-ing a single cancelled script before
+    /// escalating to SIGKILL (default: 500ms)
+    #[serde(default = "default_cancel_grace_period_ms")]
+    pub cancel_grace_period_ms: u64,
+}
+
+fn default_shutdown_grace_period_ms() -> u64 {
+    DEFAULT_SHUTDOWN_GRACE_PERIOD_MS
+}
+
+fn default_shutdown_skip_wait() -> bool {
+    DEFAULT_SHUTDOWN_SKIP_WAIT
+}
+
+fn default_cancel_grace_period_ms() -> u64 {
+    DEFAULT_CANCEL_GRACE_PERIOD_MS
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        ShutdownConfig {
+            grace_period_ms: DEFAULT_SHUTDOWN_GRACE_PERIOD_MS,
+            skip_wait: DEFAULT_SHUTDOWN_SKIP_WAIT,
+            cancel_grace_period_ms: DEFAULT_CANCEL_GRACE_PERIOD_MS,
+        }
+    }
+}
+
+// ============================================
+// FALLBACK CONFIG
+// ============================================
+
+/// A single configurable fallback row, shown in search mode when the filter
+/// matches no real results.
+///
+/// `url` is a template containing a literal `{query}` placeholder, which is
+/// replaced with the percent-encoded search text before opening.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FallbackTemplateConfig {
+    pub name: String,
+    pub url: String,
+}
+
+/// Default fallback rows, used when `fallbacks` isn't configured.
+pub fn default_fallback_templates() -> Vec<FallbackTemplateConfig> {
+    vec![
+        FallbackTemplateConfig {
+            name: "Search Google".to_string(),
+            url: "https://www.google.com/search?q={query}".to_string(),
+        },
+        FallbackTemplateConfig {
+            name: "Search DuckDuckGo".to_string(),
+            url: "https://duckduckgo.com/?q={query}".to_string(),
+        },
+        FallbackTemplateConfig {
+            name: "Define".to_string(),
+            url: "dict://{query}".to_string(),
+        },
+    ]
+}
+
+// ============================================
+// SCRIPT DIRECTORIES
+// ============================================
+
+/// A single extra directory to scan for scripts, beyond the default
+/// `~/.scriptkit/kit/*/scripts` kits. Lets scripts live in other repos
+/// (e.g. a work kenv checked out elsewhere) while still showing up in the
+/// main search.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScriptDirConfig {
+    /// Path to scan for `.ts`/`.js` scripts. Supports `~` for the home
+    /// directory.
+    pub path: String,
+    /// Name shown when grouping scripts by source (e.g. "work"). Defaults
+    /// to the directory's own name when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kenv: Option<String>,
+}
+
 // ============================================
 // SUGGESTED CONFIG
 // ============================================
@@ -147,6 +244,49 @@ impl Default for SuggestedConfig {
     }
 }
 
+// ============================================
+// SEARCH WEIGHTS
+// ============================================
+
+/// Relative weighting for the script search scoring fields in
+/// [`crate::scripts::search::fuzzy_search_scripts_weighted`]. Each value
+/// multiplies that field's contribution to a script's match score, so a
+/// description match can be made to rank above or below a name match
+/// without changing the underlying matching logic.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchWeights {
+    /// Multiplier for name matches (substring + fuzzy). Default: 1.0
+    #[serde(default = "default_search_weight_name")]
+    pub name: f64,
+    /// Multiplier for description matches. Default: 1.0
+    #[serde(default = "default_search_weight_description")]
+    pub description: f64,
+    /// Multiplier for filename matches (substring + fuzzy). Default: 1.0
+    #[serde(default = "default_search_weight_filename")]
+    pub filename: f64,
+}
+
+fn default_search_weight_name() -> f64 {
+    DEFAULT_SEARCH_WEIGHT_NAME
+}
+fn default_search_weight_description() -> f64 {
+    DEFAULT_SEARCH_WEIGHT_DESCRIPTION
+}
+fn default_search_weight_filename() -> f64 {
+    DEFAULT_SEARCH_WEIGHT_FILENAME
+}
+
+impl Default for SearchWeights {
+    fn default() -> Self {
+        SearchWeights {
+            name: DEFAULT_SEARCH_WEIGHT_NAME,
+            description: DEFAULT_SEARCH_WEIGHT_DESCRIPTION,
+            filename: DEFAULT_SEARCH_WEIGHT_FILENAME,
+        }
+    }
+}
+
 // ============================================
 // CONTENT PADDING
 // ============================================
@@ -182,6 +322,81 @@ impl Default for ContentPadding {
     }
 }
 
+// ============================================
+// WINDOW CONFIG
+// ============================================
+
+/// List/row density. Controls `crate::density::list_item_height()` and
+/// `crate::density::section_header_height()`, which every row-height
+/// calculation (list rendering, `height_for_view`, scroll-to-reveal) reads
+/// from instead of hard-coding pixel values.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Density {
+    /// 48px rows (default)
+    #[default]
+    Comfortable,
+    /// 36px rows - more results visible on small screens
+    Compact,
+}
+
+/// Where prompts appear when shown. See `Config::get_window_position_mode`
+/// and `platform::calculate_eye_line_bounds_on_mouse_display`.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowPositionMode {
+    /// Eye-line height on whichever display the mouse cursor is on
+    /// (default). Matches Raycast/Alfred-style behavior.
+    #[default]
+    MouseEyeLine,
+    /// Always show at the position the window was last dragged to (the same
+    /// bounds tracked in `window-state.json` for `WindowRole::Main`). Falls
+    /// back to `MouseEyeLine` if no position has been saved yet, or it's no
+    /// longer on a connected display.
+    Fixed,
+}
+
+/// Window-level configuration (list density, blur/vibrancy background).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct WindowConfig {
+    #[serde(default)]
+    pub density: Density,
+    /// Overrides `theme.json`'s `vibrancy.enabled`. `None` leaves the
+    /// theme's own setting in effect; `Some(_)` wins regardless of what
+    /// the theme file says.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vibrancy: Option<bool>,
+    /// Overrides `theme.json`'s `opacity.main` (the root container's
+    /// background alpha). Clamped to `0.0..=1.0` by `Config::get_window_opacity`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opacity: Option<f32>,
+    /// Where prompts appear when shown (default: `MouseEyeLine`).
+    #[serde(default)]
+    pub position: WindowPositionMode,
+    /// Hide the main window when it loses key focus to another app.
+    /// `None` uses `DEFAULT_HIDE_ON_BLUR` (true, Raycast/Alfred-style) via
+    /// `Config::get_hide_on_blur`. A `TermPrompt`/`EditorPrompt` with
+    /// unsubmitted content, the actions dialog mid-flow, a `DropPrompt`,
+    /// and pin mode are never auto-hidden regardless of this setting -
+    /// see `ScriptListApp::maybe_hide_on_blur`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "hideOnBlur"
+    )]
+    pub hide_on_blur: Option<bool>,
+    /// When hiding on blur while a script's prompt is open, keep the
+    /// prompt's state intact instead of canceling the script. `None`
+    /// defaults to `false` (cancel, matching the explicit Cmd+W/Hide
+    /// path) via `Config::get_hide_on_blur_preserve_prompt`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "hideOnBlurPreservePrompt"
+    )]
+    pub hide_on_blur_preserve_prompt: Option<bool>,
+}
+
 // ============================================
 // COMMAND CONFIG
 // ============================================
@@ -351,6 +566,46 @@ impl HotkeyConfig {
     }
 }
 
+// ============================================
+// TERMINAL CONFIG
+// ============================================
+
+/// Default settings for the `term()` SDK call, used when a script's
+/// `Message::Term` omits `shell`/`login`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TerminalConfig {
+    /// Shell binary to spawn (e.g. `/bin/bash`). Falls back to `$SHELL`,
+    /// then `/bin/zsh`, when not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
+    /// Spawn the shell as a login shell by default (default: false).
+    #[serde(default)]
+    pub login: bool,
+}
+
+impl Default for TerminalConfig {
+    fn default() -> Self {
+        TerminalConfig {
+            shell: None,
+            login: false,
+        }
+    }
+}
+
+// ============================================
+// THEME CONFIG
+// ============================================
+
+/// Theme customization options.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ThemeConfig {
+    /// Accent color source: `"system"` to follow the macOS system accent
+    /// color, or an explicit `"#rrggbb"` hex value to pin a custom accent.
+    /// Defaults to `"system"` when not configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent: Option<String>,
+}
+
 // ============================================
 // MAIN CONFIG
 // ============================================
@@ -362,6 +617,12 @@ pub struct Config {
     pub bun_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub editor: Option<String>,
+    /// Explicit interpreter path overrides for scriptlet tools, keyed by
+    /// tool name (e.g. `{"python": "/opt/homebrew/bin/python3.11"}`). Used
+    /// when the interpreter isn't on PATH or a specific version is required;
+    /// see `executor::scriptlet::resolve_tool`.
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "toolPaths")]
+    pub tool_paths: Option<HashMap<String, String>>,
     /// Padding for content areas (terminal, editor, etc.)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub padding: Option<ContentPadding>,
@@ -399,9 +660,68 @@ pub struct Config {
         rename = "clipboardHistoryMaxTextLength"
     )]
     pub clipboard_history_max_text_length: Option<usize>,
+    /// Automatically paste a clipboard history selection into the frontmost
+    /// app after hiding (default: false, matches manual-paste expectations)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "clipboardAutoPaste"
+    )]
+    pub clipboard_auto_paste: Option<bool>,
+    /// How aggressively identical clipboard content is merged: `"adjacent"`
+    /// (default, only merges with the most-recently-added entry), `"global"`
+    /// (merges with any matching entry regardless of recency), or `"off"`
+    /// (never merges). See `clipboard_history::ClipboardDedupeMode`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "clipboardHistoryDedupe"
+    )]
+    pub clipboard_history_dedupe: Option<String>,
+    /// Whether to hide the window after a script finishes running (default:
+    /// true). Set to `false` to return to the script list and stay open
+    /// instead, for running several utilities in a row. A script's own
+    /// `// KeepOpen: true` comment or typed `metadata.keepOpen` overrides
+    /// this per-script.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "hideOnExit"
+    )]
+    pub hide_on_exit: Option<bool>,
+    /// Dev convenience: persist the main menu's filter text, selection,
+    /// design variant, and logs/pin toggles to disk and restore them on the
+    /// next launch (default: false). Meant for iterating on list behavior
+    /// under `cargo run` without losing context on every restart; an
+    /// in-flight script's prompt is never part of this snapshot.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "restoreSession"
+    )]
+    pub restore_session: Option<bool>,
+    /// Emit `Message::Error` (with a machine-readable `code`) on
+    /// request/response handler failures - Clipboard, ClipboardHistory,
+    /// WindowList/WindowAction, FileSearch, GetWindowBounds,
+    /// CaptureScreenshot - instead of each handler's legacy ad-hoc fallback
+    /// (default: false). Older SDKs that don't expect an `error` message
+    /// type should leave this off; see `get_typed_errors_enabled`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "typedErrors"
+    )]
+    pub typed_errors: Option<bool>,
     /// Suggested section configuration (frecency-based ranking)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub suggested: Option<SuggestedConfig>,
+    /// Relative weights for script search fields (name/description/filename)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "searchWeights"
+    )]
+    pub search_weights: Option<SearchWeights>,
     /// Hotkey for opening Notes window (default: Cmd+Shift+N)
     #[serde(
         default,
@@ -415,6 +735,57 @@ pub struct Config {
     /// Per-command configuration overrides (shortcuts, visibility)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub commands: Option<HashMap<String, CommandConfig>>,
+    /// Theme customization (accent color override, etc.)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<ThemeConfig>,
+    /// Default shell/login-shell settings for the `term()` SDK call
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal: Option<TerminalConfig>,
+    /// Graceful shutdown configuration (grace period, skip-wait flag)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shutdown: Option<ShutdownConfig>,
+    /// Default screen position for HUD overlays (default: bottom-center).
+    /// A position passed to the `hud()` SDK call overrides this per-call.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "hudPosition"
+    )]
+    pub hud_position: Option<crate::protocol::HudPosition>,
+    /// Window-level settings (list density, etc.)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window: Option<WindowConfig>,
+    /// Fallback rows shown when a search matches nothing (e.g. "Search
+    /// Google for '...'"). Overrides the built-in set and order entirely
+    /// when present.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallbacks: Option<Vec<FallbackTemplateConfig>>,
+    /// Per-category log level overrides (e.g. `{"POSITION": "warn", "PERF":
+    /// "info", "default": "debug"}`). A category not listed falls back to
+    /// the `"default"` entry, or `debug` (log everything, today's
+    /// unfiltered behavior) if that's absent too. Levels, least to most
+    /// verbose: `error`, `warn`, `info`, `debug`, `trace`. Applied once at
+    /// startup via `logging::configure_from_config` - see that function
+    /// for why this doesn't need a lock on every log call.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logging: Option<HashMap<String, String>>,
+    /// Folders scanned for recently modified files, surfaced in the main
+    /// search as a RECENT FILES section (see `recent_files` module). Paths
+    /// support `~` for the home directory. Empty/absent means only macOS's
+    /// shared recent-documents list contributes (currently a stub - see
+    /// `recent_files::read_macos_recent_documents`).
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "recentFilesFolders"
+    )]
+    pub recent_files_folders: Option<Vec<String>>,
+    /// Extra directories to scan for scripts, beyond the default
+    /// `~/.scriptkit/kit/*/scripts` kits (see `scripts::read_scripts`).
+    /// Each script loaded from one of these directories is tagged with its
+    /// `kenv` for grouping in the main search.
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "scriptDirs")]
+    pub script_dirs: Option<Vec<ScriptDirConfig>>,
 }
 
 impl Default for Config {
@@ -425,6 +796,7 @@ impl Default for Config {
                 key: "Semicolon".to_string(), // Cmd+; matches main.rs default
             },
             bun_path: None,           // Will use system PATH if not specified
+            tool_paths: None,         // Will search PATH/common install locations
             editor: None,             // Will use $EDITOR or fallback to "code"
             padding: None,            // Will use ContentPadding::default() via getter
             editor_font_size: None,   // Will use DEFAULT_EDITOR_FONT_SIZE via getter
@@ -433,10 +805,25 @@ impl Default for Config {
             built_ins: None,          // Will use BuiltInConfig::default() via getter
             process_limits: None,     // Will use ProcessLimits::default() via getter
             clipboard_history_max_text_length: None, // Will use default via getter
+            clipboard_auto_paste: None, // Will use default via getter
+            clipboard_history_dedupe: None, // Will use ClipboardDedupeMode::default() via getter
+            hide_on_exit: None,       // Will use DEFAULT_HIDE_ON_EXIT via getter
+            restore_session: None,    // Will use DEFAULT_RESTORE_SESSION via getter
+            typed_errors: None,       // Will use DEFAULT_TYPED_ERRORS via getter
             suggested: None,          // Will use SuggestedConfig::default() via getter
+            search_weights: None,     // Will use SearchWeights::default() via getter
             notes_hotkey: None,       // Will use HotkeyConfig::default_notes_hotkey() via getter
             ai_hotkey: None,          // Will use HotkeyConfig::default_ai_hotkey() via getter
             commands: None,           // No per-command overrides by default
+            theme: None,              // Will follow system accent color via getter
+            terminal: None,           // Will use TerminalConfig::default() via getter
+            shutdown: None,           // Will use ShutdownConfig::default() via getter
+            hud_position: None,       // Will use HudPosition::default() via getter
+            window: None,             // Will use WindowConfig::default() via getter
+            fallbacks: None,          // Will use default_fallback_templates() via getter
+            logging: None,            // Will default every category to "debug" (unfiltered)
+            recent_files_folders: None, // No watched folders by default
+            script_dirs: None,        // No extra script directories by default
         }
     }
 }
@@ -452,6 +839,13 @@ impl Config {
             .unwrap_or_else(|| "code".to_string())
     }
 
+    /// Returns the configured interpreter path override for a scriptlet
+    /// tool (e.g. "python"), or `None` to search PATH/common install
+    /// locations at run time. See `executor::scriptlet::resolve_tool`.
+    pub fn get_tool_path(&self, tool: &str) -> Option<String> {
+        self.tool_paths.as_ref()?.get(tool).cloned()
+    }
+
     /// Returns the content padding, or defaults if not configured
     #[allow(dead_code)] // Will be used by TermPrompt/EditorPrompt workers
     pub fn get_padding(&self) -> ContentPadding {
@@ -490,6 +884,45 @@ impl Config {
             .unwrap_or(DEFAULT_CLIPBOARD_HISTORY_MAX_TEXT_LENGTH)
     }
 
+    /// Returns whether clipboard history selections should auto-paste into
+    /// the frontmost app after hiding, or the default (false) if not configured
+    pub fn get_clipboard_auto_paste(&self) -> bool {
+        self.clipboard_auto_paste
+            .unwrap_or(DEFAULT_CLIPBOARD_AUTO_PASTE)
+    }
+
+    /// Returns the configured clipboard history dedupe mode, or the default
+    /// (`Adjacent`) if not configured or unrecognized. See
+    /// `clipboard_history::ClipboardDedupeMode`.
+    pub fn get_clipboard_history_dedupe_mode(&self) -> crate::clipboard_history::ClipboardDedupeMode {
+        self.clipboard_history_dedupe
+            .as_deref()
+            .map(crate::clipboard_history::ClipboardDedupeMode::from_str)
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the window should hide after a script finishes
+    /// running, or the default (true) if not configured. A script's own
+    /// `KeepOpen` metadata takes precedence over this - see `ScriptExit`
+    /// handling in `prompt_handler.rs`.
+    pub fn get_hide_on_exit(&self) -> bool {
+        self.hide_on_exit.unwrap_or(DEFAULT_HIDE_ON_EXIT)
+    }
+
+    /// Returns whether the main menu's filter/selection/view session should
+    /// be persisted and restored across restarts, or the default (false) if
+    /// not configured. See `session_state` module.
+    pub fn get_restore_session(&self) -> bool {
+        self.restore_session.unwrap_or(DEFAULT_RESTORE_SESSION)
+    }
+
+    /// Returns whether request/response handlers should emit typed
+    /// `Message::Error` responses on failure, or the default (false) if not
+    /// configured. See `Message::Error` and the handlers in `execute_script.rs`.
+    pub fn get_typed_errors_enabled(&self) -> bool {
+        self.typed_errors.unwrap_or(DEFAULT_TYPED_ERRORS)
+    }
+
     /// Returns the process limits configuration, or defaults if not configured
     #[allow(dead_code)] // Will be used by process_manager module
     pub fn get_process_limits(&self) -> ProcessLimits {
@@ -501,6 +934,11 @@ impl Config {
         self.suggested.clone().unwrap_or_default()
     }
 
+    /// Returns the script search field weights, or defaults (all 1.0) if not configured
+    pub fn get_search_weights(&self) -> SearchWeights {
+        self.search_weights.clone().unwrap_or_default()
+    }
+
     /// Returns the notes hotkey configuration, or default (Cmd+Shift+N) if not configured
     #[allow(dead_code)]
     pub fn get_notes_hotkey(&self) -> HotkeyConfig {
@@ -523,6 +961,130 @@ impl Config {
         self.commands.as_ref().and_then(|cmds| cmds.get(command_id))
     }
 
+    /// Returns the configured `theme.accent` override ("system" or an
+    /// explicit "#rrggbb" hex string), or `None` if not configured (which
+    /// also means "follow the system accent color").
+    /// Returns the graceful shutdown configuration, or defaults if not configured
+    pub fn get_shutdown(&self) -> ShutdownConfig {
+        self.shutdown.clone().unwrap_or_default()
+    }
+
+    /// Returns the configured fallback rows (search/define templates shown
+    /// when a search matches nothing), or the built-in defaults.
+    pub fn get_fallbacks(&self) -> Vec<FallbackTemplateConfig> {
+        self.fallbacks
+            .clone()
+            .unwrap_or_else(default_fallback_templates)
+    }
+
+    /// Returns the configured recent-files watched folders, with `~`
+    /// expanded to the home directory, or an empty list if not configured.
+    /// See `recent_files::scan_watched_folders`.
+    pub fn get_recent_files_folders(&self) -> Vec<std::path::PathBuf> {
+        self.recent_files_folders
+            .as_ref()
+            .map(|folders| {
+                folders
+                    .iter()
+                    .map(|f| std::path::PathBuf::from(shellexpand::tilde(f).as_ref()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the configured extra script directories as `(kenv, path)`
+    /// pairs, with `~` expanded to the home directory and `kenv` defaulted
+    /// to the directory's own name when not given. Empty if unconfigured.
+    /// See `scripts::read_scripts`.
+    pub fn get_script_dirs(&self) -> Vec<(String, std::path::PathBuf)> {
+        self.script_dirs
+            .as_ref()
+            .map(|dirs| {
+                dirs.iter()
+                    .map(|d| {
+                        let path =
+                            std::path::PathBuf::from(shellexpand::tilde(&d.path).as_ref());
+                        let kenv = d.kenv.clone().unwrap_or_else(|| {
+                            path.file_name()
+                                .map(|n| n.to_string_lossy().to_string())
+                                .unwrap_or_else(|| d.path.clone())
+                        });
+                        (kenv, path)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the configured default HUD position, or `HudPosition::BottomCenter`
+    /// if not configured. A position passed directly to the `hud()` SDK call
+    /// takes precedence over this default.
+    pub fn get_hud_position(&self) -> crate::protocol::HudPosition {
+        self.hud_position.unwrap_or_default()
+    }
+
+    pub fn get_theme_accent(&self) -> Option<String> {
+        self.theme.as_ref().and_then(|t| t.accent.clone())
+    }
+
+    /// Returns the configured default `term()` shell override, or `None` to
+    /// fall back to `$SHELL`/`/bin/zsh`.
+    pub fn get_terminal_shell(&self) -> Option<String> {
+        self.terminal.as_ref().and_then(|t| t.shell.clone())
+    }
+
+    /// Returns whether `term()` should default to a login shell, or `false`
+    /// if not configured.
+    pub fn get_terminal_login(&self) -> bool {
+        self.terminal.as_ref().map(|t| t.login).unwrap_or(false)
+    }
+
+    /// Returns the configured list density, or `Density::Comfortable` if not
+    /// configured.
+    pub fn get_density(&self) -> Density {
+        self.window.as_ref().map(|w| w.density).unwrap_or_default()
+    }
+
+    /// Returns the `window.vibrancy` override, or `None` to leave
+    /// `theme.json`'s own `vibrancy.enabled` setting in effect.
+    pub fn get_window_vibrancy(&self) -> Option<bool> {
+        self.window.as_ref().and_then(|w| w.vibrancy)
+    }
+
+    /// Returns the `window.opacity` override clamped to `0.0..=1.0`, or
+    /// `None` to leave `theme.json`'s own `opacity.main` in effect.
+    pub fn get_window_opacity(&self) -> Option<f32> {
+        self.window
+            .as_ref()
+            .and_then(|w| w.opacity)
+            .map(|o| o.clamp(0.0, 1.0))
+    }
+
+    /// Returns the configured window position mode, or `MouseEyeLine` if not
+    /// configured.
+    pub fn get_window_position_mode(&self) -> WindowPositionMode {
+        self.window.as_ref().map(|w| w.position).unwrap_or_default()
+    }
+
+    /// Returns the `window.hideOnBlur` setting, or `DEFAULT_HIDE_ON_BLUR`
+    /// (true) if not configured.
+    pub fn get_hide_on_blur(&self) -> bool {
+        self.window
+            .as_ref()
+            .and_then(|w| w.hide_on_blur)
+            .unwrap_or(DEFAULT_HIDE_ON_BLUR)
+    }
+
+    /// Returns the `window.hideOnBlurPreservePrompt` setting, or `false`
+    /// (cancel the running script, matching the explicit Hide path) if not
+    /// configured.
+    pub fn get_hide_on_blur_preserve_prompt(&self) -> bool {
+        self.window
+            .as_ref()
+            .and_then(|w| w.hide_on_blur_preserve_prompt)
+            .unwrap_or(false)
+    }
+
     /// Check if a command should be hidden from the main menu.
     #[allow(dead_code)]
     pub fn is_command_hidden(&self, command_id: &str) -> bool {
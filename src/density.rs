@@ -0,0 +1,62 @@
+//! Global list density state.
+//!
+//! Every row-height calculation (list rendering, `height_for_view`,
+//! scroll-to-reveal) reads `list_item_height()`/`section_header_height()`
+//! instead of hard-coding pixel values, so toggling density live resizes
+//! everything consistently without threading a `Density` parameter through
+//! every render function.
+
+use crate::config::Density;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// `true` when compact density is active, `false` for comfortable (default).
+static COMPACT: AtomicBool = AtomicBool::new(false);
+
+/// Comfortable row height in pixels (default density).
+const COMFORTABLE_LIST_ITEM_HEIGHT: f32 = 48.0;
+/// Compact row height in pixels.
+const COMPACT_LIST_ITEM_HEIGHT: f32 = 36.0;
+
+/// Comfortable section header height in pixels.
+const COMFORTABLE_SECTION_HEADER_HEIGHT: f32 = 24.0;
+/// Compact section header height in pixels.
+const COMPACT_SECTION_HEADER_HEIGHT: f32 = 18.0;
+
+/// Set the current density, e.g. from loaded config at startup.
+pub fn set_density(density: Density) {
+    COMPACT.store(density == Density::Compact, Ordering::SeqCst);
+}
+
+/// Get the current density.
+pub fn get_density() -> Density {
+    if COMPACT.load(Ordering::SeqCst) {
+        Density::Compact
+    } else {
+        Density::Comfortable
+    }
+}
+
+/// Toggle between comfortable and compact density, returning the new value.
+pub fn toggle_density() -> Density {
+    let new_compact = !COMPACT.load(Ordering::SeqCst);
+    COMPACT.store(new_compact, Ordering::SeqCst);
+    get_density()
+}
+
+/// Row height for a single list item, in pixels, at the current density.
+pub fn list_item_height() -> f32 {
+    if COMPACT.load(Ordering::SeqCst) {
+        COMPACT_LIST_ITEM_HEIGHT
+    } else {
+        COMFORTABLE_LIST_ITEM_HEIGHT
+    }
+}
+
+/// Section header height, in pixels, at the current density.
+pub fn section_header_height() -> f32 {
+    if COMPACT.load(Ordering::SeqCst) {
+        COMPACT_SECTION_HEADER_HEIGHT
+    } else {
+        COMFORTABLE_SECTION_HEADER_HEIGHT
+    }
+}
@@ -0,0 +1,205 @@
+//! Temporary, in-memory overrides for design tokens
+//!
+//! The design gallery lets a user tweak a token's color and see the result
+//! immediately, without writing anything to `~/.scriptkit/theme.json`. This
+//! module is the storage for those overrides: a simple name -> hex color map
+//! that the gallery consults before falling back to the active design's own
+//! token value.
+
+use std::collections::HashMap;
+
+/// A set of token name -> hex color overrides, keyed by the token's name
+/// (e.g. `"background"`, `"accent"`).
+///
+/// Overrides are additive: setting a token twice replaces the previous
+/// value, and the set can be reset back to empty without affecting the
+/// design the overrides were layered on top of.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TokenOverrides {
+    values: HashMap<String, String>,
+}
+
+impl TokenOverrides {
+    /// Create an empty override set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// True if no tokens have been overridden.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Number of tokens currently overridden.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Set (or replace) the override for a token.
+    pub fn set(&mut self, token: impl Into<String>, hex_color: impl Into<String>) {
+        self.values.insert(token.into(), hex_color.into());
+    }
+
+    /// Remove the override for a single token, if any.
+    pub fn remove(&mut self, token: &str) {
+        self.values.remove(token);
+    }
+
+    /// Clear every override, reverting the gallery to the design's own tokens.
+    pub fn reset(&mut self) {
+        self.values.clear();
+    }
+
+    /// Resolve a token's effective value: the override if one is set,
+    /// otherwise the design's own `base_value`.
+    pub fn resolve<'a>(&'a self, token: &str, base_value: &'a str) -> &'a str {
+        self.values
+            .get(token)
+            .map(|v| v.as_str())
+            .unwrap_or(base_value)
+    }
+
+    /// Merge another set of overrides on top of this one. Tokens present in
+    /// `other` replace this set's value for the same token; tokens only
+    /// present in `self` are left untouched.
+    pub fn merge(&mut self, other: &TokenOverrides) {
+        for (token, value) in &other.values {
+            self.values.insert(token.clone(), value.clone());
+        }
+    }
+
+    /// Iterate over the current overrides sorted by token name, for display
+    /// or export.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        let mut entries: Vec<(&str, &str)> = self
+            .values
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        entries.into_iter()
+    }
+
+    /// Render the current overrides as a `theme.json`-shaped snippet, suitable
+    /// for pasting directly into the user's theme file.
+    pub fn to_theme_json_snippet(&self) -> String {
+        if self.values.is_empty() {
+            return "{}".to_string();
+        }
+
+        let mut lines = Vec::with_capacity(self.values.len() + 2);
+        lines.push("{".to_string());
+        let entries: Vec<(&str, &str)> = self.iter().collect();
+        for (i, (token, value)) in entries.iter().enumerate() {
+            let comma = if i + 1 == entries.len() { "" } else { "," };
+            lines.push(format!("  \"{}\": \"{}\"{}", token, value, comma));
+        }
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_overrides_is_empty() {
+        let overrides = TokenOverrides::new();
+        assert!(overrides.is_empty());
+        assert_eq!(overrides.len(), 0);
+    }
+
+    #[test]
+    fn test_set_and_resolve_override() {
+        let mut overrides = TokenOverrides::new();
+        overrides.set("accent", "#ff0000");
+
+        assert_eq!(overrides.resolve("accent", "#000000"), "#ff0000");
+        assert_eq!(overrides.resolve("background", "#ffffff"), "#ffffff");
+    }
+
+    #[test]
+    fn test_set_replaces_existing_override() {
+        let mut overrides = TokenOverrides::new();
+        overrides.set("accent", "#ff0000");
+        overrides.set("accent", "#00ff00");
+
+        assert_eq!(overrides.resolve("accent", "#000000"), "#00ff00");
+        assert_eq!(overrides.len(), 1);
+    }
+
+    #[test]
+    fn test_remove_clears_single_token() {
+        let mut overrides = TokenOverrides::new();
+        overrides.set("accent", "#ff0000");
+        overrides.set("background", "#ffffff");
+
+        overrides.remove("accent");
+
+        assert_eq!(overrides.resolve("accent", "#000000"), "#000000");
+        assert_eq!(overrides.resolve("background", "#111111"), "#ffffff");
+        assert_eq!(overrides.len(), 1);
+    }
+
+    #[test]
+    fn test_reset_clears_all_overrides() {
+        let mut overrides = TokenOverrides::new();
+        overrides.set("accent", "#ff0000");
+        overrides.set("background", "#ffffff");
+
+        overrides.reset();
+
+        assert!(overrides.is_empty());
+        assert_eq!(overrides.resolve("accent", "#000000"), "#000000");
+    }
+
+    #[test]
+    fn test_merge_overwrites_shared_tokens_and_keeps_unique_ones() {
+        let mut base = TokenOverrides::new();
+        base.set("accent", "#ff0000");
+        base.set("background", "#ffffff");
+
+        let mut incoming = TokenOverrides::new();
+        incoming.set("accent", "#0000ff");
+        incoming.set("border", "#222222");
+
+        base.merge(&incoming);
+
+        assert_eq!(base.resolve("accent", ""), "#0000ff");
+        assert_eq!(base.resolve("background", ""), "#ffffff");
+        assert_eq!(base.resolve("border", ""), "#222222");
+        assert_eq!(base.len(), 3);
+    }
+
+    #[test]
+    fn test_merge_with_empty_overrides_is_a_noop() {
+        let mut base = TokenOverrides::new();
+        base.set("accent", "#ff0000");
+
+        base.merge(&TokenOverrides::new());
+
+        assert_eq!(base.len(), 1);
+        assert_eq!(base.resolve("accent", ""), "#ff0000");
+    }
+
+    #[test]
+    fn test_to_theme_json_snippet_empty() {
+        let overrides = TokenOverrides::new();
+        assert_eq!(overrides.to_theme_json_snippet(), "{}");
+    }
+
+    #[test]
+    fn test_to_theme_json_snippet_sorted_and_formatted() {
+        let mut overrides = TokenOverrides::new();
+        overrides.set("background", "#ffffff");
+        overrides.set("accent", "#ff0000");
+
+        let snippet = overrides.to_theme_json_snippet();
+
+        assert_eq!(
+            snippet,
+            "{\n  \"accent\": \"#ff0000\",\n  \"background\": \"#ffffff\"\n}"
+        );
+    }
+}
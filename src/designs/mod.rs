@@ -335,6 +335,7 @@ pub fn render_design_item(
     is_selected: bool,
     is_hovered: bool,
     list_colors: ListItemColors,
+    frecency_label: Option<String>,
 ) -> AnyElement {
     crate::logging::log_debug(
         "DESIGN",
@@ -453,6 +454,15 @@ pub fn render_design_item(
                         Some(IconKind::Svg(icon_name)),
                     )
                 }
+                SearchResult::RecentFile(rm) => {
+                    // Recent files get a generic File icon, path as description
+                    (
+                        rm.file.name.clone(),
+                        Some(rm.file.path.to_string_lossy().to_string()),
+                        None,
+                        Some(IconKind::Svg("File".to_string())),
+                    )
+                }
                 SearchResult::Fallback(fm) => {
                     // Fallback commands from "Use with..." section
                     // Map fallback icon names to SVG icons
@@ -480,6 +490,7 @@ pub fn render_design_item(
                 .icon_kind_opt(icon_kind)
                 .description_opt(description)
                 .shortcut_opt(shortcut)
+                .frecency_annotation_opt(frecency_label)
                 .selected(is_selected)
                 .hovered(is_hovered)
                 .with_accent_bar(true)
@@ -153,6 +153,10 @@ pub struct EditorPrompt {
 
     // Choice dropdown popup state (shown when tabstop has choices)
     choices_popup: Option<ChoicesPopupState>,
+
+    // When true, the editor displays content but rejects edits (used for
+    // read-only views like RunLogView; see `read_only_view`)
+    read_only: bool,
 }
 
 impl EditorPrompt {
@@ -201,9 +205,40 @@ impl EditorPrompt {
             choices_popup: None,
             needs_focus: true, // Auto-focus on first render
             needs_initial_tabstop_selection: false,
+            read_only: false,
         }
     }
 
+    /// Create a read-only EditorPrompt for viewing static content (e.g. a
+    /// script's last run log via `AppView::RunLogView`).
+    ///
+    /// Identical to `with_height` except the underlying `InputState` is
+    /// created disabled, so the content displays with full syntax
+    /// highlighting/search but cannot be edited. There is nothing to submit,
+    /// so `on_submit` is a no-op.
+    pub fn read_only_view(
+        id: String,
+        content: String,
+        language: String,
+        focus_handle: FocusHandle,
+        theme: Arc<Theme>,
+        config: Arc<Config>,
+        content_height: Option<gpui::Pixels>,
+    ) -> Self {
+        let mut prompt = Self::with_height(
+            id,
+            content,
+            language,
+            focus_handle,
+            Arc::new(|_id: String, _value: Option<String>| {}),
+            theme,
+            config,
+            content_height,
+        );
+        prompt.read_only = true;
+        prompt
+    }
+
     /// Create a new EditorPrompt in template/snippet mode
     ///
     /// Parses the template for VSCode-style tabstops and enables Tab/Shift+Tab navigation.
@@ -299,6 +334,7 @@ impl EditorPrompt {
             choices_popup: None,
             needs_focus: true, // Auto-focus on first render
             needs_initial_tabstop_selection: needs_initial_selection,
+            read_only: false,
         }
     }
 
@@ -325,6 +361,7 @@ impl EditorPrompt {
         // Create the gpui-component InputState in code_editor mode
         // Enable tab_navigation mode if we're in snippet mode (Tab moves between tabstops)
         let in_snippet = self.snippet_state.is_some();
+        let read_only = self.read_only;
         let editor_state = cx.new(|cx| {
             InputState::new(window, cx)
                 .code_editor(&pending.language) // Sets up syntax highlighting
@@ -333,6 +370,7 @@ impl EditorPrompt {
                 .soft_wrap(false) // Code should not wrap by default
                 .default_value(pending.content)
                 .tab_navigation(in_snippet) // Propagate Tab when in snippet mode
+                .disabled(read_only) // RunLogView etc: display-only, no edits
         });
 
         // Subscribe to editor changes
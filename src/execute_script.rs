@@ -1,21 +1,192 @@
+/// Deletes a temp file when dropped - used to clean up scratch files (e.g.
+/// scriptlet temp scripts) once the interactive session that owns them ends.
+struct TempFileCleanup(Option<std::path::PathBuf>);
+
+impl Drop for TempFileCleanup {
+    fn drop(&mut self) {
+        if let Some(path) = self.0.take() {
+            match std::fs::remove_file(&path) {
+                Ok(()) => {
+                    logging::log("EXEC", &format!("Removed temp file: {}", path.display()));
+                }
+                Err(e) => {
+                    logging::log(
+                        "EXEC",
+                        &format!("Failed to remove temp file {}: {}", path.display(), e),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Drain every `Message` already queued on `response_rx` behind `first`
+/// (non-blocking - only what's immediately available, no timers) so the
+/// writer thread can fold a burst into a single write+flush. A
+/// `Message::Flush` ends the batch it's drained into: anything queued after
+/// it starts a fresh batch on the writer thread's next loop iteration,
+/// giving callers that need a synchronous point a real write boundary there.
+fn drain_response_batch(first: Message, response_rx: &mpsc::Receiver<Message>) -> Vec<Message> {
+    let mut batch = vec![first];
+    if matches!(batch[0], Message::Flush {}) {
+        return batch;
+    }
+    while let Ok(next) = response_rx.try_recv() {
+        let is_flush = matches!(next, Message::Flush {});
+        batch.push(next);
+        if is_flush {
+            break;
+        }
+    }
+    batch
+}
+
+/// Serialize a batch of response messages into one newline-separated buffer
+/// for a single `write_all`, logging each message the same way the
+/// single-message path used to (and skipping - with a log line - any that
+/// fail to serialize, rather than losing the rest of the batch).
+/// Build the response for a request/response handler failure: a typed
+/// `Message::Error` when the session has opted in (see
+/// `Config::get_typed_errors_enabled`), otherwise the handler's legacy
+/// fallback message so older SDKs keep seeing the response shape they
+/// already expect.
+fn typed_error_or_fallback(
+    typed_errors_enabled: bool,
+    request_id: &str,
+    code: protocol::ErrorCode,
+    message: String,
+    recoverable: bool,
+    legacy: Message,
+) -> Message {
+    if typed_errors_enabled {
+        Message::error(request_id.to_string(), code, message, recoverable)
+    } else {
+        legacy
+    }
+}
+
+fn serialize_response_batch(batch: &[Message], fd: i32) -> String {
+    let mut buffer = String::new();
+    for response in batch {
+        match protocol::serialize_message(response) {
+            Ok(json) => {
+                // Use truncated logging to avoid full payload in logs
+                logging::log_protocol_send(fd, &json);
+                buffer.push_str(&json);
+                buffer.push('\n');
+            }
+            Err(e) => {
+                logging::log("EXEC", &format!("Failed to serialize: {}", e));
+            }
+        }
+    }
+    buffer
+}
+
 impl ScriptListApp {
-    fn execute_interactive(&mut self, script: &scripts::Script, cx: &mut Context<Self>) {
+    /// Start an interactive session for `script`.
+    ///
+    /// `cleanup_temp_file`, if set, is deleted once the reader thread exits
+    /// (i.e. once the session's process has ended) - used for scratch files
+    /// like scriptlet temp scripts that have no other owner.
+    fn execute_interactive(
+        &mut self,
+        script: &scripts::Script,
+        args: Vec<String>,
+        cleanup_temp_file: Option<std::path::PathBuf>,
+        cx: &mut Context<Self>,
+    ) {
+        if is_shutting_down() {
+            logging::log(
+                "EXEC",
+                &format!("Refusing to start {} - shutdown in progress", script.name),
+            );
+            return;
+        }
+
+        match concurrency_guard::CONCURRENCY_GUARD.gate(&script.path, script.concurrency) {
+            concurrency_guard::GateDecision::Start => {}
+            concurrency_guard::GateDecision::Blocked => {
+                logging::log(
+                    "EXEC",
+                    &format!(
+                        "Refusing to start {} - already running (concurrency: single)",
+                        script.name
+                    ),
+                );
+                self.show_hud(format!("{} is already running", script.name), None, cx);
+                return;
+            }
+            concurrency_guard::GateDecision::Queued => {
+                logging::log(
+                    "EXEC",
+                    &format!(
+                        "Queued launch of {} - already running (concurrency: queue)",
+                        script.name
+                    ),
+                );
+                self.show_hud(format!("{} queued", script.name), None, cx);
+                return;
+            }
+        }
+
         logging::log(
             "EXEC",
             &format!("Starting interactive execution: {}", script.name),
         );
 
+        self.pending_script_args = args.iter().cloned().collect();
+
         // Store script path for error reporting in reader thread
         let script_path_for_errors = script.path.to_string_lossy().to_string();
-
-        match executor::execute_script_interactive(&script.path) {
+        // Snapshot the script's parsed metadata so the reader thread can answer
+        // `getScriptMetadata` without reaching back into the UI thread.
+        let script_metadata_snapshot = executor::ScriptMetadataSnapshot::from_script(script);
+
+        // Per-run log file for this session - captures lifecycle events, stdout
+        // noise, and stderr independently of the interleaved global app log.
+        let run_logger = match executor::RunLogger::create(&script.name, executor::DEFAULT_MAX_RUNS)
+        {
+            Ok(logger) => Some(logger),
+            Err(e) => {
+                logging::log("EXEC", &format!("Failed to create per-run log: {}", e));
+                None
+            }
+        };
+        match executor::execute_script_interactive(&script.path, &args) {
             Ok(session) => {
                 logging::log("EXEC", "Interactive session started successfully");
 
                 // Store PID for explicit cleanup (belt-and-suspenders approach)
                 let pid = session.pid();
                 self.current_script_pid = Some(pid);
+                // Fresh token for this session - any clones held by the
+                // previous session's threads stay cancelled from their own
+                // teardown and can't affect this one.
+                self.current_script_cancellation = cancellation::CancellationToken::new();
+                let session_cancellation = self.current_script_cancellation.clone();
+                self.current_script_path = Some(script.path.clone());
+                self.current_script_keep_open = script.keep_open;
                 logging::log("EXEC", &format!("Stored script PID {} for cleanup", pid));
+                if let Some(ref logger) = run_logger {
+                    logger.lifecycle(&format!("started pid={}", pid));
+                }
+
+                if script.background {
+                    // Background scripts (watchers, servers, etc.) run detached and
+                    // never show prompt UI. Hide the window immediately but leave the
+                    // process and its PID tracking alone - it keeps running in
+                    // PROCESS_MANAGER until it exits or is explicitly killed.
+                    logging::log(
+                        "EXEC",
+                        &format!(
+                            "{} is a background script - hiding main window",
+                            script.name
+                        ),
+                    );
+                    script_kit_gpui::set_main_window_visible(false);
+                    platform::hide_main_window();
+                }
 
                 *self.script_session.lock() = Some(session);
 
@@ -66,8 +237,13 @@ impl ScriptListApp {
                 // which meant stderr was never available for error messages. Now we use
                 // spawn_stderr_reader which returns a StderrCapture containing both the buffer
                 // AND a JoinHandle so we can wait for stderr to fully drain before reading.
-                let stderr_capture = stderr_handle
-                    .map(|stderr| executor::spawn_stderr_reader(stderr, script_path_for_errors.clone()));
+                let stderr_capture = stderr_handle.map(|stderr| {
+                    executor::spawn_stderr_reader_with_run_log(
+                        stderr,
+                        script_path_for_errors.clone(),
+                        run_logger.clone(),
+                    )
+                });
 
                 // Move the capture into the reader thread - it owns both buffer and join handle
                 // The reader thread will wait for stderr to drain before reading contents
@@ -114,21 +290,37 @@ impl ScriptListApp {
 
                     loop {
                         match response_rx.recv() {
-                            Ok(response) => {
-                                let json = match protocol::serialize_message(&response) {
-                                    Ok(j) => j,
-                                    Err(e) => {
-                                        logging::log(
-                                            "EXEC",
-                                            &format!("Failed to serialize: {}", e),
-                                        );
-                                        continue;
-                                    }
-                                };
-                                // Use truncated logging to avoid full payload in logs
-                                logging::log_protocol_send(fd, &json);
-                                let bytes = format!("{}\n", json);
-                                let bytes_len = bytes.len();
+                            Ok(first) => {
+                                if session_cancellation.is_cancelled() {
+                                    // Session was torn down (cancelled, shut
+                                    // down, or already exited) after this
+                                    // response was queued - the pipe may be
+                                    // dead or about to be; drop it (and
+                                    // anything else already queued) quietly
+                                    // instead of writing and logging an error.
+                                    while response_rx.try_recv().is_ok() {}
+                                    logging::log(
+                                        "EXEC",
+                                        "Dropping response(s) for cancelled session",
+                                    );
+                                    continue;
+                                }
+
+                                // Greedily drain whatever else is already queued so a
+                                // burst (e.g. rapid setChoices updates) costs one
+                                // write+flush instead of one per message. Only drains
+                                // what's already available - no timers - so the
+                                // single-message case pays no extra latency. A
+                                // Message::Flush ends the batch it's drained into so
+                                // callers that need a synchronous point get a write
+                                // boundary there rather than being merged with
+                                // whatever arrives after it.
+                                let batch = drain_response_batch(first, &response_rx);
+                                let buffer = serialize_response_batch(&batch, fd);
+                                if buffer.is_empty() {
+                                    continue;
+                                }
+                                let bytes_len = buffer.len();
 
                                 // Check fd validity before write
                                 let fcntl_result = unsafe { libc::fcntl(fd, libc::F_GETFD) };
@@ -140,13 +332,15 @@ impl ScriptListApp {
                                     ),
                                 );
 
-                                match stdin.write_all(bytes.as_bytes()) {
+                                match stdin.write_all(buffer.as_bytes()) {
                                     Ok(()) => {
                                         logging::log(
                                             "EXEC",
                                             &format!(
-                                                "Write succeeded: {} bytes to fd={}",
-                                                bytes_len, fd
+                                                "Write succeeded: {} bytes ({} message(s)) to fd={}",
+                                                bytes_len,
+                                                batch.len(),
+                                                fd
                                             ),
                                         );
                                     }
@@ -185,19 +379,35 @@ impl ScriptListApp {
                 // CRITICAL: Move _process_handle and _child into this thread to keep them alive!
                 // When the reader thread exits, they'll be dropped and the process killed.
                 let script_path_clone = script_path_for_errors.clone();
+                let script_metadata_snapshot = script_metadata_snapshot.clone();
+                // Snapshot now - the reader thread outlives this method call and
+                // can't reach back into `self.config`.
+                let typed_errors_enabled = self.config.get_typed_errors_enabled();
                 std::thread::spawn(move || {
                     // These variables keep the process alive - they're dropped when the thread exits
                     let _keep_alive_handle = _process_handle;
                     let mut keep_alive_child = _child;
+                    // Deletes `cleanup_temp_file` (if any) once this thread exits, i.e.
+                    // once the session's process has ended.
+                    let _temp_file_cleanup = TempFileCleanup(cleanup_temp_file);
                     // FIX: Use the stderr capture which includes both buffer and join handle
                     // The buffer is populated by the stderr reader thread, and we wait for it
                     // to complete (with timeout) before reading to prevent partial captures.
                     let stderr_capture = stderr_capture;
                     let script_path = script_path_clone;
+                    let run_logger = run_logger;
 
                     loop {
                         // Use next_message_graceful_with_handler to skip non-JSON lines and report parse issues
                         match stdout_reader.next_message_graceful_with_handler(|issue| {
+                            // Tee every skipped non-JSONL line into the per-run log, regardless
+                            // of whether it's reported to the UI as a toast below.
+                            if !issue.raw_preview.is_empty() {
+                                if let Some(ref logger) = run_logger {
+                                    logger.write_line("STDOUT", &issue.raw_preview);
+                                }
+                            }
+
                             let should_report = matches!(
                                 issue.kind,
                                 protocol::ParseIssueKind::InvalidPayload
@@ -294,6 +504,55 @@ impl ScriptListApp {
                                     }
                                 }
 
+                                // Try script metadata requests (also no UI needed)
+                                match executor::handle_script_metadata_message(
+                                    &msg,
+                                    &script_metadata_snapshot,
+                                ) {
+                                    executor::ScriptMetadataHandleResult::Handled(response) => {
+                                        logging::log("EXEC", &format!("Handled script metadata message, sending response: {:?}", response));
+                                        if let Err(e) = reader_response_tx.send(response) {
+                                            logging::log(
+                                                "EXEC",
+                                                &format!(
+                                                    "Failed to send script metadata response: {}",
+                                                    e
+                                                ),
+                                            );
+                                        }
+                                        continue;
+                                    }
+                                    executor::ScriptMetadataHandleResult::NotHandled => {
+                                        // Fall through to other message handling
+                                    }
+                                }
+
+                                // Next, try hotkey registration messages (also no UI needed)
+                                match executor::handle_hotkey_message(
+                                    &msg,
+                                    reader_response_tx.clone(),
+                                ) {
+                                    executor::HotkeyMessageHandleResult::Handled(response) => {
+                                        logging::log(
+                                            "EXEC",
+                                            &format!(
+                                                "Handled hotkey message, sending response: {:?}",
+                                                response
+                                            ),
+                                        );
+                                        if let Err(e) = reader_response_tx.send(response) {
+                                            logging::log(
+                                                "EXEC",
+                                                &format!("Failed to send hotkey response: {}", e),
+                                            );
+                                        }
+                                        continue;
+                                    }
+                                    executor::HotkeyMessageHandleResult::NotHandled => {
+                                        // Fall through to other message handling
+                                    }
+                                }
+
                                 // Handle ClipboardHistory directly (no UI needed)
                                 if let Message::ClipboardHistory {
                                     request_id,
@@ -306,124 +565,26 @@ impl ScriptListApp {
                                         &format!("ClipboardHistory request: {:?}", action),
                                     );
 
-                                    let response = match action {
-                                        protocol::ClipboardHistoryAction::List => {
-                                            let entries =
-                                                clipboard_history::get_clipboard_history(100);
-                                            let entry_data: Vec<protocol::ClipboardHistoryEntryData> = entries
-                                                .into_iter()
-                                                .map(|e| {
-                                                    // Truncate large content to avoid pipe buffer issues
-                                                    // Images are stored as base64 which can be huge
-                                                    let content = match e.content_type {
-                                                        clipboard_history::ContentType::Image => {
-                                                            // For images, send a placeholder with metadata
-                                                            format!("[image:{}]", e.id)
-                                                        }
-                                                        clipboard_history::ContentType::Text => {
-                                                            // Truncate very long text entries
-                                                            if e.content.len() > 1000 {
-                                                                format!("{}...", &e.content[..1000])
-                                                            } else {
-                                                                e.content
-                                                            }
-                                                        }
-                                                    };
-                                                    protocol::ClipboardHistoryEntryData {
-                                                        entry_id: e.id,
-                                                        content,
-                                                        content_type: match e.content_type {
-                                                            clipboard_history::ContentType::Text => protocol::ClipboardEntryType::Text,
-                                                            clipboard_history::ContentType::Image => protocol::ClipboardEntryType::Image,
-                                                        },
-                                                        timestamp: chrono::DateTime::from_timestamp(e.timestamp, 0)
-                                                            .map(|dt| dt.to_rfc3339())
-                                                            .unwrap_or_default(),
-                                                        pinned: e.pinned,
-                                                    }
-                                                })
-                                                .collect();
-                                            Message::clipboard_history_list_response(
-                                                request_id.clone(),
-                                                entry_data,
-                                            )
-                                        }
-                                        protocol::ClipboardHistoryAction::Pin => {
-                                            if let Some(id) = entry_id {
-                                                match clipboard_history::pin_entry(id) {
-                                                    Ok(()) => Message::clipboard_history_success(
-                                                        request_id.clone(),
-                                                    ),
-                                                    Err(e) => Message::clipboard_history_error(
-                                                        request_id.clone(),
-                                                        e.to_string(),
-                                                    ),
-                                                }
-                                            } else {
-                                                Message::clipboard_history_error(
-                                                    request_id.clone(),
-                                                    "Missing entry_id".to_string(),
-                                                )
-                                            }
-                                        }
-                                        protocol::ClipboardHistoryAction::Unpin => {
-                                            if let Some(id) = entry_id {
-                                                match clipboard_history::unpin_entry(id) {
-                                                    Ok(()) => Message::clipboard_history_success(
-                                                        request_id.clone(),
-                                                    ),
-                                                    Err(e) => Message::clipboard_history_error(
-                                                        request_id.clone(),
-                                                        e.to_string(),
-                                                    ),
-                                                }
-                                            } else {
-                                                Message::clipboard_history_error(
-                                                    request_id.clone(),
-                                                    "Missing entry_id".to_string(),
-                                                )
-                                            }
-                                        }
-                                        protocol::ClipboardHistoryAction::Remove => {
-                                            if let Some(id) = entry_id {
-                                                match clipboard_history::remove_entry(id) {
-                                                    Ok(()) => Message::clipboard_history_success(
-                                                        request_id.clone(),
-                                                    ),
-                                                    Err(e) => Message::clipboard_history_error(
-                                                        request_id.clone(),
-                                                        e.to_string(),
-                                                    ),
-                                                }
+                                    let response = message_dispatch::dispatch_clipboard_history(
+                                        &message_dispatch::LiveClipboardHistory,
+                                        request_id,
+                                        action,
+                                        entry_id,
+                                    );
+                                    let response = match response {
+                                        Message::ClipboardHistoryResult {
+                                            request_id,
+                                            success: false,
+                                            error: Some(error),
+                                        } if typed_errors_enabled => {
+                                            let code = if error == "Missing entry_id" {
+                                                protocol::ErrorCode::InvalidArgument
                                             } else {
-                                                Message::clipboard_history_error(
-                                                    request_id.clone(),
-                                                    "Missing entry_id".to_string(),
-                                                )
-                                            }
-                                        }
-                                        protocol::ClipboardHistoryAction::Clear => {
-                                            match clipboard_history::clear_history() {
-                                                Ok(()) => Message::clipboard_history_success(
-                                                    request_id.clone(),
-                                                ),
-                                                Err(e) => Message::clipboard_history_error(
-                                                    request_id.clone(),
-                                                    e.to_string(),
-                                                ),
-                                            }
-                                        }
-                                        protocol::ClipboardHistoryAction::TrimOversize => {
-                                            match clipboard_history::trim_oversize_text_entries() {
-                                                Ok(_) => Message::clipboard_history_success(
-                                                    request_id.clone(),
-                                                ),
-                                                Err(e) => Message::clipboard_history_error(
-                                                    request_id.clone(),
-                                                    e.to_string(),
-                                                ),
-                                            }
+                                                protocol::ErrorCode::Internal
+                                            };
+                                            Message::error(request_id, code, error, true)
                                         }
+                                        other => other,
                                     };
 
                                     if let Err(e) = reader_response_tx.send(response) {
@@ -485,26 +646,37 @@ impl ScriptListApp {
                                                                     value: Some(text),
                                                                 },
                                                                 Err(e) => {
-                                                                    logging::log("EXEC", &format!("Clipboard read error: {}", e));
-                                                                    Message::Submit {
-                                                                        id: req_id,
-                                                                        value: Some(String::new()),
-                                                                    }
+                                                                    let message = format!("Clipboard read error: {}", e);
+                                                                    logging::log("EXEC", &message);
+                                                                    typed_error_or_fallback(
+                                                                        typed_errors_enabled,
+                                                                        &req_id,
+                                                                        protocol::ErrorCode::Internal,
+                                                                        message,
+                                                                        true,
+                                                                        Message::Submit {
+                                                                            id: req_id.clone(),
+                                                                            value: Some(String::new()),
+                                                                        },
+                                                                    )
                                                                 }
                                                             }
                                                         }
                                                         Err(e) => {
-                                                            logging::log(
-                                                                "EXEC",
-                                                                &format!(
-                                                                    "Clipboard init error: {}",
-                                                                    e
-                                                                ),
-                                                            );
-                                                            Message::Submit {
-                                                                id: req_id,
-                                                                value: Some(String::new()),
-                                                            }
+                                                            let message =
+                                                                format!("Clipboard init error: {}", e);
+                                                            logging::log("EXEC", &message);
+                                                            typed_error_or_fallback(
+                                                                typed_errors_enabled,
+                                                                &req_id,
+                                                                protocol::ErrorCode::Internal,
+                                                                message,
+                                                                true,
+                                                                Message::Submit {
+                                                                    id: req_id.clone(),
+                                                                    value: Some(String::new()),
+                                                                },
+                                                            )
                                                         }
                                                     }
                                                 }
@@ -524,26 +696,37 @@ impl ScriptListApp {
                                                                     }
                                                                 }
                                                                 Err(e) => {
-                                                                    logging::log("EXEC", &format!("Clipboard read image error: {}", e));
-                                                                    Message::Submit {
-                                                                        id: req_id,
-                                                                        value: Some(String::new()),
-                                                                    }
+                                                                    let message = format!("Clipboard read image error: {}", e);
+                                                                    logging::log("EXEC", &message);
+                                                                    typed_error_or_fallback(
+                                                                        typed_errors_enabled,
+                                                                        &req_id,
+                                                                        protocol::ErrorCode::Internal,
+                                                                        message,
+                                                                        true,
+                                                                        Message::Submit {
+                                                                            id: req_id.clone(),
+                                                                            value: Some(String::new()),
+                                                                        },
+                                                                    )
                                                                 }
                                                             }
                                                         }
                                                         Err(e) => {
-                                                            logging::log(
-                                                                "EXEC",
-                                                                &format!(
-                                                                    "Clipboard init error: {}",
-                                                                    e
-                                                                ),
-                                                            );
-                                                            Message::Submit {
-                                                                id: req_id,
-                                                                value: Some(String::new()),
-                                                            }
+                                                            let message =
+                                                                format!("Clipboard init error: {}", e);
+                                                            logging::log("EXEC", &message);
+                                                            typed_error_or_fallback(
+                                                                typed_errors_enabled,
+                                                                &req_id,
+                                                                protocol::ErrorCode::Internal,
+                                                                message,
+                                                                true,
+                                                                Message::Submit {
+                                                                    id: req_id.clone(),
+                                                                    value: Some(String::new()),
+                                                                },
+                                                            )
                                                         }
                                                     }
                                                 }
@@ -564,39 +747,57 @@ impl ScriptListApp {
                                                                 }
                                                             }
                                                             Err(e) => {
-                                                                logging::log(
-                                                                    "EXEC",
-                                                                    &format!(
-                                                                        "Clipboard write error: {}",
-                                                                        e
-                                                                    ),
+                                                                let message = format!(
+                                                                    "Clipboard write error: {}",
+                                                                    e
                                                                 );
-                                                                Message::Submit {
-                                                                    id: req_id,
-                                                                    value: Some(String::new()),
-                                                                }
+                                                                logging::log("EXEC", &message);
+                                                                typed_error_or_fallback(
+                                                                    typed_errors_enabled,
+                                                                    &req_id,
+                                                                    protocol::ErrorCode::Internal,
+                                                                    message,
+                                                                    true,
+                                                                    Message::Submit {
+                                                                        id: req_id.clone(),
+                                                                        value: Some(String::new()),
+                                                                    },
+                                                                )
                                                             }
                                                         }
                                                     } else {
-                                                        logging::log(
-                                                            "EXEC",
-                                                            "Clipboard write: no content provided",
-                                                        );
-                                                        Message::Submit {
-                                                            id: req_id,
-                                                            value: Some(String::new()),
-                                                        }
+                                                        let message =
+                                                            "Clipboard write: no content provided"
+                                                                .to_string();
+                                                        logging::log("EXEC", &message);
+                                                        typed_error_or_fallback(
+                                                            typed_errors_enabled,
+                                                            &req_id,
+                                                            protocol::ErrorCode::InvalidArgument,
+                                                            message,
+                                                            false,
+                                                            Message::Submit {
+                                                                id: req_id.clone(),
+                                                                value: Some(String::new()),
+                                                            },
+                                                        )
                                                     }
                                                 }
                                                 Err(e) => {
-                                                    logging::log(
-                                                        "EXEC",
-                                                        &format!("Clipboard init error: {}", e),
-                                                    );
-                                                    Message::Submit {
-                                                        id: req_id,
-                                                        value: Some(String::new()),
-                                                    }
+                                                    let message =
+                                                        format!("Clipboard init error: {}", e);
+                                                    logging::log("EXEC", &message);
+                                                    typed_error_or_fallback(
+                                                        typed_errors_enabled,
+                                                        &req_id,
+                                                        protocol::ErrorCode::Internal,
+                                                        message,
+                                                        true,
+                                                        Message::Submit {
+                                                            id: req_id.clone(),
+                                                            value: Some(String::new()),
+                                                        },
+                                                    )
                                                 }
                                             }
                                         }
@@ -645,12 +846,21 @@ impl ScriptListApp {
                                             )
                                         }
                                         Err(e) => {
-                                            logging::log(
-                                                "EXEC",
-                                                &format!("WindowList error: {}", e),
-                                            );
-                                            // Return empty list on error
-                                            Message::window_list_result(request_id.clone(), vec![])
+                                            let message = format!("WindowList error: {}", e);
+                                            logging::log("EXEC", &message);
+                                            // Return empty list on error (legacy); typed-error
+                                            // sessions get a real failure instead.
+                                            typed_error_or_fallback(
+                                                typed_errors_enabled,
+                                                request_id,
+                                                protocol::ErrorCode::Internal,
+                                                message,
+                                                true,
+                                                Message::window_list_result(
+                                                    request_id.clone(),
+                                                    vec![],
+                                                ),
+                                            )
                                         }
                                     };
 
@@ -730,10 +940,25 @@ impl ScriptListApp {
                                         Ok(()) => {
                                             Message::window_action_success(request_id.clone())
                                         }
-                                        Err(e) => Message::window_action_error(
-                                            request_id.clone(),
-                                            e.to_string(),
-                                        ),
+                                        Err(e) => {
+                                            let message = e.to_string();
+                                            let code = if message.starts_with("Missing") {
+                                                protocol::ErrorCode::InvalidArgument
+                                            } else {
+                                                protocol::ErrorCode::NotFound
+                                            };
+                                            typed_error_or_fallback(
+                                                typed_errors_enabled,
+                                                request_id,
+                                                code,
+                                                message.clone(),
+                                                false,
+                                                Message::window_action_error(
+                                                    request_id.clone(),
+                                                    message,
+                                                ),
+                                            )
+                                        }
                                     };
 
                                     if let Err(e) = reader_response_tx.send(response) {
@@ -807,7 +1032,7 @@ impl ScriptListApp {
                                     );
 
                                     #[cfg(target_os = "macos")]
-                                    let bounds_json = {
+                                    let bounds_result: Result<String, (protocol::ErrorCode, String)> = {
                                         if let Some(window) = window_manager::get_main_window() {
                                             unsafe {
                                                 // Get the window frame
@@ -835,30 +1060,52 @@ impl ScriptListApp {
                                                 ));
 
                                                 // Create JSON string with bounds
-                                                format!(
+                                                Ok(format!(
                                                     r#"{{"x":{},"y":{},"width":{},"height":{}}}"#,
                                                     frame.origin.x as f64,
                                                     flipped_y as f64,
                                                     frame.size.width as f64,
                                                     frame.size.height as f64
-                                                )
+                                                ))
                                             }
                                         } else {
                                             logging::log(
                                                 "EXEC",
                                                 "GetWindowBounds: Main window not registered",
                                             );
-                                            r#"{"error":"Main window not found"}"#.to_string()
+                                            Err((
+                                                protocol::ErrorCode::NotFound,
+                                                "Main window not found".to_string(),
+                                            ))
                                         }
                                     };
 
                                     #[cfg(not(target_os = "macos"))]
-                                    let bounds_json =
-                                        r#"{"error":"Not supported on this platform"}"#.to_string();
-
-                                    let response = Message::Submit {
-                                        id: request_id.clone(),
-                                        value: Some(bounds_json),
+                                    let bounds_result: Result<String, (protocol::ErrorCode, String)> =
+                                        Err((
+                                            protocol::ErrorCode::Internal,
+                                            "Not supported on this platform".to_string(),
+                                        ));
+
+                                    let response = match bounds_result {
+                                        Ok(bounds_json) => Message::Submit {
+                                            id: request_id.clone(),
+                                            value: Some(bounds_json),
+                                        },
+                                        Err((code, error)) => typed_error_or_fallback(
+                                            typed_errors_enabled,
+                                            request_id,
+                                            code,
+                                            error.clone(),
+                                            false,
+                                            Message::Submit {
+                                                id: request_id.clone(),
+                                                value: Some(format!(
+                                                    r#"{{"error":"{}"}}"#,
+                                                    error
+                                                )),
+                                            },
+                                        ),
                                     };
                                     logging::log(
                                         "EXEC",
@@ -915,44 +1162,128 @@ impl ScriptListApp {
                                 }
 
                                 // Handle CaptureScreenshot directly (no UI needed)
-                                if let Message::CaptureScreenshot { request_id, hi_dpi } = &msg {
+                                if let Message::CaptureScreenshot {
+                                    request_id,
+                                    hi_dpi,
+                                    target,
+                                    display_index,
+                                    window_id,
+                                    region,
+                                } = &msg
+                                {
                                     let hi_dpi_mode = hi_dpi.unwrap_or(false);
-                                    tracing::info!(request_id = %request_id, hi_dpi = hi_dpi_mode, "Capturing screenshot");
-
-                                    let response = match capture_app_screenshot(hi_dpi_mode) {
-                                        Ok((png_data, width, height)) => {
-                                            use base64::Engine;
-                                            let base64_data =
-                                                base64::engine::general_purpose::STANDARD
-                                                    .encode(&png_data);
-                                            tracing::info!(
-                                                request_id = %request_id,
-                                                width = width,
-                                                height = height,
-                                                hi_dpi = hi_dpi_mode,
-                                                data_len = base64_data.len(),
-                                                "Screenshot captured successfully"
-                                            );
-                                            Message::screenshot_result(
-                                                request_id.clone(),
-                                                base64_data,
-                                                width,
-                                                height,
-                                            )
-                                        }
-                                        Err(e) => {
-                                            tracing::error!(
-                                                request_id = %request_id,
-                                                error = %e,
-                                                "Screenshot capture failed"
-                                            );
-                                            // Send empty result on error
-                                            Message::screenshot_result(
-                                                request_id.clone(),
-                                                String::new(),
-                                                0,
-                                                0,
-                                            )
+                                    let target = target.clone().unwrap_or_default();
+                                    tracing::info!(
+                                        request_id = %request_id,
+                                        hi_dpi = hi_dpi_mode,
+                                        target = ?target,
+                                        "Capturing screenshot"
+                                    );
+
+                                    let response = if !matches!(target, protocol::ScreenshotTarget::App)
+                                        && !crate::permissions_wizard::check_screen_recording_permission()
+                                    {
+                                        tracing::warn!(
+                                            request_id = %request_id,
+                                            target = ?target,
+                                            "Screenshot capture denied: screen recording permission not granted"
+                                        );
+                                        let message = "Screen recording permission not granted. \
+                                             Grant it in System Settings > Privacy & Security > \
+                                             Screen Recording, then restart Script Kit."
+                                            .to_string();
+                                        typed_error_or_fallback(
+                                            typed_errors_enabled,
+                                            request_id,
+                                            protocol::ErrorCode::PermissionDenied,
+                                            message.clone(),
+                                            true,
+                                            Message::screenshot_error(request_id.clone(), message),
+                                        )
+                                    } else {
+                                        let capture_result = match &target {
+                                            protocol::ScreenshotTarget::App => {
+                                                capture_app_screenshot(hi_dpi_mode)
+                                            }
+                                            protocol::ScreenshotTarget::Display => {
+                                                match display_index {
+                                                    Some(idx) => platform::capture_display_screenshot(
+                                                        *idx as usize,
+                                                        hi_dpi_mode,
+                                                    ),
+                                                    None => Err("Missing displayIndex for target: \"display\"".into()),
+                                                }
+                                            }
+                                            protocol::ScreenshotTarget::Window => match window_id {
+                                                Some(id) => platform::capture_window_by_system_id(
+                                                    *id, hi_dpi_mode,
+                                                ),
+                                                None => Err("Missing windowId for target: \"window\"".into()),
+                                            },
+                                            protocol::ScreenshotTarget::Region => {
+                                                match (display_index, region) {
+                                                    (Some(idx), Some(r)) => {
+                                                        platform::capture_region_screenshot(
+                                                            *idx as usize,
+                                                            r.x,
+                                                            r.y,
+                                                            r.width,
+                                                            r.height,
+                                                            hi_dpi_mode,
+                                                        )
+                                                    }
+                                                    _ => Err(
+                                                        "Missing displayIndex or region for target: \"region\"".into(),
+                                                    ),
+                                                }
+                                            }
+                                        };
+
+                                        match capture_result {
+                                            Ok((png_data, width, height)) => {
+                                                use base64::Engine;
+                                                let base64_data =
+                                                    base64::engine::general_purpose::STANDARD
+                                                        .encode(&png_data);
+                                                tracing::info!(
+                                                    request_id = %request_id,
+                                                    width = width,
+                                                    height = height,
+                                                    hi_dpi = hi_dpi_mode,
+                                                    data_len = base64_data.len(),
+                                                    "Screenshot captured successfully"
+                                                );
+                                                Message::screenshot_result(
+                                                    request_id.clone(),
+                                                    base64_data,
+                                                    width,
+                                                    height,
+                                                )
+                                            }
+                                            Err(e) => {
+                                                tracing::error!(
+                                                    request_id = %request_id,
+                                                    error = %e,
+                                                    "Screenshot capture failed"
+                                                );
+                                                let message = e.to_string();
+                                                let code = if message.starts_with("Missing") {
+                                                    protocol::ErrorCode::InvalidArgument
+                                                } else {
+                                                    protocol::ErrorCode::Internal
+                                                };
+                                                typed_error_or_fallback(
+                                                    typed_errors_enabled,
+                                                    request_id,
+                                                    code,
+                                                    message.clone(),
+                                                    false,
+                                                    Message::screenshot_error(
+                                                        request_id.clone(),
+                                                        message,
+                                                    ),
+                                                )
+                                            }
                                         }
                                     };
 
@@ -968,11 +1299,17 @@ impl ScriptListApp {
                                         placeholder,
                                         choices,
                                         actions,
+                                        timeout_ms,
+                                        choices_cmd,
+                                        footer_hints,
                                     } => Some(PromptMessage::ShowArg {
                                         id,
                                         placeholder,
                                         choices,
                                         actions,
+                                        timeout_ms,
+                                        choices_cmd,
+                                        footer_hints,
                                     }),
                                     Message::Div {
                                         id,
@@ -985,6 +1322,7 @@ impl ScriptListApp {
                                         container_bg,
                                         container_padding,
                                         opacity,
+                                        footer_hints,
                                     } => Some(PromptMessage::ShowDiv {
                                         id,
                                         html,
@@ -996,6 +1334,22 @@ impl ScriptListApp {
                                         container_bg,
                                         container_padding,
                                         opacity,
+                                        footer_hints,
+                                    }),
+                                    Message::Split {
+                                        id,
+                                        placeholder,
+                                        choices,
+                                        preview,
+                                        actions,
+                                        footer_hints,
+                                    } => Some(PromptMessage::ShowSplit {
+                                        id,
+                                        placeholder,
+                                        choices,
+                                        preview,
+                                        actions,
+                                        footer_hints,
                                     }),
                                     Message::Form { id, html, actions } => {
                                         Some(PromptMessage::ShowForm { id, html, actions })
@@ -1003,10 +1357,16 @@ impl ScriptListApp {
                                     Message::Term {
                                         id,
                                         command,
+                                        shell,
+                                        cwd,
+                                        login,
                                         actions,
                                     } => Some(PromptMessage::ShowTerm {
                                         id,
                                         command,
+                                        shell,
+                                        cwd,
+                                        login,
                                         actions,
                                     }),
                                     Message::Editor {
@@ -1015,6 +1375,7 @@ impl ScriptListApp {
                                         language,
                                         template,
                                         actions,
+                                        footer_hints,
                                         ..
                                     } => Some(PromptMessage::ShowEditor {
                                         id,
@@ -1022,6 +1383,7 @@ impl ScriptListApp {
                                         language,
                                         template,
                                         actions,
+                                        footer_hints,
                                     }),
                                     // New prompt types (scaffolding)
                                     Message::Path {
@@ -1033,14 +1395,21 @@ impl ScriptListApp {
                                         start_path,
                                         hint,
                                     }),
-                                    Message::Env { id, key, secret } => {
-                                        Some(PromptMessage::ShowEnv {
-                                            id,
-                                            key,
-                                            prompt: None,
-                                            secret: secret.unwrap_or(false),
-                                        })
-                                    }
+                                    Message::Env {
+                                        id,
+                                        key,
+                                        secret,
+                                        placeholder,
+                                        pattern,
+                                        multiline,
+                                    } => Some(PromptMessage::ShowEnv {
+                                        id,
+                                        key,
+                                        prompt: placeholder,
+                                        secret: secret.unwrap_or(false),
+                                        pattern,
+                                        multiline: multiline.unwrap_or(false),
+                                    }),
                                     Message::Drop { id } => Some(PromptMessage::ShowDrop {
                                         id,
                                         placeholder: None,
@@ -1049,27 +1418,98 @@ impl ScriptListApp {
                                     Message::Template { id, template } => {
                                         Some(PromptMessage::ShowTemplate { id, template })
                                     }
+                                    Message::Confirm {
+                                        id,
+                                        title,
+                                        message,
+                                        ok_label,
+                                        cancel_label,
+                                        destructive,
+                                    } => Some(PromptMessage::ShowConfirm {
+                                        id,
+                                        title,
+                                        message,
+                                        ok_label,
+                                        cancel_label,
+                                        destructive: destructive.unwrap_or(false),
+                                    }),
                                     Message::Select {
                                         id,
                                         placeholder,
                                         choices,
                                         multiple,
+                                        max,
                                     } => Some(PromptMessage::ShowSelect {
                                         id,
                                         placeholder: Some(placeholder),
                                         choices,
                                         multiple: multiple.unwrap_or(false),
+                                        max,
                                     }),
-                                    Message::Exit { .. } => Some(PromptMessage::ScriptExit),
+                                    Message::Exit { value, .. } => {
+                                        Some(PromptMessage::ScriptExit { value })
+                                    }
                                     Message::ForceSubmit { value } => {
                                         Some(PromptMessage::ForceSubmit { value })
                                     }
                                     Message::Hide {} => Some(PromptMessage::HideWindow),
+                                    Message::Focus {} => Some(PromptMessage::FocusWindow),
+                                    Message::SetTheme { name } => {
+                                        Some(PromptMessage::SetTheme { name })
+                                    }
                                     Message::Browse { url } => {
                                         Some(PromptMessage::OpenBrowser { url })
                                     }
-                                    Message::Hud { text, duration_ms } => {
-                                        Some(PromptMessage::ShowHud { text, duration_ms })
+                                    Message::OpenPath { path } => {
+                                        Some(PromptMessage::OpenPath { path })
+                                    }
+                                    Message::Hud {
+                                        text,
+                                        duration_ms,
+                                        position,
+                                        id,
+                                    } => Some(PromptMessage::ShowHud {
+                                        text,
+                                        duration_ms,
+                                        position,
+                                        id,
+                                    }),
+                                    Message::UpdateHud {
+                                        id,
+                                        text,
+                                        duration_ms,
+                                    } => Some(PromptMessage::UpdateHud {
+                                        id,
+                                        text,
+                                        duration_ms,
+                                    }),
+                                    Message::Copy { text, hud } => {
+                                        use arboard::Clipboard;
+                                        match Clipboard::new() {
+                                            Ok(mut clipboard) => {
+                                                if let Err(e) = clipboard.set_text(text.clone()) {
+                                                    logging::log(
+                                                        "EXEC",
+                                                        &format!(
+                                                            "Copy clipboard write error: {}",
+                                                            e
+                                                        ),
+                                                    );
+                                                }
+                                            }
+                                            Err(e) => {
+                                                logging::log(
+                                                    "EXEC",
+                                                    &format!("Copy clipboard init error: {}", e),
+                                                );
+                                            }
+                                        }
+                                        hud.unwrap_or(false).then(|| PromptMessage::ShowHud {
+                                            text: "Copied".to_string(),
+                                            duration_ms: None,
+                                            position: None,
+                                            id: None,
+                                        })
                                     }
                                     Message::SetActions { actions } => {
                                         Some(PromptMessage::SetActions { actions })
@@ -1077,10 +1517,52 @@ impl ScriptListApp {
                                     Message::SetInput { text } => {
                                         Some(PromptMessage::SetInput { text })
                                     }
+                                    Message::SetPlaceholder { text } => {
+                                        Some(PromptMessage::SetPlaceholder { text })
+                                    }
+                                    Message::SetHint { text } => {
+                                        Some(PromptMessage::SetHint { text })
+                                    }
+                                    Message::SetPreview { html } => {
+                                        Some(PromptMessage::SetPreview { html })
+                                    }
+                                    Message::Preview { value, content } => {
+                                        Some(PromptMessage::Preview { value, content })
+                                    }
+                                    Message::PlaySound { name } => {
+                                        #[cfg(target_os = "macos")]
+                                        crate::sounds::play_sound(&name);
+                                        #[cfg(not(target_os = "macos"))]
+                                        let _ = name;
+                                        None
+                                    }
+                                    Message::SetFilter { text } => {
+                                        Some(PromptMessage::SetFilter { text })
+                                    }
+                                    // Flush is only meaningful going App -> script (a
+                                    // write-boundary marker for the response writer
+                                    // thread's batching); a script would have no reason
+                                    // to send one, so treat it as a no-op here too.
+                                    Message::Flush {} => None,
+                                    Message::SetPlaceholderChoices {
+                                        id,
+                                        choices,
+                                        loading,
+                                    } => Some(PromptMessage::SetPlaceholderChoices {
+                                        id,
+                                        choices,
+                                        loading,
+                                    }),
                                     Message::ShowGrid { options } => {
                                         Some(PromptMessage::ShowGrid { options })
                                     }
                                     Message::HideGrid => Some(PromptMessage::HideGrid),
+                                    Message::Widget { id, html, options } => {
+                                        Some(PromptMessage::ShowWidget { id, html, options })
+                                    }
+                                    Message::WidgetAction { id, action, state } => {
+                                        Some(PromptMessage::WidgetAction { id, action, state })
+                                    }
                                     other => {
                                         // Get the message type name for user feedback
                                         let msg_type = format!("{:?}", other);
@@ -1128,6 +1610,11 @@ impl ScriptListApp {
                                 };
 
                                 logging::log("EXEC", &format!("Script exit code: {:?}", exit_code));
+                                if let Some(ref logger) = run_logger {
+                                    logger.exit(exit_code);
+                                }
+                                let run_log_path =
+                                    run_logger.as_ref().map(|l| l.path().display().to_string());
 
                                 // If non-zero exit code, capture stderr and send error
                                 if let Some(code) = exit_code {
@@ -1138,7 +1625,11 @@ impl ScriptListApp {
                                         // 100ms timeout is generous - stderr should drain quickly.
                                         let stderr_output = stderr_capture
                                             .as_ref()
-                                            .map(|cap| cap.get_contents_with_timeout(std::time::Duration::from_millis(100)))
+                                            .map(|cap| {
+                                                cap.get_contents_with_timeout(
+                                                    std::time::Duration::from_millis(100),
+                                                )
+                                            })
                                             .filter(|s| !s.is_empty());
 
                                         if let Some(ref stderr_text) = stderr_output {
@@ -1168,6 +1659,7 @@ impl ScriptListApp {
                                                 stack_trace,
                                                 script_path: script_path.clone(),
                                                 suggestions,
+                                                log_path: run_log_path.clone(),
                                             });
                                         } else {
                                             // No stderr, send generic error
@@ -1183,21 +1675,31 @@ impl ScriptListApp {
                                                 suggestions: vec![
                                                     "Check the script for errors".to_string()
                                                 ],
+                                                log_path: run_log_path.clone(),
                                             });
                                         }
                                     }
                                 }
 
-                                let _ = tx.send_blocking(PromptMessage::ScriptExit);
+                                let _ = tx.send_blocking(PromptMessage::ScriptExit { value: None });
                                 break;
                             }
                             Err(e) => {
                                 logging::log("EXEC", &format!("Error reading from script: {}", e));
+                                if let Some(ref logger) = run_logger {
+                                    logger.lifecycle(&format!("stdout read error: {}", e));
+                                }
+                                let run_log_path =
+                                    run_logger.as_ref().map(|l| l.path().display().to_string());
 
                                 // FIX: Wait for stderr reader to complete before reading
                                 let stderr_output = stderr_capture
                                     .as_ref()
-                                    .map(|cap| cap.get_contents_with_timeout(std::time::Duration::from_millis(100)))
+                                    .map(|cap| {
+                                        cap.get_contents_with_timeout(
+                                            std::time::Duration::from_millis(100),
+                                        )
+                                    })
                                     .filter(|s| !s.is_empty());
 
                                 if let Some(ref stderr_text) = stderr_output {
@@ -1214,10 +1716,11 @@ impl ScriptListApp {
                                         stack_trace,
                                         script_path: script_path.clone(),
                                         suggestions,
+                                        log_path: run_log_path,
                                     });
                                 }
 
-                                let _ = tx.send_blocking(PromptMessage::ScriptExit);
+                                let _ = tx.send_blocking(PromptMessage::ScriptExit { value: None });
                                 break;
                             }
                         }
@@ -1236,6 +1739,22 @@ impl ScriptListApp {
                     "EXEC",
                     &format!("Failed to start interactive session: {}", e),
                 );
+                if let Some(ref logger) = run_logger {
+                    logger.lifecycle(&format!("failed to start: {}", e));
+                }
+                // The process never actually started, so release the
+                // concurrency slot we reserved in the gate check above and
+                // dispatch anything that got queued behind it.
+                if let Some(next_path) = concurrency_guard::CONCURRENCY_GUARD.finish(&script.path) {
+                    logging::log(
+                        "EXEC",
+                        &format!(
+                            "Dispatching queued launch for {:?} after failed start",
+                            next_path
+                        ),
+                    );
+                    self.execute_script_by_path(&next_path.to_string_lossy(), Vec::new(), cx);
+                }
                 self.last_output = Some(SharedString::from(format!("✗ Error: {}", e)));
                 cx.notify();
             }
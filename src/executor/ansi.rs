@@ -0,0 +1,320 @@
+//! ANSI escape code handling for script stderr output.
+//!
+//! Scripts frequently print ANSI SGR (color/style) escape sequences to
+//! stderr, since most CLI tools (test runners, linters, `chalk`/`picocolors`
+//! consumers, etc.) assume they're writing to a real terminal. Left alone,
+//! those raw escape sequences show up as garbage in the `SCRIPT` log target,
+//! the per-run log file, and the stack-trace/suggestion regexes in
+//! [`super::errors`].
+//!
+//! The interactive `term()` prompt (`crate::terminal::alacritty`) already
+//! parses ANSI via a full VTE-backed grid (`alacritty_terminal::Term`), but
+//! that machinery exists to emulate a live pty with cursor movement, an
+//! alt-screen, scrollback, etc. - none of which applies to an
+//! already-captured, single-line string with no cursor semantics. Rather
+//! than force stderr lines through a scratch pty-less grid just to read one
+//! row back out, this module implements a small, self-contained SGR parser
+//! that turns a line into plain text or styled spans directly.
+//!
+//! Coverage is intentionally scoped to what scripts actually emit: basic
+//! and bright foreground colors, bold/dim/italic/underline, and reset.
+//! 256-color and truecolor codes (`38;5;n` / `38;2;r;g;b`, and their `48;`
+//! background equivalents) are recognized and their parameters are consumed
+//! correctly so they don't desync the rest of the sequence, but they aren't
+//! resolved to a specific color - callers get [`AnsiColor::Default`]
+//! instead. Backgrounds aren't modeled at all, since log lines render on a
+//! single app-controlled background.
+
+/// One of the 16 standard ANSI foreground colors, or the terminal default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnsiColor {
+    #[default]
+    Default,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+}
+
+impl AnsiColor {
+    fn from_basic(n: i32) -> Self {
+        match n {
+            0 => AnsiColor::Black,
+            1 => AnsiColor::Red,
+            2 => AnsiColor::Green,
+            3 => AnsiColor::Yellow,
+            4 => AnsiColor::Blue,
+            5 => AnsiColor::Magenta,
+            6 => AnsiColor::Cyan,
+            7 => AnsiColor::White,
+            _ => AnsiColor::Default,
+        }
+    }
+
+    fn from_bright(n: i32) -> Self {
+        match n {
+            0 => AnsiColor::BrightBlack,
+            1 => AnsiColor::BrightRed,
+            2 => AnsiColor::BrightGreen,
+            3 => AnsiColor::BrightYellow,
+            4 => AnsiColor::BrightBlue,
+            5 => AnsiColor::BrightMagenta,
+            6 => AnsiColor::BrightCyan,
+            7 => AnsiColor::BrightWhite,
+            _ => AnsiColor::Default,
+        }
+    }
+}
+
+/// The active text style at a point in a line, as tracked across SGR codes.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AnsiStyle {
+    pub fg: AnsiColor,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+/// A run of text that shares a single [`AnsiStyle`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnsiSpan {
+    pub text: String,
+    pub style: AnsiStyle,
+}
+
+/// Parse a line containing ANSI escape sequences into styled spans.
+///
+/// Non-SGR escape sequences (cursor movement, screen clears, OSC window
+/// title changes, etc.) are dropped rather than rendered, since they have
+/// no meaning outside a live terminal grid.
+pub fn parse_ansi_spans(input: &str) -> Vec<AnsiSpan> {
+    let mut spans = Vec::new();
+    let mut style = AnsiStyle::default();
+    let mut current = String::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            current.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            Some('[') => {
+                chars.next(); // consume '['
+                let mut seq = String::new();
+                let mut is_sgr = false;
+                for ch in chars.by_ref() {
+                    if ch.is_ascii_alphabetic() || ch == '~' {
+                        is_sgr = ch == 'm';
+                        break;
+                    }
+                    seq.push(ch);
+                }
+                if is_sgr {
+                    if !current.is_empty() {
+                        spans.push(AnsiSpan {
+                            text: std::mem::take(&mut current),
+                            style: style.clone(),
+                        });
+                    }
+                    apply_sgr_params(&seq, &mut style);
+                }
+                // Other CSI sequences are silently dropped.
+            }
+            Some(']') => {
+                // OSC sequence - terminated by BEL or ESC \ (ST).
+                chars.next();
+                for ch in chars.by_ref() {
+                    if ch == '\u{7}' {
+                        break;
+                    }
+                    if ch == '\u{1b}' {
+                        chars.next(); // consume the trailing '\' of ST
+                        break;
+                    }
+                }
+            }
+            _ => {
+                // A bare/unrecognized escape introducer - drop just the ESC.
+            }
+        }
+    }
+
+    if !current.is_empty() {
+        spans.push(AnsiSpan {
+            text: current,
+            style,
+        });
+    }
+
+    spans
+}
+
+/// Strip all ANSI escape sequences, returning plain text.
+///
+/// Used for sinks that can't render styled spans: the `SCRIPT` tracing
+/// target, the per-run log file, and the stderr ring buffer that feeds
+/// [`super::errors`]'s stack-trace and suggestion matching.
+pub fn strip_ansi_codes(input: &str) -> String {
+    if !input.contains('\u{1b}') {
+        // Fast path: the overwhelming majority of stderr lines have no
+        // escape codes at all.
+        return input.to_string();
+    }
+    parse_ansi_spans(input)
+        .into_iter()
+        .map(|span| span.text)
+        .collect()
+}
+
+/// Apply the SGR parameters between `ESC [` and the terminating `m` to `style`.
+fn apply_sgr_params(seq: &str, style: &mut AnsiStyle) {
+    let params: Vec<&str> = if seq.is_empty() {
+        vec!["0"]
+    } else {
+        seq.split(';').collect()
+    };
+
+    let mut i = 0;
+    while i < params.len() {
+        let code: i32 = params[i].parse().unwrap_or(0);
+        match code {
+            0 => *style = AnsiStyle::default(),
+            1 => style.bold = true,
+            2 => style.dim = true,
+            3 => style.italic = true,
+            4 => style.underline = true,
+            22 => {
+                style.bold = false;
+                style.dim = false;
+            }
+            23 => style.italic = false,
+            24 => style.underline = false,
+            30..=37 => style.fg = AnsiColor::from_basic(code - 30),
+            38 => {
+                i += consume_extended_color_params(&params[i + 1..]);
+                style.fg = AnsiColor::Default;
+            }
+            39 => style.fg = AnsiColor::Default,
+            48 => {
+                i += consume_extended_color_params(&params[i + 1..]);
+            }
+            90..=97 => style.fg = AnsiColor::from_bright(code - 90),
+            _ => {}
+        }
+        i += 1;
+    }
+}
+
+/// How many extra params (beyond the `38`/`48` itself) a 256-color or
+/// truecolor extended SGR sequence consumes, so the rest of the sequence
+/// doesn't get misread as separate codes.
+fn consume_extended_color_params(rest: &[&str]) -> usize {
+    match rest.first().and_then(|s| s.parse::<i32>().ok()) {
+        Some(5) => 2, // 38;5;N -> mode + index
+        Some(2) => 4, // 38;2;R;G;B -> mode + r + g + b
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_ansi_codes_plain_text_unchanged() {
+        assert_eq!(strip_ansi_codes("hello world"), "hello world");
+        assert_eq!(strip_ansi_codes(""), "");
+    }
+
+    #[test]
+    fn test_strip_ansi_codes_removes_color() {
+        let input = "\u{1b}[31mError:\u{1b}[0m something broke";
+        assert_eq!(strip_ansi_codes(input), "Error: something broke");
+    }
+
+    #[test]
+    fn test_strip_ansi_codes_removes_multiple_sequences() {
+        let input = "\u{1b}[1m\u{1b}[32mPASS\u{1b}[0m \u{1b}[2mtest.ts\u{1b}[0m";
+        assert_eq!(strip_ansi_codes(input), "PASS test.ts");
+    }
+
+    #[test]
+    fn test_strip_ansi_codes_drops_cursor_movement_sequences() {
+        // Clear line + move cursor to column 1, as many progress bars emit.
+        let input = "\u{1b}[2K\u{1b}[1Gprogress: 50%";
+        assert_eq!(strip_ansi_codes(input), "progress: 50%");
+    }
+
+    #[test]
+    fn test_strip_ansi_codes_drops_osc_title_sequence() {
+        let input = "\u{1b}]0;My Title\u{7}done";
+        assert_eq!(strip_ansi_codes(input), "done");
+    }
+
+    #[test]
+    fn test_strip_ansi_codes_survives_extended_color_codes() {
+        let input = "\u{1b}[38;5;196mred256\u{1b}[0m \u{1b}[48;2;10;20;30mtruecolor bg\u{1b}[0m after";
+        assert_eq!(strip_ansi_codes(input), "red256 truecolor bg after");
+    }
+
+    #[test]
+    fn test_strip_ansi_codes_survives_trailing_unterminated_escape() {
+        // Malformed input shouldn't panic or hang.
+        let input = "abc\u{1b}[31";
+        assert_eq!(strip_ansi_codes(input), "abc");
+    }
+
+    #[test]
+    fn test_parse_ansi_spans_basic_color() {
+        let spans = parse_ansi_spans("\u{1b}[31mError\u{1b}[0m: bad");
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].text, "Error");
+        assert_eq!(spans[0].style.fg, AnsiColor::Red);
+        assert_eq!(spans[1].text, ": bad");
+        assert_eq!(spans[1].style.fg, AnsiColor::Default);
+    }
+
+    #[test]
+    fn test_parse_ansi_spans_bold_and_bright_color_combine() {
+        let spans = parse_ansi_spans("\u{1b}[1;92mOK\u{1b}[0m");
+        assert_eq!(spans.len(), 1);
+        assert!(spans[0].style.bold);
+        assert_eq!(spans[0].style.fg, AnsiColor::BrightGreen);
+    }
+
+    #[test]
+    fn test_parse_ansi_spans_reset_clears_style() {
+        let spans = parse_ansi_spans("\u{1b}[1;31mbold red\u{1b}[0mplain");
+        assert_eq!(spans.len(), 2);
+        assert!(spans[0].style.bold);
+        assert_eq!(spans[1].style, AnsiStyle::default());
+    }
+
+    #[test]
+    fn test_parse_ansi_spans_no_escape_codes_is_one_span() {
+        let spans = parse_ansi_spans("just plain text");
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].text, "just plain text");
+        assert_eq!(spans[0].style, AnsiStyle::default());
+    }
+
+    #[test]
+    fn test_parse_ansi_spans_empty_input_is_no_spans() {
+        assert!(parse_ansi_spans("").is_empty());
+    }
+}
@@ -3,6 +3,8 @@
 //! This module provides utilities for parsing script errors and generating
 //! helpful suggestions for users.
 
+use regex::Regex;
+
 /// Parse stderr output to extract stack trace if present
 pub fn parse_stack_trace(stderr: &str) -> Option<String> {
     // Look for common stack trace patterns
@@ -47,6 +49,67 @@ pub fn parse_stack_trace(stderr: &str) -> Option<String> {
     None
 }
 
+/// A parsed `file:line:column` location from a single stack-trace frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackFrameLocation {
+    pub path: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// Whether `frame_path` refers to the same file as `script_path`, either
+/// exactly or by filename (stack frames can be prefixed with a `file://`
+/// URL or a different working-directory-relative form than the path we
+/// launched the script with).
+fn frame_matches_script(frame_path: &str, script_path: &str) -> bool {
+    if frame_path == script_path {
+        return true;
+    }
+    match (
+        std::path::Path::new(frame_path).file_name(),
+        std::path::Path::new(script_path).file_name(),
+    ) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Finds the first stack-trace frame that points at the script that
+/// actually crashed (as opposed to `node_modules` or the Kit SDK itself),
+/// returning its parsed `file:line:column`. Frames look like
+/// `    at functionName (/path/to/file.ts:12:5)` or
+/// `    at /path/to/file.ts:12:5`. Used to offer an "Open at line" action
+/// on the error toast - see `script_creation::open_in_editor_at_line`.
+pub fn first_script_frame(stack_trace: &str, script_path: &str) -> Option<StackFrameLocation> {
+    let re = Regex::new(r"([^\s()]+):(\d+):(\d+)\)?").unwrap();
+    for line in stack_trace.lines() {
+        if line.contains("node_modules") {
+            continue;
+        }
+        let Some(caps) = re.captures(line) else {
+            continue;
+        };
+        let Some(path) = caps.get(1).map(|m| m.as_str()) else {
+            continue;
+        };
+        if !frame_matches_script(path, script_path) {
+            continue;
+        }
+        let (Some(line_m), Some(col_m)) = (caps.get(2), caps.get(3)) else {
+            continue;
+        };
+        let (Ok(line_no), Ok(column)) = (line_m.as_str().parse(), col_m.as_str().parse()) else {
+            continue;
+        };
+        return Some(StackFrameLocation {
+            path: path.to_string(),
+            line: line_no,
+            column,
+        });
+    }
+    None
+}
+
 /// Extract a user-friendly error message from stderr
 pub fn extract_error_message(stderr: &str) -> String {
     let lines: Vec<&str> = stderr.lines().collect();
@@ -86,44 +149,112 @@ pub fn extract_error_message(stderr: &str) -> String {
     "Script execution failed".to_string()
 }
 
-/// Generate suggestions based on error type
-pub fn generate_suggestions(stderr: &str, exit_code: Option<i32>) -> Vec<String> {
-    let mut suggestions = Vec::new();
-    let stderr_lower = stderr.to_lowercase();
-
-    // Check for common error patterns and suggest fixes
-    if stderr_lower.contains("cannot find module") || stderr_lower.contains("module not found") {
-        suggestions.push("Run 'bun install' or 'npm install' to install dependencies".to_string());
-    }
+/// A table-driven stderr -> suggestion rule. `matches` sees both the raw
+/// stderr (needed for case-sensitive regex captures, e.g. a module name)
+/// and its lowercased form (for plain keyword checks); `suggest` builds
+/// the message once a rule matches. Rules run in table order, and (like
+/// the old if-chain) more than one rule may fire for the same stderr.
+struct SuggestionRule {
+    matches: fn(stderr: &str, stderr_lower: &str) -> bool,
+    suggest: fn(stderr: &str) -> String,
+}
 
-    if stderr_lower.contains("syntaxerror") || stderr_lower.contains("unexpected token") {
-        suggestions.push("Check for syntax errors in your script".to_string());
-    }
+/// Extracts the module name from a bun/Node "Cannot find module 'X'" (or
+/// `Cannot find package "X"`) error, if present.
+fn missing_module_name(stderr: &str) -> Option<String> {
+    let re = Regex::new(r#"Cannot find (?:module|package)\s+['"]([^'"]+)['"]"#).unwrap();
+    re.captures(stderr)
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str().to_string())
+}
 
-    if stderr_lower.contains("referenceerror") || stderr_lower.contains("is not defined") {
-        suggestions.push(
-            "Check that all variables and functions are properly imported or defined".to_string(),
-        );
-    }
+/// Whether stderr mentions the Kit SDK, so a bare "is not a function" can
+/// be attributed to an outdated `@johnlindquist/kit` install rather than a
+/// generic script bug.
+fn references_kit_sdk(stderr_lower: &str) -> bool {
+    stderr_lower.contains("@johnlindquist/kit") || stderr_lower.contains("kit-sdk")
+}
 
-    if stderr_lower.contains("typeerror") {
-        suggestions
-            .push("Check that you're using the correct types for function arguments".to_string());
-    }
+/// Whether stderr looks like a macOS TCC (Accessibility/Automation/Screen
+/// Recording) permission denial, as opposed to a plain filesystem
+/// permission error (handled separately below).
+fn is_macos_permission_error(stderr_lower: &str) -> bool {
+    stderr_lower.contains("not authorized to send apple events")
+        || stderr_lower.contains("assistive access")
+        || stderr_lower.contains("accessibility permission")
+        || stderr_lower.contains("screen recording permission")
+        || stderr_lower.contains("ktccservice")
+}
 
-    if stderr_lower.contains("permission denied") || stderr_lower.contains("eacces") {
-        suggestions
-            .push("Check file permissions or try running with elevated privileges".to_string());
-    }
+const SUGGESTION_RULES: &[SuggestionRule] = &[
+    SuggestionRule {
+        matches: |_stderr, lower| {
+            lower.contains("cannot find module")
+                || lower.contains("cannot find package")
+                || lower.contains("module not found")
+        },
+        suggest: |stderr| match missing_module_name(stderr) {
+            Some(name) => format!("Run: bun add {}", name),
+            None => "Run 'bun install' or 'npm install' to install dependencies".to_string(),
+        },
+    },
+    SuggestionRule {
+        matches: |_stderr, lower| lower.contains("is not a function") && references_kit_sdk(lower),
+        suggest: |_stderr| {
+            "Your @johnlindquist/kit SDK may be outdated - update it and re-run the script"
+                .to_string()
+        },
+    },
+    SuggestionRule {
+        matches: |_stderr, lower| is_macos_permission_error(lower),
+        suggest: |_stderr| {
+            "Grant Accessibility/Automation permission in System Settings > Privacy & Security"
+                .to_string()
+        },
+    },
+    SuggestionRule {
+        matches: |_stderr, lower| lower.contains("syntaxerror") || lower.contains("unexpected token"),
+        suggest: |_stderr| "Check for syntax errors in your script".to_string(),
+    },
+    SuggestionRule {
+        matches: |_stderr, lower| lower.contains("referenceerror") || lower.contains("is not defined"),
+        suggest: |_stderr| {
+            "Check that all variables and functions are properly imported or defined".to_string()
+        },
+    },
+    SuggestionRule {
+        matches: |_stderr, lower| lower.contains("typeerror"),
+        suggest: |_stderr| {
+            "Check that you're using the correct types for function arguments".to_string()
+        },
+    },
+    SuggestionRule {
+        matches: |_stderr, lower| lower.contains("permission denied") || lower.contains("eacces"),
+        suggest: |_stderr| {
+            "Check file permissions or try running with elevated privileges".to_string()
+        },
+    },
+    SuggestionRule {
+        matches: |_stderr, lower| lower.contains("enoent") || lower.contains("no such file"),
+        suggest: |_stderr| "Check that the file path exists and is correct".to_string(),
+    },
+    SuggestionRule {
+        matches: |_stderr, lower| lower.contains("timeout") || lower.contains("timed out"),
+        suggest: |_stderr| {
+            "The operation timed out - check network connectivity or increase timeout".to_string()
+        },
+    },
+];
 
-    if stderr_lower.contains("enoent") || stderr_lower.contains("no such file") {
-        suggestions.push("Check that the file path exists and is correct".to_string());
-    }
+/// Generate suggestions based on error type
+pub fn generate_suggestions(stderr: &str, exit_code: Option<i32>) -> Vec<String> {
+    let mut suggestions = Vec::new();
+    let stderr_lower = stderr.to_lowercase();
 
-    if stderr_lower.contains("timeout") || stderr_lower.contains("timed out") {
-        suggestions.push(
-            "The operation timed out - check network connectivity or increase timeout".to_string(),
-        );
+    for rule in SUGGESTION_RULES {
+        if (rule.matches)(stderr, &stderr_lower) {
+            suggestions.push((rule.suggest)(stderr));
+        }
     }
 
     // Exit code specific suggestions
@@ -363,3 +494,120 @@ pub fn generate_crash_suggestions(crash_info: &CrashInfo) -> Vec<String> {
 
     suggestions
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggests_bun_add_for_missing_module() {
+        let stderr = "error: Cannot find module 'lodash' from '/Users/me/script.ts'";
+        let suggestions = generate_suggestions(stderr, Some(1));
+        assert!(
+            suggestions.contains(&"Run: bun add lodash".to_string()),
+            "expected a bun add suggestion, got {:?}",
+            suggestions
+        );
+    }
+
+    #[test]
+    fn test_suggests_bun_add_for_missing_package_double_quotes() {
+        let stderr = r#"Cannot find package "@types/node" imported from /tmp/script.ts"#;
+        let suggestions = generate_suggestions(stderr, Some(1));
+        assert!(suggestions.contains(&"Run: bun add @types/node".to_string()));
+    }
+
+    #[test]
+    fn test_falls_back_to_generic_install_hint_without_module_name() {
+        let stderr = "Module not found somewhere in the dependency graph";
+        let suggestions = generate_suggestions(stderr, Some(1));
+        assert!(suggestions
+            .contains(&"Run 'bun install' or 'npm install' to install dependencies".to_string()));
+    }
+
+    #[test]
+    fn test_suggests_sdk_update_for_kit_sdk_function_error() {
+        let stderr = "TypeError: div is not a function\n    at /Users/me/.scriptkit/sdk/kit-sdk.ts:42:1";
+        let suggestions = generate_suggestions(stderr, Some(1));
+        assert!(suggestions.contains(
+            &"Your @johnlindquist/kit SDK may be outdated - update it and re-run the script"
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn test_does_not_suggest_sdk_update_for_unrelated_function_error() {
+        let stderr = "TypeError: someLocalHelper is not a function";
+        let suggestions = generate_suggestions(stderr, Some(1));
+        assert!(!suggestions.iter().any(|s| s.contains("@johnlindquist/kit")));
+    }
+
+    #[test]
+    fn test_suggests_accessibility_permission_for_apple_events_error() {
+        let stderr = "Error: osascript is not authorized to send Apple events to System Events.";
+        let suggestions = generate_suggestions(stderr, Some(1));
+        assert!(suggestions.contains(
+            &"Grant Accessibility/Automation permission in System Settings > Privacy & Security"
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn test_plain_file_permission_error_does_not_suggest_accessibility() {
+        let stderr = "Error: EACCES: permission denied, open '/etc/hosts'";
+        let suggestions = generate_suggestions(stderr, Some(1));
+        assert!(suggestions
+            .contains(&"Check file permissions or try running with elevated privileges".to_string()));
+        assert!(!suggestions.iter().any(|s| s.contains("Accessibility")));
+    }
+
+    #[test]
+    fn test_missing_module_name_extracts_single_quoted_name() {
+        assert_eq!(
+            missing_module_name("Cannot find module 'chalk'"),
+            Some("chalk".to_string())
+        );
+    }
+
+    #[test]
+    fn test_missing_module_name_returns_none_when_absent() {
+        assert_eq!(missing_module_name("some unrelated error"), None);
+    }
+
+    #[test]
+    fn test_first_script_frame_finds_matching_frame() {
+        let stack_trace = "TypeError: oops\n    at Object.<anonymous> (/Users/me/.scriptkit/scripts/foo.ts:12:5)\n    at Module._compile (node:internal/modules/cjs/loader:1105:14)";
+        let frame =
+            first_script_frame(stack_trace, "/Users/me/.scriptkit/scripts/foo.ts").unwrap();
+        assert_eq!(frame.path, "/Users/me/.scriptkit/scripts/foo.ts");
+        assert_eq!(frame.line, 12);
+        assert_eq!(frame.column, 5);
+    }
+
+    #[test]
+    fn test_first_script_frame_skips_node_modules() {
+        let stack_trace = "TypeError: oops\n    at helper (/Users/me/.scriptkit/node_modules/lib/index.js:3:1)\n    at Object.<anonymous> (/Users/me/.scriptkit/scripts/foo.ts:12:5)";
+        let frame =
+            first_script_frame(stack_trace, "/Users/me/.scriptkit/scripts/foo.ts").unwrap();
+        assert_eq!(frame.line, 12);
+    }
+
+    #[test]
+    fn test_first_script_frame_matches_by_filename_across_path_prefixes() {
+        let stack_trace = "at Object.<anonymous> (file:///Users/me/.scriptkit/scripts/foo.ts:7:2)";
+        let frame =
+            first_script_frame(stack_trace, "/Users/me/.scriptkit/scripts/foo.ts").unwrap();
+        assert_eq!(frame.line, 7);
+        assert_eq!(frame.column, 2);
+    }
+
+    #[test]
+    fn test_first_script_frame_none_when_no_frame_matches_script() {
+        let stack_trace =
+            "at helper (/Users/me/.scriptkit/sdk/kit-sdk.ts:99:1)\n    at node:internal:1:1";
+        assert_eq!(
+            first_script_frame(stack_trace, "/Users/me/.scriptkit/scripts/foo.ts"),
+            None
+        );
+    }
+}
@@ -0,0 +1,76 @@
+//! Hotkey Registration Message Handlers
+//!
+//! Handles the `registerHotkey`/`unregisterHotkey` protocol messages a
+//! running script can send to claim a global shortcut for itself. The
+//! registration itself lives in `crate::hotkeys`; this module just adapts
+//! that API to the reader thread's "handle directly, no UI involved" pattern
+//! (see `selected_text.rs` for the same shape).
+
+use std::sync::mpsc::SyncSender;
+
+use crate::hotkeys;
+use crate::logging;
+use crate::protocol::Message;
+
+/// Result of handling a hotkey registration message
+#[derive(Debug)]
+pub enum HotkeyMessageHandleResult {
+    /// Message was handled, here's the response to send back
+    Handled(Message),
+    /// Message was not a hotkey registration operation
+    NotHandled,
+}
+
+/// Handle `registerHotkey`/`unregisterHotkey` protocol messages.
+///
+/// `response_sender` is stashed by `RegisterHotkey` so later presses can be
+/// delivered back to this script as `Message::HotkeyPressed`.
+pub fn handle_hotkey_message(
+    msg: &Message,
+    response_sender: SyncSender<Message>,
+) -> HotkeyMessageHandleResult {
+    match msg {
+        Message::RegisterHotkey { id, shortcut } => {
+            let response = handle_register_hotkey(id, shortcut, response_sender);
+            HotkeyMessageHandleResult::Handled(response)
+        }
+        Message::UnregisterHotkey { id } => {
+            hotkeys::unregister_session_hotkey(id);
+            logging::log("HOTKEY", &format!("Unregistered session hotkey '{}'", id));
+            HotkeyMessageHandleResult::Handled(Message::Submit {
+                id: id.clone(),
+                value: None,
+            })
+        }
+        _ => HotkeyMessageHandleResult::NotHandled,
+    }
+}
+
+fn handle_register_hotkey(
+    id: &str,
+    shortcut: &str,
+    response_sender: SyncSender<Message>,
+) -> Message {
+    match hotkeys::register_session_hotkey(id, shortcut, response_sender) {
+        Ok(()) => {
+            logging::log(
+                "HOTKEY",
+                &format!("Registered session hotkey '{}' -> '{}'", shortcut, id),
+            );
+            Message::Submit {
+                id: id.to_string(),
+                value: None,
+            }
+        }
+        Err(e) => {
+            logging::log(
+                "HOTKEY",
+                &format!("Failed to register session hotkey '{}': {}", shortcut, e),
+            );
+            Message::Submit {
+                id: id.to_string(),
+                value: Some(format!("ERROR: {}", e)),
+            }
+        }
+    }
+}
@@ -7,9 +7,13 @@
 //! - Selected text operations
 //! - Auto-submit mode for autonomous testing
 
+mod ansi;
 mod auto_submit;
 mod errors;
+mod hotkey_messages;
+mod run_log;
 mod runner;
+mod script_metadata;
 mod scriptlet;
 mod selected_text;
 mod stderr_buffer;
@@ -18,12 +22,22 @@ mod stderr_buffer;
 // Allow unused imports - these are public API exports that may be used by external code
 // or will be used in the future (marked #[allow(dead_code)] in their source files)
 #[allow(unused_imports)]
+// Allow unused - AnsiSpan/parse_ansi_spans are exported for a future styled
+// log view; strip_ansi_codes is already consumed by stderr_buffer.
+#[allow(unused_imports)]
+pub use ansi::{parse_ansi_spans, strip_ansi_codes, AnsiColor, AnsiSpan, AnsiStyle};
+
 pub use auto_submit::{
     get_auto_submit_config, get_auto_submit_delay, get_auto_submit_index, get_auto_submit_value,
     is_auto_submit_enabled, AutoSubmitConfig,
 };
 
-pub use errors::{extract_error_message, generate_suggestions, parse_stack_trace};
+pub use errors::{
+    extract_error_message, first_script_frame, generate_suggestions, parse_stack_trace,
+    StackFrameLocation,
+};
+
+pub use hotkey_messages::{handle_hotkey_message, HotkeyMessageHandleResult};
 
 // Infrastructure exports - available for future integration
 #[allow(unused_imports)]
@@ -38,7 +52,7 @@ pub use runner::{
     spawn_script, ProcessHandle, SplitSession,
 };
 
-pub use scriptlet::{run_scriptlet, ScriptletExecOptions};
+pub use scriptlet::{resolve_tool, run_scriptlet, ScriptletExecOptions};
 
 // Additional scriptlet exports for backwards compatibility
 #[allow(unused_imports)]
@@ -48,11 +62,23 @@ pub use scriptlet::{
     execute_with_interpreter, shell_not_found_suggestions, ScriptletResult,
 };
 
+pub use script_metadata::{
+    handle_script_metadata_message, ScriptMetadataHandleResult, ScriptMetadataSnapshot,
+};
+
 pub use selected_text::{handle_selected_text_message, SelectedTextHandleResult};
 
 // Allow unused - these are public API exports for future use
 #[allow(unused_imports)]
-pub use stderr_buffer::{spawn_stderr_reader, StderrBuffer, StderrCapture};
+pub use stderr_buffer::{
+    spawn_stderr_reader, spawn_stderr_reader_with_run_log, StderrBuffer, StderrCapture,
+};
+
+pub use run_log::{find_latest_run_log, RunLogger, DEFAULT_MAX_RUNS};
+
+// Allow unused - exported for tests and future direct use
+#[allow(unused_imports)]
+pub use run_log::{run_log_dir, sanitize_script_name};
 
 // Re-export tool_extension only for tests
 #[cfg(test)]
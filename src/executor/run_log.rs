@@ -0,0 +1,293 @@
+//! Per-run script log files
+//!
+//! Each interactive script session gets its own log file under
+//! `~/.scriptkit/logs/runs/<sanitized-script-name>/<timestamp>.log`, capturing
+//! lifecycle events, stdout noise (non-JSONL lines skipped by
+//! `next_message_graceful`), stderr, and the final exit code. This
+//! complements the global `script-kit-gpui.jsonl` log (which interleaves
+//! every running script) and the in-memory `StderrBuffer` (which only keeps
+//! the most recent lines for post-mortem toasts).
+//!
+//! Old runs are rotated away, keeping only the most recent [`DEFAULT_MAX_RUNS`]
+//! files per script.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use tracing::warn;
+
+/// Default number of run logs to keep per script before rotating old ones away
+pub const DEFAULT_MAX_RUNS: usize = 20;
+
+/// Sanitize a script name for use as a directory name.
+///
+/// - Converts to lowercase
+/// - Replaces spaces and underscores with hyphens
+/// - Removes special characters (keeps only alphanumeric and hyphens),
+///   so path separators and `..` segments can't escape the logs directory
+/// - Collapses multiple consecutive hyphens and trims leading/trailing ones
+/// - Falls back to `"script"` if nothing alphanumeric survives (e.g. a name
+///   that's entirely emoji, or an empty string)
+pub fn sanitize_script_name(name: &str) -> String {
+    let sanitized: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c
+            } else if c == ' ' || c == '_' || c == '-' {
+                '-'
+            } else {
+                '\0'
+            }
+        })
+        .filter(|&c| c != '\0')
+        .collect();
+
+    let mut result = String::new();
+    let mut last_was_hyphen = false;
+    for c in sanitized.chars() {
+        if c == '-' {
+            if !last_was_hyphen && !result.is_empty() {
+                result.push(c);
+                last_was_hyphen = true;
+            }
+        } else {
+            result.push(c);
+            last_was_hyphen = false;
+        }
+    }
+    if result.ends_with('-') {
+        result.pop();
+    }
+
+    if result.is_empty() {
+        "script".to_string()
+    } else {
+        result
+    }
+}
+
+/// Get the root directory under which per-script run logs live (`~/.scriptkit/logs/runs/`)
+fn runs_root_dir() -> PathBuf {
+    dirs::home_dir()
+        .map(|h| h.join(".scriptkit").join("logs").join("runs"))
+        .unwrap_or_else(|| std::env::temp_dir().join("script-kit-runs"))
+}
+
+/// Get the log directory for a given script name (`~/.scriptkit/logs/runs/<sanitized-name>/`)
+pub fn run_log_dir(script_name: &str) -> PathBuf {
+    runs_root_dir().join(sanitize_script_name(script_name))
+}
+
+/// Delete the oldest run logs in `dir` until at most `keep` remain.
+///
+/// Run log filenames are zero-padded timestamps so lexicographic order
+/// matches chronological order.
+fn rotate_runs(dir: &Path, keep: usize) {
+    let mut entries: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(read_dir) => read_dir
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("log"))
+            .collect(),
+        Err(e) => {
+            warn!(dir = %dir.display(), error = %e, "Failed to read run log directory for rotation");
+            return;
+        }
+    };
+
+    if entries.len() <= keep {
+        return;
+    }
+
+    entries.sort();
+    let remove_count = entries.len() - keep;
+    for path in entries.into_iter().take(remove_count) {
+        if let Err(e) = fs::remove_file(&path) {
+            warn!(path = %path.display(), error = %e, "Failed to remove rotated run log");
+        }
+    }
+}
+
+/// Handle to a single script run's log file, shared between the stdout and
+/// stderr reader threads.
+///
+/// Both threads write through the same `Mutex<File>` so lines from either
+/// source are interleaved safely without corrupting each other's writes.
+#[derive(Clone)]
+pub struct RunLogger {
+    file: Arc<Mutex<File>>,
+    path: PathBuf,
+}
+
+impl RunLogger {
+    /// Create a new run log for `script_name`, rotating old runs away first.
+    pub fn create(script_name: &str, max_runs: usize) -> std::io::Result<Self> {
+        let dir = run_log_dir(script_name);
+        fs::create_dir_all(&dir)?;
+        rotate_runs(&dir, max_runs.saturating_sub(1));
+
+        let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S%3f");
+        let path = dir.join(format!("{}.log", timestamp));
+
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+        Ok(Self {
+            file: Arc::new(Mutex::new(file)),
+            path,
+        })
+    }
+
+    /// Path to this run's log file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write a tagged, timestamped line (e.g. `[STDERR]`, `[STDOUT]`, `[LIFECYCLE]`)
+    pub fn write_line(&self, tag: &str, line: &str) {
+        let timestamp = chrono::Utc::now().to_rfc3339();
+        let mut file = match self.file.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if let Err(e) = writeln!(file, "{} [{}] {}", timestamp, tag, line) {
+            warn!(path = %self.path.display(), error = %e, "Failed to write to run log");
+        }
+    }
+
+    /// Record a lifecycle event (e.g. "started pid=1234", "exited code=1")
+    pub fn lifecycle(&self, event: &str) {
+        self.write_line("LIFECYCLE", event);
+    }
+
+    /// Record the process exit code
+    pub fn exit(&self, code: Option<i32>) {
+        match code {
+            Some(code) => self.lifecycle(&format!("exited code={}", code)),
+            None => self.lifecycle("exited code=unknown"),
+        }
+    }
+}
+
+/// Find the most recently written run log for a script, if any exist.
+pub fn find_latest_run_log(script_name: &str) -> Option<PathBuf> {
+    let dir = run_log_dir(script_name);
+    let entries = fs::read_dir(&dir).ok()?;
+    entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("log"))
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_sanitize_script_name_basic() {
+        assert_eq!(sanitize_script_name("My Script"), "my-script");
+        assert_eq!(sanitize_script_name("hello_world"), "hello-world");
+        assert_eq!(
+            sanitize_script_name("Test  Multiple   Spaces"),
+            "test-multiple-spaces"
+        );
+    }
+
+    #[test]
+    fn test_sanitize_script_name_strips_path_traversal() {
+        // Path separators and ".." must never survive - they could otherwise
+        // escape the logs/runs directory.
+        let sanitized = sanitize_script_name("../../etc/passwd");
+        assert!(!sanitized.contains('/'));
+        assert!(!sanitized.contains(".."));
+        assert_eq!(sanitized, "etcpasswd");
+    }
+
+    #[test]
+    fn test_sanitize_script_name_strips_special_chars() {
+        assert_eq!(sanitize_script_name("special!@#chars"), "specialchars");
+    }
+
+    #[test]
+    fn test_sanitize_script_name_empty_falls_back() {
+        assert_eq!(sanitize_script_name(""), "script");
+        assert_eq!(sanitize_script_name("🚀🚀🚀"), "script");
+        assert_eq!(sanitize_script_name("..."), "script");
+    }
+
+    #[test]
+    fn test_rotate_runs_keeps_most_recent() {
+        let dir = TempDir::new().unwrap();
+        for i in 0..5 {
+            let path = dir.path().join(format!("2024010{}-000000-000.log", i));
+            fs::write(&path, "test").unwrap();
+        }
+
+        rotate_runs(dir.path(), 3);
+
+        let remaining: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(remaining.len(), 3);
+        // The two oldest (day 0 and 1) should have been removed
+        assert!(!remaining.iter().any(|f| f.starts_with("20240100")));
+        assert!(!remaining.iter().any(|f| f.starts_with("20240101")));
+        assert!(remaining.iter().any(|f| f.starts_with("20240104")));
+    }
+
+    #[test]
+    fn test_rotate_runs_noop_when_under_limit() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("20240101-000000-000.log"), "test").unwrap();
+
+        rotate_runs(dir.path(), 10);
+
+        let remaining: Vec<_> = fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(remaining.len(), 1);
+    }
+
+    #[test]
+    fn test_run_logger_create_and_write() {
+        let dir = TempDir::new().unwrap();
+        std::env::set_var("HOME", dir.path());
+
+        let logger = RunLogger::create("test-script", DEFAULT_MAX_RUNS).unwrap();
+        logger.lifecycle("started pid=123");
+        logger.write_line("STDERR", "oops");
+        logger.exit(Some(1));
+
+        let contents = fs::read_to_string(logger.path()).unwrap();
+        assert!(contents.contains("[LIFECYCLE] started pid=123"));
+        assert!(contents.contains("[STDERR] oops"));
+        assert!(contents.contains("[LIFECYCLE] exited code=1"));
+    }
+
+    #[test]
+    fn test_find_latest_run_log_returns_newest() {
+        let dir = TempDir::new().unwrap();
+        std::env::set_var("HOME", dir.path());
+
+        let first = RunLogger::create("another-test-script", DEFAULT_MAX_RUNS).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let second = RunLogger::create("another-test-script", DEFAULT_MAX_RUNS).unwrap();
+
+        let latest = find_latest_run_log("another-test-script").unwrap();
+        assert_eq!(latest, second.path());
+        assert_ne!(latest, first.path());
+    }
+
+    #[test]
+    fn test_find_latest_run_log_missing_script_returns_none() {
+        let dir = TempDir::new().unwrap();
+        std::env::set_var("HOME", dir.path());
+
+        assert!(find_latest_run_log("never-ran-script").is_none());
+    }
+}
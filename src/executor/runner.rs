@@ -7,6 +7,7 @@
 //! - File type detection
 
 use crate::logging;
+use crate::perf;
 use crate::process_manager::PROCESS_MANAGER;
 use crate::protocol::{serialize_message, JsonlReader, Message};
 use std::io::{BufReader, Write};
@@ -561,7 +562,7 @@ impl ScriptSession {
 
 /// Execute a script with bidirectional JSONL communication
 #[instrument(skip_all, fields(script_path = %path.display()))]
-pub fn execute_script_interactive(path: &Path) -> Result<ScriptSession, String> {
+pub fn execute_script_interactive(path: &Path, args: &[String]) -> Result<ScriptSession, String> {
     let start = Instant::now();
     debug!(path = %path.display(), "Starting interactive script execution");
     logging::log(
@@ -572,6 +573,7 @@ pub fn execute_script_interactive(path: &Path) -> Result<ScriptSession, String>
     let path_str = path
         .to_str()
         .ok_or_else(|| "Invalid path encoding".to_string())?;
+    let extra_args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
 
     // Find SDK for preloading
     let sdk_path = find_sdk_path();
@@ -583,7 +585,9 @@ pub fn execute_script_interactive(path: &Path) -> Result<ScriptSession, String>
             "EXEC",
             &format!("Trying: bun run --preload {} {}", sdk_str, path_str),
         );
-        match spawn_script("bun", &["run", "--preload", sdk_str, path_str], path_str) {
+        let mut bun_args = vec!["run", "--preload", sdk_str, path_str];
+        bun_args.extend(extra_args.iter().copied());
+        match spawn_script("bun", &bun_args, path_str) {
             Ok(session) => {
                 info!(
                     duration_ms = start.elapsed().as_millis() as u64,
@@ -591,6 +595,9 @@ pub fn execute_script_interactive(path: &Path) -> Result<ScriptSession, String>
                     preload = true,
                     "Script session started"
                 );
+                perf::diagnostics()
+                    .script_spawn_time
+                    .record(start.elapsed());
                 logging::log("EXEC", "SUCCESS: bun with preload");
                 return Ok(session);
             }
@@ -604,7 +611,9 @@ pub fn execute_script_interactive(path: &Path) -> Result<ScriptSession, String>
     // Try bun without preload as fallback
     if is_typescript(path) {
         logging::log("EXEC", &format!("Trying: bun run {}", path_str));
-        match spawn_script("bun", &["run", path_str], path_str) {
+        let mut bun_args = vec!["run", path_str];
+        bun_args.extend(extra_args.iter().copied());
+        match spawn_script("bun", &bun_args, path_str) {
             Ok(session) => {
                 info!(
                     duration_ms = start.elapsed().as_millis() as u64,
@@ -612,6 +621,9 @@ pub fn execute_script_interactive(path: &Path) -> Result<ScriptSession, String>
                     preload = false,
                     "Script session started"
                 );
+                perf::diagnostics()
+                    .script_spawn_time
+                    .record(start.elapsed());
                 logging::log("EXEC", "SUCCESS: bun without preload");
                 return Ok(session);
             }
@@ -625,13 +637,18 @@ pub fn execute_script_interactive(path: &Path) -> Result<ScriptSession, String>
     // Try node for JavaScript files
     if is_javascript(path) {
         logging::log("EXEC", &format!("Trying: node {}", path_str));
-        match spawn_script("node", &[path_str], path_str) {
+        let mut node_args = vec![path_str];
+        node_args.extend(extra_args.iter().copied());
+        match spawn_script("node", &node_args, path_str) {
             Ok(session) => {
                 info!(
                     duration_ms = start.elapsed().as_millis() as u64,
                     runtime = "node",
                     "Script session started"
                 );
+                perf::diagnostics()
+                    .script_spawn_time
+                    .record(start.elapsed());
                 logging::log("EXEC", "SUCCESS: node");
                 return Ok(session);
             }
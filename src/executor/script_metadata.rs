@@ -0,0 +1,70 @@
+//! Script Metadata Message Handler
+//!
+//! Handles the `getScriptMetadata` protocol message, letting a running
+//! script read back the metadata the host already parsed from its comment
+//! header / typed `metadata` global, instead of re-parsing its own source.
+//! Same "handle directly, no UI involved" shape as `selected_text.rs`.
+
+use crate::logging;
+use crate::protocol::Message;
+
+/// Parsed metadata for the script backing the current session, snapshotted
+/// at launch so the reader thread can answer `getScriptMetadata` without
+/// touching the UI thread.
+#[derive(Debug, Clone)]
+pub struct ScriptMetadataSnapshot {
+    pub name: String,
+    pub description: Option<String>,
+    pub alias: Option<String>,
+    pub shortcut: Option<String>,
+    pub schedule: Option<String>,
+}
+
+impl ScriptMetadataSnapshot {
+    pub fn from_script(script: &crate::scripts::Script) -> Self {
+        let typed = script.typed_metadata.as_ref();
+        ScriptMetadataSnapshot {
+            name: script.name.clone(),
+            description: script.description.clone(),
+            alias: script.alias.clone(),
+            shortcut: script.shortcut.clone(),
+            schedule: typed
+                .and_then(|m| m.schedule.clone())
+                .or_else(|| typed.and_then(|m| m.cron.clone())),
+        }
+    }
+}
+
+/// Result of handling a script metadata message
+#[derive(Debug)]
+pub enum ScriptMetadataHandleResult {
+    /// Message was handled, here's the response to send back
+    Handled(Message),
+    /// Message was not a script metadata operation
+    NotHandled,
+}
+
+/// Handle `getScriptMetadata` protocol messages.
+pub fn handle_script_metadata_message(
+    msg: &Message,
+    snapshot: &ScriptMetadataSnapshot,
+) -> ScriptMetadataHandleResult {
+    match msg {
+        Message::GetScriptMetadata { request_id } => {
+            logging::log(
+                "EXEC",
+                &format!("GetScriptMetadata request: {}", request_id),
+            );
+            let response = Message::script_metadata_result(
+                request_id.clone(),
+                snapshot.name.clone(),
+                snapshot.description.clone(),
+                snapshot.alias.clone(),
+                snapshot.shortcut.clone(),
+                snapshot.schedule.clone(),
+            );
+            ScriptMetadataHandleResult::Handled(response)
+        }
+        _ => ScriptMetadataHandleResult::NotHandled,
+    }
+}
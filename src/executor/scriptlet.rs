@@ -33,6 +33,10 @@ pub struct ScriptletExecOptions {
     pub positional_args: Vec<String>,
     /// Flags for conditional processing
     pub flags: HashMap<String, bool>,
+    /// Explicit interpreter path overrides for scriptlet tools, keyed by
+    /// tool name (from `Config::tool_paths`). Checked by `resolve_tool`
+    /// before searching common install locations or PATH.
+    pub tool_paths: HashMap<String, String>,
 }
 
 /// Result of a scriptlet execution
@@ -123,7 +127,7 @@ pub fn run_scriptlet(
         t if SHELL_TOOLS.contains(&t) => execute_shell_scriptlet(&tool, &content, &options),
 
         // Scripting languages
-        "python" => execute_with_interpreter("python3", &content, "py", &options),
+        "python" => execute_with_interpreter("python", &content, "py", &options),
         "ruby" => execute_with_interpreter("ruby", &content, "rb", &options),
         "perl" => execute_with_interpreter("perl", &content, "pl", &options),
         "php" => execute_with_interpreter("php", &content, "php", &options),
@@ -225,6 +229,111 @@ pub fn build_final_content(
     result
 }
 
+/// Map a scriptlet `tool` name to the executable it actually runs under.
+///
+/// Most tools map to themselves (e.g. `bash` -> `bash`); a few are aliases
+/// for a shared interpreter (`kit`/`ts`/`deno` all run via `bun`, matching
+/// `execute_typescript`; `python` uses `python3`, `node`/`js` use `node`).
+fn tool_executable_name(tool: &str) -> &str {
+    match tool {
+        "python" => "python3",
+        "node" | "js" => "node",
+        "kit" | "ts" | "bun" | "deno" => "bun",
+        "applescript" => "osascript",
+        other => other,
+    }
+}
+
+/// Search `PATH` directly for `name`, independent of the common-install-path
+/// probing in `find_executable`. `Command::spawn` would find this too via
+/// `execvp`, but doing the lookup ourselves lets `resolve_tool` return a
+/// clear error instead of an opaque OS "No such file or directory".
+fn find_on_path(name: &str) -> Option<PathBuf> {
+    let path_var = std::env::var_os("PATH")?;
+    std::env::split_paths(&path_var).find_map(|dir| {
+        let candidate = dir.join(name);
+        candidate.is_file().then_some(candidate)
+    })
+}
+
+/// Get installation suggestions for a missing interpreter/tool.
+fn tool_not_found_suggestions(tool: &str, executable: &str) -> String {
+    let install_hint = match executable {
+        "python3" => {
+            if cfg!(target_os = "macos") {
+                "Install with: brew install python3"
+            } else if cfg!(target_os = "linux") {
+                "Install with: apt install python3 (Debian/Ubuntu) or yum install python3 (RHEL/CentOS)"
+            } else {
+                "Install Python from: https://www.python.org/downloads/"
+            }
+        }
+        "ruby" => {
+            if cfg!(target_os = "macos") {
+                "ruby ships with macOS; for a newer version try: brew install ruby"
+            } else {
+                "Install with: apt install ruby (Debian/Ubuntu) or yum install ruby (RHEL/CentOS)"
+            }
+        }
+        "perl" => "Install with: brew install perl (macOS) or apt install perl (Debian/Ubuntu)",
+        "php" => "Install with: brew install php (macOS) or apt install php (Debian/Ubuntu)",
+        "node" => "Install with: brew install node (macOS) or see https://nodejs.org/",
+        "bun" => "Install with: curl -fsSL https://bun.sh/install | bash",
+        "osascript" => "osascript ships with macOS; AppleScript tools are unavailable on other platforms",
+        _ => "Make sure it is installed and in your PATH.",
+    };
+
+    format!(
+        "Could not find the '{}' interpreter for tool '{}'.\n\
+         Suggestions:\n\
+         - {}\n\
+         - Or set a custom path via `toolPaths.{}` in ~/.scriptkit/config.ts",
+        executable, tool, install_hint, tool
+    )
+}
+
+/// Resolve the interpreter executable for a scriptlet `tool`.
+///
+/// Resolution order:
+/// 1. `tool_paths[tool]` (a `Config::tool_paths` override) - used as-is if
+///    the path exists, otherwise an error names the misconfigured path.
+/// 2. Common install locations (`find_executable`, e.g. `~/.bun/bin`).
+/// 3. `PATH` (`find_on_path`).
+///
+/// Returns a clear, actionable error (with install hints) instead of the
+/// opaque OS error `Command::spawn` would produce for a missing binary.
+pub fn resolve_tool(tool: &str, tool_paths: &HashMap<String, String>) -> Result<PathBuf, String> {
+    let executable = tool_executable_name(tool);
+
+    if let Some(override_path) = tool_paths.get(tool) {
+        let path = PathBuf::from(override_path);
+        return if path.is_file() {
+            Ok(path)
+        } else {
+            Err(format!(
+                "{}{} does not exist: {}",
+                MISCONFIGURED_TOOL_PATH_PREFIX, tool, override_path
+            ))
+        };
+    }
+
+    if let Some(path) = find_executable(executable) {
+        return Ok(path);
+    }
+
+    if let Some(path) = find_on_path(executable) {
+        return Ok(path);
+    }
+
+    Err(tool_not_found_suggestions(tool, executable))
+}
+
+/// Prefix on the `resolve_tool` error returned when a configured
+/// `toolPaths` override points at a path that doesn't exist - checked by
+/// callers that want to preserve that specific message over a more generic
+/// "not found" one (e.g. `execute_shell_scriptlet`'s shell-specific hints).
+const MISCONFIGURED_TOOL_PATH_PREFIX: &str = "Configured toolPaths.";
+
 /// Execute a shell scriptlet (bash, zsh, sh, fish, etc.)
 pub fn execute_shell_scriptlet(
     shell: &str,
@@ -252,10 +361,25 @@ pub fn execute_shell_scriptlet(
             .map_err(|e| format!("Failed to set executable permission: {}", e))?;
     }
 
-    // Find the shell executable
-    let shell_path = find_executable(shell)
-        .map(|p| p.to_string_lossy().into_owned())
-        .unwrap_or_else(|| shell.to_string());
+    // Find the shell executable, erroring clearly (with install hints) if missing.
+    // Prefer the shell-specific suggestions (SHELL_TOOLS alternatives, shell
+    // install commands) over resolve_tool's generic "not found" message,
+    // except when a configured toolPaths override is the actual problem.
+    let shell_path = match resolve_tool(shell, &options.tool_paths) {
+        Ok(path) => path,
+        Err(e) if e.starts_with(MISCONFIGURED_TOOL_PATH_PREFIX) => {
+            let _ = std::fs::remove_file(&temp_file);
+            return Err(e);
+        }
+        Err(_) => {
+            let _ = std::fs::remove_file(&temp_file);
+            return Err(format!(
+                "Could not find the '{}' shell.\n{}",
+                shell,
+                shell_not_found_suggestions(shell)
+            ));
+        }
+    };
 
     let mut cmd = Command::new(&shell_path);
     cmd.arg(temp_file.to_str().unwrap());
@@ -354,12 +478,17 @@ pub fn shell_not_found_suggestions(shell: &str) -> String {
 }
 
 /// Execute a script with a specific interpreter
+///
+/// `tool` is the scriptlet tool name (e.g. `"python"`), used both to look up
+/// `options.tool_paths` overrides and, via `tool_executable_name`, to find
+/// the actual interpreter binary (e.g. `python3`).
 pub fn execute_with_interpreter(
-    interpreter: &str,
+    tool: &str,
     content: &str,
     extension: &str,
     options: &ScriptletExecOptions,
 ) -> Result<ScriptletResult, String> {
+    let interpreter = tool_executable_name(tool);
     logging::log(
         "EXEC",
         &format!("Executing with interpreter: {}", interpreter),
@@ -372,10 +501,14 @@ pub fn execute_with_interpreter(
     std::fs::write(&temp_file, content)
         .map_err(|e| format!("Failed to write temp script: {}", e))?;
 
-    // Find the interpreter
-    let interp_path = find_executable(interpreter)
-        .map(|p| p.to_string_lossy().into_owned())
-        .unwrap_or_else(|| interpreter.to_string());
+    // Find the interpreter, erroring clearly (with install hints) if missing
+    let interp_path = match resolve_tool(tool, &options.tool_paths) {
+        Ok(path) => path,
+        Err(e) => {
+            let _ = std::fs::remove_file(&temp_file);
+            return Err(e);
+        }
+    };
 
     let mut cmd = Command::new(&interp_path);
     cmd.arg(temp_file.to_str().unwrap());
@@ -439,10 +572,14 @@ pub fn execute_typescript(
     std::fs::write(&temp_file, content)
         .map_err(|e| format!("Failed to write temp script: {}", e))?;
 
-    // Find bun
-    let bun_path = find_executable("bun")
-        .map(|p| p.to_string_lossy().into_owned())
-        .unwrap_or_else(|| "bun".to_string());
+    // Find bun, erroring clearly (with install hints) if missing
+    let bun_path = match resolve_tool("bun", &options.tool_paths) {
+        Ok(path) => path,
+        Err(e) => {
+            let _ = std::fs::remove_file(&temp_file);
+            return Err(e);
+        }
+    };
 
     // Check if we should use SDK preload
     let sdk_path = find_sdk_path();
@@ -23,6 +23,9 @@ use std::thread::{self, JoinHandle};
 use std::time::Duration;
 use tracing::{debug, warn};
 
+use super::ansi::strip_ansi_codes;
+use super::run_log::RunLogger;
+
 /// Default maximum number of lines to buffer
 pub const DEFAULT_MAX_LINES: usize = 500;
 
@@ -185,6 +188,18 @@ impl StderrCapture {
 pub fn spawn_stderr_reader<R: Read + Send + 'static>(
     stderr: R,
     script_path: String,
+) -> StderrCapture {
+    spawn_stderr_reader_with_run_log(stderr, script_path, None)
+}
+
+/// Same as [`spawn_stderr_reader`], but also tees every line into a per-run
+/// log file if `run_logger` is provided. The `RunLogger` is `Clone` and
+/// shares a single `Mutex<File>` with the stdout reader thread, so lines
+/// from both sources interleave safely in the same file.
+pub fn spawn_stderr_reader_with_run_log<R: Read + Send + 'static>(
+    stderr: R,
+    script_path: String,
+    run_logger: Option<RunLogger>,
 ) -> StderrCapture {
     let buffer = StderrBuffer::default();
     let buffer_clone = buffer.clone();
@@ -194,10 +209,19 @@ pub fn spawn_stderr_reader<R: Read + Send + 'static>(
         for line_result in reader.lines() {
             match line_result {
                 Ok(line) => {
+                    // Strip ANSI escape codes before any sink sees the line -
+                    // none of the debug log, ring buffer, or run log file can
+                    // render styled spans, and raw codes would also break the
+                    // stack-trace/suggestion regexes in `errors.rs`.
+                    let line = strip_ansi_codes(&line);
                     // Log in real-time
                     debug!(target: "SCRIPT", script_path = %script_path, "{}", line);
                     // Buffer for post-mortem
-                    buffer_clone.push_line(line);
+                    buffer_clone.push_line(line.clone());
+                    // Tee into the per-run log file, if one was set up
+                    if let Some(ref logger) = run_logger {
+                        logger.write_line("STDERR", &line);
+                    }
                 }
                 Err(e) => {
                     warn!(target: "SCRIPT", error = %e, "stderr read error");
@@ -628,6 +628,11 @@ fn test_auto_submit_config_get_arg_value() {
             description: None,
             key: None,
             semantic_id: None,
+            img: None,
+            icon: None,
+            confirm: None,
+            preview: None,
+            actions: None,
         },
         Choice {
             name: "Banana".to_string(),
@@ -635,6 +640,11 @@ fn test_auto_submit_config_get_arg_value() {
             description: None,
             key: None,
             semantic_id: None,
+            img: None,
+            icon: None,
+            confirm: None,
+            preview: None,
+            actions: None,
         },
         Choice {
             name: "Cherry".to_string(),
@@ -642,6 +652,11 @@ fn test_auto_submit_config_get_arg_value() {
             description: None,
             key: None,
             semantic_id: None,
+            img: None,
+            icon: None,
+            confirm: None,
+            preview: None,
+            actions: None,
         },
     ];
 
@@ -761,6 +776,11 @@ fn test_auto_submit_config_get_select_value() {
             description: None,
             key: None,
             semantic_id: None,
+            img: None,
+            icon: None,
+            confirm: None,
+            preview: None,
+            actions: None,
         },
         Choice {
             name: "Banana".to_string(),
@@ -768,6 +788,11 @@ fn test_auto_submit_config_get_select_value() {
             description: None,
             key: None,
             semantic_id: None,
+            img: None,
+            icon: None,
+            confirm: None,
+            preview: None,
+            actions: None,
         },
     ];
 
@@ -1821,6 +1846,78 @@ fn test_execute_shell_scriptlet_error_includes_suggestions() {
     );
 }
 
+// ============================================================
+// resolve_tool() Tests
+// ============================================================
+
+use super::resolve_tool;
+
+#[test]
+fn test_resolve_tool_finds_bash_on_path() {
+    // bash is present on every CI/dev machine this test runs on
+    let result = resolve_tool("bash", &HashMap::new());
+    assert!(result.is_ok(), "Expected to find bash, got: {:?}", result);
+}
+
+#[test]
+fn test_resolve_tool_missing_interpreter_has_install_hint() {
+    let result = resolve_tool("nonexistent_tool_xyz123", &HashMap::new());
+    assert!(result.is_err(), "Should fail for a nonexistent tool");
+
+    let err = result.unwrap_err();
+    assert!(
+        err.contains("nonexistent_tool_xyz123"),
+        "Error should name the missing tool: '{}'",
+        err
+    );
+    assert!(
+        err.contains("toolPaths"),
+        "Error should mention the toolPaths config override: '{}'",
+        err
+    );
+}
+
+#[test]
+fn test_resolve_tool_respects_override_path() {
+    // Point the "python" tool at bash's own path (any real executable works
+    // for proving the override is honored without depending on python3)
+    let bash_path = resolve_tool("bash", &HashMap::new())
+        .expect("bash should be resolvable")
+        .to_string_lossy()
+        .into_owned();
+
+    let mut overrides = HashMap::new();
+    overrides.insert("python".to_string(), bash_path.clone());
+
+    let result = resolve_tool("python", &overrides);
+    assert!(result.is_ok(), "Override path should resolve: {:?}", result);
+    assert_eq!(result.unwrap().to_string_lossy(), bash_path);
+}
+
+#[test]
+fn test_resolve_tool_errors_clearly_on_misconfigured_override() {
+    let mut overrides = HashMap::new();
+    overrides.insert(
+        "python".to_string(),
+        "/no/such/interpreter/anywhere".to_string(),
+    );
+
+    let result = resolve_tool("python", &overrides);
+    assert!(result.is_err(), "Nonexistent override path should fail");
+
+    let err = result.unwrap_err();
+    assert!(
+        err.contains("toolPaths.python"),
+        "Error should name the misconfigured override key: '{}'",
+        err
+    );
+    assert!(
+        err.contains("/no/such/interpreter/anywhere"),
+        "Error should include the configured path: '{}'",
+        err
+    );
+}
+
 // ============================================================
 // Special Tool Tests (template, transform, edit, paste, type, submit, open)
 // ============================================================
@@ -32,7 +32,7 @@ use tracing::{debug, error, info, instrument, warn};
 use crate::expand_matcher::ExpandMatcher;
 use crate::keyboard_monitor::{KeyEvent, KeyboardMonitor, KeyboardMonitorError};
 use crate::scripts::read_scriptlets;
-use crate::template_variables::substitute_variables;
+use crate::template_variables::{substitute_variables_with_cursor_context, VariableContext};
 use crate::text_injector::{TextInjector, TextInjectorConfig};
 
 /// Delay after stopping monitor before performing expansion (ms)
@@ -275,6 +275,12 @@ impl ExpandManager {
 
             // Only process printable characters (ignore modifier keys, etc.)
             if let Some(ref character) = event.character {
+                // Global kill switch, toggled from the tray menu
+                if crate::is_paused() {
+                    debug!(character = %character, "Skipping keystroke - hotkeys/expansion paused");
+                    return;
+                }
+
                 // Skip if any modifier is held (except shift for capitals)
                 if event.command || event.control || event.option {
                     debug!(character = %character, "Skipping due to modifier key");
@@ -334,12 +340,17 @@ impl ExpandManager {
 
                                 // Substitute template variables (${clipboard}, ${date}, etc.)
                                 // Uses the centralized template_variables module
-                                let replacement = substitute_variables(&raw_content);
+                                let (replacement, cursor_offset) =
+                                    substitute_variables_with_cursor_context(
+                                        &raw_content,
+                                        &VariableContext::new(),
+                                    );
 
                                 debug!(
                                     original_len = raw_content.len(),
                                     substituted_len = replacement.len(),
                                     had_substitutions = raw_content != replacement,
+                                    cursor_offset = ?cursor_offset,
                                     "Variable substitution completed"
                                 );
 
@@ -368,6 +379,23 @@ impl ExpandManager {
                                     return;
                                 }
 
+                                // If the snippet had a {cursor} marker, move the
+                                // cursor back from the end of the pasted text to
+                                // where the marker was
+                                if let Some(offset) = cursor_offset {
+                                    let chars_after_cursor = replacement[offset..].chars().count();
+                                    if chars_after_cursor > 0 {
+                                        if let Err(e) =
+                                            injector.move_cursor_left(chars_after_cursor)
+                                        {
+                                            warn!(
+                                                error = %e,
+                                                "Failed to reposition cursor after expansion"
+                                            );
+                                        }
+                                    }
+                                }
+
                                 info!(
                                     trigger = %name,
                                     replacement_len = replacement.len(),
@@ -7,6 +7,9 @@
 //! NOTE: Some items are currently unused as this is a new module being integrated.
 #![allow(dead_code)]
 
+use std::sync::OnceLock;
+
+use crate::config::FallbackTemplateConfig;
 use crate::scripts::input_detection::{is_file_path, is_math_expression, is_url, InputType};
 
 /// Simple percent-encoding for URL query strings
@@ -312,33 +315,84 @@ pub fn get_builtin_fallbacks() -> Vec<BuiltinFallback> {
             enabled: true,
             priority: 22,
         },
-        BuiltinFallback {
-            id: "search-google",
-            name: "Search Google",
-            description: "Search Google for this text",
-            icon: "search",
-            action: FallbackAction::SearchUrl {
-                template: "https://www.google.com/search?q={query}".to_string(),
-            },
-            condition: FallbackCondition::Always,
-            enabled: true,
-            priority: 30,
-        },
-        BuiltinFallback {
-            id: "search-duckduckgo",
-            name: "Search DuckDuckGo",
-            description: "Search DuckDuckGo for this text",
-            icon: "search",
-            action: FallbackAction::SearchUrl {
-                template: "https://duckduckgo.com/?q={query}".to_string(),
-            },
-            condition: FallbackCondition::Always,
-            enabled: true,
-            priority: 31,
-        },
+        // Search Google/DuckDuckGo/Define are generated from the configurable
+        // `fallbacks` templates instead (see `get_template_fallbacks` below),
+        // so their set and order can be customized.
     ]
 }
 
+/// A fallback generated from a configurable URL template (the `fallbacks`
+/// config setting), e.g. "Search Google", "Define".
+///
+/// Shaped like [`BuiltinFallback`] but holds owned strings since its name
+/// and URL come from user config rather than a `&'static` literal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateFallback {
+    pub name: String,
+    pub url_template: String,
+    pub priority: u8,
+}
+
+impl TemplateFallback {
+    /// Build the target URL by percent-encoding `input` and substituting it
+    /// into the `{query}` placeholder.
+    pub fn build_url(&self, input: &str) -> String {
+        self.url_template.replace("{query}", &percent_encode(input))
+    }
+
+    /// Icon shown next to this fallback - `dict://` templates get the
+    /// dictionary icon, everything else gets the generic search icon.
+    pub fn icon(&self) -> &'static str {
+        if self.url_template.starts_with("dict://") {
+            "book-open"
+        } else {
+            "search"
+        }
+    }
+
+    /// Get the display subtitle with input preview, e.g. "Search Google for 'hello'"
+    pub fn get_subtitle(&self, input: &str) -> String {
+        let truncated = if input.len() > 40 {
+            format!("{}...", &input[..37])
+        } else {
+            input.to_string()
+        };
+        format!("{} for '{}'", self.name, truncated)
+    }
+}
+
+/// Configured fallback templates, set once at startup from the loaded config
+/// (see `set_fallback_templates`). Falls back to
+/// [`crate::config::default_fallback_templates`] until then.
+static FALLBACK_TEMPLATES: OnceLock<Vec<FallbackTemplateConfig>> = OnceLock::new();
+
+/// Set the configured fallback templates (call once at startup, before any
+/// search happens).
+pub fn set_fallback_templates(templates: Vec<FallbackTemplateConfig>) {
+    let _ = FALLBACK_TEMPLATES.set(templates);
+}
+
+/// Get the fallback rows generated from the configured (or default) URL
+/// templates, in configured order.
+pub fn get_template_fallbacks() -> Vec<TemplateFallback> {
+    let templates = FALLBACK_TEMPLATES
+        .get()
+        .cloned()
+        .unwrap_or_else(crate::config::default_fallback_templates);
+
+    templates
+        .into_iter()
+        .enumerate()
+        .map(|(i, t)| TemplateFallback {
+            name: t.name,
+            url_template: t.url,
+            // Slot in after the fixed "always" fallbacks (run-in-terminal=20,
+            // add-to-notes=21, copy-to-clipboard=22), preserving config order.
+            priority: 30 + i as u8,
+        })
+        .collect()
+}
+
 /// Get fallbacks that are applicable for the given input
 ///
 /// Filters the built-in fallbacks based on their conditions and the input type.
@@ -362,7 +416,7 @@ mod tests {
     #[test]
     fn test_get_builtin_fallbacks_count() {
         let fallbacks = get_builtin_fallbacks();
-        assert_eq!(fallbacks.len(), 9, "Should have 9 built-in fallbacks");
+        assert_eq!(fallbacks.len(), 7, "Should have 7 built-in fallbacks");
     }
 
     #[test]
@@ -411,8 +465,6 @@ mod tests {
         assert!(ids.contains(&"run-in-terminal"));
         assert!(ids.contains(&"add-to-notes"));
         assert!(ids.contains(&"copy-to-clipboard"));
-        assert!(ids.contains(&"search-google"));
-        assert!(ids.contains(&"search-duckduckgo"));
 
         // Should NOT include conditional fallbacks
         assert!(!ids.contains(&"open-url"));
@@ -431,7 +483,6 @@ mod tests {
 
         // Should still include always fallbacks
         assert!(ids.contains(&"copy-to-clipboard"));
-        assert!(ids.contains(&"search-google"));
     }
 
     #[test]
@@ -489,18 +540,45 @@ mod tests {
     }
 
     #[test]
-    fn test_execute_search_google() {
-        let fallbacks = get_builtin_fallbacks();
-        let google = fallbacks.iter().find(|f| f.id == "search-google").unwrap();
+    fn test_template_fallback_build_url() {
+        let google = TemplateFallback {
+            name: "Search Google".to_string(),
+            url_template: "https://www.google.com/search?q={query}".to_string(),
+            priority: 30,
+        };
 
-        let result = google.execute("hello world").unwrap();
-        match result {
-            FallbackResult::OpenUrl { url } => {
-                assert!(url.contains("google.com"));
-                assert!(url.contains("hello%20world"));
-            }
-            _ => panic!("Expected OpenUrl result"),
-        }
+        let url = google.build_url("hello world");
+        assert!(url.contains("google.com"));
+        assert!(url.contains("hello%20world"));
+    }
+
+    #[test]
+    fn test_template_fallback_icon() {
+        let search = TemplateFallback {
+            name: "Search Google".to_string(),
+            url_template: "https://www.google.com/search?q={query}".to_string(),
+            priority: 30,
+        };
+        assert_eq!(search.icon(), "search");
+
+        let define = TemplateFallback {
+            name: "Define".to_string(),
+            url_template: "dict://{query}".to_string(),
+            priority: 32,
+        };
+        assert_eq!(define.icon(), "book-open");
+    }
+
+    #[test]
+    fn test_get_template_fallbacks_uses_defaults() {
+        let fallbacks = get_template_fallbacks();
+        let names: Vec<&str> = fallbacks.iter().map(|f| f.name.as_str()).collect();
+        assert!(names.contains(&"Search Google"));
+        assert!(names.contains(&"Search DuckDuckGo"));
+        assert!(names.contains(&"Define"));
+
+        // Priorities should follow config order, starting after the fixed fallbacks.
+        assert!(fallbacks.iter().all(|f| f.priority >= 30));
     }
 
     #[test]
@@ -527,10 +605,22 @@ mod tests {
     #[test]
     fn test_get_subtitle() {
         let fallbacks = get_builtin_fallbacks();
-        let google = fallbacks.iter().find(|f| f.id == "search-google").unwrap();
+        let open_url = fallbacks.iter().find(|f| f.id == "open-url").unwrap();
+
+        let subtitle = open_url.get_subtitle("https://example.com");
+        assert_eq!(subtitle, "Open https://example.com");
+    }
+
+    #[test]
+    fn test_template_fallback_get_subtitle() {
+        let google = TemplateFallback {
+            name: "Search Google".to_string(),
+            url_template: "https://www.google.com/search?q={query}".to_string(),
+            priority: 30,
+        };
 
         let subtitle = google.get_subtitle("hello");
-        assert_eq!(subtitle, "Search for 'hello'");
+        assert_eq!(subtitle, "Search Google for 'hello'");
     }
 
     #[test]
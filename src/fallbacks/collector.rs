@@ -8,14 +8,19 @@
 
 use std::sync::Arc;
 
-use crate::fallbacks::builtins::{get_applicable_fallbacks, BuiltinFallback};
+use crate::fallbacks::builtins::{
+    get_applicable_fallbacks, get_template_fallbacks, BuiltinFallback, TemplateFallback,
+};
 use crate::scripts::{FallbackConfig, Script};
 
-/// Unified fallback item representing either a built-in fallback or a user script fallback
+/// Unified fallback item representing either a built-in fallback, a
+/// configurable URL-template fallback, or a user script fallback
 #[derive(Debug, Clone)]
 pub enum FallbackItem {
-    /// A built-in fallback command (Search Google, Copy to Clipboard, etc.)
+    /// A built-in fallback command (Copy to Clipboard, Calculate, etc.)
     Builtin(BuiltinFallback),
+    /// A fallback generated from a configured URL template (Search Google, Define, etc.)
+    Template(TemplateFallback),
     /// A user script with `fallback: true` in its metadata
     Script(FallbackConfig),
 }
@@ -25,6 +30,7 @@ impl FallbackItem {
     pub fn name(&self) -> &str {
         match self {
             FallbackItem::Builtin(b) => b.name,
+            FallbackItem::Template(t) => &t.name,
             FallbackItem::Script(s) => &s.script.name,
         }
     }
@@ -33,6 +39,7 @@ impl FallbackItem {
     pub fn description(&self) -> &str {
         match self {
             FallbackItem::Builtin(b) => b.description,
+            FallbackItem::Template(t) => &t.name,
             FallbackItem::Script(s) => s
                 .script
                 .description
@@ -45,6 +52,7 @@ impl FallbackItem {
     pub fn icon(&self) -> &str {
         match self {
             FallbackItem::Builtin(b) => b.icon,
+            FallbackItem::Template(t) => t.icon(),
             FallbackItem::Script(s) => s.script.icon.as_deref().unwrap_or("terminal"),
         }
     }
@@ -53,6 +61,7 @@ impl FallbackItem {
     pub fn priority(&self) -> u32 {
         match self {
             FallbackItem::Builtin(b) => b.priority as u32,
+            FallbackItem::Template(t) => t.priority as u32,
             // User script fallbacks have priority 50 (between conditional 10-12 and always 20-31)
             FallbackItem::Script(_) => 50,
         }
@@ -62,6 +71,7 @@ impl FallbackItem {
     pub fn label(&self) -> &str {
         match self {
             FallbackItem::Builtin(b) => b.name,
+            FallbackItem::Template(t) => &t.name,
             FallbackItem::Script(s) => &s.label,
         }
     }
@@ -71,6 +81,11 @@ impl FallbackItem {
         matches!(self, FallbackItem::Builtin(_))
     }
 
+    /// Check if this is a configured URL-template fallback
+    pub fn is_template(&self) -> bool {
+        matches!(self, FallbackItem::Template(_))
+    }
+
     /// Check if this is a script fallback
     pub fn is_script(&self) -> bool {
         matches!(self, FallbackItem::Script(_))
@@ -81,9 +96,10 @@ impl FallbackItem {
 ///
 /// This function:
 /// 1. Gets all applicable built-in fallbacks (filtered by input type)
-/// 2. Gets all user scripts with `fallback: true` metadata
-/// 3. Applies input substitution to script fallback labels
-/// 4. Sorts by priority (lower = higher in list)
+/// 2. Gets the configured URL-template fallbacks (Search Google, Define, etc.)
+/// 3. Gets all user scripts with `fallback: true` metadata
+/// 4. Applies input substitution to script fallback labels
+/// 5. Sorts by priority (lower = higher in list)
 ///
 /// # Arguments
 /// * `input` - The current user input text
@@ -99,7 +115,12 @@ pub fn collect_fallbacks(input: &str, scripts: &[Arc<Script>]) -> Vec<FallbackIt
         fallbacks.push(FallbackItem::Builtin(builtin));
     }
 
-    // 2. Add user scripts with fallback: true metadata
+    // 2. Add the configured URL-template fallbacks (Search Google, Define, etc.)
+    for template in get_template_fallbacks() {
+        fallbacks.push(FallbackItem::Template(template));
+    }
+
+    // 3. Add user scripts with fallback: true metadata
     for script in scripts {
         // Try to create a FallbackConfig from the script
         if let Some(config) = FallbackConfig::from_script(script.clone()) {
@@ -109,7 +130,7 @@ pub fn collect_fallbacks(input: &str, scripts: &[Arc<Script>]) -> Vec<FallbackIt
         }
     }
 
-    // 3. Sort by priority (lower = higher in list)
+    // 4. Sort by priority (lower = higher in list)
     fallbacks.sort_by_key(|f| f.priority());
 
     fallbacks
@@ -160,6 +181,12 @@ mod tests {
                 ..Default::default()
             }),
             schema: None,
+            concurrency: Default::default(),
+            tray: false,
+            background: false,
+            keep_open: false,
+            kenv: None,
+            app_filter: None,
         })
     }
 
@@ -175,6 +202,12 @@ mod tests {
             shortcut: None,
             typed_metadata: None,
             schema: None,
+            concurrency: Default::default(),
+            tray: false,
+            background: false,
+            keep_open: false,
+            kenv: None,
+            app_filter: None,
         })
     }
 
@@ -182,9 +215,9 @@ mod tests {
     fn test_collect_fallbacks_empty_scripts() {
         let fallbacks = collect_fallbacks("hello", &[]);
 
-        // Should have only built-in fallbacks
+        // Should have only built-in and template fallbacks (no scripts)
         assert!(!fallbacks.is_empty());
-        assert!(fallbacks.iter().all(|f| f.is_builtin()));
+        assert!(fallbacks.iter().all(|f| f.is_builtin() || f.is_template()));
     }
 
     #[test]
@@ -214,8 +247,8 @@ mod tests {
 
         let fallbacks = collect_fallbacks("hello", &scripts);
 
-        // Should have only built-in fallbacks (no script fallbacks)
-        assert!(fallbacks.iter().all(|f| f.is_builtin()));
+        // Should have only built-in and template fallbacks (no script fallbacks)
+        assert!(fallbacks.iter().all(|f| f.is_builtin() || f.is_template()));
     }
 
     #[test]
@@ -316,6 +349,20 @@ mod tests {
         assert!(has_calculate, "Math input should show 'calculate' fallback");
     }
 
+    #[test]
+    fn test_collect_fallbacks_includes_template_fallbacks() {
+        let fallbacks = collect_fallbacks("hello", &[]);
+
+        let names: Vec<&str> = fallbacks
+            .iter()
+            .filter(|f| f.is_template())
+            .map(|f| f.name())
+            .collect();
+        assert!(names.contains(&"Search Google"));
+        assert!(names.contains(&"Search DuckDuckGo"));
+        assert!(names.contains(&"Define"));
+    }
+
     #[test]
     fn test_file_path_input_includes_open_file_fallback() {
         let fallbacks = collect_builtin_fallbacks("/Users/test/Documents");
@@ -8,8 +8,9 @@ pub mod collector;
 
 #[allow(unused_imports)]
 pub use builtins::{
-    get_applicable_fallbacks, get_builtin_fallbacks, BuiltinFallback, FallbackAction,
-    FallbackCondition, FallbackResult,
+    get_applicable_fallbacks, get_builtin_fallbacks, get_template_fallbacks,
+    set_fallback_templates, BuiltinFallback, FallbackAction, FallbackCondition, FallbackResult,
+    TemplateFallback,
 };
 #[allow(unused_imports)]
 pub use collector::{
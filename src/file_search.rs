@@ -398,29 +398,43 @@ pub fn open_file(path: &str) -> Result<(), String> {
     }
 }
 
-/// Reveal a file in Finder (macOS) or file manager
+/// Reveal a file or directory in Finder (macOS) or file manager.
+///
+/// Directories are opened directly; files are revealed (selected) inside
+/// their containing folder.
 #[allow(dead_code)]
-pub fn reveal_in_finder(path: &str) -> Result<(), String> {
+pub fn reveal_in_finder(path: &str, is_dir: bool) -> Result<(), String> {
     use std::process::Command;
 
     #[cfg(target_os = "macos")]
     {
-        Command::new("open")
-            .args(["-R", path])
-            .spawn()
-            .map_err(|e| format!("Failed to reveal file: {}", e))?;
+        if is_dir {
+            Command::new("open")
+                .arg(path)
+                .spawn()
+                .map_err(|e| format!("Failed to open directory: {}", e))?;
+        } else {
+            Command::new("open")
+                .args(["-R", path])
+                .spawn()
+                .map_err(|e| format!("Failed to reveal file: {}", e))?;
+        }
         Ok(())
     }
 
     #[cfg(target_os = "linux")]
     {
-        // Try to get the parent directory and open it
-        let parent = std::path::Path::new(path)
-            .parent()
-            .map(|p| p.to_string_lossy().to_string())
-            .unwrap_or_else(|| path.to_string());
+        let target = if is_dir {
+            path.to_string()
+        } else {
+            // Try to get the parent directory and open it
+            std::path::Path::new(path)
+                .parent()
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.to_string())
+        };
         Command::new("xdg-open")
-            .arg(&parent)
+            .arg(&target)
             .spawn()
             .map_err(|e| format!("Failed to reveal file: {}", e))?;
         Ok(())
@@ -428,10 +442,17 @@ pub fn reveal_in_finder(path: &str) -> Result<(), String> {
 
     #[cfg(target_os = "windows")]
     {
-        Command::new("explorer")
-            .args(["/select,", path])
-            .spawn()
-            .map_err(|e| format!("Failed to reveal file: {}", e))?;
+        if is_dir {
+            Command::new("explorer")
+                .arg(path)
+                .spawn()
+                .map_err(|e| format!("Failed to open directory: {}", e))?;
+        } else {
+            Command::new("explorer")
+                .args(["/select,", path])
+                .spawn()
+                .map_err(|e| format!("Failed to reveal file: {}", e))?;
+        }
         Ok(())
     }
 }
@@ -62,6 +62,23 @@ impl FrecencyEntry {
         self.score * Self::decay_factor(dt, half_life_days)
     }
 
+    /// Render a short "2h ago · 14 runs" annotation for list item display,
+    /// using the current wall-clock time.
+    pub fn usage_annotation(&self) -> String {
+        self.usage_annotation_at(current_timestamp())
+    }
+
+    /// Same as [`usage_annotation`](Self::usage_annotation), but with an
+    /// explicit `now` for deterministic testing.
+    pub fn usage_annotation_at(&self, now: u64) -> String {
+        format!(
+            "{} · {} run{}",
+            crate::utils::format_relative_time(now, self.last_used),
+            self.count,
+            if self.count == 1 { "" } else { "s" }
+        )
+    }
+
     /// Record a new use with explicit timestamp (incremental frecency model)
     ///
     /// Uses the incremental model: new_score = old_score * decay(elapsed_time) + 1
@@ -407,6 +424,12 @@ impl FrecencyStore {
         self.entries.get(path).map(|e| e.score).unwrap_or(0.0)
     }
 
+    /// Get the raw frecency entry for a path (count + last used timestamp),
+    /// if one exists. Used to render "2h ago · 14 runs" style annotations.
+    pub fn get_entry(&self, path: &str) -> Option<&FrecencyEntry> {
+        self.entries.get(path)
+    }
+
     /// Get the top N items by frecency score
     ///
     /// Computes live scores (with decay) for accurate ranking.
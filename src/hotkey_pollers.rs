@@ -3,6 +3,7 @@ use gpui::{px, size, App, AppContext as _, AsyncApp, Context, Focusable, Window,
 use crate::ai;
 use crate::hotkeys;
 use crate::notes;
+use crate::perf;
 use crate::platform::calculate_eye_line_bounds_on_mouse_display;
 use crate::window_ops;
 use crate::window_resize::{initial_window_height, reset_resize_debounce};
@@ -27,10 +28,17 @@ impl HotkeyPoller {
             logging::log("HOTKEY", "Hotkey listener started (event-driven via async_channel)");
 
             while let Ok(()) = hotkeys::hotkey_channel().1.recv().await {
-                logging::log("VISIBILITY", "");
-                logging::log("VISIBILITY", "╔════════════════════════════════════════════════════════════╗");
-                logging::log("VISIBILITY", "║  HOTKEY TRIGGERED - TOGGLE WINDOW                          ║");
-                logging::log("VISIBILITY", "╚════════════════════════════════════════════════════════════╝");
+                // Collapse a rapid-press burst into a single state change instead
+                // of replaying each queued toggle (which would flap the window).
+                let extra = hotkeys::drain_pending_toggles(&hotkeys::hotkey_channel().1);
+                if extra > 0 {
+                    logging::log(
+                        "HOTKEY",
+                        &format!("Coalesced {} queued toggle(s) from rapid-press burst", extra),
+                    );
+                }
+
+                logging::log_banner("VISIBILITY", "HOTKEY TRIGGERED - TOGGLE WINDOW");
 
                 // CRITICAL: If Notes or AI windows are open, the main hotkey should be completely ignored.
                 // The hotkeys are independent - main hotkey should have ZERO effect on Notes/AI.
@@ -110,6 +118,7 @@ impl HotkeyPoller {
                         }
 
                         let hide_elapsed = hide_start.elapsed();
+                        perf::diagnostics().window_hide_latency.record(hide_elapsed);
                         logging::log(
                             "PERF",
                             &format!("Window hide took {:.2}ms", hide_elapsed.as_secs_f64() * 1000.0),
@@ -118,6 +127,7 @@ impl HotkeyPoller {
                     });
                 } else {
                     logging::log("VISIBILITY", "Decision: SHOW (window is currently hidden)");
+                    let show_start = std::time::Instant::now();
 
                     // Menu bar tracking is now handled by frontmost_app_tracker module
                     // which pre-fetches menu items in background when apps activate
@@ -136,20 +146,9 @@ impl HotkeyPoller {
                         // or win.activate_window() to prevent macOS from switching spaces
                         platform::ensure_move_to_active_space();
 
-                        // Step 1: Calculate new bounds on display with mouse, at eye-line height
+                        // Step 1: Window size is fixed; exact bounds are computed in Step 3 once
+                        // `view.config` (and thus the configured position mode) is available.
                         let window_size = size(px(750.), initial_window_height());
-                        let new_bounds = calculate_eye_line_bounds_on_mouse_display(window_size);
-
-                        logging::log(
-                            "HOTKEY",
-                            &format!(
-                                "Calculated bounds: origin=({:.0}, {:.0}) size={:.0}x{:.0}",
-                                f64::from(new_bounds.origin.x),
-                                f64::from(new_bounds.origin.y),
-                                f64::from(new_bounds.size.width),
-                                f64::from(new_bounds.size.height)
-                            ),
-                        );
 
                         // Step 2: NOW activate the app (makes window visible at new position)
                         cx.activate(true);
@@ -170,6 +169,23 @@ impl HotkeyPoller {
                                 win.focus(&focus_handle, ctx);
                                 logging::log("HOTKEY", "Window activated and focused");
 
+                                // Calculate new bounds on display with mouse, at eye-line height
+                                // (or the fixed saved position, per config)
+                                let new_bounds = calculate_eye_line_bounds_on_mouse_display(
+                                    window_size,
+                                    view.config.get_window_position_mode(),
+                                );
+                                logging::log(
+                                    "HOTKEY",
+                                    &format!(
+                                        "Calculated bounds: origin=({:.0}, {:.0}) size={:.0}x{:.0}",
+                                        f64::from(new_bounds.origin.x),
+                                        f64::from(new_bounds.origin.y),
+                                        f64::from(new_bounds.size.width),
+                                        f64::from(new_bounds.size.height)
+                                    ),
+                                );
+
                                 // Menu bar items are now tracked by frontmost_app_tracker
                                 // No state reset needed here
 
@@ -203,6 +219,13 @@ impl HotkeyPoller {
 
                         logging::log("VISIBILITY", "Window show sequence complete");
                     });
+
+                    let show_elapsed = show_start.elapsed();
+                    perf::diagnostics().window_show_latency.record(show_elapsed);
+                    logging::log(
+                        "PERF",
+                        &format!("Window show took {:.2}ms", show_elapsed.as_secs_f64() * 1000.0),
+                    );
                 }
 
                 let final_visible = script_kit_gpui::is_main_window_visible();
@@ -236,13 +259,14 @@ impl ScriptHotkeyPoller {
         cx.spawn(async move |_this, cx: &mut AsyncApp| {
             logging::log("HOTKEY", "Script hotkey listener started");
 
-            while let Ok(command_id) = hotkeys::script_hotkey_channel().1.recv().await {
+            while let Ok((command_id, args)) = hotkeys::script_hotkey_channel().1.recv().await {
                 logging::log(
                     "HOTKEY",
-                    &format!("Script shortcut received: {}", command_id),
+                    &format!("Script shortcut received: {} (args={:?})", command_id, args),
                 );
 
                 let id_clone = command_id.clone();
+                let args_clone = args.clone();
                 let _ = cx.update(move |cx: &mut App| {
                     // Execute command and check if main window should be shown
                     let should_show = window
@@ -253,7 +277,7 @@ impl ScriptHotkeyPoller {
                              ctx: &mut Context<ScriptListApp>| {
                                 // Handle both file paths (legacy) and command IDs (new format)
                                 // Returns whether main window should be shown
-                                view.execute_by_command_id_or_path(&id_clone, ctx)
+                                view.execute_by_command_id_or_path(&id_clone, args_clone, ctx)
                             },
                         )
                         .unwrap_or(true); // Default to showing window on error
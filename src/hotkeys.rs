@@ -4,7 +4,7 @@ use global_hotkey::{
 };
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::{Mutex, OnceLock, RwLock};
+use std::sync::{mpsc, Mutex, OnceLock, RwLock};
 
 use crate::{config, logging, scripts, shortcuts};
 
@@ -713,6 +713,211 @@ pub fn unregister_dynamic_shortcut(command_id: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+// =============================================================================
+// Session hotkeys (registered by the currently running script)
+// =============================================================================
+// Unlike the per-script-file shortcuts above (registered from metadata and
+// always meaning "launch this script"), these are registered on demand by a
+// running script via the `registerHotkey` protocol message and forward
+// presses back to that same script as `hotkeyPressed` messages. They live
+// only as long as the script session that registered them.
+
+/// Abstraction over OS-level hotkey (un)registration, so the bookkeeping in
+/// `SessionHotkeys` can be unit tested with a mock backend instead of a real
+/// `GlobalHotKeyManager`, which requires OS accessibility permissions.
+trait HotkeyBackend {
+    fn register(&self, hotkey: HotKey) -> Result<(), HotkeyError>;
+    fn unregister(&self, hotkey: HotKey) -> Result<(), HotkeyError>;
+}
+
+impl HotkeyBackend for GlobalHotKeyManager {
+    fn register(&self, hotkey: HotKey) -> Result<(), HotkeyError> {
+        GlobalHotKeyManager::register(self, hotkey)
+    }
+
+    fn unregister(&self, hotkey: HotKey) -> Result<(), HotkeyError> {
+        GlobalHotKeyManager::unregister(self, hotkey)
+    }
+}
+
+struct SessionHotkeyEntry {
+    hotkey: HotKey,
+    response_sender: mpsc::SyncSender<crate::protocol::Message>,
+}
+
+/// Bookkeeping for hotkeys registered by the currently running script.
+#[derive(Default)]
+struct SessionHotkeys {
+    by_id: HashMap<String, SessionHotkeyEntry>,
+}
+
+impl SessionHotkeys {
+    fn register(
+        &mut self,
+        backend: &dyn HotkeyBackend,
+        id: &str,
+        shortcut: &str,
+        response_sender: mpsc::SyncSender<crate::protocol::Message>,
+    ) -> Result<(), String> {
+        if self.by_id.contains_key(id) {
+            return Err(format!("Hotkey id '{}' is already registered", id));
+        }
+
+        let (mods, code) = shortcuts::parse_shortcut(shortcut)
+            .ok_or_else(|| format!("Failed to parse shortcut: {}", shortcut))?;
+        let hotkey = HotKey::new(Some(mods), code);
+
+        // Reject conflicts with the main/notes/ai/script shortcuts...
+        if routes().read().unwrap().get_action(hotkey.id()).is_some() {
+            return Err(format!(
+                "Shortcut '{}' conflicts with an existing hotkey",
+                shortcut
+            ));
+        }
+        // ...and with another hotkey this (or another) script already registered.
+        if self
+            .by_id
+            .values()
+            .any(|entry| entry.hotkey.id() == hotkey.id())
+        {
+            return Err(format!(
+                "Shortcut '{}' conflicts with another registered hotkey",
+                shortcut
+            ));
+        }
+
+        backend
+            .register(hotkey)
+            .map_err(|e| format!("Failed to register '{}': {}", shortcut, e))?;
+
+        self.by_id.insert(
+            id.to_string(),
+            SessionHotkeyEntry {
+                hotkey,
+                response_sender,
+            },
+        );
+        Ok(())
+    }
+
+    fn unregister(&mut self, backend: &dyn HotkeyBackend, id: &str) {
+        if let Some(entry) = self.by_id.remove(id) {
+            if let Err(e) = backend.unregister(entry.hotkey) {
+                logging::log(
+                    "HOTKEY",
+                    &format!(
+                        "Warning: failed to unregister session hotkey '{}': {}",
+                        id, e
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Unregister everything - called when the owning script exits.
+    fn clear(&mut self, backend: &dyn HotkeyBackend) {
+        for (id, entry) in self.by_id.drain() {
+            if let Err(e) = backend.unregister(entry.hotkey) {
+                logging::log(
+                    "HOTKEY",
+                    &format!(
+                        "Warning: failed to unregister session hotkey '{}' on cleanup: {}",
+                        id, e
+                    ),
+                );
+            }
+        }
+    }
+
+    fn find_by_hotkey_id(
+        &self,
+        hotkey_id: u32,
+    ) -> Option<(String, mpsc::SyncSender<crate::protocol::Message>)> {
+        self.by_id
+            .iter()
+            .find(|(_, entry)| entry.hotkey.id() == hotkey_id)
+            .map(|(id, entry)| (id.clone(), entry.response_sender.clone()))
+    }
+}
+
+static SESSION_HOTKEYS: OnceLock<Mutex<SessionHotkeys>> = OnceLock::new();
+
+fn session_hotkeys() -> &'static Mutex<SessionHotkeys> {
+    SESSION_HOTKEYS.get_or_init(|| Mutex::new(SessionHotkeys::default()))
+}
+
+/// Register a hotkey on behalf of the currently running script. `id` is
+/// chosen by the script; presses are delivered back to it as
+/// `Message::HotkeyPressed { id }` via `response_sender`.
+pub fn register_session_hotkey(
+    id: &str,
+    shortcut: &str,
+    response_sender: mpsc::SyncSender<crate::protocol::Message>,
+) -> Result<(), String> {
+    let manager = MAIN_MANAGER
+        .get()
+        .ok_or_else(|| "Hotkey manager not initialized".to_string())?;
+    let manager_guard = manager
+        .lock()
+        .map_err(|e| format!("Lock poisoned: {}", e))?;
+    session_hotkeys()
+        .lock()
+        .map_err(|e| format!("Lock poisoned: {}", e))?
+        .register(&*manager_guard, id, shortcut, response_sender)
+}
+
+/// Unregister a session hotkey by id. No-op if `id` isn't registered.
+pub fn unregister_session_hotkey(id: &str) {
+    let Some(manager) = MAIN_MANAGER.get() else {
+        return;
+    };
+    let Ok(manager_guard) = manager.lock() else {
+        return;
+    };
+    let Ok(mut session_guard) = session_hotkeys().lock() else {
+        return;
+    };
+    session_guard.unregister(&*manager_guard, id);
+}
+
+/// Force-unregister every session hotkey. Called when the owning script
+/// exits, so a hung/forgotten `unregisterHotkey` can't leak a global shortcut.
+pub fn clear_session_hotkeys() {
+    let Some(manager) = MAIN_MANAGER.get() else {
+        return;
+    };
+    let Ok(manager_guard) = manager.lock() else {
+        return;
+    };
+    let Ok(mut session_guard) = session_hotkeys().lock() else {
+        return;
+    };
+    session_guard.clear(&*manager_guard);
+}
+
+/// Look up and notify the script that registered `hotkey_id`, if any.
+/// Returns `true` if a session hotkey handled the event.
+fn dispatch_session_hotkey_press(hotkey_id: u32) -> bool {
+    let Ok(session_guard) = session_hotkeys().lock() else {
+        logging::log("HOTKEY", "Session hotkey lock poisoned; dropping press");
+        return false;
+    };
+    let Some((id, sender)) = session_guard.find_by_hotkey_id(hotkey_id) else {
+        return false;
+    };
+
+    if sender
+        .try_send(crate::protocol::Message::HotkeyPressed { id: id.clone() })
+        .is_err()
+    {
+        logging::log(
+            "HOTKEY",
+            &format!("Failed to deliver HotkeyPressed for '{}'", id),
+        );
+    }
+    true
+}
+
 // =============================================================================
 // GCD dispatch for immediate main-thread execution (bypasses async runtime)
 // =============================================================================
@@ -875,6 +1080,12 @@ fn dispatch_ai_hotkey() {
 
 // HOTKEY_CHANNEL: Event-driven async_channel for hotkey events (replaces AtomicBool polling)
 #[allow(dead_code)]
+// Bounded to 10, but a deep queue is never actually wanted here: the toggle
+// is idempotent (show/hide), so the sender drops on full with try_send and
+// the receiver drains any extras still queued behind the one it just woke
+// up for (see `drain_pending_toggles`). A burst of rapid presses therefore
+// always collapses to at most one state change instead of flapping the
+// window as queued toggles replay one by one.
 static HOTKEY_CHANNEL: OnceLock<(async_channel::Sender<()>, async_channel::Receiver<()>)> =
     OnceLock::new();
 
@@ -885,20 +1096,41 @@ pub(crate) fn hotkey_channel() -> &'static (async_channel::Sender<()>, async_cha
     HOTKEY_CHANNEL.get_or_init(|| async_channel::bounded(10))
 }
 
-// SCRIPT_HOTKEY_CHANNEL: Channel for script shortcut events (sends script path)
+/// Drain any toggle events already queued behind the one a receiver just
+/// woke up for, so a rapid-press burst collapses into a single state change
+/// instead of replaying queued toggles one by one afterward.
+///
+/// Returns the number of extra events discarded (purely for logging - the
+/// caller should still act on the one event it already received).
+pub(crate) fn drain_pending_toggles(receiver: &async_channel::Receiver<()>) -> usize {
+    let mut drained = 0;
+    while receiver.try_recv().is_ok() {
+        drained += 1;
+    }
+    drained
+}
+
+// SCRIPT_HOTKEY_CHANNEL: Channel for script shortcut events (sends script
+// path + positional args, mirroring `ExternalCommand::Run`'s (path, args)
+// shape used by Run/aliases/URL scheme)
+//
+// Unlike the main toggle channel, each event here is a distinct script
+// launch request - dropping one means a script the user asked to run never
+// runs. So this channel is unbounded rather than coalesced: a burst of
+// script hotkeys queues up and drains in order instead of losing entries.
 #[allow(dead_code)]
 static SCRIPT_HOTKEY_CHANNEL: OnceLock<(
-    async_channel::Sender<String>,
-    async_channel::Receiver<String>,
+    async_channel::Sender<(String, Vec<String>)>,
+    async_channel::Receiver<(String, Vec<String>)>,
 )> = OnceLock::new();
 
 /// Get the script hotkey channel, initializing it on first access.
 #[allow(dead_code)]
 pub(crate) fn script_hotkey_channel() -> &'static (
-    async_channel::Sender<String>,
-    async_channel::Receiver<String>,
+    async_channel::Sender<(String, Vec<String>)>,
+    async_channel::Receiver<(String, Vec<String>)>,
 ) {
-    SCRIPT_HOTKEY_CHANNEL.get_or_init(|| async_channel::bounded(10))
+    SCRIPT_HOTKEY_CHANNEL.get_or_init(async_channel::unbounded)
 }
 
 // NOTES_HOTKEY_CHANNEL: Channel for notes hotkey events
@@ -1043,6 +1275,224 @@ fn register_script_hotkey_internal(
     }
 }
 
+// =============================================================================
+// App-scoped script shortcuts
+// =============================================================================
+// A script's `// App:` comment (or typed `metadata.app`) restricts its
+// `// Shortcut:` to only fire while that app (bundle id or app name,
+// matched case-insensitively) is frontmost. This lets several scripts
+// share the same key combo, each scoped to a different app.
+//
+// `resolve_app_scoped_script` is the pure dispatch rule: given every
+// script registered under one shortcut plus the frontmost app, it picks
+// which script (if any) should run. It does not know about the OS-level
+// `HotKey`/`GlobalHotKeyManager` registration at all, so it's cheap to
+// exercise with plain unit tests below.
+//
+// NOTE: `register_script_hotkey_internal` above still registers one OS
+// hotkey per script and simply fails (logged, no route added) if two
+// scripts claim the same shortcut - wiring multiple app-scoped scripts
+// under a single registered `HotKey` and calling this resolver from the
+// `HotkeyAction::Script` dispatch arm in `start_hotkey_listener` is
+// tracked as follow-up work.
+
+/// Returns true if `filter` (an `// App:` value - bundle id or app name)
+/// matches the frontmost app's bundle id or name. Comparison is
+/// case-insensitive since bundle ids and app names are conventionally
+/// written in fixed case but users may not match it exactly.
+#[allow(dead_code)]
+fn app_filter_matches(filter: &str, bundle_id: Option<&str>, name: Option<&str>) -> bool {
+    bundle_id.is_some_and(|b| b.eq_ignore_ascii_case(filter))
+        || name.is_some_and(|n| n.eq_ignore_ascii_case(filter))
+}
+
+/// Picks which script sharing a single shortcut should run for the given
+/// frontmost app, matching `candidates` (script path, `// App:` filter) in
+/// order:
+/// 1. The first candidate whose `app_filter` matches the frontmost app.
+/// 2. Otherwise, the first candidate with no `app_filter` at all.
+/// 3. Otherwise `None` - the press doesn't belong to any of them and the
+///    original keystroke should be reposted to the frontmost app instead.
+#[allow(dead_code)]
+fn resolve_app_scoped_script<'a>(
+    candidates: &'a [(String, Option<String>)],
+    frontmost_bundle_id: Option<&str>,
+    frontmost_name: Option<&str>,
+) -> Option<&'a str> {
+    candidates
+        .iter()
+        .find(|(_, filter)| {
+            filter
+                .as_deref()
+                .is_some_and(|f| app_filter_matches(f, frontmost_bundle_id, frontmost_name))
+        })
+        .or_else(|| candidates.iter().find(|(_, filter)| filter.is_none()))
+        .map(|(path, _)| path.as_str())
+}
+
+/// Maps the subset of `Code` values `shortcuts::parse_shortcut` can
+/// produce to their macOS ANSI virtual keycodes, for reposting a hotkey
+/// press to the frontmost app via `CGEvent`. Returns `None` for codes
+/// with no fixed physical position (shouldn't happen for parsed
+/// shortcuts, but reposting is best-effort).
+#[allow(dead_code)]
+#[cfg(target_os = "macos")]
+fn code_to_cg_keycode(code: Code) -> Option<core_graphics::event::CGKeyCode> {
+    use Code::*;
+    Some(match code {
+        KeyA => 0,
+        KeyS => 1,
+        KeyD => 2,
+        KeyF => 3,
+        KeyH => 4,
+        KeyG => 5,
+        KeyZ => 6,
+        KeyX => 7,
+        KeyC => 8,
+        KeyV => 9,
+        KeyB => 11,
+        KeyQ => 12,
+        KeyW => 13,
+        KeyE => 14,
+        KeyR => 15,
+        KeyY => 16,
+        KeyT => 17,
+        Digit1 => 18,
+        Digit2 => 19,
+        Digit3 => 20,
+        Digit4 => 21,
+        Digit6 => 22,
+        Digit5 => 23,
+        Equal => 24,
+        Digit9 => 25,
+        Digit7 => 26,
+        Minus => 27,
+        Digit8 => 28,
+        Digit0 => 29,
+        BracketRight => 30,
+        KeyO => 31,
+        KeyU => 32,
+        BracketLeft => 33,
+        KeyI => 34,
+        KeyP => 35,
+        Enter => 36,
+        KeyL => 37,
+        KeyJ => 38,
+        Quote => 39,
+        KeyK => 40,
+        Semicolon => 41,
+        Backslash => 42,
+        Comma => 43,
+        Slash => 44,
+        KeyN => 45,
+        KeyM => 46,
+        Period => 47,
+        Tab => 48,
+        Space => 49,
+        Backquote => 50,
+        Backspace => 51,
+        Escape => 53,
+        F1 => 122,
+        F2 => 120,
+        F3 => 99,
+        F4 => 118,
+        F5 => 96,
+        F6 => 97,
+        F7 => 98,
+        F8 => 100,
+        F9 => 101,
+        F10 => 109,
+        F11 => 103,
+        F12 => 111,
+        Home => 115,
+        PageUp => 116,
+        Delete => 117,
+        End => 119,
+        PageDown => 121,
+        ArrowRight => 124,
+        ArrowLeft => 123,
+        ArrowDown => 125,
+        ArrowUp => 126,
+        _ => return None,
+    })
+}
+
+/// Synthesizes and posts the keystroke for `shortcut` to the HID event
+/// stream, so the frontmost app receives it as if our global hotkey
+/// registration hadn't intercepted it. Used as the passthrough fallback
+/// when an app-scoped shortcut is pressed but no script's `// App:`
+/// filter matches the frontmost app (and there's no unfiltered
+/// fallback script either).
+///
+/// Mirrors the event-synthesis pattern in `selected_text::simulate_paste_with_cg`,
+/// generalized from a hardcoded Cmd+V to an arbitrary key/modifier combo.
+#[allow(dead_code)]
+#[cfg(target_os = "macos")]
+pub(crate) fn repost_shortcut_to_frontmost(mods: Modifiers, code: Code) -> anyhow::Result<()> {
+    use core_graphics::event::{CGEvent, CGEventFlags, CGEventTapLocation};
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+    use std::thread;
+    use std::time::Duration;
+
+    let Some(keycode) = code_to_cg_keycode(code) else {
+        anyhow::bail!("No CGKeyCode mapping for {:?}, cannot repost", code);
+    };
+
+    let mut flags = CGEventFlags::empty();
+    if mods.contains(Modifiers::META) {
+        flags |= CGEventFlags::CGEventFlagCommand;
+    }
+    if mods.contains(Modifiers::SHIFT) {
+        flags |= CGEventFlags::CGEventFlagShift;
+    }
+    if mods.contains(Modifiers::CONTROL) {
+        flags |= CGEventFlags::CGEventFlagControl;
+    }
+    if mods.contains(Modifiers::ALT) {
+        flags |= CGEventFlags::CGEventFlagAlternate;
+    }
+
+    let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+        .map_err(|_| anyhow::anyhow!("Failed to create CGEventSource"))?;
+    let key_down = CGEvent::new_keyboard_event(source.clone(), keycode, true)
+        .map_err(|_| anyhow::anyhow!("Failed to create key-down CGEvent"))?;
+    key_down.set_flags(flags);
+    let key_up = CGEvent::new_keyboard_event(source, keycode, false)
+        .map_err(|_| anyhow::anyhow!("Failed to create key-up CGEvent"))?;
+    key_up.set_flags(flags);
+
+    key_down.post(CGEventTapLocation::HID);
+    thread::sleep(Duration::from_millis(5));
+    key_up.post(CGEventTapLocation::HID);
+
+    Ok(())
+}
+
+// ============================================================================
+// System Tests (require `cargo test --features system-tests`)
+// ============================================================================
+// Reposting a keystroke has no observable effect from inside the test
+// process itself - it has to be watched land in another app - so these
+// are manual-verification tests rather than assertions.
+#[cfg(all(test, feature = "system-tests"))]
+#[cfg(target_os = "macos")]
+mod system_tests {
+    use super::*;
+
+    #[test]
+    #[ignore] // Requires manual verification in another app
+    fn test_repost_shortcut_to_frontmost() {
+        // Instructions:
+        // 1. Open TextEdit and click into a new, empty document so it's frontmost
+        // 2. Run this test with:
+        //    cargo test --features system-tests test_repost_shortcut_to_frontmost -- --ignored
+        // 3. Watch TextEdit: it should receive Cmd+A as if you'd pressed it
+        //    yourself (selecting all text in the document, visibly highlighted)
+        repost_shortcut_to_frontmost(Modifiers::META, Code::KeyA)
+            .expect("Should post the synthetic keystroke");
+    }
+}
+
 #[allow(dead_code)]
 pub(crate) fn start_hotkey_listener(config: config::Config) {
     std::thread::spawn(move || {
@@ -1218,6 +1668,14 @@ pub(crate) fn start_hotkey_listener(config: config::Config) {
                     continue;
                 }
 
+                if crate::is_paused() {
+                    logging::log(
+                        "HOTKEY",
+                        &format!("Ignoring hotkey event id={} - hotkeys paused", event.id),
+                    );
+                    continue;
+                }
+
                 // Look up action in unified routing table (fast read lock)
                 let action = {
                     let routes_guard = routes().read().unwrap();
@@ -1227,9 +1685,13 @@ pub(crate) fn start_hotkey_listener(config: config::Config) {
                 match action {
                     Some(HotkeyAction::Main) => {
                         let count = HOTKEY_TRIGGER_COUNT.fetch_add(1, Ordering::Relaxed);
-                        // NON-BLOCKING: Use try_send to prevent hotkey thread from blocking
+                        // NON-BLOCKING: try_send and drop on full - a toggle queue deeper
+                        // than 1 is never what the user wants (see HOTKEY_CHANNEL docs).
                         if hotkey_channel().0.try_send(()).is_err() {
-                            logging::log("HOTKEY", "Main hotkey channel full/closed");
+                            logging::log(
+                                "HOTKEY",
+                                "Main hotkey channel full/closed - dropping toggle",
+                            );
                         }
                         logging::log(
                             "HOTKEY",
@@ -1249,17 +1711,31 @@ pub(crate) fn start_hotkey_listener(config: config::Config) {
                     }
                     Some(HotkeyAction::Script(path)) => {
                         logging::log("HOTKEY", &format!("Script shortcut triggered: {}", path));
-                        // NON-BLOCKING: Use try_send to prevent hotkey thread from blocking
-                        if script_hotkey_channel().0.try_send(path.clone()).is_err() {
+                        // Unbounded channel - this only fails if the receiver has been
+                        // dropped (app shutting down), never due to backpressure.
+                        // No per-shortcut args source exists yet (shortcuts.json
+                        // has no args field) - send an empty arg list until one does.
+                        if script_hotkey_channel()
+                            .0
+                            .try_send((path.clone(), Vec::new()))
+                            .is_err()
+                        {
                             logging::log(
-                                "HOTKEY",
-                                &format!("Script channel full/closed for {}", path),
+                                "ERROR",
+                                &format!("Script hotkey channel closed, lost launch for {}", path),
                             );
                         }
                     }
                     None => {
-                        // Unknown hotkey ID - can happen during hot-reload transitions
-                        logging::log("HOTKEY", &format!("Unknown hotkey event id={}", event.id));
+                        // Not in the unified routing table - check script-registered
+                        // session hotkeys before giving up.
+                        if !dispatch_session_hotkey_press(event.id) {
+                            // Unknown hotkey ID - can happen during hot-reload transitions
+                            logging::log(
+                                "HOTKEY",
+                                &format!("Unknown hotkey event id={}", event.id),
+                            );
+                        }
                     }
                 }
             }
@@ -1376,6 +1852,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_drain_pending_toggles_coalesces_burst_to_single_event() {
+        let (tx, rx) = async_channel::bounded(10);
+
+        // Simulate a burst of 20 rapid presses. The bounded queue only holds
+        // 10 - try_send drops the rest, exactly like the real hotkey thread
+        // does via `hotkey_channel().0.try_send(())`.
+        let mut sent = 0;
+        let mut dropped = 0;
+        for _ in 0..20 {
+            if tx.try_send(()).is_ok() {
+                sent += 1;
+            } else {
+                dropped += 1;
+            }
+        }
+        assert_eq!(sent, 10);
+        assert_eq!(dropped, 10);
+
+        // The receiver takes the first event (as `.recv().await` would)...
+        assert!(rx.try_recv().is_ok());
+        // ...then drains the rest of the burst before acting.
+        let extra = drain_pending_toggles(&rx);
+        assert_eq!(extra, 9, "should drain the 9 remaining queued toggles");
+
+        // Exactly one state change's worth of events were consumed in total
+        // (1 initial recv + 9 drained), and the channel is now empty.
+        assert!(matches!(rx.try_recv(), Err(TryRecvError::Empty)));
+    }
+
+    #[test]
+    fn test_drain_pending_toggles_on_empty_channel_is_a_noop() {
+        let (_tx, rx) = async_channel::bounded::<()>(10);
+        assert_eq!(drain_pending_toggles(&rx), 0);
+    }
+
     #[test]
     fn hotkey_channels_are_independent() {
         while hotkey_channel().1.try_recv().is_ok() {}
@@ -1390,14 +1902,14 @@ mod tests {
 
         script_hotkey_channel()
             .0
-            .send_blocking("script".to_string())
+            .send_blocking(("script".to_string(), vec!["arg1".to_string()]))
             .expect("send script hotkey");
         assert_eq!(
             script_hotkey_channel()
                 .1
                 .try_recv()
                 .expect("recv script hotkey"),
-            "script"
+            ("script".to_string(), vec!["arg1".to_string()])
         );
     }
 
@@ -1549,4 +2061,234 @@ mod tests {
             }
         }
     }
+
+    // =============================================================================
+    // Session Hotkey Bookkeeping Tests (mock backend - no OS permissions needed)
+    // =============================================================================
+    mod session_hotkeys_tests {
+        use super::*;
+
+        /// Mock backend that always succeeds, so bookkeeping can be tested
+        /// without a real `GlobalHotKeyManager`.
+        #[derive(Default)]
+        struct MockBackend;
+
+        impl HotkeyBackend for MockBackend {
+            fn register(&self, _hotkey: HotKey) -> Result<(), HotkeyError> {
+                Ok(())
+            }
+
+            fn unregister(&self, _hotkey: HotKey) -> Result<(), HotkeyError> {
+                Ok(())
+            }
+        }
+
+        /// Mock backend whose register() always fails, to exercise the error path.
+        struct RejectingBackend;
+
+        impl HotkeyBackend for RejectingBackend {
+            fn register(&self, hotkey: HotKey) -> Result<(), HotkeyError> {
+                Err(HotkeyError::AlreadyRegistered(hotkey))
+            }
+
+            fn unregister(&self, _hotkey: HotKey) -> Result<(), HotkeyError> {
+                Ok(())
+            }
+        }
+
+        fn test_sender() -> mpsc::SyncSender<crate::protocol::Message> {
+            mpsc::sync_channel(1).0
+        }
+
+        #[test]
+        fn test_register_tracks_id() {
+            let mut hotkeys = SessionHotkeys::default();
+            let result = hotkeys.register(&MockBackend, "my-hotkey", "cmd+shift+y", test_sender());
+            assert!(result.is_ok());
+            assert!(hotkeys.by_id.contains_key("my-hotkey"));
+        }
+
+        #[test]
+        fn test_register_rejects_duplicate_id() {
+            let mut hotkeys = SessionHotkeys::default();
+            hotkeys
+                .register(&MockBackend, "dup", "cmd+shift+y", test_sender())
+                .unwrap();
+
+            let result = hotkeys.register(&MockBackend, "dup", "cmd+shift+u", test_sender());
+            assert!(result.is_err());
+            // The original registration is untouched
+            assert_eq!(hotkeys.by_id.len(), 1);
+        }
+
+        #[test]
+        fn test_register_rejects_unparsable_shortcut() {
+            let mut hotkeys = SessionHotkeys::default();
+            let result = hotkeys.register(&MockBackend, "bad", "not a shortcut", test_sender());
+            assert!(result.is_err());
+            assert!(hotkeys.by_id.is_empty());
+        }
+
+        #[test]
+        fn test_register_rejects_conflicting_session_shortcut() {
+            let mut hotkeys = SessionHotkeys::default();
+            hotkeys
+                .register(&MockBackend, "first", "cmd+shift+y", test_sender())
+                .unwrap();
+
+            // Same shortcut, different id - should be rejected as a conflict
+            let result = hotkeys.register(&MockBackend, "second", "cmd+shift+y", test_sender());
+            assert!(result.is_err());
+            assert_eq!(hotkeys.by_id.len(), 1);
+        }
+
+        #[test]
+        fn test_register_surfaces_backend_error() {
+            let mut hotkeys = SessionHotkeys::default();
+            let result = hotkeys.register(&RejectingBackend, "x", "cmd+shift+y", test_sender());
+            assert!(result.is_err());
+            assert!(hotkeys.by_id.is_empty());
+        }
+
+        #[test]
+        fn test_unregister_removes_tracking() {
+            let mut hotkeys = SessionHotkeys::default();
+            hotkeys
+                .register(&MockBackend, "temp", "cmd+shift+y", test_sender())
+                .unwrap();
+
+            hotkeys.unregister(&MockBackend, "temp");
+            assert!(hotkeys.by_id.is_empty());
+        }
+
+        #[test]
+        fn test_unregister_unknown_id_is_noop() {
+            let mut hotkeys = SessionHotkeys::default();
+            hotkeys.unregister(&MockBackend, "never-registered");
+            assert!(hotkeys.by_id.is_empty());
+        }
+
+        #[test]
+        fn test_clear_removes_everything() {
+            let mut hotkeys = SessionHotkeys::default();
+            hotkeys
+                .register(&MockBackend, "a", "cmd+shift+y", test_sender())
+                .unwrap();
+            hotkeys
+                .register(&MockBackend, "b", "cmd+shift+u", test_sender())
+                .unwrap();
+
+            hotkeys.clear(&MockBackend);
+            assert!(hotkeys.by_id.is_empty());
+        }
+
+        #[test]
+        fn test_find_by_hotkey_id_returns_registering_script() {
+            let mut hotkeys = SessionHotkeys::default();
+            hotkeys
+                .register(&MockBackend, "findable", "cmd+shift+y", test_sender())
+                .unwrap();
+            let hotkey_id = hotkeys.by_id.get("findable").unwrap().hotkey.id();
+
+            let found = hotkeys.find_by_hotkey_id(hotkey_id);
+            assert_eq!(found.map(|(id, _)| id), Some("findable".to_string()));
+        }
+
+        #[test]
+        fn test_find_by_hotkey_id_unknown_returns_none() {
+            let hotkeys = SessionHotkeys::default();
+            assert!(hotkeys.find_by_hotkey_id(99999).is_none());
+        }
+    }
+
+    mod app_scoped_dispatch_tests {
+        use super::*;
+
+        fn candidate(path: &str, app_filter: Option<&str>) -> (String, Option<String>) {
+            (path.to_string(), app_filter.map(str::to_string))
+        }
+
+        #[test]
+        fn test_app_filter_matches_bundle_id_case_insensitively() {
+            assert!(app_filter_matches(
+                "com.tinyapp.TablePlus",
+                Some("com.tinyapp.tableplus"),
+                None
+            ));
+        }
+
+        #[test]
+        fn test_app_filter_matches_app_name() {
+            assert!(app_filter_matches(
+                "TablePlus",
+                Some("com.tinyapp.tableplus"),
+                Some("TablePlus")
+            ));
+        }
+
+        #[test]
+        fn test_app_filter_no_match() {
+            assert!(!app_filter_matches(
+                "com.tinyapp.TablePlus",
+                Some("com.apple.Terminal"),
+                Some("Terminal")
+            ));
+        }
+
+        #[test]
+        fn test_resolve_picks_matching_app_filter() {
+            let candidates = vec![
+                candidate("/scripts/format-sql.ts", Some("com.tinyapp.TablePlus")),
+                candidate("/scripts/format-json.ts", Some("com.apple.Terminal")),
+            ];
+            let resolved = resolve_app_scoped_script(
+                &candidates,
+                Some("com.tinyapp.TablePlus"),
+                Some("TablePlus"),
+            );
+            assert_eq!(resolved, Some("/scripts/format-sql.ts"));
+        }
+
+        #[test]
+        fn test_resolve_falls_back_to_unfiltered_script() {
+            let candidates = vec![
+                candidate("/scripts/format-sql.ts", Some("com.tinyapp.TablePlus")),
+                candidate("/scripts/default.ts", None),
+            ];
+            let resolved = resolve_app_scoped_script(
+                &candidates,
+                Some("com.apple.Terminal"),
+                Some("Terminal"),
+            );
+            assert_eq!(resolved, Some("/scripts/default.ts"));
+        }
+
+        #[test]
+        fn test_resolve_returns_none_when_nothing_matches() {
+            let candidates = vec![candidate(
+                "/scripts/format-sql.ts",
+                Some("com.tinyapp.TablePlus"),
+            )];
+            let resolved = resolve_app_scoped_script(
+                &candidates,
+                Some("com.apple.Terminal"),
+                Some("Terminal"),
+            );
+            assert_eq!(resolved, None);
+        }
+
+        #[test]
+        fn test_resolve_prefers_app_match_over_unfiltered_fallback() {
+            let candidates = vec![
+                candidate("/scripts/default.ts", None),
+                candidate("/scripts/format-sql.ts", Some("com.tinyapp.TablePlus")),
+            ];
+            let resolved = resolve_app_scoped_script(
+                &candidates,
+                Some("com.tinyapp.TablePlus"),
+                Some("TablePlus"),
+            );
+            assert_eq!(resolved, Some("/scripts/format-sql.ts"));
+        }
+    }
 }
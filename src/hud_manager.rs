@@ -20,6 +20,7 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use crate::logging;
+use crate::protocol::HudPosition;
 use crate::theme;
 
 // =============================================================================
@@ -117,6 +118,10 @@ const DEFAULT_HUD_DURATION_MS: u64 = 2000;
 /// Gap between stacked HUDs
 const HUD_STACK_GAP: f32 = 45.0;
 
+/// Vertical offset below the cursor for `HudPosition::NearCursor`, so the
+/// HUD doesn't appear directly under the pointer.
+const HUD_CURSOR_OFFSET: f32 = 24.0;
+
 /// Maximum number of simultaneous HUDs
 const MAX_SIMULTANEOUS_HUDS: usize = 3;
 
@@ -193,6 +198,10 @@ pub struct HudNotification {
     /// Optional action to execute when button is clicked
     #[allow(dead_code)]
     pub action: Option<HudAction>,
+    /// Where to show the HUD; `None` falls back to bottom-center
+    pub position: Option<HudPosition>,
+    /// Client-supplied ID, so a later `update_hud` call can target this HUD.
+    pub script_id: Option<String>,
 }
 
 impl HudNotification {
@@ -323,6 +332,8 @@ struct ActiveHud {
     /// Slot index (0..MAX_SIMULTANEOUS_HUDS) for position calculation
     #[allow(dead_code)] // Used in position calculation
     slot: usize,
+    /// Client-supplied ID, so a later `update_hud` call can target this HUD.
+    script_id: Option<String>,
 }
 
 /// Entry in the slot allocation array (lightweight, for tracking slot ownership)
@@ -412,10 +423,17 @@ fn show_notification(notif: HudNotification, cx: &mut App) {
             Some(notif.duration_ms),
             notif.action_label.unwrap(),
             notif.action.unwrap(),
+            notif.position,
             cx,
         );
     } else {
-        show_hud(notif.text, Some(notif.duration_ms), cx);
+        show_hud(
+            notif.text,
+            Some(notif.duration_ms),
+            notif.position,
+            notif.script_id,
+            cx,
+        );
     }
 }
 
@@ -428,8 +446,16 @@ fn show_notification(notif: HudNotification, cx: &mut App) {
 /// # Arguments
 /// * `text` - The message to display
 /// * `duration_ms` - Optional duration in milliseconds (default: 2000ms)
+/// * `position` - Where to show the HUD (default: bottom-center)
+/// * `script_id` - Client-supplied ID, so a later `update_hud` call can target this HUD
 /// * `cx` - GPUI App context
-pub fn show_hud(text: String, duration_ms: Option<u64>, cx: &mut App) {
+pub fn show_hud(
+    text: String,
+    duration_ms: Option<u64>,
+    position: Option<HudPosition>,
+    script_id: Option<String>,
+    cx: &mut App,
+) {
     let duration = duration_ms.unwrap_or(DEFAULT_HUD_DURATION_MS);
 
     logging::log(
@@ -457,22 +483,24 @@ pub fn show_hud(text: String, duration_ms: Option<u64>, cx: &mut App) {
                 created_at: Instant::now(),
                 action_label: None,
                 action: None,
+                position,
+                script_id,
             });
             return;
         }
     };
 
-    // Calculate position - bottom center of screen with mouse
-    let (hud_x, hud_y) = calculate_hud_position(cx);
+    // Calculate position based on the requested (or default) placement
+    let (hud_x, hud_y) = calculate_hud_position(position.unwrap_or_default(), cx);
 
     // Calculate vertical offset using SLOT index (not len) - this prevents overlap
-    let stack_offset = slot as f32 * HUD_STACK_GAP;
+    let stacked_y = stack_hud_y(hud_y, slot, position.unwrap_or_default());
 
     let hud_width: Pixels = px(HUD_WIDTH);
     let hud_height: Pixels = px(HUD_HEIGHT);
 
     let bounds = gpui::Bounds {
-        origin: point(px(hud_x), px(hud_y - stack_offset)),
+        origin: point(px(hud_x), px(stacked_y)),
         size: size(hud_width, hud_height),
     };
 
@@ -513,6 +541,7 @@ pub fn show_hud(text: String, duration_ms: Option<u64>, cx: &mut App) {
                     created_at: Instant::now(),
                     duration_ms: duration,
                     slot,
+                    script_id,
                 });
             }
 
@@ -551,6 +580,7 @@ pub fn show_hud(text: String, duration_ms: Option<u64>, cx: &mut App) {
 /// * `duration_ms` - Optional duration in milliseconds (default: 3000ms for action HUDs)
 /// * `action_label` - Label for the action button (e.g., "Open Logs")
 /// * `action` - The action to execute when the button is clicked
+/// * `position` - Where to show the HUD (default: bottom-center)
 /// * `cx` - GPUI App context
 #[allow(dead_code)]
 pub fn show_hud_with_action(
@@ -558,6 +588,7 @@ pub fn show_hud_with_action(
     duration_ms: Option<u64>,
     action_label: String,
     action: HudAction,
+    position: Option<HudPosition>,
     cx: &mut App,
 ) {
     // Action HUDs have longer default duration (3s) since user might click
@@ -591,16 +622,18 @@ pub fn show_hud_with_action(
                 created_at: Instant::now(),
                 action_label: Some(action_label),
                 action: Some(action),
+                position,
+                script_id: None,
             });
             return;
         }
     };
 
-    // Calculate position - bottom center of screen with mouse
-    let (hud_x, hud_y) = calculate_hud_position(cx);
+    // Calculate position based on the requested (or default) placement
+    let (hud_x, hud_y) = calculate_hud_position(position.unwrap_or_default(), cx);
 
     // Calculate vertical offset using SLOT index (not len) - this prevents overlap
-    let stack_offset = slot as f32 * HUD_STACK_GAP;
+    let stacked_y = stack_hud_y(hud_y, slot, position.unwrap_or_default());
 
     // Use wider dimensions for action HUDs
     let hud_width: Pixels = px(HUD_ACTION_WIDTH);
@@ -610,7 +643,7 @@ pub fn show_hud_with_action(
     let adjusted_x = hud_x - (HUD_ACTION_WIDTH - HUD_WIDTH) / 2.0;
 
     let bounds = gpui::Bounds {
-        origin: point(px(adjusted_x), px(hud_y - stack_offset)),
+        origin: point(px(adjusted_x), px(stacked_y)),
         size: size(hud_width, hud_height),
     };
 
@@ -651,6 +684,7 @@ pub fn show_hud_with_action(
                     created_at: Instant::now(),
                     duration_ms: duration,
                     slot,
+                    script_id: None,
                 });
             }
 
@@ -685,8 +719,9 @@ pub fn show_hud_with_action(
     }
 }
 
-/// Calculate HUD position - bottom center of screen containing mouse
-fn calculate_hud_position(cx: &App) -> (f32, f32) {
+/// Calculate HUD position on the screen containing the mouse, for the
+/// requested placement.
+fn calculate_hud_position(position: HudPosition, cx: &App) -> (f32, f32) {
     let displays = cx.displays();
 
     // Try to get mouse position
@@ -710,21 +745,58 @@ fn calculate_hud_position(cx: &App) -> (f32, f32) {
     // Use found display or primary
     let display = target_display.or_else(|| displays.first());
 
-    if let Some(display) = display {
-        let bounds = display.bounds();
-        let screen_x: f32 = bounds.origin.x.into();
-        let screen_y: f32 = bounds.origin.y.into();
-        let screen_width: f32 = bounds.size.width.into();
-        let screen_height: f32 = bounds.size.height.into();
+    let Some(display) = display else {
+        // Fallback position
+        return (500.0, 800.0);
+    };
 
-        // Center horizontally, position at 85% down the screen
-        let hud_x = screen_x + (screen_width - HUD_WIDTH) / 2.0;
-        let hud_y = screen_y + screen_height * 0.85;
+    let bounds = display.bounds();
+    let screen_x: f32 = bounds.origin.x.into();
+    let screen_y: f32 = bounds.origin.y.into();
+    let screen_width: f32 = bounds.size.width.into();
+    let screen_height: f32 = bounds.size.height.into();
+
+    match position {
+        HudPosition::BottomCenter => {
+            // Center horizontally, position at 85% down the screen
+            let hud_x = screen_x + (screen_width - HUD_WIDTH) / 2.0;
+            let hud_y = screen_y + screen_height * 0.85;
+            (hud_x, hud_y)
+        }
+        HudPosition::TopCenter => {
+            // Center horizontally, position near the top of the screen
+            let hud_x = screen_x + (screen_width - HUD_WIDTH) / 2.0;
+            let hud_y = screen_y + screen_height * 0.08;
+            (hud_x, hud_y)
+        }
+        HudPosition::NearCursor => {
+            // Just below the cursor, clamped so the HUD stays on-screen.
+            // Without a mouse position to anchor to, fall back to bottom-center.
+            let Some((mouse_x, mouse_y)) = mouse_pos else {
+                let hud_x = screen_x + (screen_width - HUD_WIDTH) / 2.0;
+                let hud_y = screen_y + screen_height * 0.85;
+                return (hud_x, hud_y);
+            };
+            let hud_x = (mouse_x as f32 - HUD_WIDTH / 2.0)
+                .clamp(screen_x, screen_x + screen_width - HUD_WIDTH);
+            let hud_y =
+                ((mouse_y as f32) + HUD_CURSOR_OFFSET).min(screen_y + screen_height - HUD_HEIGHT);
+            (hud_x, hud_y)
+        }
+    }
+}
 
-        (hud_x, hud_y)
-    } else {
-        // Fallback position
-        (500.0, 800.0)
+/// Apply the slot-based stacking offset to a HUD's base y position.
+///
+/// Stacking direction depends on placement: HUDs anchored near the bottom
+/// of the screen stack upward (toward the center) as more arrive, while
+/// HUDs anchored near the top or cursor stack downward, so later HUDs
+/// never get pushed off the edge of the screen.
+fn stack_hud_y(base_y: f32, slot: usize, position: HudPosition) -> f32 {
+    let stack_offset = slot as f32 * HUD_STACK_GAP;
+    match position {
+        HudPosition::BottomCenter => base_y - stack_offset,
+        HudPosition::TopCenter | HudPosition::NearCursor => base_y + stack_offset,
     }
 }
 
@@ -825,6 +897,11 @@ fn configure_hud_window_by_size(_expected_width: f32, _expected_height: f32, _cl
 ///
 /// Uses WindowHandle.update() + window.remove_window() for reliable window closing.
 /// Uses slot-based clearing instead of swap_remove to prevent position overlap.
+///
+/// If the HUD was refreshed via `update_hud` after this dismissal was
+/// scheduled, `created_at`/`duration_ms` will have moved out from under the
+/// original timer, so `is_expired()` will be false and we skip closing here
+/// — the newer timer scheduled by `update_hud` will dismiss it instead.
 fn dismiss_hud_by_id(hud_id: u64, cx: &mut App) {
     let manager = get_hud_manager();
 
@@ -832,15 +909,22 @@ fn dismiss_hud_by_id(hud_id: u64, cx: &mut App) {
     let window_to_close: Option<WindowHandle<HudView>> = {
         let mut state = manager.lock();
 
-        // First, release the slot (this is the key fix - clears by ID, not swap_remove)
-        state.release_slot_by_id(hud_id);
-
-        // Then find and remove from active_huds Vec (retain order, don't swap_remove)
-        if let Some(idx) = state.active_huds.iter().position(|h| h.id == hud_id) {
-            let hud = state.active_huds.remove(idx); // Use remove() to preserve order
-            Some(hud.window)
-        } else {
-            None
+        match state.active_huds.iter().position(|h| h.id == hud_id) {
+            Some(idx) if state.active_huds[idx].is_expired() => {
+                // First, release the slot (this is the key fix - clears by ID, not swap_remove)
+                state.release_slot_by_id(hud_id);
+                let hud = state.active_huds.remove(idx); // Use remove() to preserve order
+                Some(hud.window)
+            }
+            Some(_) => {
+                // Was refreshed by update_hud since this dismissal was scheduled.
+                logging::log(
+                    "HUD",
+                    &format!("HUD id={} was refreshed, skipping stale dismiss", hud_id),
+                );
+                return;
+            }
+            None => None,
         }
     };
 
@@ -875,6 +959,71 @@ fn dismiss_hud_by_id(hud_id: u64, cx: &mut App) {
     }
 }
 
+/// Update the text (and optionally the dismiss duration) of a live HUD
+/// previously shown with a matching `script_id`, without dismissing and
+/// re-showing it (which would flicker).
+///
+/// Resets `created_at` and reschedules the dismiss timer, so the HUD stays
+/// visible for a fresh `duration_ms` window starting now. Any dismiss timer
+/// from the original `show_hud` call is handled by `dismiss_hud_by_id`'s
+/// expiry check — it will see the refreshed `created_at` and skip closing.
+///
+/// If no live HUD has a matching `script_id`, this logs and is a no-op.
+pub fn update_hud(id: &str, text: String, duration_ms: Option<u64>, cx: &mut App) {
+    let manager = get_hud_manager();
+
+    let found: Option<(u64, WindowHandle<HudView>, u64)> = {
+        let mut state = manager.lock();
+        match state
+            .active_huds
+            .iter()
+            .position(|hud| hud.script_id.as_deref() == Some(id))
+        {
+            Some(idx) => {
+                let hud = &mut state.active_huds[idx];
+                let duration = duration_ms.unwrap_or(hud.duration_ms);
+                hud.created_at = Instant::now();
+                hud.duration_ms = duration;
+                Some((hud.id, hud.window, duration))
+            }
+            None => None,
+        }
+    };
+
+    let (hud_id, window_handle, duration) = match found {
+        Some(found) => found,
+        None => {
+            logging::log("HUD", &format!("update_hud: no live HUD with id={}", id));
+            return;
+        }
+    };
+
+    let update_result = window_handle.update(cx, |view, _window, cx| {
+        view.text = text;
+        cx.notify();
+    });
+
+    if let Err(e) = update_result {
+        logging::log(
+            "HUD",
+            &format!("update_hud: window for id={} already closed: {}", id, e),
+        );
+        return;
+    }
+
+    logging::log("HUD", &format!("Updated HUD id={}", id));
+
+    // Reschedule the dismiss timer for the refreshed duration.
+    let duration_duration = Duration::from_millis(duration);
+    cx.spawn(async move |cx: &mut gpui::AsyncApp| {
+        Timer::after(duration_duration).await;
+        let _ = cx.update(|cx| {
+            dismiss_hud_by_id(hud_id, cx);
+        });
+    })
+    .detach();
+}
+
 /// Clean up expired HUD windows and show pending ones
 fn cleanup_expired_huds(cx: &mut App) {
     let manager = get_hud_manager();
@@ -959,11 +1108,41 @@ mod tests {
             created_at: Instant::now(),
             action_label: None,
             action: None,
+            position: None,
+            script_id: None,
         };
         assert_eq!(notif.text, "Test");
         assert_eq!(notif.duration_ms, 2000);
     }
 
+    #[test]
+    fn test_hud_notification_script_id_defaults_to_none() {
+        let notif = HudNotification {
+            text: "Test".to_string(),
+            duration_ms: 2000,
+            created_at: Instant::now(),
+            action_label: None,
+            action: None,
+            position: None,
+            script_id: None,
+        };
+        assert_eq!(notif.script_id, None);
+    }
+
+    #[test]
+    fn test_hud_notification_carries_script_id() {
+        let notif = HudNotification {
+            text: "Test".to_string(),
+            duration_ms: 2000,
+            created_at: Instant::now(),
+            action_label: None,
+            action: None,
+            position: None,
+            script_id: Some("my-hud".to_string()),
+        };
+        assert_eq!(notif.script_id, Some("my-hud".to_string()));
+    }
+
     #[test]
     fn test_hud_manager_state_creation() {
         let state = HudManagerState::new();
@@ -1020,6 +1199,8 @@ mod tests {
             created_at: Instant::now(),
             action_label: None,
             action: None,
+            position: None,
+            script_id: None,
         };
         assert!(!notif_without_action.has_action());
 
@@ -1029,6 +1210,8 @@ mod tests {
             created_at: Instant::now(),
             action_label: Some("Open".to_string()),
             action: Some(HudAction::OpenUrl("https://example.com".to_string())),
+            position: None,
+            script_id: None,
         };
         assert!(notif_with_action.has_action());
     }
@@ -1215,6 +1398,8 @@ mod tests {
             created_at: Instant::now(),
             action_label: None,
             action: None,
+            position: None,
+            script_id: None,
         });
 
         state.pending_queue.push_back(HudNotification {
@@ -1223,6 +1408,8 @@ mod tests {
             created_at: Instant::now(),
             action_label: None,
             action: None,
+            position: None,
+            script_id: None,
         });
 
         assert_eq!(state.pending_queue.len(), 2);
@@ -1252,6 +1439,8 @@ mod tests {
             created_at: Instant::now(),
             action_label: Some("Click".to_string()),
             action: None,
+            position: None,
+            script_id: None,
         };
         assert!(
             !notif_label_only.has_action(),
@@ -1265,6 +1454,8 @@ mod tests {
             created_at: Instant::now(),
             action_label: None,
             action: Some(HudAction::OpenUrl("https://example.com".to_string())),
+            position: None,
+            script_id: None,
         };
         assert!(
             !notif_action_only.has_action(),
@@ -1342,6 +1533,8 @@ mod tests {
             created_at: Instant::now(),
             action_label: Some("Test".to_string()),
             action: Some(HudAction::OpenUrl("https://example.com".to_string())),
+            position: None,
+            script_id: None,
         };
 
         let cloned = original.clone();
@@ -1354,6 +1547,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_stack_hud_y_bottom_center_stacks_upward() {
+        // Bottom-anchored HUDs stack toward the center of the screen as
+        // more arrive, so later slots should move UP (smaller y).
+        let base_y = 800.0;
+        assert_eq!(stack_hud_y(base_y, 0, HudPosition::BottomCenter), 800.0);
+        assert_eq!(
+            stack_hud_y(base_y, 1, HudPosition::BottomCenter),
+            800.0 - HUD_STACK_GAP
+        );
+        assert_eq!(
+            stack_hud_y(base_y, 2, HudPosition::BottomCenter),
+            800.0 - HUD_STACK_GAP * 2.0
+        );
+    }
+
+    #[test]
+    fn test_stack_hud_y_top_center_and_near_cursor_stack_downward() {
+        // Top- and cursor-anchored HUDs stack away from the screen edge
+        // they're closest to, so later slots should move DOWN (larger y).
+        let base_y = 50.0;
+        assert_eq!(
+            stack_hud_y(base_y, 1, HudPosition::TopCenter),
+            50.0 + HUD_STACK_GAP
+        );
+        assert_eq!(
+            stack_hud_y(base_y, 1, HudPosition::NearCursor),
+            50.0 + HUD_STACK_GAP
+        );
+    }
+
+    #[test]
+    fn test_hud_position_default_is_bottom_center() {
+        assert_eq!(HudPosition::default(), HudPosition::BottomCenter);
+    }
+
     #[test]
     fn test_hud_colors_copy_trait() {
         // Test that HudColors implements Copy (important for closures)
@@ -15,6 +15,7 @@ pub mod config;
 // Unified icon system - single API for all icon sources
 // Supports gpui_component IconName, embedded SVGs, SF Symbols, app bundles
 pub mod debug_grid;
+pub mod density;
 pub mod designs;
 pub mod editor;
 pub mod error;
@@ -24,14 +25,17 @@ pub mod hotkeys;
 pub mod icons;
 pub mod list_item;
 pub mod logging;
+pub mod message_dispatch;
 pub mod navigation;
 pub mod panel;
 pub mod perf;
 pub mod platform;
+pub mod preview_doc;
 pub mod prompts;
 pub mod protocol;
 pub mod scripts;
 pub mod selected_text;
+pub mod session_state;
 pub mod shortcuts;
 pub mod syntax;
 pub mod term_prompt;
@@ -44,6 +48,7 @@ pub mod toast_manager;
 pub mod notification;
 #[cfg(not(test))]
 pub mod tray;
+pub mod url_scheme;
 pub mod utils;
 pub mod warning_banner;
 pub mod window_manager;
@@ -55,6 +60,7 @@ pub mod windows;
 // Phase 1 system API modules
 pub mod clipboard_history;
 pub mod file_search;
+pub mod recent_files;
 pub mod window_control;
 
 // Enhanced window control - backends + capabilities architecture
@@ -65,6 +71,10 @@ pub mod window_control_enhanced;
 #[cfg(target_os = "macos")]
 pub mod system_actions;
 
+// System sound playback for the PlaySound message
+#[cfg(target_os = "macos")]
+pub mod sounds;
+
 // Script creation - Create new scripts and scriptlets
 pub mod script_creation;
 
@@ -90,6 +100,7 @@ pub mod menu_cache;
 // Pre-fetches menu bar items when apps activate (before Script Kit opens)
 #[cfg(target_os = "macos")]
 pub mod frontmost_app_tracker;
+pub mod sleep_wake_tracker;
 
 // Action helpers - centralized path extraction, SDK action routing, pbcopy
 pub mod action_helpers;
@@ -104,6 +115,12 @@ pub mod fallbacks;
 // Frecency tracking for script usage
 pub mod frecency;
 
+// Persisted collapsed/expanded state for main menu section headers
+pub mod collapsed_sections;
+
+// Persisted sort order for the ungrouped main menu sections
+pub mod list_sort;
+
 // Process management for tracking bun script processes
 pub mod process_manager;
 
@@ -156,6 +173,9 @@ pub mod ocr;
 // Script scheduling with cron expressions and natural language
 pub mod scheduler;
 
+// Missed-run catch-up policy + persisted last-fire times for the scheduler
+pub mod scheduler_catchup;
+
 // Kenv environment setup and initialization
 // Ensures ~/.scriptkit exists with required directories and starter files
 pub mod setup;
@@ -249,3 +269,27 @@ pub fn is_main_window_visible() -> bool {
 pub fn set_main_window_visible(visible: bool) {
     MAIN_WINDOW_VISIBLE.store(visible, Ordering::SeqCst);
 }
+
+/// Global kill switch for global hotkeys and text expansion
+/// - Toggled from the tray menu's "Pause" item
+/// - Checked by the global hotkey listener before dispatching a press
+/// - Checked by `ExpandManager`'s keyboard monitor callback before matching
+///   a trigger
+pub static HOTKEYS_PAUSED: AtomicBool = AtomicBool::new(false);
+
+/// Check whether global hotkeys and text expansion are currently paused
+pub fn is_paused() -> bool {
+    HOTKEYS_PAUSED.load(Ordering::SeqCst)
+}
+
+/// Set the paused state for global hotkeys and text expansion
+pub fn set_paused(paused: bool) {
+    HOTKEYS_PAUSED.store(paused, Ordering::SeqCst);
+}
+
+/// Toggle the paused state and return the new value
+pub fn toggle_paused() -> bool {
+    let new_value = !is_paused();
+    set_paused(new_value);
+    new_value
+}
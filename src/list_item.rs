@@ -27,12 +27,20 @@ pub enum IconKind {
 /// IMPORTANT: When using GPUI `uniform_list`, the item closure must render
 /// at exactly this height (including padding). If you change visuals, keep the
 /// total height stable or update this constant everywhere it is used.
+///
+/// This is the comfortable-density value. The main script list, arg prompt
+/// choices, clipboard history, and window switcher read the live,
+/// density-aware height from `crate::density::list_item_height()` instead -
+/// use that unless you specifically need the comfortable default (e.g. the
+/// design gallery preview).
 pub const LIST_ITEM_HEIGHT: f32 = 48.0;
 
 /// Fixed height for section headers (RECENT, MAIN, etc.)
 /// Total height includes: pt(8px) + text (~8px via text_xs) + pb(4px) = ~20px content
 /// Using 24px for comfortable spacing while maintaining visual compactness.
 ///
+/// This is the comfortable-density value; see `crate::density::section_header_height()`.
+///
 /// ## Performance Note (uniform_list vs list)
 /// - Use `uniform_list` when every row has the same fixed height (fast O(1) scroll math).
 /// - Use `list()` when you need variable heights (e.g., headers + items); it uses a SumTree
@@ -43,7 +51,7 @@ pub const SECTION_HEADER_HEIGHT: f32 = 24.0;
 ///
 /// Used with GPUI's `list()` component when rendering grouped results (e.g., frecency with RECENT/MAIN sections).
 /// The usize in Item variant is the index into the flat results array.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum GroupedListItem {
     /// A section header (e.g., "SUGGESTED", "MAIN")
     SectionHeader(String),
@@ -289,6 +297,9 @@ pub struct ListItem {
     name: SharedString,
     description: Option<String>,
     shortcut: Option<String>,
+    /// Dim "2h ago · 14 runs" annotation sourced from frecency data, shown
+    /// right-aligned to the left of the shortcut badge.
+    frecency_annotation: Option<String>,
     icon: Option<IconKind>,
     selected: bool,
     /// Whether this item is being hovered (subtle visual feedback, separate from selected)
@@ -314,6 +325,7 @@ impl ListItem {
             name: name.into(),
             description: None,
             shortcut: None,
+            frecency_annotation: None,
             icon: None,
             selected: false,
             hovered: false,
@@ -381,6 +393,14 @@ impl ListItem {
         self
     }
 
+    /// Set the frecency usage annotation (e.g. "2h ago · 14 runs"), shown
+    /// right-aligned before the shortcut badge. Pass `None` for items with
+    /// no frecency history - no annotation is rendered in that case.
+    pub fn frecency_annotation_opt(mut self, a: Option<String>) -> Self {
+        self.frecency_annotation = a;
+        self
+    }
+
     /// Set the icon (emoji) to display on the left side
     pub fn icon(mut self, i: impl Into<String>) -> Self {
         self.icon = Some(IconKind::Emoji(i.into()));
@@ -549,7 +569,11 @@ impl RenderOnce for ListItem {
 
         // Description - text_xs (0.75rem ≈ 12px), muted color (never changes on selection - only bg shows selection)
         // Single-line with ellipsis truncation for long content
-        if let Some(desc) = self.description {
+        // Hidden in compact density: the row is too short to fit a second
+        // line, so showing it would just get clipped by the row height.
+        if let (Some(desc), crate::config::Density::Comfortable) =
+            (self.description, crate::density::get_density())
+        {
             let desc_color = rgb(colors.text_muted);
             item_content = item_content.child(
                 div()
@@ -563,6 +587,23 @@ impl RenderOnce for ListItem {
             );
         }
 
+        // Frecency annotation (if present) - dim, right-aligned, before the shortcut badge.
+        // Truncates with an ellipsis rather than wrapping or pushing the shortcut off-screen
+        // at compact list widths.
+        let frecency_element = if let Some(annotation) = self.frecency_annotation {
+            div()
+                .text_xs()
+                .text_color(rgb(colors.text_dimmed))
+                .max_w(px(100.))
+                .overflow_hidden()
+                .text_ellipsis()
+                .whitespace_nowrap()
+                .flex_shrink_0()
+                .child(annotation)
+        } else {
+            div()
+        };
+
         // Shortcut badge (if present) - right-aligned
         // text_xs (0.75rem ≈ 12px) is closest match for 11px
         let shortcut_element = if let Some(sc) = self.shortcut {
@@ -621,7 +662,9 @@ impl RenderOnce for ListItem {
                     .flex()
                     .flex_row()
                     .items_center()
+                    .gap(px(6.))
                     .flex_shrink_0()
+                    .child(frecency_element)
                     .child(shortcut_element),
             );
 
@@ -652,7 +695,7 @@ impl RenderOnce for ListItem {
         // Use left border for accent indicator - always reserve space, toggle color
         let mut container = div()
             .w_full()
-            .h(px(LIST_ITEM_HEIGHT))
+            .h(px(crate::density::list_item_height()))
             .pr(px(4.)) // Right padding only
             .flex()
             .flex_row()
@@ -788,17 +831,26 @@ pub fn icon_from_png(png_data: &[u8]) -> Option<IconKind> {
 /// * `label` - The section label (displayed as-is, standard casing)
 /// * `colors` - ListItemColors for theme-aware styling
 ///
-pub fn render_section_header(label: &str, colors: ListItemColors) -> impl IntoElement {
-    // Compact section header with explicit height (SECTION_HEADER_HEIGHT = 24px)
+pub fn render_section_header(
+    label: &str,
+    colors: ListItemColors,
+    item_count: usize,
+    collapsed: bool,
+) -> impl IntoElement {
+    // Compact section header with an explicit, density-aware height.
     // Used with GPUI's list() component which supports variable-height items.
     //
-    // Layout: 24px total height
+    // Layout (comfortable density, 24px total height):
     // - pt(8px) top padding for visual separation from above item
     // - ~8px text height (text_xs)
     // - pb(4px) bottom padding for visual separation from below item
+    //
+    // Clickable to collapse/expand the section; `collapsed` drives the
+    // chevron direction and `item_count` is always the full (pre-collapse)
+    // count so it stays meaningful while the section is hidden.
     div()
         .w_full()
-        .h(px(SECTION_HEADER_HEIGHT)) // Explicit 24px height for variable-height list
+        .h(px(crate::density::section_header_height())) // Explicit height for variable-height list
         .px(px(16.))
         .pt(px(8.)) // Top padding for visual separation
         .pb(px(4.)) // Bottom padding
@@ -807,10 +859,29 @@ pub fn render_section_header(label: &str, colors: ListItemColors) -> impl IntoEl
         .justify_center() // Center content vertically
         .child(
             div()
-                .text_xs() // 10-11px font
-                .font_weight(FontWeight::SEMIBOLD) // Slightly lighter than BOLD
-                .text_color(rgb(colors.text_dimmed))
-                .child(label.to_string()), // Standard casing (not uppercased)
+                .flex()
+                .flex_row()
+                .items_center()
+                .gap_1()
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(rgb(colors.text_dimmed))
+                        .child(if collapsed { "\u{203A}" } else { "\u{2304}" }),
+                )
+                .child(
+                    div()
+                        .text_xs() // 10-11px font
+                        .font_weight(FontWeight::SEMIBOLD) // Slightly lighter than BOLD
+                        .text_color(rgb(colors.text_dimmed))
+                        .child(label.to_string()), // Standard casing (not uppercased)
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(rgb(colors.text_dimmed))
+                        .child(format!("({})", item_count)),
+                ),
         )
 }
 
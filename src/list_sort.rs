@@ -0,0 +1,205 @@
+//! Persisted sort order for the ungrouped (SCRIPTS/SCRIPTLETS/COMMANDS/APPS/
+//! AGENTS) sections of the main menu.
+//!
+//! The SUGGESTED section always ranks by live frecency score regardless of
+//! this setting - this only controls the per-type sections beneath it.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tracing::{debug, info, instrument};
+
+/// How to order items within each ungrouped section.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ListSortMode {
+    /// Alphabetical by name (case-insensitive). The long-standing default.
+    #[default]
+    Name,
+    /// Most recently used first.
+    LastUsed,
+    /// Highest live frecency score first.
+    Frecency,
+}
+
+impl ListSortMode {
+    /// Cycle to the next mode, in the order surfaced by the Cmd+K toggle.
+    pub fn next(self) -> Self {
+        match self {
+            ListSortMode::Name => ListSortMode::LastUsed,
+            ListSortMode::LastUsed => ListSortMode::Frecency,
+            ListSortMode::Frecency => ListSortMode::Name,
+        }
+    }
+
+    /// Short label for display in the actions dialog (e.g. "Sort by: Name").
+    pub fn label(self) -> &'static str {
+        match self {
+            ListSortMode::Name => "Name",
+            ListSortMode::LastUsed => "Last Used",
+            ListSortMode::Frecency => "Frecency",
+        }
+    }
+}
+
+/// Raw data format for JSON serialization
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ListSortData {
+    mode: ListSortMode,
+}
+
+/// Store for the user's chosen sort mode, persisted across launches.
+#[derive(Debug)]
+pub struct ListSortPreference {
+    mode: ListSortMode,
+    file_path: PathBuf,
+    dirty: bool,
+}
+
+impl ListSortPreference {
+    /// Create a new store with the default path (~/.scriptkit/list_sort.json)
+    pub fn new() -> Self {
+        Self {
+            mode: ListSortMode::default(),
+            file_path: Self::default_path(),
+            dirty: false,
+        }
+    }
+
+    /// Create a store with a custom path (for testing)
+    #[allow(dead_code)]
+    pub fn with_path(path: PathBuf) -> Self {
+        Self {
+            mode: ListSortMode::default(),
+            file_path: path,
+            dirty: false,
+        }
+    }
+
+    fn default_path() -> PathBuf {
+        PathBuf::from(shellexpand::tilde("~/.scriptkit/list_sort.json").as_ref())
+    }
+
+    /// Load the sort mode from disk. Starts at the default (Name) if the
+    /// file doesn't exist.
+    #[instrument(name = "list_sort_load", skip(self))]
+    pub fn load(&mut self) -> Result<()> {
+        if !self.file_path.exists() {
+            info!(path = %self.file_path.display(), "List sort file not found, starting fresh");
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(&self.file_path).with_context(|| {
+            format!(
+                "Failed to read list sort file: {}",
+                self.file_path.display()
+            )
+        })?;
+
+        let data: ListSortData =
+            serde_json::from_str(&content).with_context(|| "Failed to parse list sort JSON")?;
+
+        self.mode = data.mode;
+        self.dirty = false;
+
+        info!(path = %self.file_path.display(), mode = ?self.mode, "Loaded list sort preference");
+        Ok(())
+    }
+
+    /// Save the sort mode to disk using atomic write (write temp + rename)
+    #[instrument(name = "list_sort_save", skip(self))]
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            debug!("No changes to save");
+            return Ok(());
+        }
+
+        if let Some(parent) = self.file_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        let json = serde_json::to_string(&ListSortData { mode: self.mode })
+            .context("Failed to serialize list sort preference")?;
+
+        let temp_path = self.file_path.with_extension("json.tmp");
+        std::fs::write(&temp_path, &json).with_context(|| {
+            format!(
+                "Failed to write temp list sort file: {}",
+                temp_path.display()
+            )
+        })?;
+        std::fs::rename(&temp_path, &self.file_path).with_context(|| {
+            format!("Failed to rename temp file to {}", self.file_path.display())
+        })?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Current sort mode
+    pub fn mode(&self) -> ListSortMode {
+        self.mode
+    }
+
+    /// Advance to the next sort mode, marking the store dirty
+    pub fn cycle(&mut self) -> ListSortMode {
+        self.mode = self.mode.next();
+        self.dirty = true;
+        self.mode
+    }
+}
+
+impl Default for ListSortPreference {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("list_sort_test_{}.json", name))
+    }
+
+    fn cleanup(path: &PathBuf) {
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_cycle_wraps_around() {
+        assert_eq!(ListSortMode::Name.next(), ListSortMode::LastUsed);
+        assert_eq!(ListSortMode::LastUsed.next(), ListSortMode::Frecency);
+        assert_eq!(ListSortMode::Frecency.next(), ListSortMode::Name);
+    }
+
+    #[test]
+    fn test_default_mode_is_name() {
+        let store = ListSortPreference::with_path(temp_path("default"));
+        assert_eq!(store.mode(), ListSortMode::Name);
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let path = temp_path("roundtrip");
+        cleanup(&path);
+
+        let mut store = ListSortPreference::with_path(path.clone());
+        store.cycle(); // Name -> LastUsed
+        store.save().unwrap();
+
+        let mut reloaded = ListSortPreference::with_path(path.clone());
+        reloaded.load().unwrap();
+        assert_eq!(reloaded.mode(), ListSortMode::LastUsed);
+
+        cleanup(&path);
+    }
+
+    #[test]
+    fn test_load_missing_file_starts_at_default() {
+        let mut store = ListSortPreference::with_path(temp_path("missing"));
+        store.load().unwrap();
+        assert_eq!(store.mode(), ListSortMode::Name);
+    }
+}
@@ -25,12 +25,15 @@
 //! {"timestamp":"2024-12-25T10:30:45.123Z","level":"INFO","target":"script_kit_gpui::main","message":"Script executed","fields":{"event_type":"script_event","script_id":"abc","duration_ms":42}}
 //! ```
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Write as FmtWrite;
 use std::fs::{self, OpenOptions};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::{Mutex, OnceLock};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::config::Config;
 
 use tracing::field::{Field, Visit};
 use tracing::{Level, Subscriber};
@@ -425,6 +428,206 @@ pub fn log_path() -> PathBuf {
     get_log_dir().join("script-kit-gpui.jsonl")
 }
 
+// =============================================================================
+// PER-CATEGORY LOG LEVELS + RATE LIMITING
+//
+// Configured once via `configure_from_config`, read on every `log()`/
+// `log_debug()` call with no lock (an `AtomicU8` load), and enforced only
+// for those two functions - the many specialized `log_*` helpers below
+// (log_script_event, log_scroll_batch, etc.) call `tracing::info!`/
+// `debug!` directly and are unaffected by this gate.
+// =============================================================================
+
+/// Logging verbosity, least to most chatty. Ordered so a configured level
+/// filters out anything strictly more verbose than itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum LogLevel {
+    Error = 0,
+    Warn = 1,
+    Info = 2,
+    Debug = 3,
+    Trace = 4,
+}
+
+impl LogLevel {
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Some(LogLevel::Error),
+            "warn" | "warning" => Some(LogLevel::Warn),
+            "info" => Some(LogLevel::Info),
+            "debug" => Some(LogLevel::Debug),
+            "trace" => Some(LogLevel::Trace),
+            _ => None,
+        }
+    }
+
+    fn from_u8(v: u8) -> Self {
+        match v {
+            0 => LogLevel::Error,
+            1 => LogLevel::Warn,
+            2 => LogLevel::Info,
+            3 => LogLevel::Debug,
+            _ => LogLevel::Trace,
+        }
+    }
+}
+
+/// Per-category level overrides, snapshotted once by `configure_from_config`.
+/// A category missing from `overrides` falls back to `default`.
+struct LevelConfig {
+    default: AtomicU8,
+    overrides: HashMap<String, AtomicU8>,
+}
+
+static LEVEL_CONFIG: OnceLock<LevelConfig> = OnceLock::new();
+
+/// Snapshots `config.logging` into atomics for lock-free reads from
+/// `log()`/`log_debug()`. Call once at startup, right after
+/// `config::load_config()` - config reloads in this app are restart-based
+/// (see `~/.scriptkit/config.ts` in the dev-workflow docs), so a single
+/// snapshot per process is sufficient; there's no live-reload path that
+/// would require re-snapshotting.
+pub fn configure_from_config(config: &Config) {
+    let Some(levels) = &config.logging else {
+        return;
+    };
+    let default_level = levels
+        .get("default")
+        .and_then(|s| LogLevel::parse(s))
+        .unwrap_or(LogLevel::Trace);
+    let overrides = levels
+        .iter()
+        .filter(|(k, _)| k.as_str() != "default")
+        .filter_map(|(k, v)| {
+            LogLevel::parse(v).map(|level| (k.to_ascii_uppercase(), AtomicU8::new(level as u8)))
+        })
+        .collect();
+    let _ = LEVEL_CONFIG.set(LevelConfig {
+        default: AtomicU8::new(default_level as u8),
+        overrides,
+    });
+}
+
+/// The configured level for `category`, or `LogLevel::Trace` (log
+/// everything, today's unfiltered behavior) if `configure_from_config` was
+/// never called or the category has no override and no `"default"` entry.
+fn level_for(category: &str) -> LogLevel {
+    let Some(config) = LEVEL_CONFIG.get() else {
+        return LogLevel::Trace;
+    };
+    let ordering = Ordering::Relaxed;
+    match config.overrides.get(&category.to_ascii_uppercase()) {
+        Some(level) => LogLevel::from_u8(level.load(ordering)),
+        None => LogLevel::from_u8(config.default.load(ordering)),
+    }
+}
+
+/// A burst of identical `(category, message)` log calls within this window
+/// collapses after the first few occurrences.
+const RATE_LIMIT_THRESHOLD: u32 = 5;
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(1);
+
+struct RateLimitEntry {
+    message: String,
+    window_start: Instant,
+    count: u32,
+    suppressed: u32,
+}
+
+static RATE_LIMITS: OnceLock<Mutex<HashMap<String, RateLimitEntry>>> = OnceLock::new();
+
+/// Whether `message` for `category` should be emitted now, applying a
+/// cap of `RATE_LIMIT_THRESHOLD` identical messages per `RATE_LIMIT_WINDOW`,
+/// a fixed window that starts at the first occurrence of the message and
+/// restarts when a different message or an expired window arrives. When a repeat is suppressed, or a window rolls
+/// over with suppressed repeats pending, returns a folded summary line to
+/// log instead of (or before) the caller's own message.
+///
+/// Known limitation: a trailing summary for the last burst before the app
+/// goes idle is never flushed on its own - there's no background timer -
+/// it only appears once another log call for that category arrives.
+fn rate_limit_check(category: &str, message: &str) -> RateLimitOutcome {
+    let limits = RATE_LIMITS.get_or_init(|| Mutex::new(HashMap::new()));
+    let Ok(mut limits) = limits.lock() else {
+        return RateLimitOutcome::Emit;
+    };
+    let now = Instant::now();
+
+    // Decide the outcome and, if the window is rolling over, gather the
+    // folded summary as an owned value - all before touching `limits`
+    // again for the insert below.
+    enum Decision {
+        Increment(u32),
+        Reset { folded_summary: Option<String> },
+    }
+    let decision = match limits.get(category) {
+        Some(entry)
+            if entry.message == message
+                && now.duration_since(entry.window_start) < RATE_LIMIT_WINDOW =>
+        {
+            Decision::Increment(entry.count + 1)
+        }
+        Some(entry) if entry.suppressed > 0 => Decision::Reset {
+            folded_summary: Some(format!("{} (repeated x{})", entry.message, entry.suppressed)),
+        },
+        _ => Decision::Reset {
+            folded_summary: None,
+        },
+    };
+
+    match decision {
+        Decision::Increment(new_count) => {
+            let entry = limits.get_mut(category).expect("entry checked above");
+            entry.count = new_count;
+            if new_count <= RATE_LIMIT_THRESHOLD {
+                RateLimitOutcome::Emit
+            } else {
+                entry.suppressed += 1;
+                RateLimitOutcome::Suppress
+            }
+        }
+        Decision::Reset { folded_summary } => {
+            limits.insert(
+                category.to_string(),
+                RateLimitEntry {
+                    message: message.to_string(),
+                    window_start: now,
+                    count: 1,
+                    suppressed: 0,
+                },
+            );
+            match folded_summary {
+                Some(summary) => RateLimitOutcome::EmitWithSummary(summary),
+                None => RateLimitOutcome::Emit,
+            }
+        }
+    }
+}
+
+enum RateLimitOutcome {
+    Emit,
+    Suppress,
+    EmitWithSummary(String),
+}
+
+/// Logs an attention-grabbing box-drawing banner (as used by VISIBILITY's
+/// "HOTKEY TRIGGERED" announcement) at `Trace`/`Debug` verbosity, or a
+/// single collapsed line once `category` is configured above debug -
+/// satisfies "collapse box-drawing banners into structured lines" without
+/// losing the announcement entirely for a noisy category configured down
+/// to e.g. "info".
+pub fn log_banner(category: &str, message: &str) {
+    if level_for(category) >= LogLevel::Debug {
+        let width = message.chars().count() + 2;
+        log(category, "");
+        log(category, &format!("╔{}╗", "═".repeat(width)));
+        log(category, &format!("║ {} ║", message));
+        log(category, &format!("╚{}╝", "═".repeat(width)));
+    } else {
+        log(category, &format!("[BANNER] {}", message));
+    }
+}
+
 // =============================================================================
 // BACKWARD COMPATIBILITY - Legacy log() function wrappers
 // =============================================================================
@@ -436,6 +639,18 @@ pub fn log_path() -> PathBuf {
 /// tracing::info!(category = "UI", duration_ms = 42, "Button clicked");
 /// ```
 pub fn log(category: &str, message: &str) {
+    if level_for(category) < LogLevel::Info {
+        return;
+    }
+    match rate_limit_check(category, message) {
+        RateLimitOutcome::Suppress => return,
+        RateLimitOutcome::EmitWithSummary(summary) => {
+            add_to_buffer(category, &summary);
+            tracing::info!(category = category, legacy = true, "{}", summary);
+        }
+        RateLimitOutcome::Emit => {}
+    }
+
     // Add to legacy buffer for UI display
     add_to_buffer(category, message);
 
@@ -479,6 +694,18 @@ pub fn get_last_logs(n: usize) -> Vec<String> {
 /// Use for verbose performance/scroll/cache logging
 #[cfg(debug_assertions)]
 pub fn log_debug(category: &str, message: &str) {
+    if level_for(category) < LogLevel::Debug {
+        return;
+    }
+    match rate_limit_check(category, message) {
+        RateLimitOutcome::Suppress => return,
+        RateLimitOutcome::EmitWithSummary(summary) => {
+            add_to_buffer(category, &summary);
+            tracing::debug!(category = category, legacy = true, "{}", summary);
+        }
+        RateLimitOutcome::Emit => {}
+    }
+
     add_to_buffer(category, message);
     tracing::debug!(category = category, legacy = true, "{}", message);
 }
@@ -1738,4 +1965,87 @@ mod tests {
         assert!(summary.contains("type:screenshotResult"));
         assert!(summary.contains(&format!("len:{}", json.len())));
     }
+
+    // -------------------------------------------------------------------------
+    // LogLevel / rate-limiting tests
+    //
+    // These avoid touching `LEVEL_CONFIG`/`configure_from_config`: it's a
+    // process-wide `OnceLock` meant to be set exactly once at startup, so
+    // asserting on it here would make results depend on test execution
+    // order. Each rate-limit test uses its own category string since
+    // `RATE_LIMITS` is shared across the whole test binary.
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_log_level_parse_recognizes_variants() {
+        assert_eq!(LogLevel::parse("error"), Some(LogLevel::Error));
+        assert_eq!(LogLevel::parse("WARN"), Some(LogLevel::Warn));
+        assert_eq!(LogLevel::parse("warning"), Some(LogLevel::Warn));
+        assert_eq!(LogLevel::parse("Info"), Some(LogLevel::Info));
+        assert_eq!(LogLevel::parse("debug"), Some(LogLevel::Debug));
+        assert_eq!(LogLevel::parse("TRACE"), Some(LogLevel::Trace));
+    }
+
+    #[test]
+    fn test_log_level_parse_rejects_unknown() {
+        assert_eq!(LogLevel::parse("verbose"), None);
+        assert_eq!(LogLevel::parse(""), None);
+    }
+
+    #[test]
+    fn test_log_level_ordering_least_to_most_verbose() {
+        assert!(LogLevel::Error < LogLevel::Warn);
+        assert!(LogLevel::Warn < LogLevel::Info);
+        assert!(LogLevel::Info < LogLevel::Debug);
+        assert!(LogLevel::Debug < LogLevel::Trace);
+    }
+
+    #[test]
+    fn test_rate_limit_check_allows_first_threshold_then_suppresses() {
+        let category = "RATE_LIMIT_TEST_THRESHOLD";
+        for _ in 0..RATE_LIMIT_THRESHOLD {
+            assert!(matches!(
+                rate_limit_check(category, "spam"),
+                RateLimitOutcome::Emit
+            ));
+        }
+        assert!(matches!(
+            rate_limit_check(category, "spam"),
+            RateLimitOutcome::Suppress
+        ));
+    }
+
+    #[test]
+    fn test_rate_limit_check_folds_suppressed_count_into_summary() {
+        let category = "RATE_LIMIT_TEST_SUMMARY";
+        for _ in 0..RATE_LIMIT_THRESHOLD {
+            rate_limit_check(category, "spam");
+        }
+        // These two are suppressed (count 6, 7 - both beyond the threshold).
+        rate_limit_check(category, "spam");
+        rate_limit_check(category, "spam");
+        // A different message for the same category rolls the window over,
+        // folding the 2 suppressed "spam" repeats into a summary.
+        match rate_limit_check(category, "different message") {
+            RateLimitOutcome::EmitWithSummary(summary) => {
+                assert!(summary.contains("spam"));
+                assert!(summary.contains("repeated x2"));
+            }
+            RateLimitOutcome::Emit => panic!("expected EmitWithSummary, got Emit"),
+            RateLimitOutcome::Suppress => panic!("expected EmitWithSummary, got Suppress"),
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_check_distinct_messages_do_not_suppress_each_other() {
+        let category = "RATE_LIMIT_TEST_DISTINCT";
+        assert!(matches!(
+            rate_limit_check(category, "first"),
+            RateLimitOutcome::Emit
+        ));
+        assert!(matches!(
+            rate_limit_check(category, "second"),
+            RateLimitOutcome::Emit
+        ));
+    }
 }
@@ -18,7 +18,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 mod process_manager;
 use cocoa::base::id;
 use cocoa::foundation::NSRect;
-use process_manager::PROCESS_MANAGER;
+use process_manager::{escalate_shutdown, PROCESS_MANAGER};
 
 // Platform utilities - mouse position, display info, window movement, screenshots
 use platform::{
@@ -30,8 +30,14 @@ extern crate objc;
 mod actions;
 mod agents;
 mod ai;
+mod cancellation;
+mod choice_actions;
+mod choice_image;
+mod choices_cmd;
 mod components;
 mod config;
+mod density;
+mod design_token_overrides;
 mod designs;
 mod editor;
 mod error;
@@ -44,15 +50,20 @@ mod hotkeys;
 mod list_item;
 mod logging;
 mod login_item;
+mod message_dispatch;
 mod navigation;
 mod panel;
 mod perf;
 mod platform;
+mod preview_doc;
 mod prompts;
 mod protocol;
 mod scripts;
 #[cfg(target_os = "macos")]
 mod selected_text;
+mod session_state;
+#[cfg(test)]
+mod session_state_persistence_tests;
 mod setup;
 mod shortcuts;
 mod stdin_commands;
@@ -63,6 +74,7 @@ mod theme;
 mod transitions;
 mod tray;
 mod ui_foundation;
+mod url_scheme;
 mod utils;
 mod warning_banner;
 mod watcher;
@@ -77,6 +89,7 @@ mod windows;
 // Phase 1 system API modules
 mod clipboard_history;
 mod file_search;
+mod recent_files;
 mod toast_manager;
 mod window_control;
 
@@ -84,6 +97,10 @@ mod window_control;
 #[cfg(target_os = "macos")]
 mod system_actions;
 
+// System sound playback for the PlaySound message
+#[cfg(target_os = "macos")]
+mod sounds;
+
 // Script creation - Create new scripts and scriptlets
 mod script_creation;
 
@@ -100,9 +117,19 @@ mod menu_bar;
 #[cfg(target_os = "macos")]
 mod frontmost_app_tracker;
 
+// Sleep/wake tracker - re-checks scheduler catch-up on system wake
+#[cfg(target_os = "macos")]
+mod sleep_wake_tracker;
+
 // Frecency tracking for script usage
 mod frecency;
 
+// Persisted collapsed/expanded state for main menu section headers
+mod collapsed_sections;
+
+// Persisted sort order for the ungrouped main menu sections
+mod list_sort;
+
 // Scriptlet parsing and variable substitution
 mod scriptlets;
 
@@ -137,9 +164,18 @@ mod expand_manager;
 // Script scheduling with cron expressions and natural language
 mod scheduler;
 
+// Missed-run catch-up policy + persisted last-fire times for the scheduler
+mod scheduler_catchup;
+
 // HUD manager - system-level overlay notifications (separate floating windows)
 mod hud_manager;
 
+// Concurrency guard - per-script-path launch policy (single/queue/parallel)
+mod concurrency_guard;
+
+// Widget manager - persistent floating HTML windows owned by scripts
+mod widget_manager;
+
 // Debug grid overlay for visual testing
 mod debug_grid;
 
@@ -166,10 +202,10 @@ use crate::toast_manager::{PendingToast, ToastManager};
 use components::ToastVariant;
 use editor::EditorPrompt;
 use prompts::{
-    ContainerOptions, ContainerPadding, DivPrompt, DropPrompt, EnvPrompt, PathInfo, PathPrompt,
-    PathPromptEvent, SelectPrompt, TemplatePrompt,
+    ConfirmPrompt, ContainerOptions, ContainerPadding, DivPrompt, DropPrompt, EnvPrompt, PathInfo,
+    PathPrompt, PathPromptEvent, SelectPrompt, TemplatePrompt,
 };
-use tray::{TrayManager, TrayMenuAction};
+use tray::{TrayManager, TrayMenuAction, TRAY_SCRIPT_ID_PREFIX, TRAY_SCRIPT_MORE_ID};
 use ui_foundation::get_vibrancy_background;
 use warning_banner::{WarningBanner, WarningBannerColors};
 use window_resize::{
@@ -177,6 +213,7 @@ use window_resize::{
     resize_first_window_to_height, resize_to_view_sync, ViewType,
 };
 
+use collapsed_sections::CollapsedSections;
 use components::{
     FormFieldColors, PromptFooter, PromptFooterColors, PromptFooterConfig, Scrollbar,
     ScrollbarColors,
@@ -184,10 +221,11 @@ use components::{
 use designs::{get_tokens, render_design_item, DesignVariant};
 use frecency::FrecencyStore;
 use list_item::{
-    render_section_header, GroupedListItem, ListItem, ListItemColors, LIST_ITEM_HEIGHT,
-    SECTION_HEADER_HEIGHT,
+    coerce_selection, render_section_header, GroupedListItem, GroupedListState, ListItem,
+    ListItemColors, LIST_ITEM_HEIGHT,
 };
-use scripts::get_grouped_results;
+use list_sort::{ListSortMode, ListSortPreference};
+use scripts::{get_grouped_results_with_sort, parse_search_scope, SearchScope};
 // strip_html_tags removed - DivPrompt now renders HTML properly
 
 use actions::{
@@ -199,7 +237,7 @@ use panel::{
     HEADER_PADDING_X, HEADER_PADDING_Y,
 };
 use parking_lot::Mutex as ParkingMutex;
-use protocol::{Choice, Message, ProtocolAction};
+use protocol::{Choice, FooterHint, Message, ProtocolAction};
 use std::sync::{mpsc, Arc, Mutex};
 use syntax::highlight_code_lines;
 
@@ -208,15 +246,18 @@ use syntax::highlight_code_lines;
 type PromptChannel = (mpsc::Sender<PromptMessage>, mpsc::Receiver<PromptMessage>);
 
 // Import utilities from modules
-use stdin_commands::{start_stdin_listener, ExternalCommand};
-use utils::render_path_with_highlights;
+use stdin_commands::ExternalCommand;
+use utils::{normalize_for_search, render_path_with_highlights};
 
 // Global state for hotkey signaling between threads
 static NEEDS_RESET: AtomicBool = AtomicBool::new(false); // Track if window needs reset to script list on next show
 
-pub use script_kit_gpui::{is_main_window_visible, set_main_window_visible};
+pub use script_kit_gpui::{
+    is_main_window_visible, is_paused, set_main_window_visible, set_paused, toggle_paused,
+};
 static PANEL_CONFIGURED: AtomicBool = AtomicBool::new(false); // Track if floating panel has been configured (one-time setup on first show)
 static SHUTDOWN_REQUESTED: AtomicBool = AtomicBool::new(false); // Track if shutdown signal received (prevents new script spawns)
+static SHUTDOWN_IN_PROGRESS: AtomicBool = AtomicBool::new(false); // Guards against running the graceful shutdown sequence twice
 
 /// Convert our ToastVariant to gpui-component's NotificationType
 fn toast_variant_to_notification_type(variant: ToastVariant) -> NotificationType {
@@ -298,7 +339,8 @@ fn show_main_window_helper(
 
     // 3. Position at eye-line on mouse display
     let window_size = gpui::size(px(750.), initial_window_height());
-    let bounds = platform::calculate_eye_line_bounds_on_mouse_display(window_size);
+    let position_mode = app_entity.read(cx).config.get_window_position_mode();
+    let bounds = platform::calculate_eye_line_bounds_on_mouse_display(window_size, position_mode);
     platform::move_first_window_to_bounds(&bounds);
 
     // 4. Configure as floating panel (first time only)
@@ -385,6 +427,10 @@ fn hide_main_window_helper(app_entity: Entity<ScriptListApp>, cx: &mut App) {
 
     // 3. Cancel prompt and reset UI
     app_entity.update(cx, |view, ctx| {
+        // Record that the user explicitly hid the window, so a script's
+        // `Message::Focus` shortly afterward doesn't immediately reopen it.
+        view.last_explicit_hide = Some(std::time::Instant::now());
+
         if view.is_in_prompt() {
             logging::log("VISIBILITY", "Canceling prompt before hiding");
             view.cancel_script_execution(ctx);
@@ -486,6 +532,8 @@ fn execute_fallback_action(
                         hud_manager::show_hud(
                             "Text copied - paste into Notes".to_string(),
                             Some(2000),
+                            None,
+                            None,
                             cx,
                         );
                     }
@@ -520,11 +568,23 @@ fn execute_fallback_action(
                             let item = gpui::ClipboardItem::new_string(result_str.clone());
                             cx.write_to_clipboard(item);
                             // Show HUD with result
-                            hud_manager::show_hud(format!("= {}", result_str), Some(2000), cx);
+                            hud_manager::show_hud(
+                                format!("= {}", result_str),
+                                Some(2000),
+                                None,
+                                None,
+                                cx,
+                            );
                         }
                         Err(e) => {
                             logging::log("FALLBACK", &format!("Calculation error: {}", e));
-                            hud_manager::show_hud(format!("Error: {}", e), Some(3000), cx);
+                            hud_manager::show_hud(
+                                format!("Error: {}", e),
+                                Some(3000),
+                                None,
+                                None,
+                                cx,
+                            );
                         }
                     }
                 }
@@ -553,6 +613,17 @@ fn execute_fallback_action(
     }
 }
 
+/// Resolve a script name or alias (as used by `scriptkit://run?script=...`)
+/// to its file path, by scanning the same script registry the main list
+/// renders from. Returns `None` if nothing matches.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn resolve_script_by_name_or_alias(name: &str) -> Option<String> {
+    scripts::read_scripts()
+        .into_iter()
+        .find(|script| script.name == name || script.alias.as_deref() == Some(name))
+        .map(|script| script.path.to_string_lossy().to_string())
+}
+
 /// Register bundled JetBrains Mono font with GPUI's text system
 ///
 /// This embeds the font files directly in the binary and registers them
@@ -610,14 +681,22 @@ enum AppView {
     ArgPrompt {
         id: String,
         placeholder: String,
+        /// Hint text shown under the header (dim, e.g. a keyboard shortcut reminder)
+        hint: Option<String>,
         choices: Vec<Choice>,
         actions: Option<Vec<ProtocolAction>>,
+        /// Script-provided footer shortcut hints; falls back to the prompt's
+        /// built-in defaults when `None` or empty.
+        footer_hints: Option<Vec<FooterHint>>,
     },
     /// Showing a div prompt from a script
     DivPrompt {
         #[allow(dead_code)]
         id: String,
         entity: Entity<DivPrompt>,
+        /// Script-provided footer shortcut hints; falls back to the prompt's
+        /// built-in Submit/Actions buttons when `None` or empty.
+        footer_hints: Option<Vec<FooterHint>>,
     },
     /// Showing a form prompt from a script (HTML form with submit button)
     FormPrompt {
@@ -640,6 +719,9 @@ enum AppView {
         /// Note: This is kept for API compatibility but focus is managed via entity.focus()
         #[allow(dead_code)]
         focus_handle: FocusHandle,
+        /// Script-provided footer shortcut hints; falls back to the prompt's
+        /// built-in Submit/Actions buttons when `None` or empty.
+        footer_hints: Option<Vec<FooterHint>>,
     },
     /// Showing a select prompt from a script (multi-select)
     SelectPrompt {
@@ -672,6 +754,12 @@ enum AppView {
         id: String,
         entity: Entity<TemplatePrompt>,
     },
+    /// Showing confirm prompt (Yes/No dialog from `ask()`)
+    ConfirmPrompt {
+        #[allow(dead_code)]
+        id: String,
+        entity: Entity<ConfirmPrompt>,
+    },
     /// Showing clipboard history
     /// P0 FIX: View state only - data comes from clipboard_history module cache
     ClipboardHistoryView {
@@ -701,6 +789,20 @@ enum AppView {
         #[allow(dead_code)]
         focus_handle: FocusHandle,
     },
+    /// Showing a script/agent's last run log, read-only (see
+    /// `EditorPrompt::read_only_view`)
+    RunLogView {
+        entity: Entity<EditorPrompt>,
+        #[allow(dead_code)]
+        focus_handle: FocusHandle,
+    },
+    /// Showing the perf/cache diagnostics report, read-only (see
+    /// `EditorPrompt::read_only_view`)
+    DiagnosticsView {
+        entity: Entity<EditorPrompt>,
+        #[allow(dead_code)]
+        focus_handle: FocusHandle,
+    },
     /// Showing quick terminal
     QuickTerminalView {
         entity: Entity<term_prompt::TermPrompt>,
@@ -710,6 +812,8 @@ enum AppView {
         query: String,
         selected_index: usize,
     },
+    /// Showing running scripts (background processes tracked by PROCESS_MANAGER)
+    RunningScriptsView { selected_index: usize },
 }
 
 /// Wrapper to hold a script session that can be shared across async boundaries
@@ -760,6 +864,8 @@ enum FocusTarget {
     TemplatePrompt,
     /// Focus the term prompt
     TermPrompt,
+    /// Focus the confirm prompt
+    ConfirmPrompt,
 }
 
 /// Identifies which prompt type is hosting the actions dialog.
@@ -817,6 +923,15 @@ enum PromptMessage {
         placeholder: String,
         choices: Vec<Choice>,
         actions: Option<Vec<ProtocolAction>>,
+        /// Milliseconds to wait for a response before showing a
+        /// "script isn't responding" toast. `None` means no timeout.
+        timeout_ms: Option<u64>,
+        /// When `choices` is empty, populate it from this shell command's
+        /// stdout (see `choices_cmd::run_choices_cmd`).
+        choices_cmd: Option<String>,
+        /// Script-provided footer shortcut hints; falls back to the prompt's
+        /// built-in defaults when `None` or empty.
+        footer_hints: Option<Vec<FooterHint>>,
     },
     ShowDiv {
         id: String,
@@ -836,6 +951,20 @@ enum PromptMessage {
         container_padding: Option<serde_json::Value>,
         /// Container opacity (0-100)
         opacity: Option<u8>,
+        /// Script-provided footer shortcut hints; falls back to the prompt's
+        /// built-in Submit/Actions buttons when `None` or empty.
+        footer_hints: Option<Vec<FooterHint>>,
+    },
+    /// Split (master-detail) prompt: an arg-style choice list plus a live
+    /// preview pane, rendered via the same path as `ShowArg` with the
+    /// preview pane wired up (see `ScriptListApp::split_preview`).
+    ShowSplit {
+        id: String,
+        placeholder: String,
+        choices: Vec<Choice>,
+        preview: Option<String>,
+        actions: Option<Vec<ProtocolAction>>,
+        footer_hints: Option<Vec<FooterHint>>,
     },
     ShowForm {
         id: String,
@@ -845,6 +974,9 @@ enum PromptMessage {
     ShowTerm {
         id: String,
         command: Option<String>,
+        shell: Option<String>,
+        cwd: Option<String>,
+        login: Option<bool>,
         actions: Option<Vec<ProtocolAction>>,
     },
     ShowEditor {
@@ -853,8 +985,17 @@ enum PromptMessage {
         language: Option<String>,
         template: Option<String>,
         actions: Option<Vec<ProtocolAction>>,
+        /// Script-provided footer shortcut hints; falls back to the prompt's
+        /// built-in Submit/Actions buttons when `None` or empty.
+        footer_hints: Option<Vec<FooterHint>>,
     },
-    /// Path picker prompt for file/folder selection
+    /// Path picker prompt for file/folder selection.
+    ///
+    /// No `footer_hints` field: Path's hint row comes from `hint` via
+    /// `PromptContainerConfig::hint`, a single dim text line rendered by a
+    /// different component (`PromptContainer`/`PromptHeader`) than the
+    /// chip-based `PromptFooter::custom_hints` used by Arg/Div/Editor. Path
+    /// has no `PromptFooter` instance to plug hint chips into.
     ShowPath {
         id: String,
         start_path: Option<String>,
@@ -866,6 +1007,10 @@ enum PromptMessage {
         key: String,
         prompt: Option<String>,
         secret: bool,
+        /// Regex the submitted value must match; submission is blocked on mismatch
+        pattern: Option<String>,
+        /// Switches the input to a textarea-style field for multi-line values
+        multiline: bool,
     },
     /// Drag and drop prompt for file uploads
     ShowDrop {
@@ -878,21 +1023,54 @@ enum PromptMessage {
         id: String,
         template: String,
     },
-    /// Multi-select prompt from choices
+    /// Yes/No confirmation dialog from `ask()`
+    ShowConfirm {
+        id: String,
+        title: Option<String>,
+        message: String,
+        ok_label: Option<String>,
+        cancel_label: Option<String>,
+        destructive: bool,
+    },
+    /// Multi-select prompt from choices.
+    ///
+    /// No `footer_hints` field: Select renders no `PromptFooter` at all (see
+    /// `render_select_prompt` in `render_prompts/other.rs`), so there is no
+    /// chip UI to plug script-provided hints into.
     ShowSelect {
         id: String,
         placeholder: Option<String>,
         choices: Vec<Choice>,
         multiple: bool,
+        max: Option<usize>,
     },
     HideWindow,
+    /// Bring the window forward mid-script, e.g. after a silent background
+    /// phase that now needs input. No-op if already visible, or if the
+    /// user explicitly hid the window moments ago.
+    FocusWindow,
     OpenBrowser {
         url: String,
     },
-    ScriptExit,
+    OpenPath {
+        path: String,
+    },
+    /// Switch the active theme to a named preset. Shows a HUD toast if
+    /// `name` doesn't match an existing preset in
+    /// `~/.scriptkit/kit/themes/`, or if that preset fails validation.
+    SetTheme {
+        name: String,
+    },
+    /// The running script called `exit()`. `value` carries its optional
+    /// result payload, written to the app's stdout for external controllers.
+    ScriptExit {
+        value: Option<serde_json::Value>,
+    },
     /// External command to run a script by path
     RunScript {
         path: String,
+        /// Positional args to pre-answer the script's `arg()` calls, in order.
+        args: Vec<String>,
     },
     /// Script error with detailed information for toast display
     ScriptError {
@@ -902,6 +1080,8 @@ enum PromptMessage {
         stack_trace: Option<String>,
         script_path: String,
         suggestions: Vec<String>,
+        /// Path to this run's per-script log file (see `executor::RunLogger`), if one was created
+        log_path: Option<String>,
     },
     /// Protocol parsing error reported from script stdout
     ProtocolError {
@@ -931,10 +1111,52 @@ enum PromptMessage {
     SetInput {
         text: String,
     },
+    /// Set the current prompt's placeholder text
+    SetPlaceholder {
+        text: String,
+    },
+    /// Set the current prompt's hint text (dim line shown below the input)
+    SetHint {
+        text: String,
+    },
+    /// Push fresh preview-pane content into the active split prompt (see
+    /// `ScriptListApp::split_preview`). A no-op when no split prompt is open.
+    SetPreview {
+        html: String,
+    },
+    /// Cache preview content for a specific choice value in the active
+    /// arg-family prompt (see `ScriptListApp::preview_content_cache`). A
+    /// no-op when no arg-family prompt is open.
+    Preview {
+        value: String,
+        content: String,
+    },
+    /// Pre-filter the main script list, usable before `HideWindow` so the
+    /// next show starts filtered, or while the script list is already shown
+    SetFilter {
+        text: String,
+    },
+    /// Stream updated choices into an open arg prompt, with an optional
+    /// loading indicator while more choices are still being fetched
+    SetPlaceholderChoices {
+        id: String,
+        choices: Vec<Choice>,
+        loading: bool,
+    },
     /// Show HUD overlay message
     ShowHud {
         text: String,
         duration_ms: Option<u64>,
+        position: Option<protocol::HudPosition>,
+        /// Client-supplied ID, so a later `UpdateHud` can target this HUD.
+        id: Option<String>,
+    },
+    /// Update the text/duration of a live HUD previously shown with a
+    /// matching `id`, without dismissing and re-showing it.
+    UpdateHud {
+        id: String,
+        text: String,
+        duration_ms: Option<u64>,
     },
     /// Set SDK actions for the ActionsDialog
     SetActions {
@@ -946,6 +1168,97 @@ enum PromptMessage {
     },
     /// Hide the debug grid overlay
     HideGrid,
+    /// Create (or replace) a persistent floating widget window
+    ShowWidget {
+        id: String,
+        html: String,
+        options: Option<protocol::WidgetOptions>,
+    },
+    /// Apply an action (setState/close) to an existing widget
+    WidgetAction {
+        id: String,
+        action: protocol::WidgetActionKind,
+        state: Option<serde_json::Value>,
+    },
+}
+
+impl PromptMessage {
+    /// The prompt id, for the variants that show a navigable prompt view.
+    /// Returns `None` for overlay/control messages (HUD, widgets, ScriptExit, ...).
+    fn id(&self) -> Option<&str> {
+        match self {
+            PromptMessage::ShowArg { id, .. }
+            | PromptMessage::ShowDiv { id, .. }
+            | PromptMessage::ShowForm { id, .. }
+            | PromptMessage::ShowTerm { id, .. }
+            | PromptMessage::ShowEditor { id, .. }
+            | PromptMessage::ShowPath { id, .. }
+            | PromptMessage::ShowEnv { id, .. }
+            | PromptMessage::ShowDrop { id, .. }
+            | PromptMessage::ShowTemplate { id, .. }
+            | PromptMessage::ShowConfirm { id, .. }
+            | PromptMessage::ShowSelect { id, .. } => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Whether this message replaces the main prompt view (as opposed to an
+    /// overlay like a HUD or widget window). Only these are tracked on the
+    /// back-navigation stack.
+    fn is_navigable_prompt(&self) -> bool {
+        self.id().is_some()
+    }
+}
+
+/// An entry on a script's prompt history, used to restore the previous
+/// prompt when the user navigates back.
+#[derive(Debug, Clone)]
+struct PromptStackEntry {
+    /// The `Show*` message that originally produced this prompt.
+    message: PromptMessage,
+    /// Snapshot of the user's typed input at the time they left this prompt.
+    /// Only populated for `ShowArg`, per the back-navigation spec.
+    input_snapshot: Option<String>,
+}
+
+/// Push the prompt currently being left onto the back-navigation stack and
+/// make `new_prompt` the new current one.
+///
+/// Kept as a plain function of its bookkeeping (rather than a `ScriptListApp`
+/// method) so it's testable without a live GPUI `Context`. Non-navigable
+/// messages (HUD, widget windows, ScriptExit, ...) are a no-op - they don't
+/// belong on the back-navigation stack.
+fn push_prompt_history(
+    current_prompt: &mut Option<PromptMessage>,
+    stack: &mut Vec<PromptStackEntry>,
+    new_prompt: PromptMessage,
+    current_arg_input: &str,
+) {
+    if !new_prompt.is_navigable_prompt() {
+        return;
+    }
+    if let Some(previous) = current_prompt.take() {
+        let input_snapshot = if matches!(previous, PromptMessage::ShowArg { .. }) {
+            Some(current_arg_input.to_string())
+        } else {
+            None
+        };
+        stack.push(PromptStackEntry {
+            message: previous,
+            input_snapshot,
+        });
+    }
+    *current_prompt = Some(new_prompt);
+}
+
+/// Pop the next arg queued for the running script (via `ExternalCommand::Run`,
+/// the URL scheme, or an aliased invocation), FIFO.
+///
+/// Kept as a plain function over the queue (rather than inlined at the
+/// `ShowArg` call site) so the "empty string is a real arg, not a skip
+/// marker" invariant is testable without a live GPUI `Context`.
+fn next_queued_arg(queue: &mut std::collections::VecDeque<String>) -> Option<String> {
+    queue.pop_front()
 }
 
 struct ScriptListApp {
@@ -960,8 +1273,16 @@ struct ScriptListApp {
     cached_clipboard_entries: Vec<clipboard_history::ClipboardEntryMeta>,
     /// P0 FIX: Cached windows for WindowSwitcherView (avoids cloning per frame)
     cached_windows: Vec<window_control::WindowInfo>,
+    /// App name to pin first when grouping `cached_windows` by application -
+    /// the app that was frontmost before Script Kit took focus. Refreshed
+    /// alongside `cached_windows`, not recomputed per render (AX calls).
+    cached_frontmost_app: Option<String>,
     /// Cached file results for FileSearchView (avoids cloning per frame)
     cached_file_results: Vec<file_search::FileResult>,
+    /// Cached recent-files scan (RECENT FILES section of the main search).
+    /// Populated lazily on first use like `cached_windows`, since scanning
+    /// the configured folders on every keystroke would be too slow.
+    cached_recent_files: Vec<recent_files::RecentFileInfo>,
     selected_index: usize,
     /// Main menu filter text (mirrors gpui-component input state)
     filter_text: String,
@@ -982,11 +1303,22 @@ struct ScriptListApp {
     theme: theme::Theme,
     #[allow(dead_code)]
     config: config::Config,
+    /// Temporary token color overrides made in the design gallery's
+    /// playground panel. Session-only - never written to theme.json.
+    design_token_overrides: design_token_overrides::TokenOverrides,
     // Scroll activity tracking for scrollbar fade
     /// Whether scroll activity is happening (scrollbar should be visible)
     is_scrolling: bool,
     /// Timestamp of last scroll activity (for fade-out timer)
     last_scroll_time: Option<std::time::Instant>,
+    /// Whether the user is currently dragging the scrollbar thumb
+    scrollbar_dragging: bool,
+    /// Whether the mouse is hovering the scrollbar track/thumb (cancels fade-out)
+    scrollbar_hovered: bool,
+    /// When the user last explicitly hid the window (Cmd+W/Escape), so a
+    /// script-triggered `Message::Focus` shortly afterward doesn't yank the
+    /// window back open against their wishes.
+    last_explicit_hide: Option<std::time::Instant>,
     // Interactive script state
     current_view: AppView,
     script_session: SharedSession,
@@ -994,6 +1326,14 @@ struct ScriptListApp {
     // Uses TextInputState for selection and clipboard support
     arg_input: TextInputState,
     arg_selected_index: usize,
+    /// Value of the choice currently awaiting a second Enter to confirm a
+    /// destructive action (set via `Choice::confirm`). `None` when no
+    /// confirmation is pending. Cleared whenever the selection moves or a
+    /// new arg prompt is shown.
+    arg_pending_confirm: Option<String>,
+    /// Whether the script is still streaming in more choices via
+    /// `setPlaceholderChoices` - shows a subtle spinner in the arg input
+    arg_choices_loading: bool,
     // Channel for receiving prompt messages from script thread (async_channel for event-driven)
     prompt_receiver: Option<async_channel::Receiver<PromptMessage>>,
     // Channel for sending responses back to script
@@ -1013,10 +1353,15 @@ struct ScriptListApp {
     design_gallery_scroll_handle: UniformListScrollHandle,
     // Scroll handle for file search list
     file_search_scroll_handle: UniformListScrollHandle,
+    // Scroll handle for running scripts list
+    running_scripts_scroll_handle: UniformListScrollHandle,
     // File search loading state (true while mdfind is running)
     file_search_loading: bool,
     // Debounce task for file search (cancelled when new input arrives)
     file_search_debounce_task: Option<gpui::Task<()>>,
+    // Debounce task for session-state persistence (cancelled when state
+    // changes again before it fires); only used when `restore_session` is on
+    session_save_task: Option<gpui::Task<()>>,
     // Actions popup overlay
     show_actions_popup: bool,
     // ActionsDialog entity for focus management
@@ -1027,6 +1372,30 @@ struct ScriptListApp {
     focused_input: FocusedInput,
     // Current script process PID for explicit cleanup (belt-and-suspenders)
     current_script_pid: Option<u32>,
+    /// Cancelled when the current script session is torn down (user
+    /// cancels, app shuts down, or the script exits). Cloned into the
+    /// session's reader/writer threads so in-flight work can notice and
+    /// drop responses instead of writing to a dead pipe.
+    current_script_cancellation: cancellation::CancellationToken,
+    /// Path of the script currently running, so `ScriptExit` can release its
+    /// slot in the concurrency guard and dispatch the next queued launch.
+    current_script_path: Option<std::path::PathBuf>,
+    /// Whether the script currently running asked to stay open (via
+    /// `// KeepOpen: true` or typed `metadata.keepOpen`), so `ScriptExit`
+    /// can return to the script list instead of hiding the window.
+    current_script_keep_open: bool,
+    /// Args queued for the script currently starting/running, consumed in
+    /// order by `ShowArg` to auto-submit instead of rendering an interactive
+    /// prompt. Falls back to interactive once empty. An empty string is a
+    /// valid queued arg, not a "skip" marker.
+    pending_script_args: std::collections::VecDeque<String>,
+    /// The prompt currently on screen, kept so the next `Show*` can push it
+    /// onto `prompt_stack` before replacing the view. `None` outside of a
+    /// script's prompt flow (e.g. on the script list).
+    current_prompt: Option<PromptMessage>,
+    /// History of prompts shown by the current script, most recent last.
+    /// Enables "← Back" navigation; cleared on `ScriptExit`.
+    prompt_stack: Vec<PromptStackEntry>,
     // P1: Cache for filtered_results() - invalidate on filter_text change only
     cached_filtered_results: Vec<scripts::SearchResult>,
     filter_cache_key: String,
@@ -1041,11 +1410,26 @@ struct ScriptListApp {
     computed_filter_text: String,
     /// Coalesces filter updates and keeps only the latest value per tick
     filter_coalescer: FilterCoalescer,
+    /// Session-only ring buffer of recent non-empty filter queries, most
+    /// recent first. Recalled with Up when the filter is empty and the
+    /// selection is at the top, shell-history style.
+    filter_history: std::collections::VecDeque<String>,
+    /// Index into `filter_history` currently shown while browsing recalled
+    /// queries with Up; `None` when not browsing.
+    filter_history_cursor: Option<usize>,
     // Scroll stabilization: track last scrolled-to index to avoid redundant scroll_to_item calls
     last_scrolled_index: Option<usize>,
     // Preview cache: avoid re-reading file and re-highlighting on every render
     preview_cache_path: Option<String>,
     preview_cache_lines: Vec<syntax::HighlightedLine>,
+    // Doc preview cache: separate from the code cache above so switching
+    // between doc and source preview modes never serves stale content from
+    // the other mode.
+    preview_doc_cache_path: Option<String>,
+    preview_doc_cache_blocks: Vec<preview_doc::MarkdownBlock>,
+    // When true, always show the raw source preview even if a doc preview
+    // is available (toggled by the "Toggle Source Preview" action).
+    force_source_preview: bool,
     // Current design variant for hot-swappable UI designs
     current_design: DesignVariant,
     // Toast manager for notification queue
@@ -1054,6 +1438,14 @@ struct ScriptListApp {
     clipboard_image_cache: std::collections::HashMap<String, Arc<gpui::RenderImage>>,
     // Frecency store for tracking script usage
     frecency_store: FrecencyStore,
+    // Persisted collapsed/expanded state for main menu section headers
+    collapsed_sections: CollapsedSections,
+    // Persisted sort order for the ungrouped main menu sections
+    list_sort: ListSortPreference,
+    // Full item count per section label, keyed before collapse-filtering.
+    // Lets collapsed headers still show "(N)" for how many items they hide.
+    // Recomputed whenever get_grouped_results_cached() recomputes.
+    section_item_counts: std::collections::HashMap<String, usize>,
     // Mouse hover tracking - independent from selected_index (keyboard focus)
     // hovered_index shows subtle visual feedback, selected_index shows full focus styling
     hovered_index: Option<usize>,
@@ -1091,6 +1483,26 @@ struct ScriptListApp {
     sdk_actions: Option<Vec<protocol::ProtocolAction>>,
     /// SDK action shortcuts: normalized_shortcut -> action_name (for O(1) lookup)
     action_shortcuts: std::collections::HashMap<String, String>,
+    /// When the actions popup was opened from a `Choice`'s own `actions`
+    /// (rather than the arg prompt's script-level `sdk_actions`), the value
+    /// of that choice - so `ActionsRoute::Execute` knows to reply with
+    /// `Message::ChoiceAction` instead of `trigger_action_by_name`.
+    choice_actions_active: Option<String>,
+    /// id of the current arg-family prompt (plain `Message::Arg` or split
+    /// `Message::Split`), used to address `Message::SelectionChange` events
+    /// back to the script and to gate `Message::Preview` caching. `None`
+    /// when no arg-family prompt is open, in which case both are no-ops.
+    split_prompt_id: Option<String>,
+    /// Live preview-pane content for the current split prompt, rendered the
+    /// same way as a `Choice.preview`. Set from `Message::Split`'s own
+    /// `preview` field and refreshed by `Message::SetPreview`.
+    split_preview: Option<String>,
+    /// Per-choice preview content pushed via `Message::Preview`, keyed by
+    /// `Choice.value`. Consulted before falling back to `split_preview` so a
+    /// script can lazily attach documentation-rich previews per choice
+    /// instead of bundling them all into `Choice.preview` up front. Cleared
+    /// whenever a new arg-family prompt is shown - see `split_prompt_id`.
+    preview_content_cache: std::collections::HashMap<String, String>,
     /// Debug grid overlay configuration (None = hidden)
     grid_config: Option<debug_grid::GridConfig>,
     // Navigation coalescing for rapid arrow key events (20ms window)
@@ -1130,6 +1542,22 @@ struct ScriptListApp {
     /// The shortcut recorder entity (persisted to maintain focus)
     shortcut_recorder_entity:
         Option<Entity<crate::components::shortcut_recorder::ShortcutRecorder>>,
+    /// Scriptlet awaiting `{{input}}` values collected via prompts before execution
+    pending_scriptlet_inputs: Option<PendingScriptletInputs>,
+    /// Multi-block scriptlet awaiting the user's choice of which block to run
+    /// (see `execute_scriptlet`'s `extra_blocks` handling)
+    pending_scriptlet_block_choice: Option<scripts::Scriptlet>,
+}
+
+/// Tracks progress collecting a scriptlet's declared `inputs` via prompts,
+/// one at a time, before substituting them into the scriptlet content and running it
+struct PendingScriptletInputs {
+    /// The scriptlet to run once all inputs are collected
+    scriptlet: scripts::Scriptlet,
+    /// Input names still awaiting a value, in declaration order
+    remaining: Vec<String>,
+    /// Values collected so far, keyed by input name
+    collected: std::collections::HashMap<String, String>,
 }
 
 /// Result of alias matching - either a Script or Scriptlet
@@ -1172,28 +1600,13 @@ impl Render for ScriptListApp {
         // This is needed because toast push sites don't have window access
         self.flush_pending_toasts(window, cx);
 
-        // Focus-lost auto-dismiss: Close dismissable prompts when the main window loses focus
-        // This includes focus loss to other app windows like Notes/AI.
-        // When is_pinned is true, the window stays open on blur (only closes via ESC/Cmd+W)
+        // Focus-lost auto-hide: hide the main window when it loses focus to
+        // another app (this includes focus loss to other app windows like
+        // Notes/AI), subject to `window.hideOnBlur` and the prompt-aware
+        // exceptions - see `maybe_hide_on_blur`.
         let is_window_focused = platform::is_main_window_focused();
         if self.was_window_focused && !is_window_focused {
-            // Window just lost focus (user clicked another window)
-            // Only auto-dismiss if we're in a dismissable view AND window is visible AND not pinned
-            if self.is_dismissable_view()
-                && script_kit_gpui::is_main_window_visible()
-                && !self.is_pinned
-            {
-                logging::log(
-                    "FOCUS",
-                    "Main window lost focus while in dismissable view - closing",
-                );
-                self.close_and_reset_window(cx);
-            } else if self.is_pinned {
-                logging::log(
-                    "FOCUS",
-                    "Main window lost focus but is pinned - staying open",
-                );
-            }
+            self.maybe_hide_on_blur(cx);
         }
         self.was_window_focused = is_window_focused;
 
@@ -1230,23 +1643,33 @@ impl Render for ScriptListApp {
             AppView::ArgPrompt {
                 id,
                 placeholder,
+                hint,
                 choices,
                 actions,
+                footer_hints,
             } => self
-                .render_arg_prompt(id, placeholder, choices, actions, cx)
+                .render_arg_prompt(id, placeholder, hint, choices, actions, footer_hints, cx)
+                .into_any_element(),
+            AppView::DivPrompt {
+                id,
+                entity,
+                footer_hints,
+            } => self
+                .render_div_prompt(id, entity, footer_hints, cx)
                 .into_any_element(),
-            AppView::DivPrompt { id, entity } => {
-                self.render_div_prompt(id, entity, cx).into_any_element()
-            }
             AppView::FormPrompt { entity, .. } => {
                 self.render_form_prompt(entity, cx).into_any_element()
             }
             AppView::TermPrompt { entity, .. } => {
                 self.render_term_prompt(entity, cx).into_any_element()
             }
-            AppView::EditorPrompt { entity, .. } => {
-                self.render_editor_prompt(entity, cx).into_any_element()
-            }
+            AppView::EditorPrompt {
+                entity,
+                footer_hints,
+                ..
+            } => self
+                .render_editor_prompt(entity, footer_hints, cx)
+                .into_any_element(),
             AppView::SelectPrompt { entity, .. } => {
                 self.render_select_prompt(entity, cx).into_any_element()
             }
@@ -1262,6 +1685,9 @@ impl Render for ScriptListApp {
             AppView::TemplatePrompt { entity, .. } => {
                 self.render_template_prompt(entity, cx).into_any_element()
             }
+            AppView::ConfirmPrompt { entity, .. } => {
+                self.render_confirm_prompt(entity, cx).into_any_element()
+            }
             // P0 FIX: View state only - data comes from self.cached_clipboard_entries
             AppView::ClipboardHistoryView {
                 filter,
@@ -1289,9 +1715,15 @@ impl Render for ScriptListApp {
             } => self
                 .render_design_gallery(filter, selected_index, cx)
                 .into_any_element(),
-            AppView::ScratchPadView { entity, .. } => {
-                self.render_editor_prompt(entity, cx).into_any_element()
-            }
+            AppView::ScratchPadView { entity, .. } => self
+                .render_editor_prompt(entity, None, cx)
+                .into_any_element(),
+            AppView::RunLogView { entity, .. } => self
+                .render_editor_prompt(entity, None, cx)
+                .into_any_element(),
+            AppView::DiagnosticsView { entity, .. } => self
+                .render_editor_prompt(entity, None, cx)
+                .into_any_element(),
             AppView::QuickTerminalView { entity, .. } => {
                 self.render_term_prompt(entity, cx).into_any_element()
             }
@@ -1301,6 +1733,9 @@ impl Render for ScriptListApp {
             } => self
                 .render_file_search(query, selected_index, cx)
                 .into_any_element(),
+            AppView::RunningScriptsView { selected_index } => self
+                .render_running_scripts(selected_index, cx)
+                .into_any_element(),
         };
 
         // Wrap content in a container that can have the debug grid overlay
@@ -1404,6 +1839,22 @@ include!("render_script_list.rs");
 fn main() {
     logging::init();
 
+    // Headless/test mode: skip the tray icon and global hotkey registration,
+    // and never show or focus the main window - it still gets created (GPUI
+    // requires a window to host the view), but stays invisible for the life
+    // of the process. Driven entirely by ExternalCommand over stdin; after
+    // each dispatched command the current state is printed to stdout in the
+    // same shape as `PromptMessage::GetState`, for integration tests that
+    // have no display. Not passed to scripts - this only gates app startup,
+    // so it doesn't conflict with the "never pass scripts as CLI args" rule.
+    let headless = std::env::args().any(|a| a == "--headless");
+    if headless {
+        logging::log(
+            "APP",
+            "Starting in --headless mode (no tray, no hotkeys, window hidden)",
+        );
+    }
+
     // Migrate from legacy ~/.kenv to new ~/.scriptkit structure (one-time migration)
     // This must happen BEFORE ensure_kit_setup() so the new path is used
     if setup::migrate_from_kenv() {
@@ -1480,6 +1931,10 @@ fn main() {
     // Load config early so we can use it for hotkey registration AND clipboard history settings
     // This avoids duplicate config::load_config() calls (~100-300ms startup savings)
     let loaded_config = config::load_config();
+    // One-time snapshot: config reloads are restart-based (see
+    // `~/.scriptkit/config.ts` in the dev-workflow docs), so this never
+    // needs to run again for the lifetime of the process.
+    logging::configure_from_config(&loaded_config);
     logging::log(
         "APP",
         &format!(
@@ -1490,6 +1945,9 @@ fn main() {
     clipboard_history::set_max_text_content_len(
         loaded_config.get_clipboard_history_max_text_length(),
     );
+    clipboard_history::set_dedupe_mode(loaded_config.get_clipboard_history_dedupe_mode());
+    density::set_density(loaded_config.get_density());
+    fallbacks::builtins::set_fallback_templates(loaded_config.get_fallbacks());
 
     // Initialize clipboard history monitoring (background thread)
     if let Err(e) = clipboard_history::init_clipboard_history() {
@@ -1594,7 +2052,9 @@ fn main() {
         }
     };
 
-    hotkeys::start_hotkey_listener(loaded_config);
+    if !headless {
+        hotkeys::start_hotkey_listener(loaded_config.clone());
+    }
 
     // Start watchers and track which ones succeeded
     // We only spawn poll loops for watchers that successfully started
@@ -1671,6 +2131,11 @@ fn main() {
         #[cfg(target_os = "macos")]
         frontmost_app_tracker::start_tracking();
 
+        // Start sleep/wake tracker - re-checks scheduler catch-up on system wake,
+        // feeding the same code path startup already uses (see synth-2130)
+        #[cfg(target_os = "macos")]
+        sleep_wake_tracker::start_tracking(scheduler.clone());
+
         // Register bundled JetBrains Mono font
         // This makes "JetBrains Mono" available as a font family for the editor
         register_bundled_fonts(cx);
@@ -1690,20 +2155,29 @@ fn main() {
 
         // Initialize tray icon and menu
         // MUST be done after Application::new() creates the NSApplication
-        let tray_manager = match TrayManager::new() {
-            Ok(tm) => {
-                logging::log("TRAY", "Tray icon initialized successfully");
-                Some(tm)
-            }
-            Err(e) => {
-                logging::log("TRAY", &format!("Failed to initialize tray icon: {}", e));
-                None
+        // Skipped in --headless mode - there's no interactive surface for a
+        // tray menu to control.
+        let tray_manager = if headless {
+            None
+        } else {
+            match TrayManager::new() {
+                Ok(tm) => {
+                    logging::log("TRAY", "Tray icon initialized successfully");
+                    Some(tm)
+                }
+                Err(e) => {
+                    logging::log("TRAY", &format!("Failed to initialize tray icon: {}", e));
+                    None
+                }
             }
         };
 
         // Calculate window bounds: try saved position first, then eye-line
         let window_size = size(px(750.), initial_window_height());
-        let default_bounds = calculate_eye_line_bounds_on_mouse_display(window_size);
+        let default_bounds = calculate_eye_line_bounds_on_mouse_display(
+            window_size,
+            loaded_config.get_window_position_mode(),
+        );
         let displays = platform::get_macos_displays();
         let bounds = window_state::get_initial_bounds(
             window_state::WindowRole::Main,
@@ -1712,7 +2186,11 @@ fn main() {
         );
 
         // Load theme to determine window background appearance (vibrancy)
-        let initial_theme = theme::load_theme();
+        let mut initial_theme = theme::load_theme();
+        initial_theme.apply_window_config_overrides(
+            loaded_config.get_window_vibrancy(),
+            loaded_config.get_window_opacity(),
+        );
         let window_background = if initial_theme.is_vibrancy_enabled() {
             WindowBackgroundAppearance::Blurred
         } else {
@@ -1821,10 +2299,17 @@ fn main() {
         cx.spawn(async move |cx: &mut gpui::AsyncApp| {
             logging::log("HOTKEY", "Main hotkey listener started");
             while let Ok(()) = hotkeys::hotkey_channel().1.recv().await {
-                logging::log("VISIBILITY", "");
-                logging::log("VISIBILITY", "╔════════════════════════════════════════════════════════════╗");
-                logging::log("VISIBILITY", "║  HOTKEY TRIGGERED - TOGGLE WINDOW                          ║");
-                logging::log("VISIBILITY", "╚════════════════════════════════════════════════════════════╝");
+                // Collapse a rapid-press burst into a single state change instead
+                // of replaying each queued toggle (which would flap the window).
+                let extra = hotkeys::drain_pending_toggles(&hotkeys::hotkey_channel().1);
+                if extra > 0 {
+                    logging::log(
+                        "HOTKEY",
+                        &format!("Coalesced {} queued toggle(s) from rapid-press burst", extra),
+                    );
+                }
+
+                logging::log_banner("VISIBILITY", "HOTKEY TRIGGERED - TOGGLE WINDOW");
 
                 let is_visible = script_kit_gpui::is_main_window_visible();
                 logging::log("VISIBILITY", &format!("State: WINDOW_VISIBLE={}", is_visible));
@@ -1887,13 +2372,17 @@ fn main() {
         let window_for_scripts = window;
         cx.spawn(async move |cx: &mut gpui::AsyncApp| {
             logging::log("HOTKEY", "Script shortcut listener started (event-driven)");
-            while let Ok(command_id) = hotkeys::script_hotkey_channel().1.recv().await {
+            while let Ok((command_id, args)) = hotkeys::script_hotkey_channel().1.recv().await {
                 logging::log(
                     "HOTKEY",
-                    &format!("Script shortcut received in main.rs: {}", command_id),
+                    &format!(
+                        "Script shortcut received in main.rs: {} (args={:?})",
+                        command_id, args
+                    ),
                 );
 
                 let id_clone = command_id.clone();
+                let args_clone = args.clone();
                 let app_entity_inner = app_entity_for_scripts.clone();
                 let window_inner = window_for_scripts;
 
@@ -1910,7 +2399,7 @@ fn main() {
                             "HOTKEY",
                             "Inside app_entity update, calling execute_by_command_id_or_path",
                         );
-                        view.execute_by_command_id_or_path(&id_clone, ctx)
+                        view.execute_by_command_id_or_path(&id_clone, args_clone, ctx)
                     });
 
                     // Only show window if command needs it AND it's currently hidden
@@ -2060,6 +2549,81 @@ fn main() {
         std::thread::spawn(move || {
             logging::log("APP", "Scheduler event handler started");
 
+            // Spawn a scheduled script headlessly via bun, tracking its process the
+            // same way for both regular and catch-up runs. `label` is prefixed onto
+            // log lines so catch-up runs (see request synth-2130) are distinguishable
+            // from a schedule's normal on-time fire.
+            let run_scheduled_script = |path: PathBuf, label: &'static str| {
+                logging::log("SCHEDULER", &format!("Executing {} scheduled script: {}", label, path.display()));
+
+                // Execute the script using the existing executor infrastructure
+                // This spawns it in the background without blocking the scheduler
+                let path_str = path.to_string_lossy().to_string();
+
+                // Use bun to run the script directly (non-interactive for scheduled scripts)
+                // Find bun path (same logic as executor)
+                let bun_path = std::env::var("BUN_PATH")
+                    .ok()
+                    .or_else(|| {
+                        // Check common locations
+                        for candidate in &[
+                            "/opt/homebrew/bin/bun",
+                            "/usr/local/bin/bun",
+                            std::env::var("HOME").ok().map(|h| format!("{}/.bun/bin/bun", h)).unwrap_or_default().as_str(),
+                        ] {
+                            if std::path::Path::new(candidate).exists() {
+                                return Some(candidate.to_string());
+                            }
+                        }
+                        None
+                    })
+                    .unwrap_or_else(|| "bun".to_string());
+
+                // Spawn bun process to run the script
+                match std::process::Command::new(&bun_path)
+                    .arg("run")
+                    .arg("--preload")
+                    .arg(format!("{}/.scriptkit/sdk/kit-sdk.ts", std::env::var("HOME").unwrap_or_default()))
+                    .arg(&path_str)
+                    .stdout(std::process::Stdio::piped())
+                    .stderr(std::process::Stdio::piped())
+                    .spawn()
+                {
+                    Ok(child) => {
+                        let pid = child.id();
+                        // Track the process
+                        PROCESS_MANAGER.register_process(pid, &path_str);
+                        logging::log("SCHEDULER", &format!("Spawned {} scheduled script PID {}: {}", label, pid, path_str));
+
+                        // Wait for completion in a separate thread to not block scheduler
+                        let path_for_log = path_str.clone();
+                        std::thread::spawn(move || {
+                            match child.wait_with_output() {
+                                Ok(output) => {
+                                    // Unregister the process now that it's done
+                                    PROCESS_MANAGER.unregister_process(pid);
+
+                                    if output.status.success() {
+                                        logging::log("SCHEDULER", &format!("{} scheduled script completed: {}", label, path_for_log));
+                                    } else {
+                                        let stderr = String::from_utf8_lossy(&output.stderr);
+                                        logging::log("SCHEDULER", &format!("{} scheduled script failed: {} - {}", label, path_for_log, stderr));
+                                    }
+                                }
+                                Err(e) => {
+                                    // Unregister on error too
+                                    PROCESS_MANAGER.unregister_process(pid);
+                                    logging::log("SCHEDULER", &format!("{} scheduled script error: {} - {}", label, path_for_log, e));
+                                }
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        logging::log("SCHEDULER", &format!("Failed to spawn {} scheduled script: {} - {}", label, path_str, e));
+                    }
+                }
+            };
+
             loop {
                 // Check shutdown flag - exit loop if shutting down
                 if SHUTDOWN_REQUESTED.load(Ordering::SeqCst) {
@@ -2077,75 +2641,25 @@ fn main() {
                                     logging::log("SCHEDULER", &format!("Skipping scheduled script (shutdown in progress): {}", path.display()));
                                     continue;
                                 }
-
-                                logging::log("SCHEDULER", &format!("Executing scheduled script: {}", path.display()));
-
-                                // Execute the script using the existing executor infrastructure
-                                // This spawns it in the background without blocking the scheduler
+                                run_scheduled_script(path, "scheduled");
+                            }
+                            scheduler::SchedulerEvent::RunScriptCatchUp(path) => {
+                                if SHUTDOWN_REQUESTED.load(Ordering::SeqCst) {
+                                    logging::log("SCHEDULER", &format!("Skipping catch-up script (shutdown in progress): {}", path.display()));
+                                    continue;
+                                }
+                                // Respect the script's concurrency policy: don't pile
+                                // a catch-up run on top of one that's already active.
                                 let path_str = path.to_string_lossy().to_string();
-
-                                // Use bun to run the script directly (non-interactive for scheduled scripts)
-                                // Find bun path (same logic as executor)
-                                let bun_path = std::env::var("BUN_PATH")
-                                    .ok()
-                                    .or_else(|| {
-                                        // Check common locations
-                                        for candidate in &[
-                                            "/opt/homebrew/bin/bun",
-                                            "/usr/local/bin/bun",
-                                            std::env::var("HOME").ok().map(|h| format!("{}/.bun/bin/bun", h)).unwrap_or_default().as_str(),
-                                        ] {
-                                            if std::path::Path::new(candidate).exists() {
-                                                return Some(candidate.to_string());
-                                            }
-                                        }
-                                        None
-                                    })
-                                    .unwrap_or_else(|| "bun".to_string());
-
-                                // Spawn bun process to run the script
-                                match std::process::Command::new(&bun_path)
-                                    .arg("run")
-                                    .arg("--preload")
-                                    .arg(format!("{}/.scriptkit/sdk/kit-sdk.ts", std::env::var("HOME").unwrap_or_default()))
-                                    .arg(&path_str)
-                                    .stdout(std::process::Stdio::piped())
-                                    .stderr(std::process::Stdio::piped())
-                                    .spawn()
-                                {
-                                    Ok(child) => {
-                                        let pid = child.id();
-                                        // Track the process
-                                        PROCESS_MANAGER.register_process(pid, &path_str);
-                                        logging::log("SCHEDULER", &format!("Spawned scheduled script PID {}: {}", pid, path_str));
-
-                                        // Wait for completion in a separate thread to not block scheduler
-                                        let path_for_log = path_str.clone();
-                                        std::thread::spawn(move || {
-                                            match child.wait_with_output() {
-                                                Ok(output) => {
-                                                    // Unregister the process now that it's done
-                                                    PROCESS_MANAGER.unregister_process(pid);
-
-                                                    if output.status.success() {
-                                                        logging::log("SCHEDULER", &format!("Scheduled script completed: {}", path_for_log));
-                                                    } else {
-                                                        let stderr = String::from_utf8_lossy(&output.stderr);
-                                                        logging::log("SCHEDULER", &format!("Scheduled script failed: {} - {}", path_for_log, stderr));
-                                                    }
-                                                }
-                                                Err(e) => {
-                                                    // Unregister on error too
-                                                    PROCESS_MANAGER.unregister_process(pid);
-                                                    logging::log("SCHEDULER", &format!("Scheduled script error: {} - {}", path_for_log, e));
-                                                }
-                                            }
-                                        });
-                                    }
-                                    Err(e) => {
-                                        logging::log("SCHEDULER", &format!("Failed to spawn scheduled script: {} - {}", path_str, e));
-                                    }
+                                let already_running = PROCESS_MANAGER
+                                    .get_active_processes()
+                                    .iter()
+                                    .any(|p| p.script_path == path_str);
+                                if already_running {
+                                    logging::log("SCHEDULER", &format!("Skipping catch-up run, already running: {}", path_str));
+                                    continue;
                                 }
+                                run_scheduled_script(path, "catch-up");
                             }
                             scheduler::SchedulerEvent::Error(msg) => {
                                 logging::log("SCHEDULER", &format!("Scheduler error: {}", msg));
@@ -2191,7 +2705,7 @@ fn main() {
                                             // Find and run the script interactively
                                             if let Some(script) = view.scripts.iter().find(|s| s.name == script_name_owned || s.path.to_string_lossy().contains(&script_name_owned)).cloned() {
                                                 logging::log("TEST", &format!("Found script: {}", script.name));
-                                                view.execute_interactive(&script, ctx);
+                                                view.execute_interactive(&script, Vec::new(), None, ctx);
                                             } else {
                                                 logging::log("TEST", &format!("Script not found: {}", script_name_owned));
                                             }
@@ -2205,8 +2719,19 @@ fn main() {
             }).detach();
         }
 
-        // External command listener - receives commands via stdin (event-driven, no polling)
-        let stdin_rx = start_stdin_listener();
+        // External command listener - receives commands via stdin (event-driven, no polling).
+        // The scriptkit:// URL scheme handler (below) feeds this same channel,
+        // so deep links and stdin JSONL commands share the one dispatch loop.
+        let (external_cmd_tx, stdin_rx) = stdin_commands::external_command_channel();
+        stdin_commands::spawn_stdin_reader(external_cmd_tx.clone());
+
+        #[cfg(target_os = "macos")]
+        url_scheme::register_url_scheme_handler(
+            &url_scheme::macos::AppleEventUrlSource,
+            external_cmd_tx,
+            resolve_script_by_name_or_alias,
+        );
+
         let window_for_stdin = window;
         let app_entity_for_stdin = app_entity.clone();
 
@@ -2240,6 +2765,10 @@ fn main() {
                 STDIN_RECEIVED.store(true, std::sync::atomic::Ordering::SeqCst);
                 logging::log("STDIN", &format!("Processing external command: {:?}", cmd));
 
+                // Captured up front since most match arms below consume `cmd`'s
+                // fields by value - only used by the headless state feed further down.
+                let cmd_request_id = cmd.request_id().map(|s| s.to_string());
+
                 let app_entity_inner = app_entity_for_stdin.clone();
                 let _ = cx.update(|cx| {
                     // Use the Root window to get Window reference, then update the app entity
@@ -2248,37 +2777,54 @@ fn main() {
                             // Note: We have both `window` from Root and `view` from entity here
                             // ctx is Context<ScriptListApp>, window is &mut Window
                         match cmd {
-                            ExternalCommand::Run { ref path, ref request_id } => {
+                            ExternalCommand::Run { ref path, ref args, ref request_id } => {
                                 let rid = request_id.as_deref().unwrap_or("-");
                                 logging::log("STDIN", &format!("[{}] Executing script: {}", rid, path));
 
                                 // NOTE: This is a simplified show path for script execution.
                                 // We show the window, then immediately run the script.
                                 // The core logic matches show_main_window_helper().
+                                //
+                                // In --headless mode we skip the activate/focus/panel steps
+                                // entirely so no GUI surface is ever shown - only the state
+                                // tracked by `set_main_window_visible` changes, which is what
+                                // the GetState-shaped stdout feed below reports on.
 
                                 script_kit_gpui::set_main_window_visible(true);
-                                platform::ensure_move_to_active_space();
-
-                                // Use Window::defer via window_ops to coalesce and defer window move.
-                                // This avoids RefCell borrow conflicts from synchronous macOS window operations.
-                                let window_size = gpui::size(px(750.), initial_window_height());
-                                let bounds = platform::calculate_eye_line_bounds_on_mouse_display(window_size);
-                                window_ops::queue_move(bounds, window, ctx);
-
-                                if !PANEL_CONFIGURED.load(std::sync::atomic::Ordering::SeqCst) {
-                                    platform::configure_as_floating_panel();
-                                    platform::swizzle_gpui_blurred_view();
-                                    platform::configure_window_vibrancy_material();
-                                    PANEL_CONFIGURED.store(true, std::sync::atomic::Ordering::SeqCst);
-                                }
 
-                                ctx.activate(true);
-                                window.activate_window();
-                                let focus_handle = view.focus_handle(ctx);
-                                window.focus(&focus_handle, ctx);
+                                if !headless {
+                                    platform::ensure_move_to_active_space();
+
+                                    // Use Window::defer via window_ops to coalesce and defer window move.
+                                    // This avoids RefCell borrow conflicts from synchronous macOS window operations.
+                                    let window_size = gpui::size(px(750.), initial_window_height());
+                                    let bounds = platform::calculate_eye_line_bounds_on_mouse_display(
+                                        window_size,
+                                        view.config.get_window_position_mode(),
+                                    );
+                                    window_ops::queue_move(bounds, window, ctx);
+
+                                    if !PANEL_CONFIGURED.load(std::sync::atomic::Ordering::SeqCst) {
+                                        platform::configure_as_floating_panel();
+                                        platform::swizzle_gpui_blurred_view();
+                                        platform::configure_window_vibrancy_material();
+                                        PANEL_CONFIGURED.store(true, std::sync::atomic::Ordering::SeqCst);
+                                    }
+
+                                    ctx.activate(true);
+                                    window.activate_window();
+                                    let focus_handle = view.focus_handle(ctx);
+                                    window.focus(&focus_handle, ctx);
+                                }
 
                                 // Send RunScript message to be handled
-                                view.handle_prompt_message(PromptMessage::RunScript { path: path.clone() }, ctx);
+                                view.handle_prompt_message(
+                                    PromptMessage::RunScript {
+                                        path: path.clone(),
+                                        args: args.clone(),
+                                    },
+                                    ctx,
+                                );
                             }
                             ExternalCommand::Show { ref request_id } => {
                                 let rid = request_id.as_deref().unwrap_or("-");
@@ -2288,27 +2834,36 @@ fn main() {
                                 // Unlike the hotkey handler, we don't need NEEDS_RESET handling
                                 // because this is an explicit show (not a toggle).
                                 // The core logic matches show_main_window_helper().
+                                //
+                                // In --headless mode we skip the activate/focus/panel steps
+                                // entirely - see the matching note in the Run arm above.
 
                                 script_kit_gpui::set_main_window_visible(true);
-                                platform::ensure_move_to_active_space();
-
-                                // Use Window::defer via window_ops to coalesce and defer window move.
-                                // This avoids RefCell borrow conflicts from synchronous macOS window operations.
-                                let window_size = gpui::size(px(750.), initial_window_height());
-                                let bounds = platform::calculate_eye_line_bounds_on_mouse_display(window_size);
-                                window_ops::queue_move(bounds, window, ctx);
-
-                                if !PANEL_CONFIGURED.load(std::sync::atomic::Ordering::SeqCst) {
-                                    platform::configure_as_floating_panel();
-                                    platform::swizzle_gpui_blurred_view();
-                                    platform::configure_window_vibrancy_material();
-                                    PANEL_CONFIGURED.store(true, std::sync::atomic::Ordering::SeqCst);
-                                }
 
-                                ctx.activate(true);
-                                window.activate_window();
-                                let focus_handle = view.focus_handle(ctx);
-                                window.focus(&focus_handle, ctx);
+                                if !headless {
+                                    platform::ensure_move_to_active_space();
+
+                                    // Use Window::defer via window_ops to coalesce and defer window move.
+                                    // This avoids RefCell borrow conflicts from synchronous macOS window operations.
+                                    let window_size = gpui::size(px(750.), initial_window_height());
+                                    let bounds = platform::calculate_eye_line_bounds_on_mouse_display(
+                                        window_size,
+                                        view.config.get_window_position_mode(),
+                                    );
+                                    window_ops::queue_move(bounds, window, ctx);
+
+                                    if !PANEL_CONFIGURED.load(std::sync::atomic::Ordering::SeqCst) {
+                                        platform::configure_as_floating_panel();
+                                        platform::swizzle_gpui_blurred_view();
+                                        platform::configure_window_vibrancy_material();
+                                        PANEL_CONFIGURED.store(true, std::sync::atomic::Ordering::SeqCst);
+                                    }
+
+                                    ctx.activate(true);
+                                    window.activate_window();
+                                    let focus_handle = view.focus_handle(ctx);
+                                    window.focus(&focus_handle, ctx);
+                                }
                             }
                             ExternalCommand::Hide { ref request_id } => {
                                 let rid = request_id.as_deref().unwrap_or("-");
@@ -2389,6 +2944,9 @@ fn main() {
                                         if has_cmd && key_lower == "k" {
                                             logging::log("STDIN", "SimulateKey: Cmd+K - toggle actions");
                                             view.toggle_actions(ctx, window);
+                                        } else if has_cmd && has_shift && key_lower == "d" {
+                                            logging::log("STDIN", "SimulateKey: Cmd+Shift+D - toggle density");
+                                            view.toggle_density(ctx);
                                         } else if view.fallback_mode && !view.cached_fallbacks.is_empty() {
                                             // Handle keys in fallback mode
                                             match key_lower.as_str() {
@@ -2634,6 +3192,41 @@ fn main() {
                                     }
                                 }
                             }
+                            ExternalCommand::TypeText { ref text } => {
+                                logging::log("STDIN", &format!("Typing text: '{}'", text));
+
+                                // Feed one character at a time through the same
+                                // per-character handling a real keystroke uses, so
+                                // behavior (filtering, selection resync, resize) matches
+                                // typing exactly rather than setting the value in bulk.
+                                match &view.current_view {
+                                    AppView::ScriptList => {
+                                        for ch in text.chars() {
+                                            let mut next = view.filter_text.clone();
+                                            next.push(ch);
+                                            view.set_filter_text_immediate(next, window, ctx);
+                                        }
+                                    }
+                                    AppView::ArgPrompt { .. } if !view.show_actions_popup => {
+                                        for ch in text.chars() {
+                                            let ch_str = ch.to_string();
+                                            view.handle_arg_text_key(
+                                                &ch_str.to_lowercase(),
+                                                Some(&ch_str),
+                                                false,
+                                                false,
+                                                false,
+                                                window,
+                                                ctx,
+                                            );
+                                        }
+                                        ctx.notify();
+                                    }
+                                    _ => {
+                                        logging::log("STDIN", &format!("TypeText: View {:?} not supported for text typing", std::mem::discriminant(&view.current_view)));
+                                    }
+                                }
+                            }
                             ExternalCommand::OpenNotes => {
                                 logging::log("STDIN", "Opening notes window via stdin command");
                                 if let Err(e) = notes::open_notes_window(ctx) {
@@ -2711,8 +3304,88 @@ fn main() {
                                 logging::log("STDIN", &format!("ShowShortcutRecorder: command_id='{}', command_name='{}'", command_id, command_name));
                                 view.show_shortcut_recorder(command_id.clone(), command_name.clone(), ctx);
                             }
+                            ExternalCommand::ListScripts { ref request_id } => {
+                                let rid = request_id.clone().unwrap_or_else(|| "-".to_string());
+                                logging::log("STDIN", &format!("[{}] Listing scripts/scriptlets/builtins", rid));
+
+                                let mut entries: Vec<protocol::AvailableEntryInfo> = Vec::new();
+                                entries.extend(view.scripts.iter().map(|script| protocol::AvailableEntryInfo {
+                                    kind: protocol::AvailableEntryKind::Script,
+                                    name: script.name.clone(),
+                                    path: Some(script.path.display().to_string()),
+                                    alias: script.alias.clone(),
+                                    shortcut: script.shortcut.clone(),
+                                    description: script.description.clone(),
+                                }));
+                                entries.extend(view.scriptlets.iter().map(|scriptlet| protocol::AvailableEntryInfo {
+                                    kind: protocol::AvailableEntryKind::Scriptlet,
+                                    name: scriptlet.name.clone(),
+                                    path: scriptlet.file_path.clone(),
+                                    alias: scriptlet.alias.clone(),
+                                    shortcut: scriptlet.shortcut.clone(),
+                                    description: scriptlet.description.clone(),
+                                }));
+                                entries.extend(view.builtin_entries.iter().map(|entry| protocol::AvailableEntryInfo {
+                                    kind: protocol::AvailableEntryKind::Builtin,
+                                    name: entry.name.clone(),
+                                    path: None,
+                                    alias: None,
+                                    shortcut: None,
+                                    description: Some(entry.description.clone()),
+                                }));
+
+                                let message = Message::scripts_list_result(rid, entries);
+                                match protocol::serialize_message(&message) {
+                                    Ok(json) => println!("{}", json),
+                                    Err(e) => logging::log("STDIN", &format!("Failed to serialize scripts list: {}", e)),
+                                }
+                            }
+                            ExternalCommand::ShowErrorToast { ref message } => {
+                                logging::log("STDIN", &format!("Showing error toast: {}", message));
+                                view.toast_manager.push(
+                                    Toast::error(message.clone(), &view.theme)
+                                        .duration_ms(Some(5000)),
+                                );
+                            }
                         }
                         ctx.notify();
+
+                        // Headless mode has no window to observe, so after every
+                        // dispatched command we emit the current prompt state to
+                        // our own stdout - same shape as PromptMessage::GetState,
+                        // so a CI harness can assert on it without a display.
+                        if headless {
+                            let request_id = cmd_request_id.clone().unwrap_or_else(|| "headless".to_string());
+                            let (
+                                prompt_type,
+                                prompt_id,
+                                placeholder,
+                                input_value,
+                                choice_count,
+                                visible_choice_count,
+                                selected_index,
+                                selected_value,
+                                is_focused,
+                                window_visible,
+                            ) = view.compute_state_fields();
+                            let state = Message::state_result(
+                                request_id,
+                                prompt_type,
+                                prompt_id,
+                                placeholder,
+                                input_value,
+                                choice_count,
+                                visible_choice_count,
+                                selected_index,
+                                selected_value,
+                                is_focused,
+                                window_visible,
+                            );
+                            match protocol::serialize_message(&state) {
+                                Ok(json) => println!("{}", json),
+                                Err(e) => logging::log("STDIN", &format!("Failed to serialize headless state: {}", e)),
+                            }
+                        }
                         }); // close app_entity_inner.update
                     }); // close window_for_stdin.update
                 }); // close cx.update
@@ -2725,6 +3398,7 @@ fn main() {
         // Clone config for use in tray handler
         let config_for_tray = config::load_config();
         if let Some(tray_mgr) = tray_manager {
+            let mut tray_mgr = tray_mgr;
             let window_for_tray = window;
             let app_entity_for_tray = app_entity.clone();
             cx.spawn(async move |cx: &mut gpui::AsyncApp| {
@@ -2734,17 +3408,54 @@ fn main() {
                     // Poll for tray menu events every 100ms
                     Timer::after(std::time::Duration::from_millis(100)).await;
 
+                    // Pick up the latest tray-tagged scripts (published whenever
+                    // ScriptListApp loads or reloads scripts) and rebuild the
+                    // "Scripts" submenu from them.
+                    if let Ok(entries) = tray::tray_script_refresh_channel().1.try_recv() {
+                        if let Err(e) = tray_mgr.rebuild_script_menu(&entries) {
+                            logging::log("TRAY", &format!("Failed to rebuild Scripts submenu: {}", e));
+                        }
+                    }
+
                     // Check for menu events
                     if let Ok(event) = tray_mgr.menu_event_receiver().try_recv() {
+                        // Dynamic script items aren't part of the fixed TrayMenuAction
+                        // enum - their IDs are generated per-path at runtime.
+                        if let Some(script_path) = event.id.0.strip_prefix(TRAY_SCRIPT_ID_PREFIX) {
+                            logging::log("TRAY", &format!("Script tray item clicked: {}", script_path));
+                            if hotkeys::script_hotkey_channel()
+                                .0
+                                .try_send((script_path.to_string(), Vec::new()))
+                                .is_err()
+                            {
+                                logging::log("TRAY", "Failed to send script path on script_hotkey_channel (full?)");
+                            }
+                            continue;
+                        }
+                        if event.id.0 == TRAY_SCRIPT_MORE_ID {
+                            logging::log("TRAY", "Scripts \"More…\" item clicked");
+                            let window_inner = window_for_tray;
+                            let app_entity_inner = app_entity_for_tray.clone();
+                            let _ = cx.update(|cx| {
+                                show_main_window_helper(window_inner, app_entity_inner, cx);
+                            });
+                            continue;
+                        }
+
                         // Convert event to action using type-safe IDs (pure function)
                         let action = TrayManager::action_from_event(&event);
 
-                        // Handle side effects for LaunchAtLogin before the match
+                        // Handle side effects for LaunchAtLogin/TogglePause before the match
                         if let Some(TrayMenuAction::LaunchAtLogin) = action {
                             if let Err(e) = tray_mgr.handle_action(TrayMenuAction::LaunchAtLogin) {
                                 logging::log("TRAY", &format!("Failed to toggle login item: {}", e));
                             }
                         }
+                        if let Some(TrayMenuAction::TogglePause) = action {
+                            if let Err(e) = tray_mgr.handle_action(TrayMenuAction::TogglePause) {
+                                logging::log("TRAY", &format!("Failed to toggle pause state: {}", e));
+                            }
+                        }
 
                         match action {
                             Some(TrayMenuAction::OpenScriptKit) => {
@@ -2781,6 +3492,13 @@ fn main() {
                                 // Side effects (toggle + checkbox update) handled above
                                 logging::log("TRAY", "Launch at Login toggled");
                             }
+                            Some(TrayMenuAction::TogglePause) => {
+                                // Side effects (flag + checkbox + icon) handled above
+                                logging::log(
+                                    "TRAY",
+                                    &format!("Hotkeys/expansion paused: {}", is_paused()),
+                                );
+                            }
                             Some(TrayMenuAction::Settings) => {
                                 logging::log("TRAY", "Settings menu item clicked");
                                 // Open config file in editor
@@ -2826,15 +3544,11 @@ fn main() {
                             }
                             Some(TrayMenuAction::Quit) => {
                                 logging::log("TRAY", "Quit menu item clicked");
-                                // Set shutdown flag FIRST - prevents new script spawns
-                                // and triggers the shutdown monitor task for unified cleanup
-                                SHUTDOWN_REQUESTED.store(true, Ordering::SeqCst);
-
-                                // Clean up processes and PID file before quitting
-                                PROCESS_MANAGER.kill_all_processes();
-                                PROCESS_MANAGER.remove_main_pid();
+                                let app_entity_inner = app_entity_for_tray.clone();
                                 let _ = cx.update(|cx| {
-                                    cx.quit();
+                                    app_entity_inner.update(cx, |view, ctx| {
+                                        view.begin_graceful_shutdown(ctx);
+                                    });
                                 });
                                 break; // Exit the polling loop
                             }
@@ -2849,9 +3563,11 @@ fn main() {
             }).detach();
         }
 
-        // Shutdown monitor task - checks SHUTDOWN_REQUESTED flag set by signal handler
-        // Performs all cleanup on the main thread where it's safe to call logging,
-        // mutexes, and other non-async-signal-safe functions.
+        // Shutdown monitor task - checks SHUTDOWN_REQUESTED flag set by the
+        // SIGINT/SIGTERM/SIGHUP handler. Performs all cleanup on the main
+        // thread where it's safe to call logging, mutexes, and other
+        // non-async-signal-safe functions.
+        let app_entity_for_shutdown = app_entity.clone();
         cx.spawn(async move |cx: &mut gpui::AsyncApp| {
             loop {
                 // Check every 100ms for shutdown signal
@@ -2860,18 +3576,11 @@ fn main() {
                 if SHUTDOWN_REQUESTED.load(Ordering::SeqCst) {
                     logging::log("SHUTDOWN", "Shutdown signal detected, performing graceful cleanup");
 
-                    // Kill all tracked child processes
-                    logging::log("SHUTDOWN", "Killing all child processes");
-                    PROCESS_MANAGER.kill_all_processes();
-
-                    // Remove main PID file
-                    PROCESS_MANAGER.remove_main_pid();
-
-                    logging::log("SHUTDOWN", "Cleanup complete, quitting application");
-
-                    // Quit the GPUI application
+                    let app_entity_inner = app_entity_for_shutdown.clone();
                     let _ = cx.update(|cx| {
-                        cx.quit();
+                        app_entity_inner.update(cx, |view, ctx| {
+                            view.begin_graceful_shutdown(ctx);
+                        });
                     });
 
                     break;
@@ -2904,4 +3613,345 @@ mod tests {
             "main visibility should mirror library visibility"
         );
     }
+
+    use super::{push_prompt_history, PromptMessage};
+
+    /// One minimal instance of every navigable `Show*` variant, tagged with
+    /// its id, so stack bookkeeping can be exercised across all prompt types.
+    fn navigable_prompts() -> Vec<PromptMessage> {
+        vec![
+            PromptMessage::ShowArg {
+                id: "arg".to_string(),
+                placeholder: String::new(),
+                choices: Vec::new(),
+                actions: None,
+                timeout_ms: None,
+                choices_cmd: None,
+                footer_hints: None,
+            },
+            PromptMessage::ShowDiv {
+                id: "div".to_string(),
+                html: String::new(),
+                container_classes: None,
+                actions: None,
+                placeholder: None,
+                hint: None,
+                footer: None,
+                container_bg: None,
+                container_padding: None,
+                opacity: None,
+                footer_hints: None,
+            },
+            PromptMessage::ShowForm {
+                id: "form".to_string(),
+                html: String::new(),
+                actions: None,
+            },
+            PromptMessage::ShowTerm {
+                id: "term".to_string(),
+                command: None,
+                shell: None,
+                cwd: None,
+                login: None,
+                actions: None,
+            },
+            PromptMessage::ShowEditor {
+                id: "editor".to_string(),
+                content: None,
+                language: None,
+                template: None,
+                actions: None,
+                footer_hints: None,
+            },
+            PromptMessage::ShowPath {
+                id: "path".to_string(),
+                start_path: None,
+                hint: None,
+            },
+            PromptMessage::ShowEnv {
+                id: "env".to_string(),
+                key: String::new(),
+                prompt: None,
+                secret: false,
+                pattern: None,
+                multiline: false,
+            },
+            PromptMessage::ShowDrop {
+                id: "drop".to_string(),
+                placeholder: None,
+                hint: None,
+            },
+            PromptMessage::ShowTemplate {
+                id: "template".to_string(),
+                template: String::new(),
+            },
+            PromptMessage::ShowConfirm {
+                id: "confirm".to_string(),
+                title: None,
+                message: String::new(),
+                ok_label: None,
+                cancel_label: None,
+                destructive: false,
+            },
+            PromptMessage::ShowSelect {
+                id: "select".to_string(),
+                placeholder: None,
+                choices: Vec::new(),
+                multiple: false,
+                max: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn navigable_prompts_expose_their_id() {
+        for prompt in navigable_prompts() {
+            assert!(
+                prompt.is_navigable_prompt(),
+                "expected {:?} to be navigable",
+                prompt
+            );
+            assert!(prompt.id().is_some());
+        }
+    }
+
+    #[test]
+    fn non_navigable_messages_are_not_tracked() {
+        assert!(!PromptMessage::ScriptExit { value: None }.is_navigable_prompt());
+        assert!(PromptMessage::ScriptExit { value: None }.id().is_none());
+
+        let hud = PromptMessage::ShowHud {
+            text: "hi".to_string(),
+            duration_ms: None,
+            position: None,
+            id: None,
+        };
+        assert!(!hud.is_navigable_prompt());
+    }
+
+    #[test]
+    fn script_exit_value_round_trips_through_json() {
+        let json = r#"{"type":"exit","code":0,"value":{"result":42}}"#;
+        let msg: crate::protocol::Message = serde_json::from_str(json).expect("should parse");
+        match msg {
+            crate::protocol::Message::Exit { code, value, .. } => {
+                assert_eq!(code, Some(0));
+                assert_eq!(value, Some(serde_json::json!({"result": 42})));
+            }
+            other => panic!("expected Exit, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn script_exit_without_value_parses_as_none() {
+        let json = r#"{"type":"exit","code":0}"#;
+        let msg: crate::protocol::Message = serde_json::from_str(json).expect("should parse");
+        match msg {
+            crate::protocol::Message::Exit { value, .. } => assert_eq!(value, None),
+            other => panic!("expected Exit, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn push_prompt_history_builds_stack_across_all_prompt_types() {
+        let mut current = None;
+        let mut stack = Vec::new();
+
+        let prompts = navigable_prompts();
+        for prompt in &prompts {
+            push_prompt_history(&mut current, &mut stack, prompt.clone(), "");
+        }
+
+        // Every prompt but the last ends up on the stack, in show order.
+        assert_eq!(stack.len(), prompts.len() - 1);
+        for (entry, prompt) in stack.iter().zip(prompts.iter()) {
+            assert_eq!(entry.message.id(), prompt.id());
+        }
+        // The most recently shown prompt is the current one, not on the stack.
+        assert_eq!(current.as_ref().unwrap().id(), prompts.last().unwrap().id());
+    }
+
+    #[test]
+    fn push_prompt_history_ignores_non_navigable_messages() {
+        let mut current = Some(PromptMessage::ShowArg {
+            id: "arg".to_string(),
+            placeholder: String::new(),
+            choices: Vec::new(),
+            actions: None,
+            timeout_ms: None,
+            choices_cmd: None,
+            footer_hints: None,
+        });
+        let mut stack = Vec::new();
+
+        push_prompt_history(
+            &mut current,
+            &mut stack,
+            PromptMessage::ShowHud {
+                text: "hi".to_string(),
+                duration_ms: None,
+                position: None,
+                id: None,
+            },
+            "",
+        );
+
+        assert!(stack.is_empty(), "a HUD overlay shouldn't join the stack");
+        assert_eq!(current.unwrap().id(), Some("arg"));
+    }
+
+    #[test]
+    fn push_prompt_history_snapshots_arg_input_only() {
+        let mut current = Some(PromptMessage::ShowArg {
+            id: "arg".to_string(),
+            placeholder: String::new(),
+            choices: Vec::new(),
+            actions: None,
+            timeout_ms: None,
+            choices_cmd: None,
+            footer_hints: None,
+        });
+        let mut stack = Vec::new();
+
+        // Leaving an arg prompt captures whatever the user had typed.
+        push_prompt_history(
+            &mut current,
+            &mut stack,
+            PromptMessage::ShowDiv {
+                id: "div".to_string(),
+                html: String::new(),
+                container_classes: None,
+                actions: None,
+                placeholder: None,
+                hint: None,
+                footer: None,
+                container_bg: None,
+                container_padding: None,
+                opacity: None,
+                footer_hints: None,
+            },
+            "partial answer",
+        );
+
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack[0].input_snapshot.as_deref(), Some("partial answer"));
+
+        // Leaving a div prompt doesn't capture anything - it has no text input.
+        push_prompt_history(
+            &mut current,
+            &mut stack,
+            PromptMessage::ShowForm {
+                id: "form".to_string(),
+                html: String::new(),
+                actions: None,
+            },
+            "ignored",
+        );
+
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack[1].input_snapshot, None);
+    }
+
+    use super::next_queued_arg;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn next_queued_arg_consumes_in_fifo_order() {
+        let mut queue: VecDeque<String> = vec!["first".to_string(), "second".to_string()]
+            .into_iter()
+            .collect();
+
+        assert_eq!(next_queued_arg(&mut queue), Some("first".to_string()));
+        assert_eq!(next_queued_arg(&mut queue), Some("second".to_string()));
+        assert_eq!(next_queued_arg(&mut queue), None);
+    }
+
+    #[test]
+    fn next_queued_arg_passes_through_empty_string_not_skipped() {
+        let mut queue: VecDeque<String> = vec!["".to_string(), "after".to_string()]
+            .into_iter()
+            .collect();
+
+        assert_eq!(next_queued_arg(&mut queue), Some(String::new()));
+        assert_eq!(next_queued_arg(&mut queue), Some("after".to_string()));
+    }
+}
+
+#[cfg(test)]
+mod writer_batching_tests {
+    use super::{drain_response_batch, mpsc, serialize_response_batch, Message};
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct CountingPipe {
+        write_calls: Arc<Mutex<usize>>,
+    }
+
+    impl Write for CountingPipe {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            *self.write_calls.lock().unwrap() += 1;
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_burst_of_100_messages_becomes_a_single_write_call() {
+        let (tx, rx) = mpsc::sync_channel::<Message>(100);
+        for i in 0..100 {
+            tx.send(Message::SetHint {
+                text: format!("hint-{i}"),
+            })
+            .unwrap();
+        }
+
+        let first = rx.recv().unwrap();
+        let batch = drain_response_batch(first, &rx);
+        assert_eq!(batch.len(), 100);
+
+        let buffer = serialize_response_batch(&batch, -1);
+        assert_eq!(buffer.lines().count(), 100);
+
+        let mut pipe = CountingPipe::default();
+        pipe.write_all(buffer.as_bytes()).unwrap();
+        assert_eq!(*pipe.write_calls.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_single_message_drains_without_waiting_for_more() {
+        let (tx, rx) = mpsc::sync_channel::<Message>(100);
+        tx.send(Message::SetHint {
+            text: "only".to_string(),
+        })
+        .unwrap();
+
+        let first = rx.recv().unwrap();
+        let batch = drain_response_batch(first, &rx);
+        assert_eq!(batch.len(), 1);
+    }
+
+    #[test]
+    fn test_flush_ends_its_batch_leaving_later_messages_for_the_next_one() {
+        let (tx, rx) = mpsc::sync_channel::<Message>(100);
+        tx.send(Message::SetHint {
+            text: "before".to_string(),
+        })
+        .unwrap();
+        tx.send(Message::Flush {}).unwrap();
+        tx.send(Message::SetHint {
+            text: "after".to_string(),
+        })
+        .unwrap();
+
+        let first = rx.recv().unwrap();
+        let batch = drain_response_batch(first, &rx);
+        assert_eq!(batch.len(), 2);
+        assert!(matches!(batch[1], Message::Flush {}));
+
+        let next = rx.recv().unwrap();
+        assert!(matches!(next, Message::SetHint { text } if text == "after"));
+    }
 }
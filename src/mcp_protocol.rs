@@ -1060,6 +1060,12 @@ mod tests {
                 shortcut: None,
                 typed_metadata: None,
                 schema: Some(schema),
+                concurrency: Default::default(),
+                tray: false,
+                background: false,
+                keep_open: false,
+                kenv: None,
+                app_filter: None,
             }
         }
 
@@ -1242,6 +1248,12 @@ mod tests {
                 shortcut: None,
                 typed_metadata: None,
                 schema: None, // No schema!
+                concurrency: Default::default(),
+                tray: false,
+                background: false,
+                keep_open: false,
+                kenv: None,
+                app_filter: None,
             };
 
             let scripts = wrap_scripts(vec![
@@ -1300,6 +1312,12 @@ mod tests {
                 shortcut: None,
                 typed_metadata: None,
                 schema: None,
+                concurrency: Default::default(),
+                tray: false,
+                background: false,
+                keep_open: false,
+                kenv: None,
+                app_filter: None,
             }
         }
 
@@ -1316,6 +1334,10 @@ mod tests {
                 file_path: None,
                 command: None,
                 alias: None,
+                inputs: Vec::new(),
+                schema: None,
+                extra_blocks: Vec::new(),
+                sequence: false,
             }
         }
 
@@ -273,6 +273,12 @@ mod tests {
             shortcut: None,
             typed_metadata: None,
             schema: None,
+            concurrency: Default::default(),
+            tray: false,
+            background: false,
+            keep_open: false,
+            kenv: None,
+            app_filter: None,
         }
     }
 
@@ -289,6 +295,10 @@ mod tests {
             file_path: None,
             command: None,
             alias: None,
+            inputs: Vec::new(),
+            schema: None,
+            extra_blocks: Vec::new(),
+            sequence: false,
         }
     }
 
@@ -524,6 +534,12 @@ mod tests {
                 input,
                 output: HashMap::new(),
             }),
+            concurrency: Default::default(),
+            tray: false,
+            background: false,
+            keep_open: false,
+            kenv: None,
+            app_filter: None,
         };
 
         let entry: ScriptResourceEntry = (&script_with_schema).into();
@@ -543,6 +559,10 @@ mod tests {
             file_path: None,
             command: None,
             alias: None,
+            inputs: Vec::new(),
+            schema: None,
+            extra_blocks: Vec::new(),
+            sequence: false,
         };
 
         let entry: ScriptletResourceEntry = (&scriptlet).into();
@@ -218,6 +218,12 @@ mod tests {
             shortcut: None,
             typed_metadata: None,
             schema: Some(schema),
+            concurrency: Default::default(),
+            tray: false,
+            background: false,
+            keep_open: false,
+            kenv: None,
+            app_filter: None,
         }
     }
 
@@ -251,6 +257,12 @@ mod tests {
             shortcut: None,
             typed_metadata: None,
             schema: None,
+            concurrency: Default::default(),
+            tray: false,
+            background: false,
+            keep_open: false,
+            kenv: None,
+            app_filter: None,
         }
     }
 
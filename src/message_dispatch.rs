@@ -0,0 +1,320 @@
+//! Dispatch table for protocol request messages the reader thread answers
+//! directly, without involving the UI thread.
+//!
+//! `execute_script.rs`'s reader loop used to inline each of these as a
+//! `if let Message::X { .. } = &msg { ... }` block, growing to roughly a
+//! thousand lines of unrelated logic that couldn't be unit tested without a
+//! live clipboard/window/filesystem. This module pulls that logic out
+//! behind small traits (one per external dependency) so each handler is a
+//! pure function of "message in, `Message` response out" and can be tested
+//! with fakes.
+//!
+//! Only [`ClipboardHistory`](protocol::Message::ClipboardHistory) has been
+//! migrated so far; `Clipboard`, `WindowList`, `WindowAction`, `FileSearch`
+//! and `GetWindowBounds` still live inline in `execute_script.rs` and are
+//! good candidates for the same treatment in a follow-up.
+
+use crate::clipboard_history;
+use crate::protocol::{self, Message};
+
+/// Clipboard history operations needed by [`dispatch_clipboard_history`],
+/// abstracted so tests can substitute an in-memory fake instead of the real
+/// SQLite-backed store in [`clipboard_history`].
+pub trait ClipboardHistorySource {
+    fn list(&self, limit: usize) -> Vec<clipboard_history::ClipboardEntry>;
+    fn pin(&self, id: &str) -> anyhow::Result<()>;
+    fn unpin(&self, id: &str) -> anyhow::Result<()>;
+    fn remove(&self, id: &str) -> anyhow::Result<()>;
+    fn clear(&self) -> anyhow::Result<()>;
+    fn trim_oversize(&self) -> anyhow::Result<usize>;
+}
+
+/// [`ClipboardHistorySource`] backed by the real clipboard history database.
+pub struct LiveClipboardHistory;
+
+impl ClipboardHistorySource for LiveClipboardHistory {
+    fn list(&self, limit: usize) -> Vec<clipboard_history::ClipboardEntry> {
+        clipboard_history::get_clipboard_history(limit)
+    }
+
+    fn pin(&self, id: &str) -> anyhow::Result<()> {
+        clipboard_history::pin_entry(id)
+    }
+
+    fn unpin(&self, id: &str) -> anyhow::Result<()> {
+        clipboard_history::unpin_entry(id)
+    }
+
+    fn remove(&self, id: &str) -> anyhow::Result<()> {
+        clipboard_history::remove_entry(id)
+    }
+
+    fn clear(&self) -> anyhow::Result<()> {
+        clipboard_history::clear_history()
+    }
+
+    fn trim_oversize(&self) -> anyhow::Result<usize> {
+        clipboard_history::trim_oversize_text_entries()
+    }
+}
+
+/// Handle a `ClipboardHistory` request and produce the `Message` to send
+/// back over the protocol. Mirrors the behavior of the inline handler this
+/// replaced byte-for-byte: List truncates text content over 1000 bytes and
+/// replaces image content with a `[image:<id>]` placeholder; the mutating
+/// actions require `entry_id` and report `"Missing entry_id"` when absent.
+pub fn dispatch_clipboard_history(
+    source: &dyn ClipboardHistorySource,
+    request_id: &str,
+    action: &protocol::ClipboardHistoryAction,
+    entry_id: &Option<String>,
+) -> Message {
+    match action {
+        protocol::ClipboardHistoryAction::List => {
+            let entries = source.list(100);
+            let entry_data: Vec<protocol::ClipboardHistoryEntryData> = entries
+                .into_iter()
+                .map(|e| {
+                    // Truncate large content to avoid pipe buffer issues.
+                    // Images are stored as base64 which can be huge.
+                    let content = match e.content_type {
+                        clipboard_history::ContentType::Image => {
+                            format!("[image:{}]", e.id)
+                        }
+                        clipboard_history::ContentType::Text => {
+                            if e.content.len() > 1000 {
+                                format!("{}...", &e.content[..1000])
+                            } else {
+                                e.content
+                            }
+                        }
+                    };
+                    protocol::ClipboardHistoryEntryData {
+                        entry_id: e.id,
+                        content,
+                        content_type: match e.content_type {
+                            clipboard_history::ContentType::Text => {
+                                protocol::ClipboardEntryType::Text
+                            }
+                            clipboard_history::ContentType::Image => {
+                                protocol::ClipboardEntryType::Image
+                            }
+                        },
+                        timestamp: chrono::DateTime::from_timestamp(e.timestamp, 0)
+                            .map(|dt| dt.to_rfc3339())
+                            .unwrap_or_default(),
+                        pinned: e.pinned,
+                        copy_count: e.copy_count,
+                    }
+                })
+                .collect();
+            Message::clipboard_history_list_response(request_id.to_string(), entry_data)
+        }
+        protocol::ClipboardHistoryAction::Pin => with_entry_id(request_id, entry_id, |id| {
+            source.pin(id)
+        }),
+        protocol::ClipboardHistoryAction::Unpin => with_entry_id(request_id, entry_id, |id| {
+            source.unpin(id)
+        }),
+        protocol::ClipboardHistoryAction::Remove => with_entry_id(request_id, entry_id, |id| {
+            source.remove(id)
+        }),
+        protocol::ClipboardHistoryAction::Clear => match source.clear() {
+            Ok(()) => Message::clipboard_history_success(request_id.to_string()),
+            Err(e) => Message::clipboard_history_error(request_id.to_string(), e.to_string()),
+        },
+        protocol::ClipboardHistoryAction::TrimOversize => match source.trim_oversize() {
+            Ok(_) => Message::clipboard_history_success(request_id.to_string()),
+            Err(e) => Message::clipboard_history_error(request_id.to_string(), e.to_string()),
+        },
+    }
+}
+
+/// Shared "requires an `entry_id`" shape used by Pin/Unpin/Remove.
+fn with_entry_id(
+    request_id: &str,
+    entry_id: &Option<String>,
+    op: impl FnOnce(&str) -> anyhow::Result<()>,
+) -> Message {
+    match entry_id {
+        Some(id) => match op(id) {
+            Ok(()) => Message::clipboard_history_success(request_id.to_string()),
+            Err(e) => Message::clipboard_history_error(request_id.to_string(), e.to_string()),
+        },
+        None => {
+            Message::clipboard_history_error(request_id.to_string(), "Missing entry_id".to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    struct FakeClipboardHistory {
+        entries: RefCell<Vec<clipboard_history::ClipboardEntry>>,
+        fail_next: RefCell<bool>,
+    }
+
+    impl FakeClipboardHistory {
+        fn with_entries(entries: Vec<clipboard_history::ClipboardEntry>) -> Self {
+            Self {
+                entries: RefCell::new(entries),
+                fail_next: RefCell::new(false),
+            }
+        }
+
+        fn failing() -> Self {
+            Self {
+                entries: RefCell::new(Vec::new()),
+                fail_next: RefCell::new(true),
+            }
+        }
+
+        fn maybe_fail(&self) -> anyhow::Result<()> {
+            if *self.fail_next.borrow() {
+                anyhow::bail!("boom");
+            }
+            Ok(())
+        }
+    }
+
+    fn entry(id: &str, content: &str, content_type: clipboard_history::ContentType) -> clipboard_history::ClipboardEntry {
+        clipboard_history::ClipboardEntry {
+            id: id.to_string(),
+            content: content.to_string(),
+            content_type,
+            timestamp: 0,
+            pinned: false,
+            ocr_text: None,
+            copy_count: 1,
+        }
+    }
+
+    impl ClipboardHistorySource for FakeClipboardHistory {
+        fn list(&self, limit: usize) -> Vec<clipboard_history::ClipboardEntry> {
+            self.entries.borrow().iter().take(limit).cloned().collect()
+        }
+
+        fn pin(&self, _id: &str) -> anyhow::Result<()> {
+            self.maybe_fail()
+        }
+
+        fn unpin(&self, _id: &str) -> anyhow::Result<()> {
+            self.maybe_fail()
+        }
+
+        fn remove(&self, _id: &str) -> anyhow::Result<()> {
+            self.maybe_fail()
+        }
+
+        fn clear(&self) -> anyhow::Result<()> {
+            self.maybe_fail()
+        }
+
+        fn trim_oversize(&self) -> anyhow::Result<usize> {
+            self.maybe_fail().map(|()| 0)
+        }
+    }
+
+    #[test]
+    fn list_truncates_long_text_and_placeholders_images() {
+        let long_text = "x".repeat(1500);
+        let fake = FakeClipboardHistory::with_entries(vec![
+            entry("1", &long_text, clipboard_history::ContentType::Text),
+            entry("2", "irrelevant", clipboard_history::ContentType::Image),
+        ]);
+
+        let response = dispatch_clipboard_history(
+            &fake,
+            "req-1",
+            &protocol::ClipboardHistoryAction::List,
+            &None,
+        );
+
+        match response {
+            Message::ClipboardHistoryList { entries, .. } => {
+                assert_eq!(entries[0].content.len(), 1003); // 1000 chars + "..."
+                assert!(entries[0].content.ends_with("..."));
+                assert_eq!(entries[1].content, "[image:2]");
+            }
+            other => panic!("expected ClipboardHistoryList, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn pin_without_entry_id_reports_missing_entry_id() {
+        let fake = FakeClipboardHistory::default();
+
+        let response = dispatch_clipboard_history(
+            &fake,
+            "req-2",
+            &protocol::ClipboardHistoryAction::Pin,
+            &None,
+        );
+
+        match response {
+            Message::ClipboardHistoryResult { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some("Missing entry_id".to_string()));
+            }
+            other => panic!("expected ClipboardHistoryResult, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn pin_success_reports_success() {
+        let fake = FakeClipboardHistory::default();
+
+        let response = dispatch_clipboard_history(
+            &fake,
+            "req-3",
+            &protocol::ClipboardHistoryAction::Pin,
+            &Some("1".to_string()),
+        );
+
+        match response {
+            Message::ClipboardHistoryResult { success, .. } => assert!(success),
+            other => panic!("expected ClipboardHistoryResult, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn clear_failure_surfaces_error_message() {
+        let fake = FakeClipboardHistory::failing();
+
+        let response = dispatch_clipboard_history(
+            &fake,
+            "req-4",
+            &protocol::ClipboardHistoryAction::Clear,
+            &None,
+        );
+
+        match response {
+            Message::ClipboardHistoryResult { success, error, .. } => {
+                assert!(!success);
+                assert_eq!(error, Some("boom".to_string()));
+            }
+            other => panic!("expected ClipboardHistoryResult, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn trim_oversize_success_reports_success() {
+        let fake = FakeClipboardHistory::default();
+
+        let response = dispatch_clipboard_history(
+            &fake,
+            "req-5",
+            &protocol::ClipboardHistoryAction::TrimOversize,
+            &None,
+        );
+
+        match response {
+            Message::ClipboardHistoryResult { success, .. } => assert!(success),
+            other => panic!("expected ClipboardHistoryResult, got {:?}", other),
+        }
+    }
+}
@@ -38,6 +38,10 @@ pub struct TypedMetadata {
     pub icon: Option<String>,
     /// Keyboard shortcut (e.g., "opt i", "cmd shift k")
     pub shortcut: Option<String>,
+    /// Restrict the `shortcut` above to only fire while this app is
+    /// frontmost (bundle id or app name). Lets several scripts share the
+    /// same key combo, each scoped to a different app.
+    pub app: Option<String>,
     /// Tags for categorization
     #[serde(default)]
     pub tags: Vec<String>,
@@ -64,6 +68,18 @@ pub struct TypedMetadata {
     pub fallback: bool,
     /// Display label for fallback with {input} placeholder (e.g., "Search docs for {input}")
     pub fallback_label: Option<String>,
+    /// Policy for relaunching this script while a previous run is still active:
+    /// "single" (refuse), "queue" (run after the current one exits), or
+    /// "parallel" (run immediately alongside it). Defaults to "single".
+    pub concurrency: Option<String>,
+    /// Whether this script should appear in the tray's "Scripts" submenu
+    #[serde(default)]
+    pub tray: bool,
+    /// Return to the script list instead of hiding the window when this
+    /// script finishes running, so several scripts can be run in a row
+    /// without re-triggering the hotkey. Overrides the `hideOnExit` config.
+    #[serde(default)]
+    pub keep_open: bool,
     /// Any additional custom fields
     #[serde(flatten)]
     pub extra: HashMap<String, serde_json::Value>,
@@ -9,6 +9,7 @@
 //! Used to establish baseline metrics and identify performance bottlenecks.
 
 use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Mutex;
 use std::sync::OnceLock;
 use std::time::{Duration, Instant};
@@ -570,6 +571,241 @@ impl Drop for ScrollPerfGuard {
     }
 }
 
+// =============================================================================
+// DIAGNOSTICS REGISTRY
+// =============================================================================
+//
+// A small metrics registry for the Diagnostics built-in (see
+// `builtins::BuiltInFeature::Diagnostics`). Unlike the trackers above (which
+// are read by the same code that logs them), these are written from hot
+// paths - search, window show/hide, script spawn - and read only when the
+// user opens the Diagnostics view or copies a report. Recording is
+// lock-free: each histogram is a fixed-size ring buffer of `AtomicU64`
+// samples with an `AtomicUsize` write cursor, so a recording thread never
+// blocks on (or is blocked by) a reader taking a snapshot.
+
+/// Number of recent samples a `Histogram` keeps for percentile calculations.
+const HISTOGRAM_CAPACITY: usize = 200;
+
+/// A fixed-capacity ring buffer of timing samples (microseconds), written
+/// without locks so recording can happen on hot paths without perturbing
+/// the very latency it's measuring.
+pub struct Histogram {
+    samples: Box<[AtomicU64]>,
+    cursor: AtomicUsize,
+    count: AtomicU64,
+}
+
+impl Histogram {
+    pub fn new(capacity: usize) -> Self {
+        let mut samples = Vec::with_capacity(capacity);
+        samples.resize_with(capacity, || AtomicU64::new(0));
+        Self {
+            samples: samples.into_boxed_slice(),
+            cursor: AtomicUsize::new(0),
+            count: AtomicU64::new(0),
+        }
+    }
+
+    /// Record a sample in microseconds. Wait-free: never blocks.
+    pub fn record_us(&self, value_us: u64) {
+        let idx = self.cursor.fetch_add(1, Ordering::Relaxed) % self.samples.len();
+        self.samples[idx].store(value_us, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a `Duration` sample.
+    pub fn record(&self, duration: Duration) {
+        self.record_us(duration.as_micros() as u64);
+    }
+
+    /// Number of samples recorded so far (may exceed capacity; only the most
+    /// recent `capacity` samples are retained).
+    pub fn sample_count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Snapshot the currently-retained samples (unsorted, oldest-first order
+    /// is not preserved since slots are overwritten in place).
+    pub fn snapshot(&self) -> Vec<u64> {
+        let filled = (self.count.load(Ordering::Relaxed) as usize).min(self.samples.len());
+        self.samples[..filled]
+            .iter()
+            .map(|s| s.load(Ordering::Relaxed))
+            .collect()
+    }
+
+    /// Percentile (0-100) over the retained samples, in microseconds.
+    /// Returns `None` if no samples have been recorded yet.
+    pub fn percentile_us(&self, p: f64) -> Option<u64> {
+        let mut samples = self.snapshot();
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable();
+        Some(samples[percentile_index(samples.len(), p)])
+    }
+}
+
+/// Nearest-rank percentile index into a sorted slice of length `len`.
+fn percentile_index(len: usize, p: f64) -> usize {
+    let rank = (p / 100.0 * len as f64).ceil() as usize;
+    rank.clamp(1, len) - 1
+}
+
+/// A single named counter, incremented without locks.
+#[derive(Default)]
+pub struct Counter(AtomicU64);
+
+impl Counter {
+    pub fn increment(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// One phase of the app's startup sequence, in the order it was recorded.
+pub struct StartupPhase {
+    pub name: String,
+    pub duration_us: u64,
+}
+
+/// Named counters and histograms fed by call sites across the app, read back
+/// by the Diagnostics built-in to render a report.
+pub struct DiagnosticsRegistry {
+    pub search_latency: Histogram,
+    pub window_show_latency: Histogram,
+    pub window_hide_latency: Histogram,
+    pub script_spawn_time: Histogram,
+    /// Startup is a short, one-time sequence rather than a hot path, so a
+    /// mutex here doesn't risk perturbing measurements the way it would on
+    /// the per-keystroke search path.
+    startup_phases: Mutex<Vec<StartupPhase>>,
+}
+
+impl DiagnosticsRegistry {
+    fn new() -> Self {
+        Self {
+            search_latency: Histogram::new(HISTOGRAM_CAPACITY),
+            window_show_latency: Histogram::new(HISTOGRAM_CAPACITY),
+            window_hide_latency: Histogram::new(HISTOGRAM_CAPACITY),
+            script_spawn_time: Histogram::new(HISTOGRAM_CAPACITY),
+            startup_phases: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn record_startup_phase(&self, name: impl Into<String>, duration: Duration) {
+        if let Ok(mut phases) = self.startup_phases.lock() {
+            phases.push(StartupPhase {
+                name: name.into(),
+                duration_us: duration.as_micros() as u64,
+            });
+        }
+    }
+
+    fn startup_phases_snapshot(&self) -> Vec<(String, u64)> {
+        self.startup_phases
+            .lock()
+            .map(|phases| {
+                phases
+                    .iter()
+                    .map(|p| (p.name.clone(), p.duration_us))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Global diagnostics registry instance.
+static DIAGNOSTICS: OnceLock<DiagnosticsRegistry> = OnceLock::new();
+
+/// Get the global diagnostics registry, initializing it on first access.
+pub fn diagnostics() -> &'static DiagnosticsRegistry {
+    DIAGNOSTICS.get_or_init(DiagnosticsRegistry::new)
+}
+
+/// Snapshot of current cache memory usage, gathered by the caller (this
+/// module has no dependency on `clipboard_history` or the app's filter/
+/// preview caches - it just formats whatever counts it's handed).
+#[derive(Debug, Clone, Default)]
+pub struct CacheStats {
+    pub filter_cache_entries: usize,
+    pub preview_cache_entries: usize,
+    pub clipboard_image_cache_entries: usize,
+}
+
+/// Render a markdown diagnostics report from the global registry, suitable
+/// for pasting into a bug report.
+pub fn generate_diagnostics_report(cache_stats: &CacheStats) -> String {
+    render_report(diagnostics(), cache_stats)
+}
+
+/// Render a markdown diagnostics report for a given registry. Never panics;
+/// sections with no samples yet say so explicitly. Split out from
+/// `generate_diagnostics_report` so tests can exercise formatting against an
+/// isolated registry instead of the process-wide one.
+fn render_report(registry: &DiagnosticsRegistry, cache_stats: &CacheStats) -> String {
+    let mut report = String::new();
+
+    report.push_str("# Script Kit Diagnostics Report\n\n");
+
+    report.push_str("## Startup breakdown\n\n");
+    let phases = registry.startup_phases_snapshot();
+    if phases.is_empty() {
+        report.push_str("_No startup phases recorded yet._\n\n");
+    } else {
+        for (name, us) in &phases {
+            report.push_str(&format!("- {}: {:.1}ms\n", name, *us as f64 / 1000.0));
+        }
+        report.push('\n');
+    }
+
+    report.push_str("## Search latency\n\n");
+    report.push_str(&histogram_section(&registry.search_latency));
+
+    report.push_str("## Window show latency\n\n");
+    report.push_str(&histogram_section(&registry.window_show_latency));
+
+    report.push_str("## Window hide latency\n\n");
+    report.push_str(&histogram_section(&registry.window_hide_latency));
+
+    report.push_str("## Script spawn time\n\n");
+    report.push_str(&histogram_section(&registry.script_spawn_time));
+
+    report.push_str("## Cache sizes\n\n");
+    report.push_str(&format!(
+        "- Filter cache: {} entries\n",
+        cache_stats.filter_cache_entries
+    ));
+    report.push_str(&format!(
+        "- Preview cache: {} entries\n",
+        cache_stats.preview_cache_entries
+    ));
+    report.push_str(&format!(
+        "- Clipboard image cache: {} entries\n",
+        cache_stats.clipboard_image_cache_entries
+    ));
+
+    report
+}
+
+/// Format a histogram's p50/p95 as a markdown bullet list, or a
+/// "no samples" note if nothing has been recorded yet.
+fn histogram_section(histogram: &Histogram) -> String {
+    match (histogram.percentile_us(50.0), histogram.percentile_us(95.0)) {
+        (Some(p50), Some(p95)) => format!(
+            "- p50: {:.1}ms\n- p95: {:.1}ms\n- samples: {}\n\n",
+            p50 as f64 / 1000.0,
+            p95 as f64 / 1000.0,
+            histogram.sample_count()
+        ),
+        _ => "_No samples recorded yet._\n\n".to_string(),
+    }
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -629,4 +865,88 @@ mod tests {
             thread::sleep(Duration::from_micros(100));
         }
     }
+
+    mod diagnostics_tests {
+        use super::*;
+
+        #[test]
+        fn test_percentile_index_nearest_rank() {
+            // 10 samples: p50 -> 5th element (index 4), p95 -> 10th element (index 9)
+            assert_eq!(percentile_index(10, 50.0), 4);
+            assert_eq!(percentile_index(10, 95.0), 9);
+            assert_eq!(percentile_index(10, 100.0), 9);
+            assert_eq!(percentile_index(1, 50.0), 0);
+        }
+
+        #[test]
+        fn test_histogram_percentile_empty() {
+            let h = Histogram::new(10);
+            assert_eq!(h.percentile_us(50.0), None);
+            assert_eq!(h.percentile_us(95.0), None);
+        }
+
+        #[test]
+        fn test_histogram_percentile_basic() {
+            let h = Histogram::new(100);
+            for v in 1..=100u64 {
+                h.record_us(v);
+            }
+            assert_eq!(h.percentile_us(50.0), Some(50));
+            assert_eq!(h.percentile_us(95.0), Some(95));
+            assert_eq!(h.percentile_us(100.0), Some(100));
+            assert_eq!(h.sample_count(), 100);
+        }
+
+        #[test]
+        fn test_histogram_ring_buffer_retains_only_recent_samples() {
+            let h = Histogram::new(10);
+            // Record 15 samples into a capacity-10 buffer: only the last 10
+            // (values 6..=15) should be retained.
+            for v in 1..=15u64 {
+                h.record_us(v);
+            }
+            let mut snapshot = h.snapshot();
+            snapshot.sort_unstable();
+            assert_eq!(snapshot, (6..=15).collect::<Vec<_>>());
+            assert_eq!(h.sample_count(), 15);
+        }
+
+        #[test]
+        fn test_counter_increments() {
+            let counter = Counter::default();
+            assert_eq!(counter.get(), 0);
+            counter.increment();
+            counter.increment();
+            assert_eq!(counter.get(), 2);
+        }
+
+        #[test]
+        fn test_report_without_samples_says_so() {
+            let registry = DiagnosticsRegistry::new();
+            let report = render_report(&registry, &CacheStats::default());
+            assert!(report.contains("No startup phases recorded yet"));
+            assert!(report.contains("No samples recorded yet"));
+        }
+
+        #[test]
+        fn test_report_includes_recorded_phases_and_samples() {
+            let registry = DiagnosticsRegistry::new();
+            registry.record_startup_phase("sdk_extract", Duration::from_millis(12));
+            registry.search_latency.record(Duration::from_micros(2_500));
+
+            let report = render_report(
+                &registry,
+                &CacheStats {
+                    filter_cache_entries: 3,
+                    preview_cache_entries: 1,
+                    clipboard_image_cache_entries: 42,
+                },
+            );
+
+            assert!(report.contains("sdk_extract: 12.0ms"));
+            assert!(report.contains("## Search latency"));
+            assert!(report.contains("p50: 2.5ms"));
+            assert!(report.contains("Clipboard image cache: 42 entries"));
+        }
+    }
 }
@@ -299,6 +299,45 @@ pub fn hide_main_window() {
     // No-op on non-macOS platforms
 }
 
+/// Bring the main window forward and give it key focus, without needing a
+/// GPUI `Window` handle (used from contexts like `handle_prompt_message`
+/// that only have `&mut Context<ScriptListApp>`).
+///
+/// # macOS Behavior
+///
+/// Uses NSWindow `makeKeyAndOrderFront:` to raise the window and make it
+/// the key window. Callers still need `cx.activate(true)` separately to
+/// bring the whole app forward.
+///
+/// # Other Platforms
+///
+/// No-op on non-macOS platforms.
+#[cfg(target_os = "macos")]
+pub fn focus_main_window() {
+    debug_assert_main_thread();
+    unsafe {
+        let window = match window_manager::get_main_window() {
+            Some(w) => w,
+            None => {
+                logging::log(
+                    "PANEL",
+                    "focus_main_window: Main window not registered, nothing to focus",
+                );
+                return;
+            }
+        };
+
+        let _: () = msg_send![window, makeKeyAndOrderFront:nil];
+
+        logging::log("PANEL", "Main window focused via makeKeyAndOrderFront:");
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn focus_main_window() {
+    // No-op on non-macOS platforms
+}
+
 /// Get the current main window bounds in canonical top-left coordinates.
 /// Returns (x, y, width, height) or None if window not available.
 #[cfg(target_os = "macos")]
@@ -1458,26 +1497,48 @@ pub fn move_first_window_to_bounds(bounds: &Bounds<Pixels>) {
 /// - Positions the window at "eye-line" height (upper 14% of the screen)
 ///
 /// This matches the behavior of Raycast/Alfred where the prompt appears on the active display.
+///
+/// `position_mode` comes from the caller's already-loaded `Config`
+/// (`Config::get_window_position_mode`) rather than being loaded here, since
+/// `config::load_config()` shells out to bun and is too slow to call on
+/// every window show. When it's `Fixed` (see `config::WindowPositionMode`),
+/// this short-circuits to the last position the window was dragged to
+/// (persisted in `window-state.json`) instead, provided that position is
+/// still on a connected display; otherwise it falls through to the eye-line
+/// calculation below.
 pub fn calculate_eye_line_bounds_on_mouse_display(
     window_size: gpui::Size<Pixels>,
+    position_mode: crate::config::WindowPositionMode,
 ) -> Bounds<Pixels> {
     // Use native macOS API to get actual display bounds with correct origins
     // GPUI's cx.displays() returns incorrect origins for secondary displays
     let displays = get_macos_displays();
 
-    logging::log("POSITION", "");
-    logging::log(
-        "POSITION",
-        "╔════════════════════════════════════════════════════════════╗",
-    );
-    logging::log(
-        "POSITION",
-        "║  CALCULATING WINDOW POSITION FOR MOUSE DISPLAY             ║",
-    );
-    logging::log(
-        "POSITION",
-        "╚════════════════════════════════════════════════════════════╝",
-    );
+    if position_mode == crate::config::WindowPositionMode::Fixed {
+        if let Some(fixed) = crate::window_state::load_window_bounds(
+            crate::window_state::WindowRole::Main,
+        )
+        .filter(|bounds| crate::window_state::is_bounds_visible(bounds, &displays))
+        {
+            logging::log(
+                "POSITION",
+                &format!(
+                    "Using fixed window position: ({:.0}, {:.0})",
+                    fixed.x, fixed.y
+                ),
+            );
+            return Bounds {
+                origin: point(px(fixed.x as f32), px(fixed.y as f32)),
+                size: window_size,
+            };
+        }
+        logging::log(
+            "POSITION",
+            "window.position is \"fixed\" but no valid saved position exists, falling back to mouse eye-line",
+        );
+    }
+
+    logging::log_banner("POSITION", "CALCULATING WINDOW POSITION FOR MOUSE DISPLAY");
     logging::log(
         "POSITION",
         &format!("Available displays: {}", displays.len()),
@@ -1812,6 +1873,225 @@ pub fn capture_window_by_title(
     Err(format!("Window with title containing '{}' not found", title_pattern).into())
 }
 
+/// Scale an already-captured image down to 1x if `hi_dpi` is false, matching
+/// the behavior of [`capture_app_screenshot`] for retina displays.
+fn scale_for_hi_dpi(
+    image: image::RgbaImage,
+    hi_dpi: bool,
+) -> (image::RgbaImage, u32, u32) {
+    let original_width = image.width();
+    let original_height = image.height();
+
+    if hi_dpi {
+        (image, original_width, original_height)
+    } else {
+        let new_width = original_width / 2;
+        let new_height = original_height / 2;
+        let resized = image::imageops::resize(
+            &image,
+            new_width,
+            new_height,
+            image::imageops::FilterType::Lanczos3,
+        );
+        (resized, new_width, new_height)
+    }
+}
+
+fn encode_png(
+    image: &image::RgbaImage,
+    width: u32,
+    height: u32,
+) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+    use image::codecs::png::PngEncoder;
+    use image::ImageEncoder;
+
+    let mut png_data = Vec::new();
+    let encoder = PngEncoder::new(&mut png_data);
+    encoder.write_image(image, width, height, image::ExtendedColorType::Rgba8)?;
+    Ok(png_data)
+}
+
+/// Capture a full display by its index into `xcap::Monitor::all()`.
+///
+/// Returns a tuple of (png_data, width, height) on success.
+pub fn capture_display_screenshot(
+    display_index: usize,
+    hi_dpi: bool,
+) -> Result<(Vec<u8>, u32, u32), Box<dyn std::error::Error + Send + Sync>> {
+    use xcap::Monitor;
+
+    let monitors = Monitor::all()?;
+    let monitor = monitors
+        .get(display_index)
+        .ok_or_else(|| format!("No display at index {}", display_index))?;
+
+    let image = monitor.capture_image()?;
+    let (final_image, width, height) = scale_for_hi_dpi(image, hi_dpi);
+    let png_data = encode_png(&final_image, width, height)?;
+
+    tracing::debug!(
+        display_index,
+        width = width,
+        height = height,
+        hi_dpi = hi_dpi,
+        file_size = png_data.len(),
+        "Display captured with xcap"
+    );
+
+    Ok((png_data, width, height))
+}
+
+/// Capture an arbitrary system window by the id reported by `WindowList`.
+///
+/// `WindowList`'s ids come from the accessibility-based `window_control`
+/// module, so this matches the target window by title + owning app rather
+/// than by xcap's own (unrelated) window id.
+pub fn capture_window_by_system_id(
+    window_id: u32,
+    hi_dpi: bool,
+) -> Result<(Vec<u8>, u32, u32), Box<dyn std::error::Error + Send + Sync>> {
+    use xcap::Window;
+
+    let target_info = crate::window_control::list_windows()
+        .map_err(|e| format!("Failed to list windows: {e}"))?
+        .into_iter()
+        .find(|w| w.id == window_id)
+        .ok_or_else(|| format!("No window with id {}", window_id))?;
+
+    let windows = Window::all()?;
+    let window = windows
+        .into_iter()
+        .find(|w| {
+            let title = w.title().unwrap_or_default();
+            let app_name = w.app_name().unwrap_or_default();
+            title == target_info.title && app_name == target_info.app
+        })
+        .ok_or_else(|| format!("Could not locate capturable window for id {}", window_id))?;
+
+    let image = window.capture_image()?;
+    let (final_image, width, height) = scale_for_hi_dpi(image, hi_dpi);
+    let png_data = encode_png(&final_image, width, height)?;
+
+    tracing::debug!(
+        window_id,
+        width = width,
+        height = height,
+        hi_dpi = hi_dpi,
+        file_size = png_data.len(),
+        "Window captured by id with xcap"
+    );
+
+    Ok((png_data, width, height))
+}
+
+/// Capture a pixel region of a display by cropping a full display capture.
+///
+/// The region is clamped to the display's bounds, so a region that spans
+/// past the display edge captures only the portion that overlaps it.
+pub fn capture_region_screenshot(
+    display_index: usize,
+    region_x: i32,
+    region_y: i32,
+    region_width: u32,
+    region_height: u32,
+    hi_dpi: bool,
+) -> Result<(Vec<u8>, u32, u32), Box<dyn std::error::Error + Send + Sync>> {
+    use xcap::Monitor;
+
+    let monitors = Monitor::all()?;
+    let monitor = monitors
+        .get(display_index)
+        .ok_or_else(|| format!("No display at index {}", display_index))?;
+
+    // Always capture at native (hi_dpi) resolution first so the crop math
+    // matches the region's own coordinate space, then scale the crop down.
+    let image = monitor.capture_image()?;
+    let (crop_x, crop_y, crop_width, crop_height) =
+        clamp_region(image.width(), image.height(), region_x, region_y, region_width, region_height)?;
+
+    let cropped = image::imageops::crop_imm(&image, crop_x, crop_y, crop_width, crop_height)
+        .to_image();
+    let (final_image, width, height) = scale_for_hi_dpi(cropped, hi_dpi);
+    let png_data = encode_png(&final_image, width, height)?;
+
+    tracing::debug!(
+        display_index,
+        region_x,
+        region_y,
+        region_width,
+        region_height,
+        width = width,
+        height = height,
+        hi_dpi = hi_dpi,
+        file_size = png_data.len(),
+        "Region captured with xcap"
+    );
+
+    Ok((png_data, width, height))
+}
+
+/// Clamp a requested region to the bounds of an image, returning
+/// `(x, y, width, height)` for the overlapping portion.
+///
+/// Errors if the region does not overlap the image at all (e.g. it is
+/// entirely off-screen).
+fn clamp_region(
+    image_width: u32,
+    image_height: u32,
+    region_x: i32,
+    region_y: i32,
+    region_width: u32,
+    region_height: u32,
+) -> Result<(u32, u32, u32, u32), Box<dyn std::error::Error + Send + Sync>> {
+    let clamped_x = region_x.max(0) as u32;
+    let clamped_y = region_y.max(0) as u32;
+
+    if clamped_x >= image_width || clamped_y >= image_height {
+        return Err("Region does not overlap the display".into());
+    }
+
+    let requested_right = region_x.saturating_add(region_width as i32).max(0) as u32;
+    let requested_bottom = region_y.saturating_add(region_height as i32).max(0) as u32;
+
+    let clamped_width = requested_right.min(image_width).saturating_sub(clamped_x);
+    let clamped_height = requested_bottom.min(image_height).saturating_sub(clamped_y);
+
+    if clamped_width == 0 || clamped_height == 0 {
+        return Err("Region does not overlap the display".into());
+    }
+
+    Ok((clamped_x, clamped_y, clamped_width, clamped_height))
+}
+
+#[cfg(test)]
+mod screenshot_region_tests {
+    use super::clamp_region;
+
+    #[test]
+    fn region_fully_inside_display_is_unchanged() {
+        let (x, y, w, h) = clamp_region(1920, 1080, 100, 200, 300, 400).unwrap();
+        assert_eq!((x, y, w, h), (100, 200, 300, 400));
+    }
+
+    #[test]
+    fn region_spanning_right_and_bottom_edge_is_clamped() {
+        let (x, y, w, h) = clamp_region(1920, 1080, 1800, 1000, 300, 300).unwrap();
+        assert_eq!((x, y, w, h), (1800, 1000, 120, 80));
+    }
+
+    #[test]
+    fn region_spanning_negative_origin_is_clamped_to_zero() {
+        let (x, y, w, h) = clamp_region(1920, 1080, -50, -50, 150, 150).unwrap();
+        assert_eq!((x, y, w, h), (0, 0, 100, 100));
+    }
+
+    #[test]
+    fn region_entirely_off_screen_errors() {
+        assert!(clamp_region(1920, 1080, 2000, 2000, 100, 100).is_err());
+        assert!(clamp_region(1920, 1080, -500, 0, 100, 100).is_err());
+    }
+}
+
 // ============================================================================
 // Open Path with System Default
 // ============================================================================
@@ -1823,7 +2103,6 @@ pub fn capture_window_by_title(
 ///
 /// This can be used to open files, folders, URLs, or any path that the
 /// system knows how to handle.
-#[allow(dead_code)]
 pub fn open_path_with_system_default(path: &str) {
     logging::log("UI", &format!("Opening path with system default: {}", path));
     let path_owned = path.to_string();
@@ -2078,7 +2357,10 @@ mod tests {
     fn test_calculate_eye_line_bounds_returns_valid() {
         use gpui::size;
         let window_size = size(px(750.0), px(500.0));
-        let bounds = calculate_eye_line_bounds_on_mouse_display(window_size);
+        let bounds = calculate_eye_line_bounds_on_mouse_display(
+            window_size,
+            crate::config::WindowPositionMode::MouseEyeLine,
+        );
 
         // Bounds should have the same size as input
         assert_eq!(bounds.size.width, window_size.width);
@@ -2091,7 +2373,10 @@ mod tests {
     fn test_calculate_eye_line_bounds_upper_portion() {
         use gpui::size;
         let window_size = size(px(750.0), px(500.0));
-        let bounds = calculate_eye_line_bounds_on_mouse_display(window_size);
+        let bounds = calculate_eye_line_bounds_on_mouse_display(
+            window_size,
+            crate::config::WindowPositionMode::MouseEyeLine,
+        );
 
         // Get the display bounds for comparison
         let displays = get_macos_displays();
@@ -2108,4 +2393,21 @@ mod tests {
             );
         }
     }
+
+    /// Test that `WindowPositionMode::Fixed` falls back to the mouse eye-line
+    /// calculation when there is no saved position (the common case: the user
+    /// has never dragged the window, or none exists in this test environment).
+    #[test]
+    #[cfg_attr(target_os = "macos", ignore = "requires main thread (run via GPUI)")]
+    fn test_calculate_eye_line_bounds_fixed_mode_falls_back_without_saved_position() {
+        use gpui::size;
+        let window_size = size(px(750.0), px(500.0));
+        let eye_line_bounds =
+            calculate_eye_line_bounds_on_mouse_display(window_size, crate::config::WindowPositionMode::MouseEyeLine);
+        let fixed_bounds =
+            calculate_eye_line_bounds_on_mouse_display(window_size, crate::config::WindowPositionMode::Fixed);
+
+        assert_eq!(fixed_bounds.size, eye_line_bounds.size);
+        assert_eq!(fixed_bounds.origin, eye_line_bounds.origin);
+    }
 }
@@ -0,0 +1,224 @@
+//! Documentation preview extraction for the script list preview pane.
+//!
+//! Well-documented scripts front-load a leading comment block (or ship an
+//! adjacent `<script-name>.md`) that's more useful to skim than the first 15
+//! lines of source, which are mostly imports. This module extracts that
+//! doc block and parses it into a small set of renderable blocks; the
+//! actual GPUI rendering lives in `app_render.rs`.
+
+use std::path::{Path, PathBuf};
+
+/// A block of parsed doc text, ready to render.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkdownBlock {
+    Heading(u8, String),
+    Paragraph(String),
+}
+
+/// Path to the markdown file that would document `script_path`, if one
+/// exists alongside it (`foo.ts` -> `foo.md`).
+pub fn adjacent_markdown_path(script_path: &Path) -> Option<PathBuf> {
+    let stem = script_path.file_stem()?;
+    Some(script_path.with_file_name(format!("{}.md", stem.to_string_lossy())))
+}
+
+/// Load the doc preview text for a script: an adjacent `.md` file if one
+/// exists, otherwise the leading comment block extracted from the script's
+/// own source. Returns `None` if neither is available.
+pub fn load_doc_preview(script_path: &Path, extension: &str, source: &str) -> Option<String> {
+    if let Some(md_path) = adjacent_markdown_path(script_path) {
+        if let Ok(contents) = std::fs::read_to_string(&md_path) {
+            let trimmed = contents.trim();
+            if !trimmed.is_empty() {
+                return Some(trimmed.to_string());
+            }
+        }
+    }
+
+    extract_comment_header(source, extension)
+}
+
+/// Extract the leading contiguous comment block from a script's source,
+/// stripping comment markers and returning the remaining text trimmed.
+///
+/// Supports `//` line comments and `/** ... */` JSDoc blocks for
+/// TS/JS-family extensions, and `#` line comments (skipping a leading
+/// shebang) for shell-family extensions. Returns `None` if the script
+/// doesn't start with a comment block.
+pub fn extract_comment_header(content: &str, extension: &str) -> Option<String> {
+    match extension {
+        "ts" | "tsx" | "js" | "jsx" | "mjs" | "cjs" => extract_js_comment_header(content),
+        "sh" | "bash" | "zsh" => extract_shell_comment_header(content),
+        _ => None,
+    }
+}
+
+fn extract_js_comment_header(content: &str) -> Option<String> {
+    let trimmed = content.trim_start();
+
+    if let Some(rest) = trimmed.strip_prefix("/**") {
+        let end = rest.find("*/")?;
+        let body = &rest[..end];
+        let lines: Vec<String> = body
+            .lines()
+            .map(|line| line.trim().trim_start_matches('*').trim().to_string())
+            .skip_while(|line| line.is_empty())
+            .collect();
+        let text = lines.join("\n").trim().to_string();
+        return if text.is_empty() { None } else { Some(text) };
+    }
+
+    let mut lines = Vec::new();
+    for line in trimmed.lines() {
+        let line = line.trim_end();
+        if let Some(rest) = line.trim_start().strip_prefix("//") {
+            lines.push(rest.trim_start().to_string());
+        } else if line.trim().is_empty() && !lines.is_empty() {
+            // A blank line (like any non-comment line) ends the header.
+            break;
+        } else {
+            break;
+        }
+    }
+
+    let text = lines.join("\n").trim().to_string();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+fn extract_shell_comment_header(content: &str) -> Option<String> {
+    let mut lines = Vec::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("#!") {
+            // Shebang - not part of the doc comment, skip it.
+            continue;
+        }
+        if let Some(rest) = trimmed.strip_prefix('#') {
+            lines.push(rest.trim_start().to_string());
+        } else if trimmed.is_empty() && !lines.is_empty() {
+            break;
+        } else {
+            break;
+        }
+    }
+
+    let text = lines.join("\n").trim().to_string();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+/// Parse doc text into a minimal sequence of renderable blocks: `#`/`##`
+/// headings and paragraphs (blank-line separated). Not a full markdown
+/// parser - just enough to make a doc comment or README read nicely.
+pub fn parse_minimal_markdown(text: &str) -> Vec<MarkdownBlock> {
+    let mut blocks = Vec::new();
+    let mut paragraph_lines: Vec<&str> = Vec::new();
+
+    let flush = |blocks: &mut Vec<MarkdownBlock>, paragraph_lines: &mut Vec<&str>| {
+        if !paragraph_lines.is_empty() {
+            blocks.push(MarkdownBlock::Paragraph(paragraph_lines.join(" ")));
+            paragraph_lines.clear();
+        }
+    };
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            flush(&mut blocks, &mut paragraph_lines);
+            continue;
+        }
+
+        let heading_level = trimmed.chars().take_while(|&c| c == '#').count();
+        if heading_level > 0
+            && heading_level <= 6
+            && trimmed.as_bytes().get(heading_level) == Some(&b' ')
+        {
+            flush(&mut blocks, &mut paragraph_lines);
+            let heading_text = trimmed[heading_level..].trim().to_string();
+            blocks.push(MarkdownBlock::Heading(heading_level as u8, heading_text));
+            continue;
+        }
+
+        paragraph_lines.push(trimmed);
+    }
+
+    flush(&mut blocks, &mut paragraph_lines);
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_jsdoc_block_from_ts_header() {
+        let content = r#"/**
+ * My Script
+ *
+ * Does something useful.
+ */
+import '../../scripts/kit-sdk';
+"#;
+        let doc = extract_comment_header(content, "ts").unwrap();
+        assert_eq!(doc, "My Script\n\nDoes something useful.");
+    }
+
+    #[test]
+    fn extracts_line_comment_block_from_ts_header() {
+        let content =
+            "// My Script\n// Does something useful.\n\nimport '../../scripts/kit-sdk';\n";
+        let doc = extract_comment_header(content, "ts").unwrap();
+        assert_eq!(doc, "My Script\nDoes something useful.");
+    }
+
+    #[test]
+    fn returns_none_when_ts_script_has_no_leading_comment() {
+        let content = "import '../../scripts/kit-sdk';\n\n// Name: My Script\n";
+        assert_eq!(extract_comment_header(content, "ts"), None);
+    }
+
+    #[test]
+    fn extracts_comment_block_from_bash_header_skipping_shebang() {
+        let content = "#!/bin/bash\n# My Script\n# Does something useful.\n\necho hi\n";
+        let doc = extract_comment_header(content, "sh").unwrap();
+        assert_eq!(doc, "My Script\nDoes something useful.");
+    }
+
+    #[test]
+    fn returns_none_when_bash_script_has_no_comment_after_shebang() {
+        let content = "#!/bin/bash\necho hi\n";
+        assert_eq!(extract_comment_header(content, "sh"), None);
+    }
+
+    #[test]
+    fn parses_headings_and_paragraphs() {
+        let blocks = parse_minimal_markdown(
+            "# Title\n\nFirst paragraph\nstill first.\n\n## Section\n\nSecond.",
+        );
+        assert_eq!(
+            blocks,
+            vec![
+                MarkdownBlock::Heading(1, "Title".to_string()),
+                MarkdownBlock::Paragraph("First paragraph still first.".to_string()),
+                MarkdownBlock::Heading(2, "Section".to_string()),
+                MarkdownBlock::Paragraph("Second.".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn adjacent_markdown_path_swaps_extension() {
+        let path = Path::new("/scripts/foo.ts");
+        assert_eq!(
+            adjacent_markdown_path(path),
+            Some(PathBuf::from("/scripts/foo.md"))
+        );
+    }
+}
@@ -20,6 +20,7 @@ use std::io::{Read, Write};
 use std::path::PathBuf;
 use std::process::Command;
 use std::sync::{LazyLock, RwLock};
+use std::time::{Duration, Instant};
 use sysinfo::{Pid, System};
 
 /// Global singleton process manager
@@ -272,6 +273,47 @@ impl ProcessManager {
         }
     }
 
+    /// Send SIGTERM to a single process's group, asking it to exit cleanly.
+    ///
+    /// Used as the escalation step between a script's voluntary shutdown
+    /// window and the final [`Self::kill_process`] SIGKILL.
+    pub fn terminate_process(&self, pid: u32) {
+        logging::log("PROC", &format!("Terminating process PID {}", pid));
+
+        #[cfg(unix)]
+        {
+            let negative_pgid = format!("-{}", pid);
+            match Command::new("kill").args(["-15", &negative_pgid]).output() {
+                Ok(output) => {
+                    if output.status.success() {
+                        logging::log("PROC", &format!("Sent SIGTERM to process group {}", pid));
+                    } else {
+                        let stderr = String::from_utf8_lossy(&output.stderr);
+                        if stderr.contains("No such process") {
+                            logging::log("PROC", &format!("Process {} already exited", pid));
+                        } else {
+                            logging::log(
+                                "PROC",
+                                &format!("Failed to terminate process {}: {}", pid, stderr),
+                            );
+                        }
+                    }
+                }
+                Err(e) => {
+                    logging::log("PROC", &format!("Failed to execute kill command: {}", e));
+                }
+            }
+        }
+
+        #[cfg(not(unix))]
+        {
+            logging::log(
+                "PROC",
+                &format!("Non-Unix platform: cannot terminate process {}", pid),
+            );
+        }
+    }
+
     /// Check if a process is currently running
     pub fn is_process_running(&self, pid: u32) -> bool {
         let mut system = System::new();
@@ -393,6 +435,139 @@ impl Default for ProcessManager {
     }
 }
 
+/// Kill a single process gracefully: send SIGTERM immediately, then SIGKILL
+/// if it's still alive after `grace_period`.
+///
+/// Used when cancelling a running script so it gets a chance to run cleanup
+/// handlers (temp files, connections) instead of being hard-killed on the
+/// spot. Spawns a background thread to wait out the grace period so the
+/// caller - typically the UI thread handling the cancel - is never blocked.
+pub fn kill_process_after_grace(pid: u32, grace_period: Duration) {
+    PROCESS_MANAGER.terminate_process(pid);
+
+    std::thread::spawn(move || {
+        let deadline = Instant::now() + grace_period;
+        let poll_interval = Duration::from_millis(50);
+        while PROCESS_MANAGER.is_process_running(pid) {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            std::thread::sleep(poll_interval.min(remaining));
+        }
+        if PROCESS_MANAGER.is_process_running(pid) {
+            PROCESS_MANAGER.kill_process(pid);
+        }
+    });
+}
+
+/// A process participating in the graceful shutdown sequence.
+///
+/// Abstracts over a real tracked script process so [`escalate_shutdown`] can
+/// be driven by fakes in tests, without spawning or killing real processes.
+pub trait ShutdownTarget {
+    /// Best-effort request for the process to exit on its own (e.g. writing
+    /// a shutdown message to its stdin). Must not block.
+    fn request_exit(&self);
+    /// Send SIGTERM (or platform equivalent).
+    fn terminate(&self);
+    /// Send SIGKILL (or platform equivalent).
+    fn kill(&self);
+    /// Whether the process is still alive.
+    fn is_alive(&self) -> bool;
+}
+
+/// Run the graceful shutdown escalation sequence against `targets`.
+///
+/// Asks every target to exit on its own, then waits up to `grace_period`
+/// (polling every `poll_interval`) for all of them to stop. Any stragglers
+/// are sent SIGTERM and given a further window of five `poll_interval`s before
+/// the final SIGKILL. Returns as soon as every target has stopped.
+pub fn escalate_shutdown<T: ShutdownTarget>(
+    targets: &[T],
+    grace_period: Duration,
+    poll_interval: Duration,
+) {
+    if targets.is_empty() {
+        return;
+    }
+
+    for target in targets {
+        target.request_exit();
+    }
+
+    if wait_until_all_stopped(targets, grace_period, poll_interval) {
+        return;
+    }
+
+    for target in targets {
+        if target.is_alive() {
+            target.terminate();
+        }
+    }
+
+    if wait_until_all_stopped(targets, poll_interval * 5, poll_interval) {
+        return;
+    }
+
+    for target in targets {
+        if target.is_alive() {
+            target.kill();
+        }
+    }
+}
+
+/// Poll `targets` until none are alive or `timeout` elapses. Returns whether
+/// everything stopped before the deadline.
+fn wait_until_all_stopped<T: ShutdownTarget>(
+    targets: &[T],
+    timeout: Duration,
+    poll_interval: Duration,
+) -> bool {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if targets.iter().all(|t| !t.is_alive()) {
+            return true;
+        }
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return false;
+        }
+        std::thread::sleep(poll_interval.min(remaining));
+    }
+}
+
+/// A real tracked script process participating in graceful shutdown.
+///
+/// `sender` is only `Some` for the foreground interactive script (the one
+/// with a live stdin writer) - scheduled/background scripts have no stdin
+/// connection to send a shutdown message over, so they skip straight to the
+/// SIGTERM/SIGKILL escalation like any other tracked process.
+pub struct RunningScriptTarget {
+    pub pid: u32,
+    pub sender: Option<std::sync::mpsc::SyncSender<crate::protocol::Message>>,
+}
+
+impl ShutdownTarget for RunningScriptTarget {
+    fn request_exit(&self) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.try_send(crate::protocol::Message::shutdown());
+        }
+    }
+
+    fn terminate(&self) {
+        PROCESS_MANAGER.terminate_process(self.pid);
+    }
+
+    fn kill(&self) {
+        PROCESS_MANAGER.kill_process(self.pid);
+    }
+
+    fn is_alive(&self) -> bool {
+        PROCESS_MANAGER.is_process_running(self.pid)
+    }
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -400,6 +575,7 @@ impl Default for ProcessManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
     use tempfile::TempDir;
 
     /// Create a ProcessManager with a temporary directory for testing
@@ -572,6 +748,136 @@ mod tests {
         assert!(manager.is_main_pid_stale());
     }
 
+    /// Fake process handle for exercising `escalate_shutdown` without real OS processes.
+    ///
+    /// Records every call it receives and lets a test decide when the
+    /// process "dies" in response to each escalation step.
+    struct FakeProcess {
+        requested_exit: AtomicBool,
+        terminated: AtomicBool,
+        killed: AtomicBool,
+        /// Which step (if any) causes the process to stop responding to `is_alive`
+        dies_on: DiesOn,
+    }
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum DiesOn {
+        RequestExit,
+        Terminate,
+        Kill,
+        Never,
+    }
+
+    impl FakeProcess {
+        fn new(dies_on: DiesOn) -> Self {
+            FakeProcess {
+                requested_exit: AtomicBool::new(false),
+                terminated: AtomicBool::new(false),
+                killed: AtomicBool::new(false),
+                dies_on,
+            }
+        }
+    }
+
+    impl ShutdownTarget for FakeProcess {
+        fn request_exit(&self) {
+            self.requested_exit.store(true, AtomicOrdering::SeqCst);
+        }
+
+        fn terminate(&self) {
+            self.terminated.store(true, AtomicOrdering::SeqCst);
+        }
+
+        fn kill(&self) {
+            self.killed.store(true, AtomicOrdering::SeqCst);
+        }
+
+        fn is_alive(&self) -> bool {
+            match self.dies_on {
+                DiesOn::RequestExit => !self.requested_exit.load(AtomicOrdering::SeqCst),
+                DiesOn::Terminate => !self.terminated.load(AtomicOrdering::SeqCst),
+                DiesOn::Kill => !self.killed.load(AtomicOrdering::SeqCst),
+                DiesOn::Never => true,
+            }
+        }
+    }
+
+    #[test]
+    fn test_escalate_shutdown_stops_on_voluntary_exit() {
+        let target = FakeProcess::new(DiesOn::RequestExit);
+        escalate_shutdown(
+            std::slice::from_ref(&target),
+            Duration::from_millis(100),
+            Duration::from_millis(5),
+        );
+
+        assert!(target.requested_exit.load(AtomicOrdering::SeqCst));
+        assert!(!target.terminated.load(AtomicOrdering::SeqCst));
+        assert!(!target.killed.load(AtomicOrdering::SeqCst));
+    }
+
+    #[test]
+    fn test_escalate_shutdown_falls_back_to_sigterm() {
+        let target = FakeProcess::new(DiesOn::Terminate);
+        escalate_shutdown(
+            std::slice::from_ref(&target),
+            Duration::from_millis(20),
+            Duration::from_millis(5),
+        );
+
+        assert!(target.requested_exit.load(AtomicOrdering::SeqCst));
+        assert!(target.terminated.load(AtomicOrdering::SeqCst));
+        assert!(!target.killed.load(AtomicOrdering::SeqCst));
+    }
+
+    #[test]
+    fn test_escalate_shutdown_falls_back_to_sigkill() {
+        let target = FakeProcess::new(DiesOn::Kill);
+        escalate_shutdown(
+            std::slice::from_ref(&target),
+            Duration::from_millis(20),
+            Duration::from_millis(5),
+        );
+
+        assert!(target.requested_exit.load(AtomicOrdering::SeqCst));
+        assert!(target.terminated.load(AtomicOrdering::SeqCst));
+        assert!(target.killed.load(AtomicOrdering::SeqCst));
+    }
+
+    #[test]
+    fn test_escalate_shutdown_no_targets_is_a_noop() {
+        let targets: Vec<FakeProcess> = Vec::new();
+        // Should return immediately rather than hang on an empty wait loop.
+        escalate_shutdown(&targets, Duration::from_secs(5), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_escalate_shutdown_mixed_targets_each_escalate_independently() {
+        let quits_immediately = FakeProcess::new(DiesOn::RequestExit);
+        let needs_sigkill = FakeProcess::new(DiesOn::Kill);
+        let targets = vec![quits_immediately, needs_sigkill];
+
+        escalate_shutdown(
+            &targets,
+            Duration::from_millis(20),
+            Duration::from_millis(5),
+        );
+
+        assert!(!targets[0].terminated.load(AtomicOrdering::SeqCst));
+        assert!(!targets[0].killed.load(AtomicOrdering::SeqCst));
+        assert!(targets[1].terminated.load(AtomicOrdering::SeqCst));
+        assert!(targets[1].killed.load(AtomicOrdering::SeqCst));
+    }
+
+    #[test]
+    fn test_kill_process_after_grace_on_nonexistent_pid_does_not_panic() {
+        // No such process exists - both the SIGTERM and the eventual SIGKILL
+        // should fail gracefully (logged, not panicking) and the background
+        // thread should still finish promptly.
+        kill_process_after_grace(u32::MAX - 1, Duration::from_millis(10));
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
     #[test]
     fn test_default_paths() {
         let manager = ProcessManager::new();
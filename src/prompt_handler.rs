@@ -2,14 +2,469 @@
 // This file is included via include!() macro in main.rs
 
 impl ScriptListApp {
+    /// Watch an arg prompt for a response, and nudge the user if the script
+    /// never reads it (e.g. it hung before calling `await arg(...)`).
+    ///
+    /// The timer doesn't need to be explicitly canceled on submit - by the
+    /// time it fires, the script will usually have moved on to a different
+    /// prompt or exited, so the `current_view` check below simply finds
+    /// nothing to do.
+    fn start_prompt_timeout(&mut self, prompt_id: String, timeout_ms: u64, cx: &mut Context<Self>) {
+        cx.spawn(async move |this, cx| {
+            Timer::after(std::time::Duration::from_millis(timeout_ms)).await;
+
+            let app_entity_for_cancel = this.clone();
+            let _ = cx.update(|cx| {
+                let Some(app) = this.upgrade() else {
+                    return;
+                };
+                app.update(cx, |view, cx| {
+                    let still_waiting = matches!(
+                        &view.current_view,
+                        AppView::ArgPrompt { id, .. } if id == &prompt_id
+                    );
+                    if !still_waiting {
+                        return;
+                    }
+
+                    logging::log(
+                        "UI",
+                        &format!("Prompt '{}' timed out waiting for a response", prompt_id),
+                    );
+
+                    let app_for_cancel = app_entity_for_cancel.clone();
+                    let toast = Toast::warning(
+                        "This script isn't responding to your input".to_string(),
+                        &view.theme,
+                    )
+                    .persistent()
+                    .action(ToastAction::new(
+                        "Cancel Script",
+                        Box::new(move |_, _, cx| {
+                            if let Some(app) = app_for_cancel.upgrade() {
+                                app.update(cx, |view, cx| {
+                                    view.cancel_script_execution(cx);
+                                });
+                            }
+                        }),
+                    ));
+                    view.toast_manager.push(toast);
+                    cx.notify();
+                });
+            });
+        })
+        .detach();
+    }
+
+    /// Summarize the current prompt as the 10-field tuple used by
+    /// `PromptMessage::GetState` and the `--headless` stdout state feed
+    /// (see `main.rs`'s external command loop). Kept separate from the
+    /// `GetState` handler itself so both call sites reuse the exact same
+    /// shape instead of drifting apart.
+    #[allow(clippy::type_complexity)]
+    fn compute_state_fields(
+        &self,
+    ) -> (
+        String,
+        Option<String>,
+        Option<String>,
+        String,
+        usize,
+        usize,
+        i32,
+        Option<String>,
+        bool,
+        bool,
+    ) {
+        let (
+            prompt_type,
+            prompt_id,
+            placeholder,
+            input_value,
+            choice_count,
+            visible_choice_count,
+            selected_index,
+            selected_value,
+        ) = match &self.current_view {
+            AppView::ScriptList => {
+                let filtered_len = self.filtered_results().len();
+                let selected_value = if self.selected_index < filtered_len {
+                    self.filtered_results()
+                        .get(self.selected_index)
+                        .map(|r| match r {
+                            scripts::SearchResult::Script(m) => m.script.name.clone(),
+                            scripts::SearchResult::Scriptlet(m) => m.scriptlet.name.clone(),
+                            scripts::SearchResult::BuiltIn(m) => m.entry.name.clone(),
+                            scripts::SearchResult::App(m) => m.app.name.clone(),
+                            scripts::SearchResult::Window(m) => m.window.title.clone(),
+                            scripts::SearchResult::Agent(m) => m.agent.name.clone(),
+                            scripts::SearchResult::RecentFile(m) => m.file.name.clone(),
+                            scripts::SearchResult::Fallback(m) => m.fallback.name().to_string(),
+                        })
+                } else {
+                    None
+                };
+                (
+                    "none".to_string(),
+                    None,
+                    None,
+                    self.filter_text.clone(),
+                    self.scripts.len()
+                        + self.scriptlets.len()
+                        + self.builtin_entries.len()
+                        + self.apps.len(),
+                    filtered_len,
+                    self.selected_index as i32,
+                    selected_value,
+                )
+            }
+            AppView::ArgPrompt {
+                id,
+                placeholder,
+                hint: _,
+                choices,
+                actions: _,
+                footer_hints: _,
+            } => {
+                let filtered = self.get_filtered_arg_choices(choices);
+                let selected_value = if self.arg_selected_index < filtered.len() {
+                    filtered
+                        .get(self.arg_selected_index)
+                        .map(|c| c.value.clone())
+                } else {
+                    None
+                };
+                (
+                    "arg".to_string(),
+                    Some(id.clone()),
+                    Some(placeholder.clone()),
+                    self.arg_input.text().to_string(),
+                    choices.len(),
+                    filtered.len(),
+                    self.arg_selected_index as i32,
+                    selected_value,
+                )
+            }
+            AppView::DivPrompt { id, .. } => (
+                "div".to_string(),
+                Some(id.clone()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::FormPrompt { id, .. } => (
+                "form".to_string(),
+                Some(id.clone()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::TermPrompt { id, .. } => (
+                "term".to_string(),
+                Some(id.clone()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::EditorPrompt { id, .. } => (
+                "editor".to_string(),
+                Some(id.clone()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::SelectPrompt { id, .. } => (
+                "select".to_string(),
+                Some(id.clone()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::PathPrompt { id, .. } => (
+                "path".to_string(),
+                Some(id.clone()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::EnvPrompt { id, .. } => (
+                "env".to_string(),
+                Some(id.clone()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::DropPrompt { id, .. } => (
+                "drop".to_string(),
+                Some(id.clone()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::TemplatePrompt { id, .. } => (
+                "template".to_string(),
+                Some(id.clone()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::ConfirmPrompt { id, .. } => (
+                "confirm".to_string(),
+                Some(id.clone()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::ActionsDialog => (
+                "actions".to_string(),
+                None,
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            // P0 FIX: View state only - data comes from self.cached_clipboard_entries
+            AppView::ClipboardHistoryView {
+                filter,
+                selected_index,
+            } => {
+                let entries = &self.cached_clipboard_entries;
+                let filtered_count = if filter.is_empty() {
+                    entries.len()
+                } else {
+                    let filter_norm = normalize_for_search(filter);
+                    entries
+                        .iter()
+                        .filter(|e| normalize_for_search(&e.text_preview).contains(&filter_norm))
+                        .count()
+                };
+                (
+                    "clipboardHistory".to_string(),
+                    None,
+                    None,
+                    filter.clone(),
+                    entries.len(),
+                    filtered_count,
+                    *selected_index as i32,
+                    None,
+                )
+            }
+            // P0 FIX: View state only - data comes from self.apps
+            AppView::AppLauncherView {
+                filter,
+                selected_index,
+            } => {
+                let apps = &self.apps;
+                let filtered_count = if filter.is_empty() {
+                    apps.len()
+                } else {
+                    let filter_norm = normalize_for_search(filter);
+                    apps.iter()
+                        .filter(|a| normalize_for_search(&a.name).contains(&filter_norm))
+                        .count()
+                };
+                (
+                    "appLauncher".to_string(),
+                    None,
+                    None,
+                    filter.clone(),
+                    apps.len(),
+                    filtered_count,
+                    *selected_index as i32,
+                    None,
+                )
+            }
+            // P0 FIX: View state only - data comes from self.cached_windows
+            AppView::WindowSwitcherView {
+                filter,
+                selected_index,
+            } => {
+                let windows = &self.cached_windows;
+                let filtered_count = if filter.is_empty() {
+                    windows.len()
+                } else {
+                    let filter_norm = normalize_for_search(filter);
+                    windows
+                        .iter()
+                        .filter(|w| {
+                            normalize_for_search(&w.title).contains(&filter_norm)
+                                || normalize_for_search(&w.app).contains(&filter_norm)
+                        })
+                        .count()
+                };
+                (
+                    "windowSwitcher".to_string(),
+                    None,
+                    None,
+                    filter.clone(),
+                    windows.len(),
+                    filtered_count,
+                    *selected_index as i32,
+                    None,
+                )
+            }
+            AppView::DesignGalleryView {
+                filter,
+                selected_index,
+            } => {
+                let total_items = designs::separator_variations::SeparatorStyle::count()
+                    + designs::icon_variations::total_icon_count()
+                    + 8
+                    + 6; // headers
+                (
+                    "designGallery".to_string(),
+                    None,
+                    None,
+                    filter.clone(),
+                    total_items,
+                    total_items,
+                    *selected_index as i32,
+                    None,
+                )
+            }
+            AppView::ScratchPadView { .. } => (
+                "scratchPad".to_string(),
+                Some("scratch-pad".to_string()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::RunLogView { .. } => (
+                "runLog".to_string(),
+                Some("run-log".to_string()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::DiagnosticsView { .. } => (
+                "diagnostics".to_string(),
+                Some("diagnostics".to_string()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::QuickTerminalView { .. } => (
+                "quickTerminal".to_string(),
+                Some("quick-terminal".to_string()),
+                None,
+                String::new(),
+                0,
+                0,
+                -1,
+                None,
+            ),
+            AppView::FileSearchView {
+                ref query,
+                selected_index,
+            } => (
+                "fileSearch".to_string(),
+                Some("file-search".to_string()),
+                None,
+                query.clone(),
+                self.cached_file_results.len(),
+                self.cached_file_results.len(),
+                *selected_index as i32,
+                self.cached_file_results
+                    .get(*selected_index)
+                    .map(|f| f.name.clone()),
+            ),
+            AppView::RunningScriptsView { selected_index } => {
+                let count = process_manager::PROCESS_MANAGER.active_count();
+                (
+                    "runningScripts".to_string(),
+                    Some("running-scripts".to_string()),
+                    None,
+                    String::new(),
+                    count,
+                    count,
+                    *selected_index as i32,
+                    None,
+                )
+            }
+        };
+
+        // Focus state: we use focused_input as a proxy since we don't have Window access here.
+        // When window is visible and we're tracking an input, we're focused.
+        let window_visible = script_kit_gpui::is_main_window_visible();
+        let is_focused = window_visible && self.focused_input != FocusedInput::None;
+
+        (
+            prompt_type,
+            prompt_id,
+            placeholder,
+            input_value,
+            choice_count,
+            visible_choice_count,
+            selected_index,
+            selected_value,
+            is_focused,
+            window_visible,
+        )
+    }
+
     /// Handle a prompt message from the script
     fn handle_prompt_message(&mut self, msg: PromptMessage, cx: &mut Context<Self>) {
+        // Push the outgoing prompt onto the back-navigation stack before
+        // replacing it. `current_prompt` is `None` while replaying a popped
+        // entry from `go_back`, so this doesn't re-push during back
+        // navigation itself.
+        push_prompt_history(
+            &mut self.current_prompt,
+            &mut self.prompt_stack,
+            msg.clone(),
+            self.arg_input.text(),
+        );
+
         match msg {
             PromptMessage::ShowArg {
                 id,
                 placeholder,
                 choices,
                 actions,
+                timeout_ms,
+                choices_cmd,
+                footer_hints,
             } => {
                 logging::log(
                     "UI",
@@ -20,7 +475,24 @@ impl ScriptListApp {
                         actions.as_ref().map(|a| a.len()).unwrap_or(0)
                     ),
                 );
+                // If args were queued for this run (via ExternalCommand::Run,
+                // script_hotkey_channel, the URL scheme, or an aliased
+                // invocation), auto-submit the next one instead of rendering -
+                // an empty string is a valid queued arg, so pop_front() is
+                // used directly rather than filtering on emptiness. Once the
+                // queue drains, ShowArg falls back to the interactive prompt.
+                if let Some(queued) = next_queued_arg(&mut self.pending_script_args) {
+                    logging::log(
+                        "EXEC",
+                        &format!("Auto-submitting queued arg for '{}': {:?}", id, queued),
+                    );
+                    self.submit_prompt_response(id, Some(queued), cx);
+                    return;
+                }
+
                 let choice_count = choices.len();
+                self.spawn_choice_image_prewarm(&choices, cx);
+                let pending_choices_cmd = choices_cmd.filter(|_| choices.is_empty());
 
                 // If actions were provided, store them in the SDK actions system
                 // so they can be triggered via shortcuts and Cmd+K
@@ -44,15 +516,27 @@ impl ScriptListApp {
                     self.action_shortcuts.clear();
                 }
 
+                let prompt_id = id.clone();
                 self.current_view = AppView::ArgPrompt {
                     id,
                     placeholder,
+                    hint: None,
                     choices,
                     actions,
+                    footer_hints,
                 };
                 self.arg_input.clear();
                 self.arg_selected_index = 0;
+                self.arg_choices_loading = false;
                 self.focused_input = FocusedInput::ArgPrompt;
+                // A plain arg prompt still addresses `Message::SelectionChange`
+                // and `Message::Preview` back to the script (see
+                // `split_prompt_id`), but never has a `Message::Split`-style
+                // seeded preview - clear any leftover state from a previous
+                // prompt.
+                self.split_prompt_id = Some(prompt_id.clone());
+                self.split_preview = None;
+                self.preview_content_cache.clear();
                 // Request focus via pending_focus mechanism (will be applied on next render)
                 self.pending_focus = Some(FocusTarget::AppRoot); // ArgPrompt uses parent focus
                                                                  // Resize window based on number of choices
@@ -62,6 +546,79 @@ impl ScriptListApp {
                     ViewType::ArgPromptWithChoices
                 };
                 resize_to_view_sync(view_type, choice_count);
+
+                if let Some(ms) = timeout_ms {
+                    self.start_prompt_timeout(prompt_id.clone(), ms, cx);
+                }
+
+                if let Some(cmd) = pending_choices_cmd {
+                    self.spawn_choices_cmd(prompt_id, cmd, cx);
+                }
+
+                cx.notify();
+            }
+            PromptMessage::ShowSplit {
+                id,
+                placeholder,
+                choices,
+                preview,
+                actions,
+                footer_hints,
+            } => {
+                logging::log(
+                    "UI",
+                    &format!(
+                        "Showing split prompt: {} with {} choices",
+                        id,
+                        choices.len()
+                    ),
+                );
+
+                let choice_count = choices.len();
+                self.spawn_choice_image_prewarm(&choices, cx);
+
+                if let Some(ref action_list) = actions {
+                    self.sdk_actions = Some(action_list.clone());
+                    self.action_shortcuts.clear();
+                    for action in action_list {
+                        if let Some(shortcut) = &action.shortcut {
+                            self.action_shortcuts.insert(
+                                shortcuts::normalize_shortcut(shortcut),
+                                action.name.clone(),
+                            );
+                        }
+                    }
+                } else {
+                    self.sdk_actions = None;
+                    self.action_shortcuts.clear();
+                }
+
+                // A split prompt renders exactly like an arg prompt - the
+                // preview pane and selection-change notifications are driven
+                // by `split_prompt_id`/`split_preview` rather than a
+                // dedicated view variant.
+                self.split_prompt_id = Some(id.clone());
+                self.split_preview = preview;
+                self.preview_content_cache.clear();
+                self.current_view = AppView::ArgPrompt {
+                    id,
+                    placeholder,
+                    hint: None,
+                    choices,
+                    actions,
+                    footer_hints,
+                };
+                self.arg_input.clear();
+                self.arg_selected_index = 0;
+                self.arg_choices_loading = false;
+                self.focused_input = FocusedInput::ArgPrompt;
+                self.pending_focus = Some(FocusTarget::AppRoot);
+                let view_type = if choice_count == 0 {
+                    ViewType::ArgPromptNoChoices
+                } else {
+                    ViewType::ArgPromptWithChoices
+                };
+                resize_to_view_sync(view_type, choice_count);
                 cx.notify();
             }
             PromptMessage::ShowDiv {
@@ -75,6 +632,7 @@ impl ScriptListApp {
                 container_bg,
                 container_padding,
                 opacity,
+                footer_hints,
             } => {
                 logging::log("UI", &format!("Showing div prompt: {}", id));
                 // Store SDK actions for the actions panel (Cmd+K)
@@ -137,7 +695,11 @@ impl ScriptListApp {
                 );
 
                 let entity = cx.new(|_| div_prompt);
-                self.current_view = AppView::DivPrompt { id, entity };
+                self.current_view = AppView::DivPrompt {
+                    id,
+                    entity,
+                    footer_hints,
+                };
                 self.focused_input = FocusedInput::None; // DivPrompt has no text input
                 self.pending_focus = Some(FocusTarget::AppRoot); // DivPrompt uses parent focus
                 resize_to_view_sync(ViewType::DivPrompt, 0);
@@ -173,6 +735,9 @@ impl ScriptListApp {
             PromptMessage::ShowTerm {
                 id,
                 command,
+                shell,
+                cwd,
+                login,
                 actions,
             } => {
                 logging::log(
@@ -215,6 +780,9 @@ impl ScriptListApp {
                 match term_prompt::TermPrompt::with_height(
                     id.clone(),
                     command,
+                    shell,
+                    cwd,
+                    login,
                     self.focus_handle.clone(),
                     submit_callback,
                     std::sync::Arc::new(self.theme.clone()),
@@ -247,6 +815,7 @@ impl ScriptListApp {
                 language,
                 template,
                 actions,
+                footer_hints,
             } => {
                 logging::log(
                     "UI",
@@ -352,6 +921,7 @@ impl ScriptListApp {
                     id,
                     entity,
                     focus_handle: editor_focus_handle,
+                    footer_hints,
                 };
                 self.focused_input = FocusedInput::None; // Editor handles its own focus
                 self.pending_focus = Some(FocusTarget::EditorPrompt);
@@ -364,17 +934,45 @@ impl ScriptListApp {
                 .detach();
                 cx.notify();
             }
-            PromptMessage::ScriptExit => {
+            PromptMessage::ScriptExit { value } => {
                 logging::log("VISIBILITY", "=== ScriptExit message received ===");
+
+                // Write the script's result to the app's own stdout so an
+                // external controller driving the app over stdin (see
+                // `stdin_commands::ExternalCommand`) can read it - this is
+                // what makes script chaining from outside the app possible.
+                if let Some(value) = value {
+                    let path = self
+                        .current_script_path
+                        .as_ref()
+                        .map(|p| p.to_string_lossy().to_string());
+                    let payload = serde_json::json!({
+                        "type": "scriptExit",
+                        "path": path,
+                        "value": value,
+                    });
+                    println!("{}", payload);
+                    logging::log(
+                        "EXEC",
+                        &format!("Wrote script exit value to stdout for {:?}", path),
+                    );
+                }
                 let was_visible = script_kit_gpui::is_main_window_visible();
                 logging::log(
                     "VISIBILITY",
                     &format!("WINDOW_VISIBLE was: {}", was_visible),
                 );
 
-                // CRITICAL: Update visibility state so hotkey toggle works correctly
-                script_kit_gpui::set_main_window_visible(false);
-                logging::log("VISIBILITY", "WINDOW_VISIBLE set to: false");
+                // A script's own KeepOpen metadata overrides the hideOnExit
+                // config - either one asking to stay open is enough to skip
+                // the hide below.
+                let keep_open = self.current_script_keep_open || !self.config.get_hide_on_exit();
+
+                if !keep_open {
+                    // CRITICAL: Update visibility state so hotkey toggle works correctly
+                    script_kit_gpui::set_main_window_visible(false);
+                    logging::log("VISIBILITY", "WINDOW_VISIBLE set to: false");
+                }
 
                 // Set flag so next hotkey show will reset to script list
                 NEEDS_RESET.store(true, Ordering::SeqCst);
@@ -383,12 +981,49 @@ impl ScriptListApp {
                 self.reset_to_script_list(cx);
                 logging::log("VISIBILITY", "reset_to_script_list() called");
 
-                // Hide window when script completes - scripts only stay active while code is running
-                cx.hide();
-                logging::log(
-                    "VISIBILITY",
-                    "cx.hide() called - window hidden on script completion",
-                );
+                // Non-persisted widgets owned by this script shouldn't outlive it
+                if let Some(pid) = self.current_script_pid {
+                    widget_manager::close_widgets_for_exited_script(pid, cx);
+                }
+
+                // Global hotkeys the script registered via registerHotkey shouldn't
+                // outlive it either - force-unregister them now.
+                hotkeys::clear_session_hotkeys();
+
+                self.current_script_keep_open = false;
+
+                // Release this path's concurrency slot and dispatch the next
+                // queued launch (if this script's policy is `Queue`). The window
+                // stays visible/shown for the next run instead of hiding and
+                // immediately needing to reappear.
+                let mut dispatched_queued_run = false;
+                if let Some(path) = self.current_script_path.take() {
+                    if let Some(next_path) = concurrency_guard::CONCURRENCY_GUARD.finish(&path) {
+                        logging::log(
+                            "EXEC",
+                            &format!("Dispatching queued launch for {:?}", next_path),
+                        );
+                        self.execute_script_by_path(&next_path.to_string_lossy(), Vec::new(), cx);
+                        dispatched_queued_run = true;
+                    }
+                }
+                if dispatched_queued_run {
+                    return;
+                }
+
+                if keep_open {
+                    logging::log(
+                        "VISIBILITY",
+                        "Script requested keep-open - window left visible on completion",
+                    );
+                } else {
+                    // Hide window when script completes - scripts only stay active while code is running
+                    cx.hide();
+                    logging::log(
+                        "VISIBILITY",
+                        "cx.hide() called - window hidden on script completion",
+                    );
+                }
             }
             PromptMessage::HideWindow => {
                 logging::log("VISIBILITY", "=== HideWindow message received ===");
@@ -412,6 +1047,49 @@ impl ScriptListApp {
                     "cx.hide() called - window should now be hidden",
                 );
             }
+            PromptMessage::FocusWindow => {
+                logging::log("VISIBILITY", "=== FocusWindow message received ===");
+
+                if script_kit_gpui::is_main_window_visible() {
+                    logging::log(
+                        "VISIBILITY",
+                        "Window already visible - FocusWindow is a no-op",
+                    );
+                    return;
+                }
+
+                // Don't yank the window back open if the user just hid it themselves.
+                const EXPLICIT_HIDE_GUARD: std::time::Duration =
+                    std::time::Duration::from_millis(1500);
+                if self
+                    .last_explicit_hide
+                    .is_some_and(|hidden_at| hidden_at.elapsed() < EXPLICIT_HIDE_GUARD)
+                {
+                    logging::log(
+                        "VISIBILITY",
+                        "User explicitly hid the window recently - ignoring FocusWindow",
+                    );
+                    return;
+                }
+
+                script_kit_gpui::set_main_window_visible(true);
+                platform::ensure_move_to_active_space();
+
+                let window_size = gpui::size(px(750.), initial_window_height());
+                let bounds = platform::calculate_eye_line_bounds_on_mouse_display(
+                    window_size,
+                    self.config.get_window_position_mode(),
+                );
+                platform::move_first_window_to_bounds(&bounds);
+
+                cx.activate(true);
+                platform::focus_main_window();
+
+                logging::log(
+                    "VISIBILITY",
+                    "FocusWindow: window repositioned and brought forward",
+                );
+            }
             PromptMessage::OpenBrowser { url } => {
                 logging::log("UI", &format!("Opening browser: {}", url));
                 #[cfg(target_os = "macos")]
@@ -454,7 +1132,23 @@ impl ScriptListApp {
                     }
                 }
             }
-            PromptMessage::RunScript { path } => {
+            PromptMessage::OpenPath { path } => {
+                let expanded = shellexpand::tilde(&path).to_string();
+                platform::open_path_with_system_default(&expanded);
+            }
+            PromptMessage::SetTheme { name } => match theme::set_theme_by_name(&name) {
+                Ok(()) => {
+                    logging::log("THEME", &format!("Switched active theme to '{}'", name));
+                    // theme.json changed on disk; the global ThemeWatcher
+                    // (theme::service) picks this up and syncs all windows
+                    // within its normal ~200ms poll, same as an external edit.
+                }
+                Err(err) => {
+                    logging::log("ERROR", &format!("Failed to switch theme: {}", err));
+                    self.show_hud_positioned(err.to_string(), None, None, None, cx);
+                }
+            },
+            PromptMessage::RunScript { path, args } => {
                 logging::log("EXEC", &format!("RunScript command received: {}", path));
 
                 // Create a Script struct from the path
@@ -480,10 +1174,16 @@ impl ScriptListApp {
                     shortcut: None,
                     typed_metadata: None,
                     schema: None,
+                    concurrency: scripts::ScriptConcurrency::default(),
+                    tray: false,
+                    background: false,
+                    keep_open: false,
+                    kenv: None,
+                    app_filter: None,
                 };
 
                 logging::log("EXEC", &format!("Executing script: {}", script_name));
-                self.execute_interactive(&script, cx);
+                self.execute_interactive(&script, args, None, cx);
             }
             PromptMessage::ScriptError {
                 error_message,
@@ -492,6 +1192,7 @@ impl ScriptListApp {
                 stack_trace,
                 script_path,
                 suggestions,
+                log_path,
             } => {
                 logging::log(
                     "ERROR",
@@ -502,8 +1203,15 @@ impl ScriptListApp {
                 );
 
                 // Create error toast with expandable details
-                // Use stderr_output if available, otherwise use stack_trace
-                let details_text = stderr_output.clone().or_else(|| stack_trace.clone());
+                // Use stderr_output if available, otherwise use stack_trace,
+                // then append the per-run log path so it's one click away
+                let mut details_text = stderr_output.clone().or_else(|| stack_trace.clone());
+                if let Some(ref path) = log_path {
+                    details_text = Some(match details_text {
+                        Some(text) => format!("{}\n\nLog: {}", text, path),
+                        None => format!("Log: {}", path),
+                    });
+                }
                 let toast = Toast::error(error_message.clone(), &self.theme)
                     .details_opt(details_text.clone())
                     .duration_ms(Some(10000)); // 10 seconds for errors
@@ -526,6 +1234,34 @@ impl ScriptListApp {
                     toast
                 };
 
+                // If the first non-node_modules stack frame points at this
+                // script (not the SDK or a library), offer a jump-to-line
+                // action - most editors accept a `file:line` argument.
+                let frame = stack_trace
+                    .as_ref()
+                    .and_then(|st| executor::first_script_frame(st, &script_path));
+                let toast = if let Some(frame) = frame {
+                    let editor = self.config.get_editor();
+                    let target_path = frame.path.clone();
+                    let target_line = frame.line;
+                    toast.action(ToastAction::new(
+                        format!("Open at line {}", target_line),
+                        Box::new(move |_, _, _| {
+                            let path = std::path::Path::new(&target_path);
+                            if let Err(e) =
+                                script_creation::open_in_editor_at_line(&editor, path, target_line)
+                            {
+                                logging::log(
+                                    "ERROR",
+                                    &format!("Failed to open editor at line: {}", e),
+                                );
+                            }
+                        }),
+                    ))
+                } else {
+                    toast
+                };
+
                 // Log suggestions if present
                 if !suggestions.is_empty() {
                     logging::log("ERROR", &format!("Suggestions: {:?}", suggestions));
@@ -598,7 +1334,6 @@ impl ScriptListApp {
                     &format!("Collecting state for request: {}", request_id),
                 );
 
-                // Collect current UI state
                 let (
                     prompt_type,
                     prompt_id,
@@ -608,305 +1343,9 @@ impl ScriptListApp {
                     visible_choice_count,
                     selected_index,
                     selected_value,
-                ) = match &self.current_view {
-                    AppView::ScriptList => {
-                        let filtered_len = self.filtered_results().len();
-                        let selected_value = if self.selected_index < filtered_len {
-                            self.filtered_results()
-                                .get(self.selected_index)
-                                .map(|r| match r {
-                                    scripts::SearchResult::Script(m) => m.script.name.clone(),
-                                    scripts::SearchResult::Scriptlet(m) => m.scriptlet.name.clone(),
-                                    scripts::SearchResult::BuiltIn(m) => m.entry.name.clone(),
-                                    scripts::SearchResult::App(m) => m.app.name.clone(),
-                                    scripts::SearchResult::Window(m) => m.window.title.clone(),
-                                    scripts::SearchResult::Agent(m) => m.agent.name.clone(),
-                                    scripts::SearchResult::Fallback(m) => {
-                                        m.fallback.name().to_string()
-                                    }
-                                })
-                        } else {
-                            None
-                        };
-                        (
-                            "none".to_string(),
-                            None,
-                            None,
-                            self.filter_text.clone(),
-                            self.scripts.len()
-                                + self.scriptlets.len()
-                                + self.builtin_entries.len()
-                                + self.apps.len(),
-                            filtered_len,
-                            self.selected_index as i32,
-                            selected_value,
-                        )
-                    }
-                    AppView::ArgPrompt {
-                        id,
-                        placeholder,
-                        choices,
-                        actions: _,
-                    } => {
-                        let filtered = self.get_filtered_arg_choices(choices);
-                        let selected_value = if self.arg_selected_index < filtered.len() {
-                            filtered
-                                .get(self.arg_selected_index)
-                                .map(|c| c.value.clone())
-                        } else {
-                            None
-                        };
-                        (
-                            "arg".to_string(),
-                            Some(id.clone()),
-                            Some(placeholder.clone()),
-                            self.arg_input.text().to_string(),
-                            choices.len(),
-                            filtered.len(),
-                            self.arg_selected_index as i32,
-                            selected_value,
-                        )
-                    }
-                    AppView::DivPrompt { id, .. } => (
-                        "div".to_string(),
-                        Some(id.clone()),
-                        None,
-                        String::new(),
-                        0,
-                        0,
-                        -1,
-                        None,
-                    ),
-                    AppView::FormPrompt { id, .. } => (
-                        "form".to_string(),
-                        Some(id.clone()),
-                        None,
-                        String::new(),
-                        0,
-                        0,
-                        -1,
-                        None,
-                    ),
-                    AppView::TermPrompt { id, .. } => (
-                        "term".to_string(),
-                        Some(id.clone()),
-                        None,
-                        String::new(),
-                        0,
-                        0,
-                        -1,
-                        None,
-                    ),
-                    AppView::EditorPrompt { id, .. } => (
-                        "editor".to_string(),
-                        Some(id.clone()),
-                        None,
-                        String::new(),
-                        0,
-                        0,
-                        -1,
-                        None,
-                    ),
-                    AppView::SelectPrompt { id, .. } => (
-                        "select".to_string(),
-                        Some(id.clone()),
-                        None,
-                        String::new(),
-                        0,
-                        0,
-                        -1,
-                        None,
-                    ),
-                    AppView::PathPrompt { id, .. } => (
-                        "path".to_string(),
-                        Some(id.clone()),
-                        None,
-                        String::new(),
-                        0,
-                        0,
-                        -1,
-                        None,
-                    ),
-                    AppView::EnvPrompt { id, .. } => (
-                        "env".to_string(),
-                        Some(id.clone()),
-                        None,
-                        String::new(),
-                        0,
-                        0,
-                        -1,
-                        None,
-                    ),
-                    AppView::DropPrompt { id, .. } => (
-                        "drop".to_string(),
-                        Some(id.clone()),
-                        None,
-                        String::new(),
-                        0,
-                        0,
-                        -1,
-                        None,
-                    ),
-                    AppView::TemplatePrompt { id, .. } => (
-                        "template".to_string(),
-                        Some(id.clone()),
-                        None,
-                        String::new(),
-                        0,
-                        0,
-                        -1,
-                        None,
-                    ),
-                    AppView::ActionsDialog => (
-                        "actions".to_string(),
-                        None,
-                        None,
-                        String::new(),
-                        0,
-                        0,
-                        -1,
-                        None,
-                    ),
-                    // P0 FIX: View state only - data comes from self.cached_clipboard_entries
-                    AppView::ClipboardHistoryView {
-                        filter,
-                        selected_index,
-                    } => {
-                        let entries = &self.cached_clipboard_entries;
-                        let filtered_count = if filter.is_empty() {
-                            entries.len()
-                        } else {
-                            let filter_lower = filter.to_lowercase();
-                            entries
-                                .iter()
-                                .filter(|e| e.text_preview.to_lowercase().contains(&filter_lower))
-                                .count()
-                        };
-                        (
-                            "clipboardHistory".to_string(),
-                            None,
-                            None,
-                            filter.clone(),
-                            entries.len(),
-                            filtered_count,
-                            *selected_index as i32,
-                            None,
-                        )
-                    }
-                    // P0 FIX: View state only - data comes from self.apps
-                    AppView::AppLauncherView {
-                        filter,
-                        selected_index,
-                    } => {
-                        let apps = &self.apps;
-                        let filtered_count = if filter.is_empty() {
-                            apps.len()
-                        } else {
-                            let filter_lower = filter.to_lowercase();
-                            apps.iter()
-                                .filter(|a| a.name.to_lowercase().contains(&filter_lower))
-                                .count()
-                        };
-                        (
-                            "appLauncher".to_string(),
-                            None,
-                            None,
-                            filter.clone(),
-                            apps.len(),
-                            filtered_count,
-                            *selected_index as i32,
-                            None,
-                        )
-                    }
-                    // P0 FIX: View state only - data comes from self.cached_windows
-                    AppView::WindowSwitcherView {
-                        filter,
-                        selected_index,
-                    } => {
-                        let windows = &self.cached_windows;
-                        let filtered_count = if filter.is_empty() {
-                            windows.len()
-                        } else {
-                            let filter_lower = filter.to_lowercase();
-                            windows
-                                .iter()
-                                .filter(|w| {
-                                    w.title.to_lowercase().contains(&filter_lower)
-                                        || w.app.to_lowercase().contains(&filter_lower)
-                                })
-                                .count()
-                        };
-                        (
-                            "windowSwitcher".to_string(),
-                            None,
-                            None,
-                            filter.clone(),
-                            windows.len(),
-                            filtered_count,
-                            *selected_index as i32,
-                            None,
-                        )
-                    }
-                    AppView::DesignGalleryView {
-                        filter,
-                        selected_index,
-                    } => {
-                        let total_items = designs::separator_variations::SeparatorStyle::count()
-                            + designs::icon_variations::total_icon_count()
-                            + 8
-                            + 6; // headers
-                        (
-                            "designGallery".to_string(),
-                            None,
-                            None,
-                            filter.clone(),
-                            total_items,
-                            total_items,
-                            *selected_index as i32,
-                            None,
-                        )
-                    }
-                    AppView::ScratchPadView { .. } => (
-                        "scratchPad".to_string(),
-                        Some("scratch-pad".to_string()),
-                        None,
-                        String::new(),
-                        0,
-                        0,
-                        -1,
-                        None,
-                    ),
-                    AppView::QuickTerminalView { .. } => (
-                        "quickTerminal".to_string(),
-                        Some("quick-terminal".to_string()),
-                        None,
-                        String::new(),
-                        0,
-                        0,
-                        -1,
-                        None,
-                    ),
-                    AppView::FileSearchView {
-                        ref query,
-                        selected_index,
-                    } => (
-                        "fileSearch".to_string(),
-                        Some("file-search".to_string()),
-                        None,
-                        query.clone(),
-                        self.cached_file_results.len(),
-                        self.cached_file_results.len(),
-                        *selected_index as i32,
-                        self.cached_file_results
-                            .get(*selected_index)
-                            .map(|f| f.name.clone()),
-                    ),
-                };
-
-                // Focus state: we use focused_input as a proxy since we don't have Window access here.
-                // When window is visible and we're tracking an input, we're focused.
-                let window_visible = script_kit_gpui::is_main_window_visible();
-                let is_focused = window_visible && self.focused_input != FocusedInput::None;
+                    is_focused,
+                    window_visible,
+                ) = self.compute_state_fields();
 
                 // Create the response
                 let response = Message::state_result(
@@ -1128,8 +1567,18 @@ impl ScriptListApp {
                 key,
                 prompt,
                 secret,
+                pattern,
+                multiline,
             } => {
-                tracing::info!(id, key, ?prompt, secret, "ShowEnv received");
+                tracing::info!(
+                    id,
+                    key,
+                    ?prompt,
+                    secret,
+                    ?pattern,
+                    multiline,
+                    "ShowEnv received"
+                );
                 logging::log(
                     "UI",
                     &format!(
@@ -1170,6 +1619,8 @@ impl ScriptListApp {
                     key,
                     prompt,
                     secret,
+                    multiline,
+                    pattern,
                     focus_handle,
                     submit_callback,
                     std::sync::Arc::new(self.theme.clone()),
@@ -1302,11 +1753,76 @@ impl ScriptListApp {
                 resize_to_view_sync(ViewType::DivPrompt, 0);
                 cx.notify();
             }
+            PromptMessage::ShowConfirm {
+                id,
+                title,
+                message,
+                ok_label,
+                cancel_label,
+                destructive,
+            } => {
+                tracing::info!(id, ?title, message, destructive, "ShowConfirm received");
+                logging::log(
+                    "UI",
+                    &format!(
+                        "ShowConfirm prompt received: {} (destructive: {})",
+                        id, destructive
+                    ),
+                );
+
+                // Create submit callback for confirm prompt
+                let response_sender = self.response_sender.clone();
+                let submit_callback: std::sync::Arc<dyn Fn(String, Option<String>) + Send + Sync> =
+                    std::sync::Arc::new(move |id, value| {
+                        if let Some(ref sender) = response_sender {
+                            let response = Message::Submit { id, value };
+                            // Use try_send to avoid blocking UI thread
+                            match sender.try_send(response) {
+                                Ok(()) => {}
+                                Err(std::sync::mpsc::TrySendError::Full(_)) => {
+                                    logging::log(
+                                        "WARN",
+                                        "Response channel full - confirm response dropped",
+                                    );
+                                }
+                                Err(std::sync::mpsc::TrySendError::Disconnected(_)) => {
+                                    logging::log(
+                                        "UI",
+                                        "Response channel disconnected - script exited",
+                                    );
+                                }
+                            }
+                        }
+                    });
+
+                // Create ConfirmPrompt entity
+                let focus_handle = self.focus_handle.clone();
+                let confirm_prompt = prompts::ConfirmPrompt::new(
+                    id.clone(),
+                    title,
+                    message,
+                    ok_label,
+                    cancel_label,
+                    destructive,
+                    focus_handle,
+                    submit_callback,
+                    std::sync::Arc::new(self.theme.clone()),
+                );
+
+                let entity = cx.new(|_| confirm_prompt);
+                self.current_view = AppView::ConfirmPrompt { id, entity };
+                self.focused_input = FocusedInput::None;
+                self.pending_focus = Some(FocusTarget::ConfirmPrompt);
+
+                resize_to_view_sync(ViewType::ConfirmDialog, 0);
+                cx.notify();
+            }
             PromptMessage::ShowSelect {
                 id,
                 placeholder,
                 choices,
                 multiple,
+                max,
             } => {
                 tracing::info!(
                     id,
@@ -1357,6 +1873,7 @@ impl ScriptListApp {
                     placeholder,
                     choices,
                     multiple,
+                    max,
                     self.focus_handle.clone(),
                     submit_callback,
                     std::sync::Arc::new(self.theme.clone()),
@@ -1375,12 +1892,62 @@ impl ScriptListApp {
                 resize_to_view_sync(view_type, choice_count);
                 cx.notify();
             }
-            PromptMessage::ShowHud { text, duration_ms } => {
-                self.show_hud(text, duration_ms, cx);
+            PromptMessage::ShowHud {
+                text,
+                duration_ms,
+                position,
+                id,
+            } => {
+                self.show_hud_positioned(text, duration_ms, position, id, cx);
+            }
+            PromptMessage::UpdateHud {
+                id,
+                text,
+                duration_ms,
+            } => {
+                hud_manager::update_hud(&id, text, duration_ms, cx);
+            }
+            PromptMessage::ShowWidget { id, html, options } => {
+                match (self.current_script_pid, self.response_sender.clone()) {
+                    (Some(pid), Some(sender)) => {
+                        widget_manager::show_widget(id, html, options, pid, sender, cx);
+                    }
+                    _ => {
+                        logging::log(
+                            "WIDGET",
+                            &format!("Cannot create widget '{}': no active script", id),
+                        );
+                    }
+                }
+            }
+            PromptMessage::WidgetAction { id, action, state } => {
+                widget_manager::handle_widget_action(&id, action, state, cx);
             }
             PromptMessage::SetInput { text } => {
                 self.set_prompt_input(text, cx);
             }
+            PromptMessage::SetPlaceholder { text } => {
+                self.set_prompt_placeholder(text, cx);
+            }
+            PromptMessage::SetHint { text } => {
+                self.set_prompt_hint(text, cx);
+            }
+            PromptMessage::SetPreview { html } => {
+                self.set_split_preview(html, cx);
+            }
+            PromptMessage::Preview { value, content } => {
+                self.cache_choice_preview(value, content, cx);
+            }
+            PromptMessage::SetFilter { text } => {
+                self.set_script_filter(text, cx);
+            }
+            PromptMessage::SetPlaceholderChoices {
+                id,
+                choices,
+                loading,
+            } => {
+                self.set_arg_choices(id, choices, loading, cx);
+            }
             PromptMessage::SetActions { actions } => {
                 logging::log(
                     "ACTIONS",
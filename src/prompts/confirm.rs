@@ -0,0 +1,350 @@
+//! ConfirmPrompt - Yes/No confirmation dialog
+//!
+//! Features:
+//! - Title + markdown-lite message body
+//! - OK/Cancel buttons with Tab to switch focus, Enter to activate
+//! - Escape always cancels (submits "false") without killing the script
+//! - Destructive mode styles OK with error colors and starts focus on Cancel
+
+use gpui::{div, prelude::*, px, rgb, Context, FocusHandle, Focusable, Render, Window};
+use std::sync::Arc;
+
+use crate::components::button::{Button, ButtonColors, ButtonVariant};
+use crate::designs::{get_tokens, DesignVariant};
+use crate::logging;
+use crate::preview_doc::{self, MarkdownBlock};
+use crate::theme;
+use crate::ui_foundation::get_vibrancy_background;
+
+use super::SubmitCallback;
+
+/// Which button currently has keyboard focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmButton {
+    Ok,
+    Cancel,
+}
+
+impl ConfirmButton {
+    fn toggled(self) -> Self {
+        match self {
+            ConfirmButton::Ok => ConfirmButton::Cancel,
+            ConfirmButton::Cancel => ConfirmButton::Ok,
+        }
+    }
+}
+
+/// Which button should have keyboard focus when the dialog first appears.
+/// A destructive action shouldn't be the one Enter fires by default.
+fn default_focused_button(destructive: bool) -> ConfirmButton {
+    if destructive {
+        ConfirmButton::Cancel
+    } else {
+        ConfirmButton::Ok
+    }
+}
+
+/// What a key press should do to a ConfirmPrompt, independent of GPUI state -
+/// kept pure so the mapping is unit-testable without a live focus handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfirmKeyAction {
+    /// Switch focus between OK and Cancel
+    ToggleFocus,
+    /// Submit whichever button currently has focus
+    SubmitFocused,
+    /// Submit "false" unconditionally, regardless of focus
+    SubmitCancel,
+    /// Not a key this prompt handles
+    None,
+}
+
+fn resolve_key_action(key: &str) -> ConfirmKeyAction {
+    match key {
+        "tab" | "left" | "arrowleft" | "right" | "arrowright" => ConfirmKeyAction::ToggleFocus,
+        "enter" => ConfirmKeyAction::SubmitFocused,
+        "escape" => ConfirmKeyAction::SubmitCancel,
+        _ => ConfirmKeyAction::None,
+    }
+}
+
+/// ConfirmPrompt - a centered Yes/No dialog
+pub struct ConfirmPrompt {
+    /// Unique ID for this prompt instance
+    pub id: String,
+    /// Optional dialog title (bold, above the message)
+    pub title: Option<String>,
+    /// Body text, rendered as markdown-lite (headings + paragraphs)
+    pub message: String,
+    /// Label for the confirming button (defaults to "OK")
+    pub ok_label: String,
+    /// Label for the cancelling button (defaults to "Cancel")
+    pub cancel_label: String,
+    /// Whether the OK button is styled as a destructive action
+    pub destructive: bool,
+    /// Which button currently has keyboard focus
+    focused_button: ConfirmButton,
+    /// Focus handle for keyboard input
+    pub focus_handle: FocusHandle,
+    /// Callback when the user resolves the dialog - "true" for OK, "false" for Cancel
+    pub on_submit: SubmitCallback,
+    /// Theme for styling
+    pub theme: Arc<theme::Theme>,
+    /// Design variant for styling
+    pub design_variant: DesignVariant,
+}
+
+impl ConfirmPrompt {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        title: Option<String>,
+        message: String,
+        ok_label: Option<String>,
+        cancel_label: Option<String>,
+        destructive: bool,
+        focus_handle: FocusHandle,
+        on_submit: SubmitCallback,
+        theme: Arc<theme::Theme>,
+    ) -> Self {
+        logging::log(
+            "PROMPTS",
+            &format!(
+                "ConfirmPrompt::new id: {} (destructive: {})",
+                id, destructive
+            ),
+        );
+
+        Self {
+            id,
+            title,
+            message,
+            ok_label: ok_label.unwrap_or_else(|| "OK".to_string()),
+            cancel_label: cancel_label.unwrap_or_else(|| "Cancel".to_string()),
+            destructive,
+            focused_button: default_focused_button(destructive),
+            focus_handle,
+            on_submit,
+            theme,
+            design_variant: DesignVariant::Default,
+        }
+    }
+
+    fn submit_ok(&mut self) {
+        (self.on_submit)(self.id.clone(), Some("true".to_string()));
+    }
+
+    fn submit_cancel(&mut self) {
+        (self.on_submit)(self.id.clone(), Some("false".to_string()));
+    }
+
+    fn submit_focused(&mut self) {
+        match self.focused_button {
+            ConfirmButton::Ok => self.submit_ok(),
+            ConfirmButton::Cancel => self.submit_cancel(),
+        }
+    }
+}
+
+impl Focusable for ConfirmPrompt {
+    fn focus_handle(&self, _cx: &gpui::App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ConfirmPrompt {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let tokens = get_tokens(self.design_variant);
+        let colors = tokens.colors();
+        let spacing = tokens.spacing();
+
+        let handle_key = cx.listener(
+            |this: &mut Self,
+             event: &gpui::KeyDownEvent,
+             _window: &mut Window,
+             cx: &mut Context<Self>| {
+                let key_str = event.keystroke.key.to_lowercase();
+
+                match resolve_key_action(&key_str) {
+                    ConfirmKeyAction::ToggleFocus => {
+                        this.focused_button = this.focused_button.toggled();
+                        cx.notify();
+                    }
+                    ConfirmKeyAction::SubmitFocused => this.submit_focused(),
+                    ConfirmKeyAction::SubmitCancel => this.submit_cancel(),
+                    ConfirmKeyAction::None => {}
+                }
+            },
+        );
+
+        // VIBRANCY: Use foundation helper - returns None when vibrancy enabled (let Root handle bg)
+        let vibrancy_bg = get_vibrancy_background(&self.theme);
+
+        let (text_color, muted_color) = if self.design_variant == DesignVariant::Default {
+            (
+                rgb(self.theme.colors.text.primary),
+                rgb(self.theme.colors.text.secondary),
+            )
+        } else {
+            (rgb(colors.text_primary), rgb(colors.text_secondary))
+        };
+
+        let button_colors = if self.design_variant == DesignVariant::Default {
+            ButtonColors::from_theme(&self.theme)
+        } else {
+            ButtonColors::from_design(&colors)
+        };
+
+        let blocks = preview_doc::parse_minimal_markdown(&self.message);
+
+        let mut body = div().flex().flex_col().gap(px(spacing.gap_sm));
+        for block in &blocks {
+            body = body.child(match block {
+                MarkdownBlock::Heading(level, text) => div()
+                    .text_sm()
+                    .font_weight(if *level <= 2 {
+                        gpui::FontWeight::SEMIBOLD
+                    } else {
+                        gpui::FontWeight::MEDIUM
+                    })
+                    .text_color(text_color)
+                    .child(text.clone()),
+                MarkdownBlock::Paragraph(text) => {
+                    div().text_sm().text_color(muted_color).child(text.clone())
+                }
+            });
+        }
+
+        let ok_focused = self.focused_button == ConfirmButton::Ok;
+        let cancel_focused = self.focused_button == ConfirmButton::Cancel;
+
+        let ok_variant = if self.destructive {
+            ButtonVariant::Destructive
+        } else if ok_focused {
+            ButtonVariant::Primary
+        } else {
+            ButtonVariant::Ghost
+        };
+        let cancel_variant = if cancel_focused && !self.destructive {
+            ButtonVariant::Primary
+        } else {
+            ButtonVariant::Ghost
+        };
+
+        // Focus ring: a visible border around whichever button Enter will
+        // activate, since destructive mode already claims the fill color.
+        let ring_color = rgb(self.theme.colors.accent.selected);
+
+        div()
+            .id(gpui::ElementId::Name("window:confirm".into()))
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .w_full()
+            .h_full()
+            .when_some(vibrancy_bg, |d, bg| d.bg(bg))
+            .text_color(text_color)
+            .p(px(spacing.padding_lg))
+            .key_context("confirm_prompt")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap(px(spacing.gap_md))
+                    .max_w(px(420.))
+                    .when_some(self.title.clone(), |d, title| {
+                        d.child(
+                            div()
+                                .text_base()
+                                .font_weight(gpui::FontWeight::SEMIBOLD)
+                                .text_color(text_color)
+                                .child(title),
+                        )
+                    })
+                    .child(body)
+                    .child(
+                        div()
+                            .flex()
+                            .flex_row()
+                            .justify_end()
+                            .gap(px(spacing.gap_sm))
+                            .mt(px(spacing.padding_md))
+                            .child(
+                                div()
+                                    .rounded(px(8.))
+                                    .when(cancel_focused, |d| d.border_2().border_color(ring_color))
+                                    .child(
+                                        Button::new(self.cancel_label.clone(), button_colors)
+                                            .variant(cancel_variant)
+                                            .on_click(Box::new(cx.listener(
+                                                |this, _event, _window, _cx| {
+                                                    this.submit_cancel();
+                                                },
+                                            ))),
+                                    ),
+                            )
+                            .child(
+                                div()
+                                    .rounded(px(8.))
+                                    .when(ok_focused, |d| d.border_2().border_color(ring_color))
+                                    .child(
+                                        Button::new(self.ok_label.clone(), button_colors)
+                                            .variant(ok_variant)
+                                            .on_click(Box::new(cx.listener(
+                                                |this, _event, _window, _cx| {
+                                                    this.submit_ok();
+                                                },
+                                            ))),
+                                    ),
+                            ),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_focus_lands_on_ok_when_not_destructive() {
+        assert_eq!(default_focused_button(false), ConfirmButton::Ok);
+    }
+
+    #[test]
+    fn default_focus_lands_on_cancel_when_destructive() {
+        assert_eq!(default_focused_button(true), ConfirmButton::Cancel);
+    }
+
+    #[test]
+    fn tab_toggles_focus() {
+        assert_eq!(resolve_key_action("tab"), ConfirmKeyAction::ToggleFocus);
+        assert_eq!(ConfirmButton::Ok.toggled(), ConfirmButton::Cancel);
+        assert_eq!(ConfirmButton::Cancel.toggled(), ConfirmButton::Ok);
+    }
+
+    #[test]
+    fn arrow_keys_also_toggle_focus() {
+        for key in ["left", "arrowleft", "right", "arrowright"] {
+            assert_eq!(resolve_key_action(key), ConfirmKeyAction::ToggleFocus);
+        }
+    }
+
+    #[test]
+    fn enter_submits_whichever_button_is_focused() {
+        assert_eq!(resolve_key_action("enter"), ConfirmKeyAction::SubmitFocused);
+    }
+
+    #[test]
+    fn escape_always_submits_cancel() {
+        assert_eq!(resolve_key_action("escape"), ConfirmKeyAction::SubmitCancel);
+    }
+
+    #[test]
+    fn unrelated_keys_are_ignored() {
+        assert_eq!(resolve_key_action("a"), ConfirmKeyAction::None);
+        assert_eq!(resolve_key_action("space"), ConfirmKeyAction::None);
+    }
+}
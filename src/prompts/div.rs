@@ -268,8 +268,11 @@ impl DivPrompt {
 type LinkClickCallback = Arc<dyn Fn(&str, &mut gpui::App) + Send + Sync>;
 
 /// Style context for rendering HTML elements
+///
+/// `pub(crate)` so other HTML-rendering surfaces in the binary (e.g.
+/// `widget_manager`) can reuse the same renderer instead of duplicating it.
 #[derive(Clone)]
-struct RenderContext {
+pub(crate) struct RenderContext {
     /// Primary text color
     text_primary: u32,
     /// Secondary text color (for muted content)
@@ -289,7 +292,7 @@ struct RenderContext {
 }
 
 impl RenderContext {
-    fn from_theme(colors: &theme::ColorScheme) -> Self {
+    pub(crate) fn from_theme(colors: &theme::ColorScheme) -> Self {
         Self {
             text_primary: colors.text.primary,
             text_secondary: colors.text.secondary,
@@ -309,7 +312,7 @@ impl RenderContext {
 }
 
 /// Render a vector of HtmlElements as a GPUI Div
-fn render_elements(elements: &[HtmlElement], ctx: RenderContext) -> Div {
+pub(crate) fn render_elements(elements: &[HtmlElement], ctx: RenderContext) -> Div {
     let mut container = div().flex().flex_col().gap_2().w_full();
 
     for element in elements {
@@ -320,7 +323,7 @@ fn render_elements(elements: &[HtmlElement], ctx: RenderContext) -> Div {
 }
 
 /// Render a single HtmlElement as a GPUI element
-fn render_element(element: &HtmlElement, ctx: RenderContext) -> Div {
+pub(crate) fn render_element(element: &HtmlElement, ctx: RenderContext) -> Div {
     match element {
         HtmlElement::Text(text) => {
             // Text is a block with the text content
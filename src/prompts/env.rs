@@ -13,6 +13,7 @@ use gpui::{
     div, prelude::*, px, rgb, rgba, svg, Context, Div, FocusHandle, Focusable, Render,
     SharedString, Window,
 };
+use regex::Regex;
 use std::sync::Arc;
 
 use crate::components::TextInputState;
@@ -29,62 +30,233 @@ use super::SubmitCallback;
 /// Service name for keyring storage
 const KEYRING_SERVICE: &str = "com.scriptkit.env";
 
-/// Get a secret from the system keyring
-pub fn get_secret(key: &str) -> Option<String> {
-    let entry = keyring::Entry::new(KEYRING_SERVICE, key);
-    match entry {
-        Ok(entry) => match entry.get_password() {
-            Ok(value) => {
-                logging::log("KEYRING", &format!("Retrieved secret for key: {}", key));
-                Some(value)
-            }
-            Err(keyring::Error::NoEntry) => {
-                logging::log("KEYRING", &format!("No entry found for key: {}", key));
-                None
-            }
-            Err(e) => {
-                logging::log(
-                    "KEYRING",
-                    &format!("Error retrieving secret for key {}: {}", key, e),
-                );
-                None
-            }
-        },
+/// Maximum bytes per keyring entry. Chosen to stay well under the smallest
+/// common backend limit (Windows Credential Manager caps a credential blob
+/// at 2560 bytes); values larger than this are split across multiple entries.
+const KEYRING_CHUNK_SIZE: usize = 2000;
+
+/// Maximum number of chunks to store for a single value. Past this point we
+/// return a clear error instead of hammering the keyring backend with an
+/// unbounded number of entries.
+const MAX_KEYRING_CHUNKS: usize = 64;
+
+/// Storage backend for secrets, abstracted so tests can substitute a fake
+/// in place of the real OS keychain.
+trait SecretStore {
+    fn get(&self, key: &str) -> Result<String, keyring::Error>;
+    fn set(&self, key: &str, value: &str) -> Result<(), keyring::Error>;
+    fn delete(&self, key: &str) -> Result<(), keyring::Error>;
+}
+
+/// The real system keyring (keychain on macOS, Credential Manager on Windows).
+struct SystemKeyring;
+
+impl SecretStore for SystemKeyring {
+    fn get(&self, key: &str) -> Result<String, keyring::Error> {
+        keyring::Entry::new(KEYRING_SERVICE, key)?.get_password()
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), keyring::Error> {
+        keyring::Entry::new(KEYRING_SERVICE, key)?.set_password(value)
+    }
+
+    fn delete(&self, key: &str) -> Result<(), keyring::Error> {
+        keyring::Entry::new(KEYRING_SERVICE, key)?.delete_credential()
+    }
+}
+
+fn chunk_count_key(key: &str) -> String {
+    format!("{}.chunks", key)
+}
+
+fn chunk_key(key: &str, index: usize) -> String {
+    format!("{}.chunk.{}", key, index)
+}
+
+/// Split a string into chunks of at most `max_bytes` bytes without
+/// splitting a multi-byte UTF-8 character across chunk boundaries.
+fn split_into_chunks(value: &str, max_bytes: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for ch in value.chars() {
+        let ch_len = ch.len_utf8();
+        if current_len + ch_len > max_bytes && !current.is_empty() {
+            chunks.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+        current.push(ch);
+        current_len += ch_len;
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+fn get_single_secret_with_store(store: &dyn SecretStore, key: &str) -> Option<String> {
+    match store.get(key) {
+        Ok(value) => {
+            logging::log("KEYRING", &format!("Retrieved secret for key: {}", key));
+            Some(value)
+        }
+        Err(keyring::Error::NoEntry) => None,
         Err(e) => {
             logging::log(
                 "KEYRING",
-                &format!("Error creating keyring entry for key {}: {}", key, e),
+                &format!("Error retrieving secret for key {}: {}", key, e),
             );
             None
         }
     }
 }
 
-/// Set a secret in the system keyring
-pub fn set_secret(key: &str, value: &str) -> Result<(), String> {
-    let entry = keyring::Entry::new(KEYRING_SERVICE, key)
-        .map_err(|e| format!("Failed to create keyring entry: {}", e))?;
+fn get_chunked_secret_with_store(store: &dyn SecretStore, key: &str) -> Option<String> {
+    let count: usize = get_single_secret_with_store(store, &chunk_count_key(key))?
+        .parse()
+        .ok()?;
 
-    entry
-        .set_password(value)
-        .map_err(|e| format!("Failed to store secret: {}", e))?;
+    let mut value = String::new();
+    for i in 0..count {
+        value.push_str(&get_single_secret_with_store(store, &chunk_key(key, i))?);
+    }
+
+    logging::log(
+        "KEYRING",
+        &format!(
+            "Retrieved chunked secret for key: {} ({} chunks)",
+            key, count
+        ),
+    );
+    Some(value)
+}
+
+fn get_secret_with_store(store: &dyn SecretStore, key: &str) -> Option<String> {
+    get_single_secret_with_store(store, key).or_else(|| get_chunked_secret_with_store(store, key))
+}
+
+fn set_single_secret_with_store(
+    store: &dyn SecretStore,
+    key: &str,
+    value: &str,
+) -> Result<(), String> {
+    store
+        .set(key, value)
+        .map_err(|e| format!("Failed to store secret: {}", e))
+}
+
+fn delete_single_secret_with_store(store: &dyn SecretStore, key: &str) -> Result<(), String> {
+    store
+        .delete(key)
+        .map_err(|e| format!("Failed to delete secret: {}", e))
+}
 
-    logging::log("KEYRING", &format!("Stored secret for key: {}", key));
+fn delete_chunks_with_store(store: &dyn SecretStore, key: &str) {
+    if let Some(count) = get_single_secret_with_store(store, &chunk_count_key(key))
+        .and_then(|c| c.parse::<usize>().ok())
+    {
+        for i in 0..count {
+            let _ = delete_single_secret_with_store(store, &chunk_key(key, i));
+        }
+        let _ = delete_single_secret_with_store(store, &chunk_count_key(key));
+    }
+}
+
+fn set_secret_with_store(store: &dyn SecretStore, key: &str, value: &str) -> Result<(), String> {
+    if value.len() <= KEYRING_CHUNK_SIZE {
+        delete_chunks_with_store(store, key);
+        return set_single_secret_with_store(store, key, value);
+    }
+
+    let chunks = split_into_chunks(value, KEYRING_CHUNK_SIZE);
+    if chunks.len() > MAX_KEYRING_CHUNKS {
+        return Err(format!(
+            "Value for {} is too large to store in the system keyring ({} bytes, max {} bytes)",
+            key,
+            value.len(),
+            MAX_KEYRING_CHUNKS * KEYRING_CHUNK_SIZE
+        ));
+    }
+
+    // Switching from a single entry to chunked storage (or resizing the chunk
+    // count) - clear the old single entry first so stale data can't resurface.
+    let _ = delete_single_secret_with_store(store, key);
+    for (index, chunk) in chunks.iter().enumerate() {
+        set_single_secret_with_store(store, &chunk_key(key, index), chunk)?;
+    }
+    set_single_secret_with_store(store, &chunk_count_key(key), &chunks.len().to_string())?;
+
+    logging::log(
+        "KEYRING",
+        &format!(
+            "Stored chunked secret for key: {} ({} chunks)",
+            key,
+            chunks.len()
+        ),
+    );
     Ok(())
 }
 
-/// Delete a secret from the system keyring
+fn delete_secret_with_store(store: &dyn SecretStore, key: &str) -> Result<(), String> {
+    delete_chunks_with_store(store, key);
+    delete_single_secret_with_store(store, key)
+}
+
+/// Get a secret from the system keyring. Transparently reassembles values
+/// that were split across multiple chunked entries.
+pub fn get_secret(key: &str) -> Option<String> {
+    get_secret_with_store(&SystemKeyring, key)
+}
+
+/// Set a secret in the system keyring. Values larger than a single keyring
+/// entry can hold (see [`KEYRING_CHUNK_SIZE`]) are transparently chunked.
+pub fn set_secret(key: &str, value: &str) -> Result<(), String> {
+    set_secret_with_store(&SystemKeyring, key, value)
+}
+
+/// Delete a secret (and any of its chunks) from the system keyring
 #[allow(dead_code)]
 pub fn delete_secret(key: &str) -> Result<(), String> {
-    let entry = keyring::Entry::new(KEYRING_SERVICE, key)
-        .map_err(|e| format!("Failed to create keyring entry: {}", e))?;
+    delete_secret_with_store(&SystemKeyring, key)
+}
 
-    entry
-        .delete_credential()
-        .map_err(|e| format!("Failed to delete secret: {}", e))?;
+/// Compile an optional regex pattern, logging (and discarding) an invalid one
+/// rather than failing the whole prompt.
+fn compile_pattern(pattern: Option<String>) -> Option<Regex> {
+    pattern.and_then(|p| match Regex::new(&p) {
+        Ok(re) => Some(re),
+        Err(e) => {
+            logging::log("PROMPTS", &format!("Invalid env pattern '{}': {}", p, e));
+            None
+        }
+    })
+}
 
-    logging::log("KEYRING", &format!("Deleted secret for key: {}", key));
-    Ok(())
+/// Validate `text` against an optional pattern, returning an error message on mismatch.
+fn validate_against_pattern(pattern: Option<&Regex>, text: &str) -> Option<String> {
+    let pattern = pattern?;
+    if pattern.is_match(text) {
+        None
+    } else {
+        Some(format!("Value must match pattern: {}", pattern.as_str()))
+    }
+}
+
+/// Mask a multi-line secret down to its character count and first/last four
+/// characters, so a PEM key or JSON blob can't leak its structure through a
+/// per-character dot mask while still letting the user confirm it pasted right.
+fn mask_multiline_secret(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let count = chars.len();
+    if count <= 8 {
+        return "•".repeat(count);
+    }
+
+    let first: String = chars[..4].iter().collect();
+    let last: String = chars[count - 4..].iter().collect();
+    format!("{} chars: {}…{}", count, first, last)
 }
 
 /// EnvPrompt - Environment variable prompt with secure storage
@@ -100,6 +272,12 @@ pub struct EnvPrompt {
     pub prompt: Option<String>,
     /// Whether to mask input (for secrets)
     pub secret: bool,
+    /// Whether the input is a textarea-style field for multi-line values
+    pub multiline: bool,
+    /// Regex the submitted value must match, if any
+    pattern: Option<Regex>,
+    /// Error shown when the current value doesn't match `pattern`
+    validation_error: Option<String>,
     /// Text input state with selection and clipboard support
     input: TextInputState,
     /// Focus handle for keyboard input
@@ -115,26 +293,38 @@ pub struct EnvPrompt {
 }
 
 impl EnvPrompt {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: String,
         key: String,
         prompt: Option<String>,
         secret: bool,
+        multiline: bool,
+        pattern: Option<String>,
         focus_handle: FocusHandle,
         on_submit: SubmitCallback,
         theme: Arc<theme::Theme>,
     ) -> Self {
         logging::log(
             "PROMPTS",
-            &format!("EnvPrompt::new for key: {} (secret: {})", key, secret),
+            &format!(
+                "EnvPrompt::new for key: {} (secret: {}, multiline: {})",
+                key, secret, multiline
+            ),
         );
 
+        let mut input = TextInputState::new();
+        input.set_multiline(multiline);
+
         EnvPrompt {
             id,
             key,
             prompt,
             secret,
-            input: TextInputState::new(),
+            multiline,
+            pattern: compile_pattern(pattern),
+            validation_error: None,
+            input,
             focus_handle,
             on_submit,
             theme,
@@ -163,18 +353,27 @@ impl EnvPrompt {
         false
     }
 
-    /// Submit the entered value
-    fn submit(&mut self) {
-        let text = self.input.text();
-        if !text.is_empty() {
-            // Store in keyring if this is a secret
-            if self.secret {
-                if let Err(e) = set_secret(&self.key, text) {
-                    logging::log("ERROR", &format!("Failed to store secret: {}", e));
-                }
+    /// Submit the entered value. Blocked while the value doesn't match `pattern`.
+    fn submit(&mut self, cx: &mut Context<Self>) {
+        let text = self.input.text().to_string();
+        if text.is_empty() {
+            return;
+        }
+
+        if let Some(error) = validate_against_pattern(self.pattern.as_ref(), &text) {
+            self.validation_error = Some(error);
+            cx.notify();
+            return;
+        }
+        self.validation_error = None;
+
+        // Store in keyring if this is a secret
+        if self.secret {
+            if let Err(e) = set_secret(&self.key, &text) {
+                logging::log("ERROR", &format!("Failed to store secret: {}", e));
             }
-            (self.on_submit)(self.id.clone(), Some(text.to_string()));
         }
+        (self.on_submit)(self.id.clone(), Some(text));
     }
 
     /// Set the input text programmatically
@@ -192,14 +391,29 @@ impl EnvPrompt {
         (self.on_submit)(self.id.clone(), None);
     }
 
-    /// Get display text (masked if secret)
+    /// Get display text. Multi-line secrets are masked down to a character
+    /// count and the first/last four characters instead of one dot per
+    /// character, so the masked text can't leak the value's line structure.
     fn display_text(&self) -> String {
-        self.input.display_text(self.secret)
+        if self.secret {
+            if self.multiline {
+                mask_multiline_secret(self.input.text())
+            } else {
+                self.input.display_text(true)
+            }
+        } else {
+            self.input.text().to_string()
+        }
     }
 
     /// Render the text input with cursor and selection
     fn render_input_text(&self, text_primary: u32, accent_color: u32) -> Div {
         let text = self.display_text();
+
+        if self.multiline && !self.secret && text.contains('\n') {
+            return self.render_multiline_input_text(&text, text_primary, accent_color);
+        }
+
         let chars: Vec<char> = text.chars().collect();
         let cursor_pos = self.input.cursor();
         let has_selection = self.input.has_selection();
@@ -256,6 +470,68 @@ impl EnvPrompt {
                 .when(!after.is_empty(), |d: Div| d.child(div().child(after)))
         }
     }
+
+    /// Render a textarea-style value (one row per line), highlighting
+    /// selection and placing the cursor on whichever line it falls in.
+    fn render_multiline_input_text(&self, text: &str, text_primary: u32, accent_color: u32) -> Div {
+        let cursor_pos = self.input.cursor();
+        let has_selection = self.input.has_selection();
+        let selection_range = has_selection.then(|| self.input.selection().range());
+
+        let mut container = div().flex().flex_col().overflow_y_hidden();
+        let mut line_start = 0usize;
+
+        for line in text.split('\n') {
+            let chars: Vec<char> = line.chars().collect();
+            let line_len = chars.len();
+            let line_end = line_start + line_len;
+
+            let local_cursor = (cursor_pos >= line_start && cursor_pos <= line_end)
+                .then_some(cursor_pos - line_start);
+            let local_selection = selection_range.and_then(|(start, end)| {
+                let clipped_start = start.max(line_start).min(line_end);
+                let clipped_end = end.max(line_start).min(line_end);
+                (clipped_start < clipped_end)
+                    .then_some((clipped_start - line_start, clipped_end - line_start))
+            });
+
+            let mut row = div().flex().flex_row().items_center();
+
+            if let Some((start, end)) = local_selection {
+                let before: String = chars[..start].iter().collect();
+                let selected: String = chars[start..end].iter().collect();
+                let after: String = chars[end..].iter().collect();
+                row = row
+                    .when(!before.is_empty(), |d: Div| d.child(div().child(before)))
+                    .child(
+                        div()
+                            .bg(rgba((accent_color << 8) | 0x60))
+                            .text_color(rgb(0xffffff))
+                            .child(selected),
+                    )
+                    .when(!after.is_empty(), |d: Div| d.child(div().child(after)));
+            } else if let Some(pos) = local_cursor {
+                let before: String = chars[..pos].iter().collect();
+                let after: String = chars[pos..].iter().collect();
+                row = row
+                    .when(!before.is_empty(), |d: Div| d.child(div().child(before)))
+                    .child(
+                        div()
+                            .w(px(CURSOR_WIDTH))
+                            .h(px(CURSOR_HEIGHT_LG))
+                            .bg(rgb(text_primary)),
+                    )
+                    .when(!after.is_empty(), |d: Div| d.child(div().child(after)));
+            } else {
+                row = row.child(div().child(line.to_string()));
+            }
+
+            container = container.child(row);
+            line_start = line_end + 1; // account for the '\n' separator
+        }
+
+        container
+    }
 }
 
 impl Focusable for EnvPrompt {
@@ -280,8 +556,14 @@ impl Render for EnvPrompt {
 
                 // Handle submit/cancel first
                 match key_str.as_str() {
+                    "enter" if this.multiline && !modifiers.platform => {
+                        // Plain Enter inserts a newline in textarea mode; Cmd+Enter submits.
+                        this.input.insert_char('\n');
+                        cx.notify();
+                        return;
+                    }
                     "enter" => {
-                        this.submit();
+                        this.submit(cx);
                         return;
                     }
                     "escape" => {
@@ -428,5 +710,154 @@ impl Render for EnvPrompt {
                             .text_color(rgb(accent_color)),
                     ),
             )
+            // Validation error, shown below the header when the value doesn't match `pattern`
+            .when_some(self.validation_error.clone(), |d: Div, error| {
+                d.child(
+                    div()
+                        .w_full()
+                        .px(px(HEADER_PADDING_X))
+                        .pb(px(HEADER_PADDING_Y))
+                        .text_sm()
+                        .text_color(rgb(design_colors.error))
+                        .child(error),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    struct FakeKeyring {
+        values: Mutex<HashMap<String, String>>,
+    }
+
+    impl FakeKeyring {
+        fn new() -> Self {
+            Self {
+                values: Mutex::new(HashMap::new()),
+            }
+        }
+    }
+
+    impl SecretStore for FakeKeyring {
+        fn get(&self, key: &str) -> Result<String, keyring::Error> {
+            self.values
+                .lock()
+                .unwrap()
+                .get(key)
+                .cloned()
+                .ok_or(keyring::Error::NoEntry)
+        }
+
+        fn set(&self, key: &str, value: &str) -> Result<(), keyring::Error> {
+            self.values
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+
+        fn delete(&self, key: &str) -> Result<(), keyring::Error> {
+            self.values.lock().unwrap().remove(key);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_small_secret_roundtrips_as_single_entry() {
+        let store = FakeKeyring::new();
+        set_secret_with_store(&store, "KEY", "hello").unwrap();
+        assert_eq!(
+            get_secret_with_store(&store, "KEY"),
+            Some("hello".to_string())
+        );
+        assert_eq!(store.values.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_large_secret_is_chunked_and_reassembled() {
+        let store = FakeKeyring::new();
+        let big_value = "x".repeat(KEYRING_CHUNK_SIZE * 3 + 17);
+        set_secret_with_store(&store, "BIG", &big_value).unwrap();
+        assert_eq!(get_secret_with_store(&store, "BIG"), Some(big_value));
+        // One entry per chunk plus the chunk-count marker
+        assert_eq!(store.values.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_value_exceeding_max_chunks_errors_clearly() {
+        let store = FakeKeyring::new();
+        let huge = "x".repeat(KEYRING_CHUNK_SIZE * MAX_KEYRING_CHUNKS + 1);
+        let err = set_secret_with_store(&store, "HUGE", &huge).unwrap_err();
+        assert!(err.contains("too large"));
+        // Nothing should have been written to the store on failure
+        assert!(store.values.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_shrinking_a_chunked_value_clears_stale_chunks() {
+        let store = FakeKeyring::new();
+        let big_value = "x".repeat(KEYRING_CHUNK_SIZE * 3 + 17);
+        set_secret_with_store(&store, "KEY", &big_value).unwrap();
+        set_secret_with_store(&store, "KEY", "small").unwrap();
+        assert_eq!(
+            get_secret_with_store(&store, "KEY"),
+            Some("small".to_string())
+        );
+        // Only the single entry should remain; old chunks must be gone
+        assert_eq!(store.values.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_split_into_chunks_preserves_utf8_boundaries() {
+        let value = "é".repeat(10);
+        let chunks = split_into_chunks(&value, 3);
+        for chunk in &chunks {
+            assert!(chunk.is_char_boundary(chunk.len()));
+        }
+        assert_eq!(chunks.concat(), value);
+    }
+
+    #[test]
+    fn test_compile_pattern_valid() {
+        assert!(compile_pattern(Some("^[a-z]+$".to_string())).is_some());
+    }
+
+    #[test]
+    fn test_compile_pattern_invalid_is_discarded() {
+        assert!(compile_pattern(Some("[".to_string())).is_none());
+    }
+
+    #[test]
+    fn test_validate_against_pattern_blocks_mismatch() {
+        let re = Regex::new("^[0-9]+$").unwrap();
+        assert!(validate_against_pattern(Some(&re), "abc").is_some());
+        assert!(validate_against_pattern(Some(&re), "123").is_none());
+    }
+
+    #[test]
+    fn test_validate_against_pattern_none_always_passes() {
+        assert!(validate_against_pattern(None, "anything").is_none());
+    }
+
+    #[test]
+    fn test_mask_multiline_secret_short_value_fully_masked() {
+        assert_eq!(mask_multiline_secret("abcdef"), "••••••");
+    }
+
+    #[test]
+    fn test_mask_multiline_secret_shows_count_and_edges() {
+        let value = "-----BEGIN KEY-----\nMIIEvQIBADANBg\n-----END KEY-----";
+        let masked = mask_multiline_secret(value);
+        assert_eq!(
+            masked,
+            format!("{} chars: {}…{}", value.chars().count(), "----", "----")
+        );
+        // The body of the key must not appear in the masked output
+        assert!(!masked.contains("MIIEvQIBADANBg"));
     }
 }
@@ -11,10 +11,12 @@
 //! - `drop`: DropPrompt - Drag and drop (skeleton)
 //! - `template`: TemplatePrompt - String templates with placeholders (skeleton)
 //! - `select`: SelectPrompt - Multi-select with checkboxes (skeleton)
+//! - `confirm`: ConfirmPrompt - Yes/No confirmation dialog
 
 #![allow(dead_code)]
 
 pub mod base;
+mod confirm;
 pub mod div;
 mod drop;
 mod env;
@@ -36,6 +38,8 @@ pub use div::{ContainerOptions, ContainerPadding, DivPrompt};
 // These exports are ready for use in main.rs when AppView variants are added
 // The #[allow(unused_imports)] is temporary until main.rs integrations are complete
 #[allow(unused_imports)]
+pub use confirm::{ConfirmButton, ConfirmPrompt};
+#[allow(unused_imports)]
 pub use drop::DropPrompt;
 #[allow(unused_imports)]
 pub use env::EnvPrompt;
@@ -289,6 +289,12 @@ impl PathPrompt {
         cx.notify();
     }
 
+    /// Set the hint text shown in the footer programmatically
+    pub fn set_hint(&mut self, text: String, cx: &mut Context<Self>) {
+        self.hint = Some(text);
+        cx.notify();
+    }
+
     /// Navigate into a directory
     pub fn navigate_to(&mut self, path: &str, cx: &mut Context<Self>) {
         self.current_path = path.to_string();
@@ -473,6 +479,39 @@ impl PathPrompt {
             .get(self.selected_index)
             .map(|entry| PathInfo::new(entry.name.clone(), entry.path.clone(), entry.is_dir))
     }
+
+    /// Show a native Quick Look preview of the selected file (Cmd+Y).
+    ///
+    /// Bound to Cmd+Y rather than bare Space since Space needs to keep
+    /// typing into the filter. Fire-and-forget - `qlmanage` owns its own
+    /// preview window and we don't need to track or wait on it.
+    fn show_quick_look(&mut self) {
+        let Some(entry) = self.filtered_entries.get(self.selected_index) else {
+            return;
+        };
+        if entry.is_dir {
+            return;
+        }
+        #[cfg(target_os = "macos")]
+        {
+            let path = entry.path.clone();
+            match std::process::Command::new("qlmanage")
+                .arg("-p")
+                .arg(&path)
+                .spawn()
+            {
+                Ok(_) => logging::log("PROMPTS", &format!("Quick Look preview: {}", path)),
+                Err(e) => logging::log(
+                    "ERROR",
+                    &format!("Failed to launch Quick Look for {}: {}", path, e),
+                ),
+            }
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            logging::log("PROMPTS", "Quick Look is only available on macOS");
+        }
+    }
 }
 
 impl Focusable for PathPrompt {
@@ -513,6 +552,13 @@ impl Render for PathPrompt {
                     return;
                 }
 
+                // Cmd+Y shows a Quick Look preview of the selected file.
+                // Bare Space is reserved for typing into the filter.
+                if has_cmd && key_str == "y" {
+                    this.show_quick_look();
+                    return;
+                }
+
                 match key_str.as_str() {
                     "up" | "arrowup" => this.move_up(cx),
                     "down" | "arrowdown" => this.move_down(cx),
@@ -4,6 +4,9 @@
 //! - Select multiple items from a list
 //! - Toggle selection with Space
 //! - Filter choices by typing
+//! - Select-all / deselect-all with Cmd+A, scoped to the current filter
+//! - Range selection with Shift+Up/Down
+//! - Optional cap on how many choices may be checked at once
 //! - Submit selected items
 
 use gpui::{
@@ -16,6 +19,7 @@ use crate::logging;
 use crate::protocol::{generate_semantic_id, Choice};
 use crate::theme;
 use crate::ui_foundation::get_vibrancy_background;
+use crate::utils::normalize_for_search;
 
 use super::SubmitCallback;
 
@@ -28,6 +32,8 @@ pub struct SelectPrompt {
     pub id: String,
     /// Placeholder text for the search input
     pub placeholder: Option<String>,
+    /// Hint text shown under the input (dim, e.g. a keyboard shortcut reminder)
+    pub hint: Option<String>,
     /// Available choices
     pub choices: Vec<Choice>,
     /// Indices of selected choices
@@ -40,6 +46,11 @@ pub struct SelectPrompt {
     pub filter_text: String,
     /// Whether multiple selection is allowed
     pub multiple: bool,
+    /// Maximum number of choices that may be checked at once, if capped
+    pub max: Option<usize>,
+    /// Brief message shown when an action is refused (e.g. the max limit),
+    /// cleared on the next selection change
+    pub limit_hint: Option<String>,
     /// Focus handle for keyboard input
     pub focus_handle: FocusHandle,
     /// Callback when user submits
@@ -56,6 +67,7 @@ impl SelectPrompt {
         placeholder: Option<String>,
         choices: Vec<Choice>,
         multiple: bool,
+        max: Option<usize>,
         focus_handle: FocusHandle,
         on_submit: SubmitCallback,
         theme: Arc<theme::Theme>,
@@ -74,12 +86,15 @@ impl SelectPrompt {
         SelectPrompt {
             id,
             placeholder,
+            hint: None,
             choices,
             selected: Vec::new(),
             filtered_choices,
             focused_index: 0,
             filter_text: String::new(),
             multiple,
+            max,
+            limit_hint: None,
             focus_handle,
             on_submit,
             theme,
@@ -89,12 +104,12 @@ impl SelectPrompt {
 
     /// Refilter choices based on current filter_text
     fn refilter(&mut self) {
-        let filter_lower = self.filter_text.to_lowercase();
+        let filter_norm = normalize_for_search(&self.filter_text);
         self.filtered_choices = self
             .choices
             .iter()
             .enumerate()
-            .filter(|(_, choice)| choice.name.to_lowercase().contains(&filter_lower))
+            .filter(|(_, choice)| normalize_for_search(&choice.name).contains(&filter_norm))
             .map(|(idx, _)| idx)
             .collect();
         self.focused_index = 0;
@@ -111,23 +126,89 @@ impl SelectPrompt {
         cx.notify();
     }
 
+    /// Set the placeholder text programmatically
+    pub fn set_placeholder(&mut self, text: String, cx: &mut Context<Self>) {
+        self.placeholder = Some(text);
+        cx.notify();
+    }
+
+    /// Set the hint text programmatically
+    pub fn set_hint(&mut self, text: String, cx: &mut Context<Self>) {
+        self.hint = Some(text);
+        cx.notify();
+    }
+
     /// Toggle selection of currently focused item
     fn toggle_selection(&mut self, cx: &mut Context<Self>) {
         if let Some(&choice_idx) = self.filtered_choices.get(self.focused_index) {
             if self.multiple {
-                if let Some(pos) = self.selected.iter().position(|&x| x == choice_idx) {
-                    self.selected.remove(pos);
+                if self.selected.contains(&choice_idx) {
+                    self.uncheck(choice_idx);
                 } else {
-                    self.selected.push(choice_idx);
+                    self.check(choice_idx);
                 }
             } else {
-                // Single select mode - replace selection
+                // Single select mode - replace selection (max doesn't apply)
                 self.selected = vec![choice_idx];
+                self.limit_hint = None;
             }
             cx.notify();
         }
     }
 
+    /// Toggle (or, in single-select mode, select) the choice at `display_idx`
+    /// in the currently filtered list, moving focus there first. Used by the
+    /// Cmd+1..9 quick-select shortcuts - see `quick_select_hint`.
+    fn quick_toggle(&mut self, display_idx: usize, cx: &mut Context<Self>) {
+        let Some(&choice_idx) = self.filtered_choices.get(display_idx) else {
+            return;
+        };
+        self.focused_index = display_idx;
+        if self.multiple {
+            if self.selected.contains(&choice_idx) {
+                self.uncheck(choice_idx);
+            } else {
+                self.check(choice_idx);
+            }
+        } else {
+            self.selected = vec![choice_idx];
+            self.limit_hint = None;
+        }
+        cx.notify();
+    }
+
+    /// The Cmd+N quick-select hint ("⌘1".."⌘9") for the choice at
+    /// `display_idx` in the currently filtered list, or `None` if
+    /// quick-select doesn't apply to that row: more than 9 choices are
+    /// visible. Unlike the arg prompt (`ScriptListApp::arg_quick_select_hint`),
+    /// this component has no script-registered action shortcuts to
+    /// conflict with, so there's nothing else to check here.
+    fn quick_select_hint(&self, display_idx: usize) -> Option<String> {
+        quick_select_digit(self.filtered_choices.len(), display_idx).map(|d| format!("⌘{}", d))
+    }
+
+    /// Check a single choice, refusing (and setting `limit_hint`) if `max` is already reached.
+    /// No-op if already checked. Returns true if the choice ends up checked.
+    fn check(&mut self, choice_idx: usize) -> bool {
+        let (next, ok) =
+            check_within_limit(std::mem::take(&mut self.selected), choice_idx, self.max);
+        self.selected = next;
+        self.limit_hint = if ok {
+            None
+        } else {
+            self.max.map(|max| format!("Max {} selected", max))
+        };
+        ok
+    }
+
+    /// Uncheck a single choice, clearing any limit hint since there's now room again.
+    fn uncheck(&mut self, choice_idx: usize) {
+        if let Some(pos) = self.selected.iter().position(|&x| x == choice_idx) {
+            self.selected.remove(pos);
+            self.limit_hint = None;
+        }
+    }
+
     /// Submit selected items as JSON array
     fn submit(&mut self) {
         let selected_values: Vec<String> = self
@@ -146,22 +227,43 @@ impl SelectPrompt {
         (self.on_submit)(self.id.clone(), None);
     }
 
-    /// Move focus up
-    fn move_up(&mut self, cx: &mut Context<Self>) {
+    /// Move focus up, optionally extending the selection over the newly
+    /// focused item (Shift+Up range selection).
+    fn move_up(&mut self, extend_selection: bool, cx: &mut Context<Self>) {
         if self.focused_index > 0 {
+            if extend_selection && self.multiple {
+                self.check_focused();
+            }
             self.focused_index -= 1;
+            if extend_selection && self.multiple {
+                self.check_focused();
+            }
             cx.notify();
         }
     }
 
-    /// Move focus down
-    fn move_down(&mut self, cx: &mut Context<Self>) {
+    /// Move focus down, optionally extending the selection over the newly
+    /// focused item (Shift+Down range selection).
+    fn move_down(&mut self, extend_selection: bool, cx: &mut Context<Self>) {
         if self.focused_index < self.filtered_choices.len().saturating_sub(1) {
+            if extend_selection && self.multiple {
+                self.check_focused();
+            }
             self.focused_index += 1;
+            if extend_selection && self.multiple {
+                self.check_focused();
+            }
             cx.notify();
         }
     }
 
+    /// Check the choice under the current focus, if any.
+    fn check_focused(&mut self) {
+        if let Some(&choice_idx) = self.filtered_choices.get(self.focused_index) {
+            self.check(choice_idx);
+        }
+    }
+
     /// Handle character input
     fn handle_char(&mut self, ch: char, cx: &mut Context<Self>) {
         self.filter_text.push(ch);
@@ -178,20 +280,36 @@ impl SelectPrompt {
         }
     }
 
-    /// Select all choices (Ctrl+A)
+    /// Select all *currently filtered* choices (Cmd/Ctrl+A). Items hidden by
+    /// the filter that were already checked are left untouched. Stops (with
+    /// `limit_hint` set) as soon as `max` is reached.
     fn select_all(&mut self, cx: &mut Context<Self>) {
         if self.multiple {
-            // Select all filtered choices
-            self.selected = self.filtered_choices.clone();
+            let (next, hit_limit) =
+                select_all_within_limit(&self.selected, &self.filtered_choices, self.max);
+            self.selected = next;
+            self.limit_hint = hit_limit.map(|max| format!("Max {} selected", max));
             cx.notify();
         }
     }
 
-    /// Deselect all choices
+    /// Deselect all *currently filtered* choices. Items hidden by the
+    /// filter that were already checked are left untouched.
     fn deselect_all(&mut self, cx: &mut Context<Self>) {
-        self.selected.clear();
+        self.selected = deselect_filtered(&self.selected, &self.filtered_choices);
+        self.limit_hint = None;
         cx.notify();
     }
+
+    /// Whether every currently filtered choice is checked (used to decide
+    /// what Cmd/Ctrl+A should do next).
+    fn all_filtered_selected(&self) -> bool {
+        !self.filtered_choices.is_empty()
+            && self
+                .filtered_choices
+                .iter()
+                .all(|idx| self.selected.contains(idx))
+    }
 }
 
 impl Focusable for SelectPrompt {
@@ -215,10 +333,9 @@ impl Render for SelectPrompt {
                 let key_str = event.keystroke.key.to_lowercase();
                 let has_ctrl = event.keystroke.modifiers.platform; // Cmd on macOS, Ctrl on others
 
-                // Handle Ctrl/Cmd+A for select all
+                // Handle Ctrl/Cmd+A for select all, scoped to the current filter
                 if has_ctrl && key_str == "a" {
-                    if this.selected.len() == this.filtered_choices.len() {
-                        // All selected, so deselect all
+                    if this.all_filtered_selected() {
                         this.deselect_all(cx);
                     } else {
                         this.select_all(cx);
@@ -226,9 +343,27 @@ impl Render for SelectPrompt {
                     return;
                 }
 
+                // Cmd+1..9 quick-select/toggle - see `quick_select_hint` for
+                // when this is actually eligible (<=9 visible choices).
+                if has_ctrl {
+                    if let Some(digit) = key_str
+                        .chars()
+                        .next()
+                        .filter(|c| key_str.len() == 1 && c.is_ascii_digit())
+                        .and_then(|c| c.to_digit(10))
+                    {
+                        if digit >= 1 && this.quick_select_hint((digit - 1) as usize).is_some() {
+                            this.quick_toggle((digit - 1) as usize, cx);
+                            return;
+                        }
+                    }
+                }
+
+                let has_shift = event.keystroke.modifiers.shift;
+
                 match key_str.as_str() {
-                    "up" | "arrowup" => this.move_up(cx),
-                    "down" | "arrowdown" => this.move_down(cx),
+                    "up" | "arrowup" => this.move_up(has_shift, cx),
+                    "down" | "arrowdown" => this.move_down(has_shift, cx),
                     "space" | " " => this.toggle_selection(cx),
                     "enter" => this.submit(),
                     "escape" => this.submit_cancel(),
@@ -301,12 +436,19 @@ impl Render for SelectPrompt {
                     })
                     .child(input_display),
             )
-            .child(
-                div()
-                    .text_sm()
-                    .text_color(muted_color)
-                    .child(format!("{} selected", self.selected.len())),
-            );
+            .child(div().text_sm().text_color(muted_color).child(format!(
+                "{} of {} selected",
+                self.selected.len(),
+                self.choices.len()
+            )))
+            .when_some(self.limit_hint.clone(), |d, hint| {
+                d.child(
+                    div()
+                        .text_sm()
+                        .text_color(rgb(self.theme.colors.ui.warning))
+                        .child(hint),
+                )
+            });
 
         // Choices list
         let mut choices_container = div()
@@ -378,6 +520,16 @@ impl Render for SelectPrompt {
                             .child(div().text_sm().text_color(muted_color).child(desc.clone()));
                     }
 
+                    if let Some(hint) = self.quick_select_hint(display_idx) {
+                        choice_item = choice_item.child(
+                            div()
+                                .text_xs()
+                                .text_color(muted_color)
+                                .flex_shrink_0()
+                                .child(hint),
+                        );
+                    }
+
                     choices_container = choices_container.child(choice_item);
                 }
             }
@@ -396,5 +548,159 @@ impl Render for SelectPrompt {
             .on_key_down(handle_key)
             .child(input_container)
             .child(choices_container)
+            .when_some(self.hint.clone(), |d, hint| {
+                d.child(
+                    div()
+                        .w_full()
+                        .px(px(spacing.item_padding_x))
+                        .py(px(spacing.padding_sm))
+                        .text_xs()
+                        .text_color(muted_color)
+                        .child(hint),
+                )
+            })
+    }
+}
+
+/// Add `choice_idx` to `selected`, refusing if `max` is already reached.
+/// No-op (and reports checked) if it's already present. Pulled out of
+/// `SelectPrompt` so the cap logic is testable without a GPUI `Context`.
+fn check_within_limit(
+    mut selected: Vec<usize>,
+    choice_idx: usize,
+    max: Option<usize>,
+) -> (Vec<usize>, bool) {
+    if selected.contains(&choice_idx) {
+        return (selected, true);
+    }
+    if let Some(max) = max {
+        if selected.len() >= max {
+            return (selected, false);
+        }
+    }
+    selected.push(choice_idx);
+    (selected, true)
+}
+
+/// Select every choice in `filtered`, preserving entries already in
+/// `selected` that aren't in `filtered` (selections hidden by the filter).
+/// Stops as soon as `max` is reached, returning it as the second element.
+fn select_all_within_limit(
+    selected: &[usize],
+    filtered: &[usize],
+    max: Option<usize>,
+) -> (Vec<usize>, Option<usize>) {
+    let mut result = selected.to_vec();
+    for &choice_idx in filtered {
+        let (next, ok) = check_within_limit(result, choice_idx, max);
+        result = next;
+        if !ok {
+            return (result, max);
+        }
+    }
+    (result, None)
+}
+
+/// Remove every choice in `filtered` from `selected`, leaving selections
+/// hidden by the filter untouched.
+fn deselect_filtered(selected: &[usize], filtered: &[usize]) -> Vec<usize> {
+    let hidden: std::collections::HashSet<usize> = filtered.iter().copied().collect();
+    selected
+        .iter()
+        .copied()
+        .filter(|idx| !hidden.contains(idx))
+        .collect()
+}
+
+/// The Cmd+N quick-select digit (1..9) for the row at `display_idx` out of
+/// `filtered_len` currently-visible choices, or `None` when quick-select
+/// doesn't apply to that row - more than 9 choices are visible, or the row
+/// itself is beyond the ninth.
+fn quick_select_digit(filtered_len: usize, display_idx: usize) -> Option<usize> {
+    if filtered_len > 9 || display_idx >= 9 {
+        return None;
+    }
+    Some(display_idx + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_all_only_affects_filtered_items() {
+        // Choice 0 was checked while hidden by an earlier filter.
+        let selected = vec![0];
+        let filtered = vec![1, 2, 3];
+
+        let (next, hit_limit) = select_all_within_limit(&selected, &filtered, None);
+
+        assert!(hit_limit.is_none());
+        assert_eq!(next, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn deselect_all_only_affects_filtered_items() {
+        let selected = vec![0, 1, 2, 3];
+        let filtered = vec![1, 2, 3];
+
+        let next = deselect_filtered(&selected, &filtered);
+
+        // Choice 0, hidden by the filter, stays checked.
+        assert_eq!(next, vec![0]);
+    }
+
+    #[test]
+    fn select_all_stops_exactly_at_max() {
+        let selected: Vec<usize> = vec![];
+        let filtered = vec![0, 1, 2, 3];
+
+        let (next, hit_limit) = select_all_within_limit(&selected, &filtered, Some(2));
+
+        assert_eq!(hit_limit, Some(2));
+        assert_eq!(next, vec![0, 1]);
+    }
+
+    #[test]
+    fn check_within_limit_allows_exactly_max_then_refuses_one_more() {
+        let mut selected: Vec<usize> = vec![];
+        let max = Some(2);
+
+        let (next, ok) = check_within_limit(selected, 0, max);
+        assert!(ok);
+        selected = next;
+
+        let (next, ok) = check_within_limit(selected, 1, max);
+        assert!(ok);
+        selected = next;
+
+        // Third choice pushes past the cap and is refused.
+        let (next, ok) = check_within_limit(selected, 2, max);
+        assert!(!ok);
+        assert_eq!(next, vec![0, 1]);
+    }
+
+    #[test]
+    fn check_within_limit_rechecking_already_selected_item_is_a_noop_not_a_refusal() {
+        let selected = vec![0, 1];
+        let (next, ok) = check_within_limit(selected, 1, Some(2));
+        assert!(ok);
+        assert_eq!(next, vec![0, 1]);
+    }
+
+    #[test]
+    fn quick_select_digit_numbers_rows_from_one() {
+        assert_eq!(quick_select_digit(5, 0), Some(1));
+        assert_eq!(quick_select_digit(5, 4), Some(5));
+    }
+
+    #[test]
+    fn quick_select_digit_none_beyond_ninth_row() {
+        assert_eq!(quick_select_digit(9, 9), None);
+    }
+
+    #[test]
+    fn quick_select_digit_none_when_more_than_nine_visible() {
+        assert_eq!(quick_select_digit(10, 0), None);
     }
 }
@@ -5,11 +5,14 @@
 //! - Tab through placeholders to fill them in
 //! - Live preview of filled template
 //! - Submit returns the filled template string
+//! - `{{name/transform}}` mirrors another field's value through a transform
+//!   (supported: `upcase`, `downcase`, `capitalize`); unknown transforms are
+//!   ignored and the raw value is echoed instead
 
 use gpui::{
     div, prelude::*, px, rgb, Context, FocusHandle, Focusable, Render, SharedString, Window,
 };
-use regex::Regex;
+use regex::{Captures, Regex};
 use std::collections::HashSet;
 use std::sync::Arc;
 
@@ -29,6 +32,44 @@ pub struct TemplateInput {
     pub placeholder: String,
 }
 
+/// A text transform applied when a placeholder mirrors another field's value.
+///
+/// Written as `{{name/transform}}`, e.g. `{{name/upcase}}` echoes the
+/// `{{name}}` field's current value in uppercase elsewhere in the template.
+/// Supported transforms: `upcase`, `downcase`, `capitalize`. An unrecognized
+/// transform name is ignored - the mirror just echoes the raw value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Transform {
+    Upcase,
+    Downcase,
+    Capitalize,
+}
+
+impl Transform {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "upcase" => Some(Transform::Upcase),
+            "downcase" => Some(Transform::Downcase),
+            "capitalize" => Some(Transform::Capitalize),
+            _ => None,
+        }
+    }
+
+    fn apply(self, value: &str) -> String {
+        match self {
+            Transform::Upcase => value.to_uppercase(),
+            Transform::Downcase => value.to_lowercase(),
+            Transform::Capitalize => {
+                let mut chars = value.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+        }
+    }
+}
+
 /// TemplatePrompt - Tab-through template editor
 ///
 /// Allows editing template strings with {{placeholder}} syntax.
@@ -109,39 +150,62 @@ impl TemplatePrompt {
         inputs
     }
 
-    /// Get the filled template string by replacing all placeholders
-    pub fn filled_template(&self) -> String {
-        let mut result = self.template.clone();
-
-        for (input, value) in self.inputs.iter().zip(self.values.iter()) {
-            let placeholder = format!("{{{{{}}}}}", input.name);
-            let replacement = if value.is_empty() {
-                // Show placeholder name if empty
-                format!("{{{{{}}}}}", input.name)
-            } else {
-                value.clone()
+    /// Replace every `{{name}}` / `{{name/transform}}` occurrence in
+    /// `template` with the matching `inputs`/`values` entry, applying the
+    /// transform when one is present and recognized. `on_empty` decides
+    /// what an occurrence renders as while its field is still empty.
+    ///
+    /// A placeholder naming a field that doesn't exist, or a `/transform`
+    /// suffix that isn't one of the supported transforms, is left as-is
+    /// (no error, no partial replacement) rather than rejected. Kept as an
+    /// associated function of plain data (no `&self`) so it's testable
+    /// without a live GPUI context.
+    fn render_placeholders(
+        template: &str,
+        inputs: &[TemplateInput],
+        values: &[String],
+        on_empty: impl Fn(&str, &str) -> String,
+    ) -> String {
+        let re = Regex::new(r"\{\{(\w+)(?:/(\w+))?\}\}").expect("Invalid regex");
+
+        re.replace_all(template, |caps: &Captures| {
+            let name = &caps[1];
+            let full_match = &caps[0];
+            let Some(idx) = inputs.iter().position(|input| input.name == name) else {
+                return full_match.to_string();
             };
-            result = result.replace(&placeholder, &replacement);
-        }
+            let value = &values[idx];
+            if value.is_empty() {
+                return on_empty(name, full_match);
+            }
+            match caps.get(2).and_then(|m| Transform::parse(m.as_str())) {
+                Some(transform) => transform.apply(value),
+                None => value.clone(),
+            }
+        })
+        .into_owned()
+    }
 
-        result
+    /// Get the filled template string by replacing all placeholders
+    pub fn filled_template(&self) -> String {
+        // Show the placeholder itself (e.g. `{{name}}`) if its field is empty
+        Self::render_placeholders(
+            &self.template,
+            &self.inputs,
+            &self.values,
+            |_name, full_match| full_match.to_string(),
+        )
     }
 
     /// Get the preview string - shows filled values or placeholder hints
     fn preview_template(&self) -> String {
-        let mut result = self.template.clone();
-
-        for (input, value) in self.inputs.iter().zip(self.values.iter()) {
-            let placeholder = format!("{{{{{}}}}}", input.name);
-            let replacement = if value.is_empty() {
-                format!("[{}]", input.name) // Show as [name] when empty
-            } else {
-                value.clone()
-            };
-            result = result.replace(&placeholder, &replacement);
-        }
-
-        result
+        // Show as [name] when empty
+        Self::render_placeholders(
+            &self.template,
+            &self.inputs,
+            &self.values,
+            |name, _full_match| format!("[{}]", name),
+        )
     }
 
     /// Set the current input value programmatically
@@ -157,12 +221,12 @@ impl TemplatePrompt {
 
     /// Submit the filled template
     fn submit(&mut self) {
-        // Replace placeholders with actual values for final submission
-        let mut result = self.template.clone();
-        for (input, value) in self.inputs.iter().zip(self.values.iter()) {
-            let placeholder = format!("{{{{{}}}}}", input.name);
-            result = result.replace(&placeholder, value);
-        }
+        // Replace placeholders with actual values for final submission;
+        // empty fields resolve to an empty string rather than staying visible.
+        let result =
+            Self::render_placeholders(&self.template, &self.inputs, &self.values, |_, _| {
+                String::new()
+            });
         (self.on_submit)(self.id.clone(), Some(result));
     }
 
@@ -445,4 +509,84 @@ mod tests {
         assert_eq!(inputs[0].name, "field1");
         assert_eq!(inputs[1].name, "field2");
     }
+
+    #[test]
+    fn test_parse_ignores_transform_suffix() {
+        // {{name/upcase}} mirrors the {{name}} field - it isn't a field of its own
+        let inputs = TemplatePrompt::parse_template_inputs("Hi {{name}}, aka {{name/upcase}}!");
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].name, "name");
+    }
+
+    fn filled(template: &str, values: &[&str]) -> String {
+        let inputs = TemplatePrompt::parse_template_inputs(template);
+        assert_eq!(inputs.len(), values.len(), "test setup mismatch");
+        let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+        TemplatePrompt::render_placeholders(template, &inputs, &values, |_name, full_match| {
+            full_match.to_string()
+        })
+    }
+
+    fn previewed(template: &str, values: &[&str]) -> String {
+        let inputs = TemplatePrompt::parse_template_inputs(template);
+        assert_eq!(inputs.len(), values.len(), "test setup mismatch");
+        let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+        TemplatePrompt::render_placeholders(template, &inputs, &values, |name, _full_match| {
+            format!("[{}]", name)
+        })
+    }
+
+    #[test]
+    fn test_filled_template_applies_upcase_transform() {
+        assert_eq!(
+            filled("Hi {{name}}, aka {{name/upcase}}!", &["ada"]),
+            "Hi ada, aka ADA!"
+        );
+    }
+
+    #[test]
+    fn test_filled_template_applies_downcase_transform() {
+        assert_eq!(
+            filled("{{name}} / {{name/downcase}}", &["ADA"]),
+            "ADA / ada"
+        );
+    }
+
+    #[test]
+    fn test_filled_template_applies_capitalize_transform() {
+        assert_eq!(
+            filled("{{name}} / {{name/capitalize}}", &["ada"]),
+            "ada / Ada"
+        );
+    }
+
+    #[test]
+    fn test_filled_template_ignores_unknown_transform() {
+        assert_eq!(filled("{{name}} / {{name/reverse}}", &["ada"]), "ada / ada");
+    }
+
+    #[test]
+    fn test_filled_template_keeps_empty_transform_placeholder_visible() {
+        assert_eq!(
+            filled("{{name}} / {{name/upcase}}", &[""]),
+            "{{name}} / {{name/upcase}}"
+        );
+    }
+
+    #[test]
+    fn test_preview_template_shows_transformed_value() {
+        assert_eq!(
+            previewed("{{name}} / {{name/upcase}}", &["ada"]),
+            "ada / ADA"
+        );
+    }
+
+    #[test]
+    fn test_transform_parse_is_case_sensitive_and_rejects_unknown() {
+        assert_eq!(Transform::parse("upcase"), Some(Transform::Upcase));
+        assert_eq!(Transform::parse("downcase"), Some(Transform::Downcase));
+        assert_eq!(Transform::parse("capitalize"), Some(Transform::Capitalize));
+        assert_eq!(Transform::parse("Upcase"), None);
+        assert_eq!(Transform::parse("reverse"), None);
+    }
 }
@@ -401,6 +401,7 @@ impl<R: Read> JsonlReader<R> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::protocol::Choice;
 
     #[test]
     fn test_log_preview_truncation() {
@@ -881,4 +882,406 @@ mod tests {
             _ => panic!("Expected HelloAck message"),
         }
     }
+
+    // ============================================================
+    // Confirm Dialog Tests
+    // ============================================================
+
+    #[test]
+    fn test_confirm_roundtrip() {
+        let original = Message::Confirm {
+            id: "confirm-1".to_string(),
+            title: Some("Delete file?".to_string()),
+            message: "This cannot be undone.".to_string(),
+            ok_label: Some("Delete".to_string()),
+            cancel_label: None,
+            destructive: Some(true),
+        };
+        let json = serde_json::to_string(&original).unwrap();
+        assert!(json.contains(r#""type":"confirm""#));
+        assert!(json.contains(r#""okLabel":"Delete""#));
+        assert!(!json.contains("cancelLabel")); // skipped when None
+
+        let restored: Message = serde_json::from_str(&json).unwrap();
+        match restored {
+            Message::Confirm {
+                id,
+                title,
+                message,
+                ok_label,
+                cancel_label,
+                destructive,
+            } => {
+                assert_eq!(id, "confirm-1");
+                assert_eq!(title.as_deref(), Some("Delete file?"));
+                assert_eq!(message, "This cannot be undone.");
+                assert_eq!(ok_label.as_deref(), Some("Delete"));
+                assert!(cancel_label.is_none());
+                assert_eq!(destructive, Some(true));
+            }
+            _ => panic!("Expected Confirm message"),
+        }
+    }
+
+    #[test]
+    fn test_confirm_minimal_parse() {
+        let json = r#"{"type":"confirm","id":"c1","message":"Continue?"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Confirm {
+                id,
+                title,
+                message,
+                ok_label,
+                cancel_label,
+                destructive,
+            }) => {
+                assert_eq!(id, "c1");
+                assert!(title.is_none());
+                assert_eq!(message, "Continue?");
+                assert!(ok_label.is_none());
+                assert!(cancel_label.is_none());
+                assert!(destructive.is_none());
+            }
+            other => panic!("Expected Confirm message, got {:?}", other),
+        }
+    }
+
+    // ============================================================
+    // Screenshot Capture Tests
+    // ============================================================
+
+    #[test]
+    fn test_capture_screenshot_default_target_omitted() {
+        let msg = Message::capture_screenshot("s1".to_string());
+        let serialized = serde_json::to_string(&msg).expect("Failed to serialize");
+
+        // target/displayIndex/windowId/region are all None and should be omitted
+        assert!(!serialized.contains("target"));
+        assert!(!serialized.contains("displayIndex"));
+        assert!(!serialized.contains("windowId"));
+        assert!(!serialized.contains("region"));
+    }
+
+    #[test]
+    fn test_capture_screenshot_display_roundtrip() {
+        use crate::protocol::types::ScreenshotTarget;
+
+        let json = r#"{"type":"captureScreenshot","requestId":"s2","target":"display","displayIndex":1,"hiDpi":true}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::CaptureScreenshot {
+                request_id,
+                hi_dpi,
+                target,
+                display_index,
+                window_id,
+                region,
+            }) => {
+                assert_eq!(request_id, "s2");
+                assert_eq!(hi_dpi, Some(true));
+                assert_eq!(target, Some(ScreenshotTarget::Display));
+                assert_eq!(display_index, Some(1));
+                assert!(window_id.is_none());
+                assert!(region.is_none());
+            }
+            other => panic!("Expected CaptureScreenshot message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_capture_screenshot_window_roundtrip() {
+        use crate::protocol::types::ScreenshotTarget;
+
+        let json = r#"{"type":"captureScreenshot","requestId":"s3","target":"window","windowId":42}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::CaptureScreenshot {
+                target, window_id, ..
+            }) => {
+                assert_eq!(target, Some(ScreenshotTarget::Window));
+                assert_eq!(window_id, Some(42));
+            }
+            other => panic!("Expected CaptureScreenshot message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_capture_screenshot_region_roundtrip() {
+        use crate::protocol::types::ScreenshotTarget;
+
+        let json = r#"{"type":"captureScreenshot","requestId":"s4","target":"region","displayIndex":0,"region":{"x":10,"y":20,"width":300,"height":400}}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::CaptureScreenshot {
+                target,
+                display_index,
+                region,
+                ..
+            }) => {
+                assert_eq!(target, Some(ScreenshotTarget::Region));
+                assert_eq!(display_index, Some(0));
+                let region = region.expect("region should be present");
+                assert_eq!(region.x, 10);
+                assert_eq!(region.y, 20);
+                assert_eq!(region.width, 300);
+                assert_eq!(region.height, 400);
+            }
+            other => panic!("Expected CaptureScreenshot message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_screenshot_result_success_omits_error() {
+        let msg = Message::screenshot_result("s5".to_string(), "abc123".to_string(), 100, 200);
+        let serialized = serde_json::to_string(&msg).expect("Failed to serialize");
+        assert!(!serialized.contains("error"));
+
+        let deserialized: Message =
+            serde_json::from_str(&serialized).expect("Failed to deserialize");
+        match deserialized {
+            Message::ScreenshotResult {
+                data,
+                width,
+                height,
+                error,
+                ..
+            } => {
+                assert_eq!(data, "abc123");
+                assert_eq!(width, 100);
+                assert_eq!(height, 200);
+                assert!(error.is_none());
+            }
+            other => panic!("Expected ScreenshotResult message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_screenshot_result_error_roundtrip() {
+        let msg = Message::screenshot_error(
+            "s6".to_string(),
+            "Screen recording permission not granted".to_string(),
+        );
+        let serialized = serde_json::to_string(&msg).expect("Failed to serialize");
+
+        let deserialized: Message =
+            serde_json::from_str(&serialized).expect("Failed to deserialize");
+        match deserialized {
+            Message::ScreenshotResult {
+                data,
+                width,
+                height,
+                error,
+                ..
+            } => {
+                assert_eq!(data, "");
+                assert_eq!(width, 0);
+                assert_eq!(height, 0);
+                assert_eq!(
+                    error,
+                    Some("Screen recording permission not granted".to_string())
+                );
+            }
+            other => panic!("Expected ScreenshotResult message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_arg_choices_cmd_roundtrip() {
+        let json = r#"{"type":"arg","id":"a1","placeholder":"Branch","choices":[],"choicesCmd":"git branch"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Arg {
+                id,
+                choices,
+                choices_cmd,
+                ..
+            }) => {
+                assert_eq!(id, "a1");
+                assert!(choices.is_empty());
+                assert_eq!(choices_cmd, Some("git branch".to_string()));
+            }
+            other => panic!("Expected Arg message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_arg_without_choices_cmd_omits_field() {
+        let msg = Message::arg("a2".to_string(), "Pick".to_string(), vec![]);
+        let serialized = serde_json::to_string(&msg).expect("Failed to serialize");
+        assert!(!serialized.contains("choicesCmd"));
+    }
+
+    // ============================================================
+    // Generic Error Response Tests
+    // ============================================================
+
+    #[test]
+    fn test_error_message_parse() {
+        let json = r#"{"type":"error","requestId":"r1","code":"notFound","message":"Window 42 not found","recoverable":true}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Error {
+                request_id,
+                code,
+                message,
+                recoverable,
+            }) => {
+                assert_eq!(request_id, "r1");
+                assert_eq!(code, crate::protocol::ErrorCode::NotFound);
+                assert_eq!(message, "Window 42 not found");
+                assert!(recoverable);
+            }
+            other => panic!("Expected Error message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_error_constructor() {
+        let msg = Message::error(
+            "r2".to_string(),
+            crate::protocol::ErrorCode::PermissionDenied,
+            "Screen recording permission not granted".to_string(),
+            true,
+        );
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""type":"error""#));
+        assert!(json.contains(r#""requestId":"r2""#));
+        assert!(json.contains(r#""code":"permissionDenied""#));
+        assert!(json.contains(r#""recoverable":true"#));
+    }
+
+    #[test]
+    fn test_error_roundtrip() {
+        let original = Message::error(
+            "r3".to_string(),
+            crate::protocol::ErrorCode::InvalidArgument,
+            "Missing window_id".to_string(),
+            false,
+        );
+        let json = serde_json::to_string(&original).unwrap();
+        let restored: Message = serde_json::from_str(&json).unwrap();
+        match (original, restored) {
+            (
+                Message::Error {
+                    request_id: id1,
+                    code: code1,
+                    message: msg1,
+                    recoverable: rec1,
+                },
+                Message::Error {
+                    request_id: id2,
+                    code: code2,
+                    message: msg2,
+                    recoverable: rec2,
+                },
+            ) => {
+                assert_eq!(id1, id2);
+                assert_eq!(code1, code2);
+                assert_eq!(msg1, msg2);
+                assert_eq!(rec1, rec2);
+            }
+            other => panic!("Expected Error/Error roundtrip, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_error_code_serializes_camel_case() {
+        assert_eq!(
+            serde_json::to_string(&crate::protocol::ErrorCode::NotFound).unwrap(),
+            r#""notFound""#
+        );
+        assert_eq!(
+            serde_json::to_string(&crate::protocol::ErrorCode::PermissionDenied).unwrap(),
+            r#""permissionDenied""#
+        );
+        assert_eq!(
+            serde_json::to_string(&crate::protocol::ErrorCode::Timeout).unwrap(),
+            r#""timeout""#
+        );
+        assert_eq!(
+            serde_json::to_string(&crate::protocol::ErrorCode::InvalidArgument).unwrap(),
+            r#""invalidArgument""#
+        );
+        assert_eq!(
+            serde_json::to_string(&crate::protocol::ErrorCode::Internal).unwrap(),
+            r#""internal""#
+        );
+    }
+
+    // ============================================================
+    // Split Prompt Tests
+    // ============================================================
+
+    #[test]
+    fn test_split_message_parse() {
+        let json = r#"{"type":"split","id":"s1","placeholder":"Search","choices":[{"name":"Alpha","value":"alpha"}],"preview":"# Alpha"}"#;
+        match parse_message_graceful(json) {
+            ParseResult::Ok(Message::Split {
+                id,
+                placeholder,
+                choices,
+                preview,
+                actions,
+                footer_hints,
+            }) => {
+                assert_eq!(id, "s1");
+                assert_eq!(placeholder, "Search");
+                assert_eq!(choices.len(), 1);
+                assert_eq!(choices[0].value, "alpha");
+                assert_eq!(preview, Some("# Alpha".to_string()));
+                assert!(actions.is_none());
+                assert!(footer_hints.is_none());
+            }
+            other => panic!("Expected Split message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_split_constructor_omits_optional_fields() {
+        let choices = vec![Choice::new("Alpha".to_string(), "alpha".to_string())];
+        let msg = Message::split("s2".to_string(), "Search".to_string(), choices);
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""type":"split""#));
+        assert!(!json.contains("preview"));
+        assert!(!json.contains("actions"));
+        assert!(!json.contains("footerHints"));
+    }
+
+    #[test]
+    fn test_split_prompt_id_matches_split_id() {
+        let choices = vec![Choice::new("Alpha".to_string(), "alpha".to_string())];
+        let msg = Message::split("s3".to_string(), "Search".to_string(), choices);
+        assert_eq!(msg.prompt_id(), Some("s3".to_string()));
+    }
+
+    #[test]
+    fn test_selection_change_constructor_and_serialize() {
+        let msg = Message::selection_change("s4".to_string(), "alpha".to_string(), 2);
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""type":"selectionChange""#));
+        assert!(json.contains(r#""id":"s4""#));
+        assert!(json.contains(r#""choiceValue":"alpha""#));
+        assert!(json.contains(r#""index":2"#));
+    }
+
+    #[test]
+    fn test_selection_change_roundtrip() {
+        let original = Message::selection_change("s5".to_string(), "beta".to_string(), 1);
+        let json = serde_json::to_string(&original).unwrap();
+        let restored: Message = serde_json::from_str(&json).unwrap();
+        match (original, restored) {
+            (
+                Message::SelectionChange {
+                    id: id1,
+                    choice_value: cv1,
+                    index: idx1,
+                },
+                Message::SelectionChange {
+                    id: id2,
+                    choice_value: cv2,
+                    index: idx2,
+                },
+            ) => {
+                assert_eq!(id1, id2);
+                assert_eq!(cv1, cv2);
+                assert_eq!(idx1, idx2);
+            }
+            other => panic!("Expected SelectionChange/SelectionChange roundtrip, got {:?}", other),
+        }
+    }
 }
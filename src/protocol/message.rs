@@ -68,6 +68,20 @@ pub enum Message {
         /// Optional actions for the actions panel (Cmd+K to open)
         #[serde(default, skip_serializing_if = "Option::is_none")]
         actions: Option<Vec<ProtocolAction>>,
+        /// Milliseconds to wait for a response before the UI shows a
+        /// "script isn't responding" toast offering to cancel it.
+        /// Defaults to no timeout, preserving current behavior.
+        #[serde(rename = "timeoutMs", default, skip_serializing_if = "Option::is_none")]
+        timeout_ms: Option<u64>,
+        /// When `choices` is empty, run this command through the shell on a
+        /// background thread and populate the prompt from its stdout (one
+        /// choice per line) via the same path as `setPlaceholderChoices`.
+        #[serde(rename = "choicesCmd", default, skip_serializing_if = "Option::is_none")]
+        choices_cmd: Option<String>,
+        /// Custom footer bar hints (label + shortcut chips), replacing the
+        /// prompt type's default hints when non-empty. See `FooterHint`.
+        #[serde(rename = "footerHints", default, skip_serializing_if = "Option::is_none")]
+        footer_hints: Option<Vec<FooterHint>>,
     },
 
     /// Script sends div (HTML display)
@@ -99,6 +113,34 @@ pub enum Message {
         /// Container opacity (0-100)
         #[serde(skip_serializing_if = "Option::is_none")]
         opacity: Option<u8>,
+        /// Custom footer bar hints (label + shortcut chips), replacing the
+        /// prompt type's default hints when non-empty. See `FooterHint`.
+        #[serde(rename = "footerHints", default, skip_serializing_if = "Option::is_none")]
+        footer_hints: Option<Vec<FooterHint>>,
+    },
+
+    /// Script sends a split (master-detail) prompt: a choice list on the
+    /// left plus a live preview pane on the right, refreshed as the
+    /// selection moves (see `Message::SelectionChange`) and redrawn by the
+    /// script via `Message::SetPreview` without submitting the prompt.
+    #[serde(rename = "split")]
+    Split {
+        id: String,
+        placeholder: String,
+        choices: Vec<Choice>,
+        /// Markdown (or plain text) for the right-hand preview pane before
+        /// any `Message::SetPreview` arrives - rendered the same way as
+        /// `Choice.preview`. A highlighted choice's own `preview` still wins
+        /// over this when it's set.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        preview: Option<String>,
+        /// Optional actions for the actions panel (Cmd+K to open)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        actions: Option<Vec<ProtocolAction>>,
+        /// Custom footer bar hints (label + shortcut chips), replacing the
+        /// prompt type's default hints when non-empty. See `FooterHint`.
+        #[serde(rename = "footerHints", default, skip_serializing_if = "Option::is_none")]
+        footer_hints: Option<Vec<FooterHint>>,
     },
 
     /// App responds with submission (selected value or null)
@@ -120,6 +162,13 @@ pub enum Message {
         code: Option<i32>,
         #[serde(skip_serializing_if = "Option::is_none")]
         message: Option<String>,
+        /// Optional result value/JSON, written to the app's own stdout so an
+        /// external controller driving the app over stdin (see
+        /// `stdin_commands::ExternalCommand`) can read what the script
+        /// produced. `None` when the script just calls `exit()` with no
+        /// payload.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        value: Option<serde_json::Value>,
     },
 
     /// Force submit the current prompt with a value (from SDK's submit() function)
@@ -130,6 +179,67 @@ pub enum Message {
     #[serde(rename = "setInput")]
     SetInput { text: String },
 
+    /// Set the current prompt's placeholder text
+    #[serde(rename = "setPlaceholder")]
+    SetPlaceholder { text: String },
+
+    /// Set the current prompt's hint text (dim line shown below the input)
+    #[serde(rename = "setHint")]
+    SetHint { text: String },
+
+    /// Pre-filter the main script list (SDK → App)
+    ///
+    /// Sets the main list's filter text and re-filters, the same way typing
+    /// into the launcher would. Useful right before `Exit`/`hide()` so the
+    /// launcher reopens already filtered to related scripts, or while the
+    /// script list is the active view. This is the in-protocol counterpart
+    /// to `ExternalCommand::SetFilter` for scripts that are already running
+    /// rather than an external controller driving the app over stdin.
+    #[serde(rename = "setFilter")]
+    SetFilter { text: String },
+
+    /// Play a named system sound for audible feedback (SDK → App)
+    ///
+    /// `name` is validated against an allow-list of macOS system sounds
+    /// (see `sounds::SYSTEM_SOUND_NAMES`) before playback; unknown names
+    /// are logged and ignored rather than played. Fire-and-forget - the
+    /// script gets no response.
+    ///
+    /// # Example
+    /// ```json
+    /// {"type":"playSound","name":"Glass"}
+    /// ```
+    #[serde(rename = "playSound")]
+    PlaySound { name: String },
+
+    /// No-op synchronization point (App → SDK)
+    ///
+    /// The writer thread batches every response message already queued when
+    /// it wakes up into a single `write_all`+`flush` to cut pipe syscall
+    /// overhead for bursts (e.g. rapid `setChoices` updates). Sending a
+    /// `Flush` marks a point in that stream that a caller cares about being
+    /// physically written - nothing reads its payload, it just rides along
+    /// in the same batch as everything queued ahead of it.
+    #[serde(rename = "flush")]
+    Flush {},
+
+    /// Stream updated choices into an open arg prompt without re-showing it
+    ///
+    /// Lets search-as-you-type scripts keep the prompt (and the user's typed
+    /// filter text) in place while fetching results asynchronously, instead
+    /// of re-sending a whole new `arg` message. `loading` toggles a subtle
+    /// spinner in the input while more choices are still coming.
+    #[serde(rename = "setPlaceholderChoices")]
+    SetPlaceholderChoices {
+        /// Id of the arg prompt to update
+        id: String,
+        /// Replacement choices for the prompt
+        choices: Vec<Choice>,
+        /// Whether the script is still fetching more choices
+        #[serde(default)]
+        loading: bool,
+    },
+
     // ============================================================
     // TEXT INPUT PROMPTS
     // ============================================================
@@ -151,6 +261,10 @@ pub enum Message {
         /// Optional actions for the actions panel (Cmd+K to open)
         #[serde(default, skip_serializing_if = "Option::is_none")]
         actions: Option<Vec<ProtocolAction>>,
+        /// Custom footer bar hints (label + shortcut chips), replacing the
+        /// prompt type's default hints when non-empty. See `FooterHint`.
+        #[serde(rename = "footerHints", default, skip_serializing_if = "Option::is_none")]
+        footer_hints: Option<Vec<FooterHint>>,
     },
 
     /// Compact arg prompt (same as Arg but compact display)
@@ -180,6 +294,15 @@ pub enum Message {
         choices: Vec<Choice>,
         #[serde(skip_serializing_if = "Option::is_none")]
         multiple: Option<bool>,
+        /// Maximum number of choices that may be checked at once.
+        /// Attempting to check past this limit is refused with a brief hint.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<usize>,
+        // NOTE: no footer_hints field here. Select has no PromptFooter (or any
+        // footer) in its render path (see `render_select_prompt` in
+        // `render_prompts/other.rs`), so there is no chip UI to plug hints into.
+        // A `footerHints` key sent by a script is accepted as an unknown field
+        // and silently ignored rather than rejected.
     },
 
     // ============================================================
@@ -216,6 +339,13 @@ pub enum Message {
         start_path: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         hint: Option<String>,
+        // NOTE: no footer_hints field here. Path's single-line hint comes from
+        // `hint` above via `PromptContainerConfig::hint`, a different
+        // mechanism from the chip-based `PromptFooter::custom_hints` that Arg/
+        // Div/Editor use; Path has no `PromptFooter` to plug chips into (see
+        // `render_path_prompt` in `render_prompts/path.rs`). A `footerHints`
+        // key sent by a script is accepted as an unknown field and silently
+        // ignored rather than rejected.
     },
 
     /// File drop zone
@@ -247,6 +377,34 @@ pub enum Message {
         key: String,
         #[serde(skip_serializing_if = "Option::is_none")]
         secret: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        placeholder: Option<String>,
+        /// Regex the submitted value must match; submission is blocked on mismatch
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pattern: Option<String>,
+        /// Switches the input to a textarea-style field for multi-line values
+        /// (e.g. PEM keys, JSON service-account blobs)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        multiline: Option<bool>,
+    },
+
+    /// Confirmation dialog with OK/Cancel buttons, for scripts that want a
+    /// yes/no answer without faking it with a two-choice arg prompt.
+    /// Resolves via `Message::Submit` with value `"true"` or `"false"`.
+    #[serde(rename = "confirm")]
+    Confirm {
+        id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        message: String,
+        #[serde(rename = "okLabel", skip_serializing_if = "Option::is_none")]
+        ok_label: Option<String>,
+        #[serde(rename = "cancelLabel", skip_serializing_if = "Option::is_none")]
+        cancel_label: Option<String>,
+        /// Styles the OK button with error colors and moves the initial
+        /// focus to Cancel, for actions like deleting a file
+        #[serde(skip_serializing_if = "Option::is_none")]
+        destructive: Option<bool>,
     },
 
     // ============================================================
@@ -262,18 +420,53 @@ pub enum Message {
         id: String,
         #[serde(skip_serializing_if = "Option::is_none")]
         command: Option<String>,
+        /// Shell binary to spawn instead of the configured/detected default
+        /// (e.g. `/bin/bash`). Falls back to `config.terminal.shell`, then
+        /// `$SHELL`, then `/bin/zsh` when omitted.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        shell: Option<String>,
+        /// Working directory for the spawned shell. Supports a leading `~`
+        /// for the home directory.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cwd: Option<String>,
+        /// Spawn the shell as a login shell (reads `.zprofile`/`.bash_profile`
+        /// etc., picking up PATH entries dotfiles add). Falls back to
+        /// `config.terminal.login` when omitted.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        login: Option<bool>,
         /// Optional actions for the actions panel (Cmd+K to open)
         #[serde(default, skip_serializing_if = "Option::is_none")]
         actions: Option<Vec<ProtocolAction>>,
     },
 
-    /// Custom widget with HTML
+    /// Create a small, persistent always-on-top widget window (a timer, a
+    /// build status dot) that outlives the prompt that created it. Renders
+    /// `html` with the same renderer as the `div` prompt. See
+    /// `widget_manager` for the window/registry implementation.
     #[serde(rename = "widget")]
     Widget {
         id: String,
         html: String,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        options: Option<serde_json::Value>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        options: Option<WidgetOptions>,
+    },
+
+    /// Update widget state, or dismiss it (script → app)
+    #[serde(rename = "widgetAction")]
+    WidgetAction {
+        id: String,
+        action: WidgetActionKind,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        state: Option<serde_json::Value>,
+    },
+
+    /// Reports a widget interaction back to the owning script (app → script)
+    #[serde(rename = "widgetEvent")]
+    WidgetEvent {
+        id: String,
+        event: WidgetEventKind,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        data: Option<serde_json::Value>,
     },
 
     /// Webcam capture
@@ -322,6 +515,23 @@ pub enum Message {
         text: String,
         #[serde(skip_serializing_if = "Option::is_none")]
         duration_ms: Option<u64>,
+        /// Where to show the HUD (default: bottom-center)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        position: Option<HudPosition>,
+        /// Client-supplied ID for this HUD, so a later `updateHud()` call can
+        /// target it without dismissing and re-showing (which flickers).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+    },
+
+    /// Update the text (and optionally the dismiss duration) of a live HUD
+    /// previously shown with a matching `id`, without dismissing/re-showing it.
+    #[serde(rename = "updateHud")]
+    UpdateHud {
+        id: String,
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        duration_ms: Option<u64>,
     },
 
     // ============================================================
@@ -348,6 +558,16 @@ pub enum Message {
         content: Option<String>,
     },
 
+    /// Copy text to the clipboard and optionally show a "Copied" HUD.
+    /// Fire-and-forget: combines a clipboard write with the confirmation HUD
+    /// scripts would otherwise need a separate `hud()` call for.
+    #[serde(rename = "copy")]
+    Copy {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        hud: Option<bool>,
+    },
+
     /// Keyboard simulation
     #[serde(rename = "keyboard")]
     Keyboard {
@@ -375,10 +595,21 @@ pub enum Message {
     #[serde(rename = "hide")]
     Hide {},
 
+    /// Bring the window forward without changing the current prompt,
+    /// e.g. after a script finishes a silent background phase and needs
+    /// the user's attention again. A no-op if the window is already
+    /// visible, or if the user explicitly hid it moments ago.
+    #[serde(rename = "focus")]
+    Focus {},
+
     /// Open URL in default browser
     #[serde(rename = "browse")]
     Browse { url: String },
 
+    /// Open a file or folder with the system default handler (~ is expanded)
+    #[serde(rename = "openPath")]
+    OpenPath { path: String },
+
     /// Execute shell command
     #[serde(rename = "exec")]
     Exec {
@@ -398,10 +629,37 @@ pub enum Message {
     #[serde(rename = "setPreview")]
     SetPreview { html: String },
 
+    /// Cache preview content for a specific choice `value` in the current
+    /// arg-family prompt (plain `Message::Arg` or `Message::Split`), keyed
+    /// so the host can show it immediately the next time that choice is
+    /// highlighted instead of re-requesting it. Unlike `Message::SetPreview`
+    /// (which unconditionally replaces the single "current" preview blob),
+    /// entries pushed here persist across selection changes for the
+    /// lifetime of the prompt. Pairs with `Message::SelectionChange`: a
+    /// script can respond to a selection-change notification by sending a
+    /// `Message::Preview` for the newly-highlighted value the first time
+    /// it's seen, then rely on the cache thereafter.
+    #[serde(rename = "preview")]
+    Preview {
+        /// `Choice.value` this preview content applies to
+        value: String,
+        /// Markdown (or plain text) preview content, rendered the same way
+        /// as `Choice.preview`
+        content: String,
+    },
+
     /// Set prompt HTML content
     #[serde(rename = "setPrompt")]
     SetPrompt { html: String },
 
+    /// Switch the active theme to a named preset from
+    /// `~/.scriptkit/kit/themes/<name>.json`, persisting the choice.
+    /// Combine with `theme::list_available_themes()` to build a picker
+    /// script. Invalid or missing names show a HUD toast instead of
+    /// changing the active theme.
+    #[serde(rename = "setTheme")]
+    SetTheme { name: String },
+
     // ============================================================
     // SELECTED TEXT OPERATIONS
     // ============================================================
@@ -434,6 +692,27 @@ pub enum Message {
         request_id: String,
     },
 
+    // ============================================================
+    // HOTKEY REGISTRATION
+    // ============================================================
+    /// Register a global hotkey for the currently running script (SDK → App)
+    ///
+    /// `id` is chosen by the script and is echoed back in `HotkeyPressed`
+    /// events and used to unregister the hotkey later. Registration is
+    /// acknowledged with a `Submit` message keyed by `id` (value `None` on
+    /// success, `Some("ERROR: ...")` on conflict/parse failure).
+    #[serde(rename = "registerHotkey")]
+    RegisterHotkey { id: String, shortcut: String },
+
+    /// Unregister a previously registered script hotkey (SDK → App)
+    #[serde(rename = "unregisterHotkey")]
+    UnregisterHotkey { id: String },
+
+    /// Notify the script that one of its registered hotkeys was pressed
+    /// (App → SDK)
+    #[serde(rename = "hotkeyPressed")]
+    HotkeyPressed { id: String },
+
     // ============================================================
     // WINDOW INFORMATION
     // ============================================================
@@ -484,6 +763,31 @@ pub enum Message {
         request_id: String,
     },
 
+    // ============================================================
+    // GENERIC ERROR RESPONSE
+    // ============================================================
+    /// Uniform failure response for request/response message pairs, sent
+    /// in place of a handler's ad-hoc fallback (an empty `Submit`, an empty
+    /// result list, ...) so SDKs can branch on `code` instead of guessing
+    /// from a human-readable string. Only sent when the session has
+    /// negotiated typed errors (see `Config::get_typed_errors_enabled`);
+    /// otherwise handlers keep their legacy fallback responses so older
+    /// SDKs aren't surprised by a response shape they don't expect.
+    ///
+    /// # Example
+    /// `{"type":"error","requestId":"abc123","code":"notFound","message":"Window 42 not found","recoverable":true}`
+    #[serde(rename = "error")]
+    Error {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        code: ErrorCode,
+        message: String,
+        /// Whether retrying the same request could plausibly succeed (e.g.
+        /// `Timeout`), as opposed to a failure that won't change without
+        /// different input (e.g. `InvalidArgument`).
+        recoverable: bool,
+    },
+
     // ============================================================
     // CLIPBOARD HISTORY
     // ============================================================
@@ -560,6 +864,17 @@ pub enum Message {
         windows: Vec<SystemWindowInfo>,
     },
 
+    /// Response with the current scripts/scriptlets/builtins, for external
+    /// tools (an Alfred workflow, a status bar app) to enumerate what's
+    /// available and build their own launchers on top. Sent to the app's
+    /// own stdout in response to `ExternalCommand::ListScripts`.
+    #[serde(rename = "scriptsListResult")]
+    ScriptsListResult {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        entries: Vec<AvailableEntryInfo>,
+    },
+
     /// Response for window action result
     #[serde(rename = "windowActionResult")]
     WindowActionResult {
@@ -594,7 +909,9 @@ pub enum Message {
     // ============================================================
     // SCREENSHOT CAPTURE
     // ============================================================
-    /// Request to capture app window screenshot
+    /// Request to capture a screenshot. Defaults to the Script Kit app window;
+    /// `target` widens this to a whole display, an arbitrary system window
+    /// (by id, from `WindowList`), or a cropped region of a display.
     #[serde(rename = "captureScreenshot")]
     CaptureScreenshot {
         #[serde(rename = "requestId")]
@@ -602,6 +919,18 @@ pub enum Message {
         /// If true, return full retina resolution (2x). If false (default), scale down to 1x.
         #[serde(rename = "hiDpi", skip_serializing_if = "Option::is_none")]
         hi_dpi: Option<bool>,
+        /// What to capture; defaults to the app window when omitted.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        target: Option<ScreenshotTarget>,
+        /// Display to capture, for `target: "display"` and `target: "region"` (index into `xcap::Monitor::all()`).
+        #[serde(rename = "displayIndex", skip_serializing_if = "Option::is_none")]
+        display_index: Option<u32>,
+        /// Window to capture, for `target: "window"` (an id from `WindowList`).
+        #[serde(rename = "windowId", skip_serializing_if = "Option::is_none")]
+        window_id: Option<u32>,
+        /// Pixel region to crop, for `target: "region"`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        region: Option<ScreenshotRegion>,
     },
 
     /// Response with screenshot data as base64 PNG
@@ -609,10 +938,41 @@ pub enum Message {
     ScreenshotResult {
         #[serde(rename = "requestId")]
         request_id: String,
-        /// Base64-encoded PNG data
+        /// Base64-encoded PNG data; empty when `error` is set.
         data: String,
         width: u32,
         height: u32,
+        /// Set when capture failed (e.g. screen recording permission not granted)
+        /// instead of silently returning an empty image.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+
+    // ============================================================
+    // SCRIPT METADATA
+    // ============================================================
+    /// Request the parsed metadata for the script backing the current
+    /// session, so it doesn't have to re-parse its own comment header.
+    #[serde(rename = "getScriptMetadata")]
+    GetScriptMetadata {
+        #[serde(rename = "requestId")]
+        request_id: String,
+    },
+
+    /// Response with the script's parsed metadata
+    #[serde(rename = "scriptMetadataResult")]
+    ScriptMetadataResult {
+        #[serde(rename = "requestId")]
+        request_id: String,
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        alias: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        shortcut: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        schedule: Option<String>,
     },
 
     // ============================================================
@@ -847,6 +1207,31 @@ pub enum Message {
     #[serde(rename = "hideGrid")]
     HideGrid,
 
+    // ============================================================
+    // PROMPT NAVIGATION
+    // ============================================================
+    /// Sent when the user navigates back to a previous prompt (app → script)
+    ///
+    /// The UI optimistically restores the previous prompt's view on its own;
+    /// this message just gives SDK-aware scripts a chance to re-issue the
+    /// earlier prompt themselves (e.g. to recompute choices). Scripts that
+    /// don't handle it keep working unchanged - the optimistic restore stays
+    /// until the script sends something new.
+    #[serde(rename = "back")]
+    Back {
+        /// Id of the prompt being returned to
+        id: String,
+    },
+
+    /// Sent when the app is shutting down and wants the script to exit on
+    /// its own (app → script)
+    ///
+    /// Gives SDK-aware scripts a chance to run cleanup before the app
+    /// escalates to SIGTERM and then SIGKILL. Scripts that don't handle it
+    /// are simply carried through the rest of the shutdown sequence.
+    #[serde(rename = "shutdown")]
+    Shutdown {},
+
     // ============================================================
     // ACTIONS API
     // ============================================================
@@ -877,6 +1262,37 @@ pub enum Message {
         input: String,
     },
 
+    /// Notify SDK that a per-choice action was triggered (outgoing to SDK)
+    ///
+    /// Sent when the user picks one of a `Choice`'s own `actions` (opened via
+    /// Cmd+K while that choice is selected, or its declared shortcut) rather
+    /// than one of the arg prompt's script-level `setActions` actions.
+    #[serde(rename = "choiceAction")]
+    ChoiceAction {
+        /// id of the prompt the choice belongs to
+        id: String,
+        /// `value` of the `Choice` the action was triggered on
+        #[serde(rename = "choiceValue")]
+        choice_value: String,
+        /// Name of the triggered action
+        #[serde(rename = "actionId")]
+        action_id: String,
+    },
+
+    /// Notify SDK that the highlighted choice changed in a `Message::Split`
+    /// prompt (outgoing to SDK), so the script can push fresh preview
+    /// content via `Message::SetPreview` without waiting for a submission.
+    #[serde(rename = "selectionChange")]
+    SelectionChange {
+        /// id of the split prompt the selection belongs to
+        id: String,
+        /// `value` of the newly-highlighted `Choice`
+        #[serde(rename = "choiceValue")]
+        choice_value: String,
+        /// Index of the newly-highlighted choice within the filtered list
+        index: usize,
+    },
+
     // ============================================================
     // MENU BAR INTEGRATION
     // ============================================================
@@ -1009,6 +1425,9 @@ impl Message {
             placeholder,
             choices,
             actions: None,
+            timeout_ms: None,
+            choices_cmd: None,
+            footer_hints: None,
         }
     }
 
@@ -1028,6 +1447,9 @@ impl Message {
             } else {
                 Some(actions)
             },
+            timeout_ms: None,
+            choices_cmd: None,
+            footer_hints: None,
         }
     }
 
@@ -1044,6 +1466,19 @@ impl Message {
             container_bg: None,
             container_padding: None,
             opacity: None,
+            footer_hints: None,
+        }
+    }
+
+    /// Create a split (master-detail) prompt message
+    pub fn split(id: String, placeholder: String, choices: Vec<Choice>) -> Self {
+        Message::Split {
+            id,
+            placeholder,
+            choices,
+            preview: None,
+            actions: None,
+            footer_hints: None,
         }
     }
 
@@ -1060,6 +1495,7 @@ impl Message {
             container_bg: None,
             container_padding: None,
             opacity: None,
+            footer_hints: None,
         }
     }
 
@@ -1070,7 +1506,11 @@ impl Message {
 
     /// Create an exit message
     pub fn exit(code: Option<i32>, message: Option<String>) -> Self {
-        Message::Exit { code, message }
+        Message::Exit {
+            code,
+            message,
+            value: None,
+        }
     }
 
     /// Get the prompt ID for prompt-type messages (arg, div, editor, etc.)
@@ -1082,6 +1522,7 @@ impl Message {
             // Core prompts
             Message::Arg { id, .. }
             | Message::Div { id, .. }
+            | Message::Split { id, .. }
             | Message::Submit { id, .. }
             | Message::Update { id, .. }
             // Text input prompts
@@ -1101,6 +1542,7 @@ impl Message {
             // Template/text prompts
             | Message::Template { id, .. }
             | Message::Env { id, .. }
+            | Message::Confirm { id, .. }
             // Media prompts
             | Message::Chat { id, .. }
             | Message::Term { id, .. }
@@ -1128,6 +1570,8 @@ impl Message {
             | Message::SelectedText { request_id, .. }
             | Message::TextSet { request_id, .. }
             | Message::AccessibilityStatus { request_id, .. }
+            // Generic error response
+            | Message::Error { request_id, .. }
             // Window information
             | Message::GetWindowBounds { request_id, .. }
             | Message::WindowBounds { request_id, .. }
@@ -1141,6 +1585,11 @@ impl Message {
             | Message::WindowAction { request_id, .. }
             | Message::WindowListResult { request_id, .. }
             | Message::WindowActionResult { request_id, .. }
+            // Scripts/scriptlets/builtins listing
+            | Message::ScriptsListResult { request_id, .. }
+            // Script metadata
+            | Message::GetScriptMetadata { request_id, .. }
+            | Message::ScriptMetadataResult { request_id, .. }
             // File search
             | Message::FileSearch { request_id, .. }
             | Message::FileSearchResult { request_id, .. }
@@ -1197,6 +1646,7 @@ impl Message {
             on_init: None,
             on_submit: None,
             actions: None,
+            footer_hints: None,
         }
     }
 
@@ -1210,6 +1660,7 @@ impl Message {
             on_init: None,
             on_submit: None,
             actions: None,
+            footer_hints: None,
         }
     }
 
@@ -1223,6 +1674,7 @@ impl Message {
             on_init: None,
             on_submit: None,
             actions: None,
+            footer_hints: None,
         }
     }
 
@@ -1251,6 +1703,7 @@ impl Message {
             placeholder,
             choices,
             multiple: if multiple { Some(true) } else { None },
+            max: None,
         }
     }
 
@@ -1305,6 +1758,9 @@ impl Message {
             id,
             key,
             secret: if secret { Some(true) } else { None },
+            placeholder: None,
+            pattern: None,
+            multiline: None,
         }
     }
 
@@ -1318,17 +1774,16 @@ impl Message {
         Message::Term {
             id,
             command,
+            shell: None,
+            cwd: None,
+            login: None,
             actions: None,
         }
     }
 
     /// Create a widget message
-    pub fn widget(id: String, html: String) -> Self {
-        Message::Widget {
-            id,
-            html,
-            options: None,
-        }
+    pub fn widget(id: String, html: String, options: Option<WidgetOptions>) -> Self {
+        Message::Widget { id, html, options }
     }
 
     /// Create a webcam prompt message
@@ -1362,8 +1817,13 @@ impl Message {
     }
 
     /// Create a HUD overlay message
-    pub fn hud(text: String, duration_ms: Option<u64>) -> Self {
-        Message::Hud { text, duration_ms }
+    pub fn hud(text: String, duration_ms: Option<u64>, position: Option<HudPosition>) -> Self {
+        Message::Hud {
+            text,
+            duration_ms,
+            position,
+            id: None,
+        }
     }
 
     /// Create a menu message
@@ -1391,6 +1851,14 @@ impl Message {
         }
     }
 
+    /// Create a copy-to-clipboard message, optionally showing a "Copied" HUD
+    pub fn copy(text: String, hud: bool) -> Self {
+        Message::Copy {
+            text,
+            hud: if hud { Some(true) } else { None },
+        }
+    }
+
     /// Create a keyboard type message
     pub fn keyboard_type(keys: String) -> Self {
         Message::Keyboard {
@@ -1466,6 +1934,11 @@ impl Message {
         Message::SetPreview { html }
     }
 
+    /// Create a preview message caching content for a specific choice value
+    pub fn preview(value: String, content: String) -> Self {
+        Message::Preview { value, content }
+    }
+
     /// Create a set prompt message
     pub fn set_prompt(html: String) -> Self {
         Message::SetPrompt { html }
@@ -1684,6 +2157,14 @@ impl Message {
         }
     }
 
+    /// Create a scripts/scriptlets/builtins listing response
+    pub fn scripts_list_result(request_id: String, entries: Vec<AvailableEntryInfo>) -> Self {
+        Message::ScriptsListResult {
+            request_id,
+            entries,
+        }
+    }
+
     /// Create a window action result (success)
     pub fn window_action_success(request_id: String) -> Self {
         Message::WindowActionResult {
@@ -1702,6 +2183,22 @@ impl Message {
         }
     }
 
+    // ============================================================
+    // Constructor methods for the generic error response
+    // ============================================================
+
+    /// Create a typed error response for a failed request/response handler.
+    /// See `Message::Error` for when to use this instead of a handler's
+    /// legacy ad-hoc fallback.
+    pub fn error(request_id: String, code: ErrorCode, message: String, recoverable: bool) -> Self {
+        Message::Error {
+            request_id,
+            code,
+            message,
+            recoverable,
+        }
+    }
+
     // ============================================================
     // Constructor methods for file search
     // ============================================================
@@ -1729,12 +2226,23 @@ impl Message {
         Message::CaptureScreenshot {
             request_id,
             hi_dpi: None,
+            target: None,
+            display_index: None,
+            window_id: None,
+            region: None,
         }
     }
 
     /// Create a capture screenshot request with hi_dpi option
     pub fn capture_screenshot_with_options(request_id: String, hi_dpi: Option<bool>) -> Self {
-        Message::CaptureScreenshot { request_id, hi_dpi }
+        Message::CaptureScreenshot {
+            request_id,
+            hi_dpi,
+            target: None,
+            display_index: None,
+            window_id: None,
+            region: None,
+        }
     }
 
     /// Create a screenshot result response
@@ -1744,6 +2252,47 @@ impl Message {
             data,
             width,
             height,
+            error: None,
+        }
+    }
+
+    /// Create a screenshot result response for a failed capture (e.g. missing
+    /// screen recording permission), instead of returning an empty image.
+    pub fn screenshot_error(request_id: String, error: String) -> Self {
+        Message::ScreenshotResult {
+            request_id,
+            data: String::new(),
+            width: 0,
+            height: 0,
+            error: Some(error),
+        }
+    }
+
+    // ============================================================
+    // Constructor methods for script metadata
+    // ============================================================
+
+    /// Create a get script metadata request
+    pub fn get_script_metadata(request_id: String) -> Self {
+        Message::GetScriptMetadata { request_id }
+    }
+
+    /// Create a script metadata result response
+    pub fn script_metadata_result(
+        request_id: String,
+        name: String,
+        description: Option<String>,
+        alias: Option<String>,
+        shortcut: Option<String>,
+        schedule: Option<String>,
+    ) -> Self {
+        Message::ScriptMetadataResult {
+            request_id,
+            name,
+            description,
+            alias,
+            shortcut,
+            schedule,
         }
     }
 
@@ -2030,11 +2579,89 @@ impl Message {
         Message::SetActions { actions }
     }
 
+    /// Create a ChoiceAction message to send to SDK
+    ///
+    /// Sent when the user triggers one of a `Choice`'s own `actions`.
+    pub fn choice_action(id: String, choice_value: String, action_id: String) -> Self {
+        Message::ChoiceAction {
+            id,
+            choice_value,
+            action_id,
+        }
+    }
+
+    /// Create a SelectionChange message to send to SDK
+    ///
+    /// Sent when the highlighted choice in a `Message::Split` prompt changes.
+    pub fn selection_change(id: String, choice_value: String, index: usize) -> Self {
+        Message::SelectionChange {
+            id,
+            choice_value,
+            index,
+        }
+    }
+
     /// Create a SetInput message
     pub fn set_input(text: String) -> Self {
         Message::SetInput { text }
     }
 
+    /// Create a SetPlaceholder message
+    pub fn set_placeholder(text: String) -> Self {
+        Message::SetPlaceholder { text }
+    }
+
+    /// Create a SetHint message
+    pub fn set_hint(text: String) -> Self {
+        Message::SetHint { text }
+    }
+
+    /// Create a PlaySound message
+    pub fn play_sound(name: String) -> Self {
+        Message::PlaySound { name }
+    }
+
+    /// Create a SetPlaceholderChoices message
+    pub fn set_placeholder_choices(id: String, choices: Vec<Choice>, loading: bool) -> Self {
+        Message::SetPlaceholderChoices {
+            id,
+            choices,
+            loading,
+        }
+    }
+
+    /// Create a Back message to send to the script owning `id`
+    pub fn back(id: String) -> Self {
+        Message::Back { id }
+    }
+
+    /// Create a Shutdown message to notify a running script the app is quitting
+    pub fn shutdown() -> Self {
+        Message::Shutdown {}
+    }
+
+    // ============================================================
+    // Constructor methods for widget windows
+    // ============================================================
+
+    /// Create a WidgetAction message (script → app)
+    pub fn widget_action(
+        id: String,
+        action: WidgetActionKind,
+        state: Option<serde_json::Value>,
+    ) -> Self {
+        Message::WidgetAction { id, action, state }
+    }
+
+    /// Create a WidgetEvent message to send to the owning script (app → script)
+    pub fn widget_event(
+        id: String,
+        event: WidgetEventKind,
+        data: Option<serde_json::Value>,
+    ) -> Self {
+        Message::WidgetEvent { id, event, data }
+    }
+
     // ============================================================
     // Constructor methods for debug grid
     // ============================================================
@@ -16,16 +16,20 @@
 //! - `hotkey`: Keyboard shortcut capture
 //! - `term`: Terminal emulator
 //! - `chat`, `mic`, `webcam`: Media prompts
+//! - `widget`: Persistent floating HTML window outside the main prompt flow
 //!
 //! ## Responses (app → script)
 //! - `submit`: User selection or form submission
 //! - `update`: Live updates (keystrokes, selections)
+//! - `widgetEvent`: Click/input/close/move/resize reported from a widget window
+//! - `back`: User navigated back to a previous prompt in this session
 //!
 //! ## System Control
 //! - `exit`: Terminate script
 //! - `show`/`hide`: Window visibility
 //! - `setPosition`, `setSize`, `setAlwaysOnTop`: Window management
 //! - `setPanel`, `setPreview`, `setPrompt`, `setInput`: UI updates
+//! - `setPlaceholderChoices`: Stream updated choices into an open arg prompt
 //! - `setActions`, `actionTriggered`: Actions menu
 //!
 //! ## State Queries (request/response pattern)
@@ -52,6 +56,10 @@ mod message;
 mod semantic_id;
 mod types;
 
+#[cfg(test)]
+#[path = "conformance_tests.rs"]
+mod conformance_tests;
+
 // Re-export all public types
 pub use io::*;
 pub use message::*;
@@ -141,6 +141,35 @@ pub struct Choice {
     /// This field is typically generated at render time, not provided by scripts.
     #[serde(skip_serializing_if = "Option::is_none", rename = "semanticId")]
     pub semantic_id: Option<String>,
+    /// Thumbnail image for this choice: a file path or a `data:image/...` URI.
+    /// Decoded and cached asynchronously; see `choice_image`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub img: Option<String>,
+    /// A single-glyph icon (typically an emoji) shown before the choice name.
+    /// Cheaper than `img` since it needs no decoding - prefer this for
+    /// scripts that just want a visual marker rather than a real thumbnail.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// When set, flags this choice as destructive: submitting it requires a
+    /// second Enter to confirm. The text is shown to the user as the
+    /// confirmation prompt (e.g. "Delete all files?"). Submitting via the
+    /// SDK's force-submit path bypasses confirmation by design.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<String>,
+    /// Markdown (or plain text) shown in the right-hand preview pane while
+    /// this choice is highlighted, mirroring the main list's script preview.
+    /// Rendered via the same minimal markdown parser; falls back to plain
+    /// text when it contains no markdown syntax.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preview: Option<String>,
+    /// Per-choice secondary actions (Raycast-style), shown via Cmd+K while
+    /// this choice is selected instead of the arg prompt's own `actions`.
+    /// A declared `shortcut` on one of these fires it directly without
+    /// opening the dialog, unless it collides with a reserved key (see
+    /// `choice_actions::build_choice_shortcut_map`). Choosing one sends
+    /// `Message::ChoiceAction` rather than submitting the prompt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<ProtocolAction>>,
 }
 
 impl Choice {
@@ -151,6 +180,11 @@ impl Choice {
             description: None,
             key: None,
             semantic_id: None,
+            img: None,
+            icon: None,
+            confirm: None,
+            preview: None,
+            actions: None,
         }
     }
 
@@ -161,9 +195,26 @@ impl Choice {
             description: Some(description),
             key: None,
             semantic_id: None,
+            img: None,
+            icon: None,
+            confirm: None,
+            preview: None,
+            actions: None,
         }
     }
 
+    /// Attach a thumbnail image source (file path or `data:image/...` URI).
+    pub fn with_img(mut self, img: String) -> Self {
+        self.img = Some(img);
+        self
+    }
+
+    /// Attach a single-glyph icon (typically an emoji).
+    pub fn with_icon(mut self, icon: String) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
     /// Set a stable key for this choice.
     /// When present, semantic ID generation will use this key instead of index.
     pub fn with_key(mut self, key: String) -> Self {
@@ -171,6 +222,27 @@ impl Choice {
         self
     }
 
+    /// Flag this choice as destructive, requiring a second Enter to confirm
+    /// before it is submitted. `text` is shown as the confirmation prompt.
+    pub fn with_confirm(mut self, text: String) -> Self {
+        self.confirm = Some(text);
+        self
+    }
+
+    /// Attach per-choice secondary actions, shown via Cmd+K while this
+    /// choice is selected (see the `actions` field doc for details).
+    pub fn with_actions(mut self, actions: Vec<ProtocolAction>) -> Self {
+        self.actions = Some(actions);
+        self
+    }
+
+    /// Attach markdown (or plain text) to show in the preview pane when this
+    /// choice is highlighted.
+    pub fn with_preview(mut self, preview: String) -> Self {
+        self.preview = Some(preview);
+        self
+    }
+
     /// Generate and set the semantic ID for this choice.
     ///
     /// If `key` is set, generates: `choice:{key}`
@@ -316,6 +388,36 @@ pub enum WindowActionType {
     Move,
 }
 
+/// Machine-readable error code for `Message::Error`, letting SDKs branch on
+/// failure category instead of pattern-matching the human-readable `message`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ErrorCode {
+    /// The requested resource (window, file, clipboard entry, ...) doesn't exist
+    NotFound,
+    /// The operation requires a permission the app hasn't been granted (e.g. screen recording)
+    PermissionDenied,
+    /// The operation didn't complete within its allotted time
+    Timeout,
+    /// A required argument was missing or malformed
+    InvalidArgument,
+    /// An unexpected failure with no more specific code
+    Internal,
+}
+
+/// Screen position for a HUD overlay
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum HudPosition {
+    /// Top-center of the screen containing the mouse
+    TopCenter,
+    /// Bottom-center of the screen containing the mouse (default)
+    #[default]
+    BottomCenter,
+    /// Just below the current mouse cursor position
+    NearCursor,
+}
+
 /// Mouse data for mouse actions
 ///
 /// Contains coordinates and optional button for click events.
@@ -445,6 +547,30 @@ pub struct TargetWindowBounds {
     pub height: u32,
 }
 
+/// What `captureScreenshot` should capture
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ScreenshotTarget {
+    /// The Script Kit app window (default, preserves prior behavior)
+    #[default]
+    App,
+    /// An entire display, selected via `display_index`
+    Display,
+    /// An arbitrary system window, selected via `window_id` (from `WindowList`)
+    Window,
+    /// A pixel region of a display, cropped from a display capture
+    Region,
+}
+
+/// A pixel region to crop out of a display capture, in display-local coordinates
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ScreenshotRegion {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
 /// Clipboard history entry data for list responses
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct ClipboardHistoryEntryData {
@@ -455,6 +581,9 @@ pub struct ClipboardHistoryEntryData {
     pub content_type: ClipboardEntryType,
     pub timestamp: String,
     pub pinned: bool,
+    /// Number of times this exact content has been copied in a row (merged via dedupe)
+    #[serde(rename = "copyCount")]
+    pub copy_count: u32,
 }
 
 /// System window information
@@ -473,6 +602,32 @@ pub struct SystemWindowInfo {
     pub is_active: Option<bool>,
 }
 
+/// The origin of an [`AvailableEntryInfo`] in an `ExternalCommand::ListScripts` result
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum AvailableEntryKind {
+    Script,
+    Scriptlet,
+    Builtin,
+}
+
+/// A single enumerable item (script, scriptlet, or built-in feature) as
+/// reported by `ExternalCommand::ListScripts`, for external tools (an
+/// Alfred workflow, a status bar app) to build their own launchers on top.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct AvailableEntryInfo {
+    pub kind: AvailableEntryKind,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shortcut: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
 /// File search result entry
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct FileSearchResultEntry {
@@ -688,6 +843,47 @@ impl ProtocolAction {
     }
 }
 
+/// A single shortcut hint rendered as a chip in a prompt's footer bar
+/// (e.g. label "Select", shortcut "↵"). See `Message::Arg::footer_hints`
+/// and `components::prompt_footer::PromptFooter`.
+///
+/// Clicking a footer chip dispatches its `shortcut` through the same
+/// `action_shortcuts` lookup that real key presses use, so a hint whose
+/// shortcut matches a registered `ProtocolAction` triggers that action;
+/// otherwise the click is a no-op (the hint is purely informational).
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FooterHint {
+    /// Display label (e.g. "Select", "Actions")
+    pub label: String,
+    /// Shortcut text shown next to the label (e.g. "↵", "⌘K")
+    pub shortcut: String,
+}
+
+impl FooterHint {
+    /// Create a new footer hint
+    pub fn new(label: impl Into<String>, shortcut: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            shortcut: shortcut.into(),
+        }
+    }
+}
+
+/// Resolve the footer hints to render for a prompt: the script-provided
+/// hints when present and non-empty, otherwise the prompt type's built-in
+/// defaults. Kept as a free function (rather than inline at each call
+/// site) so the default-vs-custom resolution can be unit tested without
+/// pulling in GPUI rendering.
+pub fn resolve_footer_hints(
+    custom: &Option<Vec<FooterHint>>,
+    defaults: &[FooterHint],
+) -> Vec<FooterHint> {
+    match custom {
+        Some(hints) if !hints.is_empty() => hints.clone(),
+        _ => defaults.to_vec(),
+    }
+}
+
 /// Scriptlet metadata for protocol serialization
 ///
 /// Matches the ScriptletMetadata struct from scriptlets.rs but optimized
@@ -872,6 +1068,58 @@ fn default_grid_size() -> u32 {
     8
 }
 
+// ============================================================
+// WIDGET WINDOWS
+// ============================================================
+
+/// Positioning and behavior options for a widget window
+///
+/// Mirrors the `WidgetOptions` interface in the SDK. All fields are
+/// optional so a script can create a widget with `widget(html)` and rely
+/// on sensible defaults.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transparent: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draggable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_shadow: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub always_on_top: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<f32>,
+    /// Keep the widget open after the owning script exits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub persist: Option<bool>,
+}
+
+/// Action requested on an existing widget (script → app)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum WidgetActionKind {
+    SetState,
+    Close,
+}
+
+/// Event reported on a widget (app → script)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum WidgetEventKind {
+    Click,
+    Input,
+    Close,
+    Moved,
+    Resized,
+}
+
 /// Manual Default implementation to match serde defaults exactly.
 /// This ensures GridOptions::default() produces the same values as
 /// deserializing an empty JSON object {}.
@@ -1463,6 +1711,45 @@ mod tests {
         assert_eq!(choice.key, Some("fruit-apple".to_string()));
     }
 
+    // ============================================================
+    // Choice Tests (with confirm field for destructive choices)
+    // ============================================================
+
+    #[test]
+    fn test_choice_with_confirm() {
+        let choice = Choice::new("Delete all".to_string(), "delete-all".to_string())
+            .with_confirm("Delete all files?".to_string());
+        assert_eq!(choice.confirm, Some("Delete all files?".to_string()));
+    }
+
+    #[test]
+    fn test_choice_without_confirm_defaults_to_none() {
+        let choice = Choice::new("Apple".to_string(), "apple".to_string());
+        assert_eq!(choice.confirm, None);
+    }
+
+    #[test]
+    fn test_choice_confirm_serialization_omitted_when_none() {
+        let choice = Choice::new("Apple".to_string(), "apple".to_string());
+        let json = serde_json::to_string(&choice).unwrap();
+        assert!(!json.contains("confirm"));
+    }
+
+    #[test]
+    fn test_choice_confirm_serialization_included_when_set() {
+        let choice = Choice::new("Delete all".to_string(), "delete-all".to_string())
+            .with_confirm("Delete all files?".to_string());
+        let json = serde_json::to_string(&choice).unwrap();
+        assert!(json.contains("\"confirm\":\"Delete all files?\""));
+    }
+
+    #[test]
+    fn test_choice_confirm_deserialization() {
+        let json = r#"{"name":"Delete all","value":"delete-all","confirm":"Delete all files?"}"#;
+        let choice: Choice = serde_json::from_str(json).unwrap();
+        assert_eq!(choice.confirm, Some("Delete all files?".to_string()));
+    }
+
     // ============================================================
     // ExecOptions Tests (with extra field for forward-compatibility)
     // ============================================================
@@ -1623,4 +1910,31 @@ mod tests {
         let json_val = SubmitValue::json(serde_json::json!({"key": "val"}));
         assert_eq!(json_val.to_json_value(), serde_json::json!({"key": "val"}));
     }
+
+    // ============================================================
+    // FooterHint Tests
+    // ============================================================
+
+    #[test]
+    fn test_resolve_footer_hints_uses_custom_when_present() {
+        let custom = Some(vec![FooterHint::new("Copy", "⌘C")]);
+        let defaults = vec![FooterHint::new("Select", "↵")];
+        let resolved = resolve_footer_hints(&custom, &defaults);
+        assert_eq!(resolved, vec![FooterHint::new("Copy", "⌘C")]);
+    }
+
+    #[test]
+    fn test_resolve_footer_hints_falls_back_to_defaults_when_none() {
+        let defaults = vec![FooterHint::new("Select", "↵")];
+        let resolved = resolve_footer_hints(&None, &defaults);
+        assert_eq!(resolved, defaults);
+    }
+
+    #[test]
+    fn test_resolve_footer_hints_falls_back_to_defaults_when_empty() {
+        let custom = Some(vec![]);
+        let defaults = vec![FooterHint::new("Select", "↵")];
+        let resolved = resolve_footer_hints(&custom, &defaults);
+        assert_eq!(resolved, defaults);
+    }
 }
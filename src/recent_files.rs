@@ -0,0 +1,180 @@
+//! Recent files provider
+//!
+//! Surfaces recently opened/modified documents in the main search, the way
+//! Raycast surfaces recently used files alongside apps. Two sources feed
+//! the provider:
+//! - macOS's shared recent-documents list (NSDocumentController / the
+//!   `~/Library/Application Support/com.apple.sharedfilelist` fallback)
+//! - a configurable set of "watched folders" (`Config::recent_files_folders`),
+//!   scanned for their most recently modified files
+//!
+//! Results are surfaced as `SearchResult::RecentFile` (see `scripts::types`)
+//! and opened with `open_path_with_system_default` on Enter, same as any
+//! other file-backed result.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// A single recently-opened/modified document surfaced in search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentFileInfo {
+    /// File name (last path component)
+    pub name: String,
+    /// Full path to the file
+    pub path: PathBuf,
+    /// Last modified time, used for recency ordering
+    pub modified: SystemTime,
+}
+
+/// Minimum filter length before the recent-files provider contributes
+/// results. Short queries match almost any filename, so gating avoids
+/// flooding the list with noise the way an unscoped 1-2 character search
+/// would.
+pub const MIN_QUERY_LEN: usize = 3;
+
+/// Read macOS's shared recent-documents list (NSDocumentController / the
+/// `~/Library/Application Support/com.apple.sharedfilelist` fallback).
+///
+/// **Not implemented in this pass.** The shared file list is stored as a
+/// binary plist (`.sfl2`), and parsing it needs a plist-decoding dependency
+/// this tree doesn't currently carry (see `Cargo.toml` - no `plist` crate);
+/// NSDocumentController itself also isn't bridged anywhere in this codebase
+/// today (only icon extraction in `app_launcher.rs` uses `objc`/`cocoa`).
+/// Returns an empty list so the provider still works from watched folders
+/// alone - see `scan_watched_folders`. A future pass can fill this in once a
+/// plist dependency is available.
+pub fn read_macos_recent_documents() -> Vec<RecentFileInfo> {
+    Vec::new()
+}
+
+/// Scan `folders` for their most recently modified files.
+///
+/// Each folder is scanned one level deep (no recursion into
+/// subdirectories), mirroring how `app_launcher`'s directory scan treats
+/// each configured directory as a flat listing. Missing or unreadable
+/// folders are skipped rather than failing the whole scan. Returns up to
+/// `limit` entries sorted by modification time, most recent first.
+pub fn scan_watched_folders(folders: &[PathBuf], limit: usize) -> Vec<RecentFileInfo> {
+    let mut files: Vec<RecentFileInfo> = Vec::new();
+
+    for folder in folders {
+        let Ok(entries) = fs::read_dir(folder) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                continue;
+            }
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            let Ok(modified) = metadata.modified() else {
+                continue;
+            };
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            files.push(RecentFileInfo {
+                name,
+                path,
+                modified,
+            });
+        }
+    }
+
+    files.sort_by(|a, b| b.modified.cmp(&a.modified));
+    files.truncate(limit);
+    files
+}
+
+/// Build the full recent-files candidate list: the macOS shared
+/// recent-documents list plus a scan of the configured watched folders,
+/// with entries whose file no longer exists dropped at query time (the
+/// underlying scan may be stale by the time the user searches - a file
+/// found a moment ago may have since been deleted or moved).
+pub fn recent_files(watched_folders: &[PathBuf], limit: usize) -> Vec<RecentFileInfo> {
+    let mut files = read_macos_recent_documents();
+    files.extend(scan_watched_folders(watched_folders, limit));
+    files.retain(|f| f.path.exists());
+    files.sort_by(|a, b| b.modified.cmp(&a.modified));
+    files.truncate(limit);
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tempfile::tempdir;
+
+    // Sets an explicit mtime so recency ordering doesn't depend on real
+    // wall-clock gaps between file creations in the test. Uses
+    // `File::set_modified` (stable since Rust 1.75) rather than pulling in
+    // a `filetime` dependency for this alone.
+    fn touch_with_mtime(path: &std::path::Path, age_secs_ago: u64) {
+        fs::write(path, b"content").unwrap();
+        let mtime = SystemTime::now() - Duration::from_secs(age_secs_ago);
+        let file = fs::File::options().write(true).open(path).unwrap();
+        file.set_modified(mtime).unwrap();
+    }
+
+    #[test]
+    fn test_scan_watched_folders_orders_by_recency() {
+        let dir = tempdir().unwrap();
+        let old = dir.path().join("old.txt");
+        let newer = dir.path().join("newer.txt");
+        let newest = dir.path().join("newest.txt");
+
+        touch_with_mtime(&old, 300);
+        touch_with_mtime(&newer, 150);
+        touch_with_mtime(&newest, 10);
+
+        let results = scan_watched_folders(&[dir.path().to_path_buf()], 10);
+
+        let names: Vec<&str> = results.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["newest.txt", "newer.txt", "old.txt"]);
+    }
+
+    #[test]
+    fn test_scan_watched_folders_respects_limit() {
+        let dir = tempdir().unwrap();
+        for i in 0..5 {
+            touch_with_mtime(&dir.path().join(format!("file{}.txt", i)), i as u64);
+        }
+
+        let results = scan_watched_folders(&[dir.path().to_path_buf()], 2);
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn test_scan_watched_folders_skips_directories_and_missing_folders() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join("subdir")).unwrap();
+        touch_with_mtime(&dir.path().join("file.txt"), 0);
+
+        let missing = dir.path().join("does-not-exist");
+        let results = scan_watched_folders(&[dir.path().to_path_buf(), missing], 10);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "file.txt");
+    }
+
+    #[test]
+    fn test_recent_files_filters_stale_entries() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("gone.txt");
+        touch_with_mtime(&path, 0);
+
+        let mut results = scan_watched_folders(&[dir.path().to_path_buf()], 10);
+        assert_eq!(results.len(), 1);
+
+        fs::remove_file(&path).unwrap();
+        // Simulate the query-time re-check that `recent_files()` performs -
+        // a scan taken before the delete would still report the entry.
+        results.retain(|f| f.path.exists());
+        assert!(results.is_empty());
+    }
+}
@@ -48,11 +48,11 @@ impl ScriptListApp {
         let filtered_entries: Vec<_> = if filter.is_empty() {
             self.cached_clipboard_entries.iter().enumerate().collect()
         } else {
-            let filter_lower = filter.to_lowercase();
+            let filter_norm = normalize_for_search(filter);
             self.cached_clipboard_entries
                 .iter()
                 .enumerate()
-                .filter(|(_, e)| e.text_preview.to_lowercase().contains(&filter_lower))
+                .filter(|(_, e)| normalize_for_search(&e.text_preview).contains(&filter_norm))
                 .collect()
         };
         let filtered_len = filtered_entries.len();
@@ -75,6 +75,7 @@ impl ScriptListApp {
                 }
 
                 let key_str = event.keystroke.key.to_lowercase();
+                let has_cmd = event.keystroke.modifiers.platform;
                 logging::log("KEY", &format!("ClipboardHistory key: '{}'", key_str));
 
                 // P0 FIX: View state only - data comes from this.cached_clipboard_entries
@@ -88,11 +89,13 @@ impl ScriptListApp {
                     let filtered_entries: Vec<_> = if filter.is_empty() {
                         this.cached_clipboard_entries.iter().enumerate().collect()
                     } else {
-                        let filter_lower = filter.to_lowercase();
+                        let filter_norm = normalize_for_search(filter);
                         this.cached_clipboard_entries
                             .iter()
                             .enumerate()
-                            .filter(|(_, e)| e.text_preview.to_lowercase().contains(&filter_lower))
+                            .filter(|(_, e)| {
+                                normalize_for_search(&e.text_preview).contains(&filter_norm)
+                            })
                             .collect()
                     };
                     let filtered_len = filtered_entries.len();
@@ -134,18 +137,65 @@ impl ScriptListApp {
                                     cx.hide();
                                     NEEDS_RESET.store(true, Ordering::SeqCst);
 
-                                    // Simulate Cmd+V paste after a brief delay to let focus return
-                                    std::thread::spawn(|| {
-                                        std::thread::sleep(std::time::Duration::from_millis(100));
-                                        if let Err(e) = selected_text::simulate_paste_with_cg() {
+                                    // Only auto-paste into the frontmost app if the user opted
+                                    // in; some prefer to paste manually (Raycast/Maccy default).
+                                    if this.config.get_clipboard_auto_paste() {
+                                        // Simulate Cmd+V paste after a brief delay to let focus return
+                                        std::thread::spawn(|| {
+                                            std::thread::sleep(std::time::Duration::from_millis(
+                                                100,
+                                            ));
+                                            if let Err(e) = selected_text::simulate_paste_with_cg()
+                                            {
+                                                logging::log(
+                                                    "ERROR",
+                                                    &format!("Failed to simulate paste: {}", e),
+                                                );
+                                            } else {
+                                                logging::log("EXEC", "Simulated Cmd+V paste");
+                                            }
+                                        });
+                                    }
+                                }
+                            }
+                        }
+                        "c" if has_cmd => {
+                            // Copy without hiding/pasting - unlike Enter, just put the
+                            // entry back on the clipboard (as an image via arboard
+                            // set_image for image entries, not placeholder text).
+                            if let Some((_, entry)) = filtered_entries.get(*selected_index) {
+                                if let Err(e) =
+                                    clipboard_history::copy_entry_to_clipboard(&entry.id)
+                                {
+                                    logging::log("ERROR", &format!("Failed to copy entry: {}", e));
+                                } else {
+                                    logging::log("EXEC", "Entry copied to clipboard (Cmd+C)");
+                                }
+                            }
+                        }
+                        "s" if has_cmd => {
+                            // Save the selected image entry to ~/Downloads as PNG.
+                            if let Some((_, entry)) = filtered_entries.get(*selected_index) {
+                                if entry.content_type == clipboard_history::ContentType::Image {
+                                    match clipboard_history::save_entry_image_to_downloads(
+                                        &entry.id,
+                                    ) {
+                                        Ok(path) => {
+                                            logging::log(
+                                                "EXEC",
+                                                &format!(
+                                                    "Saved clipboard image to {}",
+                                                    path.display()
+                                                ),
+                                            );
+                                        }
+                                        Err(e) => {
                                             logging::log(
                                                 "ERROR",
-                                                &format!("Failed to simulate paste: {}", e),
+                                                &format!("Failed to save image: {}", e),
                                             );
-                                        } else {
-                                            logging::log("EXEC", "Simulated Cmd+V paste");
                                         }
-                                    });
+                                    }
                                 }
                             }
                         }
@@ -234,7 +284,12 @@ impl ScriptListApp {
                                 let mut item = ListItem::new(name, list_colors)
                                     .description_opt(Some(relative_time))
                                     .selected(is_selected)
-                                    .with_accent_bar(true);
+                                    .with_accent_bar(true)
+                                    .shortcut_opt(if entry.copy_count > 1 {
+                                        Some(format!("×{}", entry.copy_count))
+                                    } else {
+                                        None
+                                    });
 
                                 // Add thumbnail for images, text icon for text entries
                                 if let Some(render_image) = cached_image {
@@ -246,7 +301,7 @@ impl ScriptListApp {
 
                                 div().id(ix).child(item)
                             } else {
-                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                                div().id(ix).h(px(density::list_item_height()))
                             }
                         })
                         .collect()
@@ -512,17 +567,15 @@ impl ScriptListApp {
                         let cached_image = image_cache.get(&entry.id).cloned();
 
                         let image_container = if let Some(render_image) = cached_image {
-                            // Calculate display size that fits in the preview panel
-                            // Max size is 300x300, maintain aspect ratio
+                            // Calculate display size that fits in the preview panel,
+                            // maintaining aspect ratio.
                             let max_size: f32 = 300.0;
-                            let (display_w, display_h) = if width > 0 && height > 0 {
-                                let w = width as f32;
-                                let h = height as f32;
-                                let scale = (max_size / w).min(max_size / h).min(1.0);
-                                (w * scale, h * scale)
-                            } else {
-                                (max_size, max_size)
-                            };
+                            let (display_w, display_h) = clipboard_history::fit_dimensions(
+                                width as f32,
+                                height as f32,
+                                max_size,
+                                max_size,
+                            );
 
                             div()
                                 .flex()
@@ -628,11 +681,11 @@ impl ScriptListApp {
         let filtered_apps: Vec<_> = if filter.is_empty() {
             self.apps.iter().enumerate().collect()
         } else {
-            let filter_lower = filter.to_lowercase();
+            let filter_norm = normalize_for_search(filter);
             self.apps
                 .iter()
                 .enumerate()
-                .filter(|(_, a)| a.name.to_lowercase().contains(&filter_lower))
+                .filter(|(_, a)| normalize_for_search(&a.name).contains(&filter_norm))
                 .collect()
         };
         let filtered_len = filtered_apps.len();
@@ -669,11 +722,11 @@ impl ScriptListApp {
                     let filtered_apps: Vec<_> = if filter.is_empty() {
                         this.apps.iter().enumerate().collect()
                     } else {
-                        let filter_lower = filter.to_lowercase();
+                        let filter_norm = normalize_for_search(filter);
                         this.apps
                             .iter()
                             .enumerate()
-                            .filter(|(_, a)| a.name.to_lowercase().contains(&filter_lower))
+                            .filter(|(_, a)| normalize_for_search(&a.name).contains(&filter_norm))
                             .collect()
                     };
                     let filtered_len = filtered_apps.len();
@@ -797,7 +850,7 @@ impl ScriptListApp {
                                         .with_accent_bar(true),
                                 )
                             } else {
-                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                                div().id(ix).h(px(density::list_item_height()))
                             }
                         })
                         .collect()
@@ -938,21 +991,17 @@ impl ScriptListApp {
         let bg_with_alpha = crate::ui_foundation::hex_to_rgba_with_opacity(bg_hex, opacity.main);
         let box_shadows = self.create_box_shadows();
 
-        // P0 FIX: Filter windows from self.cached_windows instead of taking ownership
-        let filtered_windows: Vec<_> = if filter.is_empty() {
-            self.cached_windows.iter().enumerate().collect()
-        } else {
-            let filter_lower = filter.to_lowercase();
-            self.cached_windows
-                .iter()
-                .enumerate()
-                .filter(|(_, w)| {
-                    w.title.to_lowercase().contains(&filter_lower)
-                        || w.app.to_lowercase().contains(&filter_lower)
-                })
-                .collect()
-        };
-        let filtered_len = filtered_windows.len();
+        // P0 FIX: Group windows from self.cached_windows instead of taking ownership.
+        // Grouping by app (sorted alphabetically, frontmost app pinned first) reuses
+        // the same GroupedListItem/render_section_header machinery as the main list.
+        let (filtered_windows, grouped_rows) = filter_and_group_windows(
+            &self.cached_windows,
+            &filter,
+            self.cached_frontmost_app.as_deref(),
+        );
+        let grouped_state = GroupedListState::from_items(&grouped_rows);
+        let selected_index =
+            coerce_selection(&grouped_rows, selected_index).unwrap_or(grouped_state.first_selectable);
 
         // Key handler for window switcher
         let handle_key = cx.listener(
@@ -972,6 +1021,7 @@ impl ScriptListApp {
                 }
 
                 let key_str = event.keystroke.key.to_lowercase();
+                let cmd_shift = event.keystroke.modifiers.platform && event.keystroke.modifiers.shift;
                 logging::log("KEY", &format!("WindowSwitcher key: '{}'", key_str));
 
                 // P0 FIX: View state only - data comes from this.cached_windows
@@ -980,71 +1030,107 @@ impl ScriptListApp {
                     selected_index,
                 } = &mut this.current_view
                 {
-                    // Apply filter to get current filtered list
-                    // P0 FIX: Reference cached_windows from self
-                    let filtered_windows: Vec<_> = if filter.is_empty() {
-                        this.cached_windows.iter().enumerate().collect()
-                    } else {
-                        let filter_lower = filter.to_lowercase();
-                        this.cached_windows
-                            .iter()
-                            .enumerate()
-                            .filter(|(_, w)| {
-                                w.title.to_lowercase().contains(&filter_lower)
-                                    || w.app.to_lowercase().contains(&filter_lower)
-                            })
-                            .collect()
-                    };
-                    let filtered_len = filtered_windows.len();
+                    // Re-derive the grouped rows from current state - windows and
+                    // the filter may have changed since this closure was built.
+                    let (filtered_windows, grouped_rows) = filter_and_group_windows(
+                        &this.cached_windows,
+                        filter,
+                        this.cached_frontmost_app.as_deref(),
+                    );
+                    let grouped_state = GroupedListState::from_items(&grouped_rows);
+                    let current = coerce_selection(&grouped_rows, *selected_index)
+                        .unwrap_or(grouped_state.first_selectable);
 
                     match key_str.as_str() {
                         "up" | "arrowup" => {
-                            if *selected_index > 0 {
-                                *selected_index -= 1;
+                            if let Some(prev) = grouped_state.prev_selectable(current) {
+                                *selected_index = prev;
                                 this.window_list_scroll_handle
-                                    .scroll_to_item(*selected_index, ScrollStrategy::Nearest);
+                                    .scroll_to_item(prev, ScrollStrategy::Nearest);
                                 cx.notify();
                             }
                         }
                         "down" | "arrowdown" => {
-                            if *selected_index < filtered_len.saturating_sub(1) {
-                                *selected_index += 1;
+                            if let Some(next) = grouped_state.next_selectable(current) {
+                                *selected_index = next;
                                 this.window_list_scroll_handle
-                                    .scroll_to_item(*selected_index, ScrollStrategy::Nearest);
+                                    .scroll_to_item(next, ScrollStrategy::Nearest);
                                 cx.notify();
                             }
                         }
                         "enter" => {
-                            // Focus selected window and hide Script Kit
-                            if let Some((_, window_info)) = filtered_windows.get(*selected_index) {
-                                logging::log(
-                                    "EXEC",
-                                    &format!("Focusing window: {}", window_info.title),
-                                );
-                                if let Err(e) = window_control::focus_window(window_info.id) {
-                                    logging::log(
-                                        "ERROR",
-                                        &format!("Failed to focus window: {}", e),
-                                    );
-                                    this.toast_manager.push(
-                                        components::toast::Toast::error(
-                                            format!("Failed to focus window: {}", e),
-                                            &this.theme,
-                                        )
-                                        .duration_ms(Some(5000)),
-                                    );
-                                    cx.notify();
-                                } else {
+                            // Focus the selected window and hide Script Kit.
+                            if let Some(GroupedListItem::Item(idx)) = grouped_rows.get(current) {
+                                if let Some(window_info) = filtered_windows.get(*idx) {
                                     logging::log(
                                         "EXEC",
-                                        &format!("Focused window: {}", window_info.title),
+                                        &format!("Focusing window: {}", window_info.title),
                                     );
-                                    script_kit_gpui::set_main_window_visible(false);
-                                    cx.hide();
-                                    NEEDS_RESET.store(true, Ordering::SeqCst);
+                                    if let Err(e) = window_control::focus_window(window_info.id) {
+                                        logging::log(
+                                            "ERROR",
+                                            &format!("Failed to focus window: {}", e),
+                                        );
+                                        this.toast_manager.push(
+                                            components::toast::Toast::error(
+                                                format!("Failed to focus window: {}", e),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(5000)),
+                                        );
+                                        cx.notify();
+                                    } else {
+                                        logging::log(
+                                            "EXEC",
+                                            &format!("Focused window: {}", window_info.title),
+                                        );
+                                        script_kit_gpui::set_main_window_visible(false);
+                                        cx.hide();
+                                        NEEDS_RESET.store(true, Ordering::SeqCst);
+                                    }
                                 }
                             }
                         }
+                        // App-level actions, scoped to an app-group header selection -
+                        // mirrors the per-choice direct-fire shortcuts in choice_actions.rs
+                        // rather than routing through the script-focused ActionsDialog.
+                        "q" | "h" | "a" if cmd_shift => {
+                            if let Some((app_name, pid)) =
+                                header_app_and_pid_at(&grouped_rows, &filtered_windows, current)
+                            {
+                                let result = match key_str.as_str() {
+                                    "q" => window_control::quit_application(pid).map(|_| "Quit"),
+                                    "h" => window_control::hide_application(pid).map(|_| "Hid"),
+                                    "a" => window_control::close_all_windows_for_pid(pid)
+                                        .map(|_| "Closed all windows for"),
+                                    _ => unreachable!(),
+                                };
+                                match result {
+                                    Ok(verb) => {
+                                        this.toast_manager.push(
+                                            components::toast::Toast::success(
+                                                format!("{} {}", verb, app_name),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(2000)),
+                                        );
+                                        if let Ok(windows) = window_control::list_windows() {
+                                            this.cached_windows = windows;
+                                        }
+                                    }
+                                    Err(e) => {
+                                        this.toast_manager.push(
+                                            components::toast::Toast::error(
+                                                format!("{} failed: {}", app_name, e),
+                                                &this.theme,
+                                            )
+                                            .duration_ms(Some(5000)),
+                                        );
+                                    }
+                                }
+                                cx.notify();
+                            }
+                        }
                         // Note: "escape" is handled by handle_global_shortcut_with_options above
                         // Text input (backspace, characters) is handled by the shared Input component
                         // which syncs via handle_filter_input_change()
@@ -1062,8 +1148,12 @@ impl ScriptListApp {
         let text_dimmed = design_colors.text_dimmed;
         let ui_border = design_colors.border;
 
-        // Build virtualized list
-        let list_element: AnyElement = if filtered_len == 0 {
+        // Build virtualized list. Section headers reuse render_section_header
+        // but are shown at the same fixed row height as items, since this view
+        // uses uniform_list (fixed-height rows) rather than the main list's
+        // variable-height `list()` component.
+        let row_count = grouped_rows.len();
+        let list_element: AnyElement = if row_count == 0 {
             div()
                 .w_full()
                 .py(px(design_spacing.padding_xl))
@@ -1078,42 +1168,47 @@ impl ScriptListApp {
                 .into_any_element()
         } else {
             // Clone data for the closure
-            let windows_for_closure: Vec<_> = filtered_windows
-                .iter()
-                .map(|(i, w)| (*i, (*w).clone()))
-                .collect();
+            let rows_for_closure = grouped_rows.clone();
+            let windows_for_closure = filtered_windows.clone();
             let selected = selected_index;
+            let header_colors = list_colors;
 
             uniform_list(
                 "window-switcher",
-                filtered_len,
+                row_count,
                 move |visible_range, _window, _cx| {
                     visible_range
-                        .map(|ix| {
-                            if let Some((_, window_info)) = windows_for_closure.get(ix) {
-                                let is_selected = ix == selected;
-
-                                // Format: "AppName: Window Title"
-                                let name = format!("{}: {}", window_info.app, window_info.title);
-
-                                // Format bounds as description
-                                let description = format!(
-                                    "{}×{} at ({}, {})",
-                                    window_info.bounds.width,
-                                    window_info.bounds.height,
-                                    window_info.bounds.x,
-                                    window_info.bounds.y
-                                );
-
-                                div().id(ix).child(
-                                    ListItem::new(name, list_colors)
-                                        .description_opt(Some(description))
-                                        .selected(is_selected)
-                                        .with_accent_bar(true),
+                        .map(|ix| match rows_for_closure.get(ix) {
+                            Some(GroupedListItem::SectionHeader(app_name)) => {
+                                let window_count = rows_for_closure[ix + 1..]
+                                    .iter()
+                                    .take_while(|r| matches!(r, GroupedListItem::Item(_)))
+                                    .count();
+                                div().id(ix).h(px(density::list_item_height())).child(
+                                    render_section_header(app_name, header_colors, window_count, false),
                                 )
-                            } else {
-                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
                             }
+                            Some(GroupedListItem::Item(idx)) => {
+                                if let Some(window_info) = windows_for_closure.get(*idx) {
+                                    let is_selected = ix == selected;
+                                    let description = format!(
+                                        "{}×{} at ({}, {})",
+                                        window_info.bounds.width,
+                                        window_info.bounds.height,
+                                        window_info.bounds.x,
+                                        window_info.bounds.y
+                                    );
+                                    div().id(ix).child(
+                                        ListItem::new(window_info.title.clone(), list_colors)
+                                            .description_opt(Some(description))
+                                            .selected(is_selected)
+                                            .with_accent_bar(true),
+                                    )
+                                } else {
+                                    div().id(ix).h(px(density::list_item_height()))
+                                }
+                            }
+                            None => div().id(ix).h(px(density::list_item_height())),
                         })
                         .collect()
                 },
@@ -1123,12 +1218,25 @@ impl ScriptListApp {
             .into_any_element()
         };
 
-        // Build actions panel for selected window
-        let selected_window = filtered_windows
-            .get(selected_index)
-            .map(|(_, w)| (*w).clone());
+        // Build actions panel for the current selection - a window or an app-group header.
+        let selected_window = match grouped_rows.get(selected_index) {
+            Some(GroupedListItem::Item(idx)) => filtered_windows.get(*idx).cloned(),
+            _ => None,
+        };
+        let selected_app_group = header_app_and_pid_at(&grouped_rows, &filtered_windows, selected_index)
+            .map(|(app, pid)| {
+                let window_count = match grouped_rows.get(selected_index) {
+                    Some(GroupedListItem::SectionHeader(_)) => grouped_rows[selected_index + 1..]
+                        .iter()
+                        .take_while(|r| matches!(r, GroupedListItem::Item(_)))
+                        .count(),
+                    _ => 0,
+                };
+                (app, pid, window_count)
+            });
         let actions_panel = self.render_window_actions_panel(
             &selected_window,
+            &selected_app_group,
             &design_colors,
             &design_spacing,
             &design_typography,
@@ -1230,6 +1338,7 @@ impl ScriptListApp {
     fn render_window_actions_panel(
         &self,
         selected_window: &Option<window_control::WindowInfo>,
+        selected_app_group: &Option<(String, i32, usize)>,
         colors: &designs::DesignColors,
         spacing: &designs::DesignSpacing,
         typography: &designs::DesignTypography,
@@ -1308,19 +1417,77 @@ impl ScriptListApp {
                         .child("Press Enter to focus window"),
                 );
             }
-            None => {
-                // Empty state
-                panel = panel.child(
-                    div()
-                        .w_full()
-                        .h_full()
-                        .flex()
-                        .items_center()
-                        .justify_center()
-                        .text_color(rgb(text_muted))
-                        .child("No window selected"),
-                );
-            }
+            None => match selected_app_group {
+                Some((app_name, _pid, window_count)) => {
+                    // App-group header info
+                    panel = panel.child(
+                        div()
+                            .text_lg()
+                            .font_weight(gpui::FontWeight::SEMIBOLD)
+                            .text_color(rgb(text_primary))
+                            .pb(px(spacing.padding_sm))
+                            .child(app_name.clone()),
+                    );
+
+                    panel = panel.child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_secondary))
+                            .pb(px(spacing.padding_lg))
+                            .child(format!(
+                                "{} window{}",
+                                window_count,
+                                if *window_count == 1 { "" } else { "s" }
+                            )),
+                    );
+
+                    panel = panel.child(
+                        div()
+                            .w_full()
+                            .h(px(visual.border_thin))
+                            .bg(rgba((ui_border << 8) | 0x60))
+                            .mb(px(spacing.padding_lg)),
+                    );
+
+                    // App-level actions, fired directly from the window switcher's
+                    // own key handler (see render_window_switcher) rather than the
+                    // script-focused Cmd+K ActionsDialog.
+                    for (shortcut, label) in [
+                        ("⌘⇧H", "Hide App"),
+                        ("⌘⇧Q", "Quit App"),
+                        ("⌘⇧A", "Close All Windows"),
+                    ] {
+                        panel = panel.child(
+                            div()
+                                .flex()
+                                .flex_row()
+                                .items_center()
+                                .justify_between()
+                                .pb(px(spacing.padding_sm))
+                                .child(div().text_sm().text_color(rgb(text_primary)).child(label))
+                                .child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(rgb(text_muted))
+                                        .child(shortcut),
+                                ),
+                        );
+                    }
+                }
+                None => {
+                    // Empty state
+                    panel = panel.child(
+                        div()
+                            .w_full()
+                            .h_full()
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .text_color(rgb(text_muted))
+                            .child("No window selected"),
+                    );
+                }
+            },
         }
 
         panel
@@ -1376,6 +1543,11 @@ impl ScriptListApp {
                     match window_control::list_windows() {
                         Ok(new_windows) => {
                             self.cached_windows = new_windows;
+                            self.cached_frontmost_app =
+                                window_control::get_frontmost_window_of_previous_app()
+                                    .ok()
+                                    .flatten()
+                                    .map(|w| w.app);
                             // Adjust selected index if needed
                             if *selected_index >= self.cached_windows.len()
                                 && !self.cached_windows.is_empty()
@@ -1423,9 +1595,18 @@ impl ScriptListApp {
         let design_typography = tokens.typography();
         let design_visual = tokens.visual();
 
-        // Use design tokens for global theming
+        // Use design tokens for global theming. The background token can be
+        // temporarily overridden from the gallery's playground panel, so
+        // resolve it through `design_token_overrides` before falling back to
+        // the active design's own value.
         let opacity = self.theme.get_opacity();
-        let bg_hex = design_colors.background;
+        let base_bg_hex = format!("#{:06x}", design_colors.background & 0x00ff_ffff);
+        let resolved_bg_hex = self
+            .design_token_overrides
+            .resolve("background", &base_bg_hex)
+            .to_string();
+        let bg_hex = u32::from_str_radix(resolved_bg_hex.trim_start_matches('#'), 16)
+            .unwrap_or(design_colors.background);
         let bg_with_alpha = crate::ui_foundation::hex_to_rgba_with_opacity(bg_hex, opacity.main);
         let box_shadows = self.create_box_shadows();
 
@@ -1510,6 +1691,18 @@ impl ScriptListApp {
                 let key_str = event.keystroke.key.to_lowercase();
                 logging::log("KEY", &format!("DesignGallery key: '{}'", key_str));
 
+                // Cmd+Shift+C copies the current token overrides as a
+                // theme.json-shaped snippet, independent of the filter text.
+                if key_str == "c"
+                    && event.keystroke.modifiers.platform
+                    && event.keystroke.modifiers.shift
+                {
+                    let snippet = this.design_token_overrides.to_theme_json_snippet();
+                    cx.write_to_clipboard(gpui::ClipboardItem::new_string(snippet));
+                    this.show_hud("Copied theme.json snippet".to_string(), Some(2000), cx);
+                    return;
+                }
+
                 if let AppView::DesignGalleryView {
                     filter,
                     selected_index,
@@ -1845,7 +2038,13 @@ impl ScriptListApp {
                             .text_sm()
                             .text_color(rgb(text_dimmed))
                             .child(format!("{} items", filtered_len)),
-                    ),
+                    )
+                    .when(!self.design_token_overrides.is_empty(), |d| {
+                        d.child(div().text_sm().text_color(rgb(text_dimmed)).child(format!(
+                            "{} override(s) · ⌘⇧C to copy",
+                            self.design_token_overrides.len()
+                        )))
+                    }),
             )
             // Divider
             .child(
@@ -1876,6 +2075,244 @@ impl ScriptListApp {
             .into_any_element()
     }
 
+    /// Render the running scripts manager: lists background processes tracked
+    /// by [`process_manager::PROCESS_MANAGER`] and lets the user kill one.
+    fn render_running_scripts(
+        &mut self,
+        selected_index: usize,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let tokens = get_tokens(self.current_design);
+        let design_colors = tokens.colors();
+        let design_spacing = tokens.spacing();
+        let design_typography = tokens.typography();
+        let design_visual = tokens.visual();
+
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = crate::ui_foundation::hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        let text_primary = design_colors.text_primary;
+        let text_muted = design_colors.text_muted;
+        let text_dimmed = design_colors.text_dimmed;
+        let ui_border = design_colors.border;
+        let list_hover = design_colors.background_hover;
+        let list_selected = design_colors.background_selected;
+
+        let mut processes = process_manager::PROCESS_MANAGER.get_active_processes();
+        processes.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+        let process_count = processes.len();
+
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                if this.shortcut_recorder_state.is_some() {
+                    return;
+                }
+
+                if this.handle_global_shortcut_with_options(event, true, cx) {
+                    return;
+                }
+
+                let key_str = event.keystroke.key.to_lowercase();
+                logging::log("KEY", &format!("RunningScripts key: '{}'", key_str));
+
+                if let AppView::RunningScriptsView { selected_index } = &mut this.current_view {
+                    let mut processes = process_manager::PROCESS_MANAGER.get_active_processes();
+                    processes.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+
+                    match key_str.as_str() {
+                        "up" | "arrowup" => {
+                            if *selected_index > 0 {
+                                *selected_index -= 1;
+                                this.running_scripts_scroll_handle
+                                    .scroll_to_item(*selected_index, ScrollStrategy::Nearest);
+                                cx.notify();
+                            }
+                        }
+                        "down" | "arrowdown" => {
+                            if *selected_index + 1 < processes.len() {
+                                *selected_index += 1;
+                                this.running_scripts_scroll_handle
+                                    .scroll_to_item(*selected_index, ScrollStrategy::Nearest);
+                                cx.notify();
+                            }
+                        }
+                        "enter" => {
+                            if let Some(info) = processes.get(*selected_index) {
+                                logging::log(
+                                    "EXEC",
+                                    &format!(
+                                        "Killing background process {} ({})",
+                                        info.pid, info.script_path
+                                    ),
+                                );
+                                process_manager::PROCESS_MANAGER.kill_process(info.pid);
+                                process_manager::PROCESS_MANAGER.unregister_process(info.pid);
+                                if *selected_index > 0 && *selected_index >= processes.len() - 1 {
+                                    *selected_index -= 1;
+                                }
+                                cx.notify();
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            },
+        );
+
+        let processes_for_closure = processes.clone();
+        let current_selected = selected_index;
+
+        let list_element: AnyElement = if process_count == 0 {
+            div()
+                .w_full()
+                .py(px(design_spacing.padding_xl))
+                .text_center()
+                .text_color(rgb(text_dimmed))
+                .font_family(design_typography.font_family)
+                .child("No background scripts running")
+                .into_any_element()
+        } else {
+            uniform_list(
+                "running-scripts",
+                process_count,
+                move |visible_range, _window, _cx| {
+                    visible_range
+                        .map(|ix| {
+                            if let Some(info) = processes_for_closure.get(ix) {
+                                let is_selected = ix == current_selected;
+                                let bg = if is_selected {
+                                    rgba((list_selected << 8) | 0xFF)
+                                } else {
+                                    rgba(0x00000000)
+                                };
+                                let hover_bg = rgba((list_hover << 8) | 0x80);
+
+                                let name = std::path::Path::new(&info.script_path)
+                                    .file_name()
+                                    .map(|n| n.to_string_lossy().to_string())
+                                    .unwrap_or_else(|| info.script_path.clone());
+                                let elapsed =
+                                    chrono::Utc::now().signed_duration_since(info.started_at);
+                                let running_for = format_running_duration(elapsed.num_seconds());
+
+                                div()
+                                    .id(ix)
+                                    .w_full()
+                                    .h(px(52.))
+                                    .flex()
+                                    .flex_row()
+                                    .items_center()
+                                    .px(px(12.))
+                                    .gap(px(12.))
+                                    .bg(bg)
+                                    .hover(move |s| s.bg(hover_bg))
+                                    .child(
+                                        div()
+                                            .flex_1()
+                                            .flex()
+                                            .flex_col()
+                                            .gap(px(2.))
+                                            .child(
+                                                div()
+                                                    .text_sm()
+                                                    .text_color(rgb(text_primary))
+                                                    .child(name),
+                                            )
+                                            .child(
+                                                div()
+                                                    .text_xs()
+                                                    .text_color(rgb(text_dimmed))
+                                                    .child(info.script_path.clone()),
+                                            ),
+                                    )
+                                    .child(
+                                        div()
+                                            .flex()
+                                            .flex_col()
+                                            .items_end()
+                                            .gap(px(2.))
+                                            .child(
+                                                div()
+                                                    .text_xs()
+                                                    .text_color(rgb(text_dimmed))
+                                                    .child(format!("pid {}", info.pid)),
+                                            )
+                                            .child(
+                                                div()
+                                                    .text_xs()
+                                                    .text_color(rgb(text_dimmed))
+                                                    .child(running_for),
+                                            ),
+                                    )
+                            } else {
+                                div().id(ix).h(px(52.))
+                            }
+                        })
+                        .collect()
+                },
+            )
+            .h_full()
+            .track_scroll(&self.running_scripts_scroll_handle)
+            .into_any_element()
+        };
+
+        div()
+            .key_context("running_scripts")
+            .track_focus(&self.focus_handle)
+            .on_key_down(handle_key)
+            .w_full()
+            .h_full()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .rounded(px(design_visual.radius_lg))
+            .border(px(design_visual.border_thin))
+            .border_color(rgba((ui_border << 8) | 0x60))
+            .child(
+                div()
+                    .w_full()
+                    .px(px(design_spacing.padding_lg))
+                    .py(px(design_spacing.padding_md))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .justify_between()
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_primary))
+                            .child("Running Scripts"),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(rgb(text_dimmed))
+                            .child(format!("{} running", process_count)),
+                    ),
+            )
+            .child(
+                div()
+                    .mx(px(design_spacing.padding_lg))
+                    .h(px(design_visual.border_thin))
+                    .bg(rgba((ui_border << 8) | 0x60)),
+            )
+            .child(div().flex_1().min_h(px(0.)).w_full().child(list_element))
+            .child(PromptFooter::new(
+                PromptFooterConfig::new()
+                    .primary_label("Kill")
+                    .primary_shortcut("↵")
+                    .show_secondary(false),
+                PromptFooterColors::from_design(&design_colors),
+            ))
+            .into_any_element()
+    }
+
     /// Render file search view with 50/50 split (list + preview)
     pub(crate) fn render_file_search(
         &mut self,
@@ -2014,7 +2451,8 @@ impl ScriptListApp {
                             // Check for Cmd+Enter (reveal in finder)
                             if has_cmd && key_str == "enter" {
                                 if let Some((_, file)) = filtered_results.get(*selected_index) {
-                                    let _ = file_search::reveal_in_finder(&file.path);
+                                    let is_dir = file.file_type == FileType::Directory;
+                                    let _ = file_search::reveal_in_finder(&file.path, is_dir);
                                 }
                             }
                         }
@@ -2329,3 +2767,69 @@ impl ScriptListApp {
             .into_any_element()
     }
 }
+
+/// Filter `windows` by `filter` (matching title or app name, as the window
+/// switcher already did) and group the result by application, returning the
+/// filtered windows (in filtered order, matching the indices inside the
+/// returned rows) alongside the header/item rows ready for rendering.
+fn filter_and_group_windows(
+    windows: &[window_control::WindowInfo],
+    filter: &str,
+    frontmost_app: Option<&str>,
+) -> (Vec<window_control::WindowInfo>, Vec<GroupedListItem>) {
+    let filtered: Vec<window_control::WindowInfo> = if filter.is_empty() {
+        windows.to_vec()
+    } else {
+        let filter_norm = normalize_for_search(filter);
+        windows
+            .iter()
+            .filter(|w| {
+                normalize_for_search(&w.title).contains(&filter_norm)
+                    || normalize_for_search(&w.app).contains(&filter_norm)
+            })
+            .cloned()
+            .collect()
+    };
+
+    let groups = window_control::group_windows_by_app(&filtered, frontmost_app);
+    let mut rows = Vec::with_capacity(filtered.len() + groups.len());
+    for group in &groups {
+        rows.push(GroupedListItem::SectionHeader(group.app.clone()));
+        rows.extend(group.window_indices.iter().map(|&idx| GroupedListItem::Item(idx)));
+    }
+
+    (filtered, rows)
+}
+
+/// Look up the (app name, pid) for the app-group header containing `row_index`,
+/// if that row is a `SectionHeader` - used to resolve app-level actions
+/// (Quit App/Hide App/Close All Windows) for the selected header.
+fn header_app_and_pid_at(
+    rows: &[GroupedListItem],
+    filtered_windows: &[window_control::WindowInfo],
+    row_index: usize,
+) -> Option<(String, i32)> {
+    match rows.get(row_index)? {
+        GroupedListItem::SectionHeader(app) => rows[row_index + 1..]
+            .iter()
+            .take_while(|r| matches!(r, GroupedListItem::Item(_)))
+            .find_map(|r| match r {
+                GroupedListItem::Item(idx) => filtered_windows.get(*idx),
+                GroupedListItem::SectionHeader(_) => None,
+            })
+            .map(|w| (app.clone(), w.pid)),
+        GroupedListItem::Item(_) => None,
+    }
+}
+
+/// Format how long a running-scripts entry has been alive, e.g. "42s", "3m 12s".
+fn format_running_duration(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    if seconds < 60 {
+        format!("{}s", seconds)
+    } else if seconds < 3600 {
+        format!("{}m {}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}h {}m", seconds / 3600, (seconds % 3600) / 60)
+    }
+}
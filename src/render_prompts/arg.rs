@@ -85,12 +85,145 @@ impl ScriptListApp {
         }
     }
 
+    /// Feed a single keystroke into the arg prompt's text input, keeping
+    /// the choice selection and window height in sync with the new text.
+    ///
+    /// Shared by the real `on_key_down` handler above and by
+    /// `ExternalCommand::TypeText`, which calls this once per character so
+    /// typed text goes through the exact same path a real keystroke would.
+    fn handle_arg_text_key(
+        &mut self,
+        key_lower: &str,
+        key_char: Option<&str>,
+        has_cmd: bool,
+        has_alt: bool,
+        has_shift: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let old_text = self.arg_input.text().to_string();
+
+        // PRESERVE SELECTION: Capture the original index of the currently selected item
+        // BEFORE handle_key changes the text (which changes the filtered results)
+        let prev_original_idx = self
+            .filtered_arg_choices()
+            .get(self.arg_selected_index)
+            .map(|(orig_idx, _)| *orig_idx);
+
+        let handled = self
+            .arg_input
+            .handle_key(key_lower, key_char, has_cmd, has_alt, has_shift, cx);
+
+        if handled && self.arg_input.text() != old_text {
+            // Filtering changes which choice the selection refers to,
+            // so any pending confirmation is no longer valid.
+            self.arg_pending_confirm = None;
+
+            // Compute the new filtered list (based on new text)
+            // Extract the data we need to avoid borrow conflicts
+            let (new_selected_idx, filtered_len, has_choices) = {
+                let filtered = self.filtered_arg_choices();
+
+                // Try to find the previously selected item in the new filtered list
+                let new_idx = if let Some(prev_idx) = prev_original_idx {
+                    filtered
+                        .iter()
+                        .position(|(orig_idx, _)| *orig_idx == prev_idx)
+                        .unwrap_or(0)
+                } else {
+                    0
+                };
+
+                // Check if there are any choices at all
+                let has_choices = if let AppView::ArgPrompt { choices, .. } = &self.current_view {
+                    !choices.is_empty()
+                } else {
+                    false
+                };
+
+                (new_idx, filtered.len(), has_choices)
+            };
+
+            // Now update selection (borrow is dropped)
+            self.arg_selected_index = new_selected_idx;
+
+            // DEFERRED RESIZE: Avoid RefCell borrow error by deferring window resize
+            // to next frame. The native macOS setFrame:display:animate: call triggers
+            // callbacks that try to borrow the RefCell while GPUI still holds it.
+            let (view_type, item_count) = if filtered_len == 0 {
+                if has_choices {
+                    (ViewType::ArgPromptWithChoices, 0)
+                } else {
+                    (ViewType::ArgPromptNoChoices, 0)
+                }
+            } else {
+                (ViewType::ArgPromptWithChoices, filtered_len)
+            };
+            // Use window_ops for coalesced resize (avoids Timer::after pattern)
+            let target_height = crate::window_resize::height_for_view(view_type, item_count);
+            crate::window_ops::queue_resize(f32::from(target_height), window, &mut *cx);
+        }
+
+        handled
+    }
+
+    /// Render the currently-highlighted choice's `preview` text in a side
+    /// panel, mirroring the main list's doc preview (see
+    /// `render_preview_panel` in `app_render.rs`). Parses the text as
+    /// minimal markdown and falls back to a single plain-text paragraph
+    /// when it contains no heading/paragraph structure worth splitting.
+    fn render_choice_preview_panel(
+        &self,
+        preview: &str,
+        design_colors: &designs::DesignColors,
+        design_spacing: &designs::DesignSpacing,
+        design_visual: &designs::DesignVisual,
+    ) -> AnyElement {
+        let blocks = preview_doc::parse_minimal_markdown(preview);
+        let bg_search_box = design_colors.background_tertiary;
+        let text_primary = design_colors.text_primary;
+        let text_secondary = design_colors.text_secondary;
+
+        let mut panel = div()
+            .w_full()
+            .min_w(px(280.))
+            .h_full()
+            .p(px(design_spacing.padding_md))
+            .rounded(px(design_visual.radius_md))
+            .bg(rgba((bg_search_box << 8) | 0x80))
+            .overflow_hidden()
+            .flex()
+            .flex_col()
+            .gap(px(design_spacing.gap_sm));
+
+        for block in blocks {
+            panel = panel.child(match block {
+                preview_doc::MarkdownBlock::Heading(level, text) => div()
+                    .text_sm()
+                    .font_weight(if level <= 2 {
+                        gpui::FontWeight::SEMIBOLD
+                    } else {
+                        gpui::FontWeight::MEDIUM
+                    })
+                    .text_color(rgb(text_primary))
+                    .child(text),
+                preview_doc::MarkdownBlock::Paragraph(text) => {
+                    div().text_sm().text_color(rgb(text_secondary)).child(text)
+                }
+            });
+        }
+
+        panel.into_any_element()
+    }
+
     fn render_arg_prompt(
         &mut self,
         id: String,
         placeholder: String,
+        hint: Option<String>,
         choices: Vec<Choice>,
         actions: Option<Vec<ProtocolAction>>,
+        footer_hints: Option<Vec<FooterHint>>,
         cx: &mut Context<Self>,
     ) -> AnyElement {
         let _theme = &self.theme;
@@ -98,6 +231,20 @@ impl ScriptListApp {
         let has_actions = actions.is_some() && !actions.as_ref().unwrap().is_empty();
         let has_choices = !choices.is_empty();
 
+        // The confirmation text for the choice currently awaiting a second Enter,
+        // if any. `uniform_list` rows are fixed-height, so rather than literally
+        // expanding the selected row we surface this as a banner anchored above
+        // the list - same information, without breaking the list's virtualization.
+        let pending_confirm_text = self
+            .arg_pending_confirm
+            .as_deref()
+            .and_then(|pending_value| {
+                choices
+                    .iter()
+                    .find(|c| c.value == pending_value)
+                    .and_then(|c| c.confirm.clone())
+            });
+
         // Use design tokens for GLOBAL theming - all prompts use current design
         let tokens = get_tokens(self.current_design);
         let design_colors = tokens.colors();
@@ -119,6 +266,16 @@ impl ScriptListApp {
                     return;
                 }
 
+                let key = event.keystroke.key.as_str();
+
+                // A pending destructive-choice confirmation intercepts Escape so it
+                // cancels just the confirmation, not the whole prompt.
+                if this.arg_pending_confirm.is_some() && ui_foundation::is_key_escape(key) {
+                    this.arg_pending_confirm = None;
+                    cx.notify();
+                    return;
+                }
+
                 // Global shortcuts (Cmd+W, ESC for dismissable prompts)
                 // Note: Escape when actions popup is open should close the popup, not dismiss prompt
                 if !this.show_actions_popup
@@ -127,28 +284,53 @@ impl ScriptListApp {
                     return;
                 }
 
-                let key = event.keystroke.key.as_str();
                 let key_char = event.keystroke.key_char.as_deref();
                 let has_cmd = event.keystroke.modifiers.platform;
                 let modifiers = &event.keystroke.modifiers;
 
+                // The currently-selected choice's own actions, if any - these
+                // take priority over the arg prompt's script-level actions
+                // while that choice is selected (Cmd+K, and direct shortcuts
+                // below).
+                let selected_choice_actions = this
+                    .filtered_arg_choices()
+                    .get(this.arg_selected_index)
+                    .and_then(|(_, choice)| {
+                        choice
+                            .actions
+                            .clone()
+                            .filter(|actions| !actions.is_empty())
+                            .map(|actions| (choice.value.clone(), actions))
+                    });
+
                 // Check for Cmd+K to toggle actions popup (if actions are available)
-                if has_cmd && ui_foundation::is_key_k(key) && has_actions_for_handler {
-                    logging::log("KEY", "Cmd+K in ArgPrompt - calling toggle_arg_actions");
-                    this.toggle_arg_actions(cx, window);
-                    return;
+                if has_cmd && ui_foundation::is_key_k(key) {
+                    if let Some((choice_value, choice_actions)) = selected_choice_actions.clone() {
+                        logging::log("KEY", "Cmd+K in ArgPrompt - calling toggle_choice_actions");
+                        this.toggle_choice_actions(choice_value, choice_actions, cx, window);
+                        return;
+                    } else if has_actions_for_handler {
+                        logging::log("KEY", "Cmd+K in ArgPrompt - calling toggle_arg_actions");
+                        this.toggle_arg_actions(cx, window);
+                        return;
+                    }
                 }
 
                 // Route to shared actions dialog handler (modal when open)
                 match this.route_key_to_actions_dialog(
                     key,
                     key_char,
+                    &event.keystroke.modifiers,
                     ActionsDialogHost::ArgPrompt,
                     window,
                     cx,
                 ) {
                     ActionsRoute::Execute { action_id } => {
-                        this.trigger_action_by_name(&action_id, cx);
+                        if let Some(choice_value) = this.choice_actions_active.clone() {
+                            this.send_choice_action(prompt_id.clone(), choice_value, action_id, cx);
+                        } else {
+                            this.trigger_action_by_name(&action_id, cx);
+                        }
                         return;
                     }
                     ActionsRoute::Handled => {
@@ -164,6 +346,21 @@ impl ScriptListApp {
                 let key_lower = key.to_lowercase();
                 let shortcut_key =
                     shortcuts::keystroke_to_shortcut(&key_lower, &event.keystroke.modifiers);
+
+                // A declared shortcut on the selected choice's own actions
+                // fires directly, without opening the ActionsDialog.
+                if let Some((choice_value, choice_actions)) = selected_choice_actions {
+                    let shortcut_map = choice_actions::build_choice_shortcut_map(&choice_actions);
+                    if let Some(action_id) = shortcut_map.get(&shortcut_key).cloned() {
+                        logging::log(
+                            "KEY",
+                            &format!("Choice action shortcut matched: {}", action_id),
+                        );
+                        this.send_choice_action(prompt_id.clone(), choice_value, action_id, cx);
+                        return;
+                    }
+                }
+
                 if let Some(action_name) = this.action_shortcuts.get(&shortcut_key).cloned() {
                     logging::log(
                         "KEY",
@@ -173,10 +370,79 @@ impl ScriptListApp {
                     return;
                 }
 
+                // Cmd+1..9 quick-select - jump straight to the Nth visible
+                // choice without arrowing down to it. Only eligible when
+                // `arg_quick_select_hint` says so (<=9 visible choices, and
+                // Cmd+N isn't already claimed above by an SDK or per-choice
+                // action shortcut), so the hint shown in the row always
+                // matches what actually happens here.
+                if has_cmd {
+                    if let Some(digit) = key_lower
+                        .chars()
+                        .next()
+                        .filter(|c| key_lower.len() == 1 && c.is_ascii_digit())
+                        .and_then(|c| c.to_digit(10))
+                        .filter(|d| *d >= 1)
+                    {
+                        let visible_index = (digit - 1) as usize;
+                        // Gather the target choice's data as owned values (not
+                        // references borrowed from `this`) before mutating
+                        // `this` below.
+                        let quick_select_choice = {
+                            let filtered = this.filtered_arg_choices();
+                            let filtered_len = filtered.len();
+                            match filtered.get(visible_index) {
+                                Some((_, choice))
+                                    if this
+                                        .arg_quick_select_hint(visible_index, filtered_len, choice)
+                                        .is_some() =>
+                                {
+                                    Some((choice.value.clone(), choice.confirm.clone()))
+                                }
+                                _ => None,
+                            }
+                        };
+                        if let Some((value, confirm)) = quick_select_choice {
+                            logging::log(
+                                "KEY",
+                                &format!("Cmd+{} quick-select: '{}'", digit, value),
+                            );
+                            this.arg_selected_index = visible_index;
+                            if let Some(confirm_text) = confirm {
+                                if this.arg_pending_confirm.as_deref() == Some(value.as_str()) {
+                                    this.arg_pending_confirm = None;
+                                    this.submit_prompt_response(
+                                        prompt_id.clone(),
+                                        Some(value),
+                                        cx,
+                                    );
+                                } else {
+                                    logging::log(
+                                        "KEY",
+                                        &format!(
+                                            "Arg choice '{}' requires confirmation: {}",
+                                            value, confirm_text
+                                        ),
+                                    );
+                                    this.arg_pending_confirm = Some(value);
+                                    cx.notify();
+                                }
+                            } else {
+                                this.arg_pending_confirm = None;
+                                this.submit_prompt_response(prompt_id.clone(), Some(value), cx);
+                            }
+                            return;
+                        }
+                    }
+                }
+
                 // Arrow up/down: list navigation (use allocation-free helpers)
                 if ui_foundation::is_key_up(key) && !modifiers.shift {
                     if this.arg_selected_index > 0 {
                         this.arg_selected_index -= 1;
+                        // Moving the selection away from the choice awaiting
+                        // confirmation cancels the pending confirmation.
+                        this.arg_pending_confirm = None;
                         // P0: Scroll to keep selection visible
                         this.arg_list_scroll_handle
                             .scroll_to_item(this.arg_selected_index, ScrollStrategy::Nearest);
@@ -184,6 +450,16 @@ impl ScriptListApp {
                             "SCROLL",
                             &format!("P0: Arg up: selected_index={}", this.arg_selected_index),
                         );
+                        if this.split_prompt_id.is_some() {
+                            let filtered = this.filtered_arg_choices();
+                            if let Some((_, choice)) = filtered.get(this.arg_selected_index) {
+                                this.notify_selection_change(
+                                    choice.value.clone(),
+                                    this.arg_selected_index,
+                                    cx,
+                                );
+                            }
+                        }
                         cx.notify();
                     }
                     return;
@@ -193,6 +469,9 @@ impl ScriptListApp {
                     let filtered = this.filtered_arg_choices();
                     if this.arg_selected_index < filtered.len().saturating_sub(1) {
                         this.arg_selected_index += 1;
+                        // Moving the selection away from the choice awaiting
+                        // confirmation cancels the pending confirmation.
+                        this.arg_pending_confirm = None;
                         // P0: Scroll to keep selection visible
                         this.arg_list_scroll_handle
                             .scroll_to_item(this.arg_selected_index, ScrollStrategy::Nearest);
@@ -200,6 +479,16 @@ impl ScriptListApp {
                             "SCROLL",
                             &format!("P0: Arg down: selected_index={}", this.arg_selected_index),
                         );
+                        if this.split_prompt_id.is_some() {
+                            let filtered = this.filtered_arg_choices();
+                            if let Some((_, choice)) = filtered.get(this.arg_selected_index) {
+                                this.notify_selection_change(
+                                    choice.value.clone(),
+                                    this.arg_selected_index,
+                                    cx,
+                                );
+                            }
+                        }
                         cx.notify();
                     }
                     return;
@@ -208,9 +497,31 @@ impl ScriptListApp {
                 if ui_foundation::is_key_enter(key) {
                     let filtered = this.filtered_arg_choices();
                     if let Some((_, choice)) = filtered.get(this.arg_selected_index) {
-                        // Case 1: There are filtered choices - submit the selected one
-                        let value = choice.value.clone();
-                        this.submit_prompt_response(prompt_id.clone(), Some(value), cx);
+                        // Case 1: There are filtered choices - submit the selected one,
+                        // unless it's flagged as destructive and not yet confirmed.
+                        if let Some(confirm_text) = choice.confirm.clone() {
+                            if this.arg_pending_confirm.as_deref() == Some(choice.value.as_str()) {
+                                // Second Enter - confirmed, proceed with submission
+                                this.arg_pending_confirm = None;
+                                let value = choice.value.clone();
+                                this.submit_prompt_response(prompt_id.clone(), Some(value), cx);
+                            } else {
+                                // First Enter on a destructive choice - ask for confirmation
+                                logging::log(
+                                    "KEY",
+                                    &format!(
+                                        "Arg choice '{}' requires confirmation: {}",
+                                        choice.value, confirm_text
+                                    ),
+                                );
+                                this.arg_pending_confirm = Some(choice.value.clone());
+                                cx.notify();
+                            }
+                        } else {
+                            this.arg_pending_confirm = None;
+                            let value = choice.value.clone();
+                            this.submit_prompt_response(prompt_id.clone(), Some(value), cx);
+                        }
                     } else if !this.arg_input.is_empty() {
                         // Case 2: No choices but user typed something - submit input text
                         let value = this.arg_input.text().to_string();
@@ -221,73 +532,16 @@ impl ScriptListApp {
                 }
 
                 // Delegate all other keys to TextInputState for editing, selection, clipboard
-                let old_text = this.arg_input.text().to_string();
-
-                // PRESERVE SELECTION: Capture the original index of the currently selected item
-                // BEFORE handle_key changes the text (which changes the filtered results)
-                let prev_original_idx = this
-                    .filtered_arg_choices()
-                    .get(this.arg_selected_index)
-                    .map(|(orig_idx, _)| *orig_idx);
-
-                let handled = this.arg_input.handle_key(
+                let handled = this.handle_arg_text_key(
                     &key_lower,
                     key_char,
                     modifiers.platform, // Cmd key on macOS
                     modifiers.alt,
                     modifiers.shift,
+                    window,
                     cx,
                 );
-
                 if handled {
-                    // If text changed (not just cursor move), update selection and resize
-                    if this.arg_input.text() != old_text {
-                        // Compute the new filtered list (based on new text)
-                        // Extract the data we need to avoid borrow conflicts
-                        let (new_selected_idx, filtered_len, has_choices) = {
-                            let filtered = this.filtered_arg_choices();
-
-                            // Try to find the previously selected item in the new filtered list
-                            let new_idx = if let Some(prev_idx) = prev_original_idx {
-                                filtered
-                                    .iter()
-                                    .position(|(orig_idx, _)| *orig_idx == prev_idx)
-                                    .unwrap_or(0)
-                            } else {
-                                0
-                            };
-
-                            // Check if there are any choices at all
-                            let has_choices =
-                                if let AppView::ArgPrompt { choices, .. } = &this.current_view {
-                                    !choices.is_empty()
-                                } else {
-                                    false
-                                };
-
-                            (new_idx, filtered.len(), has_choices)
-                        };
-
-                        // Now update selection (borrow is dropped)
-                        this.arg_selected_index = new_selected_idx;
-
-                        // DEFERRED RESIZE: Avoid RefCell borrow error by deferring window resize
-                        // to next frame. The native macOS setFrame:display:animate: call triggers
-                        // callbacks that try to borrow the RefCell while GPUI still holds it.
-                        let (view_type, item_count) = if filtered_len == 0 {
-                            if has_choices {
-                                (ViewType::ArgPromptWithChoices, 0)
-                            } else {
-                                (ViewType::ArgPromptNoChoices, 0)
-                            }
-                        } else {
-                            (ViewType::ArgPromptWithChoices, filtered_len)
-                        };
-                        // Use window_ops for coalesced resize (avoids Timer::after pattern)
-                        let target_height =
-                            crate::window_resize::height_for_view(view_type, item_count);
-                        crate::window_ops::queue_resize(f32::from(target_height), window, &mut *cx);
-                    }
                     cx.notify();
                 }
             },
@@ -305,6 +559,13 @@ impl ScriptListApp {
         let arg_selected_index = self.arg_selected_index;
         let filtered_choices = self.get_filtered_arg_choices_owned();
         let filtered_choices_len = filtered_choices.len();
+        // Dim "⌘1".."⌘9" row hints for Cmd+N quick-select - see
+        // `arg_quick_select_hint` for the eligibility/conflict rules.
+        let quick_select_hints: Vec<Option<String>> = filtered_choices
+            .iter()
+            .enumerate()
+            .map(|(ix, (_, choice))| self.arg_quick_select_hint(ix, filtered_choices_len, choice))
+            .collect();
         logging::log_debug(
             "UI",
             &format!(
@@ -313,6 +574,25 @@ impl ScriptListApp {
             ),
         );
 
+        // The preview pane for the currently-highlighted choice, in priority
+        // order: a per-choice `Choice.preview` bundled up front, then any
+        // `Message::Preview` content cached lazily for this choice's value,
+        // then the split prompt's own live preview
+        // (`Message::Split`/`Message::SetPreview`) as a final fallback.
+        let selected_choice_preview: Option<AnyElement> = filtered_choices
+            .get(arg_selected_index)
+            .and_then(|(_, choice)| {
+                choice.preview.as_deref().or_else(|| {
+                    self.preview_content_cache
+                        .get(&choice.value)
+                        .map(|s| s.as_str())
+                })
+            })
+            .or(self.split_preview.as_deref())
+            .map(|preview| {
+                self.render_choice_preview_panel(preview, &design_colors, &design_spacing, &design_visual)
+            });
+
         // P0: Build virtualized choice list using uniform_list
         let list_element: AnyElement = if filtered_choices_len == 0 {
             div()
@@ -339,16 +619,30 @@ impl ScriptListApp {
                             if let Some((_, choice)) = filtered_choices.get(ix) {
                                 let is_selected = ix == arg_selected_index;
 
+                                // Thumbnail wins over the emoji icon when both are set -
+                                // it's the richer visual. Only a cache lookup here; decoding
+                                // happens off the render thread (see spawn_choice_image_prewarm).
+                                let icon_kind = choice
+                                    .img
+                                    .as_deref()
+                                    .and_then(choice_image::get_cached_choice_image)
+                                    .map(list_item::IconKind::Image)
+                                    .or_else(|| choice.icon.clone().map(list_item::IconKind::Emoji));
+
                                 // Use shared ListItem component for consistent design
                                 div().id(ix).child(
                                     ListItem::new(choice.name.clone(), arg_list_colors)
                                         .description_opt(choice.description.clone())
+                                        .icon_kind_opt(icon_kind)
                                         .selected(is_selected)
                                         .with_accent_bar(true)
-                                        .index(ix),
+                                        .index(ix)
+                                        .shortcut_opt(
+                                            quick_select_hints.get(ix).cloned().flatten(),
+                                        ),
                                 )
                             } else {
-                                div().id(ix).h(px(LIST_ITEM_HEIGHT))
+                                div().id(ix).h(px(density::list_item_height()))
                             }
                         })
                         .collect()
@@ -392,6 +686,11 @@ impl ScriptListApp {
                     .flex_row()
                     .items_center()
                     .gap(px(HEADER_GAP))
+                    // Subtle back affordance - only shown once there's a previous prompt
+                    // to return to (Cmd+[ is the keyboard equivalent)
+                    .when(self.can_go_back(), |d| {
+                        d.child(div().text_xs().text_color(rgb(text_muted)).child("← Back"))
+                    })
                     // Search input with cursor and selection support
                     // Use explicit height matching main menu: CURSOR_HEIGHT_LG + 2*CURSOR_MARGIN_Y = 22px
                     .child({
@@ -440,8 +739,60 @@ impl ScriptListApp {
                             .when(!input_is_empty, |d: gpui::Div| {
                                 d.child(self.render_arg_input_text(text_primary, accent_color))
                             })
+                    })
+                    // Subtle loading indicator while the script streams in more
+                    // choices via setPlaceholderChoices
+                    .when(self.arg_choices_loading, |d| {
+                        d.child(div().text_xs().text_color(rgb(text_muted)).child("⟳"))
                     }),
             )
+            // Hint line set via setHint (dim, below the header)
+            .when_some(hint.clone(), |d, hint| {
+                d.child(
+                    div()
+                        .w_full()
+                        .px(px(HEADER_PADDING_X))
+                        .pb(px(design_spacing.padding_xs))
+                        .text_xs()
+                        .text_color(rgb(text_muted))
+                        .child(hint),
+                )
+            })
+            // Destructive-choice confirmation banner - shown instead of the normal
+            // hint while a choice flagged with `confirm` is awaiting a second Enter
+            .when_some(pending_confirm_text.clone(), |d, confirm_text| {
+                d.child(
+                    div()
+                        .w_full()
+                        .px(px(HEADER_PADDING_X))
+                        .py(px(design_spacing.padding_xs))
+                        .flex()
+                        .flex_row()
+                        .items_center()
+                        .justify_between()
+                        .gap(px(design_spacing.padding_md))
+                        .bg(rgba((design_colors.error << 8) | 0x20))
+                        .border_l(px(design_visual.border_thin * 2.0))
+                        .border_color(rgb(design_colors.error))
+                        .child(
+                            div()
+                                .flex_1()
+                                .text_sm()
+                                .text_color(rgb(design_colors.error))
+                                .child(confirm_text),
+                        )
+                        .child(
+                            div()
+                                .flex()
+                                .flex_row()
+                                .gap(px(design_spacing.padding_sm))
+                                .text_xs()
+                                .text_color(rgb(design_colors.error))
+                                .child("Confirm (Enter)")
+                                .child("Cancel (Esc)"),
+                        ),
+                )
+            })
             // Choices list (only when prompt has choices)
             .when(has_choices, |d| {
                 d.child(
@@ -453,35 +804,94 @@ impl ScriptListApp {
                 .child(
                     div()
                         .flex()
-                        .flex_col()
+                        .flex_row()
                         .flex_1()
                         .min_h(px(0.)) // P0: Allow flex container to shrink
                         .w_full()
                         .py(px(design_spacing.padding_xs))
-                        .child(list_element),
+                        .gap(px(design_spacing.gap_sm))
+                        .child(
+                            div()
+                                .flex()
+                                .flex_col()
+                                .flex_1()
+                                .min_h(px(0.))
+                                .child(list_element),
+                        )
+                        // Preview pane for the highlighted choice, when it set one
+                        .when_some(selected_choice_preview, |d, preview| d.child(preview)),
                 )
             })
             // Footer with unified actions
             .child({
                 let footer_colors = PromptFooterColors::from_design(&design_colors);
-                let footer_config = PromptFooterConfig::new()
+                let mut footer_config = PromptFooterConfig::new()
                     .primary_label("Submit")
                     .primary_shortcut("↵")
                     .secondary_label("Actions")
                     .secondary_shortcut("⌘K")
                     .show_secondary(has_actions);
 
+                // A script-provided footer_hints list replaces the default
+                // Submit/Actions buttons with its own chips; clicking a chip
+                // dispatches through the same action_shortcuts lookup real
+                // key presses use (see route_key_to_actions_dialog).
+                let custom_hints = footer_hints.filter(|hints| !hints.is_empty());
+                if let Some(ref hints) = custom_hints {
+                    footer_config = footer_config.custom_hints(hints.clone());
+                }
+
                 // Create click handlers
                 let prompt_id_for_primary = id.clone();
                 let handle_primary = cx.entity().downgrade();
                 let handle_secondary = cx.entity().downgrade();
+                let handle_hints = cx.entity().downgrade();
+                let hints_for_click = custom_hints.clone().unwrap_or_default();
 
                 PromptFooter::new(footer_config, footer_colors)
+                    .on_hint_click(Box::new(move |index, _event, _window, cx| {
+                        if let Some(app) = handle_hints.upgrade() {
+                            app.update(cx, |this, cx| {
+                                if let Some(hint) = hints_for_click.get(index) {
+                                    let normalized = shortcuts::normalize_shortcut(&hint.shortcut);
+                                    if let Some(action_name) =
+                                        this.action_shortcuts.get(&normalized).cloned()
+                                    {
+                                        this.trigger_action_by_name(&action_name, cx);
+                                    }
+                                }
+                            });
+                        }
+                    }))
                     .on_primary_click(Box::new(move |_, _window, cx| {
                         if let Some(app) = handle_primary.upgrade() {
                             app.update(cx, |this, cx| {
                                 let filtered = this.filtered_arg_choices();
                                 if let Some((_, choice)) = filtered.get(this.arg_selected_index) {
+                                    if let Some(confirm_text) = choice.confirm.clone() {
+                                        if this.arg_pending_confirm.as_deref()
+                                            == Some(choice.value.as_str())
+                                        {
+                                            this.arg_pending_confirm = None;
+                                            let value = choice.value.clone();
+                                            this.submit_prompt_response(
+                                                prompt_id_for_primary.clone(),
+                                                Some(value),
+                                                cx,
+                                            );
+                                        } else {
+                                            logging::log(
+                                                "KEY",
+                                                &format!(
+                                                    "Arg choice '{}' requires confirmation: {}",
+                                                    choice.value, confirm_text
+                                                ),
+                                            );
+                                            this.arg_pending_confirm = Some(choice.value.clone());
+                                            cx.notify();
+                                        }
+                                        return;
+                                    }
                                     let value = choice.value.clone();
                                     this.submit_prompt_response(
                                         prompt_id_for_primary.clone(),
@@ -6,6 +6,7 @@ impl ScriptListApp {
         &mut self,
         id: String,
         entity: Entity<DivPrompt>,
+        footer_hints: Option<Vec<FooterHint>>,
         cx: &mut Context<Self>,
     ) -> AnyElement {
         let has_actions =
@@ -54,6 +55,7 @@ impl ScriptListApp {
                 match this.route_key_to_actions_dialog(
                     key,
                     key_char,
+                    &event.keystroke.modifiers,
                     ActionsDialogHost::DivPrompt,
                     window,
                     cx,
@@ -89,14 +91,25 @@ impl ScriptListApp {
         };
 
         // Footer config with Submit as primary action
-        let footer_config = PromptFooterConfig::new()
+        let mut footer_config = PromptFooterConfig::new()
             .primary_label("Submit")
             .primary_shortcut("↵")
             .show_secondary(has_actions);
 
+        // A script-provided footer_hints list replaces the default
+        // Submit/Actions buttons with its own chips; clicking a chip
+        // dispatches through the same action_shortcuts lookup real
+        // key presses use (see route_key_to_actions_dialog).
+        let custom_hints = footer_hints.filter(|hints| !hints.is_empty());
+        if let Some(ref hints) = custom_hints {
+            footer_config = footer_config.custom_hints(hints.clone());
+        }
+
         // Create click handlers for footer
         let handle_submit = cx.entity().downgrade();
         let handle_actions = cx.entity().downgrade();
+        let handle_hints = cx.entity().downgrade();
+        let hints_for_click = custom_hints.clone().unwrap_or_default();
         let prompt_id = id.clone();
 
         div()
@@ -123,6 +136,20 @@ impl ScriptListApp {
             // Footer with Submit button and Actions
             .child(
                 PromptFooter::new(footer_config, footer_colors)
+                    .on_hint_click(Box::new(move |index, _event, _window, cx| {
+                        if let Some(app) = handle_hints.upgrade() {
+                            app.update(cx, |this, cx| {
+                                if let Some(hint) = hints_for_click.get(index) {
+                                    let normalized = shortcuts::normalize_shortcut(&hint.shortcut);
+                                    if let Some(action_name) =
+                                        this.action_shortcuts.get(&normalized).cloned()
+                                    {
+                                        this.trigger_action_by_name(&action_name, cx);
+                                    }
+                                }
+                            });
+                        }
+                    }))
                     .on_primary_click(Box::new(move |_, _window, cx| {
                         if let Some(app) = handle_submit.upgrade() {
                             let id = prompt_id.clone();
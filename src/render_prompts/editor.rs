@@ -5,6 +5,7 @@ impl ScriptListApp {
     fn render_editor_prompt(
         &mut self,
         entity: Entity<EditorPrompt>,
+        footer_hints: Option<Vec<FooterHint>>,
         cx: &mut Context<Self>,
     ) -> AnyElement {
         let has_actions =
@@ -68,6 +69,7 @@ impl ScriptListApp {
                 match this.route_key_to_actions_dialog(
                     key,
                     key_char,
+                    &event.keystroke.modifiers,
                     ActionsDialogHost::EditorPrompt,
                     window,
                     cx,
@@ -195,8 +197,33 @@ impl ScriptListApp {
                     footer_config = footer_config.helper_text(helper.clone());
                 }
 
-                let mut footer = PromptFooter::new(footer_config, footer_colors).on_primary_click(
-                    Box::new(move |_, _window, cx| {
+                // A script-provided footer_hints list replaces the default
+                // Submit/Actions buttons with its own chips; clicking a chip
+                // dispatches through the same action_shortcuts lookup real
+                // key presses use (see route_key_to_actions_dialog).
+                let custom_hints = footer_hints.filter(|hints| !hints.is_empty());
+                if let Some(ref hints) = custom_hints {
+                    footer_config = footer_config.custom_hints(hints.clone());
+                }
+                let handle_hints = cx.entity().downgrade();
+                let hints_for_click = custom_hints.clone().unwrap_or_default();
+
+                let mut footer = PromptFooter::new(footer_config, footer_colors)
+                    .on_hint_click(Box::new(move |index, _event, _window, cx| {
+                        if let Some(app) = handle_hints.upgrade() {
+                            app.update(cx, |this, cx| {
+                                if let Some(hint) = hints_for_click.get(index) {
+                                    let normalized = shortcuts::normalize_shortcut(&hint.shortcut);
+                                    if let Some(action_name) =
+                                        this.action_shortcuts.get(&normalized).cloned()
+                                    {
+                                        this.trigger_action_by_name(&action_name, cx);
+                                    }
+                                }
+                            });
+                        }
+                    }))
+                    .on_primary_click(Box::new(move |_, _window, cx| {
                         // Get editor content and submit
                         if let Some(editor_entity) = entity_weak.upgrade() {
                             let content = editor_entity.update(cx, |editor, cx| editor.content(cx));
@@ -65,6 +65,7 @@ impl ScriptListApp {
                 match this.route_key_to_actions_dialog(
                     key,
                     key_char,
+                    &event.keystroke.modifiers,
                     ActionsDialogHost::FormPrompt,
                     window,
                     cx,
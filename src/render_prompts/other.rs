@@ -178,4 +178,49 @@ impl ScriptListApp {
             .child(div().size_full().child(entity))
             .into_any_element()
     }
+
+    fn render_confirm_prompt(
+        &mut self,
+        entity: Entity<ConfirmPrompt>,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        // Use design tokens for GLOBAL theming
+        let tokens = get_tokens(self.current_design);
+        let design_colors = tokens.colors();
+        let design_visual = tokens.visual();
+
+        // Use design tokens for global theming
+        let opacity = self.theme.get_opacity();
+        let bg_hex = design_colors.background;
+        let bg_with_alpha = crate::ui_foundation::hex_to_rgba_with_opacity(bg_hex, opacity.main);
+        let box_shadows = self.create_box_shadows();
+
+        // Key handler for global shortcuts (Cmd+W only - ESC is NOT dismissable here).
+        // Unlike other prompts, ESC on a confirm dialog means "answer no" and should
+        // let the script keep running to branch on that answer, not kill the script
+        // the way the generic ESC-to-cancel path does.
+        let handle_key = cx.listener(
+            move |this: &mut Self,
+                  event: &gpui::KeyDownEvent,
+                  _window: &mut Window,
+                  cx: &mut Context<Self>| {
+                let _ = this.handle_global_shortcut_with_options(event, false, cx);
+            },
+        );
+
+        // ConfirmPrompt entity has its own track_focus and on_key_down in its render method.
+        // We wrap with our own handler to intercept Cmd+W first.
+        div()
+            .flex()
+            .flex_col()
+            .bg(rgba(bg_with_alpha))
+            .shadow(box_shadows)
+            .w_full()
+            .h_full()
+            .overflow_hidden()
+            .rounded(px(design_visual.radius_lg))
+            .on_key_down(handle_key)
+            .child(div().size_full().child(entity))
+            .into_any_element()
+    }
 }
@@ -67,6 +67,7 @@ impl ScriptListApp {
                 match this.route_key_to_actions_dialog(
                     key,
                     key_char,
+                    &event.keystroke.modifiers,
                     ActionsDialogHost::TermPrompt,
                     window,
                     cx,
@@ -172,9 +172,9 @@ impl ScriptListApp {
                 }
             }
 
-            // Calculate true content height: headers at 24px, items at 48px
-            let total_content_height = (header_count as f32 * SECTION_HEADER_HEIGHT)
-                + (item_count_regular as f32 * LIST_ITEM_HEIGHT);
+            // Calculate true content height at the current density
+            let total_content_height = (header_count as f32 * density::section_header_height())
+                + (item_count_regular as f32 * density::list_item_height());
 
             // Estimated visible container height
             // Window is 500px, header is ~60px, remaining ~440px for list area
@@ -194,11 +194,63 @@ impl ScriptListApp {
             // Note: list state updates and scroll_to_selected_if_needed already done above
             // before the theme borrow section
 
-            // Create scrollbar using pre-computed scrollbar_colors and scroll_offset
+            // Create scrollbar using pre-computed scrollbar_colors and scroll_offset.
+            // Approximate header height (same assumption `estimated_container_height`
+            // above is built on) so clicks/drags can be mapped onto a target item.
+            let track_origin_y = 60.0_f32;
+            let scrollbar_entity = cx.entity();
+            let scrollbar_item_count = item_count;
             let scrollbar =
                 Scrollbar::new(item_count, visible_items, scroll_offset, scrollbar_colors)
                     .container_height(estimated_container_height)
-                    .visible(self.is_scrolling);
+                    .track_origin_y(track_origin_y)
+                    .visible(self.is_scrolling)
+                    .dragging(self.scrollbar_dragging)
+                    .on_scroll({
+                        let entity = scrollbar_entity.clone();
+                        move |ratio, absolute, _window, cx| {
+                            entity.update(cx, |this, cx| {
+                                if !absolute && !this.scrollbar_dragging {
+                                    // Plain track click: page toward the click rather than
+                                    // snapping straight there.
+                                    let direction = if ratio
+                                        > this.main_list_state.logical_scroll_top().item_ix as f32
+                                            / scrollbar_item_count.max(1) as f32
+                                    {
+                                        visible_items as i32
+                                    } else {
+                                        -(visible_items as i32)
+                                    };
+                                    this.move_selection_by(direction, cx);
+                                    return;
+                                }
+                                let target = ((ratio * scrollbar_item_count as f32) as usize)
+                                    .min(scrollbar_item_count.saturating_sub(1));
+                                this.main_list_state.scroll_to_reveal_item(target);
+                                this.trigger_scroll_activity(cx);
+                            });
+                        }
+                    })
+                    .on_drag_changed({
+                        let entity = scrollbar_entity.clone();
+                        move |dragging, _window, cx| {
+                            entity.update(cx, |this, cx| {
+                                this.scrollbar_dragging = dragging;
+                                this.trigger_scroll_activity(cx);
+                            });
+                        }
+                    })
+                    .on_hover_changed({
+                        let entity = scrollbar_entity.clone();
+                        move |hovered, _window, cx| {
+                            entity.update(cx, |this, cx| {
+                                this.scrollbar_hovered = hovered;
+                                if hovered {
+                                    this.trigger_scroll_activity(cx);
+                                }
+                            });
+                        }
+                    });
 
             // Capture entity handle for use in the render closure
             let entity = cx.entity();
@@ -216,18 +268,35 @@ impl ScriptListApp {
                         if let Some(grouped_item) = grouped_items_clone.get(ix) {
                             match grouped_item {
                                 GroupedListItem::SectionHeader(label) => {
-                                    // Section header at 24px height (SECTION_HEADER_HEIGHT)
+                                    // Section header height, density-aware
+                                    let item_count = this
+                                        .section_item_counts
+                                        .get(label)
+                                        .copied()
+                                        .unwrap_or(0);
+                                    let collapsed = this.collapsed_sections.is_collapsed(label);
+                                    let header_label = label.clone();
                                     div()
                                         .id(ElementId::NamedInteger(
                                             "section-header".into(),
                                             ix as u64,
                                         ))
-                                        .h(px(SECTION_HEADER_HEIGHT))
-                                        .child(render_section_header(label, theme_colors))
+                                        .h(px(density::section_header_height()))
+                                        .on_click(cx.listener(
+                                            move |this: &mut ScriptListApp, _event, _window, cx| {
+                                                this.toggle_section_collapsed(&header_label, cx);
+                                            },
+                                        ))
+                                        .child(render_section_header(
+                                            label,
+                                            theme_colors,
+                                            item_count,
+                                            collapsed,
+                                        ))
                                         .into_any_element()
                                 }
                                 GroupedListItem::Item(result_idx) => {
-                                    // Regular item at 48px height (LIST_ITEM_HEIGHT)
+                                    // Regular item height, density-aware
                                     if let Some(result) = flat_results_clone.get(*result_idx) {
                                         let is_selected = ix == current_selected;
                                         let is_hovered = current_hovered == Some(ix);
@@ -300,6 +369,15 @@ impl ScriptListApp {
                                             _ => false,
                                         };
 
+                                        // Usage annotation (e.g. "2h ago · 14 runs"), absent
+                                        // for items with no frecency history yet.
+                                        let frecency_label =
+                                            scripts::frecency_key_for_result(result)
+                                                .and_then(|key| {
+                                                    this.frecency_store.get_entry(&key)
+                                                })
+                                                .map(|entry| entry.usage_annotation());
+
                                         // Dispatch to design-specific item renderer
                                         let item_element = render_design_item(
                                             current_design,
@@ -308,6 +386,7 @@ impl ScriptListApp {
                                             is_selected,
                                             is_hovered,
                                             theme_colors,
+                                            frecency_label,
                                         );
 
                                         // Wrap with confirmation overlay if pending
@@ -323,7 +402,7 @@ impl ScriptListApp {
 
                                             div()
                                                 .w_full()
-                                                .h(px(LIST_ITEM_HEIGHT))
+                                                .h(px(density::list_item_height()))
                                                 .flex()
                                                 .items_center()
                                                 .px(px(16.))
@@ -346,20 +425,20 @@ impl ScriptListApp {
                                                 "script-item".into(),
                                                 ix as u64,
                                             ))
-                                            .h(px(LIST_ITEM_HEIGHT)) // Explicit 48px height
+                                            .h(px(density::list_item_height())) // Explicit height at current density
                                             .on_hover(hover_handler)
                                             .on_click(click_handler)
                                             .child(final_element)
                                             .into_any_element()
                                     } else {
                                         // Fallback for missing result
-                                        div().h(px(LIST_ITEM_HEIGHT)).into_any_element()
+                                        div().h(px(density::list_item_height())).into_any_element()
                                     }
                                 }
                             }
                         } else {
                             // Fallback for out-of-bounds index
-                            div().h(px(LIST_ITEM_HEIGHT)).into_any_element()
+                            div().h(px(density::list_item_height())).into_any_element()
                         }
                     })
                 })
@@ -376,7 +455,7 @@ impl ScriptListApp {
             // which works correctly like keyboard navigation does.
             //
             // Average item height for delta-to-index conversion:
-            // Most items are LIST_ITEM_HEIGHT (48px), headers are SECTION_HEADER_HEIGHT (24px)
+            // Most items and headers are sized via density::list_item_height()/section_header_height()
             // Use 44px as a reasonable average that feels natural for scrolling
             let avg_item_height = 44.0_f32;
 
@@ -392,6 +471,12 @@ impl ScriptListApp {
                 .h_full()
                 .on_scroll_wheel(cx.listener(
                     move |this, event: &gpui::ScrollWheelEvent, _window, cx| {
+                        // Show the scrollbar for the wheel event itself, not just when it
+                        // accumulates into a selection change below - otherwise slow
+                        // trackpad scrolls (steps == 0) or scrolling at a list boundary
+                        // (selection already clamped) never fade the scrollbar in.
+                        this.trigger_scroll_activity(cx);
+
                         // Convert scroll delta to lines/items
                         // Lines: direct item count, Pixels: convert based on average item height
                         let delta_lines: f32 = match event.delta {
@@ -553,6 +638,23 @@ impl ScriptListApp {
                             this.handle_action("quit".to_string(), cx);
                             return;
                         }
+                        // Cmd+Shift+D - toggle compact/comfortable density
+                        // (plain Cmd+D is not used elsewhere; Cmd+K above is
+                        // already the actions popup toggle)
+                        "d" if has_shift => {
+                            this.toggle_density(cx);
+                            return;
+                        }
+                        // Cmd+Shift+Left/Right - collapse/expand the section
+                        // the current selection is in
+                        "left" | "arrowleft" if has_shift => {
+                            this.toggle_current_section_collapsed(cx);
+                            return;
+                        }
+                        "right" | "arrowright" if has_shift => {
+                            this.toggle_current_section_collapsed(cx);
+                            return;
+                        }
                         _ => {}
                     }
                 }
@@ -694,6 +796,9 @@ impl ScriptListApp {
 
                 // Normal script list navigation
                 match key_str.as_str() {
+                    "up" | "arrowup" if this.should_recall_filter_history() => {
+                        this.recall_previous_filter_history(window, cx);
+                    }
                     "up" | "arrowup" => {
                         let _key_perf = crate::perf::KeyEventPerfGuard::new();
                         match this.nav_coalescer.record(NavDirection::Up) {
@@ -710,6 +815,8 @@ impl ScriptListApp {
                     }
                     "down" | "arrowdown" => {
                         let _key_perf = crate::perf::KeyEventPerfGuard::new();
+                        // Leaving the top of the list exits filter-history browsing
+                        this.filter_history_cursor = None;
                         match this.nav_coalescer.record(NavDirection::Down) {
                             NavRecord::ApplyImmediate => this.move_selection_down(cx),
                             NavRecord::Coalesced => {}
@@ -722,6 +829,12 @@ impl ScriptListApp {
                         }
                         this.ensure_nav_flush_task(cx);
                     }
+                    // Left/Right collapse/expand the section the current
+                    // selection is in. Ignored while the filter input has
+                    // focus so normal cursor movement still works.
+                    "left" | "arrowleft" | "right" | "arrowright" if !this.gpui_input_focused => {
+                        this.toggle_current_section_collapsed(cx);
+                    }
                     "enter" => {
                         if !this.gpui_input_focused {
                             this.execute_selected(cx);
@@ -890,19 +1003,41 @@ impl ScriptListApp {
                     .items_center()
                     .gap(px(header_gap))
                     // Search input with cursor and selection support
-                    .child(
-                        div().flex_1().flex().flex_row().items_center().child(
-                            Input::new(&self.gpui_input_state)
-                                .w_full()
-                                .h(px(input_height))
-                                .px(px(0.))
-                                .py(px(0.))
-                                .with_size(Size::Size(px(design_typography.font_size_xl)))
-                                .appearance(false)
-                                .bordered(false)
-                                .focus_bordered(false),
-                        ),
-                    )
+                    .child({
+                        let (scope, _) = parse_search_scope(&self.filter_text);
+                        let scope_bg = (accent_color << 8) | 0x26; // 15% opacity
+                        div()
+                            .flex_1()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .gap(px(6.))
+                            .when_some(scope, |d, scope| {
+                                d.child(
+                                    div()
+                                        .flex()
+                                        .items_center()
+                                        .px(px(6.))
+                                        .py(px(2.))
+                                        .rounded(px(4.))
+                                        .bg(rgba(scope_bg))
+                                        .text_xs()
+                                        .text_color(rgb(accent_color))
+                                        .child(scope.label()),
+                                )
+                            })
+                            .child(
+                                Input::new(&self.gpui_input_state)
+                                    .w_full()
+                                    .h(px(input_height))
+                                    .px(px(0.))
+                                    .py(px(0.))
+                                    .with_size(Size::Size(px(design_typography.font_size_xl)))
+                                    .appearance(false)
+                                    .bordered(false)
+                                    .focus_bordered(false),
+                            )
+                    })
                     // "Ask AI [Tab]" button - yellow text, grey badge, hover state
                     .child({
                         // Hover background: accent color at 15% opacity
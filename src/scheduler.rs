@@ -20,6 +20,8 @@ use std::thread::{self, JoinHandle};
 use std::time::Duration;
 use tracing::{debug, info, warn};
 
+use crate::scheduler_catchup::{self, MissedRunsPolicy};
+
 /// Indicates whether the schedule came from a raw cron expression or natural language.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ScheduleSource {
@@ -41,13 +43,20 @@ pub struct ScheduledScript {
     /// Whether this schedule came from Cron: or Schedule: metadata
     #[allow(dead_code)]
     pub source: ScheduleSource,
+    /// Catch-up policy for occurrences missed while asleep/closed
+    /// (`// MissedRuns:` metadata)
+    pub missed_runs: MissedRunsPolicy,
 }
 
 /// Events emitted by the scheduler.
 #[derive(Debug, Clone)]
 pub enum SchedulerEvent {
-    /// A script is due to run
+    /// A script is due to run at its regular next-scheduled time
     RunScript(PathBuf),
+    /// A script is running to catch up on an occurrence that was missed
+    /// while asleep/closed, per its `// MissedRuns:` policy. Handlers should
+    /// label these distinctly in logs/toasts (see request synth-2130).
+    RunScriptCatchUp(PathBuf),
     /// An error occurred during scheduling
     #[allow(dead_code)]
     Error(String),
@@ -91,6 +100,8 @@ impl Scheduler {
     /// * `path` - Path to the script file
     /// * `cron` - Optional raw cron expression (from `// Cron:` metadata)
     /// * `schedule` - Optional natural language schedule (from `// Schedule:` metadata)
+    /// * `missed_runs` - Catch-up policy for occurrences missed while
+    ///   asleep/closed (from `// MissedRuns:` metadata)
     ///
     /// # Returns
     /// Returns `Ok(())` if the script was successfully added, or an error if
@@ -99,11 +110,19 @@ impl Scheduler {
     /// # Note
     /// If both `cron` and `schedule` are provided, `cron` takes precedence.
     /// If neither is provided, returns an error.
+    ///
+    /// Also runs catch-up: if this schedule fired before (tracked in
+    /// `scheduler_catchup`) and occurrences were missed since then, this
+    /// emits `SchedulerEvent::RunScriptCatchUp` per `missed_runs` before
+    /// returning. This is what feeds both app startup (every script is
+    /// re-added on the initial scan) and sleep/wake (`Scheduler::recheck`
+    /// re-adds every currently-known script) through the same code path.
     pub fn add_script(
         &self,
         path: PathBuf,
         cron: Option<String>,
         schedule: Option<String>,
+        missed_runs: MissedRunsPolicy,
     ) -> Result<()> {
         let (cron_expr, source) = match (cron, schedule) {
             (Some(expr), _) => (expr, ScheduleSource::Cron),
@@ -127,11 +146,34 @@ impl Scheduler {
         let next_run = find_next_occurrence(&parsed_cron, &now)
             .context("Failed to calculate next run time")?;
 
+        // Catch up on occurrences missed since this schedule last fired
+        // (e.g. while the laptop was asleep or the app was closed).
+        let last_fire = scheduler_catchup::last_fire_time(&path);
+        let missed = scheduler_catchup::compute_missed_occurrences(
+            &parsed_cron,
+            last_fire,
+            now,
+            missed_runs,
+        );
+        for missed_at in &missed {
+            info!(
+                path = %path.display(),
+                missed_occurrence = %missed_at,
+                policy = ?missed_runs,
+                "Running catch-up for missed schedule occurrence"
+            );
+            if self.tx.send(SchedulerEvent::RunScriptCatchUp(path.clone())).is_err() {
+                warn!("Failed to send RunScriptCatchUp event, receiver dropped");
+                break;
+            }
+        }
+
         let scheduled_script = ScheduledScript {
             path: path.clone(),
             cron_expr: cron_expr.clone(),
             next_run,
             source: source.clone(),
+            missed_runs,
         };
 
         // Add to the list
@@ -161,6 +203,34 @@ impl Scheduler {
         Ok(())
     }
 
+    /// Re-check every currently-scheduled script for missed occurrences,
+    /// without needing to re-scan the filesystem.
+    ///
+    /// This is the sleep/wake code path (see
+    /// `platform::register_sleep_wake_observer`): waking from sleep re-adds
+    /// each already-known script via `add_script`, which recomputes and
+    /// emits any catch-up runs exactly as it does at startup.
+    pub fn recheck_missed_runs(&self) {
+        let known: Vec<ScheduledScript> = self.scripts.lock().unwrap().clone();
+        for script in known {
+            // `cron_expr` already holds a resolved cron expression even for
+            // schedules that originated as natural language (converted once
+            // in `add_script`), so it's always safe to re-add as `cron`.
+            if let Err(e) = self.add_script(
+                script.path.clone(),
+                Some(script.cron_expr.clone()),
+                None,
+                script.missed_runs,
+            ) {
+                warn!(
+                    path = %script.path.display(),
+                    error = %e,
+                    "Failed to re-check scheduled script for missed runs"
+                );
+            }
+        }
+    }
+
     /// Remove a script from the scheduler.
     #[allow(dead_code)]
     pub fn remove_script(&self, path: &PathBuf) -> bool {
@@ -265,9 +335,11 @@ impl Scheduler {
                 }
             }
 
-            // Send run events and update next_run times
+            // Send run events, update next_run times, and remember this fire
+            // for future catch-up computations.
             for path in scripts_to_run {
                 debug!(path = %path.display(), "Script due to run");
+                scheduler_catchup::record_fire(&path, now);
                 if tx.send(SchedulerEvent::RunScript(path.clone())).is_err() {
                     warn!("Failed to send RunScript event, receiver dropped");
                     return;
@@ -348,6 +420,22 @@ fn find_next_occurrence(cron: &Cron, after: &DateTime<Utc>) -> Result<DateTime<U
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::env;
+    use tempfile::TempDir;
+
+    /// Point `dirs::home_dir()` at a scratch directory for the duration of
+    /// `f`, since `add_script` now reads/writes `scheduler_catchup`'s
+    /// `schedule-state.json`. Mirrors the helper in
+    /// `window_state_persistence_tests.rs`.
+    fn with_temp_state_dir<F: FnOnce()>(f: F) {
+        let temp_dir = TempDir::new().unwrap();
+        let old_home = env::var("HOME").ok();
+        env::set_var("HOME", temp_dir.path());
+        f();
+        if let Some(home) = old_home {
+            env::set_var("HOME", home);
+        }
+    }
 
     #[test]
     fn test_parse_cron_valid() {
@@ -449,103 +537,134 @@ mod tests {
 
     #[test]
     fn test_scheduler_add_script_with_cron() {
-        let (scheduler, _rx) = Scheduler::new();
+        with_temp_state_dir(|| {
+            let (scheduler, _rx) = Scheduler::new();
 
-        let result = scheduler.add_script(
-            PathBuf::from("/test/script.ts"),
-            Some("*/5 * * * *".to_string()),
-            None,
-        );
+            let result = scheduler.add_script(
+                PathBuf::from("/test/script.ts"),
+                Some("*/5 * * * *".to_string()),
+                None,
+                MissedRunsPolicy::Skip,
+            );
 
-        assert!(result.is_ok(), "Failed to add script: {:?}", result.err());
+            assert!(result.is_ok(), "Failed to add script: {:?}", result.err());
 
-        let scripts = scheduler.list_scripts();
-        assert_eq!(scripts.len(), 1);
-        assert_eq!(scripts[0].path, PathBuf::from("/test/script.ts"));
-        assert_eq!(scripts[0].source, ScheduleSource::Cron);
+            let scripts = scheduler.list_scripts();
+            assert_eq!(scripts.len(), 1);
+            assert_eq!(scripts[0].path, PathBuf::from("/test/script.ts"));
+            assert_eq!(scripts[0].source, ScheduleSource::Cron);
+        });
     }
 
     #[test]
     fn test_scheduler_add_script_with_natural_language() {
-        let (scheduler, _rx) = Scheduler::new();
+        with_temp_state_dir(|| {
+            let (scheduler, _rx) = Scheduler::new();
 
-        let result = scheduler.add_script(
-            PathBuf::from("/test/script.ts"),
-            None,
-            Some("every hour".to_string()),
-        );
+            let result = scheduler.add_script(
+                PathBuf::from("/test/script.ts"),
+                None,
+                Some("every hour".to_string()),
+                MissedRunsPolicy::Skip,
+            );
 
-        assert!(result.is_ok(), "Failed to add script: {:?}", result.err());
+            assert!(result.is_ok(), "Failed to add script: {:?}", result.err());
 
-        let scripts = scheduler.list_scripts();
-        assert_eq!(scripts.len(), 1);
-        assert_eq!(scripts[0].source, ScheduleSource::NaturalLanguage);
+            let scripts = scheduler.list_scripts();
+            assert_eq!(scripts.len(), 1);
+            assert_eq!(scripts[0].source, ScheduleSource::NaturalLanguage);
+        });
     }
 
     #[test]
     fn test_scheduler_add_script_cron_takes_precedence() {
-        let (scheduler, _rx) = Scheduler::new();
+        with_temp_state_dir(|| {
+            let (scheduler, _rx) = Scheduler::new();
 
-        let result = scheduler.add_script(
-            PathBuf::from("/test/script.ts"),
-            Some("0 9 * * *".to_string()),
-            Some("every hour".to_string()), // Should be ignored
-        );
+            let result = scheduler.add_script(
+                PathBuf::from("/test/script.ts"),
+                Some("0 9 * * *".to_string()),
+                Some("every hour".to_string()), // Should be ignored
+                MissedRunsPolicy::Skip,
+            );
 
-        assert!(result.is_ok());
+            assert!(result.is_ok());
 
-        let scripts = scheduler.list_scripts();
-        assert_eq!(scripts.len(), 1);
-        assert_eq!(scripts[0].source, ScheduleSource::Cron);
-        assert_eq!(scripts[0].cron_expr, "0 9 * * *");
+            let scripts = scheduler.list_scripts();
+            assert_eq!(scripts.len(), 1);
+            assert_eq!(scripts[0].source, ScheduleSource::Cron);
+            assert_eq!(scripts[0].cron_expr, "0 9 * * *");
+        });
     }
 
     #[test]
     fn test_scheduler_add_script_no_schedule() {
-        let (scheduler, _rx) = Scheduler::new();
+        with_temp_state_dir(|| {
+            let (scheduler, _rx) = Scheduler::new();
 
-        let result = scheduler.add_script(PathBuf::from("/test/script.ts"), None, None);
+            let result = scheduler.add_script(
+                PathBuf::from("/test/script.ts"),
+                None,
+                None,
+                MissedRunsPolicy::Skip,
+            );
 
-        assert!(result.is_err(), "Should fail when no schedule provided");
+            assert!(result.is_err(), "Should fail when no schedule provided");
+        });
     }
 
     #[test]
     fn test_scheduler_remove_script() {
-        let (scheduler, _rx) = Scheduler::new();
-
-        scheduler
-            .add_script(
-                PathBuf::from("/test/script.ts"),
-                Some("* * * * *".to_string()),
-                None,
-            )
-            .unwrap();
-
-        assert_eq!(scheduler.list_scripts().len(), 1);
-
-        let removed = scheduler.remove_script(&PathBuf::from("/test/script.ts"));
-        assert!(removed);
-        assert!(scheduler.list_scripts().is_empty());
+        with_temp_state_dir(|| {
+            let (scheduler, _rx) = Scheduler::new();
+
+            scheduler
+                .add_script(
+                    PathBuf::from("/test/script.ts"),
+                    Some("* * * * *".to_string()),
+                    None,
+                    MissedRunsPolicy::Skip,
+                )
+                .unwrap();
+
+            assert_eq!(scheduler.list_scripts().len(), 1);
+
+            let removed = scheduler.remove_script(&PathBuf::from("/test/script.ts"));
+            assert!(removed);
+            assert!(scheduler.list_scripts().is_empty());
+        });
     }
 
     #[test]
     fn test_scheduler_update_existing_script() {
-        let (scheduler, _rx) = Scheduler::new();
-        let path = PathBuf::from("/test/script.ts");
-
-        // Add initial script
-        scheduler
-            .add_script(path.clone(), Some("* * * * *".to_string()), None)
-            .unwrap();
-
-        // Update with new schedule
-        scheduler
-            .add_script(path.clone(), Some("0 9 * * *".to_string()), None)
-            .unwrap();
-
-        let scripts = scheduler.list_scripts();
-        assert_eq!(scripts.len(), 1); // Should still be 1, not 2
-        assert_eq!(scripts[0].cron_expr, "0 9 * * *");
+        with_temp_state_dir(|| {
+            let (scheduler, _rx) = Scheduler::new();
+            let path = PathBuf::from("/test/script.ts");
+
+            // Add initial script
+            scheduler
+                .add_script(
+                    path.clone(),
+                    Some("* * * * *".to_string()),
+                    None,
+                    MissedRunsPolicy::Skip,
+                )
+                .unwrap();
+
+            // Update with new schedule
+            scheduler
+                .add_script(
+                    path.clone(),
+                    Some("0 9 * * *".to_string()),
+                    None,
+                    MissedRunsPolicy::Skip,
+                )
+                .unwrap();
+
+            let scripts = scheduler.list_scripts();
+            assert_eq!(scripts.len(), 1); // Should still be 1, not 2
+            assert_eq!(scripts[0].cron_expr, "0 9 * * *");
+        });
     }
 
     #[test]
@@ -553,10 +672,57 @@ mod tests {
         let event = SchedulerEvent::RunScript(PathBuf::from("/test.ts"));
         let _cloned = event.clone();
 
+        let catchup_event = SchedulerEvent::RunScriptCatchUp(PathBuf::from("/test.ts"));
+        let _cloned = catchup_event.clone();
+
         let error_event = SchedulerEvent::Error("test error".to_string());
         let _cloned = error_event.clone();
     }
 
+    #[test]
+    fn test_scheduler_add_script_emits_catchup_for_missed_occurrence() {
+        with_temp_state_dir(|| {
+            let (scheduler, rx) = Scheduler::new();
+            let path = PathBuf::from("/test/catchup.ts");
+            scheduler_catchup::record_fire(&path, Utc::now() - chrono::Duration::hours(3));
+
+            scheduler
+                .add_script(
+                    path.clone(),
+                    Some("0 * * * *".to_string()), // hourly, so a 3h gap missed occurrences
+                    None,
+                    MissedRunsPolicy::Once,
+                )
+                .unwrap();
+
+            let event = rx.try_recv().expect("expected a catch-up event");
+            match event {
+                SchedulerEvent::RunScriptCatchUp(p) => assert_eq!(p, path),
+                other => panic!("expected RunScriptCatchUp, got {:?}", other),
+            }
+        });
+    }
+
+    #[test]
+    fn test_scheduler_add_script_skip_policy_emits_no_catchup() {
+        with_temp_state_dir(|| {
+            let (scheduler, rx) = Scheduler::new();
+            let path = PathBuf::from("/test/no-catchup.ts");
+            scheduler_catchup::record_fire(&path, Utc::now() - chrono::Duration::hours(3));
+
+            scheduler
+                .add_script(
+                    path.clone(),
+                    Some("0 * * * *".to_string()),
+                    None,
+                    MissedRunsPolicy::Skip,
+                )
+                .unwrap();
+
+            assert!(rx.try_recv().is_err(), "Skip policy should emit no catch-up events");
+        });
+    }
+
     #[test]
     fn test_schedule_source_equality() {
         assert_eq!(ScheduleSource::Cron, ScheduleSource::Cron);
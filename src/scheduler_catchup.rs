@@ -0,0 +1,307 @@
+//! Scheduler catch-up: persisted last-fire times + missed-occurrence policy
+//!
+//! Cron-scheduled scripts only fire while the app is running; without this
+//! module, a run that would have fired while the laptop was asleep or the
+//! app was closed is silently skipped. This tracks the last time each
+//! schedule fired (persisted to `~/.sk/kit/schedule-state.json`) and
+//! computes which occurrences were missed since then, subject to a
+//! per-script `// MissedRuns:` policy.
+//!
+//! `compute_missed_occurrences` takes `now` as a parameter rather than
+//! reading the wall clock itself, so it can be tested deterministically
+//! (including across DST transitions) without mocking time globally.
+
+use chrono::{DateTime, Utc};
+use croner::Cron;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::logging;
+
+/// Hard cap on how many missed occurrences a single catch-up pass will
+/// enumerate, so a schedule left unattended for months doesn't queue an
+/// unbounded backlog of runs.
+pub const MAX_CATCHUP_RUNS: usize = 10;
+
+/// Per-script policy for `// MissedRuns:` metadata, controlling what happens
+/// to occurrences that would have fired while the app wasn't running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissedRunsPolicy {
+    /// Do nothing; only the next scheduled occurrence runs (default).
+    #[default]
+    Skip,
+    /// Run once to catch up, regardless of how many occurrences were missed.
+    Once,
+    /// Run once per missed occurrence, in order, capped at `MAX_CATCHUP_RUNS`.
+    All,
+}
+
+impl MissedRunsPolicy {
+    /// Parse a `// MissedRuns:` metadata value. Unrecognized values fall back
+    /// to the default (`Skip`) rather than failing script registration.
+    pub fn parse(value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "once" => MissedRunsPolicy::Once,
+            "all" => MissedRunsPolicy::All,
+            _ => MissedRunsPolicy::Skip,
+        }
+    }
+}
+
+/// Compute which scheduled occurrences between `last_fire` and `now` were
+/// missed, filtered/truncated per `policy`.
+///
+/// `last_fire` and `now` are both passed in (rather than read from the wall
+/// clock) so this is deterministic and safe to test across DST transitions.
+/// Returns an empty vec when there's nothing to catch up on: `policy` is
+/// `Skip`, this is the schedule's first-ever run (`last_fire` is `None`), or
+/// `last_fire` is already at or after `now`.
+pub fn compute_missed_occurrences(
+    cron: &Cron,
+    last_fire: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+    policy: MissedRunsPolicy,
+) -> Vec<DateTime<Utc>> {
+    if policy == MissedRunsPolicy::Skip {
+        return Vec::new();
+    }
+    let Some(last_fire) = last_fire else {
+        return Vec::new();
+    };
+    if last_fire >= now {
+        return Vec::new();
+    }
+
+    let mut missed = Vec::new();
+    let mut cursor = last_fire;
+    while missed.len() < MAX_CATCHUP_RUNS {
+        let Ok(next) = cron.find_next_occurrence(&cursor, false) else {
+            break;
+        };
+        if next > now {
+            break;
+        }
+        missed.push(next);
+        cursor = next;
+    }
+
+    match policy {
+        MissedRunsPolicy::Skip => Vec::new(),
+        MissedRunsPolicy::Once => missed.last().copied().into_iter().collect(),
+        MissedRunsPolicy::All => missed,
+    }
+}
+
+/// Persisted last-fire time per schedule, keyed by script path (as a string).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ScheduleStateFile {
+    #[serde(default = "default_version")]
+    pub version: u32,
+    #[serde(default)]
+    pub last_fire: HashMap<String, DateTime<Utc>>,
+}
+
+fn default_version() -> u32 {
+    1
+}
+
+/// Path to the schedule catch-up state file: ~/.sk/kit/schedule-state.json
+pub fn get_state_file_path() -> PathBuf {
+    let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    home.join(".sk").join("kit").join("schedule-state.json")
+}
+
+/// Load the schedule state file, ignoring (and logging) anything unreadable
+/// or unparseable rather than failing startup.
+fn load_state_file() -> ScheduleStateFile {
+    let path = get_state_file_path();
+    if !path.exists() {
+        return ScheduleStateFile::default();
+    }
+    match fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(e) => {
+            logging::log(
+                "SCHEDULER",
+                &format!("Failed to read schedule-state.json: {}", e),
+            );
+            ScheduleStateFile::default()
+        }
+    }
+}
+
+/// Save the schedule state file (atomic write: temp file then rename).
+fn save_state_file(state: &ScheduleStateFile) {
+    let path = get_state_file_path();
+    if let Some(parent) = path.parent() {
+        if let Err(e) = fs::create_dir_all(parent) {
+            logging::log("SCHEDULER", &format!("Failed to create directory: {}", e));
+            return;
+        }
+    }
+    let json = match serde_json::to_string_pretty(state) {
+        Ok(j) => j,
+        Err(e) => {
+            logging::log("SCHEDULER", &format!("Failed to serialize: {}", e));
+            return;
+        }
+    };
+    let tmp_path = path.with_extension("json.tmp");
+    if let Err(e) = fs::write(&tmp_path, &json) {
+        logging::log("SCHEDULER", &format!("Failed to write temp file: {}", e));
+        return;
+    }
+    if let Err(e) = fs::rename(&tmp_path, &path) {
+        logging::log(
+            "SCHEDULER",
+            &format!("Failed to rename temp file: {}", e),
+        );
+        let _ = fs::remove_file(&tmp_path);
+    }
+}
+
+/// Look up the last time `path`'s schedule fired.
+pub fn last_fire_time(path: &Path) -> Option<DateTime<Utc>> {
+    load_state_file()
+        .last_fire
+        .get(&path.to_string_lossy().to_string())
+        .copied()
+}
+
+/// Record that `path`'s schedule fired at `at`.
+pub fn record_fire(path: &Path, at: DateTime<Utc>) {
+    let mut state = load_state_file();
+    state
+        .last_fire
+        .insert(path.to_string_lossy().to_string(), at);
+    save_state_file(&state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler::parse_cron;
+    use chrono::TimeZone;
+    use std::env;
+    use tempfile::TempDir;
+
+    /// Point `dirs::home_dir()` at a scratch directory for the duration of
+    /// `f`, matching the helper in `window_state_persistence_tests.rs`.
+    fn with_temp_state_dir<F: FnOnce()>(f: F) {
+        let temp_dir = TempDir::new().unwrap();
+        let old_home = env::var("HOME").ok();
+        env::set_var("HOME", temp_dir.path());
+        f();
+        if let Some(home) = old_home {
+            env::set_var("HOME", home);
+        }
+    }
+
+    #[test]
+    fn test_missed_runs_policy_parse() {
+        assert_eq!(MissedRunsPolicy::parse("skip"), MissedRunsPolicy::Skip);
+        assert_eq!(MissedRunsPolicy::parse("Once"), MissedRunsPolicy::Once);
+        assert_eq!(MissedRunsPolicy::parse(" all "), MissedRunsPolicy::All);
+        assert_eq!(MissedRunsPolicy::parse("garbage"), MissedRunsPolicy::Skip);
+    }
+
+    #[test]
+    fn test_compute_missed_occurrences_skip_policy_never_catches_up() {
+        let cron = parse_cron("0 9 * * *").unwrap();
+        let last_fire = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 1, 5, 9, 0, 0).unwrap();
+        assert!(compute_missed_occurrences(&cron, Some(last_fire), now, MissedRunsPolicy::Skip)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_compute_missed_occurrences_first_run_has_nothing_to_catch_up() {
+        let cron = parse_cron("0 9 * * *").unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 1, 5, 9, 0, 0).unwrap();
+        assert!(compute_missed_occurrences(&cron, None, now, MissedRunsPolicy::All).is_empty());
+    }
+
+    #[test]
+    fn test_compute_missed_occurrences_once_returns_single_latest() {
+        // Daily at 9am, missed 3 days.
+        let cron = parse_cron("0 9 * * *").unwrap();
+        let last_fire = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 1, 4, 10, 0, 0).unwrap();
+        let missed = compute_missed_occurrences(&cron, Some(last_fire), now, MissedRunsPolicy::Once);
+        assert_eq!(missed, vec![Utc.with_ymd_and_hms(2024, 1, 4, 9, 0, 0).unwrap()]);
+    }
+
+    #[test]
+    fn test_compute_missed_occurrences_all_returns_every_occurrence_in_order() {
+        let cron = parse_cron("0 9 * * *").unwrap();
+        let last_fire = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 1, 4, 10, 0, 0).unwrap();
+        let missed = compute_missed_occurrences(&cron, Some(last_fire), now, MissedRunsPolicy::All);
+        assert_eq!(
+            missed,
+            vec![
+                Utc.with_ymd_and_hms(2024, 1, 2, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 3, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 4, 9, 0, 0).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compute_missed_occurrences_caps_at_max_catchup_runs() {
+        // Every hour, unattended for far longer than MAX_CATCHUP_RUNS hours.
+        let cron = parse_cron("0 * * * *").unwrap();
+        let last_fire = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let missed = compute_missed_occurrences(&cron, Some(last_fire), now, MissedRunsPolicy::All);
+        assert_eq!(missed.len(), MAX_CATCHUP_RUNS);
+        // Occurrences must still be in chronological order starting right
+        // after last_fire, not an arbitrary/truncated-from-the-end slice.
+        assert_eq!(missed[0], Utc.with_ymd_and_hms(2024, 1, 1, 1, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_compute_missed_occurrences_across_dst_spring_forward() {
+        // Spans the US DST spring-forward date (2024-03-10). Occurrences are
+        // computed in UTC, which has no DST gap, so every day's 2:30 occurs;
+        // this checks the sequence stays ordered and in range across that date.
+        let cron = parse_cron("30 2 * * *").unwrap();
+        let last_fire = Utc.with_ymd_and_hms(2024, 3, 8, 2, 30, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 3, 12, 12, 0, 0).unwrap();
+        let missed = compute_missed_occurrences(&cron, Some(last_fire), now, MissedRunsPolicy::All);
+        // Whatever croner resolves each occurrence to, they must be strictly
+        // increasing and none may be in the future relative to `now`.
+        assert!(!missed.is_empty());
+        for pair in missed.windows(2) {
+            assert!(pair[0] < pair[1], "occurrences must be strictly increasing across DST");
+        }
+        assert!(missed.iter().all(|t| *t <= now));
+    }
+
+    #[test]
+    fn test_record_and_load_fire_time_roundtrip() {
+        with_temp_state_dir(|| {
+            let path = Path::new("/scripts/daily.ts");
+            assert_eq!(last_fire_time(path), None);
+
+            let at = Utc.with_ymd_and_hms(2024, 6, 1, 9, 0, 0).unwrap();
+            record_fire(path, at);
+            assert_eq!(last_fire_time(path), Some(at));
+
+            // A later fire overwrites, it doesn't accumulate history.
+            let later = Utc.with_ymd_and_hms(2024, 6, 2, 9, 0, 0).unwrap();
+            record_fire(path, later);
+            assert_eq!(last_fire_time(path), Some(later));
+        });
+    }
+
+    #[test]
+    fn test_load_fire_time_missing_script_returns_none() {
+        with_temp_state_dir(|| {
+            record_fire(Path::new("/scripts/a.ts"), Utc::now());
+            assert_eq!(last_fire_time(Path::new("/scripts/b.ts")), None);
+        });
+    }
+}
@@ -287,6 +287,34 @@ pub fn open_in_editor(path: &Path, config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Open a file in `editor`, jumped to `line`, using the widely-supported
+/// `file:line` argument convention (VS Code, Sublime Text, and most others
+/// accept this form).
+///
+/// Unlike `open_in_editor`, this takes the editor command directly rather
+/// than a `Config` - it's also called from toast action closures (see the
+/// `ScriptError` handler in `prompt_handler.rs`), which can't borrow
+/// `Config` across the closure's `'static` lifetime, so callers read
+/// `config.get_editor()` once up front instead.
+///
+/// # Errors
+///
+/// Returns an error if the editor command fails to spawn.
+#[instrument(name = "open_in_editor_at_line", fields(path = %path.display(), line))]
+pub fn open_in_editor_at_line(editor: &str, path: &Path, line: u32) -> Result<()> {
+    let target = format!("{}:{}", path.display(), line);
+
+    info!(editor = %editor, target = %target, "Opening file in editor at line");
+
+    let status = Command::new(editor).arg(&target).spawn().with_context(|| {
+        format!("Failed to spawn editor '{}' for target: {}", editor, target)
+    })?;
+
+    drop(status);
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -452,6 +480,8 @@ mod tests {
         let default_config = Config {
             hotkey: config.hotkey.clone(),
             bun_path: None,
+            tool_paths: None,
+            typed_errors: None,
             editor: None,
             padding: None,
             editor_font_size: None,
@@ -460,6 +490,7 @@ mod tests {
             built_ins: None,
             process_limits: None,
             clipboard_history_max_text_length: None,
+            clipboard_auto_paste: None,
             suggested: None,
             notes_hotkey: None,
             ai_hotkey: None,
@@ -479,6 +510,7 @@ mod tests {
             built_ins: None,
             process_limits: None,
             clipboard_history_max_text_length: None,
+            clipboard_auto_paste: None,
             suggested: None,
             notes_hotkey: None,
             ai_hotkey: None,
@@ -329,7 +329,7 @@ fn slugify(name: &str) -> String {
 
 /// Extract named input placeholders from scriptlet content
 /// Finds all {{variableName}} patterns
-fn extract_named_inputs(content: &str) -> Vec<String> {
+pub(crate) fn extract_named_inputs(content: &str) -> Vec<String> {
     let mut inputs = Vec::new();
     let mut chars = content.chars().peekable();
 
@@ -4,21 +4,82 @@
 //! sections like RECENT, SCRIPTS, APPS, etc.
 
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tracing::{debug, instrument};
 
 use crate::app_launcher::AppInfo;
 use crate::builtins::{menu_bar_items_to_entries, BuiltInEntry, BuiltInGroup};
-use crate::config::SuggestedConfig;
+use crate::config::{SearchWeights, SuggestedConfig};
 use crate::fallbacks::collector::collect_fallbacks;
 use crate::frecency::FrecencyStore;
 use crate::list_item::GroupedListItem;
+use crate::list_sort::ListSortMode;
 use crate::menu_bar::MenuBarItem;
+use crate::recent_files::RecentFileInfo;
+use crate::window_control::WindowInfo;
 
-use super::search::fuzzy_search_unified_all;
+use super::search::{
+    fuzzy_search_recent_files, fuzzy_search_unified_all_weighted, fuzzy_search_windows,
+};
 use super::types::{FallbackMatch, Script, Scriptlet, SearchResult};
 
+/// Scope restricting search to a single source, selected with a sigil
+/// prefix on the filter text (`@safari`, `>deploy`, `#mail`, `?settings`).
+///
+/// There is intentionally no variant for plain scripts - unscoped search
+/// already covers scripts, and no sigil is reserved for them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchScope {
+    /// `@` - installed applications
+    Apps,
+    /// `>` - scriptlets
+    Scriptlets,
+    /// `?` - built-in commands
+    Builtins,
+    /// `#` - open windows
+    Windows,
+}
+
+impl SearchScope {
+    fn from_sigil(sigil: char) -> Option<Self> {
+        match sigil {
+            '@' => Some(SearchScope::Apps),
+            '>' => Some(SearchScope::Scriptlets),
+            '?' => Some(SearchScope::Builtins),
+            '#' => Some(SearchScope::Windows),
+            _ => None,
+        }
+    }
+
+    /// Short label for the scope chip shown to the left of the search input
+    pub fn label(self) -> &'static str {
+        match self {
+            SearchScope::Apps => "APPS",
+            SearchScope::Scriptlets => "SCRIPTLETS",
+            SearchScope::Builtins => "BUILTINS",
+            SearchScope::Windows => "WINDOWS",
+        }
+    }
+}
+
+/// Parse a leading scope sigil off `filter_text`.
+///
+/// Returns the matching [`SearchScope`] (if the first character is a
+/// recognized sigil) and the remaining text to fuzzy-search with. A sigil
+/// with nothing after it (e.g. just `"@"`) still parses - the scope applies
+/// with an empty query, which shows every item of that source.
+///
+/// A leading `/` is not recognized here: this build has no file-search
+/// built-in for it to scope to.
+pub fn parse_search_scope(filter_text: &str) -> (Option<SearchScope>, &str) {
+    let mut chars = filter_text.chars();
+    match chars.next().and_then(SearchScope::from_sigil) {
+        Some(scope) => (Some(scope), chars.as_str()),
+        None => (None, filter_text),
+    }
+}
+
 /// Default maximum number of items to show in the RECENT section
 pub const DEFAULT_MAX_RECENT_ITEMS: usize = 10;
 
@@ -30,6 +91,26 @@ pub const MAX_MENU_BAR_ITEMS: usize = 5;
 /// This filters out weak matches that would clutter the list
 pub const MIN_MENU_BAR_SCORE: i32 = 25;
 
+/// The frecency store key for a search result, if it tracks usage.
+///
+/// Shared between grouping (SUGGESTED section ranking) and list item
+/// rendering (the "2h ago · 14 runs" annotation) so both agree on identity.
+pub fn frecency_key_for_result(result: &SearchResult) -> Option<String> {
+    match result {
+        SearchResult::Script(sm) => Some(sm.script.path.to_string_lossy().to_string()),
+        SearchResult::App(am) => Some(am.app.path.to_string_lossy().to_string()),
+        SearchResult::BuiltIn(bm) => Some(format!("builtin:{}", bm.entry.name)),
+        SearchResult::Scriptlet(sm) => Some(format!("scriptlet:{}", sm.scriptlet.name)),
+        SearchResult::Window(wm) => Some(format!("window:{}:{}", wm.window.app, wm.window.title)),
+        SearchResult::Agent(am) => Some(format!("agent:{}", am.agent.path.to_string_lossy())),
+        SearchResult::RecentFile(rm) => {
+            Some(format!("file:{}", rm.file.path.to_string_lossy()))
+        }
+        // Fallbacks don't have paths - they're only shown in search mode, not grouped view
+        SearchResult::Fallback(_) => None,
+    }
+}
+
 /// Get grouped results with SUGGESTED/MAIN sections based on frecency
 ///
 /// This function creates a grouped view of search results:
@@ -50,8 +131,12 @@ pub const MIN_MENU_BAR_SCORE: i32 = 25;
 /// * `scriptlets` - Scriptlets to include in results
 /// * `builtins` - Built-in entries to include in results
 /// * `apps` - Application entries to include in results
+/// * `windows` - Open windows to include in results (only searched when `scope` is `Windows`)
+/// * `recent_files` - Recently modified files to include in results
 /// * `frecency_store` - Store containing frecency data for ranking
-/// * `filter_text` - Search filter text (empty = grouped view, non-empty = search mode)
+/// * `filter_text` - Search filter text with any scope sigil already stripped
+///   (empty = grouped view, non-empty = search mode)
+/// * `scope` - Restrict results to a single source and skip the rest entirely
 /// * `suggested_config` - Configuration for the SUGGESTED section
 /// * `menu_bar_items` - Optional menu bar items from the frontmost application
 /// * `menu_bar_bundle_id` - Optional bundle ID of the frontmost application
@@ -68,11 +153,91 @@ pub fn get_grouped_results(
     scriptlets: &[Arc<Scriptlet>],
     builtins: &[BuiltInEntry],
     apps: &[AppInfo],
+    windows: &[WindowInfo],
+    recent_files: &[RecentFileInfo],
     frecency_store: &FrecencyStore,
     filter_text: &str,
+    scope: Option<SearchScope>,
     suggested_config: &SuggestedConfig,
     menu_bar_items: &[MenuBarItem],
     menu_bar_bundle_id: Option<&str>,
+) -> (Vec<GroupedListItem>, Vec<SearchResult>) {
+    get_grouped_results_with_weights(
+        scripts,
+        scriptlets,
+        builtins,
+        apps,
+        windows,
+        recent_files,
+        frecency_store,
+        filter_text,
+        scope,
+        suggested_config,
+        menu_bar_items,
+        menu_bar_bundle_id,
+        &SearchWeights::default(),
+    )
+}
+
+/// Same as [`get_grouped_results`], but scores scripts using `weights`
+/// instead of the default (1.0) field weights.
+#[allow(clippy::too_many_arguments)]
+pub fn get_grouped_results_with_weights(
+    scripts: &[Arc<Script>],
+    scriptlets: &[Arc<Scriptlet>],
+    builtins: &[BuiltInEntry],
+    apps: &[AppInfo],
+    windows: &[WindowInfo],
+    recent_files: &[RecentFileInfo],
+    frecency_store: &FrecencyStore,
+    filter_text: &str,
+    scope: Option<SearchScope>,
+    suggested_config: &SuggestedConfig,
+    menu_bar_items: &[MenuBarItem],
+    menu_bar_bundle_id: Option<&str>,
+    weights: &SearchWeights,
+) -> (Vec<GroupedListItem>, Vec<SearchResult>) {
+    get_grouped_results_with_sort(
+        scripts,
+        scriptlets,
+        builtins,
+        apps,
+        windows,
+        recent_files,
+        frecency_store,
+        filter_text,
+        scope,
+        suggested_config,
+        menu_bar_items,
+        menu_bar_bundle_id,
+        weights,
+        ListSortMode::Name,
+    )
+}
+
+/// Same as [`get_grouped_results_with_weights`], but orders the per-type
+/// sections (SCRIPTS/SCRIPTLETS/COMMANDS/APPS/AGENTS) using `sort_mode`
+/// instead of always sorting alphabetically.
+///
+/// The SUGGESTED section is unaffected - it always ranks by live frecency
+/// score regardless of `sort_mode`.
+#[instrument(level = "debug", skip_all, fields(filter_len = filter_text.len()))]
+#[allow(clippy::too_many_arguments)]
+pub fn get_grouped_results_with_sort(
+    scripts: &[Arc<Script>],
+    scriptlets: &[Arc<Scriptlet>],
+    builtins: &[BuiltInEntry],
+    apps: &[AppInfo],
+    windows: &[WindowInfo],
+    recent_files: &[RecentFileInfo],
+    frecency_store: &FrecencyStore,
+    filter_text: &str,
+    scope: Option<SearchScope>,
+    suggested_config: &SuggestedConfig,
+    menu_bar_items: &[MenuBarItem],
+    menu_bar_bundle_id: Option<&str>,
+    weights: &SearchWeights,
+    sort_mode: ListSortMode,
 ) -> (Vec<GroupedListItem>, Vec<SearchResult>) {
     // When filter is non-empty and we have menu bar items, include them in search
     let all_builtins: Vec<BuiltInEntry>;
@@ -89,11 +254,65 @@ pub fn get_grouped_results(
         builtins
     };
 
+    // A scope restricts the search to one source - pass empty slices for the
+    // rest so fuzzy_search_unified_all skips them entirely rather than
+    // matching and then discarding their results.
+    let scripts_in_scope: &[Arc<Script>] = if scope.is_none() { scripts } else { &[] };
+    let scriptlets_in_scope: &[Arc<Scriptlet>] =
+        if matches!(scope, None | Some(SearchScope::Scriptlets)) {
+            scriptlets
+        } else {
+            &[]
+        };
+    let builtins_in_scope: &[BuiltInEntry] = if matches!(scope, None | Some(SearchScope::Builtins))
+    {
+        builtins_to_use
+    } else {
+        &[]
+    };
+    let apps_in_scope: &[AppInfo] = if matches!(scope, None | Some(SearchScope::Apps)) {
+        apps
+    } else {
+        &[]
+    };
+    // No dedicated scope sigil for recent files yet - they're only part of
+    // the unscoped, unified search (like scripts).
+    let recent_files_in_scope: &[RecentFileInfo] = if scope.is_none() {
+        recent_files
+    } else {
+        &[]
+    };
+
     // Get all unified search results
-    let results = fuzzy_search_unified_all(scripts, scriptlets, builtins_to_use, apps, filter_text);
+    let mut results = fuzzy_search_unified_all_weighted(
+        scripts_in_scope,
+        scriptlets_in_scope,
+        builtins_in_scope,
+        apps_in_scope,
+        filter_text,
+        weights,
+    );
+
+    // Recent files aren't part of fuzzy_search_unified_all_weighted (they're
+    // gated on MIN_QUERY_LEN rather than a shared weights struct) - append
+    // them directly the same way windows are appended below.
+    results.extend(
+        fuzzy_search_recent_files(recent_files_in_scope, filter_text)
+            .into_iter()
+            .map(SearchResult::RecentFile),
+    );
+
+    // Windows aren't part of the default unified search - they only show up
+    // once the user has scoped to them with `#`.
+    if scope == Some(SearchScope::Windows) {
+        results = fuzzy_search_windows(windows, filter_text)
+            .into_iter()
+            .map(SearchResult::Window)
+            .collect();
+    }
 
     // Search mode: return flat list with section header for menu bar items
-    if !filter_text.is_empty() {
+    if !filter_text.is_empty() || scope.is_some() {
         // Partition results into non-menu-bar and menu-bar items
         let mut non_menu_bar_indices: Vec<usize> = Vec::new();
         let mut menu_bar_indices: Vec<usize> = Vec::new();
@@ -115,7 +334,6 @@ pub fn get_grouped_results(
         menu_bar_indices.truncate(MAX_MENU_BAR_ITEMS);
 
         let mut grouped: Vec<GroupedListItem> = Vec::new();
-        let mut results = results; // Make results mutable so we can append fallbacks
 
         // Track counts before consuming the vectors
         let non_menu_bar_count = non_menu_bar_indices.len();
@@ -138,7 +356,13 @@ pub fn get_grouped_results(
         }
 
         // Collect fallback commands and append as "Use {query} with..." section OR as primary results
-        let fallbacks = collect_fallbacks(filter_text, scripts);
+        // Skipped entirely when scoped - fallbacks always suggest running a script,
+        // which doesn't make sense while scoped to apps/scriptlets/builtins/windows.
+        let fallbacks = if scope.is_none() {
+            collect_fallbacks(filter_text, scripts)
+        } else {
+            Vec::new()
+        };
         let fallback_count = fallbacks.len();
 
         if !fallbacks.is_empty() {
@@ -194,21 +418,7 @@ pub fn get_grouped_results(
         .collect();
 
     // Map each result to its frecency score (if any)
-    // We need to get the path for each result type
-    let get_result_path = |result: &SearchResult| -> Option<String> {
-        match result {
-            SearchResult::Script(sm) => Some(sm.script.path.to_string_lossy().to_string()),
-            SearchResult::App(am) => Some(am.app.path.to_string_lossy().to_string()),
-            SearchResult::BuiltIn(bm) => Some(format!("builtin:{}", bm.entry.name)),
-            SearchResult::Scriptlet(sm) => Some(format!("scriptlet:{}", sm.scriptlet.name)),
-            SearchResult::Window(wm) => {
-                Some(format!("window:{}:{}", wm.window.app, wm.window.title))
-            }
-            SearchResult::Agent(am) => Some(format!("agent:{}", am.agent.path.to_string_lossy())),
-            // Fallbacks don't have paths - they're only shown in search mode, not grouped view
-            SearchResult::Fallback(_) => None,
-        }
-    };
+    let get_result_path = frecency_key_for_result;
 
     // Find indices of results that are "suggested" and categorize non-suggested by type
     let mut suggested_indices: Vec<(usize, f64)> = Vec::new();
@@ -217,6 +427,7 @@ pub fn get_grouped_results(
     let mut commands_indices: Vec<usize> = Vec::new();
     let mut apps_indices: Vec<usize> = Vec::new();
     let mut agents_indices: Vec<usize> = Vec::new();
+    let mut recent_files_indices: Vec<usize> = Vec::new();
 
     // Get excluded commands for filtering builtins from SUGGESTED section
     let excluded_commands = &suggested_config.excluded_commands;
@@ -246,6 +457,7 @@ pub fn get_grouped_results(
                     }
                     SearchResult::App(_) => apps_indices.push(idx),
                     SearchResult::Agent(_) => agents_indices.push(idx),
+                    SearchResult::RecentFile(_) => recent_files_indices.push(idx),
                     // Fallbacks should never appear in grouped view - they're search-mode only
                     SearchResult::Fallback(_) => {}
                 }
@@ -258,6 +470,7 @@ pub fn get_grouped_results(
                 SearchResult::BuiltIn(_) | SearchResult::Window(_) => commands_indices.push(idx),
                 SearchResult::App(_) => apps_indices.push(idx),
                 SearchResult::Agent(_) => agents_indices.push(idx),
+                SearchResult::RecentFile(_) => recent_files_indices.push(idx),
                 // Fallbacks should never appear in grouped view - they're search-mode only
                 SearchResult::Fallback(_) => {}
             }
@@ -270,21 +483,52 @@ pub fn get_grouped_results(
     // Limit suggested items to max_items from config
     suggested_indices.truncate(suggested_config.max_items);
 
-    // Sort each type section alphabetically by name (case-insensitive)
-    let sort_alphabetically = |indices: &mut Vec<usize>| {
-        indices.sort_by(|&a, &b| {
-            results[a]
-                .name()
-                .to_lowercase()
-                .cmp(&results[b].name().to_lowercase())
-        });
+    // Sort each type section according to `sort_mode`. Ties (e.g. two items
+    // with no frecency history) always fall back to alphabetical order so
+    // sections don't jitter between renders.
+    let sort_by_name = |a: usize, b: usize| {
+        results[a]
+            .name()
+            .to_lowercase()
+            .cmp(&results[b].name().to_lowercase())
+    };
+    let sort_items = |indices: &mut Vec<usize>| match sort_mode {
+        ListSortMode::Name => {
+            indices.sort_by(|&a, &b| sort_by_name(a, b));
+        }
+        ListSortMode::LastUsed => {
+            indices.sort_by(|&a, &b| {
+                let a_ts = get_result_path(&results[a])
+                    .and_then(|p| frecency_store.get_entry(&p).map(|e| e.last_used))
+                    .unwrap_or(0);
+                let b_ts = get_result_path(&results[b])
+                    .and_then(|p| frecency_store.get_entry(&p).map(|e| e.last_used))
+                    .unwrap_or(0);
+                b_ts.cmp(&a_ts).then_with(|| sort_by_name(a, b))
+            });
+        }
+        ListSortMode::Frecency => {
+            indices.sort_by(|&a, &b| {
+                let a_score = get_result_path(&results[a])
+                    .map(|p| frecency_store.get_score(&p))
+                    .unwrap_or(0.0);
+                let b_score = get_result_path(&results[b])
+                    .map(|p| frecency_store.get_score(&p))
+                    .unwrap_or(0.0);
+                b_score
+                    .partial_cmp(&a_score)
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| sort_by_name(a, b))
+            });
+        }
     };
 
-    sort_alphabetically(&mut scripts_indices);
-    sort_alphabetically(&mut scriptlets_indices);
-    sort_alphabetically(&mut commands_indices);
-    sort_alphabetically(&mut apps_indices);
-    sort_alphabetically(&mut agents_indices);
+    sort_items(&mut scripts_indices);
+    sort_items(&mut scriptlets_indices);
+    sort_items(&mut commands_indices);
+    sort_items(&mut apps_indices);
+    sort_items(&mut agents_indices);
+    sort_items(&mut recent_files_indices);
 
     // Build grouped list: SUGGESTED first (if enabled), then SCRIPTS, SCRIPTLETS, COMMANDS, APPS
     if suggested_config.enabled && !suggested_indices.is_empty() {
@@ -294,12 +538,7 @@ pub fn get_grouped_results(
         }
     }
 
-    if !scripts_indices.is_empty() {
-        grouped.push(GroupedListItem::SectionHeader("SCRIPTS".to_string()));
-        for idx in &scripts_indices {
-            grouped.push(GroupedListItem::Item(*idx));
-        }
-    }
+    push_scripts_section(&mut grouped, &scripts_indices, &results);
 
     if !scriptlets_indices.is_empty() {
         grouped.push(GroupedListItem::SectionHeader("SCRIPTLETS".to_string()));
@@ -329,6 +568,13 @@ pub fn get_grouped_results(
         }
     }
 
+    if !recent_files_indices.is_empty() {
+        grouped.push(GroupedListItem::SectionHeader("RECENT FILES".to_string()));
+        for idx in &recent_files_indices {
+            grouped.push(GroupedListItem::Item(*idx));
+        }
+    }
+
     debug!(
         suggested_count = suggested_indices.len(),
         scripts_count = scripts_indices.len(),
@@ -336,9 +582,112 @@ pub fn get_grouped_results(
         commands_count = commands_indices.len(),
         apps_count = apps_indices.len(),
         agents_count = agents_indices.len(),
+        recent_files_count = recent_files_indices.len(),
         total_grouped = grouped.len(),
         "Grouped view: created type-based sections"
     );
 
     (grouped, results)
 }
+
+/// Push the SCRIPTS section of the grouped view, split into one sub-section
+/// per kenv when `scripts_indices` spans more than one (see
+/// `Config.script_dirs` / `Script.kenv`). With zero or one kenv - the
+/// default single-kit setup - this is just the plain "SCRIPTS" header,
+/// unchanged from before kenv tagging existed.
+fn push_scripts_section(
+    grouped: &mut Vec<GroupedListItem>,
+    scripts_indices: &[usize],
+    results: &[SearchResult],
+) {
+    if scripts_indices.is_empty() {
+        return;
+    }
+
+    let kenv_of = |idx: usize| -> Option<&str> {
+        match &results[idx] {
+            SearchResult::Script(sm) => sm.script.kenv.as_deref(),
+            _ => None,
+        }
+    };
+
+    let mut kenvs: Vec<&str> = scripts_indices.iter().filter_map(|&idx| kenv_of(idx)).collect();
+    kenvs.sort_unstable();
+    kenvs.dedup();
+
+    let has_untagged = scripts_indices.iter().any(|&idx| kenv_of(idx).is_none());
+    if kenvs.len() + usize::from(has_untagged) <= 1 {
+        grouped.push(GroupedListItem::SectionHeader("SCRIPTS".to_string()));
+        for &idx in scripts_indices {
+            grouped.push(GroupedListItem::Item(idx));
+        }
+        return;
+    }
+
+    for kenv in &kenvs {
+        grouped.push(GroupedListItem::SectionHeader(format!(
+            "SCRIPTS ({})",
+            kenv.to_uppercase()
+        )));
+        for &idx in scripts_indices
+            .iter()
+            .filter(|&&idx| kenv_of(idx) == Some(*kenv))
+        {
+            grouped.push(GroupedListItem::Item(idx));
+        }
+    }
+
+    if has_untagged {
+        grouped.push(GroupedListItem::SectionHeader("SCRIPTS".to_string()));
+        for &idx in scripts_indices.iter().filter(|&&idx| kenv_of(idx).is_none()) {
+            grouped.push(GroupedListItem::Item(idx));
+        }
+    }
+}
+
+/// Hide the items belonging to collapsed sections from a grouped-view result,
+/// leaving the section headers themselves in place (so they can still be
+/// clicked to expand again) and returning the full, pre-collapse item count
+/// per section so headers can display it regardless of collapsed state.
+///
+/// Only meant for the grouped view (empty filter, no scope) - callers should
+/// skip this entirely while a filter is active so search always shows
+/// everything.
+pub fn filter_collapsed_sections(
+    items: Vec<GroupedListItem>,
+    is_collapsed: impl Fn(&str) -> bool,
+) -> (Vec<GroupedListItem>, HashMap<String, usize>) {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let mut section: Option<&str> = None;
+    for item in &items {
+        match item {
+            GroupedListItem::SectionHeader(label) => {
+                counts.entry(label.clone()).or_insert(0);
+                section = Some(label.as_str());
+            }
+            GroupedListItem::Item(_) => {
+                if let Some(label) = section {
+                    *counts.get_mut(label).unwrap() += 1;
+                }
+            }
+        }
+    }
+
+    let mut filtered = Vec::with_capacity(items.len());
+    let mut hide_current = false;
+    for item in items {
+        match &item {
+            GroupedListItem::SectionHeader(label) => {
+                hide_current = is_collapsed(label);
+                filtered.push(item);
+            }
+            GroupedListItem::Item(_) => {
+                if !hide_current {
+                    filtered.push(item);
+                }
+            }
+        }
+    }
+
+    (filtered, counts)
+}
@@ -3,24 +3,40 @@
 //! This module provides functions for loading scripts from the
 //! ~/.scriptkit/*/scripts/ directories.
 
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tracing::{debug, instrument, warn};
 
 use glob::glob;
 
+use crate::config::{load_config, Config};
 use crate::setup::get_kit_path;
 
 use super::metadata::extract_metadata_full;
-use super::types::Script;
+use super::types::{Script, ScriptConcurrency};
 
-/// Reads scripts from ~/.scriptkit/*/scripts/ directories
-/// Returns a sorted list of Arc-wrapped Script structs for .ts and .js files
-/// Returns empty vec if directory doesn't exist or is inaccessible
+/// Reads scripts from `~/.scriptkit/kit/*/scripts/` plus any extra
+/// directories from `Config.script_dirs`. Loads the config itself, so
+/// prefer [`read_scripts_with_config`] if the caller already has one (see
+/// the "duplicate config::load_config() calls" note on that function).
+///
+/// Returns a sorted list of Arc-wrapped Script structs for .ts and .js files.
 ///
 /// H1 Optimization: Returns Arc<Script> to avoid expensive clones during filter operations.
 #[instrument(level = "debug", skip_all)]
 pub fn read_scripts() -> Vec<Arc<Script>> {
+    read_scripts_with_config(&load_config())
+}
+
+/// Same as [`read_scripts`], but reuses an already-loaded `Config` instead
+/// of calling `config::load_config()` again (which shells out to bun and
+/// costs 100-300ms).
+///
+/// Returns empty if neither the default kit directories nor any configured
+/// `script_dirs` entry can be read.
+#[instrument(level = "debug", skip_all)]
+pub fn read_scripts_with_config(config: &Config) -> Vec<Arc<Script>> {
     let kit_path = get_kit_path();
 
     // Glob pattern to find scripts in all kits (under kit/ subdirectory)
@@ -28,24 +44,36 @@ pub fn read_scripts() -> Vec<Arc<Script>> {
     let pattern_str = pattern.to_string_lossy().to_string();
 
     let mut scripts = Vec::new();
+    let mut seen_paths: HashSet<PathBuf> = HashSet::new();
 
     // Find all kit script directories
-    let script_dirs: Vec<PathBuf> = match glob(&pattern_str) {
+    let kit_script_dirs: Vec<PathBuf> = match glob(&pattern_str) {
         Ok(paths) => paths.filter_map(|p| p.ok()).collect(),
         Err(e) => {
             warn!(error = %e, pattern = %pattern_str, "Failed to glob script directories");
-            return vec![];
+            Vec::new()
         }
     };
 
-    if script_dirs.is_empty() {
-        debug!(pattern = %pattern_str, "No script directories found");
-        return vec![];
+    // Read scripts from each kit's scripts directory, tagged with the
+    // kenv name (the `kit/<kenv>/scripts` directory's own name).
+    for scripts_dir in &kit_script_dirs {
+        let kenv = scripts_dir
+            .parent()
+            .and_then(|p| p.file_name())
+            .map(|n| n.to_string_lossy().to_string());
+        read_scripts_from_dir(scripts_dir, kenv.as_deref(), &mut scripts, &mut seen_paths);
     }
 
-    // Read scripts from each kit's scripts directory
-    for scripts_dir in script_dirs {
-        read_scripts_from_dir(&scripts_dir, &mut scripts);
+    // Read scripts from any extra configured directories, deduping by path
+    // against what the kit dirs already contributed (and against each other).
+    for (kenv, dir) in config.get_script_dirs() {
+        read_scripts_from_dir(&dir, Some(kenv.as_str()), &mut scripts, &mut seen_paths);
+    }
+
+    if scripts.is_empty() {
+        debug!(pattern = %pattern_str, "No scripts found in any configured directory");
+        return vec![];
     }
 
     // Sort by name
@@ -55,9 +83,17 @@ pub fn read_scripts() -> Vec<Arc<Script>> {
     scripts
 }
 
-/// Read scripts from a single directory and append to the scripts vector
+/// Read scripts from a single directory and append to the scripts vector,
+/// tagging each with `kenv`. Paths already present in `seen_paths` are
+/// skipped, so a script reachable from two configured directories is only
+/// loaded once (first directory scanned wins).
 /// H1 Optimization: Creates Arc-wrapped Scripts for cheap cloning.
-pub(crate) fn read_scripts_from_dir(scripts_dir: &PathBuf, scripts: &mut Vec<Arc<Script>>) {
+pub(crate) fn read_scripts_from_dir(
+    scripts_dir: &PathBuf,
+    kenv: Option<&str>,
+    scripts: &mut Vec<Arc<Script>>,
+    seen_paths: &mut HashSet<PathBuf>,
+) {
     // Read the directory contents
     match std::fs::read_dir(scripts_dir) {
         Ok(entries) => {
@@ -66,6 +102,10 @@ pub(crate) fn read_scripts_from_dir(scripts_dir: &PathBuf, scripts: &mut Vec<Arc
                     if file_metadata.is_file() {
                         let path = entry.path();
 
+                        if seen_paths.contains(&path) {
+                            continue;
+                        }
+
                         // Check extension
                         if let Some(ext) = path.extension() {
                             if let Some(ext_str) = ext.to_str() {
@@ -81,7 +121,16 @@ pub(crate) fn read_scripts_from_dir(scripts_dir: &PathBuf, scripts: &mut Vec<Arc
                                             let name = script_metadata
                                                 .name
                                                 .unwrap_or_else(|| filename_str.to_string());
-
+                                            let concurrency = script_metadata
+                                                .concurrency
+                                                .as_deref()
+                                                .and_then(ScriptConcurrency::parse)
+                                                .unwrap_or_default();
+                                            let tray = script_metadata.tray;
+                                            let background = script_metadata.background;
+                                            let keep_open = script_metadata.keep_open;
+
+                                            seen_paths.insert(path.clone());
                                             scripts.push(Arc::new(Script {
                                                 name,
                                                 path: path.clone(),
@@ -90,8 +139,14 @@ pub(crate) fn read_scripts_from_dir(scripts_dir: &PathBuf, scripts: &mut Vec<Arc
                                                 icon: script_metadata.icon,
                                                 alias: script_metadata.alias,
                                                 shortcut: script_metadata.shortcut,
+                                                app_filter: script_metadata.app_filter,
                                                 typed_metadata,
                                                 schema,
+                                                concurrency,
+                                                tray,
+                                                background,
+                                                keep_open,
+                                                kenv: kenv.map(|s| s.to_string()),
                                             }));
                                         }
                                     }
@@ -111,3 +166,48 @@ pub(crate) fn read_scripts_from_dir(scripts_dir: &PathBuf, scripts: &mut Vec<Arc
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_read_scripts_from_dir_tags_kenv() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("hello.ts"), "// Name: Hello").unwrap();
+
+        let mut scripts = Vec::new();
+        let mut seen_paths = HashSet::new();
+        read_scripts_from_dir(
+            &temp_dir.path().to_path_buf(),
+            Some("work"),
+            &mut scripts,
+            &mut seen_paths,
+        );
+
+        assert_eq!(scripts.len(), 1);
+        assert_eq!(scripts[0].kenv.as_deref(), Some("work"));
+    }
+
+    #[test]
+    fn test_read_scripts_from_dir_skips_already_seen_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let script_path = temp_dir.path().join("hello.ts");
+        fs::write(&script_path, "// Name: Hello").unwrap();
+
+        let mut scripts = Vec::new();
+        let mut seen_paths = HashSet::new();
+        seen_paths.insert(script_path);
+
+        read_scripts_from_dir(
+            &temp_dir.path().to_path_buf(),
+            Some("work"),
+            &mut scripts,
+            &mut seen_paths,
+        );
+
+        assert!(scripts.is_empty());
+    }
+}
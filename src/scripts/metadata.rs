@@ -84,6 +84,31 @@ pub fn extract_script_metadata(content: &str) -> ScriptMetadata {
                         metadata.shortcut = Some(value);
                     }
                 }
+                "app" => {
+                    if metadata.app_filter.is_none() && !value.is_empty() {
+                        metadata.app_filter = Some(value);
+                    }
+                }
+                "concurrency" => {
+                    if metadata.concurrency.is_none() && !value.is_empty() {
+                        metadata.concurrency = Some(value);
+                    }
+                }
+                "tray" => {
+                    if !metadata.tray && value.eq_ignore_ascii_case("true") {
+                        metadata.tray = true;
+                    }
+                }
+                "background" => {
+                    if !metadata.background && value.eq_ignore_ascii_case("true") {
+                        metadata.background = true;
+                    }
+                }
+                "keepopen" => {
+                    if !metadata.keep_open && value.eq_ignore_ascii_case("true") {
+                        metadata.keep_open = true;
+                    }
+                }
                 _ => {} // Ignore other metadata keys for now
             }
         }
@@ -124,6 +149,11 @@ pub fn extract_full_metadata(
             icon: typed.icon.clone().or(comment_meta.icon),
             alias: typed.alias.clone().or(comment_meta.alias),
             shortcut: typed.shortcut.clone().or(comment_meta.shortcut),
+            app_filter: typed.app.clone().or(comment_meta.app_filter),
+            concurrency: typed.concurrency.clone().or(comment_meta.concurrency),
+            tray: typed.tray || comment_meta.tray,
+            background: typed.background || comment_meta.background,
+            keep_open: typed.keep_open || comment_meta.keep_open,
         }
     } else {
         comment_meta
@@ -167,8 +197,8 @@ pub(crate) fn extract_metadata_full(
 }
 
 /// Extract schedule metadata from script content
-/// Parses lines looking for "// Cron:" and "// Schedule:" with lenient matching
-/// Only checks the first 30 lines of the file
+/// Parses lines looking for "// Cron:", "// Schedule:", and "// MissedRuns:"
+/// with lenient matching. Only checks the first 30 lines of the file.
 pub fn extract_schedule_metadata(content: &str) -> ScheduleMetadata {
     let mut metadata = ScheduleMetadata::default();
 
@@ -185,6 +215,9 @@ pub fn extract_schedule_metadata(content: &str) -> ScheduleMetadata {
                         metadata.schedule = Some(value);
                     }
                 }
+                "missedruns" => {
+                    metadata.missed_runs = crate::scheduler_catchup::MissedRunsPolicy::parse(&value);
+                }
                 _ => {} // Ignore other metadata keys
             }
         }
@@ -29,10 +29,13 @@ mod search;
 mod types;
 
 // Re-export core types (always used)
-pub use types::{AgentMatch, FallbackConfig, Script, Scriptlet, SearchResult};
+pub use types::{
+    AgentMatch, FallbackConfig, RecentFileMatch, Script, ScriptConcurrency, Scriptlet,
+    ScriptletBlock, SearchResult,
+};
 
 // Re-export loader functions (always used)
-pub use loader::read_scripts;
+pub use loader::{read_scripts, read_scripts_with_config};
 
 // Re-export scriptlet loader functions (always used)
 pub use scriptlet_loader::{load_scriptlets, read_scriptlets, read_scriptlets_from_file};
@@ -40,10 +43,15 @@ pub use scriptlet_loader::{load_scriptlets, read_scriptlets, read_scriptlets_fro
 // Re-export search functions (always used)
 pub use search::{
     compute_match_indices_for_result, fuzzy_search_unified, fuzzy_search_unified_all,
+    fuzzy_search_unified_all_weighted, fuzzy_search_unified_weighted,
 };
 
 // Re-export grouping functions (always used)
-pub use grouping::get_grouped_results;
+pub use grouping::{
+    filter_collapsed_sections, frecency_key_for_result, get_grouped_results,
+    get_grouped_results_with_sort, get_grouped_results_with_weights, parse_search_scope,
+    SearchScope,
+};
 
 // Re-export scheduling functions (always used)
 pub use scheduling::register_scheduled_scripts;
@@ -57,8 +65,9 @@ pub use metadata::{extract_full_metadata, extract_script_metadata, parse_metadat
 
 #[cfg(test)]
 pub use search::{
-    fuzzy_search_builtins, fuzzy_search_scriptlets, fuzzy_search_scripts,
-    fuzzy_search_unified_with_builtins, fuzzy_search_unified_with_windows, fuzzy_search_windows,
+    fuzzy_search_builtins, fuzzy_search_recent_files, fuzzy_search_scriptlets,
+    fuzzy_search_scripts, fuzzy_search_scripts_weighted, fuzzy_search_unified_with_builtins,
+    fuzzy_search_unified_with_windows, fuzzy_search_windows,
 };
 
 // Re-export external types needed by tests via super::*
@@ -76,8 +85,8 @@ pub use std::path::PathBuf;
 // Internal re-exports for tests
 #[cfg(test)]
 pub(crate) use scriptlet_loader::{
-    build_scriptlet_file_path, extract_code_block, extract_html_comment_metadata,
-    extract_kit_from_path, parse_scriptlet_section,
+    build_scriptlet_file_path, extract_code_block, extract_code_blocks,
+    extract_html_comment_metadata, extract_kit_from_path, parse_scriptlet_section,
 };
 #[cfg(test)]
 pub(crate) use search::{
@@ -76,6 +76,7 @@ pub fn register_scheduled_scripts(scheduler: &Scheduler) -> usize {
                                 path.clone(),
                                 schedule_meta.cron.clone(),
                                 schedule_meta.schedule.clone(),
+                                schedule_meta.missed_runs,
                             ) {
                                 Ok(()) => {
                                     registered_count += 1;
@@ -83,6 +84,7 @@ pub fn register_scheduled_scripts(scheduler: &Scheduler) -> usize {
                                         path = %path.display(),
                                         cron = ?schedule_meta.cron,
                                         schedule = ?schedule_meta.schedule,
+                                        missed_runs = ?schedule_meta.missed_runs,
                                         "Registered scheduled script"
                                     );
                                 }
@@ -14,7 +14,7 @@ use glob::glob;
 use crate::scriptlets as scriptlet_parser;
 use crate::setup::get_kit_path;
 
-use super::types::Scriptlet;
+use super::types::{Scriptlet, ScriptletBlock};
 
 /// Extract metadata from HTML comments in scriptlet markdown
 /// Looks for <!-- key: value --> patterns
@@ -69,6 +69,41 @@ pub(crate) fn extract_code_block(text: &str) -> Option<(String, String)> {
     None
 }
 
+/// Extract every fenced code block in `text`, in order, paired with an
+/// optional label taken from a `### <label>` sub-heading directly preceding
+/// it (a label only applies to the fence immediately after it).
+///
+/// Unlike `extract_code_block`, this walks line-by-line so it can find more
+/// than one fence per section - used when a scriptlet heading contains
+/// several labeled tools (see `parse_scriptlet_section`).
+pub(crate) fn extract_code_blocks(text: &str) -> Vec<(Option<String>, String, String)> {
+    let mut blocks = Vec::new();
+    let mut pending_label: Option<String> = None;
+    let mut lines = text.lines();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("###") && !trimmed.starts_with("####") {
+            pending_label = Some(trimmed.trim_start_matches('#').trim().to_string());
+            continue;
+        }
+
+        if let Some(rest) = trimmed.strip_prefix("```") {
+            let language = rest.trim().to_string();
+            let mut code_lines = Vec::new();
+            for code_line in lines.by_ref() {
+                if code_line.trim_start().starts_with("```") {
+                    break;
+                }
+                code_lines.push(code_line);
+            }
+            blocks.push((pending_label.take(), language, code_lines.join("\n").trim().to_string()));
+        }
+    }
+
+    blocks
+}
+
 /// Convert a name to a command slug (lowercase, spaces/special chars to hyphens)
 pub(crate) fn slugify_name(name: &str) -> String {
     name.to_lowercase()
@@ -111,8 +146,26 @@ pub(crate) fn parse_scriptlet_section(
     // Extract metadata from HTML comments
     let metadata = extract_html_comment_metadata(section);
 
-    // Extract code block
-    let (tool, code) = extract_code_block(section)?;
+    // Extract every code block - most scriptlets have exactly one, which
+    // becomes `code`/`tool` below; any further blocks become `extra_blocks`.
+    let mut blocks = extract_code_blocks(section);
+    if blocks.is_empty() {
+        return None;
+    }
+    let (_, tool, code) = blocks.remove(0);
+    let extra_blocks = blocks
+        .into_iter()
+        .map(|(label, tool, code)| ScriptletBlock {
+            label,
+            inputs: scriptlet_parser::extract_named_inputs(&code),
+            tool,
+            code,
+        })
+        .collect();
+    let sequence = metadata
+        .get("sequence")
+        .map(|v| v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false);
 
     // Generate command slug from name
     let command = slugify_name(&name);
@@ -120,6 +173,8 @@ pub(crate) fn parse_scriptlet_section(
     // Build file_path with anchor if source_path is provided
     let file_path = source_path.map(|p| format!("{}#{}", p.display(), command));
 
+    let inputs = scriptlet_parser::extract_named_inputs(&code);
+
     Some(Scriptlet {
         name,
         description: metadata.get("description").cloned(),
@@ -131,6 +186,10 @@ pub(crate) fn parse_scriptlet_section(
         file_path,
         command: Some(command),
         alias: metadata.get("alias").cloned(),
+        inputs,
+        schema: None,
+        extra_blocks,
+        sequence,
     })
 }
 
@@ -292,6 +351,10 @@ pub fn load_scriptlets() -> Vec<Arc<Scriptlet>> {
                                             file_path: Some(file_path),
                                             command: Some(parsed_scriptlet.command),
                                             alias: parsed_scriptlet.metadata.alias,
+                                            inputs: parsed_scriptlet.inputs,
+                                            schema: parsed_scriptlet.schema,
+                                            extra_blocks: Vec::new(),
+                                            sequence: false,
                                         }));
                                     }
                                 }
@@ -427,6 +490,10 @@ pub fn read_scriptlets_from_file(path: &Path) -> Vec<Arc<Scriptlet>> {
                 file_path: Some(file_path),
                 command: Some(parsed_scriptlet.command),
                 alias: parsed_scriptlet.metadata.alias,
+                inputs: parsed_scriptlet.inputs,
+                schema: parsed_scriptlet.schema,
+                extra_blocks: Vec::new(),
+                sequence: false,
             })
         })
         .collect();
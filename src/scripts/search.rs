@@ -1,7 +1,10 @@
 //! Fuzzy search functionality for scripts, scriptlets, and other items
 //!
 //! This module provides fuzzy search functions using nucleo for high-performance
-//! matching and scoring, plus ASCII case-folding helpers for efficiency.
+//! matching and scoring, plus ASCII case-folding helpers for efficiency. For
+//! non-ASCII text, matching falls back to [`crate::utils::normalize_for_search`]
+//! so that diacritics (e.g. "Zürich" vs "Zurich") and compatibility forms are
+//! folded away consistently with the rest of the app's contains-style filters.
 
 use std::cmp::Ordering;
 use std::sync::Arc;
@@ -11,11 +14,14 @@ use nucleo_matcher::{Matcher, Utf32Str};
 
 use crate::app_launcher::AppInfo;
 use crate::builtins::{BuiltInEntry, BuiltInGroup};
+use crate::config::SearchWeights;
+use crate::recent_files::RecentFileInfo;
+use crate::utils::{contains_normalized, find_normalized, normalize_for_search};
 use crate::window_control::WindowInfo;
 
 use super::types::{
-    AppMatch, BuiltInMatch, MatchIndices, Script, ScriptMatch, Scriptlet, ScriptletMatch,
-    SearchResult, WindowMatch,
+    AppMatch, BuiltInMatch, MatchIndices, RecentFileMatch, Script, ScriptMatch, Scriptlet,
+    ScriptletMatch, SearchResult, WindowMatch,
 };
 
 // ============================================
@@ -151,6 +157,68 @@ pub(crate) fn fuzzy_match_with_indices(haystack: &str, pattern: &str) -> (bool,
     (matched, if matched { indices } else { Vec::new() })
 }
 
+/// Extract the lowercase initial letter of each "word" in `haystack`, where a
+/// new word starts at the beginning of the string, after any non-alphanumeric
+/// separator (space, dash, underscore, ...), or at an uppercase letter that
+/// follows a lowercase one (camelCase boundaries like "gitCommitAll").
+fn word_initials(haystack: &str) -> String {
+    let mut initials = String::new();
+    let mut prev: Option<char> = None;
+    let mut at_word_start = true;
+
+    for ch in haystack.chars() {
+        if !ch.is_alphanumeric() {
+            at_word_start = true;
+            prev = Some(ch);
+            continue;
+        }
+
+        let is_boundary = at_word_start
+            || prev
+                .map(|p| p.is_lowercase() && ch.is_uppercase())
+                .unwrap_or(false);
+        if is_boundary {
+            initials.extend(ch.to_lowercase());
+        }
+
+        at_word_start = false;
+        prev = Some(ch);
+    }
+
+    initials
+}
+
+/// Bonus for queries that read as an acronym/initialism of `haystack`'s
+/// words, e.g. "gca" for "Git Commit All". Returns 0 unless every character
+/// of `query_lower` matches a word-initial of `haystack`, in order - a plain
+/// fuzzy subsequence match elsewhere in the word (not on an initial) doesn't
+/// count, so "gca" doesn't get this bonus against "Gallacia" even though
+/// nucleo's ordinary subsequence match still finds it there.
+pub(crate) fn acronym_bonus(haystack: &str, query_lower: &str) -> i32 {
+    if query_lower.is_empty() {
+        return 0;
+    }
+
+    let initials = word_initials(haystack);
+    if initials.chars().count() < query_lower.chars().count() {
+        return 0;
+    }
+
+    let mut query_chars = query_lower.chars().peekable();
+    for ch in initials.chars() {
+        if query_chars.peek() == Some(&ch) {
+            query_chars.next();
+        }
+    }
+    if query_chars.peek().is_some() {
+        return 0;
+    }
+
+    // Scales with query length so a longer, more specific acronym match
+    // (less likely to be coincidental) outranks a shorter one.
+    60 + query_lower.chars().count() as i32 * 10
+}
+
 /// Score a haystack against a nucleo pattern.
 /// Returns Some(score) if the pattern matches, None otherwise.
 /// Score range is typically 0-1000+ (higher = better match).
@@ -190,6 +258,13 @@ pub(crate) struct NucleoCtx {
 impl NucleoCtx {
     /// Create a new NucleoCtx for the given query string.
     /// The query is parsed with case-insensitive matching and smart normalization.
+    ///
+    /// Callers should pass `query` through [`normalize_for_search`] first (and
+    /// score haystacks the same way via [`NucleoCtx::score`]'s own
+    /// expectations below) so that accented text like "Zürich" scores a
+    /// match for the unaccented query "Zurich" - nucleo's own `Normalization`
+    /// setting only handles Unicode width/compatibility forms, not
+    /// diacritics.
     pub fn new(query: &str) -> Self {
         let pattern = Pattern::parse(
             query,
@@ -337,6 +412,26 @@ pub fn compute_match_indices_for_result(result: &SearchResult, query: &str) -> M
 
             indices
         }
+        SearchResult::RecentFile(rm) => {
+            let mut indices = MatchIndices::default();
+
+            // Try file name first
+            let (name_matched, name_indices) =
+                fuzzy_match_with_indices_ascii(&rm.file.name, &query_lower);
+            if name_matched {
+                indices.name_indices = name_indices;
+                return indices;
+            }
+
+            // Fall back to full path, same as Script's filename fallback
+            let path_str = rm.file.path.to_string_lossy().to_string();
+            let (path_matched, path_indices) = fuzzy_match_with_indices_ascii(&path_str, &query_lower);
+            if path_matched {
+                indices.filename_indices = path_indices;
+            }
+
+            indices
+        }
         SearchResult::Fallback(fm) => {
             let mut indices = MatchIndices::default();
 
@@ -391,6 +486,22 @@ pub(crate) fn extract_scriptlet_display_path(file_path: &Option<String>) -> Opti
 /// H1 Optimization: Accepts Arc<Script> to avoid expensive clones during filter operations.
 /// Each ScriptMatch contains an Arc::clone which is just a refcount bump.
 pub fn fuzzy_search_scripts(scripts: &[Arc<Script>], query: &str) -> Vec<ScriptMatch> {
+    fuzzy_search_scripts_weighted(scripts, query, &SearchWeights::default())
+}
+
+/// Fuzzy search scripts by query string, same as [`fuzzy_search_scripts`] but
+/// with each scoring field (name, filename, description) scaled by `weights`
+/// before being added to a script's total score. A field's substring bonus
+/// and its nucleo fuzzy bonus share that field's weight, since they're both
+/// contributions from the same field.
+///
+/// Path matches are intentionally left unweighted - they're a minor
+/// tie-breaker, not something users have asked to tune.
+pub fn fuzzy_search_scripts_weighted(
+    scripts: &[Arc<Script>],
+    query: &str,
+    weights: &SearchWeights,
+) -> Vec<ScriptMatch> {
     if query.is_empty() {
         // If no query, return all scripts with equal score, sorted by name
         return scripts
@@ -408,10 +519,13 @@ pub fn fuzzy_search_scripts(scripts: &[Arc<Script>], query: &str) -> Vec<ScriptM
     }
 
     let query_lower = query.to_lowercase();
+    let query_norm = normalize_for_search(query);
     let mut matches = Vec::new();
 
     // Create nucleo context once for all scripts - reuses buffer across calls
-    let mut nucleo = NucleoCtx::new(&query_lower);
+    // Normalized (not just lowercased) so accented names like "Zürich" score
+    // a match for the unaccented query "Zurich".
+    let mut nucleo = NucleoCtx::new(&query_norm);
     // Check if query is ASCII once for all items
     let query_is_ascii = query_lower.is_ascii();
 
@@ -423,50 +537,72 @@ pub fn fuzzy_search_scripts(scripts: &[Arc<Script>], query: &str) -> Vec<ScriptM
         let filename = extract_filename(&script.path);
 
         // Score by name match - highest priority
-        // Only use ASCII fast-path when both strings are ASCII
+        // ASCII fast-path when both strings are ASCII; otherwise fall back
+        // to diacritic-insensitive normalized matching.
+        let mut name_score = 0i32;
         if query_is_ascii && script.name.is_ascii() {
             if let Some(pos) = find_ignore_ascii_case(&script.name, &query_lower) {
                 // Bonus for exact substring match at start of name
-                score += if pos == 0 { 100 } else { 75 };
+                name_score += if pos == 0 { 100 } else { 75 };
             }
+        } else if let Some(pos) = find_normalized(&script.name, &query_norm) {
+            name_score += if pos == 0 { 100 } else { 75 };
         }
 
-        // Fuzzy character matching in name using nucleo (handles Unicode correctly)
-        if let Some(nucleo_s) = nucleo.score(&script.name) {
+        // Fuzzy character matching in name using nucleo (handles Unicode and,
+        // via normalization above, diacritics too)
+        if let Some(nucleo_s) = nucleo.score(&normalize_for_search(&script.name)) {
             // Scale nucleo score (0-1000+) to match existing weights (~50 for fuzzy match)
-            score += 50 + (nucleo_s / 20) as i32;
+            name_score += 50 + (nucleo_s / 20) as i32;
         }
 
+        // Acronym/initialism bonus - "gca" should rank "Git Commit All" above
+        // a script that only matches "gca" as scattered characters.
+        name_score += acronym_bonus(&script.name, &query_lower);
+
+        score += (name_score as f64 * weights.name).round() as i32;
+
         // Score by filename match - high priority (allows searching by ".ts", ".js", etc.)
         // Filenames are typically ASCII
+        let mut filename_score = 0i32;
         if query_is_ascii && filename.is_ascii() {
             if let Some(pos) = find_ignore_ascii_case(&filename, &query_lower) {
                 // Bonus for exact substring match at start of filename
-                score += if pos == 0 { 60 } else { 45 };
+                filename_score += if pos == 0 { 60 } else { 45 };
             }
+        } else if let Some(pos) = find_normalized(&filename, &query_norm) {
+            filename_score += if pos == 0 { 60 } else { 45 };
         }
 
         // Fuzzy character matching in filename using nucleo (handles Unicode)
-        if let Some(nucleo_s) = nucleo.score(&filename) {
+        if let Some(nucleo_s) = nucleo.score(&normalize_for_search(&filename)) {
             // Scale nucleo score to match existing weights (~35 for filename fuzzy match)
-            score += 35 + (nucleo_s / 30) as i32;
+            filename_score += 35 + (nucleo_s / 30) as i32;
         }
+        score += (filename_score as f64 * weights.filename).round() as i32;
 
         // Score by description match - medium priority
-        // Only use ASCII fast-path when both are ASCII
+        // ASCII fast-path when both are ASCII; otherwise normalized fallback.
+        let mut description_score = 0i32;
         if let Some(ref desc) = script.description {
-            if query_is_ascii && desc.is_ascii() && contains_ignore_ascii_case(desc, &query_lower) {
-                score += 25;
+            if query_is_ascii && desc.is_ascii() {
+                if contains_ignore_ascii_case(desc, &query_lower) {
+                    description_score += 25;
+                }
+            } else if contains_normalized(desc, &query_norm) {
+                description_score += 25;
             }
         }
+        score += (description_score as f64 * weights.description).round() as i32;
 
         // Score by path match - lower priority
         // Paths are typically ASCII
         let path_str = script.path.to_string_lossy();
-        if query_is_ascii
-            && path_str.is_ascii()
-            && contains_ignore_ascii_case(&path_str, &query_lower)
-        {
+        if query_is_ascii && path_str.is_ascii() {
+            if contains_ignore_ascii_case(&path_str, &query_lower) {
+                score += 10;
+            }
+        } else if contains_normalized(&path_str, &query_norm) {
             score += 10;
         }
 
@@ -514,10 +650,11 @@ pub fn fuzzy_search_scriptlets(scriptlets: &[Arc<Scriptlet>], query: &str) -> Ve
     }
 
     let query_lower = query.to_lowercase();
+    let query_norm = normalize_for_search(query);
     let mut matches = Vec::new();
 
     // Create nucleo context once for all scriptlets - reuses buffer across calls
-    let mut nucleo = NucleoCtx::new(&query_lower);
+    let mut nucleo = NucleoCtx::new(&query_norm);
     // Check if query is ASCII once for all items
     let query_is_ascii = query_lower.is_ascii();
 
@@ -529,16 +666,19 @@ pub fn fuzzy_search_scriptlets(scriptlets: &[Arc<Scriptlet>], query: &str) -> Ve
         let display_file_path = extract_scriptlet_display_path(&scriptlet.file_path);
 
         // Score by name match - highest priority
-        // Only use ASCII fast-path when both strings are ASCII
+        // ASCII fast-path when both strings are ASCII; otherwise fall back
+        // to diacritic-insensitive normalized matching.
         if query_is_ascii && scriptlet.name.is_ascii() {
             if let Some(pos) = find_ignore_ascii_case(&scriptlet.name, &query_lower) {
                 // Bonus for exact substring match at start of name
                 score += if pos == 0 { 100 } else { 75 };
             }
+        } else if let Some(pos) = find_normalized(&scriptlet.name, &query_norm) {
+            score += if pos == 0 { 100 } else { 75 };
         }
 
-        // Fuzzy character matching in name using nucleo (handles Unicode)
-        if let Some(nucleo_s) = nucleo.score(&scriptlet.name) {
+        // Fuzzy character matching in name using nucleo (handles Unicode and diacritics)
+        if let Some(nucleo_s) = nucleo.score(&normalize_for_search(&scriptlet.name)) {
             // Scale nucleo score to match existing weights (~50 for fuzzy match)
             score += 50 + (nucleo_s / 20) as i32;
         }
@@ -551,27 +691,33 @@ pub fn fuzzy_search_scriptlets(scriptlets: &[Arc<Scriptlet>], query: &str) -> Ve
                     // Bonus for exact substring match at start of file_path
                     score += if pos == 0 { 60 } else { 45 };
                 }
+            } else if let Some(pos) = find_normalized(fp, &query_norm) {
+                score += if pos == 0 { 60 } else { 45 };
             }
 
             // Fuzzy character matching in file_path using nucleo (handles Unicode)
-            if let Some(nucleo_s) = nucleo.score(fp) {
+            if let Some(nucleo_s) = nucleo.score(&normalize_for_search(fp)) {
                 // Scale nucleo score to match existing weights (~35 for file_path fuzzy match)
                 score += 35 + (nucleo_s / 30) as i32;
             }
         }
 
         // Score by description match - medium priority
-        // Only use ASCII fast-path when both are ASCII
+        // ASCII fast-path when both are ASCII; otherwise normalized fallback.
         if let Some(ref desc) = scriptlet.description {
-            if query_is_ascii && desc.is_ascii() && contains_ignore_ascii_case(desc, &query_lower) {
+            if query_is_ascii && desc.is_ascii() {
+                if contains_ignore_ascii_case(desc, &query_lower) {
+                    score += 25;
+                }
+            } else if contains_normalized(desc, &query_norm) {
                 score += 25;
             }
         }
 
         // CRITICAL OPTIMIZATION: Only search code when query is long enough (>=4 chars)
         // and no other matches were found. Code search is the biggest performance hit
-        // because scriptlet.code can be very large.
-        // Code is typically ASCII
+        // because scriptlet.code can be very large, so unlike the fields above we keep
+        // this one ASCII-only rather than paying for a full-body normalization pass.
         if query_lower.len() >= 4
             && score == 0
             && query_is_ascii
@@ -583,10 +729,11 @@ pub fn fuzzy_search_scriptlets(scriptlets: &[Arc<Scriptlet>], query: &str) -> Ve
 
         // Bonus for tool type match
         // Tool types are ASCII (snippet, template, etc.)
-        if query_is_ascii
-            && scriptlet.tool.is_ascii()
-            && contains_ignore_ascii_case(&scriptlet.tool, &query_lower)
-        {
+        if query_is_ascii && scriptlet.tool.is_ascii() {
+            if contains_ignore_ascii_case(&scriptlet.tool, &query_lower) {
+                score += 10;
+            }
+        } else if contains_normalized(&scriptlet.tool, &query_norm) {
             score += 10;
         }
 
@@ -776,10 +923,11 @@ pub fn fuzzy_search_apps(apps: &[AppInfo], query: &str) -> Vec<AppMatch> {
     }
 
     let query_lower = query.to_lowercase();
+    let query_norm = normalize_for_search(query);
     let mut matches = Vec::new();
 
     // Create nucleo context once for all apps - reuses buffer across calls
-    let mut nucleo = NucleoCtx::new(&query_lower);
+    let mut nucleo = NucleoCtx::new(&query_norm);
     // Check if query is ASCII once for all items
     let query_is_ascii = query_lower.is_ascii();
 
@@ -787,16 +935,20 @@ pub fn fuzzy_search_apps(apps: &[AppInfo], query: &str) -> Vec<AppMatch> {
         let mut score = 0i32;
 
         // Score by name match - highest priority
-        // App names can have Unicode (e.g., "日本語アプリ")
+        // App names can have Unicode (e.g., "日本語アプリ") or Latin diacritics
+        // (e.g., "Écran Total"), so fall back to normalized matching when the
+        // ASCII fast path doesn't apply.
         if query_is_ascii && app.name.is_ascii() {
             if let Some(pos) = find_ignore_ascii_case(&app.name, &query_lower) {
                 // Bonus for exact substring match at start of name
                 score += if pos == 0 { 100 } else { 75 };
             }
+        } else if let Some(pos) = find_normalized(&app.name, &query_norm) {
+            score += if pos == 0 { 100 } else { 75 };
         }
 
-        // Fuzzy character matching in name using nucleo (handles Unicode)
-        if let Some(nucleo_s) = nucleo.score(&app.name) {
+        // Fuzzy character matching in name using nucleo (handles Unicode and diacritics)
+        if let Some(nucleo_s) = nucleo.score(&normalize_for_search(&app.name)) {
             // Scale nucleo score to match existing weights (~50 for fuzzy match)
             score += 50 + (nucleo_s / 20) as i32;
         }
@@ -839,6 +991,76 @@ pub fn fuzzy_search_apps(apps: &[AppInfo], query: &str) -> Vec<AppMatch> {
     matches
 }
 
+/// Fuzzy search recently opened/modified files by query string
+/// Searches across file name and full path
+///
+/// Gated on `recent_files::MIN_QUERY_LEN` for non-empty queries - a query
+/// shorter than that returns no results, since short queries match almost
+/// any filename and would otherwise flood the list with noise (see
+/// `recent_files` module docs). An empty query (grouped view) instead
+/// returns every file with score 0, preserving `files`' incoming order
+/// (most-recently-modified first), mirroring `fuzzy_search_apps`.
+/// Returns results sorted by relevance score (highest first).
+pub fn fuzzy_search_recent_files(files: &[RecentFileInfo], query: &str) -> Vec<RecentFileMatch> {
+    if query.is_empty() {
+        return files
+            .iter()
+            .map(|f| RecentFileMatch {
+                file: f.clone(),
+                score: 0,
+            })
+            .collect();
+    }
+
+    if query.chars().count() < crate::recent_files::MIN_QUERY_LEN {
+        return Vec::new();
+    }
+
+    let query_lower = query.to_lowercase();
+    let query_norm = normalize_for_search(query);
+    let mut matches = Vec::new();
+    let mut nucleo = NucleoCtx::new(&query_norm);
+    let query_is_ascii = query_lower.is_ascii();
+
+    for file in files {
+        let mut score = 0i32;
+
+        if query_is_ascii && file.name.is_ascii() {
+            if let Some(pos) = find_ignore_ascii_case(&file.name, &query_lower) {
+                score += if pos == 0 { 100 } else { 75 };
+            }
+        } else if let Some(pos) = find_normalized(&file.name, &query_norm) {
+            score += if pos == 0 { 100 } else { 75 };
+        }
+
+        if let Some(nucleo_s) = nucleo.score(&normalize_for_search(&file.name)) {
+            score += 50 + (nucleo_s / 20) as i32;
+        }
+
+        let path_str = file.path.to_string_lossy();
+        if query_is_ascii
+            && path_str.is_ascii()
+            && contains_ignore_ascii_case(&path_str, &query_lower)
+        {
+            score += 5;
+        }
+
+        if score > 0 {
+            matches.push(RecentFileMatch {
+                file: file.clone(),
+                score,
+            });
+        }
+    }
+
+    matches.sort_by(|a, b| match b.score.cmp(&a.score) {
+        Ordering::Equal => a.file.name.cmp(&b.file.name),
+        other => other,
+    });
+
+    matches
+}
+
 /// Fuzzy search windows by query string
 /// Searches across app name and window title
 /// Returns results sorted by relevance score (highest first)
@@ -868,10 +1090,11 @@ pub fn fuzzy_search_windows(windows: &[WindowInfo], query: &str) -> Vec<WindowMa
     }
 
     let query_lower = query.to_lowercase();
+    let query_norm = normalize_for_search(query);
     let mut matches = Vec::new();
 
     // Create nucleo context once for all windows - reuses buffer across calls
-    let mut nucleo = NucleoCtx::new(&query_lower);
+    let mut nucleo = NucleoCtx::new(&query_norm);
     // Check if query is ASCII once for all items
     let query_is_ascii = query_lower.is_ascii();
 
@@ -879,12 +1102,14 @@ pub fn fuzzy_search_windows(windows: &[WindowInfo], query: &str) -> Vec<WindowMa
         let mut score = 0i32;
 
         // Score by app name match - highest priority
-        // App names can have Unicode
+        // App names can have Unicode or Latin diacritics
         if query_is_ascii && window.app.is_ascii() {
             if let Some(pos) = find_ignore_ascii_case(&window.app, &query_lower) {
                 // Bonus for exact substring match at start of app name
                 score += if pos == 0 { 100 } else { 75 };
             }
+        } else if let Some(pos) = find_normalized(&window.app, &query_norm) {
+            score += if pos == 0 { 100 } else { 75 };
         }
 
         // Score by window title match - high priority
@@ -894,16 +1119,18 @@ pub fn fuzzy_search_windows(windows: &[WindowInfo], query: &str) -> Vec<WindowMa
                 // Bonus for exact substring match at start of title
                 score += if pos == 0 { 90 } else { 65 };
             }
+        } else if let Some(pos) = find_normalized(&window.title, &query_norm) {
+            score += if pos == 0 { 90 } else { 65 };
         }
 
-        // Fuzzy character matching in app name using nucleo (handles Unicode)
-        if let Some(nucleo_s) = nucleo.score(&window.app) {
+        // Fuzzy character matching in app name using nucleo (handles Unicode and diacritics)
+        if let Some(nucleo_s) = nucleo.score(&normalize_for_search(&window.app)) {
             // Scale nucleo score to match existing weights (~50 for app name fuzzy match)
             score += 50 + (nucleo_s / 20) as i32;
         }
 
-        // Fuzzy character matching in window title using nucleo (handles Unicode)
-        if let Some(nucleo_s) = nucleo.score(&window.title) {
+        // Fuzzy character matching in window title using nucleo (handles Unicode and diacritics)
+        if let Some(nucleo_s) = nucleo.score(&normalize_for_search(&window.title)) {
             // Scale nucleo score to match existing weights (~40 for title fuzzy match)
             score += 40 + (nucleo_s / 25) as i32;
         }
@@ -941,6 +1168,17 @@ pub fn fuzzy_search_unified(
     fuzzy_search_unified_with_builtins(scripts, scriptlets, &[], query)
 }
 
+/// Same as [`fuzzy_search_unified`], but scores scripts using `weights`
+/// instead of the default (1.0) field weights.
+pub fn fuzzy_search_unified_weighted(
+    scripts: &[Arc<Script>],
+    scriptlets: &[Arc<Scriptlet>],
+    query: &str,
+    weights: &SearchWeights,
+) -> Vec<SearchResult> {
+    fuzzy_search_unified_all_weighted(scripts, scriptlets, &[], &[], query, weights)
+}
+
 /// Perform unified fuzzy search across scripts, scriptlets, and built-ins
 /// Returns combined and ranked results sorted by relevance
 /// Built-ins appear at the TOP of results (before scripts) when scores are equal
@@ -968,6 +1206,26 @@ pub fn fuzzy_search_unified_all(
     builtins: &[BuiltInEntry],
     apps: &[AppInfo],
     query: &str,
+) -> Vec<SearchResult> {
+    fuzzy_search_unified_all_weighted(
+        scripts,
+        scriptlets,
+        builtins,
+        apps,
+        query,
+        &SearchWeights::default(),
+    )
+}
+
+/// Same as [`fuzzy_search_unified_all`], but scores scripts using
+/// `weights` instead of the default (1.0) field weights.
+pub fn fuzzy_search_unified_all_weighted(
+    scripts: &[Arc<Script>],
+    scriptlets: &[Arc<Scriptlet>],
+    builtins: &[BuiltInEntry],
+    apps: &[AppInfo],
+    query: &str,
+    weights: &SearchWeights,
 ) -> Vec<SearchResult> {
     let mut results = Vec::new();
 
@@ -984,7 +1242,7 @@ pub fn fuzzy_search_unified_all(
     }
 
     // Search scripts
-    let script_matches = fuzzy_search_scripts(scripts, query);
+    let script_matches = fuzzy_search_scripts_weighted(scripts, query, weights);
     for sm in script_matches {
         results.push(SearchResult::Script(sm));
     }
@@ -1009,7 +1267,8 @@ pub fn fuzzy_search_unified_all(
                         SearchResult::Script(_) => 3,
                         SearchResult::Scriptlet(_) => 4,
                         SearchResult::Agent(_) => 5,
-                        SearchResult::Fallback(_) => 6, // Fallbacks always last
+                        SearchResult::RecentFile(_) => 6,
+                        SearchResult::Fallback(_) => 7, // Fallbacks always last
                     }
                 };
                 let type_order_a = type_order(a);
@@ -1085,7 +1344,8 @@ pub fn fuzzy_search_unified_with_windows(
                         SearchResult::Script(_) => 3,
                         SearchResult::Scriptlet(_) => 4,
                         SearchResult::Agent(_) => 5,
-                        SearchResult::Fallback(_) => 6, // Fallbacks always last
+                        SearchResult::RecentFile(_) => 6,
+                        SearchResult::Fallback(_) => 7, // Fallbacks always last
                     }
                 };
                 let type_order_a = type_order(a);
@@ -25,10 +25,62 @@ pub struct Script {
     pub alias: Option<String>,
     /// Keyboard shortcut for direct invocation (e.g., "opt i", "cmd shift k")
     pub shortcut: Option<String>,
+    /// Restricts `shortcut` to only fire while this app (bundle id or name)
+    /// is frontmost. From `// App:` comment or typed `metadata.app`; lets
+    /// several scripts share one key combo, each scoped to a different app.
+    pub app_filter: Option<String>,
     /// Typed metadata from `metadata = { ... }` declaration in script
     pub typed_metadata: Option<TypedMetadata>,
     /// Schema definition from `schema = { ... }` declaration in script
     pub schema: Option<Schema>,
+    /// Policy governing what happens when this script is launched while
+    /// an earlier run of the same path is still active.
+    /// From `// Concurrency:` comment or typed metadata; defaults to `Single`.
+    pub concurrency: ScriptConcurrency,
+    /// Whether this script should appear in the tray's "Scripts" submenu.
+    /// From `// Tray: true` comment or typed `metadata.tray`; defaults to `false`.
+    pub tray: bool,
+    /// Whether this script runs as a detached background process (watcher,
+    /// server, etc.) instead of showing a prompt. From `// Background: true`
+    /// comment or typed `metadata.background`; defaults to `false`.
+    pub background: bool,
+    /// Whether to return to the script list instead of hiding the window
+    /// when this script finishes running. From `// KeepOpen: true` comment
+    /// or typed `metadata.keepOpen`; defaults to `false`.
+    pub keep_open: bool,
+    /// Name of the kenv (script directory) this script was loaded from, for
+    /// grouping scripts kept in multiple repos. Set by `read_scripts` from
+    /// either the `kit/<kenv>/scripts` directory name or a configured
+    /// `Config.script_dirs` entry's `kenv` override. `None` for scripts
+    /// constructed outside the loader (e.g. in tests).
+    pub kenv: Option<String>,
+}
+
+/// Policy for handling a launch request for a script whose previous run
+/// (by path) hasn't exited yet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScriptConcurrency {
+    /// Refuse the new launch and show a HUD telling the user it's already running.
+    #[default]
+    Single,
+    /// Hold the new launch and start it automatically once the running one exits.
+    Queue,
+    /// Start the new launch immediately, side-by-side with the running one.
+    Parallel,
+}
+
+impl ScriptConcurrency {
+    /// Parse a `// Concurrency:` comment value or typed metadata string.
+    /// Matching is case-insensitive; unrecognized values return `None`
+    /// so callers can fall back to the default.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "single" => Some(ScriptConcurrency::Single),
+            "queue" => Some(ScriptConcurrency::Queue),
+            "parallel" => Some(ScriptConcurrency::Parallel),
+            _ => None,
+        }
+    }
 }
 
 /// Represents a scriptlet parsed from a markdown file
@@ -49,6 +101,32 @@ pub struct Scriptlet {
     pub command: Option<String>,
     /// Alias for quick triggering
     pub alias: Option<String>,
+    /// Named input placeholders declared in the content (e.g., from `{{variableName}}`)
+    pub inputs: Vec<String>,
+    /// Schema definition from a ```schema codefence block, used to type `inputs` (text vs choice)
+    pub schema: Option<Schema>,
+    /// Additional fenced code blocks beyond the first, for scriptlets with
+    /// several blocks under one heading (e.g. separate setup/run steps).
+    /// Empty for the common single-block case, where `code`/`tool` above are
+    /// the only block - existing single-block consumers need no changes.
+    pub extra_blocks: Vec<ScriptletBlock>,
+    /// Whether `extra_blocks` should run automatically in order instead of
+    /// prompting which block to run. From a `sequence: true` HTML-comment
+    /// metadata entry; ignored when there are no `extra_blocks`.
+    pub sequence: bool,
+}
+
+/// One labeled code block within a multi-block scriptlet (see
+/// `Scriptlet::extra_blocks`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScriptletBlock {
+    /// Label for this block, from a `### <label>` sub-heading directly
+    /// preceding its fence; `None` for an unlabeled fence.
+    pub label: Option<String>,
+    pub tool: String,
+    pub code: String,
+    /// Named `{{input}}` placeholders declared in this block's own code.
+    pub inputs: Vec<String>,
 }
 
 /// Represents match indices for highlighting matched characters
@@ -117,6 +195,14 @@ pub struct AgentMatch {
     pub match_indices: MatchIndices,
 }
 
+/// Represents a scored match result for fuzzy search on recently
+/// opened/modified files (see `crate::recent_files`)
+#[derive(Clone, Debug)]
+pub struct RecentFileMatch {
+    pub file: crate::recent_files::RecentFileInfo,
+    pub score: i32,
+}
+
 /// Represents a fallback command match for the "Use with..." section
 ///
 /// Fallbacks are always shown at the bottom of search results when there's a filter query.
@@ -129,7 +215,8 @@ pub struct FallbackMatch {
     pub score: i32,
 }
 
-/// Unified search result that can be a Script, Scriptlet, BuiltIn, App, Window, Agent, or Fallback
+/// Unified search result that can be a Script, Scriptlet, BuiltIn, App,
+/// Window, Agent, RecentFile, or Fallback
 #[derive(Clone, Debug)]
 #[allow(clippy::large_enum_variant)]
 pub enum SearchResult {
@@ -139,6 +226,8 @@ pub enum SearchResult {
     App(AppMatch),
     Window(WindowMatch),
     Agent(AgentMatch),
+    /// A recently opened/modified document (see `crate::recent_files`)
+    RecentFile(RecentFileMatch),
     /// Fallback command from "Use with..." section (shown at bottom of search results)
     Fallback(FallbackMatch),
 }
@@ -153,6 +242,7 @@ impl SearchResult {
             SearchResult::App(am) => &am.app.name,
             SearchResult::Window(wm) => &wm.window.title,
             SearchResult::Agent(am) => &am.agent.name,
+            SearchResult::RecentFile(rm) => &rm.file.name,
             SearchResult::Fallback(fm) => fm.fallback.name(),
         }
     }
@@ -166,6 +256,7 @@ impl SearchResult {
             SearchResult::App(am) => am.app.path.to_str(),
             SearchResult::Window(wm) => Some(&wm.window.app),
             SearchResult::Agent(am) => am.agent.description.as_deref(),
+            SearchResult::RecentFile(rm) => rm.file.path.to_str(),
             SearchResult::Fallback(fm) => Some(fm.fallback.description()),
         }
     }
@@ -179,6 +270,7 @@ impl SearchResult {
             SearchResult::App(am) => am.score,
             SearchResult::Window(wm) => wm.score,
             SearchResult::Agent(am) => am.score,
+            SearchResult::RecentFile(rm) => rm.score,
             SearchResult::Fallback(fm) => fm.score,
         }
     }
@@ -192,6 +284,7 @@ impl SearchResult {
             SearchResult::App(_) => "App",
             SearchResult::Window(_) => "Window",
             SearchResult::Agent(_) => "Agent",
+            SearchResult::RecentFile(_) => "Recent File",
             SearchResult::Fallback(_) => "Fallback",
         }
     }
@@ -234,6 +327,7 @@ impl SearchResult {
             SearchResult::App(_) => "Launch App",
             SearchResult::Window(_) => "Switch to Window",
             SearchResult::Agent(_) => "Run Agent",
+            SearchResult::RecentFile(_) => "Open File",
             SearchResult::Fallback(fm) => {
                 // Fallbacks use their action type
                 if fm.fallback.is_builtin() {
@@ -257,6 +351,23 @@ pub struct ScriptMetadata {
     pub alias: Option<String>,
     /// Keyboard shortcut for direct invocation (e.g., "opt i", "cmd shift k")
     pub shortcut: Option<String>,
+    /// Restricts `shortcut` to only fire while this app (bundle id or name)
+    /// is frontmost. From `// App:` comment or typed `metadata.app`.
+    pub app_filter: Option<String>,
+    /// Raw concurrency policy string (e.g., "single", "queue", "parallel")
+    /// from `// Concurrency:` or typed metadata; see `ScriptConcurrency::parse`
+    pub concurrency: Option<String>,
+    /// Whether `// Tray: true` was set, showing this script in the tray's
+    /// "Scripts" submenu.
+    pub tray: bool,
+    /// Whether this script runs as a detached background process (watcher,
+    /// server, etc.) instead of showing a prompt. From `// Background: true`
+    /// comment or typed `metadata.background`; defaults to `false`.
+    pub background: bool,
+    /// Whether to return to the script list instead of hiding the window
+    /// when this script finishes running. From `// KeepOpen: true` comment
+    /// or typed `metadata.keepOpen`; defaults to `false`.
+    pub keep_open: bool,
 }
 
 /// Schedule metadata extracted from script file comments
@@ -267,6 +378,9 @@ pub struct ScheduleMetadata {
     pub cron: Option<String>,
     /// Natural language schedule from `// Schedule: every tuesday at 2pm`
     pub schedule: Option<String>,
+    /// Catch-up behavior for occurrences missed while asleep/closed, from
+    /// `// MissedRuns: skip | once | all` (defaults to `Skip`).
+    pub missed_runs: crate::scheduler_catchup::MissedRunsPolicy,
 }
 
 /// Runtime configuration for fallback commands
@@ -1,5 +1,5 @@
 use super::*;
-use crate::config::SuggestedConfig;
+use crate::config::{SearchWeights, SuggestedConfig};
 use std::sync::Arc;
 
 /// Helper to wrap Vec<Script> into Vec<Arc<Script>> for tests
@@ -25,6 +25,10 @@ fn test_scriptlet(name: &str, tool: &str, code: &str) -> Scriptlet {
         file_path: None,
         command: None,
         alias: None,
+        inputs: Vec::new(),
+        schema: None,
+        extra_blocks: Vec::new(),
+        sequence: false,
     }
 }
 
@@ -41,6 +45,10 @@ fn test_scriptlet_with_desc(name: &str, tool: &str, code: &str, desc: &str) -> S
         file_path: None,
         command: None,
         alias: None,
+        inputs: Vec::new(),
+        schema: None,
+        extra_blocks: Vec::new(),
+        sequence: false,
     }
 }
 
@@ -123,6 +131,10 @@ fn test_scriptlet_new_fields() {
         file_path: Some("/path/to/file.md#test".to_string()),
         command: Some("test".to_string()),
         alias: None,
+        inputs: Vec::new(),
+        schema: None,
+        extra_blocks: Vec::new(),
+        sequence: false,
     };
 
     assert_eq!(scriptlet.group, Some("My Group".to_string()));
@@ -229,6 +241,60 @@ fn test_parse_scriptlet_none_without_code_block() {
     assert!(scriptlet.is_none());
 }
 
+// ============================================
+// MULTI-BLOCK SCRIPTLET PARSING TESTS
+// ============================================
+
+#[test]
+fn test_extract_code_blocks_two_tools_under_one_heading() {
+    let text = "### Setup\n```bash\nmkdir -p out\n```\n\n### Run\n```python\nprint('hi')\n```";
+    let blocks = extract_code_blocks(text);
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(blocks[0], (Some("Setup".to_string()), "bash".to_string(), "mkdir -p out".to_string()));
+    assert_eq!(blocks[1], (Some("Run".to_string()), "python".to_string(), "print('hi')".to_string()));
+}
+
+#[test]
+fn test_extract_code_blocks_unlabeled_fence_has_no_label() {
+    let text = "```ts\nconst x = 1;\n```";
+    let blocks = extract_code_blocks(text);
+    assert_eq!(blocks, vec![(None, "ts".to_string(), "const x = 1;".to_string())]);
+}
+
+#[test]
+fn test_parse_scriptlet_with_two_labeled_tools_under_one_heading() {
+    let section = "## Build and Run\n\n### Build\n```bash\ncargo build\n```\n\n### Run\n```bash\ncargo run\n```";
+    let scriptlet = parse_scriptlet_section(section, None).unwrap();
+
+    assert_eq!(scriptlet.name, "Build and Run");
+    // The first block becomes the primary code/tool, for single-block consumers.
+    assert_eq!(scriptlet.tool, "bash");
+    assert_eq!(scriptlet.code, "cargo build");
+    assert_eq!(scriptlet.extra_blocks.len(), 1);
+    assert_eq!(scriptlet.extra_blocks[0].label, Some("Run".to_string()));
+    assert_eq!(scriptlet.extra_blocks[0].tool, "bash");
+    assert_eq!(scriptlet.extra_blocks[0].code, "cargo run");
+    assert!(!scriptlet.sequence);
+}
+
+#[test]
+fn test_parse_scriptlet_sequence_metadata_enables_sequence_flag() {
+    let section = "## Deploy\n\n<!-- \nsequence: true\n-->\n\n### Build\n```bash\nmake build\n```\n\n### Deploy\n```bash\nmake deploy\n```";
+    let scriptlet = parse_scriptlet_section(section, None).unwrap();
+
+    assert!(scriptlet.sequence);
+    assert_eq!(scriptlet.extra_blocks.len(), 1);
+}
+
+#[test]
+fn test_parse_scriptlet_single_block_has_no_extra_blocks() {
+    let section = "## Test Snippet\n\n```ts\nconst x = 1;\n```";
+    let scriptlet = parse_scriptlet_section(section, None).unwrap();
+
+    assert!(scriptlet.extra_blocks.is_empty());
+    assert!(!scriptlet.sequence);
+}
+
 #[test]
 fn test_read_scripts_returns_vec() {
     let scripts = read_scripts();
@@ -778,6 +844,7 @@ fn test_fuzzy_search_unified_scripts_first() {
         SearchResult::App(_) => panic!("Script should be first"),
         SearchResult::Window(_) => panic!("Script should be first"),
         SearchResult::Agent(_) => panic!("Script should be first"),
+        SearchResult::RecentFile(_) => panic!("Script should be first"),
         SearchResult::Fallback(_) => panic!("Script should be first"),
     }
 }
@@ -821,6 +888,10 @@ fn test_scriptlet_with_all_metadata() {
         file_path: None,
         command: None,
         alias: None,
+        inputs: Vec::new(),
+        schema: None,
+        extra_blocks: Vec::new(),
+        sequence: false,
     };
 
     assert_eq!(scriptlet.name, "Full Scriptlet");
@@ -1784,10 +1855,65 @@ fn test_fuzzy_search_with_unicode() {
         ..Default::default()
     }]);
 
-    // Should be able to search for the ASCII version
+    // Typing the unaccented ASCII version should match the accented name -
+    // diacritic-insensitive matching via normalize_for_search.
     let results = fuzzy_search_scripts(&scripts, "cafe");
-    // Depending on implementation, may or may not match
-    let _ = results;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].script.name, "café");
+}
+
+#[test]
+fn test_fuzzy_search_diacritic_insensitive_german() {
+    let scripts = wrap_scripts(vec![Script {
+        name: "Zürich Office".to_string(),
+        path: PathBuf::from("/zurich.ts"),
+        ..Default::default()
+    }]);
+
+    let results = fuzzy_search_scripts(&scripts, "zurich");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].script.name, "Zürich Office");
+}
+
+#[test]
+fn test_fuzzy_search_diacritic_insensitive_spanish() {
+    let scripts = wrap_scripts(vec![Script {
+        name: "Jalapeño Recipe".to_string(),
+        path: PathBuf::from("/jalapeno.ts"),
+        ..Default::default()
+    }]);
+
+    let results = fuzzy_search_scripts(&scripts, "jalapeno");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].script.name, "Jalapeño Recipe");
+}
+
+#[test]
+fn test_fuzzy_search_japanese_exact_match() {
+    let scripts = wrap_scripts(vec![Script {
+        name: "スクリプト".to_string(),
+        path: PathBuf::from("/script.ts"),
+        ..Default::default()
+    }]);
+
+    let results = fuzzy_search_scripts(&scripts, "スクリプト");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].script.name, "スクリプト");
+}
+
+#[test]
+fn test_fuzzy_search_name_with_emoji() {
+    let scripts = wrap_scripts(vec![Script {
+        name: "🚀 Launch Script".to_string(),
+        path: PathBuf::from("/launch.ts"),
+        ..Default::default()
+    }]);
+
+    // Matching should still work on the non-emoji portion of the name, and
+    // the emoji itself shouldn't break normalization.
+    let results = fuzzy_search_scripts(&scripts, "launch");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].script.name, "🚀 Launch Script");
 }
 
 #[test]
@@ -2161,6 +2287,7 @@ fn test_unified_search_ties_scripts_first() {
         SearchResult::App(_) => panic!("Expected Script first"),
         SearchResult::Window(_) => panic!("Expected Script first"),
         SearchResult::Agent(_) => panic!("Expected Script first"),
+        SearchResult::RecentFile(_) => panic!("Expected Script first"),
         SearchResult::Fallback(_) => panic!("Expected Script first"),
     }
 }
@@ -2808,8 +2935,11 @@ fn test_get_grouped_results_search_mode_flat_list() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "open",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -2870,8 +3000,11 @@ fn test_get_grouped_results_empty_filter_grouped_view() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -2887,6 +3020,114 @@ fn test_get_grouped_results_empty_filter_grouped_view() {
     assert!(matches!(&grouped[0], GroupedListItem::SectionHeader(s) if s == "SCRIPTS"));
 }
 
+#[test]
+fn test_get_grouped_results_single_kenv_uses_plain_scripts_header() {
+    let scripts = wrap_scripts(vec![
+        Script {
+            name: "alpha".to_string(),
+            path: PathBuf::from("/alpha.ts"),
+            extension: "ts".to_string(),
+            icon: None,
+            description: None,
+            alias: None,
+            shortcut: None,
+            kenv: Some("main".to_string()),
+            ..Default::default()
+        },
+        Script {
+            name: "beta".to_string(),
+            path: PathBuf::from("/beta.ts"),
+            extension: "ts".to_string(),
+            icon: None,
+            description: None,
+            alias: None,
+            shortcut: None,
+            kenv: Some("main".to_string()),
+            ..Default::default()
+        },
+    ]);
+    let scriptlets: Vec<Arc<Scriptlet>> = wrap_scriptlets(vec![]);
+    let frecency_store = FrecencyStore::new();
+
+    let (grouped, _results) = get_grouped_results(
+        &scripts,
+        &scriptlets,
+        &[],
+        &[],
+        &[],
+        &[],
+        &frecency_store,
+        "",
+        None,
+        &SuggestedConfig::default(),
+        &[],
+        None,
+    );
+
+    let headers: Vec<&str> = grouped
+        .iter()
+        .filter_map(|item| match item {
+            GroupedListItem::SectionHeader(s) => Some(s.as_str()),
+            GroupedListItem::Item(_) => None,
+        })
+        .collect();
+    assert_eq!(headers, vec!["SCRIPTS"]);
+}
+
+#[test]
+fn test_get_grouped_results_multiple_kenvs_get_a_header_each() {
+    let scripts = wrap_scripts(vec![
+        Script {
+            name: "alpha".to_string(),
+            path: PathBuf::from("/main/alpha.ts"),
+            extension: "ts".to_string(),
+            icon: None,
+            description: None,
+            alias: None,
+            shortcut: None,
+            kenv: Some("main".to_string()),
+            ..Default::default()
+        },
+        Script {
+            name: "beta".to_string(),
+            path: PathBuf::from("/work/beta.ts"),
+            extension: "ts".to_string(),
+            icon: None,
+            description: None,
+            alias: None,
+            shortcut: None,
+            kenv: Some("work".to_string()),
+            ..Default::default()
+        },
+    ]);
+    let scriptlets: Vec<Arc<Scriptlet>> = wrap_scriptlets(vec![]);
+    let frecency_store = FrecencyStore::new();
+
+    let (grouped, _results) = get_grouped_results(
+        &scripts,
+        &scriptlets,
+        &[],
+        &[],
+        &[],
+        &[],
+        &frecency_store,
+        "",
+        None,
+        &SuggestedConfig::default(),
+        &[],
+        None,
+    );
+
+    let headers: Vec<&str> = grouped
+        .iter()
+        .filter_map(|item| match item {
+            GroupedListItem::SectionHeader(s) => Some(s.as_str()),
+            GroupedListItem::Item(_) => None,
+        })
+        .collect();
+    assert_eq!(headers, vec!["SCRIPTS (MAIN)", "SCRIPTS (WORK)"]);
+}
+
 #[test]
 fn test_get_grouped_results_with_frecency() {
     let scripts = wrap_scripts(vec![
@@ -2935,8 +3176,11 @@ fn test_get_grouped_results_with_frecency() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -3005,8 +3249,11 @@ fn test_get_grouped_results_frecency_script_appears_before_builtins() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -3129,8 +3376,11 @@ fn test_get_grouped_results_builtin_with_frecency_vs_script_frecency() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -3216,8 +3466,11 @@ fn test_get_grouped_results_selection_priority_with_frecency() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -3315,8 +3568,11 @@ fn test_get_grouped_results_no_frecency_items_in_type_sections() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -3367,8 +3623,11 @@ fn test_get_grouped_results_empty_inputs() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -3413,8 +3672,11 @@ fn test_get_grouped_results_items_reference_correct_indices() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -3561,51 +3823,166 @@ fn test_fuzzy_search_scripts_name_match_higher_priority_than_filename() {
 }
 
 #[test]
-fn test_fuzzy_search_scripts_match_indices_for_name() {
-    let scripts = wrap_scripts(vec![Script {
-        name: "openfile".to_string(),
-        path: PathBuf::from("/scripts/test.ts"),
-        extension: "ts".to_string(),
-        icon: None,
-        description: None,
-        alias: None,
-        shortcut: None,
-        ..Default::default()
-    }]);
+fn test_fuzzy_search_scripts_description_match_ranks_above_path_match() {
+    // A query that hits only the description of one script and only the path
+    // of another should rank the description match first - description is a
+    // weightier signal than an incidental path substring.
+    let scripts = wrap_scripts(vec![
+        Script {
+            name: "foo".to_string(),
+            path: PathBuf::from("/scripts/foo.ts"), // Doesn't contain "widget"
+            extension: "ts".to_string(),
+            icon: None,
+            description: Some("Manages your widget inventory".to_string()), // Matches
+            alias: None,
+            shortcut: None,
+            ..Default::default()
+        },
+        Script {
+            name: "bar".to_string(),
+            path: PathBuf::from("/scripts/widget/bar.ts"), // Matches via path
+            extension: "ts".to_string(),
+            icon: None,
+            description: None,
+            alias: None,
+            shortcut: None,
+            ..Default::default()
+        },
+    ]);
 
-    let results = fuzzy_search_scripts(&scripts, "opf");
-    assert_eq!(results.len(), 1);
-    // Match indices are now computed lazily - verify using compute_match_indices_for_result
-    let indices =
-        compute_match_indices_for_result(&SearchResult::Script(results[0].clone()), "opf");
-    // "opf" matches indices 0, 1, 4 in "openfile"
+    let results = fuzzy_search_scripts(&scripts, "widget");
+    assert_eq!(results.len(), 2);
     assert_eq!(
-        indices.name_indices,
-        vec![0, 1, 4],
-        "Should return correct match indices for name"
+        results[0].script.name, "foo",
+        "Description match should rank higher than path-only match"
     );
+    assert_eq!(results[1].script.name, "bar");
 }
 
 #[test]
-fn test_fuzzy_search_scripts_match_indices_for_filename() {
-    let scripts = wrap_scripts(vec![Script {
-        name: "Other Name".to_string(), // Name doesn't match
-        path: PathBuf::from("/scripts/my-test.ts"),
-        extension: "ts".to_string(),
-        icon: None,
-        description: None,
-        alias: None,
-        shortcut: None,
-        ..Default::default()
-    }]);
+fn test_fuzzy_search_scripts_weighted_description_flips_below_path_match() {
+    // With the description weight turned down far enough, a description-only
+    // match should fall below a path-only match - weights must actually move
+    // the ranking, not just the absolute score.
+    let scripts = wrap_scripts(vec![
+        Script {
+            name: "foo".to_string(),
+            path: PathBuf::from("/scripts/foo.ts"),
+            extension: "ts".to_string(),
+            icon: None,
+            description: Some("Manages your widget inventory".to_string()),
+            alias: None,
+            shortcut: None,
+            ..Default::default()
+        },
+        Script {
+            name: "bar".to_string(),
+            path: PathBuf::from("/scripts/widget/bar.ts"),
+            extension: "ts".to_string(),
+            icon: None,
+            description: None,
+            alias: None,
+            shortcut: None,
+            ..Default::default()
+        },
+    ]);
 
-    let results = fuzzy_search_scripts(&scripts, "mts");
-    assert_eq!(results.len(), 1);
-    // Match indices are now computed lazily - verify using compute_match_indices_for_result
-    let indices =
-        compute_match_indices_for_result(&SearchResult::Script(results[0].clone()), "mts");
-    // "mts" matches indices in "my-test.ts": m=0, t=3, s=5
-    assert_eq!(
+    let weights = SearchWeights {
+        name: 1.0,
+        description: 0.2, // 25 * 0.2 = 5, below the unweighted path bonus of 10
+        filename: 1.0,
+    };
+    let results = fuzzy_search_scripts_weighted(&scripts, "widget", &weights);
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].script.name, "bar",
+        "Lowering the description weight should let the path match outrank it"
+    );
+    assert_eq!(results[1].script.name, "foo");
+}
+
+#[test]
+fn test_fuzzy_search_scripts_acronym_query_ranks_initialism_above_subsequence() {
+    // "gca" spells out the initials of "Git Commit All" - that should rank
+    // above "Gallacia", which only matches "gca" as scattered characters.
+    let scripts = wrap_scripts(vec![
+        Script {
+            name: "Gallacia".to_string(),
+            path: PathBuf::from("/scripts/gallacia.ts"),
+            extension: "ts".to_string(),
+            icon: None,
+            description: None,
+            alias: None,
+            shortcut: None,
+            ..Default::default()
+        },
+        Script {
+            name: "Git Commit All".to_string(),
+            path: PathBuf::from("/scripts/git-commit-all.ts"),
+            extension: "ts".to_string(),
+            icon: None,
+            description: None,
+            alias: None,
+            shortcut: None,
+            ..Default::default()
+        },
+    ]);
+
+    let results = fuzzy_search_scripts(&scripts, "gca");
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].script.name, "Git Commit All",
+        "Acronym-aligned match should outrank a scattered-character match"
+    );
+    assert_eq!(results[1].script.name, "Gallacia");
+}
+
+#[test]
+fn test_fuzzy_search_scripts_match_indices_for_name() {
+    let scripts = wrap_scripts(vec![Script {
+        name: "openfile".to_string(),
+        path: PathBuf::from("/scripts/test.ts"),
+        extension: "ts".to_string(),
+        icon: None,
+        description: None,
+        alias: None,
+        shortcut: None,
+        ..Default::default()
+    }]);
+
+    let results = fuzzy_search_scripts(&scripts, "opf");
+    assert_eq!(results.len(), 1);
+    // Match indices are now computed lazily - verify using compute_match_indices_for_result
+    let indices =
+        compute_match_indices_for_result(&SearchResult::Script(results[0].clone()), "opf");
+    // "opf" matches indices 0, 1, 4 in "openfile"
+    assert_eq!(
+        indices.name_indices,
+        vec![0, 1, 4],
+        "Should return correct match indices for name"
+    );
+}
+
+#[test]
+fn test_fuzzy_search_scripts_match_indices_for_filename() {
+    let scripts = wrap_scripts(vec![Script {
+        name: "Other Name".to_string(), // Name doesn't match
+        path: PathBuf::from("/scripts/my-test.ts"),
+        extension: "ts".to_string(),
+        icon: None,
+        description: None,
+        alias: None,
+        shortcut: None,
+        ..Default::default()
+    }]);
+
+    let results = fuzzy_search_scripts(&scripts, "mts");
+    assert_eq!(results.len(), 1);
+    // Match indices are now computed lazily - verify using compute_match_indices_for_result
+    let indices =
+        compute_match_indices_for_result(&SearchResult::Script(results[0].clone()), "mts");
+    // "mts" matches indices in "my-test.ts": m=0, t=3, s=5
+    assert_eq!(
         indices.filename_indices,
         vec![0, 3, 5],
         "Should return correct match indices for filename when name doesn't match"
@@ -3627,6 +4004,10 @@ fn test_fuzzy_search_scriptlets_by_file_path() {
             file_path: Some("/path/to/urls.md#open-github".to_string()),
             command: Some("open-github".to_string()),
             alias: None,
+            inputs: Vec::new(),
+            schema: None,
+            extra_blocks: Vec::new(),
+            sequence: false,
         },
         Scriptlet {
             name: "Copy Text".to_string(),
@@ -3639,6 +4020,10 @@ fn test_fuzzy_search_scriptlets_by_file_path() {
             file_path: Some("/path/to/clipboard.md#copy-text".to_string()),
             command: Some("copy-text".to_string()),
             alias: None,
+            inputs: Vec::new(),
+            schema: None,
+            extra_blocks: Vec::new(),
+            sequence: false,
         },
     ]);
 
@@ -3661,6 +4046,10 @@ fn test_fuzzy_search_scriptlets_by_anchor() {
             file_path: Some("/path/to/file.md#open-github".to_string()),
             command: Some("open-github".to_string()),
             alias: None,
+            inputs: Vec::new(),
+            schema: None,
+            extra_blocks: Vec::new(),
+            sequence: false,
         },
         Scriptlet {
             name: "Close Tab".to_string(),
@@ -3673,6 +4062,10 @@ fn test_fuzzy_search_scriptlets_by_anchor() {
             file_path: Some("/path/to/file.md#close-tab".to_string()),
             command: Some("close-tab".to_string()),
             alias: None,
+            inputs: Vec::new(),
+            schema: None,
+            extra_blocks: Vec::new(),
+            sequence: false,
         },
     ]);
 
@@ -3695,6 +4088,10 @@ fn test_fuzzy_search_scriptlets_display_file_path() {
         file_path: Some("/home/user/.scriptkit/scriptlets/urls.md#test-slug".to_string()),
         command: Some("test-slug".to_string()),
         alias: None,
+        inputs: Vec::new(),
+        schema: None,
+        extra_blocks: Vec::new(),
+        sequence: false,
     }]);
 
     let results = fuzzy_search_scriptlets(&scriptlets, "");
@@ -3719,6 +4116,10 @@ fn test_fuzzy_search_scriptlets_match_indices() {
         file_path: Some("/path/urls.md#test".to_string()),
         command: None,
         alias: None,
+        inputs: Vec::new(),
+        schema: None,
+        extra_blocks: Vec::new(),
+        sequence: false,
     }]);
 
     let results = fuzzy_search_scriptlets(&scriptlets, "url");
@@ -4111,6 +4512,10 @@ fn bench_get_grouped_results_repeated_calls() {
                 group: None,
                 command: None,
                 alias: None,
+                inputs: Vec::new(),
+                schema: None,
+                extra_blocks: Vec::new(),
+                sequence: false,
             })
         })
         .collect();
@@ -4144,8 +4549,11 @@ fn bench_get_grouped_results_repeated_calls() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -4159,8 +4567,11 @@ fn bench_get_grouped_results_repeated_calls() {
             &scriptlets,
             &builtins,
             &apps,
+            &[],
+            &[],
             &frecency_store,
             "",
+            None,
             &SuggestedConfig::default(),
             &[],
             None,
@@ -4176,8 +4587,11 @@ fn bench_get_grouped_results_repeated_calls() {
             &scriptlets,
             &builtins,
             &apps,
+            &[],
+            &[],
             &frecency_store,
             "scr",
+            None,
             &SuggestedConfig::default(),
             &[],
             None,
@@ -4454,8 +4868,11 @@ fn test_get_grouped_results_respects_frecency_ordering() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -4473,8 +4890,11 @@ fn test_get_grouped_results_respects_frecency_ordering() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -4546,8 +4966,11 @@ fn test_get_grouped_results_updates_after_frecency_change() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -4577,8 +5000,11 @@ fn test_get_grouped_results_updates_after_frecency_change() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         "",
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -4651,8 +5077,11 @@ fn test_frecency_cache_invalidation_required() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         filter_text,
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -4678,8 +5107,11 @@ fn test_frecency_cache_invalidation_required() {
             &scriptlets,
             &builtins,
             &apps,
+            &[],
+            &[],
             &frecency_store,
             filter_text,
+            None,
             &SuggestedConfig::default(),
             &[],
             None,
@@ -4694,8 +5126,11 @@ fn test_frecency_cache_invalidation_required() {
             &scriptlets,
             &builtins,
             &apps,
+            &[],
+            &[],
             &frecency_store,
             filter_text,
+            None,
             &SuggestedConfig::default(),
             &[],
             None,
@@ -4712,8 +5147,11 @@ fn test_frecency_cache_invalidation_required() {
         &scriptlets,
         &builtins,
         &apps,
+        &[],
+        &[],
         &frecency_store,
         filter_text,
+        None,
         &SuggestedConfig::default(),
         &[],
         None,
@@ -4854,8 +5292,11 @@ fn test_frecency_change_invalidates_cache() {
                 scriptlets,
                 builtins,
                 apps,
+                &[],
+                &[],
                 frecency_store,
                 filter_text,
+                None,
                 &SuggestedConfig::default(),
                 &[],
                 None,
@@ -5082,3 +5523,382 @@ fn test_nucleo_score_case_insensitive() {
         "nucleo with Smart case matching should match lowercase 'hello' in uppercase 'HELLO WORLD'"
     );
 }
+
+// ============================================
+// SEARCH SCOPE SIGIL TESTS
+// ============================================
+
+#[test]
+fn test_parse_search_scope_no_sigil() {
+    let (scope, rest) = parse_search_scope("deploy");
+    assert_eq!(scope, None);
+    assert_eq!(rest, "deploy");
+}
+
+#[test]
+fn test_parse_search_scope_empty_string() {
+    let (scope, rest) = parse_search_scope("");
+    assert_eq!(scope, None);
+    assert_eq!(rest, "");
+}
+
+#[test]
+fn test_parse_search_scope_apps_with_query() {
+    let (scope, rest) = parse_search_scope("@safari");
+    assert_eq!(scope, Some(SearchScope::Apps));
+    assert_eq!(rest, "safari");
+}
+
+#[test]
+fn test_parse_search_scope_apps_bare_sigil() {
+    let (scope, rest) = parse_search_scope("@");
+    assert_eq!(scope, Some(SearchScope::Apps));
+    assert_eq!(rest, "");
+}
+
+#[test]
+fn test_parse_search_scope_scriptlets() {
+    let (scope, rest) = parse_search_scope(">deploy");
+    assert_eq!(scope, Some(SearchScope::Scriptlets));
+    assert_eq!(rest, "deploy");
+}
+
+#[test]
+fn test_parse_search_scope_builtins() {
+    let (scope, rest) = parse_search_scope("?settings");
+    assert_eq!(scope, Some(SearchScope::Builtins));
+    assert_eq!(rest, "settings");
+}
+
+#[test]
+fn test_parse_search_scope_windows() {
+    let (scope, rest) = parse_search_scope("#mail");
+    assert_eq!(scope, Some(SearchScope::Windows));
+    assert_eq!(rest, "mail");
+}
+
+#[test]
+fn test_parse_search_scope_unrecognized_sigil_is_not_scoped() {
+    // `/` is not a recognized sigil in this build - no file-search built-in to scope to
+    let (scope, rest) = parse_search_scope("/etc/hosts");
+    assert_eq!(scope, None);
+    assert_eq!(rest, "/etc/hosts");
+}
+
+// ============================================
+// SCOPED GROUPING TESTS
+// ============================================
+
+fn scoped_grouping_fixture() -> (
+    Vec<Arc<Script>>,
+    Vec<Arc<Scriptlet>>,
+    Vec<BuiltInEntry>,
+    Vec<AppInfo>,
+    crate::frecency::FrecencyStore,
+) {
+    let scripts = wrap_scripts(vec![Script {
+        name: "deploy-script".to_string(),
+        path: PathBuf::from("/deploy-script.ts"),
+        extension: "ts".to_string(),
+        icon: None,
+        description: None,
+        alias: None,
+        shortcut: None,
+        ..Default::default()
+    }]);
+    let scriptlets = wrap_scriptlets(vec![test_scriptlet(
+        "deploy-scriptlet",
+        "bash",
+        "echo deploy",
+    )]);
+    let builtins = vec![BuiltInEntry {
+        id: "deploy-builtin".to_string(),
+        name: "deploy-builtin".to_string(),
+        description: "A built-in".to_string(),
+        keywords: vec![],
+        feature: crate::builtins::BuiltInFeature::ClipboardHistory,
+        icon: None,
+        group: crate::builtins::BuiltInGroup::Core,
+    }];
+    let apps = vec![AppInfo {
+        name: "Deploy App".to_string(),
+        path: PathBuf::from("/Applications/DeployApp.app"),
+        bundle_id: Some("com.test.deployapp".to_string()),
+        icon: None,
+    }];
+    (
+        scripts,
+        scriptlets,
+        builtins,
+        apps,
+        crate::frecency::FrecencyStore::new(),
+    )
+}
+
+#[test]
+fn test_get_grouped_results_apps_scope_excludes_other_sources() {
+    let (scripts, scriptlets, builtins, apps, frecency_store) = scoped_grouping_fixture();
+
+    let (_grouped, results) = get_grouped_results(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &[],
+        &[],
+        &frecency_store,
+        "deploy",
+        Some(SearchScope::Apps),
+        &SuggestedConfig::default(),
+        &[],
+        None,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert!(matches!(&results[0], SearchResult::App(_)));
+}
+
+#[test]
+fn test_get_grouped_results_scriptlets_scope_excludes_other_sources() {
+    let (scripts, scriptlets, builtins, apps, frecency_store) = scoped_grouping_fixture();
+
+    let (_grouped, results) = get_grouped_results(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &[],
+        &[],
+        &frecency_store,
+        "deploy",
+        Some(SearchScope::Scriptlets),
+        &SuggestedConfig::default(),
+        &[],
+        None,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert!(matches!(&results[0], SearchResult::Scriptlet(_)));
+}
+
+#[test]
+fn test_get_grouped_results_builtins_scope_excludes_other_sources() {
+    let (scripts, scriptlets, builtins, apps, frecency_store) = scoped_grouping_fixture();
+
+    let (_grouped, results) = get_grouped_results(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &[],
+        &[],
+        &frecency_store,
+        "deploy",
+        Some(SearchScope::Builtins),
+        &SuggestedConfig::default(),
+        &[],
+        None,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert!(matches!(&results[0], SearchResult::BuiltIn(_)));
+}
+
+#[test]
+fn test_get_grouped_results_windows_scope_excludes_other_sources() {
+    let (scripts, scriptlets, builtins, apps, frecency_store) = scoped_grouping_fixture();
+    let windows: Vec<crate::window_control::WindowInfo> = vec![];
+
+    let (_grouped, results) = get_grouped_results(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &windows,
+        &[],
+        &frecency_store,
+        "deploy",
+        Some(SearchScope::Windows),
+        &SuggestedConfig::default(),
+        &[],
+        None,
+    );
+
+    // No windows were provided, so the Windows scope should return nothing
+    // rather than falling back to scripts/scriptlets/builtins/apps.
+    assert!(results.is_empty());
+}
+
+#[test]
+fn test_get_grouped_results_apps_scope_bare_sigil_returns_all_apps() {
+    let (scripts, scriptlets, builtins, apps, frecency_store) = scoped_grouping_fixture();
+
+    // An empty query (bare "@" sigil already stripped) should still enter
+    // search mode and return every app, not fall back to the grouped view.
+    let (_grouped, results) = get_grouped_results(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &[],
+        &[],
+        &frecency_store,
+        "",
+        Some(SearchScope::Apps),
+        &SuggestedConfig::default(),
+        &[],
+        None,
+    );
+
+    assert_eq!(results.len(), 1);
+    assert!(matches!(&results[0], SearchResult::App(_)));
+}
+
+#[test]
+fn test_get_grouped_results_no_scope_searches_everything() {
+    let (scripts, scriptlets, builtins, apps, frecency_store) = scoped_grouping_fixture();
+
+    let (_grouped, results) = get_grouped_results(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &[],
+        &[],
+        &frecency_store,
+        "deploy",
+        None,
+        &SuggestedConfig::default(),
+        &[],
+        None,
+    );
+
+    assert_eq!(results.len(), 4);
+}
+
+#[test]
+fn test_filter_collapsed_sections_hides_items_keeps_header() {
+    let rows = vec![
+        GroupedListItem::SectionHeader("SCRIPTS".to_string()),
+        GroupedListItem::Item(0),
+        GroupedListItem::Item(1),
+        GroupedListItem::SectionHeader("APPS".to_string()),
+        GroupedListItem::Item(2),
+    ];
+
+    let (filtered, counts) = filter_collapsed_sections(rows, |label| label == "SCRIPTS");
+
+    // SCRIPTS header stays (so it can still be clicked to expand), its two
+    // items are hidden, and APPS is untouched.
+    assert_eq!(
+        filtered,
+        vec![
+            GroupedListItem::SectionHeader("SCRIPTS".to_string()),
+            GroupedListItem::SectionHeader("APPS".to_string()),
+            GroupedListItem::Item(2),
+        ]
+    );
+
+    // Counts reflect the full, pre-collapse item count for every section,
+    // including the collapsed one.
+    assert_eq!(counts.get("SCRIPTS"), Some(&2));
+    assert_eq!(counts.get("APPS"), Some(&1));
+}
+
+#[test]
+fn test_filter_collapsed_sections_no_sections_collapsed_is_noop() {
+    let rows = vec![
+        GroupedListItem::SectionHeader("SCRIPTS".to_string()),
+        GroupedListItem::Item(0),
+        GroupedListItem::Item(1),
+    ];
+    let rows_copy = rows.clone();
+
+    let (filtered, counts) = filter_collapsed_sections(rows, |_label| false);
+
+    assert_eq!(filtered, rows_copy);
+    assert_eq!(counts.get("SCRIPTS"), Some(&2));
+}
+
+#[test]
+fn test_filter_collapsed_sections_then_get_grouped_results_index_mapping() {
+    // When a section is collapsed, the indices left in the flattened
+    // GroupedListItem::Item entries must still point at the right row in
+    // the (untouched) flat SearchResult array - collapsing only removes
+    // rows from the grouped view, it never renumbers `results`.
+    let scripts = wrap_scripts(vec![
+        Script {
+            name: "alpha".to_string(),
+            path: PathBuf::from("/alpha.ts"),
+            extension: "ts".to_string(),
+            icon: None,
+            description: None,
+            alias: None,
+            shortcut: None,
+            ..Default::default()
+        },
+        Script {
+            name: "beta".to_string(),
+            path: PathBuf::from("/beta.ts"),
+            extension: "ts".to_string(),
+            icon: None,
+            description: None,
+            alias: None,
+            shortcut: None,
+            ..Default::default()
+        },
+    ]);
+    let scriptlets: Vec<Arc<Scriptlet>> = wrap_scriptlets(vec![]);
+    let builtins: Vec<BuiltInEntry> = vec![];
+    let apps: Vec<AppInfo> = vec![];
+    let frecency_store = FrecencyStore::new();
+
+    let (grouped, results) = get_grouped_results(
+        &scripts,
+        &scriptlets,
+        &builtins,
+        &apps,
+        &[],
+        &[],
+        &frecency_store,
+        "",
+        None,
+        &SuggestedConfig::default(),
+        &[],
+        None,
+    );
+
+    let (filtered, _counts) = filter_collapsed_sections(grouped, |label| label == "SCRIPTS");
+
+    // Every remaining Item still indexes a real result, and the underlying
+    // name matches what it did before collapsing - collapsing never
+    // shuffles `results`, it only hides rows from `filtered`.
+    for item in &filtered {
+        if let GroupedListItem::Item(idx) = item {
+            assert!(results.get(*idx).is_some());
+        }
+    }
+
+    // SCRIPTS is the only section here, so collapsing it hides every item,
+    // leaving just its header.
+    assert_eq!(
+        filtered,
+        vec![GroupedListItem::SectionHeader("SCRIPTS".to_string())]
+    );
+}
+
+#[test]
+fn test_filter_collapsed_sections_search_mode_ignores_collapse() {
+    // Search mode (non-empty filter text) must never consult collapsed
+    // state at all - callers are expected to skip filter_collapsed_sections
+    // entirely while a filter is active, so a flat search-mode result with
+    // no headers should pass through filter_collapsed_sections unchanged
+    // even if every "section" name would otherwise be collapsed.
+    let rows = vec![GroupedListItem::Item(0), GroupedListItem::Item(1)];
+    let rows_copy = rows.clone();
+
+    let (filtered, counts) = filter_collapsed_sections(rows, |_label| true);
+
+    assert_eq!(filtered, rows_copy);
+    assert!(counts.is_empty());
+}
@@ -0,0 +1,176 @@
+//! Dev session-state persistence (opt-in via `restore_session` config flag)
+//!
+//! During development the app restarts constantly (`cargo run`), which
+//! normally loses the filter text, selection, and view toggles every time.
+//! This module persists a small snapshot of that state to
+//! `~/.sk/kit/session-state.json` and restores it in `ScriptListApp::new`.
+//!
+//! Prompt/session state (whatever an in-flight script is showing) is never
+//! part of this snapshot - only the idle script-list view. Corrupt or
+//! version-mismatched snapshots are ignored rather than failing startup.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::logging;
+
+/// Current on-disk format version. Bump this if the shape of
+/// [`SessionStateFile`] changes in a way that isn't backwards compatible;
+/// older/newer snapshots are then ignored instead of misread.
+pub const SESSION_STATE_VERSION: u32 = 1;
+
+/// The full persisted session snapshot
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct SessionStateFile {
+    #[serde(default = "default_version")]
+    pub version: u32,
+    #[serde(default)]
+    pub filter_text: String,
+    pub selected_frecency_path: Option<String>,
+    pub design_variant: Option<u8>,
+    #[serde(default)]
+    pub show_logs: bool,
+    #[serde(default)]
+    pub is_pinned: bool,
+}
+
+fn default_version() -> u32 {
+    SESSION_STATE_VERSION
+}
+
+/// Get the path to the session state file: ~/.sk/kit/session-state.json
+pub fn get_state_file_path() -> PathBuf {
+    let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    home.join(".sk").join("kit").join("session-state.json")
+}
+
+/// Load the session snapshot, ignoring (and logging) anything unreadable,
+/// unparseable, or from an incompatible version rather than failing startup.
+pub fn load_state_file() -> Option<SessionStateFile> {
+    let path = get_state_file_path();
+    if !path.exists() {
+        return None;
+    }
+    let state: SessionStateFile = match fs::read_to_string(&path) {
+        Ok(contents) => match serde_json::from_str(&contents) {
+            Ok(state) => state,
+            Err(e) => {
+                logging::log(
+                    "SESSION_STATE",
+                    &format!("Failed to parse session-state.json: {}", e),
+                );
+                return None;
+            }
+        },
+        Err(e) => {
+            logging::log(
+                "SESSION_STATE",
+                &format!("Failed to read session-state.json: {}", e),
+            );
+            return None;
+        }
+    };
+    if state.version != SESSION_STATE_VERSION {
+        logging::log(
+            "SESSION_STATE",
+            &format!(
+                "Ignoring session-state.json from incompatible version {} (expected {})",
+                state.version, SESSION_STATE_VERSION
+            ),
+        );
+        return None;
+    }
+    Some(state)
+}
+
+/// Save the session snapshot (atomic write: temp file then rename).
+pub fn save_state_file(state: &SessionStateFile) -> bool {
+    let path = get_state_file_path();
+    if let Some(parent) = path.parent() {
+        if let Err(e) = fs::create_dir_all(parent) {
+            logging::log(
+                "SESSION_STATE",
+                &format!("Failed to create directory: {}", e),
+            );
+            return false;
+        }
+    }
+    let json = match serde_json::to_string_pretty(state) {
+        Ok(j) => j,
+        Err(e) => {
+            logging::log("SESSION_STATE", &format!("Failed to serialize: {}", e));
+            return false;
+        }
+    };
+    let tmp_path = path.with_extension("json.tmp");
+    if let Err(e) = fs::write(&tmp_path, &json) {
+        logging::log(
+            "SESSION_STATE",
+            &format!("Failed to write temp file: {}", e),
+        );
+        return false;
+    }
+    if let Err(e) = fs::rename(&tmp_path, &path) {
+        logging::log(
+            "SESSION_STATE",
+            &format!("Failed to rename temp file: {}", e),
+        );
+        let _ = fs::remove_file(&tmp_path);
+        return false;
+    }
+    true
+}
+
+/// Re-resolve a previously selected script by its frecency path (the same
+/// path used to key [`crate::frecency::FrecencyStore`] entries) against the
+/// current list of `(selection_index, path)` pairs.
+///
+/// Returns `None` if there was nothing to resolve or the script no longer
+/// exists - callers should then fall back to their own default selection
+/// rather than treating this as an error, since index shifts or a deleted
+/// script are expected across restarts.
+pub fn resolve_selected_index(
+    selected_frecency_path: Option<&str>,
+    current_script_paths: &[(usize, String)],
+) -> Option<usize> {
+    let target = selected_frecency_path?;
+    current_script_paths
+        .iter()
+        .find(|(_, path)| path == target)
+        .map(|(idx, _)| *idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_selected_index_finds_match() {
+        let paths = vec![
+            (0, "/scripts/a.ts".to_string()),
+            (2, "/scripts/b.ts".to_string()),
+        ];
+        assert_eq!(
+            resolve_selected_index(Some("/scripts/b.ts"), &paths),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_resolve_selected_index_missing_script_returns_none() {
+        let paths = vec![(0, "/scripts/a.ts".to_string())];
+        assert_eq!(
+            resolve_selected_index(Some("/scripts/deleted.ts"), &paths),
+            None
+        );
+    }
+
+    #[test]
+    fn test_resolve_selected_index_no_prior_selection_returns_none() {
+        let paths = vec![(0, "/scripts/a.ts".to_string())];
+        assert_eq!(resolve_selected_index(None, &paths), None);
+    }
+}
+
+// Round-trip/persistence tests are in src/session_state_persistence_tests.rs
@@ -0,0 +1,64 @@
+//! Tests for session_state persistence module
+
+#[cfg(test)]
+mod tests {
+    use crate::session_state::*;
+    use std::env;
+    use tempfile::TempDir;
+
+    fn with_temp_state_dir<F: FnOnce()>(f: F) {
+        let temp_dir = TempDir::new().unwrap();
+        let old_home = env::var("HOME").ok();
+        env::set_var("HOME", temp_dir.path());
+        let kit_dir = temp_dir.path().join(".sk").join("kit");
+        std::fs::create_dir_all(&kit_dir).unwrap();
+        f();
+        if let Some(home) = old_home {
+            env::set_var("HOME", home);
+        }
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        with_temp_state_dir(|| {
+            let state = SessionStateFile {
+                version: SESSION_STATE_VERSION,
+                filter_text: "editor".to_string(),
+                selected_frecency_path: Some("/scripts/hello.ts".to_string()),
+                design_variant: Some(4),
+                show_logs: true,
+                is_pinned: true,
+            };
+            assert!(save_state_file(&state));
+            let loaded = load_state_file().expect("should load the snapshot just saved");
+            assert_eq!(loaded, state);
+        });
+    }
+
+    #[test]
+    fn test_load_missing_file_returns_none() {
+        with_temp_state_dir(|| {
+            assert!(load_state_file().is_none());
+        });
+    }
+
+    #[test]
+    fn test_load_corrupt_file_returns_none() {
+        with_temp_state_dir(|| {
+            let path = get_state_file_path();
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, "not valid json {{{").unwrap();
+            assert!(load_state_file().is_none());
+        });
+    }
+
+    #[test]
+    fn test_load_version_mismatch_returns_none() {
+        with_temp_state_dir(|| {
+            let path = get_state_file_path();
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, r#"{"version":999,"filter_text":"x"}"#).unwrap();
+            assert!(load_state_file().is_none());
+        });
+    }
+}
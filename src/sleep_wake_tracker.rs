@@ -0,0 +1,140 @@
+//! Sleep/wake tracker for scheduler catch-up
+//!
+//! Cron-scheduled scripts only fire while the app's scheduler loop is polling
+//! (see `scheduler.rs`); a laptop going to sleep silently pauses that loop.
+//! This module watches for `NSWorkspaceDidWakeNotification` and re-runs the
+//! same missed-occurrence catch-up check that startup does, via
+//! `Scheduler::recheck_missed_runs` (see request synth-2130).
+//!
+//! ## Architecture
+//!
+//! Modeled on `frontmost_app_tracker`'s NSWorkspace observer: a background
+//! thread registers an observer class and runs its own `NSRunLoop` forever.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use crate::sleep_wake_tracker::start_tracking;
+//!
+//! // Call once at app startup, after the scheduler is created.
+//! start_tracking(scheduler.clone());
+//! ```
+
+use crate::logging;
+use crate::scheduler::Scheduler;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Whether tracking has been started
+static TRACKING_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Start watching for system sleep/wake and re-checking missed schedule
+/// occurrences on wake.
+///
+/// Safe to call multiple times - subsequent calls are no-ops.
+#[cfg(target_os = "macos")]
+pub fn start_tracking(scheduler: Arc<Mutex<Scheduler>>) {
+    if TRACKING_STARTED.swap(true, Ordering::SeqCst) {
+        // Already started
+        return;
+    }
+
+    logging::log("APP", "Starting sleep/wake tracker for scheduler catch-up");
+
+    std::thread::spawn(move || {
+        setup_workspace_observer(scheduler);
+    });
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn start_tracking(_scheduler: Arc<Mutex<Scheduler>>) {
+    // No-op on non-macOS platforms
+    logging::log("APP", "Sleep/wake tracking not available on this platform");
+}
+
+/// Set up the NSWorkspace notification observer for wake events.
+#[cfg(target_os = "macos")]
+fn setup_workspace_observer(scheduler: Arc<Mutex<Scheduler>>) {
+    use objc::declare::ClassDecl;
+    use objc::runtime::{Class, Object, Sel};
+    use objc::{msg_send, sel, sel_impl};
+    use std::os::raw::c_void;
+
+    // The extern "C" callback can't capture state, so stash the scheduler
+    // in a thread-local it can read back, since this thread only ever
+    // handles wake notifications for this one scheduler.
+    thread_local! {
+        static SCHEDULER: std::cell::RefCell<Option<Arc<Mutex<Scheduler>>>> = const { std::cell::RefCell::new(None) };
+    }
+    SCHEDULER.with(|cell| *cell.borrow_mut() = Some(scheduler));
+
+    unsafe {
+        let superclass = Class::get("NSObject").unwrap();
+
+        let observer_class = if let Some(existing) = Class::get("ScriptKitSleepWakeObserver") {
+            logging::log("APP", "Using existing ScriptKitSleepWakeObserver class");
+            existing
+        } else {
+            let mut decl = match ClassDecl::new("ScriptKitSleepWakeObserver", superclass) {
+                Some(d) => d,
+                None => {
+                    logging::log("ERROR", "Failed to create ScriptKitSleepWakeObserver class");
+                    return;
+                }
+            };
+
+            // SAFETY: invoked from Objective-C on this thread's NSRunLoop.
+            // catch_unwind prevents panics from unwinding across the FFI
+            // boundary, which is undefined behavior.
+            extern "C" fn handle_wake(_this: &Object, _sel: Sel, _notification: *mut Object) {
+                let _ = std::panic::catch_unwind(|| {
+                    logging::log("APP", "System woke from sleep, re-checking missed schedules");
+                    SCHEDULER.with(|cell| {
+                        if let Some(scheduler) = cell.borrow().as_ref() {
+                            scheduler.lock().unwrap().recheck_missed_runs();
+                        }
+                    });
+                });
+            }
+
+            decl.add_method(
+                sel!(handleWake:),
+                handle_wake as extern "C" fn(&Object, Sel, *mut Object),
+            );
+
+            decl.register()
+        };
+
+        let observer: *mut Object = msg_send![observer_class, alloc];
+        let observer: *mut Object = msg_send![observer, init];
+
+        let workspace_class = Class::get("NSWorkspace").unwrap();
+        let workspace: *mut Object = msg_send![workspace_class, sharedWorkspace];
+        let notification_center: *mut Object = msg_send![workspace, notificationCenter];
+
+        let notification_name = objc_nsstring("NSWorkspaceDidWakeNotification");
+
+        let _: () = msg_send![
+            notification_center,
+            addObserver: observer
+            selector: sel!(handleWake:)
+            name: notification_name
+            object: std::ptr::null::<c_void>()
+        ];
+
+        logging::log("APP", "NSWorkspace observer registered for system wake");
+
+        let run_loop: *mut Object = msg_send![Class::get("NSRunLoop").unwrap(), currentRunLoop];
+        let _: () = msg_send![run_loop, run];
+    }
+}
+
+#[cfg(target_os = "macos")]
+unsafe fn objc_nsstring(s: &str) -> *mut objc::runtime::Object {
+    use objc::runtime::Class;
+    use objc::{msg_send, sel, sel_impl};
+
+    let nsstring_class = Class::get("NSString").unwrap();
+    let cstr = std::ffi::CString::new(s).unwrap();
+    msg_send![nsstring_class, stringWithUTF8String: cstr.as_ptr()]
+}
@@ -4,7 +4,8 @@
 //!
 //! Supported syntax:
 //! - `$1`, `$2`, `$3` - Simple tabstops (numbered positions)
-//! - `${1:default}` - Tabstops with placeholder text
+//! - `${1:default}` - Tabstops with placeholder text (may themselves contain
+//!   nested tabstops, e.g. `${1:before ${2:inner} after}`)
 //! - `${1|a,b,c|}` - Choice tabstops (dropdown options)
 //! - `$0` - Final cursor position
 //! - `$$` - Escaped literal dollar sign
@@ -64,9 +65,30 @@ impl ParsedSnippet {
     /// assert_eq!(snippet.tabstops.len(), 1);
     /// ```
     pub fn parse(template: &str) -> Self {
+        let (parts, text) = Self::parse_parts(template, 0);
+
+        // Build tabstop info, merging same indices
+        let tabstops = Self::build_tabstop_info(&parts);
+
+        Self {
+            parts,
+            text,
+            tabstops,
+        }
+    }
+
+    /// Tokenize `template` into parts and its expanded text, starting the
+    /// char-index bookkeeping at `char_offset`.
+    ///
+    /// `char_offset` lets this be called recursively on a placeholder's
+    /// default text (e.g. the `before ${2:inner} after` in
+    /// `${1:before ${2:inner} after}`) so nested tabstops end up with
+    /// ranges relative to the *outer* template rather than the nested
+    /// snippet alone.
+    fn parse_parts(template: &str, char_offset: usize) -> (Vec<SnippetPart>, String) {
         let mut parts = Vec::new();
         let mut text = String::new();
-        let mut char_count: usize = 0; // Track char count for char-based indices
+        let mut char_count: usize = char_offset;
         let mut chars = template.chars().peekable();
         let mut current_text = String::new();
 
@@ -107,6 +129,10 @@ impl ParsedSnippet {
                             choices: tabstop.choices,
                             range: tabstop.range,
                         });
+                        // Nested tabstops discovered inside this placeholder's
+                        // default text (already offset-adjusted) get their own
+                        // entries so they can still be tabbed to independently.
+                        parts.extend(tabstop.nested);
                     }
                     // Simple tabstop: $N
                     Some(d) if d.is_ascii_digit() => {
@@ -153,14 +179,7 @@ impl ParsedSnippet {
             parts.push(SnippetPart::Text(current_text));
         }
 
-        // Build tabstop info, merging same indices
-        let tabstops = Self::build_tabstop_info(&parts);
-
-        Self {
-            parts,
-            text,
-            tabstops,
-        }
+        (parts, text)
     }
 
     /// Parse a braced tabstop: `{1}`, `{1:default}`, or `{1|a,b,c|}`
@@ -186,10 +205,27 @@ impl ParsedSnippet {
 
         // Check what follows the index
         match chars.peek() {
-            // Placeholder: ${1:text}
+            // Placeholder: ${1:text}, possibly with nested tabstops like
+            // ${1:before ${2:inner} after}
             Some(':') => {
                 chars.next(); // consume ':'
-                let placeholder = Self::parse_until_close_brace(chars);
+                let raw_placeholder = Self::parse_until_close_brace(chars);
+
+                let (placeholder, nested) = if raw_placeholder.contains('$') {
+                    let (nested_parts, resolved) =
+                        Self::parse_parts(&raw_placeholder, char_offset);
+                    // Only the tabstops matter here - the surrounding text is
+                    // already folded into `resolved`, which becomes this
+                    // tabstop's placeholder/default text.
+                    let nested_tabstops = nested_parts
+                        .into_iter()
+                        .filter(|p| matches!(p, SnippetPart::Tabstop { .. }))
+                        .collect();
+                    (resolved, nested_tabstops)
+                } else {
+                    (raw_placeholder, Vec::new())
+                };
+
                 // Use char count, not byte length
                 let placeholder_char_len = placeholder.chars().count();
                 let range = (char_offset, char_offset + placeholder_char_len);
@@ -198,6 +234,7 @@ impl ParsedSnippet {
                     placeholder: Some(placeholder),
                     choices: None,
                     range,
+                    nested,
                 }
             }
             // Choices: ${1|a,b,c|}
@@ -212,6 +249,7 @@ impl ParsedSnippet {
                     placeholder: None,
                     choices: Some(choices),
                     range,
+                    nested: Vec::new(),
                 }
             }
             // Simple: ${1}
@@ -222,6 +260,7 @@ impl ParsedSnippet {
                     placeholder: None,
                     choices: None,
                     range: (char_offset, char_offset),
+                    nested: Vec::new(),
                 }
             }
             // Unexpected - consume until }
@@ -232,6 +271,7 @@ impl ParsedSnippet {
                     placeholder: None,
                     choices: None,
                     range: (char_offset, char_offset),
+                    nested: Vec::new(),
                 }
             }
         }
@@ -457,6 +497,10 @@ struct TabstopParseResult {
     placeholder: Option<String>,
     choices: Option<Vec<String>>,
     range: (usize, usize),
+    /// Tabstops found nested inside this one's placeholder default text,
+    /// e.g. the `$2` in `${1:before $2 after}`. Ranges are already
+    /// relative to the outer template.
+    nested: Vec<SnippetPart>,
 }
 
 #[cfg(test)]
@@ -940,4 +984,75 @@ export default function ${1:Component}() {
         assert_eq!(order, vec![1, 2]); // No 0
         assert_eq!(snippet.get_tabstop(0), None);
     }
+
+    // =========================================================================
+    // Nested placeholder tests
+    // =========================================================================
+
+    #[test]
+    fn test_parse_nested_placeholder() {
+        // ${1:before ${2:inner} after} - tabstop 2 is nested in tabstop 1's default
+        let snippet = ParsedSnippet::parse("${1:before ${2:inner} after}");
+
+        assert_eq!(snippet.text, "before inner after");
+
+        let t1 = snippet.get_tabstop(1).unwrap();
+        assert_eq!(t1.placeholder.as_deref(), Some("before inner after"));
+
+        let t2 = snippet.get_tabstop(2).unwrap();
+        assert_eq!(t2.placeholder.as_deref(), Some("inner"));
+
+        // Nested tabstop should still be reachable in navigation order
+        assert_eq!(snippet.tabstop_order(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_nested_placeholder_ranges() {
+        // Ranges are relative to the outer, fully expanded text
+        let snippet = ParsedSnippet::parse("${1:before ${2:inner} after}");
+
+        let t1 = snippet.get_tabstop(1).unwrap();
+        assert_eq!(t1.ranges, vec![(0, 19)]); // "before inner after"
+
+        let t2 = snippet.get_tabstop(2).unwrap();
+        assert_eq!(t2.ranges, vec![(7, 12)]); // "inner" starts after "before "
+    }
+
+    #[test]
+    fn test_nested_placeholder_with_simple_tabstop() {
+        // A simple $N tabstop nested inside a placeholder's default text
+        let snippet = ParsedSnippet::parse("${1:hello $2}");
+
+        assert_eq!(snippet.text, "hello ");
+
+        let t1 = snippet.get_tabstop(1).unwrap();
+        assert_eq!(t1.placeholder.as_deref(), Some("hello "));
+
+        let t2 = snippet.get_tabstop(2).unwrap();
+        assert!(t2.placeholder.is_none());
+        assert_eq!(t2.ranges, vec![(6, 6)]);
+    }
+
+    #[test]
+    fn test_nested_placeholder_with_escaped_dollar() {
+        // Escaped $$ inside a nested placeholder default should still collapse to $
+        let snippet = ParsedSnippet::parse("${1:cost ${2:$$5}}");
+
+        assert_eq!(snippet.text, "cost $5");
+
+        let t2 = snippet.get_tabstop(2).unwrap();
+        assert_eq!(t2.placeholder.as_deref(), Some("$5"));
+    }
+
+    #[test]
+    fn test_doubly_nested_placeholder() {
+        // Nesting is not limited to a single level
+        let snippet = ParsedSnippet::parse("${1:a ${2:b ${3:c}}}");
+
+        assert_eq!(snippet.text, "a b c");
+        assert_eq!(snippet.tabstop_order(), vec![1, 2, 3]);
+
+        let t3 = snippet.get_tabstop(3).unwrap();
+        assert_eq!(t3.placeholder.as_deref(), Some("c"));
+    }
 }
@@ -0,0 +1,66 @@
+//! System sound playback for the `playSound` SDK message
+//!
+//! Plays one of macOS's built-in alert sounds via `afplay`, for scripts
+//! that want an audible cue (e.g. a long-running script signaling it's
+//! done while the user is in another window). Names are validated
+//! against an allow-list so a script can't use this to play arbitrary
+//! files off disk.
+
+use crate::logging;
+use std::process::Command;
+
+/// Allow-listed sound names, matching the built-in alert sounds under
+/// `/System/Library/Sounds/*.aiff` on macOS.
+pub const SYSTEM_SOUND_NAMES: &[&str] = &[
+    "Basso",
+    "Blow",
+    "Bottle",
+    "Frog",
+    "Funk",
+    "Glass",
+    "Hero",
+    "Morse",
+    "Ping",
+    "Pop",
+    "Purr",
+    "Sosumi",
+    "Submarine",
+    "Tink",
+];
+
+/// Plays `name` via `afplay` if it's in `SYSTEM_SOUND_NAMES`, fire-and-forget
+/// on a background thread. Unknown names are logged and ignored rather than
+/// played.
+pub fn play_sound(name: &str) {
+    if !SYSTEM_SOUND_NAMES.contains(&name) {
+        logging::log(
+            "SCRIPT",
+            &format!("Ignoring playSound for unknown sound name: {}", name),
+        );
+        return;
+    }
+
+    let path = format!("/System/Library/Sounds/{}.aiff", name);
+    std::thread::spawn(move || match Command::new("afplay").arg(&path).spawn() {
+        Ok(_) => logging::log("SCRIPT", &format!("Playing sound: {}", path)),
+        Err(e) => logging::log("ERROR", &format!("Failed to play sound {}: {}", path, e)),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allow_list_contains_known_sound() {
+        assert!(SYSTEM_SOUND_NAMES.contains(&"Glass"));
+    }
+
+    #[test]
+    fn test_play_sound_ignores_unknown_name() {
+        // Should not panic or spawn afplay for a name outside the allow-list -
+        // exercised here mainly to document the rejection path; we don't
+        // assert on process spawning since that's fire-and-forget.
+        play_sound("../../etc/passwd");
+    }
+}
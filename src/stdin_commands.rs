@@ -14,6 +14,8 @@
 //! {"type": "setFilter", "text": "search term"}
 //! {"type": "triggerBuiltin", "name": "clipboardHistory"}
 //! {"type": "simulateKey", "key": "enter", "modifiers": ["cmd"]}
+//! {"type": "typeText", "text": "hello world"}
+//! {"type": "listScripts"}
 //! ```
 //!
 //! # Example Usage
@@ -49,6 +51,11 @@ pub enum ExternalCommand {
     /// Run a script by path
     Run {
         path: String,
+        /// Positional args to pre-answer the script's `arg()` calls, in
+        /// order. An empty string is a valid arg (some prompts expect one)
+        /// and is passed through, not dropped.
+        #[serde(default)]
+        args: Vec<String>,
         /// Optional request ID for correlation in logs
         #[serde(default, rename = "requestId")]
         request_id: Option<String>,
@@ -74,6 +81,14 @@ pub enum ExternalCommand {
     },
     /// Trigger a built-in feature by name (for testing)
     TriggerBuiltin { name: String },
+    /// Surface an error toast to the user.
+    ///
+    /// Not a normal stdin/testing command - this is how sources that dispatch
+    /// through this same channel but have no UI of their own (currently just
+    /// the `scriptkit://` URL handler, see `url_scheme::handle_incoming_url`)
+    /// report a rejected request back to the main window instead of only
+    /// logging it.
+    ShowErrorToast { message: String },
     /// Simulate a key press (for testing)
     /// key: Key name like "enter", "escape", "up", "down", "k", etc.
     /// modifiers: Optional array of modifiers ["cmd", "shift", "alt", "ctrl"]
@@ -82,6 +97,12 @@ pub enum ExternalCommand {
         #[serde(default)]
         modifiers: Vec<String>,
     },
+    /// Type a whole string into the currently focused input (for testing)
+    /// Feeds each character through the same per-character handling real
+    /// key presses use, one at a time, so it's easier to fill in an arg
+    /// prompt or the main filter from a test script than sending a
+    /// `simulateKey` per character.
+    TypeText { text: String },
     /// Open the Notes window (for testing)
     OpenNotes,
     /// Open the AI Chat window (for testing)
@@ -139,26 +160,56 @@ pub enum ExternalCommand {
         /// The user's input text to use with the fallback action
         input: String,
     },
+    /// List the currently available scripts, scriptlets, and built-ins as
+    /// JSON on stdout, so an external tool (an Alfred workflow, a status
+    /// bar app) can enumerate what's available and build its own launcher.
+    ListScripts {
+        /// Optional request ID for correlation in logs
+        #[serde(default, rename = "requestId")]
+        request_id: Option<String>,
+    },
 }
 
-/// Start a thread that listens on stdin for external JSONL commands.
-/// Returns an async_channel::Receiver that can be awaited without polling.
+impl ExternalCommand {
+    /// The request ID carried by this command, if any. Only the commands
+    /// that originate from the stdin JSON protocol's request/response
+    /// pattern carry one; used to correlate the `--headless` stdout state
+    /// feed with the command that triggered it.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            ExternalCommand::Run { request_id, .. }
+            | ExternalCommand::Show { request_id }
+            | ExternalCommand::Hide { request_id }
+            | ExternalCommand::SetFilter { request_id, .. }
+            | ExternalCommand::ListScripts { request_id } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Create the bounded channel `ExternalCommand`s flow through. Both the
+/// stdin reader ([`spawn_stdin_reader`]) and the `scriptkit://` URL handler
+/// (`url_scheme::register_url_scheme_handler`) feed the same channel, so the
+/// main event loop only needs one dispatch loop regardless of which source
+/// a command came from.
 ///
 /// # Channel Capacity
 ///
-/// Uses a bounded channel with capacity of 100 to prevent unbounded memory growth.
-/// This is generous for stdin commands which typically arrive at < 10/sec.
-///
-/// # Thread Safety
-///
-/// Spawns a background thread that reads stdin line-by-line. When the channel
-/// is closed (receiver dropped), the thread will exit gracefully.
-pub fn start_stdin_listener() -> async_channel::Receiver<ExternalCommand> {
-    use std::io::BufRead;
+/// Uses a bounded channel with capacity of 100 to prevent unbounded memory
+/// growth. This is generous for external commands, which typically arrive
+/// at < 10/sec.
+pub fn external_command_channel() -> (
+    async_channel::Sender<ExternalCommand>,
+    async_channel::Receiver<ExternalCommand>,
+) {
+    async_channel::bounded(100)
+}
 
-    // P1-6: Use bounded channel to prevent unbounded memory growth
-    // Capacity of 100 is generous for stdin commands (typically < 10/sec)
-    let (tx, rx) = async_channel::bounded(100);
+/// Spawn the background thread that reads stdin line-by-line and sends
+/// parsed commands into `tx`. When the channel is closed (receiver
+/// dropped), the thread exits gracefully.
+pub fn spawn_stdin_reader(tx: async_channel::Sender<ExternalCommand>) {
+    use std::io::BufRead;
 
     std::thread::spawn(move || {
         logging::log("STDIN", "External command listener started");
@@ -192,8 +243,6 @@ pub fn start_stdin_listener() -> async_channel::Receiver<ExternalCommand> {
         }
         logging::log("STDIN", "External command listener exiting");
     });
-
-    rx
 }
 
 // ============================================================================
@@ -209,8 +258,13 @@ mod tests {
         let json = r#"{"type": "run", "path": "/path/to/script.ts"}"#;
         let cmd: ExternalCommand = serde_json::from_str(json).unwrap();
         match cmd {
-            ExternalCommand::Run { path, request_id } => {
+            ExternalCommand::Run {
+                path,
+                args,
+                request_id,
+            } => {
                 assert_eq!(path, "/path/to/script.ts");
+                assert!(args.is_empty());
                 assert!(request_id.is_none());
             }
             _ => panic!("Expected Run command"),
@@ -222,7 +276,9 @@ mod tests {
         let json = r#"{"type": "run", "path": "/path/to/script.ts", "requestId": "req-123"}"#;
         let cmd: ExternalCommand = serde_json::from_str(json).unwrap();
         match cmd {
-            ExternalCommand::Run { path, request_id } => {
+            ExternalCommand::Run {
+                path, request_id, ..
+            } => {
                 assert_eq!(path, "/path/to/script.ts");
                 assert_eq!(request_id, Some("req-123".to_string()));
             }
@@ -230,6 +286,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_external_command_run_with_args_deserialization() {
+        let json =
+            r#"{"type": "run", "path": "/path/to/script.ts", "args": ["hello", "", "world"]}"#;
+        let cmd: ExternalCommand = serde_json::from_str(json).unwrap();
+        match cmd {
+            ExternalCommand::Run { path, args, .. } => {
+                assert_eq!(path, "/path/to/script.ts");
+                assert_eq!(
+                    args,
+                    vec!["hello".to_string(), "".to_string(), "world".to_string()]
+                );
+            }
+            _ => panic!("Expected Run command"),
+        }
+    }
+
     #[test]
     fn test_external_command_show_deserialization() {
         let json = r#"{"type": "show"}"#;
@@ -318,6 +391,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_external_command_type_text_deserialization() {
+        let json = r#"{"type": "typeText", "text": "hello world"}"#;
+        let cmd: ExternalCommand = serde_json::from_str(json).unwrap();
+        match cmd {
+            ExternalCommand::TypeText { text } => assert_eq!(text, "hello world"),
+            _ => panic!("Expected TypeText command"),
+        }
+    }
+
+    #[test]
+    fn test_external_command_type_text_requires_text_field() {
+        let json = r#"{"type": "typeText"}"#;
+        let result = serde_json::from_str::<ExternalCommand>(json);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_external_command_invalid_json_fails() {
         let json = r#"{"type": "unknown"}"#;
@@ -337,6 +427,7 @@ mod tests {
     fn test_external_command_clone() {
         let cmd = ExternalCommand::Run {
             path: "/test".to_string(),
+            args: Vec::new(),
             request_id: None,
         };
         let cloned = cmd.clone();
@@ -487,4 +578,26 @@ mod tests {
             _ => panic!("Expected ExecuteFallback command"),
         }
     }
+
+    #[test]
+    fn test_external_command_list_scripts_deserialization() {
+        let json = r#"{"type": "listScripts"}"#;
+        let cmd: ExternalCommand = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            cmd,
+            ExternalCommand::ListScripts { request_id: None }
+        ));
+    }
+
+    #[test]
+    fn test_external_command_list_scripts_with_request_id() {
+        let json = r#"{"type": "listScripts", "requestId": "req-789"}"#;
+        let cmd: ExternalCommand = serde_json::from_str(json).unwrap();
+        match cmd {
+            ExternalCommand::ListScripts { request_id } => {
+                assert_eq!(request_id, Some("req-789".to_string()));
+            }
+            _ => panic!("Expected ListScripts command"),
+        }
+    }
 }
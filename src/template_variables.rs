@@ -27,13 +27,24 @@
 //! | `day` | Day of week | "Monday" |
 //! | `month` | Month name | "January" |
 //! | `year` | Year | "2024" |
+//! | `uuid` | Random UUID v4 | "f47ac10b-58cc-4372-a567-0e02b2c3d479" |
+//! | `selection` | Currently selected text (via Accessibility API) | "highlighted text" |
+//! | `datetime:FORMAT` | Date/time with a custom chrono strftime format | "2024-01-15 2:30 PM" |
 //!
+//! `cursor` is special: it doesn't resolve to text. Instead it marks where the
+//! text cursor should land after the expanded text is inserted. Use
+//! [`substitute_variables_with_cursor_context`] to get both the substituted text and
+//! the cursor offset.
 
 use arboard::Clipboard;
 use chrono::{Datelike, Local, Timelike};
+use regex::Regex;
 use std::collections::HashMap;
 use tracing::{debug, warn};
 
+/// Variable name for the cursor placement marker (see module docs)
+const CURSOR_VARIABLE: &str = "cursor";
+
 // ============================================================================
 // Variable Context
 // ============================================================================
@@ -139,11 +150,37 @@ pub fn substitute_variables(content: &str) -> String {
 /// The content with all recognized variables substituted
 ///
 pub fn substitute_variables_with_context(content: &str, ctx: &VariableContext) -> String {
+    let (result, _cursor_offset) = substitute_variables_with_cursor_context(content, ctx);
+    result
+}
+
+/// Substitute template variables and report where the `{cursor}` marker landed
+///
+/// Behaves exactly like [`substitute_variables_with_context`], but additionally
+/// removes the `${cursor}`/`{{cursor}}` marker (if present) and returns the
+/// byte offset in the resulting string where it was found, so callers (e.g.
+/// the text expansion system) can reposition the cursor after inserting the
+/// expanded text. Returns `None` if no cursor marker was present.
+///
+/// Only the first cursor marker found is honored; any additional ones are
+/// removed without being reported (unlike other unresolved variables, which
+/// are left in place).
+pub fn substitute_variables_with_cursor_context(
+    content: &str,
+    ctx: &VariableContext,
+) -> (String, Option<usize>) {
     let mut result = content.to_string();
 
     // Early exit if no variable markers present
     if !result.contains('$') && !result.contains('{') {
-        return result;
+        return (result, None);
+    }
+
+    // Resolve `${datetime:FORMAT}` / `{{datetime:FORMAT}}` before the generic
+    // name-keyed substitution below, since the format string is part of the
+    // placeholder itself rather than a fixed variable name.
+    if ctx.should_evaluate_builtins() {
+        result = substitute_datetime_format_variables(&result);
     }
 
     // Build the set of values to substitute
@@ -168,7 +205,76 @@ pub fn substitute_variables_with_context(content: &str, ctx: &VariableContext) -
         }
     }
 
-    result
+    // `{cursor}` doesn't resolve to a value - find it, strip it, and report
+    // where it was so the caller can move the cursor there.
+    let cursor_dollar = format!("${{{}}}", CURSOR_VARIABLE);
+    let cursor_brace = format!("{{{{{}}}}}", CURSOR_VARIABLE);
+    let cursor_offset = result
+        .find(&cursor_dollar)
+        .map(|idx| (idx, cursor_dollar.len()))
+        .or_else(|| {
+            result
+                .find(&cursor_brace)
+                .map(|idx| (idx, cursor_brace.len()))
+        });
+
+    let cursor_offset = cursor_offset.map(|(idx, marker_len)| {
+        result.replace_range(idx..idx + marker_len, "");
+        idx
+    });
+    // Remove any remaining (extra) cursor markers without reporting them
+    result = result
+        .replace(&cursor_dollar, "")
+        .replace(&cursor_brace, "");
+
+    (result, cursor_offset)
+}
+
+/// Resolve `${datetime:FORMAT}` and `{{datetime:FORMAT}}` placeholders using
+/// a custom chrono strftime format string, e.g. `${datetime:%I:%M %p}`.
+///
+/// An empty or missing format falls back to the default `datetime` format.
+fn substitute_datetime_format_variables(content: &str) -> String {
+    if !content.contains("datetime:") {
+        return content.to_string();
+    }
+
+    let dollar_re = Regex::new(r"\$\{datetime:([^}]*)\}").unwrap();
+    let brace_re = Regex::new(r"\{\{datetime:([^}]*)\}\}").unwrap();
+
+    let now = Local::now();
+    let apply = |s: &str, re: &Regex| -> String {
+        re.replace_all(s, |caps: &regex::Captures| {
+            format_datetime(&now, caps[1].trim())
+        })
+        .into_owned()
+    };
+
+    let result = apply(content, &dollar_re);
+    apply(&result, &brace_re)
+}
+
+/// Format `now` using a user-supplied strftime format, falling back to the
+/// default `datetime` format if it's empty or contains invalid specifiers
+/// (chrono panics on `.to_string()` for a format with unrecognized items, so
+/// this validates first rather than letting a bad `{datetime:...}` crash the
+/// whole substitution).
+fn format_datetime(now: &chrono::DateTime<Local>, fmt: &str) -> String {
+    const DEFAULT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+    if fmt.is_empty() {
+        return now.format(DEFAULT_FORMAT).to_string();
+    }
+
+    let has_invalid_specifier = chrono::format::StrftimeItems::new(fmt)
+        .any(|item| matches!(item, chrono::format::Item::Error));
+
+    if has_invalid_specifier {
+        warn!(format = %fmt, "Invalid datetime format string, falling back to default");
+        return now.format(DEFAULT_FORMAT).to_string();
+    }
+
+    now.format(fmt).to_string()
 }
 
 /// Check if content contains any variable placeholders
@@ -279,6 +385,18 @@ fn add_builtin_variables(values: &mut HashMap<String, String>) {
         }
     }
 
+    // Currently selected text (only fetch if not already provided)
+    if !values.contains_key("selection") {
+        if let Some(text) = get_selection_text() {
+            values.insert("selection".to_string(), text);
+        }
+    }
+
+    // Random UUID (only generate if not already provided)
+    if !values.contains_key("uuid") {
+        values.insert("uuid".to_string(), uuid::Uuid::new_v4().to_string());
+    }
+
     // Date/Time variables (only compute if needed - lazy would be better but simple is fine)
     let now = Local::now();
 
@@ -374,6 +492,24 @@ fn get_clipboard_text() -> Option<String> {
     }
 }
 
+/// Get the currently selected text safely, falling back to `None` (leaving
+/// the `{selection}` placeholder unresolved) on any failure
+fn get_selection_text() -> Option<String> {
+    match crate::selected_text::get_selected_text() {
+        Ok(text) => {
+            debug!(
+                text_len = text.len(),
+                "Retrieved selected text for variable substitution"
+            );
+            Some(text)
+        }
+        Err(e) => {
+            debug!(error = %e, "Could not get selected text for variable substitution");
+            None
+        }
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -581,6 +717,140 @@ mod tests {
         );
     }
 
+    // ========================================
+    // New Built-in Variable Tests (uuid, selection, datetime:format, cursor)
+    // ========================================
+
+    #[test]
+    fn test_uuid_variable_is_valid_uuid() {
+        let result = substitute_variables("${uuid}");
+        assert!(
+            uuid::Uuid::parse_str(&result).is_ok(),
+            "uuid should be a valid UUID: {}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_uuid_variable_is_unique_per_call() {
+        let first = substitute_variables("${uuid}");
+        let second = substitute_variables("${uuid}");
+        assert_ne!(first, second, "each substitution should mint a fresh uuid");
+    }
+
+    #[test]
+    fn test_selection_missing_falls_back_to_literal() {
+        // In a headless test environment there's no accessibility permission
+        // or selection, so {selection} should be left unresolved rather than
+        // substituted with garbage or panicking.
+        let result = substitute_variables("${selection}");
+        assert_eq!(result, "${selection}");
+    }
+
+    #[test]
+    fn test_selection_custom_override() {
+        let mut ctx = VariableContext::new();
+        ctx.set("selection", "highlighted text");
+
+        let result = substitute_variables_with_context("${selection}", &ctx);
+        assert_eq!(result, "highlighted text");
+    }
+
+    #[test]
+    fn test_datetime_format_dollar_syntax() {
+        let result = substitute_variables("${datetime:%Y}");
+        assert_eq!(
+            result.len(),
+            4,
+            "formatted year should be 4 digits: {}",
+            result
+        );
+        assert!(result.parse::<u32>().is_ok());
+    }
+
+    #[test]
+    fn test_datetime_format_brace_syntax() {
+        let result = substitute_variables("{{datetime:%Y}}");
+        assert_eq!(
+            result.len(),
+            4,
+            "formatted year should be 4 digits: {}",
+            result
+        );
+        assert!(result.parse::<u32>().is_ok());
+    }
+
+    #[test]
+    fn test_datetime_format_empty_falls_back_to_default() {
+        let result = substitute_variables("${datetime:}");
+        // Default format is "%Y-%m-%d %H:%M:%S" (19 chars)
+        assert_eq!(
+            result.len(),
+            19,
+            "should fall back to default format: {}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_datetime_format_invalid_falls_back_to_default() {
+        // %Q is not a valid chrono specifier - should not panic, should fall back
+        let result = substitute_variables("${datetime:%Q}");
+        assert_eq!(
+            result.len(),
+            19,
+            "invalid format should fall back to default: {}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_datetime_format_custom_override_by_ctx_unaffected() {
+        // Custom "datetime" context values shouldn't interfere with the
+        // separately-resolved datetime:FORMAT placeholders
+        let mut ctx = VariableContext::new();
+        ctx.set("datetime", "CUSTOM");
+
+        let result = substitute_variables_with_context("${datetime} / ${datetime:%Y}", &ctx);
+        assert!(result.starts_with("CUSTOM / "));
+        let year_part = result.strip_prefix("CUSTOM / ").unwrap();
+        assert_eq!(year_part.len(), 4);
+    }
+
+    #[test]
+    fn test_cursor_marker_removed_and_offset_reported() {
+        let ctx = VariableContext::custom_only();
+        let (result, offset) =
+            substitute_variables_with_cursor_context("before ${cursor} after", &ctx);
+        assert_eq!(result, "before  after");
+        assert_eq!(offset, Some("before ".len()));
+    }
+
+    #[test]
+    fn test_cursor_marker_brace_syntax() {
+        let ctx = VariableContext::custom_only();
+        let (result, offset) =
+            substitute_variables_with_cursor_context("before {{cursor}} after", &ctx);
+        assert_eq!(result, "before  after");
+        assert_eq!(offset, Some("before ".len()));
+    }
+
+    #[test]
+    fn test_cursor_marker_absent_returns_none() {
+        let ctx = VariableContext::custom_only();
+        let (result, offset) = substitute_variables_with_cursor_context("no marker here", &ctx);
+        assert_eq!(result, "no marker here");
+        assert_eq!(offset, None);
+    }
+
+    #[test]
+    fn test_cursor_marker_missing_in_plain_substitution_is_stripped() {
+        // substitute_variables (no cursor tracking) should still strip the
+        // marker rather than leaving a literal "{cursor}" in the output
+        let result = substitute_variables("Hello ${cursor}World");
+        assert_eq!(result, "Hello World");
+    }
+
     // ========================================
     // Context Tests
     // ========================================
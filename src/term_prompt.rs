@@ -3,6 +3,7 @@
 //! Renders terminal content and handles keyboard input with proper monospace grid,
 //! cursor rendering, per-cell colors, and control character handling.
 
+use anyhow::Context as _;
 use gpui::{
     div, prelude::*, px, rgb, Context, FocusHandle, Focusable, MouseButton, MouseDownEvent,
     MouseMoveEvent, MouseUpEvent, Pixels, Render, ScrollDelta, ScrollWheelEvent, SharedString,
@@ -15,7 +16,9 @@ use tracing::{debug, info, trace, warn};
 
 use crate::config::Config;
 use crate::prompts::SubmitCallback;
-use crate::terminal::{CellAttributes, TerminalContent, TerminalEvent, TerminalHandle};
+use crate::terminal::{
+    pty, CellAttributes, TermSpawnOptions, TerminalContent, TerminalEvent, TerminalHandle,
+};
 use crate::theme::Theme;
 
 const SLOW_RENDER_THRESHOLD_MS: u128 = 16; // 60fps threshold
@@ -109,7 +112,18 @@ impl TermPrompt {
         theme: Arc<Theme>,
         config: Arc<Config>,
     ) -> anyhow::Result<Self> {
-        Self::with_height(id, command, focus_handle, on_submit, theme, config, None)
+        Self::with_height(
+            id,
+            command,
+            None,
+            None,
+            None,
+            focus_handle,
+            on_submit,
+            theme,
+            config,
+            None,
+        )
     }
 
     /// Create new terminal prompt with explicit height
@@ -117,9 +131,18 @@ impl TermPrompt {
     /// This is necessary because GPUI entities don't inherit parent flex sizing.
     /// When rendered as a child of a sized container, h_full() doesn't resolve
     /// to the parent's height. We must pass an explicit height.
+    ///
+    /// `shell`/`cwd`/`login` come from the `term()` SDK call's `Message::Term`
+    /// fields; a `None` falls back to `config.terminal.shell`/`.login`, then
+    /// (for `shell`) `$SHELL`, then `/bin/zsh`. `cwd` is tilde-expanded and
+    /// validated here so callers get a real error instead of a dead terminal.
+    #[allow(clippy::too_many_arguments)]
     pub fn with_height(
         id: String,
         command: Option<String>,
+        shell: Option<String>,
+        cwd: Option<String>,
+        login: Option<bool>,
         focus_handle: FocusHandle,
         on_submit: SubmitCallback,
         theme: Arc<Theme>,
@@ -130,11 +153,21 @@ impl TermPrompt {
         let initial_cols = 80;
         let initial_rows = 24;
 
-        let terminal = match command {
-            Some(cmd) => TerminalHandle::with_command(&cmd, initial_cols, initial_rows)?,
-            None => TerminalHandle::new(initial_cols, initial_rows)?,
+        let cwd = cwd
+            .map(|raw| {
+                pty::resolve_cwd(&raw).with_context(|| format!("invalid term() cwd: {}", raw))
+            })
+            .transpose()?;
+
+        let options = TermSpawnOptions {
+            shell: shell.or_else(|| config.get_terminal_shell()),
+            cwd,
+            login: login.unwrap_or_else(|| config.get_terminal_login()),
         };
 
+        let terminal =
+            TerminalHandle::with_options(command.as_deref(), initial_cols, initial_rows, options)?;
+
         info!(
             id = %id,
             content_height = ?content_height,
@@ -318,6 +318,22 @@ impl TerminalState {
     }
 }
 
+/// Options for spawning a `term()` terminal beyond the bare command + size.
+///
+/// Mirrors the `Message::Term` `shell`/`cwd`/`login` fields, which in turn
+/// fall back to `config.terminal.shell`/`config.terminal.login` when the
+/// script omits them.
+#[derive(Debug, Clone, Default)]
+pub struct TermSpawnOptions {
+    /// Shell binary override; resolved via `pty::resolve_shell` when absent.
+    pub shell: Option<String>,
+    /// Working directory; must already be tilde-expanded and validated (see
+    /// `pty::resolve_cwd`).
+    pub cwd: Option<String>,
+    /// Spawn as a login shell.
+    pub login: bool,
+}
+
 /// Handle to an Alacritty terminal emulator instance.
 ///
 /// `TerminalHandle` provides the core terminal emulation functionality:
@@ -395,7 +411,7 @@ impl TerminalHandle {
     /// Returns an error if PTY creation or command spawning fails.
     #[instrument(level = "info", name = "terminal_with_command", fields(cmd = %cmd, cols, rows))]
     pub fn with_command(cmd: &str, cols: u16, rows: u16) -> Result<Self> {
-        Self::create_internal(Some(cmd), cols, rows, DEFAULT_SCROLLBACK_LINES)
+        Self::create_internal(Some(cmd), cols, rows, DEFAULT_SCROLLBACK_LINES, None)
     }
 
     /// Creates a new terminal handle with custom scrollback size.
@@ -415,7 +431,28 @@ impl TerminalHandle {
         fields(cols, rows, scrollback_lines)
     )]
     pub fn with_scrollback(cols: u16, rows: u16, scrollback_lines: usize) -> Result<Self> {
-        Self::create_internal(None, cols, rows, scrollback_lines)
+        Self::create_internal(None, cols, rows, scrollback_lines, None)
+    }
+
+    /// Creates a new terminal handle honoring a `term()` SDK call's
+    /// `shell`/`cwd`/`login` overrides.
+    ///
+    /// See [`crate::terminal::pty::resolve_shell`] and
+    /// [`crate::terminal::pty::build_shell_args`] for the shell-resolution
+    /// and login-argument semantics. `options.cwd` must already be
+    /// tilde-expanded and validated by the caller.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if PTY creation or shell spawning fails.
+    #[instrument(level = "info", name = "terminal_with_options", fields(cols, rows, login = options.login))]
+    pub fn with_options(
+        cmd: Option<&str>,
+        cols: u16,
+        rows: u16,
+        options: TermSpawnOptions,
+    ) -> Result<Self> {
+        Self::create_internal(cmd, cols, rows, DEFAULT_SCROLLBACK_LINES, Some(options))
     }
 
     /// Internal creation method.
@@ -424,13 +461,29 @@ impl TerminalHandle {
         cols: u16,
         rows: u16,
         scrollback_lines: usize,
+        options: Option<TermSpawnOptions>,
     ) -> Result<Self> {
         use std::sync::atomic::{AtomicBool, Ordering};
         use std::sync::mpsc;
 
-        // Always spawn an interactive shell - never use -c which exits after command
-        // If a command is provided, we'll write it to the PTY after creation
-        let mut pty = PtyManager::with_size(cols, rows).context("Failed to create PTY")?;
+        // Login spawns run `cmd` directly via `-c`/`-il` (see
+        // `build_shell_args`), so it must not also be typed into the shell
+        // below. Non-login spawns always start an interactive shell - never
+        // `-c`, which exits after the command - and type `cmd` in afterward.
+        let login = options.as_ref().is_some_and(|o| o.login);
+        let mut pty = match &options {
+            Some(options) => PtyManager::with_shell_options(
+                options.shell.as_deref(),
+                cmd,
+                options.login,
+                options.cwd.as_deref(),
+                cols,
+                rows,
+            )
+            .context("Failed to create PTY")?,
+            None => PtyManager::with_size(cols, rows).context("Failed to create PTY")?,
+        };
+        let cmd = if login { None } else { cmd };
 
         // Create terminal configuration
         let config = TermConfig {
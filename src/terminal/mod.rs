@@ -32,7 +32,7 @@ pub mod pty;
 pub mod theme_adapter;
 
 // Re-export main types for convenient access
-pub use alacritty::{CellAttributes, TerminalContent, TerminalHandle};
+pub use alacritty::{CellAttributes, TermSpawnOptions, TerminalContent, TerminalHandle};
 
 /// Events emitted by the terminal for GPUI integration.
 ///
@@ -91,7 +91,7 @@ impl PtyManager {
     pub fn with_size(cols: u16, rows: u16) -> Result<Self> {
         let shell = Self::detect_shell();
         info!(shell = %shell, cols, rows, "Spawning shell with custom size");
-        Self::spawn_internal(&shell, &[], cols, rows)
+        Self::spawn_internal(&shell, &[], cols, rows, None)
     }
 
     /// Creates a new PTY manager running a specific command.
@@ -107,7 +107,7 @@ impl PtyManager {
     ///
     #[instrument(level = "info", name = "pty_spawn_command", fields(cmd = %cmd))]
     pub fn with_command(cmd: &str, args: &[&str]) -> Result<Self> {
-        Self::spawn_internal(cmd, args, 80, 24)
+        Self::spawn_internal(cmd, args, 80, 24, None)
     }
 
     /// Creates a new PTY manager running a specific command with custom dimensions.
@@ -124,11 +124,50 @@ impl PtyManager {
     /// Returns an error if PTY creation or command spawning fails.
     #[instrument(level = "info", name = "pty_spawn_full", fields(cmd = %cmd, cols, rows))]
     pub fn with_command_and_size(cmd: &str, args: &[&str], cols: u16, rows: u16) -> Result<Self> {
-        Self::spawn_internal(cmd, args, cols, rows)
+        Self::spawn_internal(cmd, args, cols, rows, None)
+    }
+
+    /// Creates a new PTY manager for a `term()` SDK call, honoring its
+    /// `shell`/`login`/`cwd` overrides.
+    ///
+    /// `shell` is resolved via [`resolve_shell`] (explicit override, then
+    /// `$SHELL`, then `/bin/zsh`). Argument construction follows
+    /// [`build_shell_args`]: non-login spawns start the shell bare (the
+    /// caller types `command` into it afterward, keeping the shell open);
+    /// login spawns run `command` directly via `-c` (or `-il` when absent),
+    /// matching `ssh`/`su -l` semantics. `cwd` must already be
+    /// tilde-expanded and validated by the caller (see [`resolve_cwd`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if PTY creation or shell spawning fails.
+    #[instrument(
+        level = "info",
+        name = "pty_spawn_term",
+        fields(shell, login, cols, rows)
+    )]
+    pub fn with_shell_options(
+        shell: Option<&str>,
+        command: Option<&str>,
+        login: bool,
+        cwd: Option<&str>,
+        cols: u16,
+        rows: u16,
+    ) -> Result<Self> {
+        let shell = resolve_shell(shell);
+        let args = build_shell_args(login, command);
+        let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        Self::spawn_internal(&shell, &arg_refs, cols, rows, cwd)
     }
 
     /// Internal spawn implementation.
-    fn spawn_internal(cmd: &str, args: &[&str], cols: u16, rows: u16) -> Result<Self> {
+    fn spawn_internal(
+        cmd: &str,
+        args: &[&str],
+        cols: u16,
+        rows: u16,
+        cwd: Option<&str>,
+    ) -> Result<Self> {
         let pty_system = native_pty_system();
 
         let size = PtySize {
@@ -154,6 +193,9 @@ impl PtyManager {
         for arg in args {
             command.arg(*arg);
         }
+        if let Some(cwd) = cwd {
+            command.cwd(cwd);
+        }
 
         // Set up environment for interactive shell
         #[cfg(unix)]
@@ -427,6 +469,56 @@ impl Drop for PtyManager {
     }
 }
 
+/// Resolves the shell binary for a `term()` spawn, given an optional
+/// explicit override (from the `Message::Term` or `config.terminal.shell`).
+///
+/// Falls back to `$SHELL`, then `/bin/zsh` with a logged warning when
+/// neither is available (GUI apps are often launched without `$SHELL` set).
+pub fn resolve_shell(requested: Option<&str>) -> String {
+    if let Some(shell) = requested.filter(|s| !s.is_empty()) {
+        return shell.to_string();
+    }
+    match std::env::var("SHELL") {
+        Ok(shell) if !shell.is_empty() => shell,
+        _ => {
+            warn!("$SHELL not set and no shell requested, falling back to /bin/zsh");
+            "/bin/zsh".to_string()
+        }
+    }
+}
+
+/// Builds the argv (excluding the shell binary) for spawning a `term()`
+/// shell, given its login/command settings.
+///
+/// Non-login spawns take no arguments: the shell starts interactively and
+/// the caller types `command` into it afterward, so the shell stays open
+/// once the command finishes. Login spawns instead run `command` directly
+/// via `-l -c`, matching `ssh`/`su -l` semantics, so the shell exits when
+/// the command completes; with no command, `-il` starts an interactive
+/// login shell that stays open.
+pub fn build_shell_args(login: bool, command: Option<&str>) -> Vec<String> {
+    if !login {
+        return Vec::new();
+    }
+    match command {
+        Some(cmd) => vec!["-l".to_string(), "-c".to_string(), cmd.to_string()],
+        None => vec!["-il".to_string()],
+    }
+}
+
+/// Tilde-expands and validates a requested `term()` working directory.
+///
+/// Returns the expanded path if it exists and is a directory. Callers
+/// should surface the error as a toast rather than spawning a dead
+/// terminal in a nonexistent directory.
+pub fn resolve_cwd(requested: &str) -> Result<String> {
+    let expanded = shellexpand::tilde(requested).into_owned();
+    if !std::path::Path::new(&expanded).is_dir() {
+        anyhow::bail!("working directory '{}' does not exist", expanded);
+    }
+    Ok(expanded)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -577,4 +669,71 @@ mod tests {
             assert!(debug_str.contains("size"));
         }
     }
+
+    #[test]
+    fn test_build_shell_args_non_login_is_bare() {
+        // Non-login spawns never take argv - the shell starts interactively
+        // and the caller types `command` in afterward.
+        assert!(build_shell_args(false, None).is_empty());
+        assert!(build_shell_args(false, Some("ls")).is_empty());
+    }
+
+    #[test]
+    fn test_build_shell_args_login_with_command() {
+        assert_eq!(
+            build_shell_args(true, Some("ls -la")),
+            vec!["-l".to_string(), "-c".to_string(), "ls -la".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_build_shell_args_login_without_command() {
+        assert_eq!(build_shell_args(true, None), vec!["-il".to_string()]);
+    }
+
+    #[test]
+    fn test_resolve_shell_prefers_requested() {
+        assert_eq!(resolve_shell(Some("/bin/bash")), "/bin/bash");
+    }
+
+    #[test]
+    fn test_resolve_shell_ignores_empty_requested() {
+        // An empty override is treated as "not requested" rather than
+        // spawning a PTY with an empty command.
+        let resolved = resolve_shell(Some(""));
+        assert!(!resolved.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_shell_falls_back_to_shell_env() {
+        std::env::set_var("SHELL", "/bin/test-shell");
+        assert_eq!(resolve_shell(None), "/bin/test-shell");
+        std::env::remove_var("SHELL");
+    }
+
+    #[test]
+    fn test_resolve_shell_falls_back_to_zsh_default() {
+        let saved = std::env::var("SHELL").ok();
+        std::env::remove_var("SHELL");
+        assert_eq!(resolve_shell(None), "/bin/zsh");
+        if let Some(shell) = saved {
+            std::env::set_var("SHELL", shell);
+        }
+    }
+
+    #[test]
+    fn test_resolve_cwd_expands_tilde() {
+        let home = std::env::var("HOME").unwrap_or_default();
+        if home.is_empty() {
+            return;
+        }
+        let resolved = resolve_cwd("~").expect("home directory should exist");
+        assert_eq!(resolved, home);
+    }
+
+    #[test]
+    fn test_resolve_cwd_rejects_missing_directory() {
+        let result = resolve_cwd("/this/path/should/not/exist/ever");
+        assert!(result.is_err());
+    }
 }
@@ -188,6 +188,39 @@ impl TextInjector {
         Ok(())
     }
 
+    /// Move the text cursor left by simulating left-arrow key presses
+    ///
+    /// Used after expansion to reposition the cursor at a `{cursor}` marker
+    /// inside the expanded snippet, rather than leaving it at the end of the
+    /// pasted text.
+    ///
+    /// # Arguments
+    /// * `count` - Number of left-arrow key presses to send
+    ///
+    /// # Errors
+    /// Returns error if CGEventPost fails
+    ///
+    #[instrument(skip(self), fields(count))]
+    pub fn move_cursor_left(&self, count: usize) -> Result<()> {
+        if count == 0 {
+            debug!("No cursor movement needed");
+            return Ok(());
+        }
+
+        debug!(count, "Moving cursor left via arrow key simulation");
+
+        for i in 0..count {
+            simulate_left_arrow()?;
+
+            if i < count - 1 && self.config.key_delay_ms > 0 {
+                thread::sleep(Duration::from_millis(self.config.key_delay_ms));
+            }
+        }
+
+        info!(count, "Moved cursor left successfully");
+        Ok(())
+    }
+
     /// Inject text by deleting trigger characters and pasting replacement
     ///
     /// This is a convenience function that combines `delete_chars()` and
@@ -261,6 +294,35 @@ fn simulate_backspace() -> Result<()> {
     Ok(())
 }
 
+/// Simulate a single left-arrow keypress using Core Graphics
+///
+/// Sends both key down and key up events for the left-arrow key.
+fn simulate_left_arrow() -> Result<()> {
+    use core_graphics::event::{CGEvent, CGEventTapLocation, CGKeyCode};
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    // Left-arrow key is keycode 123 on macOS
+    const KEY_LEFT_ARROW: CGKeyCode = 123;
+
+    let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+        .ok()
+        .context("Failed to create CGEventSource")?;
+
+    let key_down = CGEvent::new_keyboard_event(source.clone(), KEY_LEFT_ARROW, true)
+        .ok()
+        .context("Failed to create left-arrow key down event")?;
+
+    let key_up = CGEvent::new_keyboard_event(source, KEY_LEFT_ARROW, false)
+        .ok()
+        .context("Failed to create left-arrow key up event")?;
+
+    key_down.post(CGEventTapLocation::HID);
+    thread::sleep(Duration::from_millis(1));
+    key_up.post(CGEventTapLocation::HID);
+
+    Ok(())
+}
+
 /// Simulate Cmd+V paste keystroke using Core Graphics
 ///
 /// Sends key down and key up events for 'v' with Command modifier.
@@ -345,6 +407,14 @@ mod tests {
         let result = injector.delete_chars(0);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_move_cursor_left_zero() {
+        // Moving zero positions should succeed without doing anything
+        let injector = TextInjector::new();
+        let result = injector.move_cursor_left(0);
+        assert!(result.is_ok());
+    }
 }
 
 // ============================================================================
@@ -379,6 +449,20 @@ mod system_tests {
         println!("Pasted text");
     }
 
+    #[test]
+    #[ignore] // Requires accessibility permission and user interaction
+    fn test_move_cursor_left() {
+        // Instructions:
+        // 1. Open TextEdit and type "hello world", leaving the cursor at the end
+        // 2. Run: cargo test --features system-tests test_move_cursor_left -- --ignored
+        // 3. The cursor should move left by 6 positions (before "world")
+        let injector = TextInjector::new();
+        injector
+            .move_cursor_left(6)
+            .expect("Should move cursor left");
+        println!("Moved cursor left");
+    }
+
     #[test]
     #[ignore] // Requires accessibility permission and user interaction
     fn test_inject_text() {
@@ -15,12 +15,16 @@
 //! - `helpers` - Lightweight color extraction for render closures
 //! - `gpui_integration` - gpui-component theme mapping
 //! - `service` - Global theme watcher service
+//! - `system_accent` - macOS system accent/highlight color detection
+//! - `switcher` - Named theme presets (list + apply by name)
 
 mod gpui_integration;
 mod helpers;
 pub mod hex_color;
 pub mod semantic;
 pub mod service;
+pub mod switcher;
+pub mod system_accent;
 mod types;
 pub mod validation;
 
@@ -37,7 +41,9 @@ pub use semantic::{FocusAware, SemanticColors, Surface, SurfaceStyle};
 
 // Re-export validation types
 #[allow(unused_imports)]
-pub use validation::{validate_theme_json, Diagnostic, DiagnosticSeverity, ThemeDiagnostics};
+pub use validation::{
+    validate, validate_theme_json, Diagnostic, DiagnosticSeverity, ThemeDiagnostics,
+};
 
 // Re-export loader functions
 pub use types::load_theme;
@@ -45,6 +51,9 @@ pub use types::load_theme;
 // Re-export gpui integration
 pub use gpui_integration::sync_gpui_component_theme;
 
+// Re-export theme switching
+pub use switcher::{list_available_themes, set_theme_by_name, SetThemeError};
+
 // Additional exports for tests
 #[cfg(test)]
 pub use hex_color::{hex_color_serde, HexColor};
@@ -0,0 +1,155 @@
+//! Named theme switching
+//!
+//! Lets a script pick a theme by name from a small library of presets
+//! stored under `~/.scriptkit/kit/themes/<name>.json`, and apply one as the
+//! active theme (`~/.scriptkit/kit/theme.json`, the file `load_theme()`
+//! reads). Pairs with a script that lists `list_available_themes()` in a
+//! `select()` prompt and posts back `Message::SetTheme { name }`.
+//!
+//! Applying a theme validates its JSON first, so a malformed preset never
+//! overwrites a working active theme. The write is picked up by the
+//! existing `ThemeWatcher`/theme service like any other edit to
+//! `theme.json`, so no separate "apply" signal is needed here.
+
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use super::validation::validate_theme_json;
+
+/// Directory holding named theme presets, one JSON file per theme.
+fn themes_dir() -> PathBuf {
+    PathBuf::from(shellexpand::tilde("~/.scriptkit/kit/themes").as_ref())
+}
+
+/// Path to the active theme file that `load_theme()` reads from.
+fn active_theme_path() -> PathBuf {
+    PathBuf::from(shellexpand::tilde("~/.scriptkit/kit/theme.json").as_ref())
+}
+
+/// List the names of available theme presets (file stem, without `.json`),
+/// sorted alphabetically. Returns an empty list if the themes directory
+/// doesn't exist yet.
+pub fn list_available_themes() -> Vec<String> {
+    list_available_themes_in(&themes_dir())
+}
+
+fn list_available_themes_in(dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|entry| {
+            entry
+                .path()
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Error switching the active theme to a named preset.
+#[derive(Debug, Error)]
+pub enum SetThemeError {
+    #[error("No theme named '{0}' found in ~/.scriptkit/kit/themes/")]
+    NotFound(String),
+    #[error("Theme '{name}' could not be read: {source}")]
+    Read {
+        name: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Theme '{name}' is not valid JSON: {source}")]
+    Malformed {
+        name: String,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("Theme '{name}' failed validation ({error_count} error(s)): {message}")]
+    Invalid {
+        name: String,
+        error_count: usize,
+        message: String,
+    },
+    #[error("Could not save '{name}' as the active theme: {source}")]
+    Write {
+        name: String,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/// Switch the active theme to the named preset, persisting the choice to
+/// `~/.scriptkit/kit/theme.json` so it survives restarts. Validates the
+/// preset's JSON before writing it as the active theme.
+pub fn set_theme_by_name(name: &str) -> Result<(), SetThemeError> {
+    let preset_path = themes_dir().join(format!("{name}.json"));
+    if !preset_path.exists() {
+        return Err(SetThemeError::NotFound(name.to_string()));
+    }
+
+    let contents = std::fs::read_to_string(&preset_path).map_err(|source| SetThemeError::Read {
+        name: name.to_string(),
+        source,
+    })?;
+
+    let json: serde_json::Value =
+        serde_json::from_str(&contents).map_err(|source| SetThemeError::Malformed {
+            name: name.to_string(),
+            source,
+        })?;
+
+    let diagnostics = validate_theme_json(&json);
+    if diagnostics.has_errors() {
+        return Err(SetThemeError::Invalid {
+            name: name.to_string(),
+            error_count: diagnostics.error_count(),
+            message: diagnostics.format_for_log(),
+        });
+    }
+
+    std::fs::write(active_theme_path(), &contents).map_err(|source| SetThemeError::Write {
+        name: name.to_string(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_available_themes_in_missing_dir_is_empty() {
+        let dir = PathBuf::from("/nonexistent/scriptkit-theme-switcher-test");
+        assert!(list_available_themes_in(&dir).is_empty());
+    }
+
+    #[test]
+    fn list_available_themes_in_sorts_and_ignores_non_json() {
+        let dir = std::env::temp_dir().join(format!(
+            "scriptkit-theme-switcher-test-{:?}",
+            std::thread::current().id()
+        ));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        std::fs::write(dir.join("zebra.json"), "{}").unwrap();
+        std::fs::write(dir.join("aurora.json"), "{}").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let names = list_available_themes_in(&dir);
+
+        std::fs::remove_dir_all(&dir).ok();
+        assert_eq!(names, vec!["aurora".to_string(), "zebra".to_string()]);
+    }
+
+    #[test]
+    fn set_theme_by_name_reports_not_found_for_missing_preset() {
+        // themes_dir() is the real ~/.scriptkit/kit/themes; a random name is
+        // exceedingly unlikely to collide with a preset the user has saved.
+        let err = set_theme_by_name("__scriptkit_test_theme_that_does_not_exist__").unwrap_err();
+        assert!(matches!(err, SetThemeError::NotFound(_)));
+    }
+}
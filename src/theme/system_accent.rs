@@ -0,0 +1,186 @@
+//! macOS system accent/highlight color detection
+//!
+//! Reads the user's system accent color via `NSColor` so the default theme
+//! color scheme doesn't clash with their OS-level color choice. Falls back
+//! to `None` on non-macOS platforms or whenever the color can't be read
+//! (headless environments, API changes, etc.) so callers can keep the
+//! existing theme colors unchanged.
+
+use super::hex_color::{hex_color_serde, HexColor};
+use super::types::Theme;
+use tracing::warn;
+
+/// Read the user's macOS system accent color (`NSColor.controlAccentColor`),
+/// converted to an sRGB hex value.
+#[cfg(target_os = "macos")]
+pub fn read_system_accent_color() -> Option<HexColor> {
+    use objc::runtime::Object;
+    use objc::{class, msg_send, sel, sel_impl};
+
+    unsafe {
+        let color: *mut Object = msg_send![class!(NSColor), controlAccentColor];
+        rgb_from_ns_color(color)
+    }
+}
+
+/// Read the user's macOS system selection-highlight color
+/// (`NSColor.selectedContentBackgroundColor`), converted to an sRGB hex value.
+#[cfg(target_os = "macos")]
+pub fn read_system_highlight_color() -> Option<HexColor> {
+    use objc::runtime::Object;
+    use objc::{class, msg_send, sel, sel_impl};
+
+    unsafe {
+        let color: *mut Object = msg_send![class!(NSColor), selectedContentBackgroundColor];
+        rgb_from_ns_color(color)
+    }
+}
+
+/// # Safety
+///
+/// `color` must be a valid `NSColor*` (or null).
+#[cfg(target_os = "macos")]
+unsafe fn rgb_from_ns_color(color: *mut objc::runtime::Object) -> Option<HexColor> {
+    use objc::runtime::Object;
+    use objc::{class, msg_send, sel, sel_impl};
+
+    if color.is_null() {
+        return None;
+    }
+
+    // System colors can live in a dynamic/pattern color space; convert to
+    // sRGB first so the component reads below are well-defined.
+    let srgb_space: *mut Object = msg_send![class!(NSColorSpace), sRGBColorSpace];
+    let rgb_color: *mut Object = msg_send![color, colorUsingColorSpace: srgb_space];
+    if rgb_color.is_null() {
+        return None;
+    }
+
+    let red: f64 = msg_send![rgb_color, redComponent];
+    let green: f64 = msg_send![rgb_color, greenComponent];
+    let blue: f64 = msg_send![rgb_color, blueComponent];
+
+    Some(rgb_components_to_hex(red, green, blue))
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn read_system_accent_color() -> Option<HexColor> {
+    None
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn read_system_highlight_color() -> Option<HexColor> {
+    None
+}
+
+/// Convert normalized (0.0-1.0) RGB components into a packed `0xRRGGBB` value.
+fn rgb_components_to_hex(red: f64, green: f64, blue: f64) -> HexColor {
+    let r = (red.clamp(0.0, 1.0) * 255.0).round() as u32;
+    let g = (green.clamp(0.0, 1.0) * 255.0).round() as u32;
+    let b = (blue.clamp(0.0, 1.0) * 255.0).round() as u32;
+    (r << 16) | (g << 8) | b
+}
+
+/// Resolve the effective accent-color override from a `theme.accent` config
+/// value (`"system"`, an explicit `"#rrggbb"` hex string, or unset).
+///
+/// Unset and `"system"` both mean "follow the macOS system accent color".
+/// Anything else is parsed as an explicit hex override; an invalid value is
+/// logged and ignored so the existing theme colors are left untouched.
+pub fn resolve_accent_override(configured: Option<&str>) -> Option<HexColor> {
+    match configured {
+        None | Some("system") => read_system_accent_color(),
+        Some(hex) => match hex_color_serde::parse_color_string(hex) {
+            Ok(color) => Some(color),
+            Err(e) => {
+                warn!(value = hex, error = %e, "Invalid theme.accent override, ignoring");
+                None
+            }
+        },
+    }
+}
+
+/// Apply `theme.accent` config overrides to an already-loaded theme.
+///
+/// Overrides both `accent.selected` (buttons, focus borders, highlighted
+/// text) and `accent.selected_subtle` (list selection highlight background)
+/// so a pinned custom accent - not just the system-derived default - is
+/// threaded through everywhere the theme's accent tokens are used.
+pub fn apply_accent_override(theme: &mut Theme, configured: Option<&str>) {
+    if let Some(accent) = resolve_accent_override(configured) {
+        theme.colors.accent.selected = accent;
+        theme.colors.accent.selected_subtle = accent;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb_components_to_hex_black() {
+        assert_eq!(rgb_components_to_hex(0.0, 0.0, 0.0), 0x000000);
+    }
+
+    #[test]
+    fn test_rgb_components_to_hex_white() {
+        assert_eq!(rgb_components_to_hex(1.0, 1.0, 1.0), 0xFFFFFF);
+    }
+
+    #[test]
+    fn test_rgb_components_to_hex_known_value() {
+        // macOS "blue" control accent is approximately RGB(0, 122, 255)
+        assert_eq!(rgb_components_to_hex(0.0, 122.0 / 255.0, 1.0), 0x007AFF);
+    }
+
+    #[test]
+    fn test_rgb_components_to_hex_clamps_out_of_range() {
+        assert_eq!(rgb_components_to_hex(-0.5, 2.0, 0.5), 0x00FF80);
+    }
+
+    #[test]
+    fn test_resolve_accent_override_unset_follows_system() {
+        // On non-macOS test runners this resolves to None; the important
+        // behavior under test is that "unset" takes the same path as
+        // "system" rather than falling through to hex parsing.
+        assert_eq!(resolve_accent_override(None), read_system_accent_color());
+    }
+
+    #[test]
+    fn test_resolve_accent_override_system_keyword() {
+        assert_eq!(
+            resolve_accent_override(Some("system")),
+            read_system_accent_color()
+        );
+    }
+
+    #[test]
+    fn test_resolve_accent_override_explicit_hex() {
+        assert_eq!(resolve_accent_override(Some("#ff0000")), Some(0xff0000));
+    }
+
+    #[test]
+    fn test_resolve_accent_override_invalid_hex_is_ignored() {
+        assert_eq!(resolve_accent_override(Some("not-a-color")), None);
+    }
+
+    #[test]
+    fn test_apply_accent_override_updates_selected_and_subtle() {
+        let mut theme = Theme::default();
+        apply_accent_override(&mut theme, Some("#ff0000"));
+        assert_eq!(theme.colors.accent.selected, 0xff0000);
+        assert_eq!(theme.colors.accent.selected_subtle, 0xff0000);
+    }
+
+    #[test]
+    fn test_apply_accent_override_invalid_hex_leaves_theme_unchanged() {
+        let theme = Theme::default();
+        let mut overridden = theme.clone();
+        apply_accent_override(&mut overridden, Some("not-a-color"));
+        assert_eq!(overridden.colors.accent.selected, theme.colors.accent.selected);
+        assert_eq!(
+            overridden.colors.accent.selected_subtle,
+            theme.colors.accent.selected_subtle
+        );
+    }
+}
@@ -13,6 +13,8 @@ use std::process::Command;
 use tracing::{debug, error, info, warn};
 
 use super::hex_color::{hex_color_serde, HexColor};
+use super::system_accent;
+use super::validation::{self, DiagnosticSeverity};
 
 /// Background opacity settings for window transparency
 /// Values range from 0.0 (fully transparent) to 1.0 (fully opaque)
@@ -929,6 +931,23 @@ impl Theme {
         self.get_vibrancy().enabled
     }
 
+    /// Apply `config.ts`'s `window.vibrancy`/`window.opacity` overrides on
+    /// top of whatever `theme.json` already specifies, the same way
+    /// `theme.accent` overrides the theme's accent color. Either argument
+    /// may be `None` to leave the corresponding theme.json setting as-is.
+    pub fn apply_window_config_overrides(&mut self, vibrancy: Option<bool>, opacity: Option<f32>) {
+        if let Some(enabled) = vibrancy {
+            self.vibrancy
+                .get_or_insert_with(VibrancySettings::default)
+                .enabled = enabled;
+        }
+        if let Some(main) = opacity {
+            self.opacity
+                .get_or_insert_with(BackgroundOpacity::default)
+                .main = main;
+        }
+    }
+
     /// Get font configuration
     /// Returns the configured fonts or sensible defaults
     pub fn get_fonts(&self) -> FontConfig {
@@ -1065,11 +1084,12 @@ pub fn load_theme() -> Theme {
         warn!(path = %theme_path.display(), "Theme file not found, using defaults based on system appearance");
         // Auto-select based on system appearance
         let is_dark = detect_system_appearance();
-        let color_scheme = if is_dark {
+        let mut color_scheme = if is_dark {
             ColorScheme::dark_default()
         } else {
             ColorScheme::light_default()
         };
+        apply_system_accent(&mut color_scheme);
         let theme = Theme {
             focus_aware: None,
             colors: color_scheme,
@@ -1087,11 +1107,12 @@ pub fn load_theme() -> Theme {
         Err(e) => {
             error!(path = %theme_path.display(), error = %e, "Failed to read theme file, using defaults");
             let is_dark = detect_system_appearance();
-            let color_scheme = if is_dark {
+            let mut color_scheme = if is_dark {
                 ColorScheme::dark_default()
             } else {
                 ColorScheme::light_default()
             };
+            apply_system_accent(&mut color_scheme);
             let theme = Theme {
                 colors: color_scheme,
                 focus_aware: None,
@@ -1103,43 +1124,74 @@ pub fn load_theme() -> Theme {
             log_theme_config(&theme);
             theme
         }
-        Ok(contents) => match serde_json::from_str::<Theme>(&contents) {
-            Ok(theme) => {
-                debug!(path = %theme_path.display(), "Successfully loaded theme");
-                log_theme_config(&theme);
-                theme
+        Ok(contents) => {
+            if let Err(diagnostics) = validation::validate(&theme_path) {
+                for diag in &diagnostics {
+                    match diag.severity {
+                        DiagnosticSeverity::Error => {
+                            error!(path = %diag.path, message = %diag.message, "Theme validation error")
+                        }
+                        DiagnosticSeverity::Warning => {
+                            warn!(path = %diag.path, message = %diag.message, "Theme validation warning")
+                        }
+                        DiagnosticSeverity::Info => {
+                            debug!(path = %diag.path, message = %diag.message, "Theme validation note")
+                        }
+                    }
+                }
             }
-            Err(e) => {
-                error!(
-                    path = %theme_path.display(),
-                    error = %e,
-                    "Failed to parse theme JSON, using defaults"
-                );
-                debug!(content = %contents, "Malformed theme file content");
-                let is_dark = detect_system_appearance();
-                let color_scheme = if is_dark {
-                    ColorScheme::dark_default()
-                } else {
-                    ColorScheme::light_default()
-                };
-                let theme = Theme {
-                    colors: color_scheme,
-                    focus_aware: None,
-                    opacity: Some(BackgroundOpacity::default()),
-                    drop_shadow: Some(DropShadow::default()),
-                    vibrancy: Some(VibrancySettings::default()),
-                    fonts: Some(FontConfig::default()),
-                };
-                log_theme_config(&theme);
-                theme
+            match serde_json::from_str::<Theme>(&contents) {
+                Ok(theme) => {
+                    debug!(path = %theme_path.display(), "Successfully loaded theme");
+                    log_theme_config(&theme);
+                    theme
+                }
+                Err(e) => {
+                    error!(
+                        path = %theme_path.display(),
+                        error = %e,
+                        "Failed to parse theme JSON, using defaults"
+                    );
+                    debug!(content = %contents, "Malformed theme file content");
+                    let is_dark = detect_system_appearance();
+                    let mut color_scheme = if is_dark {
+                        ColorScheme::dark_default()
+                    } else {
+                        ColorScheme::light_default()
+                    };
+                    apply_system_accent(&mut color_scheme);
+                    let theme = Theme {
+                        colors: color_scheme,
+                        focus_aware: None,
+                        opacity: Some(BackgroundOpacity::default()),
+                        drop_shadow: Some(DropShadow::default()),
+                        vibrancy: Some(VibrancySettings::default()),
+                        fonts: Some(FontConfig::default()),
+                    };
+                    log_theme_config(&theme);
+                    theme
+                }
             }
-        },
+        }
     }
 }
 // ============================================================================
 // End Lightweight Theme Extraction Helpers
 // ============================================================================
 
+/// Overlay the macOS system accent/highlight colors onto a default color
+/// scheme, if available, so the out-of-the-box theme doesn't clash with the
+/// user's OS-level color choice. A no-op when the system color can't be read
+/// (non-macOS, headless environment, etc.) - the hardcoded defaults stand.
+fn apply_system_accent(color_scheme: &mut ColorScheme) {
+    if let Some(accent) = system_accent::read_system_accent_color() {
+        color_scheme.accent.selected = accent;
+    }
+    if let Some(highlight) = system_accent::read_system_highlight_color() {
+        color_scheme.accent.selected_subtle = highlight;
+    }
+}
+
 /// Log theme configuration for debugging
 fn log_theme_config(theme: &Theme) {
     let opacity = theme.get_opacity();
@@ -12,6 +12,7 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashSet;
+use std::path::Path;
 
 // ============================================================================
 // Diagnostic types
@@ -233,6 +234,28 @@ pub fn validate_theme_json(json: &Value) -> ThemeDiagnostics {
     diags
 }
 
+/// Validate a theme file on disk, returning every diagnostic found.
+///
+/// Reads and parses `path`, then runs the same checks as
+/// [`validate_theme_json`] (named color tokens, opacity ranges, vibrancy
+/// materials, unknown keys, ...). `Ok(())` means the file parsed cleanly with
+/// nothing to report; `Err` carries the full diagnostic list - including
+/// warnings - so a typo like `"#gggggg"` is reported by path (e.g.
+/// `/colors/accent/selected`) instead of silently falling back to a default
+/// color.
+pub fn validate(path: &Path) -> Result<(), Vec<Diagnostic>> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| vec![Diagnostic::error("", format!("Failed to read theme file: {}", e))])?;
+    let json: Value = serde_json::from_str(&contents)
+        .map_err(|e| vec![Diagnostic::error("", format!("Invalid JSON: {}", e))])?;
+    let diags = validate_theme_json(&json);
+    if diags.diagnostics.is_empty() {
+        Ok(())
+    } else {
+        Err(diags.diagnostics)
+    }
+}
+
 fn check_unknown_keys<'a>(
     diags: &mut ThemeDiagnostics,
     parent_path: &str,
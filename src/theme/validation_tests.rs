@@ -2,6 +2,8 @@
 
 use super::validation::*;
 use serde_json::json;
+use std::fs;
+use tempfile::tempdir;
 
 #[test]
 fn test_diagnostic_creation() {
@@ -229,3 +231,33 @@ fn test_severity_ordering() {
     assert!(DiagnosticSeverity::Error < DiagnosticSeverity::Warning);
     assert!(DiagnosticSeverity::Warning < DiagnosticSeverity::Info);
 }
+
+#[test]
+fn test_validate_reports_invalid_color_by_path() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("theme.json");
+    fs::write(&path, r#"{"colors": {"accent": {"selected": "#gggggg"}}}"#).unwrap();
+
+    let diagnostics = validate(&path).expect_err("invalid hex color should fail validation");
+    assert!(diagnostics
+        .iter()
+        .any(|d| d.path == "/colors/accent/selected" && d.severity == DiagnosticSeverity::Error));
+}
+
+#[test]
+fn test_validate_ok_for_clean_theme() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("theme.json");
+    fs::write(&path, r#"{"colors": {"accent": {"selected": "#3B82F6"}}}"#).unwrap();
+
+    assert!(validate(&path).is_ok());
+}
+
+#[test]
+fn test_validate_missing_file() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("does-not-exist.json");
+    let diagnostics = validate(&path).expect_err("missing file should fail validation");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+}
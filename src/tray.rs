@@ -4,6 +4,8 @@
 //! The icon uses the Script Kit logo rendered as a template image for proper
 //! light/dark mode adaptation.
 
+use std::sync::OnceLock;
+
 use anyhow::{bail, Context, Result};
 use tracing::warn;
 use tray_icon::{
@@ -16,6 +18,58 @@ use tray_icon::{
 
 use crate::login_item;
 
+/// Maximum number of user-script items shown directly in the "Scripts" tray
+/// submenu before the rest are collapsed behind a "More…" item.
+const MAX_SCRIPT_TRAY_ITEMS: usize = 10;
+
+/// ID prefix for dynamic script tray items, followed by the script's file path.
+/// Not part of `TrayMenuAction` since these IDs are generated per-path at
+/// runtime rather than being a fixed, known-ahead-of-time set.
+pub const TRAY_SCRIPT_ID_PREFIX: &str = "tray.script:";
+
+/// ID for the "More…" item shown when there are more tray-tagged scripts
+/// than `MAX_SCRIPT_TRAY_ITEMS`.
+pub const TRAY_SCRIPT_MORE_ID: &str = "tray.script.more";
+
+/// A script tagged `// Tray: true` (or typed `metadata.tray = true`),
+/// rendered as an item in the tray's "Scripts" submenu.
+#[derive(Debug, Clone)]
+pub struct ScriptTrayEntry {
+    /// Display label shown in the tray menu (the script's name).
+    pub label: String,
+    /// File path sent to `script_hotkey_channel` to launch the script.
+    pub path: String,
+}
+
+/// Channel used to push the latest set of tray-tagged scripts from
+/// `ScriptListApp` (whenever scripts load or reload) over to the tray menu
+/// event loop, which owns the actual `TrayManager` and rebuilds its
+/// "Scripts" submenu from the most recent entries it finds waiting.
+#[allow(dead_code)]
+static TRAY_SCRIPT_REFRESH_CHANNEL: OnceLock<(
+    async_channel::Sender<Vec<ScriptTrayEntry>>,
+    async_channel::Receiver<Vec<ScriptTrayEntry>>,
+)> = OnceLock::new();
+
+/// Get the tray script refresh channel, initializing it on first access.
+#[allow(dead_code)]
+pub(crate) fn tray_script_refresh_channel() -> &'static (
+    async_channel::Sender<Vec<ScriptTrayEntry>>,
+    async_channel::Receiver<Vec<ScriptTrayEntry>>,
+) {
+    TRAY_SCRIPT_REFRESH_CHANNEL.get_or_init(|| async_channel::bounded(1))
+}
+
+/// Publish the latest set of tray-tagged scripts. Drops any entry left over
+/// from a previous publish that the tray loop hasn't picked up yet - only
+/// the most recent set matters.
+#[allow(dead_code)]
+pub(crate) fn publish_script_entries(entries: Vec<ScriptTrayEntry>) {
+    let (tx, rx) = tray_script_refresh_channel();
+    while rx.try_recv().is_ok() {}
+    let _ = tx.try_send(entries);
+}
+
 /// Renders an SVG string to RGBA pixel data with validation.
 ///
 /// # Arguments
@@ -76,6 +130,15 @@ const LOGO_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="32" hei
   <path fill="currentColor" d="M14 25a2 2 0 0 1 2-2h14a2 2 0 1 1 0 4H16a2 2 0 0 1-2-2ZM0 7.381c0-1.796 1.983-2.884 3.498-1.92l13.728 8.736c1.406.895 1.406 2.946 0 3.84L3.498 26.775C1.983 27.738 0 26.649 0 24.854V7.38Z"/>
 </svg>"#;
 
+/// Logo variant shown in the menu bar while hotkeys/expansion are paused -
+/// the regular logo dimmed behind a pause glyph, so the paused state is
+/// visible at a glance without opening the menu.
+const LOGO_PAUSED_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="32" height="32" fill="currentColor" viewBox="0 0 32 32">
+  <path fill="currentColor" opacity="0.35" d="M14 25a2 2 0 0 1 2-2h14a2 2 0 1 1 0 4H16a2 2 0 0 1-2-2ZM0 7.381c0-1.796 1.983-2.884 3.498-1.92l13.728 8.736c1.406.895 1.406 2.946 0 3.84L3.498 26.775C1.983 27.738 0 26.649 0 24.854V7.38Z"/>
+  <rect x="9" y="9" width="5" height="16" rx="1.5" fill="currentColor"/>
+  <rect x="18" y="9" width="5" height="16" rx="1.5" fill="currentColor"/>
+</svg>"#;
+
 // Menu item SVG icons (16x16, white outline style for dark menus)
 // These are rendered as white icons for macOS dark mode menu bar
 
@@ -132,6 +195,7 @@ pub enum TrayMenuAction {
     FollowUs,
     Settings,
     LaunchAtLogin,
+    TogglePause,
     Quit,
 }
 
@@ -149,6 +213,7 @@ impl TrayMenuAction {
             Self::FollowUs => "tray.follow_us",
             Self::Settings => "tray.settings",
             Self::LaunchAtLogin => "tray.launch_at_login",
+            Self::TogglePause => "tray.toggle_pause",
             Self::Quit => "tray.quit",
         }
     }
@@ -166,6 +231,7 @@ impl TrayMenuAction {
             "tray.follow_us" => Some(Self::FollowUs),
             "tray.settings" => Some(Self::Settings),
             "tray.launch_at_login" => Some(Self::LaunchAtLogin),
+            "tray.toggle_pause" => Some(Self::TogglePause),
             "tray.quit" => Some(Self::Quit),
             _ => None,
         }
@@ -184,6 +250,7 @@ impl TrayMenuAction {
             Self::FollowUs,
             Self::Settings,
             Self::LaunchAtLogin,
+            Self::TogglePause,
             Self::Quit,
         ]
     }
@@ -191,10 +258,18 @@ impl TrayMenuAction {
 
 /// Manages the system tray icon and menu
 pub struct TrayManager {
-    #[allow(dead_code)]
     tray_icon: TrayIcon,
     /// The "Launch at Login" checkbox, stored for updating its checked state
     launch_at_login_item: CheckMenuItem,
+    /// The "Pause" checkbox, stored for updating its checked state to match
+    /// the global `HOTKEYS_PAUSED` flag.
+    pause_item: CheckMenuItem,
+    /// The "Scripts" submenu, populated from scripts tagged `// Tray: true`.
+    /// Starts empty and is rebuilt by `rebuild_script_menu` as scripts load.
+    scripts_submenu: Submenu,
+    /// Items currently appended to `scripts_submenu`, tracked so they can be
+    /// removed before the next rebuild.
+    script_items: Vec<MenuItem>,
 }
 
 impl TrayManager {
@@ -206,8 +281,13 @@ impl TrayManager {
     /// - PNG rendering fails
     /// - Tray icon creation fails
     pub fn new() -> Result<Self> {
-        let icon = Self::create_icon_from_svg()?;
-        let (menu, launch_at_login_item) = Self::create_menu()?;
+        let starting_svg = if crate::is_paused() {
+            LOGO_PAUSED_SVG
+        } else {
+            LOGO_SVG
+        };
+        let icon = Self::create_icon_from_svg(starting_svg)?;
+        let (menu, launch_at_login_item, pause_item, scripts_submenu) = Self::create_menu()?;
 
         let mut builder = TrayIconBuilder::new()
             .with_icon(icon)
@@ -225,23 +305,27 @@ impl TrayManager {
         Ok(Self {
             tray_icon,
             launch_at_login_item,
+            pause_item,
+            scripts_submenu,
+            script_items: Vec::new(),
         })
     }
 
-    /// Converts the embedded SVG logo to a tray icon.
+    /// Converts the given embedded SVG to a tray icon (e.g. the regular or
+    /// paused logo).
     ///
     /// Uses `render_svg_to_rgba` for validated rendering.
-    fn create_icon_from_svg() -> Result<Icon> {
+    fn create_icon_from_svg(svg: &str) -> Result<Icon> {
         // Get dimensions from SVG (logo is 32x32)
         let opts = usvg::Options::default();
-        let tree = usvg::Tree::from_str(LOGO_SVG, &opts).context("Failed to parse logo SVG")?;
+        let tree = usvg::Tree::from_str(svg, &opts).context("Failed to parse logo SVG")?;
         let size = tree.size();
         let width = size.width() as u32;
         let height = size.height() as u32;
 
         // Render with validation
-        let rgba = render_svg_to_rgba(LOGO_SVG, width, height)
-            .context("Failed to render tray logo SVG")?;
+        let rgba =
+            render_svg_to_rgba(svg, width, height).context("Failed to render tray logo SVG")?;
 
         // Create tray icon from RGBA data
         Icon::from_rgba(rgba, width, height).context("Failed to create tray icon from RGBA data")
@@ -270,21 +354,24 @@ impl TrayManager {
     /// Menu structure (Raycast-style):
     /// 1. Open Script Kit
     /// 2. ---
-    /// 3. Open Notes
-    /// 4. Open AI Chat
-    /// 5. ---
-    /// 6. Open on GitHub
-    /// 7. Manual
-    /// 8. Join Community
-    /// 9. Follow Us
-    /// 10. ---
-    /// 11. Settings
-    /// 12. ---
-    /// 13. Launch at Login (checkmark)
-    /// 14. Version X.Y.Z (disabled)
+    /// 3. Pause (checkmark) - global kill switch for hotkeys/text expansion
+    /// 4. ---
+    /// 5. Open Notes
+    /// 6. Open AI Chat
+    /// 7. Scripts (submenu, populated from `// Tray: true` scripts)
+    /// 8. ---
+    /// 9. Open on GitHub
+    /// 10. Manual
+    /// 11. Join Community
+    /// 12. Follow Us
+    /// 13. ---
+    /// 14. Settings
     /// 15. ---
-    /// 16. Quit Script Kit
-    fn create_menu() -> Result<(Box<dyn ContextMenu>, CheckMenuItem)> {
+    /// 16. Launch at Login (checkmark)
+    /// 17. Version X.Y.Z (disabled)
+    /// 18. ---
+    /// 19. Quit Script Kit
+    fn create_menu() -> Result<(Box<dyn ContextMenu>, CheckMenuItem, CheckMenuItem, Submenu)> {
         // Use Submenu as context menu root - works cross-platform
         // (Menu::append only allows Submenu on macOS, but Submenu::append allows any item)
         let menu = Submenu::with_id("tray.root", "Script Kit", true);
@@ -323,6 +410,10 @@ impl TrayManager {
             None,
         );
 
+        // Scripts submenu - starts empty, populated by `rebuild_script_menu`
+        // once scripts tagged `// Tray: true` are loaded.
+        let scripts_submenu = Submenu::with_id("tray.scripts_root", "Scripts", true);
+
         // External links
         let open_on_github_item = IconMenuItem::with_id(
             TrayMenuAction::OpenOnGitHub.id(),
@@ -371,6 +462,20 @@ impl TrayManager {
             None, // no accelerator
         );
 
+        // Global kill switch for hotkeys and text expansion, checked against
+        // the current `HOTKEYS_PAUSED` flag in case this menu is being
+        // rebuilt after the app already set it (e.g. restored from a prior
+        // run's state - currently always false on startup, but reading the
+        // flag here rather than hardcoding `false` keeps this item honest if
+        // that ever changes).
+        let pause_item = CheckMenuItem::with_id(
+            TrayMenuAction::TogglePause.id(),
+            "Pause",
+            true, // enabled
+            crate::is_paused(),
+            None, // no accelerator
+        );
+
         // Version display (disabled, informational only)
         let version_item = MenuItem::new(
             format!("Version {}", env!("CARGO_PKG_VERSION")),
@@ -392,11 +497,19 @@ impl TrayManager {
         menu.append(&PredefinedMenuItem::separator())
             .context("Failed to add separator")?;
 
+        // Section 1.5: Global kill switch
+        menu.append(&pause_item)
+            .context("Failed to add Pause item")?;
+        menu.append(&PredefinedMenuItem::separator())
+            .context("Failed to add separator")?;
+
         // Section 2: App features
         menu.append(&open_notes_item)
             .context("Failed to add Open Notes item")?;
         menu.append(&open_ai_chat_item)
             .context("Failed to add Open AI Chat item")?;
+        menu.append(&scripts_submenu)
+            .context("Failed to add Scripts submenu")?;
         menu.append(&PredefinedMenuItem::separator())
             .context("Failed to add separator")?;
 
@@ -429,7 +542,51 @@ impl TrayManager {
         // Section 6: Quit
         menu.append(&quit_item).context("Failed to add Quit item")?;
 
-        Ok((Box::new(menu), launch_at_login_item))
+        Ok((
+            Box::new(menu),
+            launch_at_login_item,
+            pause_item,
+            scripts_submenu,
+        ))
+    }
+
+    /// Rebuilds the "Scripts" submenu from the given tray-tagged scripts.
+    ///
+    /// Shows at most `MAX_SCRIPT_TRAY_ITEMS` entries (in the order given);
+    /// if there are more, appends a "More…" item (`TRAY_SCRIPT_MORE_ID`)
+    /// that the caller should handle by showing the full script list.
+    ///
+    /// # Errors
+    /// Returns an error if removing a stale item or appending a new one fails.
+    pub fn rebuild_script_menu(&mut self, entries: &[ScriptTrayEntry]) -> Result<()> {
+        for item in self.script_items.drain(..) {
+            self.scripts_submenu
+                .remove(&item)
+                .context("Failed to remove stale script tray item")?;
+        }
+
+        for entry in entries.iter().take(MAX_SCRIPT_TRAY_ITEMS) {
+            let item = MenuItem::with_id(
+                format!("{}{}", TRAY_SCRIPT_ID_PREFIX, entry.path),
+                &entry.label,
+                true,
+                None,
+            );
+            self.scripts_submenu
+                .append(&item)
+                .context("Failed to add script tray item")?;
+            self.script_items.push(item);
+        }
+
+        if entries.len() > MAX_SCRIPT_TRAY_ITEMS {
+            let more_item = MenuItem::with_id(TRAY_SCRIPT_MORE_ID, "More…", true, None);
+            self.scripts_submenu
+                .append(&more_item)
+                .context("Failed to add More item")?;
+            self.script_items.push(more_item);
+        }
+
+        Ok(())
     }
 
     /// Returns the menu event receiver for handling menu clicks.
@@ -450,17 +607,28 @@ impl TrayManager {
 
     /// Handles any side effects for a menu action.
     ///
-    /// Currently only `LaunchAtLogin` has side effects (toggling the OS setting
-    /// and updating the checkbox).
+    /// `LaunchAtLogin` toggles the OS setting and updates its checkbox;
+    /// `TogglePause` flips the global hotkeys/expansion kill switch, updates
+    /// its checkbox, and swaps the menu bar icon to the paused variant.
     ///
     /// # Errors
-    /// Returns an error if the action's side effect fails (e.g., login item toggle).
+    /// Returns an error if the action's side effect fails (e.g., login item
+    /// toggle, or re-rendering the tray icon).
     pub fn handle_action(&self, action: TrayMenuAction) -> Result<()> {
         if action == TrayMenuAction::LaunchAtLogin {
             // Toggle login item then re-read state from OS (never trust "intended" state)
             login_item::toggle_login_item().context("Failed to toggle login item")?;
             self.refresh_launch_at_login_checkmark();
         }
+        if action == TrayMenuAction::TogglePause {
+            let paused = crate::toggle_paused();
+            self.pause_item.set_checked(paused);
+            let svg = if paused { LOGO_PAUSED_SVG } else { LOGO_SVG };
+            let icon = Self::create_icon_from_svg(svg).context("Failed to render tray icon")?;
+            self.tray_icon
+                .set_icon(Some(icon))
+                .context("Failed to set tray icon")?;
+        }
         // Other actions have no side effects in TrayManager
         Ok(())
     }
@@ -542,7 +710,7 @@ mod tests {
     #[test]
     fn test_tray_menu_action_all_count() {
         // Verify all() returns all variants
-        assert_eq!(TrayMenuAction::all().len(), 10);
+        assert_eq!(TrayMenuAction::all().len(), 11);
     }
 
     // ========================================================================
@@ -599,6 +767,16 @@ mod tests {
         assert!(result.is_ok(), "Logo SVG should render: {:?}", result);
     }
 
+    #[test]
+    fn test_render_svg_to_rgba_paused_logo_renders() {
+        let result = render_svg_to_rgba(LOGO_PAUSED_SVG, 32, 32);
+        assert!(
+            result.is_ok(),
+            "Paused logo SVG should render: {:?}",
+            result
+        );
+    }
+
     #[test]
     fn test_render_svg_to_rgba_menu_icons_render() {
         // Test all menu icon SVGs render successfully
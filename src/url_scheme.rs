@@ -0,0 +1,597 @@
+//! `scriptkit://` URL scheme parsing and dispatch.
+//!
+//! Other apps, browser bookmarks, and `open scriptkit://...` from a shell
+//! can trigger the app through a custom URL scheme. Registering the scheme
+//! itself is an Info.plist entry (configuration, not code); this module is
+//! the handling side - the OS hands us a raw URL string via an Apple Event
+//! (`kAEGetURL`) on macOS, and we turn it into one of the same
+//! [`ExternalCommand`] variants the stdin protocol already uses.
+//!
+//! The module is split in two so the interesting logic can be unit tested
+//! without a running NSApplication:
+//! - [`parse_url`] and [`to_external_command`] are pure functions.
+//! - [`UrlEventSource`] is a thin trait over the actual OS "open URL" hook;
+//!   production code registers the real Apple Event handler, tests can
+//!   register a fake one and fire URLs directly.
+//!
+//! Supported URLs (anything else is rejected with [`UrlSchemeError`] and
+//! logged, never silently ignored; a rejected `scriptkit://` URL - as
+//! opposed to one that wasn't ours to begin with - also surfaces an error
+//! toast via [`ExternalCommand::ShowErrorToast`], see [`handle_incoming_url`]):
+//! - `scriptkit://run?script=<name>&arg=<value>` - run a script resolved by
+//!   name or alias. `arg` may repeat; order is preserved.
+//! - `scriptkit://show` - show the main window.
+//! - `scriptkit://builtin?name=<name>` - trigger a built-in.
+
+use crate::stdin_commands::ExternalCommand;
+use thiserror::Error;
+
+/// A parsed `scriptkit://` URL, before a script name has been resolved to a
+/// path (resolution needs the live script registry, so it happens in
+/// [`to_external_command`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptKitUrl {
+    /// `scriptkit://run?script=<name>&arg=<value>`
+    Run { script: String, args: Vec<String> },
+    /// `scriptkit://show`
+    Show,
+    /// `scriptkit://builtin?name=<name>`
+    Builtin { name: String },
+}
+
+/// Why a `scriptkit://` URL was rejected.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum UrlSchemeError {
+    #[error("not a scriptkit:// URL: {0}")]
+    UnsupportedScheme(String),
+    #[error("unknown scriptkit:// action: {0}")]
+    UnsupportedAction(String),
+    #[error("missing required parameter '{0}'")]
+    MissingParam(&'static str),
+    #[error("unknown parameter '{0}' for scriptkit://{1}")]
+    UnknownParam(String, String),
+    #[error("unknown script: {0}")]
+    UnknownScript(String),
+}
+
+const SCHEME_PREFIX: &str = "scriptkit://";
+
+/// Parse a raw `scriptkit://` URL into a [`ScriptKitUrl`].
+///
+/// The action (`run` / `show` / `builtin`) is the URL's host; its query
+/// string is percent-decoded and checked against a strict per-action
+/// whitelist of parameter names - an unrecognized parameter is rejected
+/// rather than ignored, so a typo'd query string fails loudly instead of
+/// silently doing nothing.
+pub fn parse_url(raw: &str) -> Result<ScriptKitUrl, UrlSchemeError> {
+    let rest = raw
+        .strip_prefix(SCHEME_PREFIX)
+        .ok_or_else(|| UrlSchemeError::UnsupportedScheme(raw.to_string()))?;
+
+    let (action, query) = match rest.split_once('?') {
+        Some((action, query)) => (action, query),
+        None => (rest, ""),
+    };
+    // Tolerate a trailing slash, e.g. `scriptkit://show/`.
+    let action = action.trim_end_matches('/');
+    let params = parse_query(query)?;
+
+    match action {
+        "run" => {
+            let mut script = None;
+            let mut args = Vec::new();
+            for (key, value) in params {
+                match key.as_str() {
+                    "script" => script = Some(value),
+                    "arg" => args.push(value),
+                    other => {
+                        return Err(UrlSchemeError::UnknownParam(
+                            other.to_string(),
+                            "run".into(),
+                        ))
+                    }
+                }
+            }
+            let script = script.ok_or(UrlSchemeError::MissingParam("script"))?;
+            Ok(ScriptKitUrl::Run { script, args })
+        }
+        "show" => {
+            if let Some((key, _)) = params.into_iter().next() {
+                return Err(UrlSchemeError::UnknownParam(key, "show".into()));
+            }
+            Ok(ScriptKitUrl::Show)
+        }
+        "builtin" => {
+            let mut name = None;
+            for (key, value) in params {
+                match key.as_str() {
+                    "name" => name = Some(value),
+                    other => {
+                        return Err(UrlSchemeError::UnknownParam(
+                            other.to_string(),
+                            "builtin".into(),
+                        ))
+                    }
+                }
+            }
+            let name = name.ok_or(UrlSchemeError::MissingParam("name"))?;
+            Ok(ScriptKitUrl::Builtin { name })
+        }
+        other => Err(UrlSchemeError::UnsupportedAction(other.to_string())),
+    }
+}
+
+/// Parse and percent-decode a `key=value&key=value` query string.
+fn parse_query(query: &str) -> Result<Vec<(String, String)>, UrlSchemeError> {
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            Ok((percent_decode(key), percent_decode(value)))
+        })
+        .collect()
+}
+
+/// Percent-decode a URL component, treating `+` as a literal (we don't use
+/// `application/x-www-form-urlencoded` space encoding here). Malformed
+/// escapes (a trailing `%`, or non-hex digits) are passed through verbatim
+/// rather than rejected - callers still see garbage, not a panic.
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+            if let Some(byte) = hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Turn a parsed [`ScriptKitUrl`] into the same [`ExternalCommand`] the
+/// stdin protocol uses, so the main event loop only needs one dispatch path.
+///
+/// `resolve_script` looks up a script's path by name or alias (typically
+/// backed by `scripts::read_scripts()`); `Run` fails with
+/// [`UrlSchemeError::UnknownScript`] when it returns `None`, which callers
+/// should surface as an error toast per the concurrency/error conventions
+/// used for the rest of script launching.
+pub fn to_external_command(
+    url: ScriptKitUrl,
+    resolve_script: impl FnOnce(&str) -> Option<String>,
+) -> Result<ExternalCommand, UrlSchemeError> {
+    match url {
+        ScriptKitUrl::Run { script, args } => {
+            let path = resolve_script(&script).ok_or(UrlSchemeError::UnknownScript(script))?;
+            Ok(ExternalCommand::Run {
+                path,
+                args,
+                request_id: None,
+            })
+        }
+        ScriptKitUrl::Show => Ok(ExternalCommand::Show { request_id: None }),
+        ScriptKitUrl::Builtin { name } => Ok(ExternalCommand::TriggerBuiltin { name }),
+    }
+}
+
+/// Abstraction over the OS-level "the app was asked to open a URL" event, so
+/// the parsing/dispatch logic above can be exercised without a running
+/// NSApplication (registering a real Apple Event handler works once per
+/// process and requires an active app instance).
+///
+/// The real macOS implementation is [`macos::AppleEventUrlSource`]; tests
+/// use a fake that stores the callback and invokes it directly.
+pub trait UrlEventSource {
+    /// Register `on_url` to be called with the raw URL string each time the
+    /// OS delivers an "open URL" event for our scheme. Implementations may
+    /// only support a single registered callback.
+    fn register(&self, on_url: Box<dyn Fn(String) + Send + 'static>);
+}
+
+/// Parse `raw_url`, resolve it against `resolve_script`, and hand the
+/// resulting [`ExternalCommand`] to `dispatch` - or log and drop it if the
+/// URL was rejected. This is the glue `register`'s callback runs on every
+/// incoming URL; factored out so [`register_url_scheme_handler`] stays thin.
+pub fn handle_incoming_url(
+    raw_url: &str,
+    resolve_script: impl FnOnce(&str) -> Option<String>,
+    dispatch: impl FnOnce(ExternalCommand),
+) {
+    match parse_url(raw_url).and_then(|url| to_external_command(url, resolve_script)) {
+        Ok(cmd) => dispatch(cmd),
+        // Not a `scriptkit://` URL at all - nothing that was meant for us was
+        // rejected, so there's nothing worth surfacing to the user.
+        Err(e @ UrlSchemeError::UnsupportedScheme(_)) => {
+            crate::logging::log(
+                "URL",
+                &format!("Rejected incoming URL '{}': {}", raw_url, e),
+            );
+        }
+        // A real `scriptkit://` URL that we couldn't act on (unknown script,
+        // bad/missing params, etc.) - log it, and also report it through the
+        // same dispatch path so the caller can show an error toast instead of
+        // leaving the user wondering why nothing happened.
+        Err(e) => {
+            crate::logging::log(
+                "URL",
+                &format!("Rejected incoming URL '{}': {}", raw_url, e),
+            );
+            dispatch(ExternalCommand::ShowErrorToast {
+                message: format!("scriptkit:// link failed: {}", e),
+            });
+        }
+    }
+}
+
+/// Wire a [`UrlEventSource`] up to `tx`, the same channel the stdin listener
+/// feeds - so `scriptkit://` URLs and stdin JSONL commands flow through one
+/// dispatch path in the main event loop.
+pub fn register_url_scheme_handler(
+    source: &dyn UrlEventSource,
+    tx: async_channel::Sender<ExternalCommand>,
+    resolve_script: impl Fn(&str) -> Option<String> + Send + 'static,
+) {
+    source.register(Box::new(move |raw_url| {
+        handle_incoming_url(
+            &raw_url,
+            |name| resolve_script(name),
+            |cmd| {
+                if tx.send_blocking(cmd).is_err() {
+                    crate::logging::log("URL", "Command channel closed, dropping URL event");
+                }
+            },
+        );
+    }));
+}
+
+/// Real macOS "open URL" event source, backed by an Apple Event (`GURL`)
+/// handler registered on the shared `NSAppleEventManager`.
+#[cfg(target_os = "macos")]
+pub mod macos {
+    use super::UrlEventSource;
+    use cocoa::base::{id, nil};
+    use objc::declare::ClassDecl;
+    use objc::runtime::{Object, Sel};
+    use objc::{class, msg_send, sel, sel_impl};
+    use std::sync::{Mutex, OnceLock};
+
+    /// `kInternetEventClass` / `kAEGetURL`, both the four-char code `'GURL'`.
+    const K_GURL: u32 = u32::from_be_bytes(*b"GURL");
+    /// `keyDirectObject`, the four-char code `'----'`.
+    const KEY_DIRECT_OBJECT: u32 = u32::from_be_bytes(*b"----");
+
+    static URL_CALLBACK: OnceLock<Mutex<Option<Box<dyn Fn(String) + Send>>>> = OnceLock::new();
+
+    /// Registers a `handleGetURLEvent:withReplyEvent:` handler on the shared
+    /// `NSAppleEventManager` for `GURL` events, the standard macOS mechanism
+    /// by which an app receives URLs for schemes it's registered (via
+    /// Info.plist `CFBundleURLTypes`) to handle.
+    pub struct AppleEventUrlSource;
+
+    impl UrlEventSource for AppleEventUrlSource {
+        fn register(&self, on_url: Box<dyn Fn(String) + Send + 'static>) {
+            *URL_CALLBACK
+                .get_or_init(|| Mutex::new(None))
+                .lock()
+                .unwrap() = Some(on_url);
+
+            unsafe {
+                let mut decl = match ClassDecl::new("ScriptKitUrlEventHandler", class!(NSObject)) {
+                    Some(decl) => decl,
+                    // Re-registering after the class already exists (e.g. a
+                    // second call in tests that link against a real run) -
+                    // the handler is still installed below either way.
+                    None => {
+                        crate::logging::log(
+                            "URL",
+                            "ScriptKitUrlEventHandler class already registered",
+                        );
+                        let handler_class = objc::runtime::Class::get("ScriptKitUrlEventHandler")
+                            .expect("class was just reported as already registered");
+                        let handler_obj: id = msg_send![handler_class, new];
+                        install_handler(handler_obj);
+                        return;
+                    }
+                };
+                decl.add_method(
+                    sel!(handleGetURLEvent:withReplyEvent:),
+                    handle_get_url_event as extern "C" fn(&Object, Sel, id, id),
+                );
+                let handler_class = decl.register();
+                let handler_obj: id = msg_send![handler_class, new];
+                install_handler(handler_obj);
+            }
+        }
+    }
+
+    unsafe fn install_handler(handler_obj: id) {
+        let manager: id = msg_send![class!(NSAppleEventManager), sharedAppleEventManager];
+        let _: () = msg_send![
+            manager,
+            setEventHandler: handler_obj
+            andSelector: sel!(handleGetURLEvent:withReplyEvent:)
+            forEventClass: K_GURL
+            andEventID: K_GURL
+        ];
+        crate::logging::log("URL", "Registered scriptkit:// Apple Event handler");
+    }
+
+    extern "C" fn handle_get_url_event(_this: &Object, _sel: Sel, event: id, _reply_event: id) {
+        unsafe {
+            if event == nil {
+                return;
+            }
+            let desc: id = msg_send![event, paramDescriptorForKeyword: KEY_DIRECT_OBJECT];
+            if desc == nil {
+                return;
+            }
+            let s: id = msg_send![desc, stringValue];
+            if s == nil {
+                return;
+            }
+            let utf8: *const std::os::raw::c_char = msg_send![s, UTF8String];
+            if utf8.is_null() {
+                return;
+            }
+            let url = std::ffi::CStr::from_ptr(utf8)
+                .to_string_lossy()
+                .into_owned();
+
+            if let Some(guard) = URL_CALLBACK.get().and_then(|m| m.lock().ok()) {
+                if let Some(callback) = guard.as_ref() {
+                    callback(url);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn parses_run_with_args_in_order() {
+        let url = parse_url("scriptkit://run?script=my-script&arg=hello&arg=world").unwrap();
+        assert_eq!(
+            url,
+            ScriptKitUrl::Run {
+                script: "my-script".into(),
+                args: vec!["hello".into(), "world".into()],
+            }
+        );
+    }
+
+    #[test]
+    fn parses_run_with_percent_encoded_script_name() {
+        let url = parse_url("scriptkit://run?script=my%20script").unwrap();
+        assert_eq!(
+            url,
+            ScriptKitUrl::Run {
+                script: "my script".into(),
+                args: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn run_requires_script_param() {
+        let err = parse_url("scriptkit://run").unwrap_err();
+        assert_eq!(err, UrlSchemeError::MissingParam("script"));
+    }
+
+    #[test]
+    fn run_rejects_unknown_params() {
+        let err = parse_url("scriptkit://run?script=x&bogus=1").unwrap_err();
+        assert_eq!(
+            err,
+            UrlSchemeError::UnknownParam("bogus".into(), "run".into())
+        );
+    }
+
+    #[test]
+    fn parses_show_with_no_params() {
+        assert_eq!(parse_url("scriptkit://show").unwrap(), ScriptKitUrl::Show);
+        // Trailing slash is tolerated.
+        assert_eq!(parse_url("scriptkit://show/").unwrap(), ScriptKitUrl::Show);
+    }
+
+    #[test]
+    fn show_rejects_any_param() {
+        let err = parse_url("scriptkit://show?foo=1").unwrap_err();
+        assert_eq!(
+            err,
+            UrlSchemeError::UnknownParam("foo".into(), "show".into())
+        );
+    }
+
+    #[test]
+    fn parses_builtin_with_percent_decoded_name() {
+        let url = parse_url("scriptkit://builtin?name=clipboard%2Dhistory").unwrap();
+        assert_eq!(
+            url,
+            ScriptKitUrl::Builtin {
+                name: "clipboard-history".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn builtin_requires_name_param() {
+        let err = parse_url("scriptkit://builtin").unwrap_err();
+        assert_eq!(err, UrlSchemeError::MissingParam("name"));
+    }
+
+    #[test]
+    fn rejects_unknown_action() {
+        let err = parse_url("scriptkit://does-not-exist").unwrap_err();
+        assert_eq!(
+            err,
+            UrlSchemeError::UnsupportedAction("does-not-exist".into())
+        );
+    }
+
+    #[test]
+    fn rejects_non_scriptkit_scheme() {
+        let err = parse_url("https://example.com/run").unwrap_err();
+        assert_eq!(
+            err,
+            UrlSchemeError::UnsupportedScheme("https://example.com/run".into())
+        );
+    }
+
+    #[test]
+    fn dispatches_run_to_resolved_path() {
+        let url = ScriptKitUrl::Run {
+            script: "my-script".into(),
+            args: vec!["hello".into()],
+        };
+        let cmd = to_external_command(url, |name| {
+            assert_eq!(name, "my-script");
+            Some("/kit/scripts/my-script.ts".into())
+        })
+        .unwrap();
+        match cmd {
+            ExternalCommand::Run {
+                path,
+                args,
+                request_id,
+            } => {
+                assert_eq!(path, "/kit/scripts/my-script.ts");
+                assert_eq!(args, vec!["hello".to_string()]);
+                assert_eq!(request_id, None);
+            }
+            other => panic!("expected Run, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn dispatch_fails_for_unknown_script() {
+        let url = ScriptKitUrl::Run {
+            script: "nope".into(),
+            args: vec![],
+        };
+        let err = to_external_command(url, |_| None).unwrap_err();
+        assert_eq!(err, UrlSchemeError::UnknownScript("nope".into()));
+    }
+
+    #[test]
+    fn dispatches_show_and_builtin() {
+        assert!(matches!(
+            to_external_command(ScriptKitUrl::Show, |_| None).unwrap(),
+            ExternalCommand::Show { request_id: None }
+        ));
+        match to_external_command(
+            ScriptKitUrl::Builtin {
+                name: "clipboard-history".into(),
+            },
+            |_| None,
+        )
+        .unwrap()
+        {
+            ExternalCommand::TriggerBuiltin { name } => assert_eq!(name, "clipboard-history"),
+            other => panic!("expected TriggerBuiltin, got {:?}", other),
+        }
+    }
+
+    /// A fake [`UrlEventSource`] for testing the end-to-end
+    /// handle-incoming-URL flow without any OS hook.
+    struct FakeUrlEventSource {
+        callback: RefCell<Option<Box<dyn Fn(String) + Send>>>,
+    }
+
+    impl FakeUrlEventSource {
+        fn new() -> Self {
+            Self {
+                callback: RefCell::new(None),
+            }
+        }
+
+        fn fire(&self, url: &str) {
+            if let Some(cb) = self.callback.borrow().as_ref() {
+                cb(url.to_string());
+            }
+        }
+    }
+
+    impl UrlEventSource for FakeUrlEventSource {
+        fn register(&self, on_url: Box<dyn Fn(String) + Send + 'static>) {
+            *self.callback.borrow_mut() = Some(on_url);
+        }
+    }
+
+    #[test]
+    fn register_url_scheme_handler_dispatches_through_to_channel() {
+        let source = FakeUrlEventSource::new();
+        let (tx, rx) = async_channel::unbounded();
+
+        register_url_scheme_handler(&source, tx, |name| {
+            (name == "known-script").then(|| "/kit/scripts/known-script.ts".to_string())
+        });
+
+        source.fire("scriptkit://run?script=known-script");
+
+        let cmd = rx.try_recv().unwrap();
+        match cmd {
+            ExternalCommand::Run { path, .. } => {
+                assert_eq!(path, "/kit/scripts/known-script.ts")
+            }
+            other => panic!("expected Run, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn register_url_scheme_handler_drops_urls_not_meant_for_us() {
+        let source = FakeUrlEventSource::new();
+        let (tx, rx) = async_channel::unbounded();
+
+        register_url_scheme_handler(&source, tx, |_| None);
+        source.fire("not-a-scriptkit-url");
+
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn register_url_scheme_handler_reports_unknown_script_as_error_toast() {
+        let source = FakeUrlEventSource::new();
+        let (tx, rx) = async_channel::unbounded();
+
+        register_url_scheme_handler(&source, tx, |_| None);
+        source.fire("scriptkit://run?script=unknown-script");
+
+        match rx.try_recv().expect("expected a ShowErrorToast command") {
+            ExternalCommand::ShowErrorToast { message } => {
+                assert!(message.contains("unknown-script"), "{message}");
+            }
+            other => panic!("expected ShowErrorToast, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn handle_incoming_url_invokes_dispatch_exactly_once_on_success() {
+        let calls = Rc::new(RefCell::new(0));
+        let calls_clone = calls.clone();
+        handle_incoming_url(
+            "scriptkit://show",
+            |_| None,
+            |cmd| {
+                assert!(matches!(cmd, ExternalCommand::Show { .. }));
+                *calls_clone.borrow_mut() += 1;
+            },
+        );
+        assert_eq!(*calls.borrow(), 1);
+    }
+}
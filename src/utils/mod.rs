@@ -5,11 +5,15 @@
 //! - `assets`: Asset path resolution
 //! - `paths`: Path highlighting for search results
 //! - `tailwind`: Tailwind CSS class mapping
+//! - `text_normalize`: Diacritic-insensitive text normalization for search
+//! - `relative_time`: Relative-time formatting for frecency "last used" annotations
 
 mod assets;
 mod html;
 mod paths;
+mod relative_time;
 mod tailwind;
+mod text_normalize;
 
 // Re-export all public items for backwards compatibility
 // Allow unused imports - these are public API exports for external use
@@ -18,7 +22,9 @@ pub use assets::{get_asset_path, get_logo_path};
 #[allow(unused_imports)]
 pub use html::{elements_to_text, parse_html, strip_html_tags, HtmlElement};
 pub use paths::render_path_with_highlights;
+pub use relative_time::format_relative_time;
 pub use tailwind::{parse_color, TailwindStyles};
+pub use text_normalize::{contains_normalized, find_normalized, normalize_for_search};
 
 #[cfg(test)]
 mod tests {
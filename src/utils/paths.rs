@@ -38,14 +38,18 @@ pub fn render_path_with_highlights(
         return vec![(display_path.to_string(), false)];
     }
 
-    // Find where the filename starts in the display path
-    let filename_start = if let Some(pos) = display_path.rfind(filename) {
+    // Find where the filename starts in the display path. `rfind` returns a
+    // *byte* offset, but everything below indexes by *char* position (so
+    // that highlight ranges never split a multi-byte character) - convert
+    // once here rather than mixing the two index spaces.
+    let filename_start_byte = if let Some(pos) = display_path.rfind(filename) {
         pos
     } else if let Some(pos) = display_path.rfind('/') {
         pos + 1
     } else {
         0
     };
+    let filename_start = display_path[..filename_start_byte].chars().count();
 
     let mut result = Vec::new();
     let chars: Vec<char> = display_path.chars().collect();
@@ -136,6 +140,19 @@ mod tests {
         assert_eq!(result[1], ("le.txt".to_string(), false));
     }
 
+    #[test]
+    fn test_render_path_multibyte_prefix_does_not_panic_or_split_chars() {
+        // The directory component contains multi-byte characters (Japanese),
+        // so the filename's *byte* offset differs from its *char* offset.
+        // A regression here previously caused incorrect highlight splits.
+        let result = render_path_with_highlights("スクリプト/file.txt", "file.txt", &[0, 1]);
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0], ("スクリプト/".to_string(), false));
+        assert_eq!(result[1], ("fi".to_string(), true));
+        assert_eq!(result[2], ("le.txt".to_string(), false));
+    }
+
     #[test]
     fn test_render_path_filename_not_found() {
         // When filename doesn't match (falls back to last '/' position)
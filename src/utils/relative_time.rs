@@ -0,0 +1,63 @@
+//! Relative-time formatting for frecency-derived "last used" annotations
+
+/// Format the difference between `now` and `then` (both Unix timestamps in
+/// seconds) as a short relative-time string, e.g. "2h ago", "14d ago".
+///
+/// Anything under a minute is "just now"; beyond that, granularity steps from
+/// minutes to hours to days, rounding down at each boundary, so 59s elapsed
+/// is "just now" and 61s elapsed is "1m ago".
+/// Timestamps in the future (or equal) are treated as "just now".
+pub fn format_relative_time(now: u64, then: u64) -> String {
+    let elapsed = now.saturating_sub(then);
+
+    if elapsed < 60 {
+        "just now".to_string()
+    } else if elapsed < 3600 {
+        format!("{}m ago", elapsed / 60)
+    } else if elapsed < 86400 {
+        format!("{}h ago", elapsed / 3600)
+    } else {
+        format!("{}d ago", elapsed / 86400)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_just_now_under_a_minute() {
+        assert_eq!(format_relative_time(100, 100), "just now");
+        assert_eq!(format_relative_time(100, 41), "just now");
+    }
+
+    #[test]
+    fn test_minute_boundary_59s_vs_61s() {
+        assert_eq!(format_relative_time(100, 41), "just now"); // 59s elapsed
+        assert_eq!(format_relative_time(101, 40), "1m ago"); // 61s elapsed
+    }
+
+    #[test]
+    fn test_minutes() {
+        assert_eq!(format_relative_time(1000, 1000 - 5 * 60), "5m ago");
+    }
+
+    #[test]
+    fn test_hour_boundary() {
+        assert_eq!(format_relative_time(10_000, 10_000 - 23 * 3600), "23h ago");
+        assert_eq!(format_relative_time(10_000, 10_000 - 25 * 3600), "1d ago");
+    }
+
+    #[test]
+    fn test_days() {
+        assert_eq!(
+            format_relative_time(1_000_000, 1_000_000 - 7 * 86400),
+            "7d ago"
+        );
+    }
+
+    #[test]
+    fn test_future_timestamp_is_just_now() {
+        assert_eq!(format_relative_time(50, 100), "just now");
+    }
+}
@@ -0,0 +1,115 @@
+//! Text normalization for locale-aware, diacritic-insensitive search matching
+//!
+//! Plain `to_lowercase()` + `contains()` (used throughout the contains-style
+//! filters in clipboard history, the app launcher, the window switcher, and
+//! arg prompt choices) fails on accented input: typing "Zurich" will not
+//! match a script named "Zürich" because `ü` and `u` are different code
+//! points. This module provides a single normalization routine that strips
+//! that kind of distinction out before comparison, so every filter in the
+//! app treats accented and unaccented text the same way.
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Normalize a string for diacritic-insensitive, case-insensitive search
+/// comparison.
+///
+/// Runs NFKD decomposition (which also folds compatibility forms like
+/// fullwidth CJK characters onto their canonical equivalents), drops
+/// combining marks produced by that decomposition (except the kana
+/// voiced/semi-voiced sound marks, which are recomposed so `プ` stays `プ`),
+/// then case-folds via `to_lowercase()`. The result has no defined correspondence to the byte
+/// or character offsets of the input string - use it only for matching,
+/// never for computing highlight ranges against the original text.
+///
+/// # Examples
+/// ```ignore
+/// assert_eq!(normalize_for_search("Zürich"), normalize_for_search("zurich"));
+/// assert_eq!(normalize_for_search("café"), normalize_for_search("CAFE"));
+/// ```
+pub fn normalize_for_search(s: &str) -> String {
+    s.nfkd()
+        .filter(|c| !is_combining_mark(*c) || matches!(*c, '\u{3099}' | '\u{309A}'))
+        .nfc()
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// Check whether `haystack` contains `needle_normalized` once both are
+/// normalized via [`normalize_for_search`].
+///
+/// `needle_normalized` must already have been passed through
+/// `normalize_for_search` by the caller (callers typically normalize the
+/// query once outside a loop, then call this per-item).
+pub fn contains_normalized(haystack: &str, needle_normalized: &str) -> bool {
+    if needle_normalized.is_empty() {
+        return true;
+    }
+    normalize_for_search(haystack).contains(needle_normalized)
+}
+
+/// Find the position of `needle_normalized` within `haystack` once both are
+/// normalized via [`normalize_for_search`].
+///
+/// The returned position is an offset into the *normalized* haystack, not
+/// the original string - callers that only need to know whether a match
+/// starts at the beginning (e.g. to award a "prefix match" scoring bonus)
+/// can still compare it against `0`, but it must not be used to index into
+/// the original (un-normalized) string.
+pub fn find_normalized(haystack: &str, needle_normalized: &str) -> Option<usize> {
+    if needle_normalized.is_empty() {
+        return Some(0);
+    }
+    normalize_for_search(haystack).find(needle_normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_strips_latin_diacritics() {
+        // German umlaut
+        assert_eq!(
+            normalize_for_search("Zürich"),
+            normalize_for_search("Zurich")
+        );
+        assert_eq!(normalize_for_search("Zürich"), "zurich");
+
+        // Spanish tilde
+        assert_eq!(normalize_for_search("Año"), normalize_for_search("Ano"));
+        assert_eq!(normalize_for_search("jalapeño"), "jalapeno");
+    }
+
+    #[test]
+    fn test_normalize_is_case_insensitive() {
+        assert_eq!(normalize_for_search("CAFÉ"), normalize_for_search("cafe"));
+    }
+
+    #[test]
+    fn test_normalize_leaves_cjk_intact() {
+        // Japanese text has no diacritics to strip and should round-trip
+        // through NFKD unchanged (aside from lowercasing, which is a no-op
+        // for these characters).
+        let name = "スクリプト"; // "script" in katakana
+        assert_eq!(normalize_for_search(name), name);
+    }
+
+    #[test]
+    fn test_normalize_leaves_emoji_intact() {
+        let name = "🚀 Launch Script";
+        assert_eq!(normalize_for_search(name), "🚀 launch script");
+    }
+
+    #[test]
+    fn test_contains_normalized_matches_accented_text() {
+        let query = normalize_for_search("zurich");
+        assert!(contains_normalized("Zürich Office", &query));
+        assert!(!contains_normalized("Berlin Office", &query));
+    }
+
+    #[test]
+    fn test_contains_normalized_empty_needle_matches_everything() {
+        assert!(contains_normalized("anything", ""));
+    }
+}
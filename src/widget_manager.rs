@@ -0,0 +1,642 @@
+//! Widget Manager - persistent floating HTML windows owned by scripts
+//!
+//! A widget is a small, independent window (a timer, a build status dot)
+//! created via the SDK's `widget()` call. Unlike prompts, a widget outlives
+//! the prompt flow that created it and keeps running until the owning
+//! script closes it (or, unless `persist` is set, until the script exits).
+//!
+//! Rendering reuses the same HTML renderer as the `div` prompt
+//! (`prompts::div::render_elements`). Window creation and lifecycle follow
+//! the same pattern as `hud_manager`: a global registry behind a
+//! `Mutex`, with GPUI window handles kept alongside pure bookkeeping so the
+//! bookkeeping itself stays unit-testable without a live `App`.
+
+use gpui::{
+    div, prelude::*, px, rgb, App, Context, MouseButton, Render, Window,
+    WindowBackgroundAppearance, WindowHandle, WindowOptions,
+};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::mpsc::SyncSender;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::logging;
+use crate::process_manager::PROCESS_MANAGER;
+use crate::prompts::div::{render_elements, RenderContext};
+use crate::protocol::{Message, WidgetActionKind, WidgetEventKind, WidgetOptions};
+use crate::scriptlets::format_scriptlet;
+use crate::theme;
+use crate::utils::parse_html;
+
+const DEFAULT_WIDGET_WIDTH: f32 = 320.0;
+const DEFAULT_WIDGET_HEIGHT: f32 = 200.0;
+
+/// Bookkeeping for a single open widget, independent of any GPUI window
+/// handle so it can be exercised by plain unit tests.
+#[derive(Debug, Clone)]
+struct WidgetEntry {
+    /// PID of the script that created this widget
+    owner_pid: u32,
+    /// Keep the widget open after the owning script exits
+    persist: bool,
+}
+
+/// Pure-data registry of open widgets, keyed by widget id.
+///
+/// Kept separate from `WidgetManagerState` (which also stores GPUI window
+/// handles) so lifecycle bookkeeping is testable without a live `App`.
+#[derive(Debug, Default)]
+struct WidgetRegistry {
+    entries: HashMap<String, WidgetEntry>,
+}
+
+impl WidgetRegistry {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn insert(&mut self, id: String, owner_pid: u32, persist: bool) {
+        self.entries.insert(id, WidgetEntry { owner_pid, persist });
+    }
+
+    fn remove(&mut self, id: &str) -> Option<WidgetEntry> {
+        self.entries.remove(id)
+    }
+
+    fn contains(&self, id: &str) -> bool {
+        self.entries.contains_key(id)
+    }
+
+    fn owner_pid(&self, id: &str) -> Option<u32> {
+        self.entries.get(id).map(|e| e.owner_pid)
+    }
+
+    /// Widget ids owned by `pid` that should close because the script
+    /// exited without `persist: true`.
+    fn ids_to_close_on_exit(&self, pid: u32) -> Vec<String> {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| entry.owner_pid == pid && !entry.persist)
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+}
+
+/// A widget window and the channel used to notify its owning script.
+struct ActiveWidget {
+    window: WindowHandle<WidgetView>,
+    /// Cloned at creation time so events can reach the script even after
+    /// it's no longer the app's "currently focused" script.
+    response_tx: SyncSender<Message>,
+    /// The HTML passed to `widget()`, kept verbatim so `setState` can
+    /// re-render from the original `{{key}}` placeholders rather than
+    /// substituting into already-substituted markup.
+    html_template: String,
+    /// State merged across `setState` calls (each call merges its keys
+    /// into this map, matching the SDK's `Record<string, unknown>` partial
+    /// update semantics), substituted into `html_template` with the same
+    /// `{{key}}` templating `format_scriptlet` uses for scriptlet inputs.
+    state: HashMap<String, String>,
+}
+
+/// A widget's last-known screen position and size, persisted per widget id
+/// so it reopens where the user left it across script runs.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct WidgetPosition {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
+/// On-disk store of widget positions, keyed by widget id.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct WidgetPositionStore {
+    #[serde(default)]
+    positions: HashMap<String, WidgetPosition>,
+}
+
+fn load_widget_positions() -> WidgetPositionStore {
+    let path = default_position_store_path();
+    let Ok(contents) = fs::read_to_string(&path) else {
+        return WidgetPositionStore::default();
+    };
+    serde_json::from_str(&contents).unwrap_or_default()
+}
+
+fn load_widget_position(id: &str) -> Option<WidgetPosition> {
+    load_widget_positions().positions.get(id).copied()
+}
+
+fn save_widget_position(id: &str, position: WidgetPosition) {
+    let path = default_position_store_path();
+    let mut store = load_widget_positions();
+    store.positions.insert(id.to_string(), position);
+
+    if let Some(parent) = path.parent() {
+        if let Err(e) = fs::create_dir_all(parent) {
+            logging::log(
+                "WIDGET",
+                &format!("Failed to create widget position store dir: {}", e),
+            );
+            return;
+        }
+    }
+    match serde_json::to_string_pretty(&store) {
+        Ok(json) => {
+            if let Err(e) = fs::write(&path, json) {
+                logging::log(
+                    "WIDGET",
+                    &format!("Failed to save position for widget '{}': {}", id, e),
+                );
+            }
+        }
+        Err(e) => logging::log(
+            "WIDGET",
+            &format!("Failed to serialize widget positions: {}", e),
+        ),
+    }
+}
+
+#[derive(Default)]
+struct WidgetManagerState {
+    registry: WidgetRegistry,
+    windows: HashMap<String, ActiveWidget>,
+}
+
+static WIDGET_MANAGER: std::sync::OnceLock<Arc<Mutex<WidgetManagerState>>> =
+    std::sync::OnceLock::new();
+
+fn get_widget_manager() -> &'static Arc<Mutex<WidgetManagerState>> {
+    WIDGET_MANAGER.get_or_init(|| Arc::new(Mutex::new(WidgetManagerState::default())))
+}
+
+/// GPUI view rendering a widget's current HTML content.
+struct WidgetView {
+    id: String,
+    html: String,
+    /// `(x, y, width, height)` as of the last render, used to detect moves
+    /// and resizes so they can be reported back to the owning script.
+    last_bounds: Option<(f32, f32, f32, f32)>,
+    last_bounds_save: Instant,
+}
+
+impl WidgetView {
+    /// Debounce for move/resize reporting and position persistence, matching
+    /// the Notes window's `BOUNDS_DEBOUNCE_MS`.
+    const BOUNDS_DEBOUNCE_MS: u64 = 250;
+
+    fn new(id: String, html: String) -> Self {
+        Self {
+            id,
+            html,
+            last_bounds: None,
+            last_bounds_save: Instant::now(),
+        }
+    }
+
+    /// Replace the rendered HTML, e.g. after a `setState` re-render.
+    fn set_html(&mut self, html: String) {
+        self.html = html;
+    }
+
+    /// Detect a moved or resized window since the last render, forward it to
+    /// the owning script as a `widgetEvent`, and persist the new position so
+    /// it can be restored the next time this widget id is shown.
+    fn maybe_report_move_resize(&mut self, window: &Window) {
+        let bounds = window.bounds();
+        let x = f32::from(bounds.origin.x);
+        let y = f32::from(bounds.origin.y);
+        let width = f32::from(bounds.size.width);
+        let height = f32::from(bounds.size.height);
+
+        let Some((prev_x, prev_y, prev_width, prev_height)) = self.last_bounds else {
+            self.last_bounds = Some((x, y, width, height));
+            return;
+        };
+
+        let moved = (prev_x, prev_y) != (x, y);
+        let resized = (prev_width, prev_height) != (width, height);
+        if !moved && !resized {
+            return;
+        }
+        if self.last_bounds_save.elapsed() < Duration::from_millis(Self::BOUNDS_DEBOUNCE_MS) {
+            return;
+        }
+
+        if moved {
+            notify_widget_event(
+                &self.id,
+                WidgetEventKind::Moved,
+                Some(serde_json::json!({ "x": x, "y": y })),
+            );
+        }
+        if resized {
+            notify_widget_event(
+                &self.id,
+                WidgetEventKind::Resized,
+                Some(serde_json::json!({ "width": width, "height": height })),
+            );
+        }
+        save_widget_position(&self.id, WidgetPosition { x, y, width, height });
+
+        self.last_bounds = Some((x, y, width, height));
+        self.last_bounds_save = Instant::now();
+    }
+}
+
+impl Render for WidgetView {
+    fn render(&mut self, window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        self.maybe_report_move_resize(window);
+
+        let theme = theme::load_theme();
+        let ctx = RenderContext::from_theme(&theme.colors);
+        let elements = parse_html(&self.html);
+        let id = self.id.clone();
+
+        div()
+            .size_full()
+            .bg(rgb(theme.colors.background.main))
+            .p_3()
+            .on_mouse_down(MouseButton::Left, move |event, _window, _cx: &mut App| {
+                notify_widget_event(
+                    &id,
+                    WidgetEventKind::Click,
+                    Some(serde_json::json!({
+                        "x": f32::from(event.position.x),
+                        "y": f32::from(event.position.y),
+                    })),
+                );
+            })
+            .child(render_elements(&elements, ctx))
+    }
+}
+
+/// Create a new widget window for the given owning script.
+///
+/// `response_tx` is the owning script's stdin writer channel, captured so
+/// `widgetEvent` messages can reach it for as long as the script keeps
+/// running, independent of whether it's still the "active" script.
+pub fn show_widget(
+    id: String,
+    html: String,
+    options: Option<WidgetOptions>,
+    owner_pid: u32,
+    response_tx: SyncSender<Message>,
+    cx: &mut App,
+) {
+    let options = options.unwrap_or_default();
+    // Explicit options always win; otherwise fall back to wherever this
+    // widget id was left last time, then to the hardcoded defaults.
+    let saved = load_widget_position(&id);
+    let width = options
+        .width
+        .or(saved.map(|p| p.width))
+        .unwrap_or(DEFAULT_WIDGET_WIDTH);
+    let height = options
+        .height
+        .or(saved.map(|p| p.height))
+        .unwrap_or(DEFAULT_WIDGET_HEIGHT);
+    let x = options.x.or(saved.map(|p| p.x)).unwrap_or(0.0);
+    let y = options.y.or(saved.map(|p| p.y)).unwrap_or(0.0);
+    let persist = options.persist.unwrap_or(false);
+    let always_on_top = options.always_on_top.unwrap_or(false);
+
+    // Replace an existing widget with the same id rather than stacking a
+    // second window under it.
+    close_widget(&id, cx);
+
+    let bounds = gpui::Bounds {
+        origin: gpui::point(px(x), px(y)),
+        size: gpui::size(px(width), px(height)),
+    };
+
+    let view_id = id.clone();
+    let html_template = html.clone();
+    let window_result = cx.open_window(
+        WindowOptions {
+            window_bounds: Some(gpui::WindowBounds::Windowed(bounds)),
+            titlebar: None,
+            is_movable: options.draggable.unwrap_or(true),
+            window_background: if options.transparent.unwrap_or(false) {
+                WindowBackgroundAppearance::Transparent
+            } else {
+                WindowBackgroundAppearance::Opaque
+            },
+            focus: false,
+            show: true,
+            ..Default::default()
+        },
+        |_, cx| cx.new(|_| WidgetView::new(view_id, html)),
+    );
+
+    match window_result {
+        Ok(window) => {
+            if always_on_top {
+                configure_widget_always_on_top();
+            }
+            let manager = get_widget_manager();
+            let mut state = manager.lock();
+            state.registry.insert(id.clone(), owner_pid, persist);
+            state.windows.insert(
+                id.clone(),
+                ActiveWidget {
+                    window,
+                    response_tx,
+                    html_template,
+                    state: HashMap::new(),
+                },
+            );
+            logging::log(
+                "WIDGET",
+                &format!("Widget '{}' created (pid={})", id, owner_pid),
+            );
+        }
+        Err(e) => {
+            logging::log(
+                "WIDGET",
+                &format!("Failed to create widget '{}': {:?}", id, e),
+            );
+        }
+    }
+}
+
+/// Apply macOS floating window level to the most-recently-opened window, per
+/// `WidgetOptions::always_on_top`. Mirrors `actions/window.rs`'s
+/// `configure_actions_popup_window` call site: since we just created the
+/// window synchronously, it's the last entry in `NSApp.windows`.
+#[cfg(target_os = "macos")]
+fn configure_widget_always_on_top() {
+    use cocoa::appkit::NSApp;
+    use cocoa::base::{id, nil};
+    use objc::{msg_send, sel, sel_impl};
+
+    unsafe {
+        let app: id = NSApp();
+        let windows: id = msg_send![app, windows];
+        let count: usize = msg_send![windows, count];
+        if count == 0 {
+            return;
+        }
+        let ns_window: id = msg_send![windows, lastObject];
+        if ns_window != nil {
+            let _: () = msg_send![ns_window, setLevel: crate::platform::NS_FLOATING_WINDOW_LEVEL];
+        }
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn configure_widget_always_on_top() {}
+
+/// Apply a `widgetAction` message: update displayed HTML for `setState`, or
+/// close the window for `close`.
+pub fn handle_widget_action(
+    id: &str,
+    action: WidgetActionKind,
+    state: Option<serde_json::Value>,
+    cx: &mut App,
+) {
+    match action {
+        WidgetActionKind::SetState => apply_widget_state(id, state, cx),
+        WidgetActionKind::Close => close_widget(id, cx),
+    }
+}
+
+/// Merge a `setState` payload into a widget's cumulative state and re-render
+/// its HTML from the original `html_template`, using the same `{{key}}`
+/// substitution `format_scriptlet` uses for scriptlet inputs (see
+/// `scriptlets.rs`). Each call merges its keys into previously-set state
+/// rather than replacing it, matching the SDK's `Record<string, unknown>`
+/// partial-update semantics — unlike `update_hud`'s direct text replacement,
+/// a widget's state isn't the markup itself.
+fn apply_widget_state(id: &str, state: Option<serde_json::Value>, cx: &mut App) {
+    let Some(state) = state else {
+        logging::log(
+            "WIDGET",
+            &format!("setState for widget '{}' had no state payload", id),
+        );
+        return;
+    };
+    let Some(state_obj) = state.as_object() else {
+        logging::log(
+            "WIDGET",
+            &format!("setState for widget '{}' was not a JSON object", id),
+        );
+        return;
+    };
+
+    let (window, rendered_html) = {
+        let manager = get_widget_manager();
+        let mut manager_state = manager.lock();
+        let Some(active) = manager_state.windows.get_mut(id) else {
+            logging::log("WIDGET", &format!("setState for unknown widget '{}'", id));
+            return;
+        };
+        for (key, value) in state_obj {
+            active.state.insert(key.clone(), template_value_to_string(value));
+        }
+        let rendered = format_scriptlet(&active.html_template, &active.state, &[], cfg!(windows));
+        (active.window, rendered)
+    };
+
+    let update_result = window.update(cx, |view, _window, cx| {
+        view.set_html(rendered_html);
+        cx.notify();
+    });
+    if let Err(e) = update_result {
+        logging::log(
+            "WIDGET",
+            &format!("setState: window for '{}' already closed: {}", id, e),
+        );
+    }
+}
+
+/// Stringify a `setState` JSON value for `{{key}}` substitution. Strings are
+/// used verbatim (no surrounding quotes); everything else uses its JSON
+/// representation.
+fn template_value_to_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// Close a widget window and drop its registry entry, notifying the owning
+/// script with a `close` `widgetEvent` so `onClose` handlers fire regardless
+/// of whether the script itself requested the close (e.g. `show_widget`
+/// replacing an id that's still owned by a different in-flight script run).
+pub fn close_widget(id: &str, cx: &mut App) {
+    let closed = {
+        let manager = get_widget_manager();
+        let mut state = manager.lock();
+        let owner_pid = state.registry.owner_pid(id);
+        state.registry.remove(id);
+        state.windows.remove(id).map(|active| (active, owner_pid))
+    };
+
+    if let Some((active, owner_pid)) = closed {
+        let _ = active.window.update(cx, |_, window, _cx| {
+            window.remove_window();
+        });
+        if let Some(owner_pid) = owner_pid {
+            send_widget_event(
+                owner_pid,
+                &active.response_tx,
+                id,
+                WidgetEventKind::Close,
+                None,
+            );
+        }
+        logging::log("WIDGET", &format!("Widget '{}' closed", id));
+    }
+}
+
+/// Close every non-persisted widget owned by `pid`, called when that
+/// script exits.
+pub fn close_widgets_for_exited_script(pid: u32, cx: &mut App) {
+    let ids = {
+        let manager = get_widget_manager();
+        let state = manager.lock();
+        state.registry.ids_to_close_on_exit(pid)
+    };
+
+    for id in ids {
+        close_widget(&id, cx);
+    }
+}
+
+/// Report a widget interaction back to its owning script, if the widget and
+/// its owning script are both still around. Silently drops the event
+/// otherwise (the widget may outlive its creator when `persist: true`).
+fn notify_widget_event(id: &str, event: WidgetEventKind, data: Option<serde_json::Value>) {
+    let manager = get_widget_manager();
+    let state = manager.lock();
+
+    let Some(owner_pid) = state.registry.owner_pid(id) else {
+        return;
+    };
+    let Some(active) = state.windows.get(id) else {
+        return;
+    };
+
+    send_widget_event(owner_pid, &active.response_tx, id, event, data);
+}
+
+/// Send a `widgetEvent` to `response_tx` if `owner_pid` is still running.
+/// Shared by `notify_widget_event` (widget still open) and `close_widget`
+/// (widget just removed, but we already have its response channel).
+fn send_widget_event(
+    owner_pid: u32,
+    response_tx: &SyncSender<Message>,
+    id: &str,
+    event: WidgetEventKind,
+    data: Option<serde_json::Value>,
+) {
+    if !PROCESS_MANAGER.is_process_running(owner_pid) {
+        logging::log(
+            "WIDGET",
+            &format!("Owning script for widget '{}' is gone, dropping event", id),
+        );
+        return;
+    }
+
+    let message = Message::widget_event(id.to_string(), event, data);
+    if response_tx.try_send(message).is_err() {
+        logging::log(
+            "WIDGET",
+            &format!("Failed to deliver widgetEvent for '{}'", id),
+        );
+    }
+}
+
+fn default_position_store_path() -> PathBuf {
+    PathBuf::from(shellexpand::tilde("~/.scriptkit/widget_positions.json").as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_widget_registry_insert_and_contains() {
+        let mut registry = WidgetRegistry::new();
+        assert!(!registry.contains("w1"));
+
+        registry.insert("w1".to_string(), 100, false);
+        assert!(registry.contains("w1"));
+        assert_eq!(registry.owner_pid("w1"), Some(100));
+    }
+
+    #[test]
+    fn test_widget_registry_remove() {
+        let mut registry = WidgetRegistry::new();
+        registry.insert("w1".to_string(), 100, false);
+
+        let removed = registry.remove("w1");
+        assert!(removed.is_some());
+        assert!(!registry.contains("w1"));
+        assert!(registry.remove("w1").is_none());
+    }
+
+    #[test]
+    fn test_ids_to_close_on_exit_skips_persisted_widgets() {
+        let mut registry = WidgetRegistry::new();
+        registry.insert("ephemeral".to_string(), 100, false);
+        registry.insert("sticky".to_string(), 100, true);
+        registry.insert("other-script".to_string(), 200, false);
+
+        let mut to_close = registry.ids_to_close_on_exit(100);
+        to_close.sort();
+        assert_eq!(to_close, vec!["ephemeral".to_string()]);
+    }
+
+    #[test]
+    fn test_ids_to_close_on_exit_no_match() {
+        let mut registry = WidgetRegistry::new();
+        registry.insert("w1".to_string(), 100, false);
+
+        assert!(registry.ids_to_close_on_exit(999).is_empty());
+    }
+
+    #[test]
+    fn test_default_position_store_path_expands_home() {
+        let path = default_position_store_path();
+        assert!(path.to_string_lossy().ends_with("widget_positions.json"));
+        assert!(!path.to_string_lossy().contains('~'));
+    }
+
+    #[test]
+    fn test_widget_position_store_roundtrips_through_json() {
+        let mut store = WidgetPositionStore::default();
+        store.positions.insert(
+            "w1".to_string(),
+            WidgetPosition {
+                x: 10.0,
+                y: 20.0,
+                width: 320.0,
+                height: 200.0,
+            },
+        );
+
+        let json = serde_json::to_string(&store).unwrap();
+        let parsed: WidgetPositionStore = serde_json::from_str(&json).unwrap();
+        let restored = parsed.positions.get("w1").unwrap();
+        assert_eq!(restored.x, 10.0);
+        assert_eq!(restored.height, 200.0);
+    }
+
+    #[test]
+    fn test_template_value_to_string_unwraps_strings() {
+        assert_eq!(
+            template_value_to_string(&serde_json::json!("hello")),
+            "hello"
+        );
+        assert_eq!(template_value_to_string(&serde_json::json!(42)), "42");
+        assert_eq!(template_value_to_string(&serde_json::Value::Null), "");
+    }
+}
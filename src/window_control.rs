@@ -171,6 +171,145 @@ impl WindowInfo {
     }
 }
 
+/// A group of windows belonging to the same application, for grouped
+/// rendering in the window switcher (see `group_windows_by_app`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowAppGroup {
+    /// Application name (matches `WindowInfo::app` for every grouped window).
+    pub app: String,
+    /// Process ID of the owning application, for app-level actions
+    /// (quit/hide/close-all) on the group.
+    pub pid: i32,
+    /// Indices into the `windows` slice passed to `group_windows_by_app`,
+    /// in their original order.
+    pub window_indices: Vec<usize>,
+}
+
+/// Group `windows` by application, sorted alphabetically by app name with
+/// `frontmost_app` (if given and present) pinned first.
+///
+/// Groups are built only from windows actually present in `windows`, so an
+/// app with no windows in the input never produces an (empty) group.
+pub fn group_windows_by_app(windows: &[WindowInfo], frontmost_app: Option<&str>) -> Vec<WindowAppGroup> {
+    let mut order: Vec<String> = Vec::new();
+    let mut groups: std::collections::HashMap<String, WindowAppGroup> = std::collections::HashMap::new();
+
+    for (idx, window) in windows.iter().enumerate() {
+        groups
+            .entry(window.app.clone())
+            .or_insert_with(|| {
+                order.push(window.app.clone());
+                WindowAppGroup {
+                    app: window.app.clone(),
+                    pid: window.pid,
+                    window_indices: Vec::new(),
+                }
+            })
+            .window_indices
+            .push(idx);
+    }
+
+    order.sort();
+    if let Some(front) = frontmost_app {
+        if let Some(pos) = order.iter().position(|app| app == front) {
+            let app = order.remove(pos);
+            order.insert(0, app);
+        }
+    }
+
+    order
+        .into_iter()
+        .filter_map(|app| groups.remove(&app))
+        .collect()
+}
+
+/// Quit an application (equivalent to Cmd+Q) via `NSRunningApplication::terminate`.
+///
+/// # Errors
+/// Returns an error if no running application with `pid` can be found.
+pub fn quit_application(pid: i32) -> Result<()> {
+    let terminated = unsafe {
+        use objc::runtime::{Class, Object};
+        use objc::{msg_send, sel, sel_impl};
+
+        let workspace_class = Class::get("NSWorkspace").context("Failed to get NSWorkspace")?;
+        let workspace: *mut Object = msg_send![workspace_class, sharedWorkspace];
+        let running_apps: *mut Object = msg_send![workspace, runningApplications];
+        let app_count: usize = msg_send![running_apps, count];
+
+        let mut terminated = false;
+        for i in 0..app_count {
+            let app: *mut Object = msg_send![running_apps, objectAtIndex: i];
+            let app_pid: i32 = msg_send![app, processIdentifier];
+            if app_pid == pid {
+                terminated = msg_send![app, terminate];
+                break;
+            }
+        }
+        terminated
+    };
+
+    if terminated {
+        info!(pid, "Quit application");
+        Ok(())
+    } else {
+        bail!("No running application with pid {} found to quit", pid);
+    }
+}
+
+/// Hide an application (equivalent to Cmd+H) via `NSRunningApplication::hide`.
+///
+/// # Errors
+/// Returns an error if no running application with `pid` can be found.
+pub fn hide_application(pid: i32) -> Result<()> {
+    let hidden = unsafe {
+        use objc::runtime::{Class, Object};
+        use objc::{msg_send, sel, sel_impl};
+
+        let workspace_class = Class::get("NSWorkspace").context("Failed to get NSWorkspace")?;
+        let workspace: *mut Object = msg_send![workspace_class, sharedWorkspace];
+        let running_apps: *mut Object = msg_send![workspace, runningApplications];
+        let app_count: usize = msg_send![running_apps, count];
+
+        let mut hidden = false;
+        for i in 0..app_count {
+            let app: *mut Object = msg_send![running_apps, objectAtIndex: i];
+            let app_pid: i32 = msg_send![app, processIdentifier];
+            if app_pid == pid {
+                hidden = msg_send![app, hide];
+                break;
+            }
+        }
+        hidden
+    };
+
+    if hidden {
+        info!(pid, "Hid application");
+        Ok(())
+    } else {
+        bail!("No running application with pid {} found to hide", pid);
+    }
+}
+
+/// Close every window owned by `pid`, best-effort: keeps going on a
+/// per-window failure and returns the number of windows successfully closed.
+///
+/// # Errors
+/// Returns an error only if `list_windows()` itself fails; individual
+/// window-close failures are logged and skipped rather than aborting.
+pub fn close_all_windows_for_pid(pid: i32) -> Result<usize> {
+    let windows = list_windows()?;
+    let mut closed = 0;
+    for window in windows.iter().filter(|w| w.pid == pid) {
+        match close_window(window.id) {
+            Ok(()) => closed += 1,
+            Err(e) => warn!(window_id = window.id, error = %e, "Failed to close window"),
+        }
+    }
+    info!(pid, closed, "Closed all windows for application");
+    Ok(closed)
+}
+
 /// Tiling positions for windows
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TilePosition {
@@ -1329,6 +1468,60 @@ mod tests {
         assert_ne!(TilePosition::LeftHalf, TilePosition::RightHalf);
     }
 
+    fn test_window(app: &str, pid: i32, title: &str) -> WindowInfo {
+        WindowInfo {
+            id: (pid as u32) << 16,
+            app: app.to_string(),
+            title: title.to_string(),
+            bounds: Bounds::new(0, 0, 800, 600),
+            pid,
+            ax_window: None,
+        }
+    }
+
+    #[test]
+    fn test_group_windows_by_app_three_apps_sorted_alphabetically() {
+        let windows = vec![
+            test_window("Zed", 1, "main.rs"),
+            test_window("Arc", 2, "New Tab"),
+            test_window("Arc", 2, "Docs"),
+            test_window("Messages", 3, "Inbox"),
+        ];
+
+        let groups = group_windows_by_app(&windows, None);
+        let app_names: Vec<&str> = groups.iter().map(|g| g.app.as_str()).collect();
+        assert_eq!(app_names, vec!["Arc", "Messages", "Zed"]);
+        assert_eq!(groups[0].pid, 2);
+        assert_eq!(groups[0].window_indices, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_group_windows_by_app_pins_frontmost_first() {
+        let windows = vec![
+            test_window("Zed", 1, "main.rs"),
+            test_window("Arc", 2, "New Tab"),
+            test_window("Messages", 3, "Inbox"),
+        ];
+
+        let groups = group_windows_by_app(&windows, Some("Zed"));
+        let app_names: Vec<&str> = groups.iter().map(|g| g.app.as_str()).collect();
+        assert_eq!(app_names, vec!["Zed", "Arc", "Messages"]);
+    }
+
+    #[test]
+    fn test_group_windows_by_app_unknown_frontmost_is_ignored() {
+        let windows = vec![test_window("Zed", 1, "main.rs")];
+        let groups = group_windows_by_app(&windows, Some("Not Running"));
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].app, "Zed");
+    }
+
+    #[test]
+    fn test_group_windows_by_app_empty_input_has_no_groups() {
+        let groups = group_windows_by_app(&[], Some("Zed"));
+        assert!(groups.is_empty());
+    }
+
     #[test]
     fn test_permission_check_does_not_panic() {
         // This test verifies the permission check doesn't panic
@@ -18,7 +18,7 @@ use tracing::{debug, warn};
 
 use crate::logging;
 
-use crate::list_item::LIST_ITEM_HEIGHT;
+use crate::density;
 use crate::window_manager;
 
 /// Layout constants for height calculations
@@ -49,6 +49,15 @@ pub mod layout {
 
     /// Maximum window height for full-content views (editor, div, term)
     pub const MAX_HEIGHT: Pixels = px(700.0);
+
+    /// Generous upper bound for a user-resized editor/terminal height, so a
+    /// hand-edited or corrupted state file can't produce an unusably large
+    /// window.
+    pub const MAX_USER_HEIGHT: Pixels = px(1200.0);
+
+    /// Height for the confirm dialog - title, a couple lines of message,
+    /// and a button row, no list or input
+    pub const CONFIRM_DIALOG_HEIGHT: Pixels = px(220.0);
 }
 
 /// View types for height calculation
@@ -66,6 +75,21 @@ pub enum ViewType {
     EditorPrompt,
     /// Terminal prompt - full height
     TermPrompt,
+    /// Confirmation dialog (OK/Cancel) - small fixed height, no list/input
+    ConfirmDialog,
+}
+
+impl ViewType {
+    /// Key used to persist a user-resized height for this view type (see
+    /// `window_state::load_view_height` / `save_view_height`), or `None` for
+    /// views whose height is fixed or computed rather than user-adjustable.
+    fn persisted_height_key(self) -> Option<&'static str> {
+        match self {
+            ViewType::EditorPrompt => Some("editor"),
+            ViewType::TermPrompt => Some("term"),
+            _ => None,
+        }
+    }
 }
 
 /// Get the target height for a specific view type
@@ -92,15 +116,25 @@ pub fn height_for_view(view_type: ViewType, item_count: usize) -> Pixels {
         ViewType::ScriptList | ViewType::DivPrompt => STANDARD_HEIGHT,
         ViewType::ArgPromptWithChoices => {
             let visible_items = item_count.max(1) as f32;
-            let list_height =
-                (visible_items * LIST_ITEM_HEIGHT) + ARG_LIST_PADDING_Y + ARG_DIVIDER_HEIGHT;
+            let list_height = (visible_items * density::list_item_height())
+                + ARG_LIST_PADDING_Y
+                + ARG_DIVIDER_HEIGHT;
             let total_height = ARG_HEADER_HEIGHT + list_height;
             clamp_height(px(total_height))
         }
         // Input-only prompt - compact
         ViewType::ArgPromptNoChoices => MIN_HEIGHT,
-        // Full content views (editor, terminal) - max height
-        ViewType::EditorPrompt | ViewType::TermPrompt => MAX_HEIGHT,
+        // Full content views (editor, terminal) - user-resizable and
+        // remembered per view type; falls back to MAX_HEIGHT until the user
+        // resizes one of these prompts (see `close_and_reset_window`, the
+        // save site for this preference).
+        ViewType::EditorPrompt | ViewType::TermPrompt => view_type
+            .persisted_height_key()
+            .and_then(crate::window_state::load_view_height)
+            .map(|h| px((h as f32).clamp(f32::from(MIN_HEIGHT), f32::from(MAX_USER_HEIGHT))))
+            .unwrap_or(MAX_HEIGHT),
+        // Confirm dialog - small fixed height, no dynamic content
+        ViewType::ConfirmDialog => CONFIRM_DIALOG_HEIGHT,
     }
 }
 
@@ -246,6 +280,24 @@ pub fn get_first_window_height() -> Option<Pixels> {
 mod tests {
     use super::*;
     use gpui::px;
+    use std::env;
+    use tempfile::TempDir;
+
+    /// Point `dirs::home_dir()` at a scratch directory for the duration of
+    /// `f`, so tests that read/write `window-state.json` (via
+    /// `height_for_view`'s persisted-height lookup) don't depend on -- or
+    /// clobber -- the real `~/.sk/kit/window-state.json` on the machine
+    /// running the tests. Mirrors the helper in
+    /// `window_state_persistence_tests.rs`.
+    fn with_temp_state_dir<F: FnOnce()>(f: F) {
+        let temp_dir = TempDir::new().unwrap();
+        let old_home = env::var("HOME").ok();
+        env::set_var("HOME", temp_dir.path());
+        f();
+        if let Some(home) = old_home {
+            env::set_var("HOME", home);
+        }
+    }
 
     #[test]
     fn test_script_list_fixed_height() {
@@ -269,13 +321,14 @@ mod tests {
         // Arg with choices should size to items, clamped to STANDARD_HEIGHT
         let base_height =
             layout::ARG_HEADER_HEIGHT + layout::ARG_DIVIDER_HEIGHT + layout::ARG_LIST_PADDING_Y;
+        let item_height = density::list_item_height();
         assert_eq!(
             height_for_view(ViewType::ArgPromptWithChoices, 1),
-            px(base_height + LIST_ITEM_HEIGHT)
+            px(base_height + item_height)
         );
         assert_eq!(
             height_for_view(ViewType::ArgPromptWithChoices, 2),
-            px(base_height + (2.0 * LIST_ITEM_HEIGHT))
+            px(base_height + (2.0 * item_height))
         );
         assert_eq!(
             height_for_view(ViewType::ArgPromptWithChoices, 100),
@@ -283,6 +336,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_arg_with_choices_respects_density() {
+        // Density is process-global state, so both densities are exercised
+        // within a single test to avoid racing with other tests that read it.
+        let base_height =
+            layout::ARG_HEADER_HEIGHT + layout::ARG_DIVIDER_HEIGHT + layout::ARG_LIST_PADDING_Y;
+
+        density::set_density(crate::config::Density::Compact);
+        assert_eq!(density::list_item_height(), 36.0);
+        assert_eq!(
+            height_for_view(ViewType::ArgPromptWithChoices, 1),
+            px(base_height + 36.0)
+        );
+
+        density::set_density(crate::config::Density::Comfortable);
+        assert_eq!(density::list_item_height(), 48.0);
+        assert_eq!(
+            height_for_view(ViewType::ArgPromptWithChoices, 1),
+            px(base_height + 48.0)
+        );
+    }
+
     #[test]
     fn test_arg_no_choices_compact() {
         // Arg without choices should be MIN_HEIGHT
@@ -294,12 +369,31 @@ mod tests {
 
     #[test]
     fn test_full_height_views() {
-        // Editor and Terminal use MAX_HEIGHT (700px)
-        assert_eq!(
-            height_for_view(ViewType::EditorPrompt, 0),
-            layout::MAX_HEIGHT
-        );
-        assert_eq!(height_for_view(ViewType::TermPrompt, 0), layout::MAX_HEIGHT);
+        // Editor and Terminal fall back to MAX_HEIGHT (700px) when the user
+        // has never resized them.
+        with_temp_state_dir(|| {
+            assert_eq!(
+                height_for_view(ViewType::EditorPrompt, 0),
+                layout::MAX_HEIGHT
+            );
+            assert_eq!(height_for_view(ViewType::TermPrompt, 0), layout::MAX_HEIGHT);
+        });
+    }
+
+    #[test]
+    fn test_full_height_views_use_persisted_height() {
+        // Once the user has resized an editor/terminal prompt, height_for_view
+        // should remember it instead of the fixed default.
+        with_temp_state_dir(|| {
+            crate::window_state::save_view_height("editor", 850.0);
+            crate::window_state::save_view_height("term", 10.0);
+            assert_eq!(height_for_view(ViewType::EditorPrompt, 0), px(850.0));
+            // Below MIN_HEIGHT is clamped back up so the window stays usable.
+            assert_eq!(
+                height_for_view(ViewType::TermPrompt, 0),
+                layout::MIN_HEIGHT
+            );
+        });
     }
 
     #[test]
@@ -311,6 +405,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_confirm_dialog_fixed_height() {
+        // Confirm dialog should always be CONFIRM_DIALOG_HEIGHT regardless of item count
+        assert_eq!(
+            height_for_view(ViewType::ConfirmDialog, 0),
+            layout::CONFIRM_DIALOG_HEIGHT
+        );
+    }
+
     #[test]
     fn test_initial_window_height() {
         assert_eq!(initial_window_height(), layout::STANDARD_HEIGHT);
@@ -322,4 +425,20 @@ mod tests {
         assert_eq!(layout::STANDARD_HEIGHT, px(500.0));
         assert_eq!(layout::MAX_HEIGHT, px(700.0));
     }
+
+    #[test]
+    fn test_footer_height_is_baked_into_arg_header_height() {
+        // PromptFooter is a fixed 40px row (chips or default buttons - the
+        // row itself never grows with content), so ARG_HEADER_HEIGHT already
+        // reserves that space regardless of whether ShowArg::footer_hints
+        // swaps in custom chips. Guards against someone shrinking
+        // ARG_HEADER_HEIGHT and clipping the footer.
+        assert!(layout::ARG_HEADER_HEIGHT >= layout::FOOTER_HEIGHT);
+        assert_eq!(
+            layout::ARG_HEADER_HEIGHT,
+            (layout::ARG_INPUT_PADDING_Y * 2.0)
+                + layout::ARG_INPUT_LINE_HEIGHT
+                + layout::FOOTER_HEIGHT
+        );
+    }
 }
@@ -135,6 +135,12 @@ pub struct WindowStateFile {
     pub main: Option<PersistedWindowBounds>,
     pub notes: Option<PersistedWindowBounds>,
     pub ai: Option<PersistedWindowBounds>,
+    /// User-adjusted heights for prompt view types that remember their size
+    /// (currently the editor and terminal prompts), keyed by a short view
+    /// name such as `"editor"` or `"term"`. See
+    /// `window_resize::height_for_view`.
+    #[serde(default)]
+    pub view_heights: std::collections::HashMap<String, f64>,
 }
 
 fn default_version() -> u32 {
@@ -252,6 +258,24 @@ pub fn save_window_bounds(role: WindowRole, bounds: PersistedWindowBounds) {
     );
 }
 
+/// Load the user-adjusted height for a resizable prompt view (e.g. `"editor"`
+/// or `"term"`). Returns `None` if the user has never resized that view.
+pub fn load_view_height(view_key: &str) -> Option<f64> {
+    load_state_file()?.view_heights.get(view_key).copied()
+}
+
+/// Save a user-adjusted height for a resizable prompt view.
+pub fn save_view_height(view_key: &str, height: f64) {
+    let mut state = load_state_file().unwrap_or_default();
+    state.version = 1;
+    state.view_heights.insert(view_key.to_string(), height);
+    save_state_file(&state);
+    logging::log(
+        "WINDOW_STATE",
+        &format!("Saved {} prompt height: {:.0}px", view_key, height),
+    );
+}
+
 /// Reset all window positions (delete the state file)
 pub fn reset_all_positions() {
     let path = get_state_file_path();